@@ -55,6 +55,13 @@ mod benchmark {
         let encoded_flex_uints = roundtrip_flex_uint_test(&unsigned_values).unwrap();
         let encoded_flex_ints = roundtrip_flex_int_test(&signed_values).unwrap();
 
+        // Most FlexUInts and FlexInts in real data (lengths, symbol IDs, macro addresses) are only
+        // one or two bytes long. Benchmark that case separately.
+        let small_unsigned_values = generate_unsigned_values(u64::MIN, 1 << 14);
+        let small_signed_values = generate_signed_values(-1 << 13, 1 << 13);
+        let encoded_small_flex_uints = roundtrip_flex_uint_test(&small_unsigned_values).unwrap();
+        let encoded_small_flex_ints = roundtrip_flex_int_test(&small_signed_values).unwrap();
+
         let mut binary_1_0_group = c.benchmark_group("binary 1.0");
         binary_1_0_group.bench_function("write VarUInt", |b| {
             // `io::sink()` is an implementation of `io::Write` that simply discards the provided bytes
@@ -154,6 +161,68 @@ mod benchmark {
                 assert_eq!(decoded_length, encoded_flex_ints.len());
             })
         });
+        binary_1_1_group.bench_function("read small FlexUInt", |b| {
+            b.iter(|| {
+                let encoding_context = EncodingContext::empty();
+                let mut decoded_length: usize = 0;
+                let mut input = BinaryBuffer::new(encoding_context.get_ref(), encoded_small_flex_uints.as_slice());
+                for _ in 0..small_unsigned_values.len() {
+                    let (flex_uint, remaining) = input.read_flex_uint().unwrap();
+                    input = remaining;
+                    decoded_length += flex_uint.size_in_bytes();
+                }
+                assert_eq!(decoded_length, encoded_small_flex_uints.len());
+            })
+        });
+        binary_1_1_group.bench_function("read small FlexInt", |b| {
+            b.iter(|| {
+                let encoding_context = EncodingContext::empty();
+                let mut decoded_length: usize = 0;
+                let mut input = BinaryBuffer::new(encoding_context.get_ref(), encoded_small_flex_ints.as_slice());
+                for _ in 0..small_signed_values.len() {
+                    let (flex_int, remaining) = input.read_flex_int().unwrap();
+                    input = remaining;
+                    decoded_length += flex_int.size_in_bytes();
+                }
+                assert_eq!(decoded_length, encoded_small_flex_ints.len());
+            })
+        });
+        // The benchmarks above include the overhead of `BinaryBuffer`'s bookkeeping. These read
+        // each primitive directly from a byte slice to isolate the cost of decoding.
+        for (label, encoded_values, num_values) in [
+            ("FlexUInt", &encoded_flex_uints, unsigned_values.len()),
+            ("small FlexUInt", &encoded_small_flex_uints, small_unsigned_values.len()),
+        ] {
+            binary_1_1_group.bench_function(format!("read {label} from slice"), |b| {
+                b.iter(|| {
+                    let mut input = encoded_values.as_slice();
+                    let mut sum: u64 = 0;
+                    for _ in 0..num_values {
+                        let flex_uint = FlexUInt::read(input, 0).unwrap();
+                        input = &input[flex_uint.size_in_bytes()..];
+                        sum = sum.wrapping_add(flex_uint.value());
+                    }
+                    black_box(sum);
+                })
+            });
+        }
+        for (label, encoded_values, num_values) in [
+            ("FlexInt", &encoded_flex_ints, signed_values.len()),
+            ("small FlexInt", &encoded_small_flex_ints, small_signed_values.len()),
+        ] {
+            binary_1_1_group.bench_function(format!("read {label} from slice"), |b| {
+                b.iter(|| {
+                    let mut input = encoded_values.as_slice();
+                    let mut sum: i64 = 0;
+                    for _ in 0..num_values {
+                        let flex_int = FlexInt::read(input, 0).unwrap();
+                        input = &input[flex_int.size_in_bytes()..];
+                        sum = sum.wrapping_add(flex_int.value());
+                    }
+                    black_box(sum);
+                })
+            });
+        }
         binary_1_1_group.finish();
     }
 
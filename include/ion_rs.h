@@ -0,0 +1,103 @@
+/*
+ * C declarations for the functions in ion-rs's `ffi` module. Build the library with:
+ *
+ *     cargo rustc --release --features ffi --crate-type cdylib
+ *
+ * Every function returns an IonStatus; other results are stored through out-pointers. After a
+ * failure, ion_error_message() describes it. Text and byte pointers returned by a reader or
+ * writer remain valid until the next call that uses the same handle. Text is UTF-8 and is not
+ * NUL-terminated unless noted. Handles are not thread-safe.
+ */
+
+#ifndef ION_RS_H
+#define ION_RS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef enum IonStatus {
+    ION_STATUS_OK = 0,
+    ION_STATUS_IO_ERROR = 1,
+    ION_STATUS_INCOMPLETE = 2,
+    ION_STATUS_ENCODING_ERROR = 3,
+    ION_STATUS_DECODING_ERROR = 4,
+    ION_STATUS_ILLEGAL_OPERATION = 5,
+    ION_STATUS_CONVERSION_ERROR = 6,
+    ION_STATUS_INVALID_ARGUMENT = 7,
+    /* The library panicked. The handle in use should only be freed. */
+    ION_STATUS_PANIC = 8,
+    ION_STATUS_CANCELLED = 9,
+} IonStatus;
+
+typedef enum IonTypeCode {
+    ION_TYPE_NONE = 0,
+    ION_TYPE_NULL = 1,
+    ION_TYPE_BOOL = 2,
+    ION_TYPE_INT = 3,
+    ION_TYPE_FLOAT = 4,
+    ION_TYPE_DECIMAL = 5,
+    ION_TYPE_TIMESTAMP = 6,
+    ION_TYPE_SYMBOL = 7,
+    ION_TYPE_STRING = 8,
+    ION_TYPE_CLOB = 9,
+    ION_TYPE_BLOB = 10,
+    ION_TYPE_LIST = 11,
+    ION_TYPE_SEXP = 12,
+    ION_TYPE_STRUCT = 13,
+} IonTypeCode;
+
+typedef struct IonReader IonReader;
+typedef struct IonWriter IonWriter;
+
+/* A NUL-terminated description of the most recent failure on the calling thread. */
+const char *ion_error_message(void);
+
+IonStatus ion_reader_new(const uint8_t *data, size_t length, IonReader **reader);
+void ion_reader_free(IonReader *reader);
+/* Stores ION_TYPE_NONE at the end of the stream or of the current container. */
+IonStatus ion_reader_next(IonReader *reader, IonTypeCode *ion_type);
+IonStatus ion_reader_step_in(IonReader *reader);
+IonStatus ion_reader_step_out(IonReader *reader);
+IonStatus ion_reader_is_null(IonReader *reader, bool *is_null);
+IonStatus ion_reader_field_name(IonReader *reader, const char **text, size_t *length);
+IonStatus ion_reader_annotation_count(IonReader *reader, size_t *count);
+IonStatus ion_reader_annotation(IonReader *reader, size_t index, const char **text, size_t *length);
+IonStatus ion_reader_read_bool(IonReader *reader, bool *value);
+IonStatus ion_reader_read_i64(IonReader *reader, int64_t *value);
+IonStatus ion_reader_read_f64(IonReader *reader, double *value);
+IonStatus ion_reader_read_text(IonReader *reader, const char **text, size_t *length);
+IonStatus ion_reader_read_bytes(IonReader *reader, const uint8_t **data, size_t *length);
+/* The current value (of any type) as Ion text. */
+IonStatus ion_reader_read_ion_text(IonReader *reader, const char **text, size_t *length);
+
+IonStatus ion_writer_new(bool binary, IonWriter **writer);
+void ion_writer_free(IonWriter *writer);
+IonStatus ion_writer_field_name(IonWriter *writer, const char *text, size_t length);
+IonStatus ion_writer_annotation(IonWriter *writer, const char *text, size_t length);
+/* `ion_type` is an IonTypeCode other than ION_TYPE_NONE. */
+IonStatus ion_writer_write_null(IonWriter *writer, uint32_t ion_type);
+IonStatus ion_writer_write_bool(IonWriter *writer, bool value);
+IonStatus ion_writer_write_i64(IonWriter *writer, int64_t value);
+IonStatus ion_writer_write_f64(IonWriter *writer, double value);
+IonStatus ion_writer_write_string(IonWriter *writer, const char *text, size_t length);
+IonStatus ion_writer_write_symbol(IonWriter *writer, const char *text, size_t length);
+IonStatus ion_writer_write_blob(IonWriter *writer, const uint8_t *data, size_t length);
+IonStatus ion_writer_write_clob(IonWriter *writer, const uint8_t *data, size_t length);
+/* Parses one value from Ion text and writes it. */
+IonStatus ion_writer_write_ion_text(IonWriter *writer, const char *text, size_t length);
+/* `ion_type` is ION_TYPE_LIST, ION_TYPE_SEXP, or ION_TYPE_STRUCT. */
+IonStatus ion_writer_step_in(IonWriter *writer, uint32_t ion_type);
+IonStatus ion_writer_step_out(IonWriter *writer);
+/* Stores all of the output the writer has produced so far. */
+IonStatus ion_writer_flush(IonWriter *writer, const uint8_t **data, size_t *length);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ION_RS_H */
@@ -0,0 +1,241 @@
+//! Derive macros for [`ion-rs`](https://docs.rs/ion-rs).
+//!
+//! This crate is not intended to be used directly. Enable the `ion-rs-derive` feature of `ion-rs`
+//! instead, which re-exports each macro alongside the trait that it implements.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+use syn::{
+    parenthesized, parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Field, Fields,
+    LitStr, Token,
+};
+
+/// Implements `WriteAsIon` for a struct with named fields, writing it as an Ion struct with a
+/// field for each of the Rust struct's fields. Each field's value must implement `WriteAsIon`.
+///
+/// The generated implementation can be customized with `#[ion(...)]` attributes.
+///
+/// On the struct:
+/// * `annotations("a", "b", ...)` annotates the Ion struct with the given annotations.
+///
+/// On a field:
+/// * `rename = "name"` uses the given text as the Ion field name instead of the Rust field name.
+/// * `annotations("a", "b", ...)` annotates the field's value with the given annotations.
+/// * `symbol` writes the field's value as an Ion symbol rather than using its `WriteAsIon`
+///   implementation. The field's type must implement `AsRef<str>`.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{v1_0, Element, WriteAsIon};
+///
+/// #[derive(WriteAsIon)]
+/// #[ion(annotations("customer"))]
+/// struct Customer {
+///     #[ion(rename = "customer_id")]
+///     id: u64,
+///     name: String,
+///     #[ion(symbol)]
+///     tier: String,
+///     #[ion(annotations("USD"))]
+///     balance: f64,
+/// }
+///
+/// let customer = Customer {
+///     id: 17,
+///     name: "Alice".to_string(),
+///     tier: "gold".to_string(),
+///     balance: 12.5,
+/// };
+/// let ion_text: String = customer.encode_as(v1_0::Text)?;
+/// let expected = Element::read_one(
+///     r#"customer::{customer_id: 17, name: "Alice", tier: gold, balance: USD::12.5e0}"#,
+/// )?;
+/// assert_eq!(Element::read_one(ion_text)?, expected);
+///# Ok(())
+///# }
+/// ```
+#[proc_macro_derive(WriteAsIon, attributes(ion))]
+pub fn derive_write_as_ion(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_write_as_ion(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_write_as_ion(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "WriteAsIon can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "WriteAsIon can only be derived for structs",
+            ))
+        }
+    };
+
+    let container_attributes = ContainerAttributes::parse(&input.attrs)?;
+    let field_writes = fields
+        .iter()
+        .map(write_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let struct_writer = if container_attributes.annotations.is_empty() {
+        quote! { ::ion_rs::ValueWriter::struct_writer(writer)? }
+    } else {
+        let annotations = &container_attributes.annotations;
+        quote! {
+            ::ion_rs::ValueWriter::struct_writer(
+                ::ion_rs::AnnotatableWriter::with_annotations(writer, [#(#annotations),*])?
+            )?
+        }
+    };
+
+    // Each type parameter must be writable for the struct to be writable.
+    let type_params: Vec<_> = input
+        .generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = input.generics.make_where_clause();
+    for type_param in type_params {
+        where_clause
+            .predicates
+            .push(parse_quote!(#type_param: ::ion_rs::WriteAsIon));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::ion_rs::WriteAsIon for #name #type_generics #where_clause {
+            fn write_as_ion<__V: ::ion_rs::ValueWriter>(
+                &self,
+                writer: __V,
+            ) -> ::ion_rs::IonResult<()> {
+                let mut struct_writer = #struct_writer;
+                #(#field_writes)*
+                ::ion_rs::StructWriter::close(struct_writer)
+            }
+        }
+    })
+}
+
+/// Generates the statement that writes `field` to a `StructWriter` named `struct_writer`.
+fn write_field(field: &Field) -> syn::Result<TokenStream2> {
+    let attributes = FieldAttributes::parse(&field.attrs)?;
+    let ident = field
+        .ident
+        .as_ref()
+        .expect("fields of a struct with named fields have identifiers");
+    let name = attributes
+        .rename
+        .unwrap_or_else(|| LitStr::new(&ident.unraw().to_string(), ident.span()));
+
+    if attributes.annotations.is_empty() && !attributes.symbol {
+        return Ok(quote! {
+            ::ion_rs::StructWriter::write(&mut struct_writer, #name, &self.#ident)?;
+        });
+    }
+
+    let annotate = if attributes.annotations.is_empty() {
+        quote! {}
+    } else {
+        let annotations = &attributes.annotations;
+        quote! {
+            let value_writer =
+                ::ion_rs::AnnotatableWriter::with_annotations(value_writer, [#(#annotations),*])?;
+        }
+    };
+    let write_value = if attributes.symbol {
+        quote! {
+            ::ion_rs::ValueWriter::write_symbol(
+                value_writer,
+                ::std::convert::AsRef::<str>::as_ref(&self.#ident),
+            )?;
+        }
+    } else {
+        quote! { ::ion_rs::ValueWriter::write(value_writer, &self.#ident)?; }
+    };
+    Ok(quote! {
+        {
+            let value_writer = ::ion_rs::StructWriter::field_writer(&mut struct_writer, #name);
+            #annotate
+            #write_value
+        }
+    })
+}
+
+#[derive(Default)]
+struct ContainerAttributes {
+    annotations: Vec<LitStr>,
+}
+
+impl ContainerAttributes {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut attributes = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("ion")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("annotations") {
+                    attributes.annotations.extend(parse_annotations(&meta)?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `ion` attribute for a struct"))
+                }
+            })?;
+        }
+        Ok(attributes)
+    }
+}
+
+#[derive(Default)]
+struct FieldAttributes {
+    rename: Option<LitStr>,
+    annotations: Vec<LitStr>,
+    symbol: bool,
+}
+
+impl FieldAttributes {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut attributes = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("ion")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    if attributes.rename.is_some() {
+                        return Err(meta.error("duplicate `rename` attribute"));
+                    }
+                    attributes.rename = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("annotations") {
+                    attributes.annotations.extend(parse_annotations(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("symbol") {
+                    attributes.symbol = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `ion` attribute for a field"))
+                }
+            })?;
+        }
+        Ok(attributes)
+    }
+}
+
+/// Parses the parenthesized list of strings in an `annotations("a", "b", ...)` attribute.
+fn parse_annotations(meta: &ParseNestedMeta) -> syn::Result<Vec<LitStr>> {
+    let content;
+    parenthesized!(content in meta.input);
+    let annotations = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+    Ok(annotations.into_iter().collect())
+}
@@ -0,0 +1,84 @@
+use ion_rs::{v1_0, Element, IonResult, Symbol, WriteAsIon};
+
+/// Encodes `value` as text and binary Ion, confirming that each encoding reads back as `expected`.
+fn assert_writes_as<T: WriteAsIon>(value: &T, expected: &str) -> IonResult<()> {
+    let expected = Element::read_one(expected)?;
+    let text: String = value.encode_as(v1_0::Text)?;
+    assert_eq!(Element::read_one(text)?, expected);
+    let binary: Vec<u8> = value.encode_as(v1_0::Binary)?;
+    assert_eq!(Element::read_one(binary)?, expected);
+    Ok(())
+}
+
+#[derive(WriteAsIon)]
+struct Point {
+    x: i64,
+    y: i64,
+}
+
+#[test]
+fn named_fields() -> IonResult<()> {
+    assert_writes_as(&Point { x: 1, y: 2 }, "{x: 1, y: 2}")
+}
+
+#[derive(WriteAsIon)]
+#[ion(annotations("shape", "polygon"))]
+struct Polygon {
+    #[ion(rename = "points", annotations("closed"))]
+    vertices: Vec<Point>,
+    #[ion(symbol)]
+    color: String,
+    #[ion(symbol, annotations("style"))]
+    stroke: &'static str,
+    r#type: Option<Symbol>,
+}
+
+#[test]
+fn attributes() -> IonResult<()> {
+    let polygon = Polygon {
+        vertices: vec![Point { x: 0, y: 0 }, Point { x: 1, y: 1 }],
+        color: "red".to_string(),
+        stroke: "dashed",
+        r#type: None,
+    };
+    assert_writes_as(
+        &polygon,
+        r#"
+        shape::polygon::{
+            points: closed::[{x: 0, y: 0}, {x: 1, y: 1}],
+            color: red,
+            stroke: style::dashed,
+            type: null,
+        }
+        "#,
+    )
+}
+
+#[derive(WriteAsIon)]
+struct Tagged<'a, T> {
+    tag: &'a str,
+    value: T,
+}
+
+#[test]
+fn generic_fields() -> IonResult<()> {
+    let tagged = Tagged {
+        tag: "nested",
+        value: Tagged {
+            tag: "inner",
+            value: 2.5f64,
+        },
+    };
+    assert_writes_as(
+        &tagged,
+        r#"{tag: "nested", value: {tag: "inner", value: 2.5e0}}"#,
+    )
+}
+
+#[derive(WriteAsIon)]
+struct Empty {}
+
+#[test]
+fn no_fields() -> IonResult<()> {
+    assert_writes_as(&Empty {}, "{}")
+}
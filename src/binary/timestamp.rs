@@ -71,7 +71,7 @@ where
                     bytes_written += VarUInt::write_u64(self, utc.hour() as u64)?;
                     bytes_written += VarUInt::write_u64(self, utc.minute() as u64)?;
                     if timestamp.precision > TimestampPrecision::HourAndMinute {
-                        bytes_written += VarUInt::write_u64(self, utc.second() as u64)?;
+                        bytes_written += VarUInt::write_u64(self, timestamp.second() as u64)?;
                         if let Some(ref mantissa) = timestamp.fractional_seconds {
                             // TODO: Both branches encode directly due to one
                             // branch owning vs borrowing the decimal
@@ -81,7 +81,8 @@ where
                                     // Consider the following case: `2000-01-01T00:00:00.123Z`.
                                     // That's 123 millis, or 123,000,000 nanos.
                                     // Our mantissa is 0.123, or 123d-3.
-                                    let scaled = utc.nanosecond() / 10u32.pow(9 - *precision); // 123,000,000 -> 123
+                                    let scaled = timestamp.subsecond_nanoseconds()
+                                        / 10u32.pow(9 - *precision); // 123,000,000 -> 123
                                     let exponent = (*precision as i64).neg(); // -3
                                     let fractional = Decimal::new(scaled, exponent); // 123d-3
                                     bytes_written += self.encode_decimal(&fractional)?;
@@ -1,5 +1,7 @@
+use crate::lazy::expanded::macro_table::MacroTable;
 use crate::shared_symbol_table::SharedSymbolTable;
 use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
 
 /// A Catalog is a collection of Shared Symbol Tables.
 /// For more information about the concept of a catalog,
@@ -13,17 +15,40 @@ pub trait Catalog {
     /// Returns the Shared Symbol Table with given table name and version
     /// If a table with given name and version doesn't exists then it returns None
     fn get_table_with_version(&self, name: &str, version: usize) -> Option<&SharedSymbolTable>;
+    /// Returns the macros defined by the shared module with given name and version.
+    /// Ion 1.1 streams import a shared module's symbols and macros with the `use` directive.
+    /// If the catalog doesn't hold macros for that module then it returns None
+    fn get_macro_table_with_version(&self, _name: &str, _version: usize) -> Option<&MacroTable> {
+        None
+    }
+}
+
+/// A catalog can be shared by readers on several threads by wrapping it in an `Arc`.
+impl<C: Catalog + ?Sized> Catalog for Arc<C> {
+    fn get_table(&self, name: &str) -> Option<&SharedSymbolTable> {
+        (**self).get_table(name)
+    }
+
+    fn get_table_with_version(&self, name: &str, version: usize) -> Option<&SharedSymbolTable> {
+        (**self).get_table_with_version(name, version)
+    }
+
+    fn get_macro_table_with_version(&self, name: &str, version: usize) -> Option<&MacroTable> {
+        (**self).get_macro_table_with_version(name, version)
+    }
 }
 
 #[derive(Default)]
 pub struct MapCatalog {
     tables_by_name: HashMap<String, BTreeMap<usize, SharedSymbolTable>>,
+    macro_tables_by_name: HashMap<String, BTreeMap<usize, MacroTable>>,
 }
 
 impl MapCatalog {
     pub fn new() -> Self {
         Self {
             tables_by_name: HashMap::new(),
+            macro_tables_by_name: HashMap::new(),
         }
     }
 }
@@ -48,6 +73,10 @@ impl Catalog for MapCatalog {
 
         versions.get(&version)
     }
+
+    fn get_macro_table_with_version(&self, name: &str, version: usize) -> Option<&MacroTable> {
+        self.macro_tables_by_name.get(name)?.get(&version)
+    }
 }
 
 impl MapCatalog {
@@ -65,6 +94,133 @@ impl MapCatalog {
             }
         };
     }
+
+    /// Adds the macros defined by the shared module with the given name and version into the
+    /// Catalog. A module may have both a Shared Symbol Table and a macro table.
+    ///
+    /// The macro table can be an `Arc<MacroTable>` that is also used elsewhere. Streams that
+    /// import the module refer to its macros instead of copying them.
+    pub fn insert_macro_table(
+        &mut self,
+        name: impl Into<String>,
+        version: usize,
+        macro_table: impl Into<Arc<MacroTable>>,
+    ) {
+        self.macro_tables_by_name
+            .entry(name.into())
+            .or_default()
+            .insert(version, MacroTable::extending(macro_table.into()));
+    }
+}
+
+impl MapCatalog {
+    /// Adds all of the tables and macro tables in `other` to this catalog. If both catalogs have
+    /// a table with the same name and version, the one from `other` replaces the one in `self`.
+    pub fn merge(&mut self, other: MapCatalog) {
+        for (name, versions) in other.tables_by_name {
+            self.tables_by_name
+                .entry(name)
+                .or_default()
+                .extend(versions);
+        }
+        for (name, versions) in other.macro_tables_by_name {
+            self.macro_tables_by_name
+                .entry(name)
+                .or_default()
+                .extend(versions);
+        }
+    }
+
+    /// Returns a catalog containing the contents of `self` and `overrides`. If both catalogs have
+    /// a table with the same name and version, the one from `overrides` is kept.
+    pub fn with_overrides(mut self, overrides: MapCatalog) -> Self {
+        self.merge(overrides);
+        self
+    }
+}
+
+/// A catalog that searches a series of other catalogs, allowing (for example) a small set of
+/// in-memory overrides to be placed in front of a larger catalog.
+///
+/// Catalogs added earlier take precedence over those added later:
+/// * A request for a specific version of a table or macro table is answered by the first catalog
+///   that has that version.
+/// * A request for the latest version of a table returns the highest version found in any
+///   catalog. If several catalogs have that version, the first of them is used.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{Catalog, CompositeCatalog, MapCatalog, SharedSymbolTable};
+///
+/// let mut built_in = MapCatalog::new();
+/// built_in.insert_table(SharedSymbolTable::new("app", 1, ["id", "name"])?);
+/// built_in.insert_table(SharedSymbolTable::new("app", 2, ["id", "name", "email"])?);
+/// let mut overrides = MapCatalog::new();
+/// overrides.insert_table(SharedSymbolTable::new("app", 1, ["id", "full_name"])?);
+///
+/// let catalog = CompositeCatalog::new().with_catalog(overrides).with_catalog(built_in);
+/// let app_v1 = catalog.get_table_with_version("app", 1).unwrap();
+/// assert_eq!(app_v1.symbols()[1].text(), Some("full_name"));
+/// assert_eq!(catalog.get_table("app").unwrap().version(), 2);
+///# Ok(())
+///# }
+/// ```
+#[derive(Default)]
+pub struct CompositeCatalog {
+    catalogs: Vec<Box<dyn Catalog>>,
+}
+
+impl CompositeCatalog {
+    /// Constructs a `CompositeCatalog` that does not contain any catalogs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `catalog` to the end of the search order; it will only be consulted for tables that
+    /// the previously added catalogs do not have.
+    pub fn with_catalog(mut self, catalog: impl Catalog + 'static) -> Self {
+        self.push(catalog);
+        self
+    }
+
+    /// Adds `catalog` to the end of the search order.
+    pub fn push(&mut self, catalog: impl Catalog + 'static) {
+        self.catalogs.push(Box::new(catalog));
+    }
+
+    /// Returns the number of catalogs being searched.
+    pub fn len(&self) -> usize {
+        self.catalogs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.catalogs.is_empty()
+    }
+}
+
+impl Catalog for CompositeCatalog {
+    fn get_table(&self, name: &str) -> Option<&SharedSymbolTable> {
+        self.catalogs
+            .iter()
+            .filter_map(|catalog| catalog.get_table(name))
+            // `max_by_key` returns the last of several equal elements, so search in reverse to
+            // give earlier catalogs precedence.
+            .rev()
+            .max_by_key(|table| table.version())
+    }
+
+    fn get_table_with_version(&self, name: &str, version: usize) -> Option<&SharedSymbolTable> {
+        self.catalogs
+            .iter()
+            .find_map(|catalog| catalog.get_table_with_version(name, version))
+    }
+
+    fn get_macro_table_with_version(&self, name: &str, version: usize) -> Option<&MacroTable> {
+        self.catalogs
+            .iter()
+            .find_map(|catalog| catalog.get_macro_table_with_version(name, version))
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -82,9 +238,22 @@ impl Catalog for EmptyCatalog {
 
 #[cfg(test)]
 mod tests {
-    use crate::catalog::{Catalog, MapCatalog};
+    use crate::catalog::{Catalog, CompositeCatalog, MapCatalog};
     use crate::shared_symbol_table::SharedSymbolTable;
-    use crate::{IonResult, Symbol};
+    use crate::{IonResult, MacroTable, Symbol};
+    use std::sync::Arc;
+
+    fn catalog_with(tables: &[(&str, usize, &str)]) -> IonResult<MapCatalog> {
+        let mut catalog = MapCatalog::new();
+        for (name, version, symbol) in tables {
+            catalog.insert_table(SharedSymbolTable::new(*name, *version, [*symbol])?);
+        }
+        Ok(catalog)
+    }
+
+    fn first_symbol(table: Option<&SharedSymbolTable>) -> Option<&str> {
+        table?.symbols()[0].text()
+    }
 
     #[test]
     fn get_table_with_name_test() -> IonResult<()> {
@@ -121,4 +290,64 @@ mod tests {
         assert!(catalog.get_table_with_version("T", 2).is_none());
         Ok(())
     }
+
+    #[test]
+    fn composite_catalog_precedence() -> IonResult<()> {
+        let overrides = catalog_with(&[("T", 1, "override"), ("S", 1, "override")])?;
+        let base = catalog_with(&[("T", 1, "base"), ("T", 2, "base"), ("S", 1, "base")])?;
+        let catalog = CompositeCatalog::new()
+            .with_catalog(overrides)
+            .with_catalog(base);
+        assert_eq!(catalog.len(), 2);
+
+        // Earlier catalogs win for a specific version...
+        assert_eq!(
+            first_symbol(catalog.get_table_with_version("T", 1)),
+            Some("override")
+        );
+        assert_eq!(
+            first_symbol(catalog.get_table_with_version("T", 2)),
+            Some("base")
+        );
+        assert!(catalog.get_table_with_version("T", 3).is_none());
+        // ...while the latest version is the highest one in any catalog, preferring earlier
+        // catalogs when several have it.
+        assert_eq!(catalog.get_table("T").unwrap().version(), 2);
+        assert_eq!(first_symbol(catalog.get_table("S")), Some("override"));
+        assert!(catalog.get_table("U").is_none());
+        assert!(CompositeCatalog::new().get_table("T").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn composite_catalog_macro_tables() {
+        let mut overrides = MapCatalog::new();
+        overrides.insert_macro_table("m", 1, Arc::new(MacroTable::empty()));
+        let mut base = MapCatalog::new();
+        base.insert_macro_table("m", 2, Arc::new(MacroTable::empty()));
+        let catalog = CompositeCatalog::new()
+            .with_catalog(Arc::new(overrides))
+            .with_catalog(base);
+        assert!(catalog.get_macro_table_with_version("m", 1).is_some());
+        assert!(catalog.get_macro_table_with_version("m", 2).is_some());
+        assert!(catalog.get_macro_table_with_version("m", 3).is_none());
+    }
+
+    #[test]
+    fn merge_map_catalogs() -> IonResult<()> {
+        let base = catalog_with(&[("T", 1, "base"), ("T", 2, "base"), ("S", 1, "base")])?;
+        let overrides = catalog_with(&[("T", 2, "override"), ("T", 3, "override")])?;
+        let merged = base.with_overrides(overrides);
+        assert_eq!(
+            first_symbol(merged.get_table_with_version("T", 1)),
+            Some("base")
+        );
+        assert_eq!(
+            first_symbol(merged.get_table_with_version("T", 2)),
+            Some("override")
+        );
+        assert_eq!(merged.get_table("T").unwrap().version(), 3);
+        assert_eq!(first_symbol(merged.get_table("S")), Some("base"));
+        Ok(())
+    }
 }
@@ -0,0 +1,1017 @@
+//! Converts between Ion and Avro data, either as individual datums (such as the payloads of Kafka
+//! messages) or as Avro object container files.
+//!
+//! Avro values become Ion values as follows:
+//! * `null`, `boolean`, `int` and `long`, `float` and `double`, `string`, and `bytes` become
+//!   nulls, bools, ints, floats, strings, and blobs.
+//! * Records and maps become structs, arrays become lists, enum values become symbols, and
+//!   `fixed` values become blobs.
+//! * A union becomes the value of the branch that it holds.
+//!
+//! Logical types are mapped as follows:
+//! * `decimal` values (of either `bytes` or `fixed` type) become decimals with the schema's scale.
+//! * `date` values become timestamps with day precision.
+//! * `timestamp-millis` and `timestamp-micros` values become UTC timestamps with millisecond or
+//!   microsecond precision. `local-timestamp-millis` and `local-timestamp-micros` values become
+//!   timestamps with the same precision and an unknown offset.
+//! * `uuid` values become 16-byte blobs.
+//! * `time-millis` and `time-micros` values, which Ion has no type for, become ints.
+//! * `duration` values become structs with `months`, `days`, and `milliseconds` fields.
+//!
+//! When writing Avro, the inverse mapping is used. In addition, ints are accepted for `float`,
+//! `double`, and `decimal` values, symbols are accepted for `string` values, strings are accepted
+//! for `enum` and `uuid` values, and clobs are accepted for `bytes` and `fixed` values. Record
+//! fields that are missing (or null) are written as null, which requires the field's schema to
+//! allow it. A value written to a union uses the first branch that can hold it.
+//!
+//! Values that would lose information in the conversion (like a timestamp with more precision
+//! than its Avro type supports) cause an `Err`. Schemas that refer to named types by name are not
+//! supported.
+
+use avro_schema::error::Error as AvroError;
+use avro_schema::file::CompressedBlock;
+use avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
+use avro_schema::read::{block_iterator, read_metadata};
+use avro_schema::schema::{
+    BytesLogical, FixedLogical, IntLogical, LongLogical, Record, Schema, StringLogical,
+};
+use avro_schema::write::{write_block, write_metadata};
+use chrono::{DateTime, Datelike, NaiveDate, Timelike};
+use std::io::{Read, Write};
+
+use crate::convert::{unix_time, unscaled_value};
+use crate::lazy::decoder::Decoder;
+use crate::lazy::encoder::writer::Writer;
+use crate::lazy::encoding::Encoding;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::result::IonFailure;
+use crate::write_config::WriteConfig;
+use crate::{
+    Decimal, Element, IonError, IonResult, IonType, List, Reader, Struct, Symbol, Timestamp,
+    TimestampPrecision,
+};
+
+/// The number of records written to each block of an object container file.
+const RECORDS_PER_BLOCK: usize = 1024;
+
+/// Parses an Avro schema from its JSON representation.
+pub fn parse_schema(json: &str) -> IonResult<Schema> {
+    serde_json::from_str(json)
+        .map_err(|e| IonError::decoding_error(format!("invalid Avro schema: {e}")))
+}
+
+/// Reads a single Avro datum with the given `schema` from `input`. The datum must not be preceded
+/// by any framing, like the schema ID that some Kafka serializers add.
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::convert::avro::{parse_schema, read_datum};
+/// use ion_rs::Element;
+///
+/// let schema = parse_schema(
+///     r#"{"type": "record", "name": "Reading", "fields": [
+///         {"name": "sensor", "type": "string"},
+///         {"name": "at", "type": {"type": "long", "logicalType": "timestamp-millis"}}
+///     ]}"#,
+/// )?;
+/// let datum = [0x04, b'a', b'7', 0x80, 0xd0, 0x8f, 0xa5, 0x98, 0x63];
+/// assert_eq!(
+///     read_datum(&mut &datum[..], &schema)?,
+///     Element::read_one("{sensor: \"a7\", at: 2024-01-01T00:00:00.000Z}")?
+/// );
+///# Ok(())
+///# }
+/// ```
+pub fn read_datum(input: &mut impl Read, schema: &Schema) -> IonResult<Element> {
+    let element = match schema {
+        Schema::Null => Element::null(IonType::Null),
+        Schema::Boolean => match read_byte(input)? {
+            0 => false.into(),
+            1 => true.into(),
+            byte => {
+                return IonResult::decoding_error(format!("invalid Avro boolean byte: {byte:#04x}"))
+            }
+        },
+        Schema::Int(None | Some(IntLogical::Time))
+        | Schema::Long(None | Some(LongLogical::Time)) => read_long(input)?.into(),
+        Schema::Int(Some(IntLogical::Date)) => date(read_long(input)?)?.into(),
+        Schema::Long(Some(logical)) => {
+            let (units_per_second, utc) = timestamp_kind(*logical);
+            epoch_timestamp(read_long(input)?, units_per_second, utc)?.into()
+        }
+        Schema::Float => {
+            let mut bytes = [0u8; 4];
+            input.read_exact(&mut bytes)?;
+            (f32::from_le_bytes(bytes) as f64).into()
+        }
+        Schema::Double => {
+            let mut bytes = [0u8; 8];
+            input.read_exact(&mut bytes)?;
+            f64::from_le_bytes(bytes).into()
+        }
+        Schema::Bytes(None) => Element::blob(read_bytes(input)?),
+        Schema::Bytes(Some(BytesLogical::Decimal(_, scale))) => {
+            decimal(&read_bytes(input)?, *scale)?.into()
+        }
+        Schema::String(logical) => {
+            let Ok(text) = String::from_utf8(read_bytes(input)?) else {
+                return IonResult::decoding_error("Avro string was not valid UTF-8");
+            };
+            match logical {
+                None => text.into(),
+                Some(StringLogical::Uuid) => match parse_uuid(&text) {
+                    Some(uuid) => Element::blob(uuid),
+                    None => return IonResult::decoding_error(format!("invalid UUID: '{text}'")),
+                },
+            }
+        }
+        Schema::Record(record) => {
+            let mut fields = Vec::with_capacity(record.fields.len());
+            for field in &record.fields {
+                fields.push((field.name.as_str(), read_datum(input, &field.schema)?));
+            }
+            Struct::from_iter(fields).into()
+        }
+        Schema::Enum(enum_) => {
+            let index = read_long(input)?;
+            match usize::try_from(index)
+                .ok()
+                .and_then(|i| enum_.symbols.get(i))
+            {
+                Some(symbol) => Symbol::from(symbol.as_str()).into(),
+                None => {
+                    return IonResult::decoding_error(format!(
+                        "enum {} has no symbol with index {index}",
+                        enum_.name
+                    ))
+                }
+            }
+        }
+        Schema::Array(items) => {
+            let mut values = Vec::new();
+            read_blocks(input, |input| {
+                values.push(read_datum(input, items)?);
+                Ok(())
+            })?;
+            List::from(values).into()
+        }
+        Schema::Map(values) => {
+            let mut fields = Vec::new();
+            read_blocks(input, |input| {
+                let Ok(key) = String::from_utf8(read_bytes(input)?) else {
+                    return IonResult::decoding_error("Avro map key was not valid UTF-8");
+                };
+                fields.push((key, read_datum(input, values)?));
+                Ok(())
+            })?;
+            Struct::from_iter(fields).into()
+        }
+        Schema::Union(branches) => {
+            let index = read_long(input)?;
+            match usize::try_from(index).ok().and_then(|i| branches.get(i)) {
+                Some(branch) => read_datum(input, branch)?,
+                None => {
+                    return IonResult::decoding_error(format!(
+                        "union has no branch with index {index}"
+                    ))
+                }
+            }
+        }
+        Schema::Fixed(fixed) => {
+            let mut bytes = vec![0u8; fixed.size];
+            input.read_exact(&mut bytes)?;
+            match fixed.logical {
+                None => Element::blob(bytes),
+                Some(FixedLogical::Decimal(_, scale)) => decimal(&bytes, scale)?.into(),
+                Some(FixedLogical::Duration) if fixed.size == 12 => {
+                    let part = |index: usize| {
+                        let bytes: [u8; 4] = bytes[index * 4..][..4].try_into().unwrap();
+                        Element::from(u32::from_le_bytes(bytes) as i64)
+                    };
+                    Struct::from_iter([
+                        ("months", part(0)),
+                        ("days", part(1)),
+                        ("milliseconds", part(2)),
+                    ])
+                    .into()
+                }
+                Some(FixedLogical::Duration) => {
+                    return IonResult::decoding_error("a duration must be a fixed of size 12")
+                }
+            }
+        }
+    };
+    Ok(element)
+}
+
+/// Writes `value` to `output` as a single Avro datum with the given `schema`.
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::convert::avro::{parse_schema, read_datum, write_datum};
+/// use ion_rs::Element;
+///
+/// let schema = parse_schema(r#"{"type": "array", "items": ["null", "long"]}"#)?;
+/// let value = Element::read_one("[1, null, -1]")?;
+/// let mut datum = Vec::new();
+/// write_datum(&mut datum, &schema, &value)?;
+/// assert_eq!(datum, [0x06, 0x02, 0x02, 0x00, 0x02, 0x01, 0x00]);
+/// assert_eq!(read_datum(&mut datum.as_slice(), &schema)?, value);
+///# Ok(())
+///# }
+/// ```
+pub fn write_datum(output: &mut impl Write, schema: &Schema, value: &Element) -> IonResult<()> {
+    if let Schema::Union(branches) = schema {
+        // Prefer a branch whose type corresponds exactly to the value's; fall back to one that
+        // can hold it after a conversion (e.g. an int written as a double).
+        let index = branches
+            .iter()
+            .position(|branch| matches_type(branch, value, false))
+            .or_else(|| {
+                branches
+                    .iter()
+                    .position(|branch| matches_type(branch, value, true))
+            });
+        let Some(index) = index else {
+            return mismatch(value, schema);
+        };
+        write_long(output, index as i64)?;
+        return write_datum(output, &branches[index], value);
+    }
+    if value.is_null() {
+        return match schema {
+            Schema::Null => Ok(()),
+            _ => mismatch(value, schema),
+        };
+    }
+    match schema {
+        Schema::Null | Schema::Union(_) => return mismatch(value, schema),
+        Schema::Boolean => match value.as_bool() {
+            Some(b) => output.write_all(&[b as u8])?,
+            None => return mismatch(value, schema),
+        },
+        Schema::Int(None | Some(IntLogical::Time)) => {
+            match value
+                .as_int()
+                .map(|i| i.as_i64().and_then(|i| i32::try_from(i).ok()))
+            {
+                Some(Some(i)) => write_long(output, i as i64)?,
+                Some(None) => return out_of_range(value, schema),
+                None => return mismatch(value, schema),
+            }
+        }
+        Schema::Long(None | Some(LongLogical::Time)) => match value.as_int().map(|i| i.as_i64()) {
+            Some(Some(i)) => write_long(output, i)?,
+            Some(None) => return out_of_range(value, schema),
+            None => return mismatch(value, schema),
+        },
+        Schema::Int(Some(IntLogical::Date)) => {
+            let Some(timestamp) = value.as_timestamp() else {
+                return mismatch(value, schema);
+            };
+            let days = (timestamp.precision() <= TimestampPrecision::Day)
+                .then(|| {
+                    let date =
+                        NaiveDate::from_ymd_opt(timestamp.year() as i32, timestamp.month(), 1)?
+                            .with_day(timestamp.day())?;
+                    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+                    Some(date.signed_duration_since(epoch).num_days())
+                })
+                .flatten();
+            match days {
+                Some(days) => write_long(output, days)?,
+                None => return out_of_range(value, schema),
+            }
+        }
+        Schema::Long(Some(logical)) => {
+            let Some(timestamp) = value.as_timestamp() else {
+                return mismatch(value, schema);
+            };
+            let (units_per_second, utc) = timestamp_kind(*logical);
+            match epoch_units(&timestamp, units_per_second, utc) {
+                Some(units) => write_long(output, units)?,
+                None => return out_of_range(value, schema),
+            }
+        }
+        Schema::Float | Schema::Double => {
+            let number = match value.ion_type() {
+                IonType::Float => value.as_float(),
+                IonType::Int => value.as_int().and_then(|i| i.as_i64()).map(|i| i as f64),
+                _ => None,
+            };
+            match (number, schema) {
+                (Some(number), Schema::Float) => {
+                    output.write_all(&(number as f32).to_le_bytes())?
+                }
+                (Some(number), _) => output.write_all(&number.to_le_bytes())?,
+                (None, _) => return mismatch(value, schema),
+            }
+        }
+        Schema::Bytes(None) => match value.as_lob() {
+            Some(bytes) => write_bytes(output, bytes)?,
+            None => return mismatch(value, schema),
+        },
+        Schema::Bytes(Some(BytesLogical::Decimal(precision, scale))) => {
+            let Some(unscaled) = unscaled_value(value, *precision as u32, *scale as u32) else {
+                return mismatch_or_out_of_range(value, schema);
+            };
+            write_bytes(output, &minimal_twos_complement(unscaled))?;
+        }
+        Schema::String(None) => match value.as_text() {
+            Some(text) => write_bytes(output, text.as_bytes())?,
+            None => return mismatch(value, schema),
+        },
+        Schema::String(Some(StringLogical::Uuid)) => {
+            let uuid = match value.ion_type() {
+                IonType::Blob => value.as_lob().and_then(|bytes| bytes.try_into().ok()),
+                IonType::String | IonType::Symbol => value.as_text().and_then(parse_uuid),
+                _ => return mismatch(value, schema),
+            };
+            let Some(uuid) = uuid else {
+                return IonResult::encoding_error(format!("{value} is not a valid UUID"));
+            };
+            write_bytes(output, format_uuid(&uuid).as_bytes())?;
+        }
+        Schema::Record(record) => {
+            let Some(strukt) = value.as_struct() else {
+                return mismatch(value, schema);
+            };
+            for (name, _) in strukt {
+                if !record
+                    .fields
+                    .iter()
+                    .any(|field| Some(&*field.name) == name.text())
+                {
+                    return IonResult::encoding_error(format!(
+                        "record {} has no field named '{}'",
+                        record.name,
+                        name.text().unwrap_or("$0")
+                    ));
+                }
+            }
+            let missing = Element::null(IonType::Null);
+            for field in &record.fields {
+                let field_value = strukt.get(&field.name).unwrap_or(&missing);
+                write_datum(output, &field.schema, field_value).map_err(|e| {
+                    IonError::encoding_error(format!("field '{}': {e}", field.name))
+                })?;
+            }
+        }
+        Schema::Enum(enum_) => {
+            let index = match value.ion_type() {
+                IonType::Symbol | IonType::String => value
+                    .as_text()
+                    .and_then(|text| enum_.symbols.iter().position(|symbol| symbol == text)),
+                _ => return mismatch(value, schema),
+            };
+            match index {
+                Some(index) => write_long(output, index as i64)?,
+                None => {
+                    return IonResult::encoding_error(format!(
+                        "enum {} has no symbol {value}",
+                        enum_.name
+                    ))
+                }
+            }
+        }
+        Schema::Array(items) => {
+            let Some(sequence) = value.as_sequence() else {
+                return mismatch(value, schema);
+            };
+            if !sequence.is_empty() {
+                write_long(output, sequence.len() as i64)?;
+                for item in sequence {
+                    write_datum(output, items, item)?;
+                }
+            }
+            write_long(output, 0)?;
+        }
+        Schema::Map(values) => {
+            let Some(strukt) = value.as_struct() else {
+                return mismatch(value, schema);
+            };
+            let mut fields = strukt.iter().peekable();
+            if fields.peek().is_some() {
+                write_long(output, strukt.len() as i64)?;
+                for (name, field_value) in fields {
+                    let Some(name) = name.text() else {
+                        return IonResult::encoding_error(
+                            "field names with unknown text cannot be used as map keys",
+                        );
+                    };
+                    write_bytes(output, name.as_bytes())?;
+                    write_datum(output, values, field_value)?;
+                }
+            }
+            write_long(output, 0)?;
+        }
+        Schema::Fixed(fixed) => match fixed.logical {
+            None => match value.as_lob() {
+                Some(bytes) if bytes.len() == fixed.size => output.write_all(bytes)?,
+                Some(_) => {
+                    return IonResult::encoding_error(format!(
+                        "fixed {} requires exactly {} bytes",
+                        fixed.name, fixed.size
+                    ))
+                }
+                None => return mismatch(value, schema),
+            },
+            Some(FixedLogical::Decimal(precision, scale)) => {
+                let Some(unscaled) = unscaled_value(value, precision as u32, scale as u32) else {
+                    return mismatch_or_out_of_range(value, schema);
+                };
+                let bytes = minimal_twos_complement(unscaled);
+                if bytes.len() > fixed.size {
+                    return out_of_range(value, schema);
+                }
+                let sign_extension = if unscaled < 0 { 0xFF } else { 0x00 };
+                let padding = vec![sign_extension; fixed.size - bytes.len()];
+                output.write_all(&padding)?;
+                output.write_all(&bytes)?;
+            }
+            Some(FixedLogical::Duration) => {
+                let Some(strukt) = value.as_struct() else {
+                    return mismatch(value, schema);
+                };
+                for name in ["months", "days", "milliseconds"] {
+                    let part = strukt.get(name).map_or(Some(Some(0)), |part| {
+                        part.as_int()
+                            .map(|i| i.as_i64().and_then(|i| u32::try_from(i).ok()))
+                    });
+                    match part {
+                        Some(Some(part)) => output.write_all(&part.to_le_bytes())?,
+                        Some(None) => return out_of_range(value, schema),
+                        None => return mismatch(value, schema),
+                    }
+                }
+            }
+        },
+    }
+    Ok(())
+}
+
+/// Reads an Avro object container file from `input` and writes each of its records to `output`
+/// as an Ion struct using a [`Writer`] constructed from `config`. Returns `output` once all of
+/// the records have been written.
+///
+/// Compressed files are not supported.
+pub fn avro_to_ion<E: Encoding, W: Write>(
+    mut input: impl Read,
+    config: impl Into<WriteConfig<E>>,
+    output: W,
+) -> IonResult<W> {
+    let metadata = read_metadata(&mut input).map_err(avro_error)?;
+    let schema = Schema::Record(metadata.record);
+    let mut writer = Writer::new(config, output)?;
+    let mut blocks = block_iterator(input, metadata.compression, metadata.marker);
+    while let Some(block) = blocks.next().map_err(avro_error)? {
+        let mut data = block.data.as_slice();
+        for _ in 0..block.number_of_rows {
+            writer.write(&read_datum(&mut data, &schema)?)?;
+        }
+        writer.flush()?;
+    }
+    writer.close()
+}
+
+/// Reads each of the remaining values in `reader` and writes it to `output` as a record of an
+/// (uncompressed) Avro object container file with the given `schema`. Returns the number of
+/// records written.
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::convert::avro::{avro_to_ion, ion_to_avro, parse_schema};
+/// use ion_rs::v1_0::Binary;
+/// use ion_rs::{AnyEncoding, Element, Reader};
+///
+/// let schema = parse_schema(
+///     r#"{"type": "record", "name": "Order", "fields": [
+///         {"name": "id", "type": "long"},
+///         {"name": "total", "type": {"type": "bytes", "logicalType": "decimal", "precision": 9, "scale": 2}}
+///     ]}"#,
+/// )?;
+/// let avro_schema::schema::Schema::Record(record) = schema else { unreachable!() };
+/// let orders = "{id: 1, total: 19.99} {id: 2, total: 5}";
+/// let mut avro = Vec::new();
+/// ion_to_avro(&mut Reader::new(AnyEncoding, orders)?, &record, &mut avro)?;
+///
+/// let ion = avro_to_ion(avro.as_slice(), Binary, Vec::new())?;
+/// assert_eq!(
+///     Element::read_all(ion)?,
+///     Element::read_all("{id: 1, total: 19.99} {id: 2, total: 5.00}")?
+/// );
+///# Ok(())
+///# }
+/// ```
+pub fn ion_to_avro<D: Decoder, I: IonInput>(
+    reader: &mut Reader<D, I>,
+    schema: &Record,
+    mut output: impl Write,
+) -> IonResult<usize> {
+    write_metadata(&mut output, schema.clone(), None).map_err(avro_error)?;
+    let schema = Schema::Record(schema.clone());
+    let mut block = CompressedBlock::default();
+    let mut num_records = 0;
+    while let Some(value) = reader.next()? {
+        write_datum(&mut block.data, &schema, &Element::try_from(value)?)
+            .map_err(|e| IonError::encoding_error(format!("record {num_records}: {e}")))?;
+        block.number_of_rows += 1;
+        num_records += 1;
+        if block.number_of_rows == RECORDS_PER_BLOCK {
+            write_block(&mut output, &block).map_err(avro_error)?;
+            block.data.clear();
+            block.number_of_rows = 0;
+        }
+    }
+    if block.number_of_rows > 0 {
+        write_block(&mut output, &block).map_err(avro_error)?;
+    }
+    output.flush()?;
+    Ok(num_records)
+}
+
+/// Returns whether `value` can be written as a branch of a union with the given `schema`. If
+/// `lenient` is `false`, only branches of the type corresponding to the value's Ion type match.
+fn matches_type(schema: &Schema, value: &Element, lenient: bool) -> bool {
+    if value.is_null() {
+        return matches!(schema, Schema::Null);
+    }
+    match (schema, value.ion_type()) {
+        (Schema::Boolean, IonType::Bool) => true,
+        (
+            Schema::Int(None | Some(IntLogical::Time))
+            | Schema::Long(None | Some(LongLogical::Time)),
+            IonType::Int,
+        ) => true,
+        (Schema::Int(Some(IntLogical::Date)), IonType::Timestamp) => true,
+        (Schema::Long(Some(LongLogical::Time)), _) => false,
+        (Schema::Long(Some(_)), IonType::Timestamp) => true,
+        (Schema::Float | Schema::Double, IonType::Float) => true,
+        (Schema::Float | Schema::Double, IonType::Int) => lenient,
+        (Schema::Bytes(None), IonType::Blob | IonType::Clob) => true,
+        (Schema::Bytes(Some(_)), IonType::Decimal) => true,
+        (Schema::Bytes(Some(_)), IonType::Int) => lenient,
+        (Schema::String(None), IonType::String | IonType::Symbol) => true,
+        (Schema::String(Some(StringLogical::Uuid)), IonType::Blob) => true,
+        (Schema::String(Some(StringLogical::Uuid)), IonType::String) => lenient,
+        (Schema::Enum(enum_), IonType::Symbol | IonType::String) => value
+            .as_text()
+            .is_some_and(|text| enum_.symbols.iter().any(|symbol| symbol == text)),
+        (Schema::Record(_) | Schema::Map(_), IonType::Struct) => true,
+        (Schema::Array(_), IonType::List | IonType::SExp) => true,
+        (Schema::Fixed(fixed), ion_type) => match (fixed.logical, ion_type) {
+            (None, IonType::Blob | IonType::Clob) => value
+                .as_lob()
+                .is_some_and(|bytes| bytes.len() == fixed.size),
+            (Some(FixedLogical::Decimal(..)), IonType::Decimal) => true,
+            (Some(FixedLogical::Decimal(..)), IonType::Int) => lenient,
+            (Some(FixedLogical::Duration), IonType::Struct) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns the number of `units_per_second` in a second for a timestamp logical type, and whether
+/// the timestamp is in UTC (as opposed to local time).
+fn timestamp_kind(logical: LongLogical) -> (i64, bool) {
+    match logical {
+        LongLogical::TimestampMillis => (1_000, true),
+        LongLogical::TimestampMicros => (1_000_000, true),
+        LongLogical::LocalTimestampMillis => (1_000, false),
+        LongLogical::LocalTimestampMicros | LongLogical::Time => (1_000_000, false),
+    }
+}
+
+/// Constructs a timestamp from a number of milliseconds or microseconds since the Unix epoch. If
+/// `utc` is `false`, the timestamp will have an unknown offset.
+fn epoch_timestamp(value: i64, units_per_second: i64, utc: bool) -> IonResult<Timestamp> {
+    let seconds = value.div_euclid(units_per_second);
+    let units = value.rem_euclid(units_per_second) as u32;
+    let nanoseconds = units * (1_000_000_000 / units_per_second) as u32;
+    let Some(date_time) = DateTime::from_timestamp(seconds, nanoseconds) else {
+        return IonResult::decoding_error(format!("Avro timestamp {value} is out of range"));
+    };
+    let builder = Timestamp::with_ymd(date_time.year() as u32, date_time.month(), date_time.day())
+        .with_hms(date_time.hour(), date_time.minute(), date_time.second());
+    let builder = if units_per_second == 1_000 {
+        builder.with_milliseconds(units)
+    } else {
+        builder.with_microseconds(units)
+    };
+    if utc {
+        builder.with_offset(0).build()
+    } else {
+        builder.build()
+    }
+}
+
+/// Returns the number of `units_per_second` between the Unix epoch and `timestamp` (or, if `utc`
+/// is `false`, its local time), or `None` if the timestamp is out of range or more precise than
+/// the unit.
+fn epoch_units(timestamp: &Timestamp, units_per_second: i64, utc: bool) -> Option<i64> {
+    let (mut seconds, nanoseconds) = unix_time(timestamp);
+    if !utc {
+        seconds += timestamp.offset().unwrap_or(0) as i64 * 60;
+    }
+    let nanoseconds_per_unit = (1_000_000_000 / units_per_second) as u32;
+    if nanoseconds % nanoseconds_per_unit != 0 {
+        return None;
+    }
+    seconds
+        .checked_mul(units_per_second)?
+        .checked_add((nanoseconds / nanoseconds_per_unit) as i64)
+}
+
+/// Constructs a timestamp with day precision from a number of days since the Unix epoch.
+fn date(days: i64) -> IonResult<Timestamp> {
+    let Some(date_time) = days
+        .checked_mul(86_400)
+        .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+    else {
+        return IonResult::decoding_error(format!("Avro date {days} is out of range"));
+    };
+    Timestamp::with_ymd(date_time.year() as u32, date_time.month(), date_time.day()).build()
+}
+
+/// Constructs a decimal from the big-endian two's complement representation of its unscaled
+/// value.
+fn decimal(bytes: &[u8], scale: usize) -> IonResult<Decimal> {
+    if bytes.len() > 16 {
+        return IonResult::decoding_error("Avro decimals larger than 16 bytes are not supported");
+    }
+    let sign_extension = if bytes.first().is_some_and(|&b| b & 0x80 != 0) {
+        0xFF
+    } else {
+        0x00
+    };
+    let mut buffer = [sign_extension; 16];
+    buffer[16 - bytes.len()..].copy_from_slice(bytes);
+    Ok(Decimal::new(i128::from_be_bytes(buffer), -(scale as i64)))
+}
+
+/// Returns the shortest big-endian two's complement representation of `value`.
+fn minimal_twos_complement(value: i128) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let mut start = 0;
+    // Drop leading bytes that only repeat the sign bit of the byte that follows them.
+    while start < bytes.len() - 1 {
+        let (byte, next) = (bytes[start], bytes[start + 1]);
+        if (byte == 0x00 && next & 0x80 == 0) || (byte == 0xFF && next & 0x80 != 0) {
+            start += 1;
+        } else {
+            break;
+        }
+    }
+    bytes[start..].to_vec()
+}
+
+fn parse_uuid(text: &str) -> Option<[u8; 16]> {
+    let text = text.as_bytes();
+    if text.len() != 36 || [8, 13, 18, 23].iter().any(|&i| text[i] != b'-') {
+        return None;
+    }
+    let mut digits = text.iter().filter(|&&b| b != b'-');
+    let mut uuid = [0u8; 16];
+    for byte in uuid.iter_mut() {
+        let high = (*digits.next()? as char).to_digit(16)?;
+        let low = (*digits.next()? as char).to_digit(16)?;
+        *byte = (high * 16 + low) as u8;
+    }
+    Some(uuid)
+}
+
+fn format_uuid(uuid: &[u8; 16]) -> String {
+    let mut text = String::with_capacity(36);
+    for (index, byte) in uuid.iter().enumerate() {
+        if [4, 6, 8, 10].contains(&index) {
+            text.push('-');
+        }
+        text.push_str(&format!("{byte:02x}"));
+    }
+    text
+}
+
+fn read_byte(input: &mut impl Read) -> IonResult<u8> {
+    let mut byte = [0u8; 1];
+    input.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
+
+/// Reads a zig-zag encoded variable-length integer.
+fn read_long(input: &mut impl Read) -> IonResult<i64> {
+    let mut value = 0u64;
+    for index in 0..10 {
+        let byte = read_byte(input)?;
+        value |= ((byte & 0x7F) as u64) << (index * 7);
+        if byte & 0x80 == 0 {
+            return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+        }
+    }
+    IonResult::decoding_error("Avro varint was longer than 10 bytes")
+}
+
+fn read_bytes(input: &mut impl Read) -> IonResult<Vec<u8>> {
+    let length = read_long(input)?;
+    let Ok(length) = usize::try_from(length) else {
+        return IonResult::decoding_error(format!("invalid Avro length: {length}"));
+    };
+    let mut bytes = Vec::new();
+    input.take(length as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != length {
+        return IonResult::decoding_error("unexpected end of Avro data");
+    }
+    Ok(bytes)
+}
+
+/// Reads the blocks of an array or map, calling `read_item` for each item.
+fn read_blocks<R: Read>(
+    input: &mut R,
+    mut read_item: impl FnMut(&mut R) -> IonResult<()>,
+) -> IonResult<()> {
+    loop {
+        let count = read_long(input)?;
+        if count == 0 {
+            return Ok(());
+        }
+        if count < 0 {
+            // A negative count is followed by the size of the block in bytes, which we don't need.
+            read_long(input)?;
+        }
+        for _ in 0..count.unsigned_abs() {
+            read_item(input)?;
+        }
+    }
+}
+
+fn write_long(output: &mut impl Write, value: i64) -> IonResult<()> {
+    let mut value = ((value << 1) ^ (value >> 63)) as u64;
+    let mut buffer = [0u8; 10];
+    let mut length = 0;
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            buffer[length] = byte;
+            length += 1;
+            break;
+        }
+        buffer[length] = byte | 0x80;
+        length += 1;
+    }
+    output.write_all(&buffer[..length])?;
+    Ok(())
+}
+
+fn write_bytes(output: &mut impl Write, bytes: &[u8]) -> IonResult<()> {
+    write_long(output, bytes.len() as i64)?;
+    output.write_all(bytes)?;
+    Ok(())
+}
+
+fn type_name(schema: &Schema) -> &'static str {
+    match schema {
+        Schema::Null => "null",
+        Schema::Boolean => "boolean",
+        Schema::Int(None) => "int",
+        Schema::Int(Some(IntLogical::Date)) => "date",
+        Schema::Int(Some(IntLogical::Time)) => "time-millis",
+        Schema::Long(None) => "long",
+        Schema::Long(Some(LongLogical::Time)) => "time-micros",
+        Schema::Long(Some(LongLogical::TimestampMillis)) => "timestamp-millis",
+        Schema::Long(Some(LongLogical::TimestampMicros)) => "timestamp-micros",
+        Schema::Long(Some(LongLogical::LocalTimestampMillis)) => "local-timestamp-millis",
+        Schema::Long(Some(LongLogical::LocalTimestampMicros)) => "local-timestamp-micros",
+        Schema::Float => "float",
+        Schema::Double => "double",
+        Schema::Bytes(None) => "bytes",
+        Schema::Bytes(Some(BytesLogical::Decimal(..))) => "decimal",
+        Schema::String(None) => "string",
+        Schema::String(Some(StringLogical::Uuid)) => "uuid",
+        Schema::Record(_) => "record",
+        Schema::Enum(_) => "enum",
+        Schema::Array(_) => "array",
+        Schema::Map(_) => "map",
+        Schema::Union(_) => "union",
+        Schema::Fixed(fixed) => match fixed.logical {
+            None => "fixed",
+            Some(FixedLogical::Decimal(..)) => "decimal",
+            Some(FixedLogical::Duration) => "duration",
+        },
+    }
+}
+
+fn mismatch<T>(value: &Element, schema: &Schema) -> IonResult<T> {
+    let ion_type = value.ion_type();
+    let null = if value.is_null() { "null " } else { "" };
+    IonResult::encoding_error(format!(
+        "a(n) {null}{ion_type} cannot be written as an Avro {}",
+        type_name(schema)
+    ))
+}
+
+fn out_of_range<T>(value: &Element, schema: &Schema) -> IonResult<T> {
+    IonResult::encoding_error(format!(
+        "{value} cannot be written as an Avro {} without losing information",
+        type_name(schema)
+    ))
+}
+
+fn mismatch_or_out_of_range<T>(value: &Element, schema: &Schema) -> IonResult<T> {
+    match value.ion_type() {
+        IonType::Int | IonType::Decimal => out_of_range(value, schema),
+        _ => mismatch(value, schema),
+    }
+}
+
+fn avro_error(error: AvroError) -> IonError {
+    match error {
+        AvroError::OutOfSpec => IonError::decoding_error("invalid Avro object container file"),
+        AvroError::RequiresCompression => {
+            IonError::decoding_error("compressed Avro object container files are not supported")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{v1_0, AnyEncoding};
+    use rstest::rstest;
+
+    const SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "Event",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "kind", "type": {"type": "enum", "name": "Kind", "symbols": ["CREATED", "DELETED"]}},
+            {"name": "note", "type": ["null", "string"]},
+            {"name": "ok", "type": "boolean"},
+            {"name": "scores", "type": {"type": "array", "items": "double"}},
+            {"name": "counts", "type": {"type": "map", "values": "int"}},
+            {"name": "amount", "type": {"type": "bytes", "logicalType": "decimal", "precision": 12, "scale": 3}},
+            {"name": "rate", "type": {"type": "fixed", "name": "Rate", "size": 4, "logicalType": "decimal", "precision": 6, "scale": 4}},
+            {"name": "day", "type": {"type": "int", "logicalType": "date"}},
+            {"name": "at", "type": {"type": "long", "logicalType": "timestamp-micros"}},
+            {"name": "at_local", "type": {"type": "long", "logicalType": "local-timestamp-millis"}},
+            {"name": "uuid", "type": {"type": "string", "logicalType": "uuid"}},
+            {"name": "hash", "type": {"type": "fixed", "name": "Hash", "size": 2}},
+            {"name": "ratio", "type": "float"}
+        ]
+    }"#;
+
+    const EVENT: &str = r#"{
+        id: -7,
+        kind: DELETED,
+        note: "hello",
+        ok: true,
+        scores: [1.5e0, -2e0],
+        counts: {a: 1, b: -300},
+        amount: -1234.500,
+        rate: 0.0725,
+        day: 1969-12-30,
+        at: 2024-02-29T23:59:59.000001Z,
+        at_local: 2024-02-29T08:00:00.250-00:00,
+        uuid: {{EjRWeJq8TvCBI0VniavN7w==}},
+        hash: {{q80=}},
+        ratio: 0.25e0,
+    }"#;
+
+    fn record_schema() -> Record {
+        match parse_schema(SCHEMA).unwrap() {
+            Schema::Record(record) => record,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn datum_round_trip() -> IonResult<()> {
+        let schema = parse_schema(SCHEMA)?;
+        let event = Element::read_one(EVENT)?;
+        let mut datum = Vec::new();
+        write_datum(&mut datum, &schema, &event)?;
+        let mut input = datum.as_slice();
+        assert_eq!(read_datum(&mut input, &schema)?, event);
+        assert!(input.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn write_accepts_alternate_representations() -> IonResult<()> {
+        let schema = parse_schema(SCHEMA)?;
+        let event = Element::read_one(
+            r#"{
+                id: 0, kind: "CREATED", note: symbol, ok: false, scores: (1), counts: {}, amount: 7,
+                rate: 1, day: 2000-01-01T, at: 1970-01-01T00:00Z, at_local: 1970-01-01T00:00+01:00,
+                uuid: "12345678-9abc-4ef0-8123-456789abcdef", hash: {{"hi"}}, ratio: 3
+            }"#,
+        )?;
+        let mut datum = Vec::new();
+        write_datum(&mut datum, &schema, &event)?;
+        let expected = Element::read_one(
+            r#"{
+                id: 0, kind: CREATED, note: "symbol", ok: false, scores: [1e0], counts: {},
+                amount: 7.000, rate: 1.0000, day: 2000-01-01, at: 1970-01-01T00:00:00.000000Z,
+                at_local: 1970-01-01T00:00:00.000-00:00, uuid: {{EjRWeJq8TvCBI0VniavN7w==}},
+                hash: {{aGk=}}, ratio: 3e0
+            }"#,
+        )?;
+        assert_eq!(read_datum(&mut datum.as_slice(), &schema)?, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::zero(0, &[0x00])]
+    #[case::minus_one(-1, &[0x01])]
+    #[case::one(1, &[0x02])]
+    #[case::two_bytes(64, &[0x80, 0x01])]
+    #[case::min(i64::MIN, &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01])]
+    fn zig_zag_longs(#[case] value: i64, #[case] expected: &[u8]) -> IonResult<()> {
+        let mut output = Vec::new();
+        write_long(&mut output, value)?;
+        assert_eq!(output, expected);
+        assert_eq!(read_long(&mut output.as_slice())?, value);
+        Ok(())
+    }
+
+    #[test]
+    fn container_file_round_trip() -> IonResult<()> {
+        let schema = record_schema();
+        let events: Vec<Element> = (0..(RECORDS_PER_BLOCK as i64 + 3))
+            .map(|id| {
+                let event = Element::read_one(EVENT).unwrap();
+                let event = event.as_struct().unwrap().clone_builder();
+                event.remove_field("id").with_field("id", id).build().into()
+            })
+            .collect();
+        let mut ion = Vec::new();
+        for event in &events {
+            ion.extend(event.to_string().into_bytes());
+            ion.push(b'\n');
+        }
+        let mut avro = Vec::new();
+        let mut reader = Reader::new(AnyEncoding, ion)?;
+        assert_eq!(ion_to_avro(&mut reader, &schema, &mut avro)?, events.len());
+        let ion = avro_to_ion(avro.as_slice(), v1_0::Binary, Vec::new())?;
+        assert_eq!(Element::read_all(ion)?, events.into());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::unknown_field("{id: 1, extra: 2}")]
+    #[case::int_out_of_range("{id: 9223372036854775808}")]
+    #[case::bad_enum_symbol("{kind: UPDATED}")]
+    #[case::union_without_matching_branch("{note: 5}")]
+    #[case::too_many_fractional_digits("{amount: 0.0001}")]
+    #[case::too_many_digits("{amount: 1234567890.0}")]
+    #[case::too_precise_for_date("{day: 2024-01-01T12:00Z}")]
+    #[case::too_precise_for_timestamp("{at: 2024-01-01T00:00:00.0000001Z}")]
+    #[case::invalid_uuid("{uuid: \"not-a-uuid\"}")]
+    #[case::wrong_fixed_size("{hash: {{AAAA}}}")]
+    fn write_errors(#[case] fields: &str) -> IonResult<()> {
+        // Replace some fields of an otherwise valid record with invalid values.
+        let event = Element::read_one(EVENT)?;
+        let replacements = Element::read_one(fields)?;
+        let mut builder = event.as_struct().unwrap().clone_builder();
+        for (name, value) in replacements.as_struct().unwrap() {
+            builder = builder
+                .remove_field(name.text().unwrap())
+                .with_field(name.clone(), value.clone());
+        }
+        let mut datum = Vec::new();
+        let result = write_datum(&mut datum, &parse_schema(SCHEMA)?, &builder.build().into());
+        assert!(result.is_err(), "wrote invalid record {fields}");
+        Ok(())
+    }
+
+    #[test]
+    fn missing_fields_are_written_as_null() -> IonResult<()> {
+        let schema = parse_schema(SCHEMA)?;
+        let event = Element::read_one(EVENT)?;
+        let event = event.as_struct().unwrap();
+        let mut datum = Vec::new();
+        let without_note = event.clone_builder().remove_field("note").build();
+        write_datum(&mut datum, &schema, &without_note.into())?;
+        let read_back = read_datum(&mut datum.as_slice(), &schema)?;
+        assert!(read_back
+            .as_struct()
+            .unwrap()
+            .get("note")
+            .unwrap()
+            .is_null());
+        // `id` is not nullable.
+        let without_id = event.clone_builder().remove_field("id").build();
+        assert!(write_datum(&mut Vec::new(), &schema, &without_id.into()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn read_errors() -> IonResult<()> {
+        let schema = parse_schema(r#"["null", "boolean"]"#)?;
+        for datum in [&[0x04][..], &[0x02, 0x02], &[0x02], &[]] {
+            assert!(read_datum(&mut &datum[..], &schema).is_err());
+        }
+        assert!(avro_to_ion(&b"not avro"[..], v1_0::Text, Vec::new()).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,426 @@
+//! Converts a stream of flat Ion structs into comma- or tab-separated values.
+
+use std::io::Write;
+
+use crate::convert::json::{clob_text, symbol_text, write_decimal, write_float, write_json};
+use crate::ion_path::IonPath;
+use crate::lazy::decoder::Decoder;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::result::IonFailure;
+use crate::{IonResult, IonType, Reader};
+
+/// How to handle a record that does not have a value for one of the columns.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum MissingFieldPolicy {
+    /// Leave the cell empty. This is the default.
+    #[default]
+    Empty,
+    /// Stop the conversion and return an `Err`.
+    Error,
+}
+
+/// How to handle a null value (of any type) in one of the columns.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum NullPolicy {
+    /// Leave the cell empty. This is the default.
+    #[default]
+    Empty,
+    /// Write the text `null` in the cell.
+    Keyword,
+    /// Stop the conversion and return an `Err`.
+    Error,
+}
+
+/// How to handle a list, s-expression, or struct in one of the columns.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum NestedValuePolicy {
+    /// Stop the conversion and return an `Err`. This is the default.
+    #[default]
+    Error,
+    /// Write the value's JSON representation in the cell.
+    Json,
+    /// Leave the cell empty.
+    Skip,
+}
+
+/// Writes a stream of Ion structs as rows of delimited text, one row per struct.
+///
+/// Each column is identified by a path (see [`IonPath`]) that is looked up in every struct; if a
+/// struct has more than one field with a given name, the first is used. If no columns are
+/// specified, the columns will be the field names of the first struct in the stream, in the order
+/// in which they appear.
+///
+/// Scalar values are written as text: ints, floats, and decimals as numbers, timestamps in their
+/// Ion text format, strings and symbols as their text, blobs in base64, and clobs with each byte
+/// represented by the code point with the same value. Annotations are ignored. Cells are quoted
+/// when they contain the delimiter, a double quote, or a line break, and rows end with `\n`.
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::convert::csv::{CsvConverter, NestedValuePolicy};
+/// use ion_rs::{AnyEncoding, Reader};
+///
+/// let data = r#"
+///     {name: "Alice", age: 32, pets: [cat]}
+///     {name: "Bob, Jr.", pets: []}
+/// "#;
+/// let mut reader = Reader::new(AnyEncoding, data)?;
+/// let mut output = Vec::new();
+/// CsvConverter::new()
+///     .with_nested_values(NestedValuePolicy::Json)
+///     .convert(&mut reader, &mut output)?;
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "name,age,pets\nAlice,32,\"[\"\"cat\"\"]\"\n\"Bob, Jr.\",,[]\n"
+/// );
+///# Ok(())
+///# }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CsvConverter {
+    delimiter: u8,
+    columns: Option<Vec<String>>,
+    write_header: bool,
+    missing_fields: MissingFieldPolicy,
+    nulls: NullPolicy,
+    nested_values: NestedValuePolicy,
+}
+
+impl Default for CsvConverter {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            columns: None,
+            write_header: true,
+            missing_fields: MissingFieldPolicy::default(),
+            nulls: NullPolicy::default(),
+            nested_values: NestedValuePolicy::default(),
+        }
+    }
+}
+
+/// A column of the output and the path of the value it holds.
+struct Column {
+    header: String,
+    path: IonPath,
+}
+
+impl CsvConverter {
+    /// Constructs a converter that writes comma-separated values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Constructs a converter that writes tab-separated values.
+    pub fn tsv() -> Self {
+        Self::new().with_delimiter(b'\t')
+    }
+
+    /// Sets the byte used to separate cells within a row.
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the columns to write. Each column is a path expression like `address.city` (see
+    /// [`IonPath::parse`]) which is also used as the column's header. If the expression is not
+    /// valid, [`CsvConverter::convert`] will return an `Err`.
+    pub fn with_columns<S: Into<String>>(mut self, columns: impl IntoIterator<Item = S>) -> Self {
+        self.columns = Some(columns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets whether the first row of the output should contain the column headers. Defaults
+    /// to `true`.
+    pub fn with_header(mut self, write_header: bool) -> Self {
+        self.write_header = write_header;
+        self
+    }
+
+    /// Sets how to handle a struct that has no value for one of the columns. Defaults to
+    /// [`MissingFieldPolicy::Empty`], which leaves the cell empty.
+    pub fn with_missing_fields(mut self, policy: MissingFieldPolicy) -> Self {
+        self.missing_fields = policy;
+        self
+    }
+
+    /// Sets how to handle a null value in one of the columns. Defaults to [`NullPolicy::Empty`],
+    /// which leaves the cell empty.
+    pub fn with_nulls(mut self, policy: NullPolicy) -> Self {
+        self.nulls = policy;
+        self
+    }
+
+    /// Sets how to handle a list, s-expression, or struct in one of the columns. Defaults to
+    /// [`NestedValuePolicy::Error`], which stops the conversion and returns an `Err`.
+    pub fn with_nested_values(mut self, policy: NestedValuePolicy) -> Self {
+        self.nested_values = policy;
+        self
+    }
+
+    /// Reads each of the remaining values in `reader` and writes it to `output` as a row. Returns
+    /// the number of rows written, not including the header.
+    ///
+    /// Returns an `Err` if any value in the stream is not a struct, if a value cannot be written
+    /// under the configured policies, or if reading or writing fails.
+    pub fn convert<D: Decoder, I: IonInput>(
+        &self,
+        reader: &mut Reader<D, I>,
+        mut output: impl Write,
+    ) -> IonResult<usize> {
+        let mut columns = match &self.columns {
+            Some(columns) => Some(
+                columns
+                    .iter()
+                    .map(|text| {
+                        Ok(Column {
+                            header: text.clone(),
+                            path: IonPath::parse(text)?,
+                        })
+                    })
+                    .collect::<IonResult<Vec<_>>>()?,
+            ),
+            None => None,
+        };
+        if let (true, Some(columns)) = (self.write_header, &columns) {
+            self.write_header_row(&mut output, columns)?;
+        }
+
+        let mut cell = Vec::new();
+        let mut num_rows = 0;
+        while let Some(record) = reader.next()? {
+            if record.ion_type() != IonType::Struct || record.is_null() {
+                return IonResult::encoding_error(format!(
+                    "expected each record to be a struct, but found a(n) {}",
+                    record.ion_type()
+                ));
+            }
+            let columns = match &mut columns {
+                Some(columns) => columns,
+                no_columns @ None => {
+                    let inferred = Self::infer_columns(record)?;
+                    if self.write_header {
+                        self.write_header_row(&mut output, &inferred)?;
+                    }
+                    no_columns.insert(inferred)
+                }
+            };
+            for (index, column) in columns.iter().enumerate() {
+                if index > 0 {
+                    output.write_all(&[self.delimiter])?;
+                }
+                cell.clear();
+                match record.get_path(&column.path)? {
+                    Some(value) => self.write_cell(&mut cell, value)?,
+                    None if self.missing_fields == MissingFieldPolicy::Error => {
+                        return IonResult::encoding_error(format!(
+                            "record {num_rows} has no value for column '{}'",
+                            column.header
+                        ));
+                    }
+                    None => {}
+                }
+                self.write_escaped(&mut output, &cell)?;
+            }
+            output.write_all(b"\n")?;
+            num_rows += 1;
+        }
+        output.flush()?;
+        Ok(num_rows)
+    }
+
+    fn infer_columns<D: Decoder>(record: LazyValue<'_, D>) -> IonResult<Vec<Column>> {
+        let mut columns: Vec<Column> = Vec::new();
+        for field in record.read()?.expect_struct()? {
+            let name = symbol_text(&field?.name()?)?;
+            if !columns.iter().any(|column| column.header == name) {
+                columns.push(Column {
+                    header: name.to_owned(),
+                    path: IonPath::new().field(name),
+                });
+            }
+        }
+        Ok(columns)
+    }
+
+    fn write_header_row(&self, output: &mut impl Write, columns: &[Column]) -> IonResult<()> {
+        for (index, column) in columns.iter().enumerate() {
+            if index > 0 {
+                output.write_all(&[self.delimiter])?;
+            }
+            self.write_escaped(output, column.header.as_bytes())?;
+        }
+        output.write_all(b"\n")?;
+        Ok(())
+    }
+
+    /// Writes the unescaped text of `value` to `cell`.
+    fn write_cell<D: Decoder>(&self, cell: &mut Vec<u8>, value: LazyValue<'_, D>) -> IonResult<()> {
+        match value.read()? {
+            ValueRef::Null(ion_type) => match self.nulls {
+                NullPolicy::Empty => {}
+                NullPolicy::Keyword => cell.extend_from_slice(b"null"),
+                NullPolicy::Error => {
+                    return IonResult::encoding_error(format!(
+                        "found a null.{ion_type} and nulls are not permitted"
+                    ))
+                }
+            },
+            ValueRef::Bool(b) => write!(cell, "{b}")?,
+            ValueRef::Int(i) => write!(cell, "{i}")?,
+            ValueRef::Float(f) if f.is_nan() => cell.extend_from_slice(b"nan"),
+            ValueRef::Float(f) if f.is_infinite() => match f.is_sign_positive() {
+                true => cell.extend_from_slice(b"+inf"),
+                false => cell.extend_from_slice(b"-inf"),
+            },
+            ValueRef::Float(f) => write_float(cell, f)?,
+            ValueRef::Decimal(d) => write_decimal(cell, &d)?,
+            ValueRef::Timestamp(t) => write!(cell, "{t}")?,
+            ValueRef::String(s) => cell.extend_from_slice(s.text().as_bytes()),
+            ValueRef::Symbol(s) => cell.extend_from_slice(symbol_text(&s)?.as_bytes()),
+            ValueRef::Blob(b) => {
+                cell.extend_from_slice(crate::text::base64::encode(b.data()).as_bytes())
+            }
+            ValueRef::Clob(c) => cell.extend_from_slice(clob_text(c.data()).as_bytes()),
+            ValueRef::List(_) | ValueRef::SExp(_) | ValueRef::Struct(_) => {
+                match self.nested_values {
+                    NestedValuePolicy::Json => write_json(cell, value)?,
+                    NestedValuePolicy::Skip => {}
+                    NestedValuePolicy::Error => {
+                        return IonResult::encoding_error(format!(
+                            "found a nested {} and nested values are not permitted",
+                            value.ion_type()
+                        ))
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes `cell` to `output`, surrounding it with quotes if it contains the delimiter, a
+    /// quote, or a line break.
+    fn write_escaped(&self, output: &mut impl Write, cell: &[u8]) -> IonResult<()> {
+        let needs_quotes = cell
+            .iter()
+            .any(|&byte| matches!(byte, b'"' | b'\n' | b'\r') || byte == self.delimiter);
+        if !needs_quotes {
+            output.write_all(cell)?;
+            return Ok(());
+        }
+        output.write_all(b"\"")?;
+        for segment in cell.split_inclusive(|&byte| byte == b'"') {
+            output.write_all(segment)?;
+            if segment.ends_with(b"\"") {
+                output.write_all(b"\"")?;
+            }
+        }
+        output.write_all(b"\"")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::encoder::writer::Writer;
+    use crate::lazy::encoding::BinaryEncoding_1_0;
+    use crate::write_config::WriteConfig;
+    use crate::{v1_0, Element, ElementWriter};
+
+    const DATA: &str = r#"
+        {id: 1, name: "Alice", address: {city: Seattle}, scores: [1, 2]}
+        {id: 2, name: "O'Brien, \"Pat\"", address: {city: null}, score: 1.5}
+        {name: "Carol\nLine", id: 3}
+    "#;
+
+    fn convert(converter: &CsvConverter, data: &str) -> IonResult<String> {
+        let mut reader = Reader::new(v1_0::Text, data)?;
+        let mut output = Vec::new();
+        converter.convert(&mut reader, &mut output)?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn infers_columns_from_first_record() -> IonResult<()> {
+        let converter = CsvConverter::new().with_nested_values(NestedValuePolicy::Json);
+        let expected = concat!(
+            "id,name,address,scores\n",
+            "1,Alice,\"{\"\"city\"\":\"\"Seattle\"\"}\",\"[1,2]\"\n",
+            "2,\"O'Brien, \"\"Pat\"\"\",\"{\"\"city\"\":null}\",\n",
+            "3,\"Carol\nLine\",,\n",
+        );
+        assert_eq!(convert(&converter, DATA)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn selected_columns() -> IonResult<()> {
+        let converter = CsvConverter::tsv()
+            .with_columns(["name", "address.city", "score"])
+            .with_nulls(NullPolicy::Keyword);
+        // In TSV output, commas do not require quotes, but double quotes and line breaks do.
+        let expected = concat!(
+            "name\taddress.city\tscore\n",
+            "Alice\tSeattle\t\n",
+            "\"O'Brien, \"\"Pat\"\"\"\tnull\t1.5\n",
+            "\"Carol\nLine\"\t\t\n",
+        );
+        assert_eq!(convert(&converter, DATA)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_input_without_header() -> IonResult<()> {
+        let mut writer = Writer::new(WriteConfig::<BinaryEncoding_1_0>::new(), Vec::new())?;
+        writer.write_elements(&Element::read_all(DATA)?)?;
+        let binary_data = writer.close()?;
+        let mut reader = Reader::new(v1_0::Binary, binary_data.as_slice())?;
+        let mut output = Vec::new();
+        let num_rows = CsvConverter::new()
+            .with_columns(["id", "score"])
+            .with_header(false)
+            .convert(&mut reader, &mut output)?;
+        assert_eq!(num_rows, 3);
+        assert_eq!(String::from_utf8(output).unwrap(), "1,\n2,1.5\n3,\n");
+        Ok(())
+    }
+
+    #[test]
+    fn nested_value_policies() -> IonResult<()> {
+        let skip = CsvConverter::new()
+            .with_columns(["id", "scores"])
+            .with_nested_values(NestedValuePolicy::Skip);
+        assert_eq!(convert(&skip, DATA)?, "id,scores\n1,\n2,\n3,\n");
+        let error = CsvConverter::new().with_columns(["id", "scores"]);
+        assert!(convert(&error, DATA).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        let missing = CsvConverter::new()
+            .with_columns(["score"])
+            .with_missing_fields(MissingFieldPolicy::Error);
+        assert!(convert(&missing, DATA).is_err());
+        let nulls = CsvConverter::new()
+            .with_columns(["address.city"])
+            .with_nulls(NullPolicy::Error);
+        assert!(convert(&nulls, DATA).is_err());
+        let invalid_path = CsvConverter::new().with_columns(["a..b"]);
+        assert!(convert(&invalid_path, DATA).is_err());
+        assert!(convert(&CsvConverter::new(), "{a: 1} 2").is_err());
+    }
+
+    #[test]
+    fn scalar_formats() -> IonResult<()> {
+        let data = r#"{a: true, b: 5d-1, c: 2024-01-15T00:00Z, d: {{aGk=}}, e: -inf, f: 1e300}"#;
+        assert_eq!(
+            convert(&CsvConverter::new().with_header(false), data)?,
+            "true,0.5,2024-01-15T00:00+00:00,aGk=,-inf,1e300\n"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,400 @@
+//! Down-converts Ion values to JSON text. See the [parent module](crate::convert) for a
+//! description of how each Ion type is represented.
+
+use std::io::Write;
+
+use crate::lazy::decoder::Decoder;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::result::IonFailure;
+use crate::{Decimal, Element, IonResult, SymbolRef, Timestamp, TimestampPrecision, Value};
+
+/// How a [`JsonWriter`] handles values that cannot be represented in JSON without discarding
+/// information.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum JsonMode {
+    /// Annotations are discarded and `nan`, `+inf`, and `-inf` are written as `null`.
+    #[default]
+    Lossy,
+    /// Writing a value that has annotations or that is (or contains) `nan`, `+inf`, or `-inf`
+    /// returns an `Err`.
+    Strict,
+}
+
+/// Returns the JSON representation of `element`, down-converting it as described in
+/// [the module documentation](crate::convert). To report values that would lose information
+/// instead, use a [`JsonWriter`] in [`JsonMode::Strict`].
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// // The `convert` module is also available as `conversion`.
+/// use ion_rs::conversion::json::to_json_string;
+/// use ion_rs::Element;
+///
+/// let element = Element::read_one("{id: 7, seen: 2024-01-15T, data: {{aGk=}}}")?;
+/// assert_eq!(
+///     to_json_string(&element)?,
+///     r#"{"id":7,"seen":"2024-01-15","data":"aGk="}"#
+/// );
+///# Ok(())
+///# }
+/// ```
+pub fn to_json_string(element: &Element) -> IonResult<String> {
+    let mut output = Vec::new();
+    write_element(&mut output, element, JsonMode::Lossy)?;
+    // The writer only emits valid UTF-8.
+    Ok(String::from_utf8(output).expect("JSON output was not valid UTF-8"))
+}
+
+/// Writes each Ion value it is given to an [`io::Write`](Write) as a line of JSON text.
+///
+/// Values can be written from an [`Element`] or directly from a [`LazyValue`]; the latter is
+/// converted as it is read, without being materialized. Values are down-converted as described
+/// in [the module documentation](crate::convert), subject to the writer's [`JsonMode`].
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::convert::json::{JsonMode, JsonWriter};
+/// use ion_rs::{AnyEncoding, Reader};
+///
+/// let mut reader = Reader::new(AnyEncoding, "{a: [1, 2.50]} sym 2024T")?;
+/// let mut writer = JsonWriter::new(Vec::new());
+/// while let Some(value) = reader.next()? {
+///     writer.write_value(value)?;
+/// }
+/// let output = writer.close()?;
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "{\"a\":[1,2.50]}\n\"sym\"\n\"2024\"\n"
+/// );
+///
+/// let mut strict = JsonWriter::new(Vec::new()).with_mode(JsonMode::Strict);
+/// let mut reader = Reader::new(AnyEncoding, "[1e0, nan]")?;
+/// assert!(strict.write_value(reader.expect_next()?).is_err());
+///# Ok(())
+///# }
+/// ```
+pub struct JsonWriter<W: Write> {
+    output: W,
+    mode: JsonMode,
+}
+
+impl<W: Write> JsonWriter<W> {
+    /// Constructs a writer in [`JsonMode::Lossy`].
+    pub fn new(output: W) -> Self {
+        Self {
+            output,
+            mode: JsonMode::Lossy,
+        }
+    }
+
+    pub fn with_mode(mut self, mode: JsonMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn mode(&self) -> JsonMode {
+        self.mode
+    }
+
+    /// Writes `element` followed by a newline.
+    pub fn write_element(&mut self, element: &Element) -> IonResult<()> {
+        write_element(&mut self.output, element, self.mode)?;
+        self.output.write_all(b"\n")?;
+        Ok(())
+    }
+
+    /// Reads `value` and writes it followed by a newline.
+    pub fn write_value<D: Decoder>(&mut self, value: LazyValue<'_, D>) -> IonResult<()> {
+        write_value(&mut self.output, value, self.mode)?;
+        self.output.write_all(b"\n")?;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> IonResult<()> {
+        self.output.flush()?;
+        Ok(())
+    }
+
+    pub fn output(&self) -> &W {
+        &self.output
+    }
+
+    /// Flushes the writer and returns its output.
+    pub fn close(mut self) -> IonResult<W> {
+        self.flush()?;
+        Ok(self.output)
+    }
+}
+
+/// Writes the JSON representation of `value` to `output`.
+pub(crate) fn write_json<D: Decoder>(
+    output: &mut impl Write,
+    value: LazyValue<'_, D>,
+) -> IonResult<()> {
+    write_value(output, value, JsonMode::Lossy)
+}
+
+fn write_value<D: Decoder>(
+    output: &mut impl Write,
+    value: LazyValue<'_, D>,
+    mode: JsonMode,
+) -> IonResult<()> {
+    if mode == JsonMode::Strict && value.has_annotations() {
+        return annotations_error();
+    }
+    match value.read()? {
+        ValueRef::Null(_) => output.write_all(b"null")?,
+        ValueRef::Bool(b) => write!(output, "{b}")?,
+        ValueRef::Int(i) => write!(output, "{i}")?,
+        ValueRef::Float(f) => write_float_or_null(output, f, mode)?,
+        ValueRef::Decimal(d) => write_decimal(output, &d)?,
+        ValueRef::Timestamp(t) => write_json_string(output, &iso_8601(&t))?,
+        ValueRef::String(s) => write_json_string(output, s.text())?,
+        ValueRef::Symbol(s) => write_json_string(output, symbol_text(&s)?)?,
+        ValueRef::Blob(b) => write_json_string(output, &crate::text::base64::encode(b.data()))?,
+        ValueRef::Clob(c) => write_json_string(output, &clob_text(c.data()))?,
+        ValueRef::List(list) => write_json_array(output, list.iter(), mode)?,
+        ValueRef::SExp(sexp) => write_json_array(output, sexp.iter(), mode)?,
+        ValueRef::Struct(strukt) => {
+            output.write_all(b"{")?;
+            for (index, field) in strukt.iter().enumerate() {
+                let field = field?;
+                if index > 0 {
+                    output.write_all(b",")?;
+                }
+                write_json_string(output, symbol_text(&field.name()?)?)?;
+                output.write_all(b":")?;
+                write_value(output, field.value(), mode)?;
+            }
+            output.write_all(b"}")?;
+        }
+    }
+    Ok(())
+}
+
+fn write_element(output: &mut impl Write, element: &Element, mode: JsonMode) -> IonResult<()> {
+    if mode == JsonMode::Strict && !element.annotations().is_empty() {
+        return annotations_error();
+    }
+    match element.value() {
+        Value::Null(_) => output.write_all(b"null")?,
+        Value::Bool(b) => write!(output, "{b}")?,
+        Value::Int(i) => write!(output, "{i}")?,
+        Value::Float(f) => write_float_or_null(output, *f, mode)?,
+        Value::Decimal(d) => write_decimal(output, d)?,
+        Value::Timestamp(t) => write_json_string(output, &iso_8601(t))?,
+        Value::String(s) => write_json_string(output, s.text())?,
+        Value::Symbol(s) => write_json_string(output, symbol_text(&s.into())?)?,
+        Value::Blob(b) => write_json_string(output, &crate::text::base64::encode(b.as_ref()))?,
+        Value::Clob(c) => write_json_string(output, &clob_text(c.as_ref()))?,
+        Value::List(sequence) | Value::SExp(sequence) => {
+            output.write_all(b"[")?;
+            for (index, child) in sequence.iter().enumerate() {
+                if index > 0 {
+                    output.write_all(b",")?;
+                }
+                write_element(output, child, mode)?;
+            }
+            output.write_all(b"]")?;
+        }
+        Value::Struct(strukt) => {
+            output.write_all(b"{")?;
+            for (index, (name, value)) in strukt.fields().enumerate() {
+                if index > 0 {
+                    output.write_all(b",")?;
+                }
+                write_json_string(output, symbol_text(&name.into())?)?;
+                output.write_all(b":")?;
+                write_element(output, value, mode)?;
+            }
+            output.write_all(b"}")?;
+        }
+    }
+    Ok(())
+}
+
+fn annotations_error() -> IonResult<()> {
+    IonResult::encoding_error("annotations cannot be represented in JSON")
+}
+
+fn write_float_or_null(output: &mut impl Write, value: f64, mode: JsonMode) -> IonResult<()> {
+    match (value.is_finite(), mode) {
+        (true, _) => write_float(output, value),
+        (false, JsonMode::Lossy) => Ok(output.write_all(b"null")?),
+        (false, JsonMode::Strict) => IonResult::encoding_error(format!(
+            "the float value {value} cannot be represented in JSON"
+        )),
+    }
+}
+
+fn write_json_array<'top, D: Decoder + 'top>(
+    output: &mut impl Write,
+    values: impl Iterator<Item = IonResult<LazyValue<'top, D>>>,
+    mode: JsonMode,
+) -> IonResult<()> {
+    output.write_all(b"[")?;
+    for (index, value) in values.enumerate() {
+        if index > 0 {
+            output.write_all(b",")?;
+        }
+        write_value(output, value?, mode)?;
+    }
+    output.write_all(b"]")?;
+    Ok(())
+}
+
+/// Writes `text` as a quoted JSON string, escaping any characters that require it.
+pub(crate) fn write_json_string(output: &mut impl Write, text: &str) -> IonResult<()> {
+    output.write_all(b"\"")?;
+    let bytes = text.as_bytes();
+    let mut unescaped_start = 0;
+    for (index, &byte) in bytes.iter().enumerate() {
+        let escape: &[u8] = match byte {
+            b'"' => b"\\\"",
+            b'\\' => b"\\\\",
+            b'\n' => b"\\n",
+            b'\r' => b"\\r",
+            b'\t' => b"\\t",
+            0x00..=0x1F => b"",
+            _ => continue,
+        };
+        output.write_all(&bytes[unescaped_start..index])?;
+        if escape.is_empty() {
+            write!(output, "\\u{byte:04x}")?;
+        } else {
+            output.write_all(escape)?;
+        }
+        unescaped_start = index + 1;
+    }
+    output.write_all(&bytes[unescaped_start..])?;
+    output.write_all(b"\"")?;
+    Ok(())
+}
+
+/// Writes a finite float as a JSON number, using positional notation for values of moderate
+/// magnitude and scientific notation otherwise.
+pub(crate) fn write_float(output: &mut impl Write, value: f64) -> IonResult<()> {
+    let magnitude = value.abs();
+    if magnitude == 0.0 || (1e-5..1e16).contains(&magnitude) {
+        write!(output, "{value}")?;
+    } else {
+        write!(output, "{value:e}")?;
+    }
+    Ok(())
+}
+
+/// Writes a decimal as a JSON number. Precision is retained, but negative zero is not
+/// distinguished from zero by most JSON readers.
+pub(crate) fn write_decimal(output: &mut impl Write, value: &Decimal) -> IonResult<()> {
+    // Ion's text representation of a decimal differs from a JSON number only in its use of `d` to
+    // introduce the exponent and in its trailing `.` on values with an exponent of zero.
+    let text = value.to_string();
+    let text = text.strip_suffix('.').unwrap_or(&text);
+    output.write_all(text.replace('d', "e").as_bytes())?;
+    Ok(())
+}
+
+pub(crate) fn symbol_text<'a>(symbol: &SymbolRef<'a>) -> IonResult<&'a str> {
+    match symbol.text() {
+        Some(text) => Ok(text),
+        None => IonResult::encoding_error("symbols with unknown text cannot be converted to text"),
+    }
+}
+
+/// Returns the ISO 8601 representation of `timestamp`. This is its Ion text representation
+/// without the `T` that Ion requires at the end of timestamps with year, month, or day precision.
+pub(crate) fn iso_8601(timestamp: &Timestamp) -> String {
+    let text = timestamp.to_string();
+    match timestamp.precision() {
+        TimestampPrecision::Year | TimestampPrecision::Month | TimestampPrecision::Day => {
+            text.trim_end_matches('T').to_owned()
+        }
+        _ => text,
+    }
+}
+
+/// Interprets each byte of a clob as the code point with the same value.
+pub(crate) fn clob_text(bytes: &[u8]) -> String {
+    bytes.iter().map(|&byte| byte as char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{v1_0, Reader};
+    use rstest::rstest;
+
+    fn to_json(ion: &str) -> IonResult<String> {
+        let mut reader = Reader::new(v1_0::Text, ion)?;
+        let mut output = Vec::new();
+        write_json(&mut output, reader.expect_next()?)?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[rstest]
+    #[case::null("null.int", "null")]
+    #[case::bool("true", "true")]
+    #[case::int("-17", "-17")]
+    #[case::big_int("123456789012345678901234567890", "123456789012345678901234567890")]
+    #[case::float("2.5e0", "2.5")]
+    #[case::huge_float("1e300", "1e300")]
+    #[case::nan("nan", "null")]
+    #[case::infinity("-inf", "null")]
+    #[case::decimal("1.50", "1.50")]
+    #[case::decimal_integer("12.", "12")]
+    #[case::decimal_exponent("5d3", "5e3")]
+    #[case::timestamp("2024-01-15T10:30Z", "\"2024-01-15T10:30+00:00\"")]
+    #[case::month_timestamp("2024-01T", "\"2024-01\"")]
+    #[case::day_timestamp("2024-01-15T", "\"2024-01-15\"")]
+    #[case::string(r#""say \"hi\"\n""#, r#""say \"hi\"\n""#)]
+    #[case::control_character(r#""\x01""#, r#""\u0001""#)]
+    #[case::symbol("'hello world'", "\"hello world\"")]
+    #[case::blob("{{aGVsbG8=}}", "\"aGVsbG8=\"")]
+    #[case::clob(r#"{{"hi\xff"}}"#, "\"hiÿ\"")]
+    #[case::annotated("a::b::1", "1")]
+    #[case::list("[1, [true], {}]", "[1,[true],{}]")]
+    #[case::sexp("(+ 1 2)", "[\"+\",1,2]")]
+    #[case::structure("{a: 1, 'b c': {d: null}}", r#"{"a":1,"b c":{"d":null}}"#)]
+    fn down_convert(#[case] ion: &str, #[case] expected: &str) -> IonResult<()> {
+        assert_eq!(to_json(ion)?, expected);
+        assert_eq!(to_json_string(&Element::read_one(ion)?)?, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::annotated("a::1")]
+    #[case::nested_annotation("{a: [1, b::2]}")]
+    #[case::nan("nan")]
+    #[case::nested_infinity("[1e0, (+inf)]")]
+    fn strict_mode_rejects_lossy_values(#[case] ion: &str) -> IonResult<()> {
+        let mut writer = JsonWriter::new(Vec::new()).with_mode(JsonMode::Strict);
+        assert!(writer.write_element(&Element::read_one(ion)?).is_err());
+        let mut reader = Reader::new(v1_0::Text, ion)?;
+        assert!(writer.write_value(reader.expect_next()?).is_err());
+
+        let mut lossy = JsonWriter::new(Vec::new());
+        lossy.write_element(&Element::read_one(ion)?)?;
+        Ok(())
+    }
+
+    #[test]
+    fn writer_writes_one_value_per_line() -> IonResult<()> {
+        let mut writer = JsonWriter::new(Vec::new()).with_mode(JsonMode::Strict);
+        for element in Element::read_all("{a: 1} [2e0] \"three\"")? {
+            writer.write_element(&element)?;
+        }
+        let mut reader = Reader::new(v1_0::Text, "null.struct")?;
+        writer.write_value(reader.expect_next()?)?;
+        let output = String::from_utf8(writer.close()?).unwrap();
+        assert_eq!(output, "{\"a\":1}\n[2]\n\"three\"\nnull\n");
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_symbol_text_is_an_error() {
+        assert!(to_json("$0").is_err());
+        assert!(to_json("{$0: 1}").is_err());
+        assert!(to_json_string(&Element::read_one("$0").unwrap()).is_err());
+    }
+}
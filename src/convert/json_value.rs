@@ -0,0 +1,372 @@
+//! Conversions between Ion [`Element`]s and the `serde_json` crate's [`serde_json::Value`].
+//!
+//! JSON values become Ion values as follows:
+//! * `null`, booleans, strings, arrays, and objects become Ion nulls, bools, strings, lists, and
+//!   structs. Object fields are visited in the order that the `serde_json` map yields them.
+//! * Numbers are read from their exact text, which this module's `json-interop` feature asks
+//!   `serde_json` to preserve by enabling its `arbitrary_precision` feature. Integers become ints
+//!   and numbers written with a fraction or an exponent become decimals with the same digits, so
+//!   `0.1` becomes `0.1` and `1.50` keeps its trailing zero. Numbers whose digits do not fit in
+//!   an `i128` cannot be stored in an [`Int`](crate::Int) or a [`Decimal`] and become the nearest
+//!   float instead.
+//! * If [`JsonValueConverter::with_timestamp_strings`] is enabled, strings holding an ISO 8601
+//!   date (`2024-01-15`) or an Ion timestamp with at least day precision
+//!   (`2024-01-15T10:30:00Z`) become timestamps.
+//!
+//! Ion values become JSON values using the rules described in [the parent module](crate::convert),
+//! except that ints and decimals become JSON numbers with the same digits, whatever their size.
+//! Decimals with a positive exponent, or with an exponent of zero, are written with an exponent
+//! (`5e3`, `-0e0`) so that reading them back produces a decimal rather than an int. If a struct
+//! has more than one field with the same name, the last one is kept.
+//!
+//! In [`JsonMode::Strict`], which the [`TryFrom`] conversion uses, repeated field names return an
+//! `Err` instead, as do annotations and the float values `nan`, `+inf`, and `-inf`.
+
+use serde_json::{Map, Number, Value as JsonValue};
+
+use crate::convert::json::{clob_text, iso_8601, symbol_text, JsonMode};
+use crate::element::builders::StructBuilder;
+use crate::result::IonFailure;
+use crate::{Decimal, Element, IonError, IonResult, IonType, List, Value};
+
+/// Converts between [`Element`]s and [`serde_json::Value`]s using configurable rules.
+///
+/// The [`From`] conversion from a JSON value does not read timestamps from strings, and the
+/// [`TryFrom`] conversion from an `Element` uses [`JsonMode::Strict`].
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "json-interop")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::convert::json_value::JsonValueConverter;
+/// use ion_rs::{Element, IonType};
+/// use serde_json::json;
+///
+/// let converter = JsonValueConverter::new().with_timestamp_strings(true);
+/// let element = converter.to_element(json!({"id": u64::MAX, "seen": "2024-01-15"}));
+/// assert_eq!(element, Element::read_one("{id: 18446744073709551615, seen: 2024-01-15T}")?);
+///
+/// let json = converter.to_json_value(&Element::read_one("[0.1, 12345678901234567890123]")?)?;
+/// assert_eq!(json.to_string(), "[0.1,12345678901234567890123]");
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "json-interop"))]
+///# fn main() {}
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct JsonValueConverter {
+    mode: JsonMode,
+    timestamp_strings: bool,
+}
+
+impl JsonValueConverter {
+    /// Constructs a converter in [`JsonMode::Lossy`] that reads JSON strings as Ion strings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_mode(mut self, mode: JsonMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets whether JSON strings that hold an ISO 8601 date or an Ion timestamp with at least day
+    /// precision are converted to Ion timestamps. Timestamps with year or month precision are
+    /// not recognized, since strings like `"2024"` are rarely meant as dates.
+    pub fn with_timestamp_strings(mut self, timestamp_strings: bool) -> Self {
+        self.timestamp_strings = timestamp_strings;
+        self
+    }
+
+    pub fn mode(&self) -> JsonMode {
+        self.mode
+    }
+
+    pub fn reads_timestamp_strings(&self) -> bool {
+        self.timestamp_strings
+    }
+
+    /// Converts a JSON value to an [`Element`].
+    pub fn to_element(&self, value: JsonValue) -> Element {
+        match value {
+            JsonValue::Null => Element::null(IonType::Null),
+            JsonValue::Bool(b) => b.into(),
+            JsonValue::Number(n) => number_to_element(&n),
+            JsonValue::String(s) => match self.timestamp_strings {
+                true => parse_timestamp(&s).unwrap_or_else(|| s.into()),
+                false => s.into(),
+            },
+            JsonValue::Array(values) => {
+                let values: Vec<Element> = values.into_iter().map(|v| self.to_element(v)).collect();
+                List::from(values).into()
+            }
+            JsonValue::Object(fields) => fields
+                .into_iter()
+                .fold(StructBuilder::new(), |builder, (name, value)| {
+                    builder.with_field(name, self.to_element(value))
+                })
+                .build()
+                .into(),
+        }
+    }
+
+    /// Converts an [`Element`] to a JSON value, down-converting it as described in
+    /// [the module documentation](self).
+    pub fn to_json_value(&self, element: &Element) -> IonResult<JsonValue> {
+        if self.mode == JsonMode::Strict && !element.annotations().is_empty() {
+            return IonResult::encoding_error("annotations cannot be represented in JSON");
+        }
+        let value = match element.value() {
+            Value::Null(_) => JsonValue::Null,
+            Value::Bool(b) => JsonValue::Bool(*b),
+            Value::Int(i) => JsonValue::Number(exact_number(i.to_string())),
+            Value::Float(f) => match Number::from_f64(*f) {
+                Some(number) => JsonValue::Number(number),
+                None if self.mode == JsonMode::Lossy => JsonValue::Null,
+                None => {
+                    return IonResult::encoding_error(format!(
+                        "the float value {f} cannot be represented in JSON"
+                    ))
+                }
+            },
+            Value::Decimal(d) => JsonValue::Number(decimal_to_number(d)),
+            Value::Timestamp(t) => JsonValue::String(iso_8601(t)),
+            Value::String(s) => JsonValue::String(s.text().to_owned()),
+            Value::Symbol(s) => JsonValue::String(symbol_text(&s.into())?.to_owned()),
+            Value::Blob(b) => JsonValue::String(crate::text::base64::encode(b.as_ref())),
+            Value::Clob(c) => JsonValue::String(clob_text(c.as_ref())),
+            Value::List(sequence) | Value::SExp(sequence) => JsonValue::Array(
+                sequence
+                    .iter()
+                    .map(|child| self.to_json_value(child))
+                    .collect::<IonResult<_>>()?,
+            ),
+            Value::Struct(strukt) => {
+                let mut fields = Map::new();
+                for (name, value) in strukt.fields() {
+                    let name = symbol_text(&name.into())?;
+                    let value = self.to_json_value(value)?;
+                    if fields.insert(name.to_owned(), value).is_some()
+                        && self.mode == JsonMode::Strict
+                    {
+                        return IonResult::encoding_error(format!(
+                            "the struct has more than one field named '{name}'"
+                        ));
+                    }
+                }
+                JsonValue::Object(fields)
+            }
+        };
+        Ok(value)
+    }
+}
+
+/// Converts a JSON value to an [`Element`] as described in [the module documentation](self).
+/// Every JSON value can be represented in Ion, so this conversion cannot fail.
+impl From<JsonValue> for Element {
+    fn from(value: JsonValue) -> Self {
+        JsonValueConverter::new().to_element(value)
+    }
+}
+
+/// Converts an [`Element`] to a JSON value in [`JsonMode::Strict`], returning an `Err` if any
+/// part of it would be changed or discarded.
+impl TryFrom<&Element> for JsonValue {
+    type Error = IonError;
+
+    fn try_from(element: &Element) -> Result<Self, Self::Error> {
+        JsonValueConverter::new()
+            .with_mode(JsonMode::Strict)
+            .to_json_value(element)
+    }
+}
+
+impl TryFrom<Element> for JsonValue {
+    type Error = IonError;
+
+    fn try_from(element: Element) -> Result<Self, Self::Error> {
+        JsonValue::try_from(&element)
+    }
+}
+
+fn number_to_element(number: &Number) -> Element {
+    let text = number.as_str();
+    let exact = if text.contains(['.', 'e', 'E']) {
+        parse_decimal(text).map(Element::from)
+    } else {
+        text.parse::<i128>().ok().map(Element::from)
+    };
+    // Numbers that need more digits than an i128 holds fall back to the nearest float.
+    exact.unwrap_or_else(|| number.as_f64().expect("JSON number is an f64").into())
+}
+
+/// Returns the decimal with the same digits as the JSON number `text`, or `None` if its
+/// coefficient or exponent is too large to store.
+fn parse_decimal(text: &str) -> Option<Decimal> {
+    let (mantissa, exponent) = match text.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>().ok()?),
+        None => (text, 0),
+    };
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let coefficient: i128 = format!("{whole}{fraction}").parse().ok()?;
+    let exponent = exponent.checked_sub(fraction.len() as i64)?;
+    if coefficient == 0 && whole.starts_with('-') {
+        return Some(Decimal::negative_zero_with_exponent(exponent));
+    }
+    Some(Decimal::new(coefficient, exponent))
+}
+
+/// Returns a JSON number with the same digits as `decimal`.
+fn decimal_to_number(decimal: &Decimal) -> Number {
+    // Ion's text representation of a decimal is also a JSON number once its `d` is replaced and
+    // any dot without digits after it (`1.d-400`) is dropped, except for decimals like `5.`
+    // whose exponent is zero. Those get an explicit exponent so that they are not read back as
+    // ints.
+    let text = decimal.to_string().replace(".d", "e").replace('d', "e");
+    match text.strip_suffix('.') {
+        Some(digits) => exact_number(format!("{digits}e0")),
+        None => exact_number(text),
+    }
+}
+
+/// Returns the JSON number spelled by `text`, which must be valid JSON number syntax.
+fn exact_number(text: String) -> Number {
+    text.parse().expect("text is a valid JSON number")
+}
+
+/// Returns the timestamp in `text` if it is an ISO 8601 date or an Ion timestamp with at least
+/// day precision.
+fn parse_timestamp(text: &str) -> Option<Element> {
+    let is_date = |date: &[u8]| {
+        date.iter().enumerate().all(|(index, byte)| match index {
+            4 | 7 => *byte == b'-',
+            _ => byte.is_ascii_digit(),
+        })
+    };
+    if !text.as_bytes().get(..10).is_some_and(is_date) {
+        return None;
+    }
+    let element = if text.len() == 10 {
+        Element::read_one(format!("{text}T"))
+    } else {
+        Element::read_one(text)
+    };
+    element.ok().filter(|e| e.ion_type() == IonType::Timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+
+    #[rstest]
+    #[case::null(json!(null), "null")]
+    #[case::bool(json!(true), "true")]
+    #[case::int(json!(-17), "-17")]
+    #[case::u64(json!(u64::MAX), "18446744073709551615")]
+    #[case::fraction(json!(1.5), "1.5")]
+    #[case::tenth(number("0.1"), "0.1")]
+    #[case::trailing_zero(number("1.50"), "1.50")]
+    #[case::exponent(number("-2.5E+3"), "-2.5d3")]
+    #[case::negative_zero(number("-0.0"), "-0.0")]
+    #[case::beyond_u64(number("18446744073709551616"), "18446744073709551616")]
+    #[case::beyond_i128(number("1e99999"), "1d99999")]
+    #[case::beyond_coefficient(
+        number("1234567890123456789012345678901234567890"),
+        "1.2345678901234568e39"
+    )]
+    #[case::string(json!("2024-01-15"), "\"2024-01-15\"")]
+    #[case::array(json!([1, [true], {}]), "[1, [true], {}]")]
+    #[case::object(json!({"a": 1, "b c": {"d": null}}), "{a: 1, 'b c': {d: null}}")]
+    fn json_to_ion(#[case] json: JsonValue, #[case] expected: &str) -> IonResult<()> {
+        assert_eq!(Element::from(json), Element::read_one(expected)?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::date("2024-01-15", "2024-01-15T")]
+    #[case::ion_date("2024-01-15T", "2024-01-15T")]
+    #[case::minutes("2024-01-15T10:30Z", "2024-01-15T10:30Z")]
+    #[case::fractional_seconds("2024-01-15T10:30:00.123-05:00", "2024-01-15T10:30:00.123-05:00")]
+    #[case::year("2024", "\"2024\"")]
+    #[case::month("2024-01", "\"2024-01\"")]
+    #[case::no_offset("2024-01-15T10:30", "\"2024-01-15T10:30\"")]
+    #[case::invalid_day("2024-02-31", "\"2024-02-31\"")]
+    #[case::trailing_text("2024-01-15 is a Monday", "\"2024-01-15 is a Monday\"")]
+    #[case::multibyte("2024-01-1é", "\"2024-01-1é\"")]
+    fn timestamp_strings(#[case] text: &str, #[case] expected: &str) -> IonResult<()> {
+        let converter = JsonValueConverter::new().with_timestamp_strings(true);
+        assert_eq!(
+            converter.to_element(json!(text)),
+            Element::read_one(expected)?
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::null("null.int", json!(null))]
+    #[case::int("-17", json!(-17))]
+    #[case::u64("18446744073709551615", json!(u64::MAX))]
+    #[case::float("2.5e0", json!(2.5))]
+    #[case::beyond_u64("-18446744073709551616", number("-18446744073709551616"))]
+    #[case::decimal("1.50", number("1.50"))]
+    #[case::tenth("0.1", number("0.1"))]
+    #[case::decimal_exponent("5d3", number("5e3"))]
+    #[case::whole_decimal("5.", number("5e0"))]
+    #[case::negative_zero("-0.", number("-0e0"))]
+    #[case::tiny_decimal("1d-400", number("1e-400"))]
+    #[case::timestamp("2024-01-15T", json!("2024-01-15"))]
+    #[case::symbol("'hello world'", json!("hello world"))]
+    #[case::blob("{{aGVsbG8=}}", json!("aGVsbG8="))]
+    #[case::sexp("(+ 1 2)", json!(["+", 1, 2]))]
+    #[case::structure("{a: 1, 'b c': {d: [null]}}", json!({"a": 1, "b c": {"d": [null]}}))]
+    fn ion_to_json(#[case] ion: &str, #[case] expected: JsonValue) -> IonResult<()> {
+        assert_eq!(JsonValue::try_from(Element::read_one(ion)?)?, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::annotated("a::1", json!(1))]
+    #[case::nan("nan", json!(null))]
+    #[case::duplicate_field("{a: 1, a: 2}", json!({"a": 2}))]
+    fn strict_mode_rejects_lossy_values(
+        #[case] ion: &str,
+        #[case] lossy: JsonValue,
+    ) -> IonResult<()> {
+        let element = Element::read_one(ion)?;
+        assert!(JsonValue::try_from(&element).is_err());
+        assert_eq!(JsonValueConverter::new().to_json_value(&element)?, lossy);
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> IonResult<()> {
+        let json = json!({"id": 7, "big": u64::MAX, "tags": ["a", "b"], "ratio": 0.25});
+        let element = Element::from(json.clone());
+        assert_eq!(JsonValue::try_from(&element)?, json);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::big_int("-170141183460469231731687303715884105728")]
+    #[case::tenth("0.1")]
+    #[case::precise_decimal("0.10000000000000000001")]
+    #[case::trailing_zeros("100.00")]
+    #[case::whole_decimal("5.")]
+    #[case::wide_decimal("1234567.")]
+    #[case::positive_exponent("12d5")]
+    #[case::negative_zero("-0d-3")]
+    #[case::huge_decimal("1d400")]
+    #[case::tiny_decimal("-1d-400")]
+    fn numbers_round_trip_exactly(#[case] ion: &str) -> IonResult<()> {
+        let element = Element::read_one(ion)?;
+        let json = JsonValue::try_from(&element)?;
+        let reread: JsonValue = serde_json::from_str(&json.to_string()).unwrap();
+        assert_eq!(Element::from(reread), element);
+        Ok(())
+    }
+
+    fn number(text: &str) -> JsonValue {
+        serde_json::from_str(text).unwrap()
+    }
+}
@@ -0,0 +1,110 @@
+//! Converters between streams of Ion and other data formats.
+//!
+//! Each converter reads its input one record at a time, so streams of any size can be converted
+//! without being held in memory.
+//!
+//! A conversion can be stopped early by giving its reader (or, for conversions to Ion, the
+//! [`WriteConfig`](crate::WriteConfig) of its writer) a cancellation token. Cancelled conversions
+//! stop at the next value boundary and return [`IonError::Cancelled`](crate::IonError::Cancelled).
+//!
+//! When Ion values are written as JSON (or embedded as JSON in another format), they are
+//! down-converted using the conventional rules:
+//! * Nulls of any type become `null`.
+//! * Ints, decimals, and floats become JSON numbers. Float values with no JSON representation
+//!   (`nan`, `+inf`, and `-inf`) become `null`.
+//! * Timestamps become strings containing their ISO 8601 representation.
+//! * Strings and symbols become strings.
+//! * Blobs become strings containing their base64 encoding; clobs become strings in which each
+//!   byte is represented by the code point with the same value.
+//! * Lists and s-expressions become arrays, and structs become objects.
+//! * Annotations are discarded.
+
+#[cfg(feature = "avro")]
+pub mod avro;
+pub mod csv;
+pub mod json;
+#[cfg(feature = "json-interop")]
+pub mod json_value;
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
+pub mod ndjson;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+
+#[cfg(any(feature = "msgpack", feature = "protobuf"))]
+use crate::IonResult;
+#[cfg(any(
+    feature = "avro",
+    feature = "msgpack",
+    feature = "parquet",
+    feature = "protobuf"
+))]
+use crate::Timestamp;
+#[cfg(any(feature = "avro", feature = "parquet"))]
+use crate::{Decimal, Element, Int, IonType};
+
+/// Returns the number of whole seconds between the Unix epoch and `timestamp`, along with the
+/// number of nanoseconds into the following second.
+#[cfg(any(
+    feature = "avro",
+    feature = "msgpack",
+    feature = "parquet",
+    feature = "protobuf"
+))]
+fn unix_time(timestamp: &Timestamp) -> (i64, u32) {
+    (
+        timestamp.date_time.and_utc().timestamp(),
+        timestamp.nanoseconds(),
+    )
+}
+
+/// Constructs a UTC timestamp from a number of seconds since the Unix epoch and a number of
+/// nanoseconds. If `nanoseconds` is zero, the timestamp will have second precision; otherwise, it
+/// will have nanosecond precision.
+#[cfg(any(feature = "msgpack", feature = "protobuf"))]
+fn utc_timestamp(seconds: i64, nanoseconds: u32) -> IonResult<Timestamp> {
+    use crate::result::IonFailure;
+    use chrono::{DateTime, Datelike, Timelike};
+
+    let Some(date_time) = DateTime::from_timestamp(seconds, nanoseconds) else {
+        return IonResult::decoding_error(format!(
+            "{seconds} seconds and {nanoseconds} nanoseconds from the Unix epoch is out of range"
+        ));
+    };
+    let builder = Timestamp::with_ymd(date_time.year() as u32, date_time.month(), date_time.day())
+        .with_hms(date_time.hour(), date_time.minute(), date_time.second());
+    if nanoseconds == 0 {
+        builder.with_offset(0).build()
+    } else {
+        builder.with_nanoseconds(nanoseconds).with_offset(0).build()
+    }
+}
+
+/// Returns `value` (an int or decimal) multiplied by `10^scale`, or `None` if `value` is not an
+/// int or decimal, has more than `scale` fractional digits, or needs more than `precision` digits.
+#[cfg(any(feature = "avro", feature = "parquet"))]
+fn unscaled_value(value: &Element, precision: u32, scale: u32) -> Option<i128> {
+    let (coefficient, exponent) = match value.ion_type() {
+        IonType::Int => (value.as_int()?.as_i128()?, 0),
+        IonType::Decimal => {
+            let decimal: Decimal = value.as_decimal()?;
+            let coefficient = Int::try_from(decimal.coefficient()).ok()?.as_i128()?;
+            (coefficient, decimal.exponent())
+        }
+        _ => return None,
+    };
+    let shift = exponent + scale as i64;
+    let unscaled = if shift >= 0 {
+        coefficient.checked_mul(10i128.checked_pow(u32::try_from(shift).ok()?)?)?
+    } else {
+        let divisor = 10i128.checked_pow(u32::try_from(-shift).ok()?)?;
+        if coefficient % divisor != 0 {
+            return None;
+        }
+        coefficient / divisor
+    };
+    let limit = 10u128.checked_pow(precision).unwrap_or(u128::MAX);
+    (unscaled.unsigned_abs() < limit).then_some(unscaled)
+}
@@ -0,0 +1,395 @@
+//! Converts between Ion values and [MessagePack](https://msgpack.org).
+//!
+//! Ion values are written as MessagePack using the following mapping:
+//! * Nulls of any type become `nil`.
+//! * Bools become booleans, and ints become integers. Ints that do not fit in an `i64` or `u64`
+//!   cannot be written.
+//! * Floats become 64-bit floats.
+//! * Decimals become strings containing the decimal's text, so that no precision is lost.
+//! * Timestamps become values of the MessagePack timestamp extension type (`-1`) with nanosecond
+//!   precision. The timestamp's offset is not retained.
+//! * Strings and symbols become strings.
+//! * Blobs and clobs become binary (`bin`) values.
+//! * Lists and s-expressions become arrays, and structs become maps with string keys.
+//! * Annotations are discarded.
+//!
+//! When reading MessagePack, each value is mapped to the corresponding Ion type: `nil` to `null`,
+//! integers to ints, floats to floats, strings to strings, `bin` values to blobs, arrays to lists,
+//! maps to structs, and timestamp extension values to UTC timestamps. Maps whose keys are not
+//! strings and extension types other than timestamps cannot be read.
+
+use std::io;
+use std::io::{BufRead, Read, Write};
+
+use rmp::encode::{self, ValueWriteError};
+use rmp::Marker;
+
+use crate::convert::json::{symbol_text, write_decimal};
+use crate::convert::{unix_time, utc_timestamp};
+use crate::element::builders::StructBuilder;
+use crate::lazy::decoder::Decoder;
+use crate::lazy::encoder::writer::Writer;
+use crate::lazy::encoding::Encoding;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::result::IonFailure;
+use crate::write_config::WriteConfig;
+use crate::{Element, IonError, IonResult, IonType, List, Reader, Timestamp};
+
+/// The MessagePack extension type reserved for timestamps.
+const TIMESTAMP_EXT_TYPE: i8 = -1;
+
+/// Writes the MessagePack representation of `value` to `output`.
+pub fn write_msgpack<D: Decoder>(
+    value: LazyValue<'_, D>,
+    output: &mut impl Write,
+) -> IonResult<()> {
+    match value.read()? {
+        ValueRef::Null(_) => encode::write_nil(output)?,
+        ValueRef::Bool(b) => encode::write_bool(output, b)?,
+        ValueRef::Int(i) => {
+            if let Some(signed) = i.as_i64() {
+                encode::write_sint(output, signed).map_err(write_error)?;
+            } else if let Some(unsigned) = i.as_u64() {
+                encode::write_uint(output, unsigned).map_err(write_error)?;
+            } else {
+                return IonResult::encoding_error(format!(
+                    "int {i} is too large to be written as MessagePack"
+                ));
+            }
+        }
+        ValueRef::Float(f) => encode::write_f64(output, f).map_err(write_error)?,
+        ValueRef::Decimal(d) => {
+            let mut text = Vec::new();
+            write_decimal(&mut text, &d)?;
+            // The decimal text is always ASCII.
+            encode::write_str(output, std::str::from_utf8(&text).unwrap()).map_err(write_error)?;
+        }
+        ValueRef::Timestamp(t) => write_timestamp(output, &t)?,
+        ValueRef::String(s) => encode::write_str(output, s.text()).map_err(write_error)?,
+        ValueRef::Symbol(s) => encode::write_str(output, symbol_text(&s)?).map_err(write_error)?,
+        ValueRef::Blob(b) | ValueRef::Clob(b) => {
+            encode::write_bin(output, b.data()).map_err(write_error)?
+        }
+        ValueRef::List(list) => {
+            encode::write_array_len(output, length(list.iter().count())?).map_err(write_error)?;
+            for child in &list {
+                write_msgpack(child?, output)?;
+            }
+        }
+        ValueRef::SExp(sexp) => {
+            encode::write_array_len(output, length(sexp.iter().count())?).map_err(write_error)?;
+            for child in &sexp {
+                write_msgpack(child?, output)?;
+            }
+        }
+        ValueRef::Struct(strukt) => {
+            encode::write_map_len(output, length(strukt.iter().count())?).map_err(write_error)?;
+            for field in &strukt {
+                let field = field?;
+                encode::write_str(output, symbol_text(&field.name()?)?).map_err(write_error)?;
+                write_msgpack(field.value(), output)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Writes `timestamp` using the smallest of the timestamp extension's three formats that can
+/// represent it.
+fn write_timestamp(output: &mut impl Write, timestamp: &Timestamp) -> IonResult<()> {
+    let (seconds, nanoseconds) = unix_time(timestamp);
+    if nanoseconds == 0 && (0..=u32::MAX as i64).contains(&seconds) {
+        encode::write_ext_meta(output, 4, TIMESTAMP_EXT_TYPE).map_err(write_error)?;
+        output.write_all(&(seconds as u32).to_be_bytes())?;
+    } else if (0..1i64 << 34).contains(&seconds) {
+        encode::write_ext_meta(output, 8, TIMESTAMP_EXT_TYPE).map_err(write_error)?;
+        let packed = ((nanoseconds as u64) << 34) | seconds as u64;
+        output.write_all(&packed.to_be_bytes())?;
+    } else {
+        encode::write_ext_meta(output, 12, TIMESTAMP_EXT_TYPE).map_err(write_error)?;
+        output.write_all(&nanoseconds.to_be_bytes())?;
+        output.write_all(&seconds.to_be_bytes())?;
+    }
+    Ok(())
+}
+
+fn length(count: usize) -> IonResult<u32> {
+    u32::try_from(count).or_else(|_| {
+        IonResult::encoding_error("containers with more than 2^32-1 values cannot be written")
+    })
+}
+
+fn write_error(error: ValueWriteError<io::Error>) -> IonError {
+    match error {
+        ValueWriteError::InvalidMarkerWrite(e) | ValueWriteError::InvalidDataWrite(e) => e.into(),
+    }
+}
+
+/// Reads the next MessagePack value from `input` and returns it as an [`Element`]. Returns
+/// `Ok(None)` if `input` is exhausted.
+pub fn read_msgpack(input: &mut impl BufRead) -> IonResult<Option<Element>> {
+    if input.fill_buf()?.is_empty() {
+        return Ok(None);
+    }
+    read_value(input).map(Some)
+}
+
+fn read_value(input: &mut impl Read) -> IonResult<Element> {
+    let [marker_byte] = read_array::<1>(input)?;
+    let element: Element = match Marker::from_u8(marker_byte) {
+        Marker::Null => Element::null(IonType::Null),
+        Marker::True => true.into(),
+        Marker::False => false.into(),
+        Marker::FixPos(n) => (n as i64).into(),
+        Marker::FixNeg(n) => (n as i64).into(),
+        Marker::U8 => (u8::from_be_bytes(read_array(input)?) as i64).into(),
+        Marker::U16 => (u16::from_be_bytes(read_array(input)?) as i64).into(),
+        Marker::U32 => (u32::from_be_bytes(read_array(input)?) as i64).into(),
+        Marker::U64 => u64::from_be_bytes(read_array(input)?).into(),
+        Marker::I8 => (i8::from_be_bytes(read_array(input)?) as i64).into(),
+        Marker::I16 => (i16::from_be_bytes(read_array(input)?) as i64).into(),
+        Marker::I32 => (i32::from_be_bytes(read_array(input)?) as i64).into(),
+        Marker::I64 => i64::from_be_bytes(read_array(input)?).into(),
+        Marker::F32 => (f32::from_be_bytes(read_array(input)?) as f64).into(),
+        Marker::F64 => f64::from_be_bytes(read_array(input)?).into(),
+        marker @ (Marker::FixStr(_) | Marker::Str8 | Marker::Str16 | Marker::Str32) => {
+            let length = read_length(input, marker)?;
+            read_string(input, length)?.into()
+        }
+        marker @ (Marker::Bin8 | Marker::Bin16 | Marker::Bin32) => {
+            let length = read_length(input, marker)?;
+            Element::blob(read_bytes(input, length)?)
+        }
+        marker @ (Marker::FixArray(_) | Marker::Array16 | Marker::Array32) => {
+            let length = read_length(input, marker)?;
+            let mut values = Vec::with_capacity(length.min(1024));
+            for _ in 0..length {
+                values.push(read_value(input)?);
+            }
+            List::from(values).into()
+        }
+        marker @ (Marker::FixMap(_) | Marker::Map16 | Marker::Map32) => {
+            let length = read_length(input, marker)?;
+            let mut builder = StructBuilder::new();
+            for _ in 0..length {
+                let key = read_value(input)?;
+                let Some(name) = key.as_string() else {
+                    return IonResult::decoding_error(format!(
+                        "MessagePack map keys must be strings to be read as Ion, found {key}"
+                    ));
+                };
+                builder = builder.with_field(name, read_value(input)?);
+            }
+            builder.build().into()
+        }
+        marker @ (Marker::FixExt1
+        | Marker::FixExt2
+        | Marker::FixExt4
+        | Marker::FixExt8
+        | Marker::FixExt16
+        | Marker::Ext8
+        | Marker::Ext16
+        | Marker::Ext32) => {
+            let length = read_length(input, marker)?;
+            let [ext_type] = read_array::<1>(input)?;
+            let data = read_bytes(input, length)?;
+            if ext_type as i8 != TIMESTAMP_EXT_TYPE {
+                return IonResult::decoding_error(format!(
+                    "MessagePack extension type {} cannot be read as Ion",
+                    ext_type as i8
+                ));
+            }
+            read_timestamp(&data)?.into()
+        }
+        Marker::Reserved => {
+            return IonResult::decoding_error("found reserved MessagePack marker byte 0xC1")
+        }
+    };
+    Ok(element)
+}
+
+/// Returns the length of the string, binary, array, map, or extension value that begins with
+/// `marker`, reading it from `input` if it is not part of the marker itself.
+fn read_length(input: &mut impl Read, marker: Marker) -> IonResult<usize> {
+    let length = match marker {
+        Marker::FixStr(n) | Marker::FixArray(n) | Marker::FixMap(n) => n as usize,
+        Marker::FixExt1 => 1,
+        Marker::FixExt2 => 2,
+        Marker::FixExt4 => 4,
+        Marker::FixExt8 => 8,
+        Marker::FixExt16 => 16,
+        Marker::Str8 | Marker::Bin8 | Marker::Ext8 => read_array::<1>(input)?[0] as usize,
+        Marker::Str16 | Marker::Bin16 | Marker::Array16 | Marker::Map16 | Marker::Ext16 => {
+            u16::from_be_bytes(read_array(input)?) as usize
+        }
+        _ => u32::from_be_bytes(read_array(input)?) as usize,
+    };
+    Ok(length)
+}
+
+fn read_array<const N: usize>(input: &mut impl Read) -> IonResult<[u8; N]> {
+    let mut bytes = [0u8; N];
+    input.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_bytes(input: &mut impl Read, length: usize) -> IonResult<Vec<u8>> {
+    let mut bytes = Vec::new();
+    input.take(length as u64).read_to_end(&mut bytes)?;
+    if bytes.len() < length {
+        return IonResult::decoding_error("MessagePack value ended unexpectedly");
+    }
+    Ok(bytes)
+}
+
+fn read_string(input: &mut impl Read, length: usize) -> IonResult<String> {
+    String::from_utf8(read_bytes(input, length)?)
+        .or_else(|_| IonResult::decoding_error("MessagePack string was not valid UTF-8"))
+}
+
+fn read_timestamp(data: &[u8]) -> IonResult<Timestamp> {
+    let (seconds, nanoseconds) = match data.len() {
+        4 => (u32::from_be_bytes(data.try_into().unwrap()) as i64, 0),
+        8 => {
+            let packed = u64::from_be_bytes(data.try_into().unwrap());
+            ((packed & ((1 << 34) - 1)) as i64, (packed >> 34) as u32)
+        }
+        12 => (
+            i64::from_be_bytes(data[4..].try_into().unwrap()),
+            u32::from_be_bytes(data[..4].try_into().unwrap()),
+        ),
+        length => {
+            return IonResult::decoding_error(format!(
+                "MessagePack timestamps must be 4, 8, or 12 bytes long, found {length}"
+            ))
+        }
+    };
+    utc_timestamp(seconds, nanoseconds)
+}
+
+/// Reads each of the remaining values in `reader` and writes it to `output` as MessagePack.
+/// Returns the number of values written.
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::convert::msgpack::{ion_to_msgpack, msgpack_to_ion};
+/// use ion_rs::{v1_0, AnyEncoding, Element, Reader};
+///
+/// let ion = "{id: 7, tags: [a, b], when: 2024-05-01T12:00:00Z} {{aGk=}}";
+/// let mut msgpack = Vec::new();
+/// assert_eq!(ion_to_msgpack(&mut Reader::new(AnyEncoding, ion)?, &mut msgpack)?, 2);
+///
+/// let round_tripped = msgpack_to_ion(msgpack.as_slice(), v1_0::Binary, Vec::new())?;
+/// assert_eq!(
+///     Element::read_all(round_tripped)?,
+///     Element::read_all(r#"{id: 7, tags: ["a", "b"], when: 2024-05-01T12:00:00Z} {{aGk=}}"#)?
+/// );
+///# Ok(())
+///# }
+/// ```
+pub fn ion_to_msgpack<D: Decoder, I: IonInput>(
+    reader: &mut Reader<D, I>,
+    mut output: impl Write,
+) -> IonResult<usize> {
+    let mut num_values = 0;
+    while let Some(value) = reader.next()? {
+        write_msgpack(value, &mut output)?;
+        num_values += 1;
+    }
+    output.flush()?;
+    Ok(num_values)
+}
+
+/// Reads each MessagePack value in `input` and writes it to `output` as Ion using a [`Writer`]
+/// constructed from `config`. Each value is flushed to `output` after it is read. Returns `output`
+/// once the input has been exhausted.
+pub fn msgpack_to_ion<E: Encoding, W: Write>(
+    mut input: impl BufRead,
+    config: impl Into<WriteConfig<E>>,
+    output: W,
+) -> IonResult<W> {
+    let mut writer = Writer::new(config, output)?;
+    while let Some(element) = read_msgpack(&mut input)? {
+        writer.write(&element)?;
+        writer.flush()?;
+    }
+    writer.close()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1_0;
+    use rstest::rstest;
+
+    fn to_msgpack(ion: &str) -> IonResult<Vec<u8>> {
+        let mut output = Vec::new();
+        ion_to_msgpack(&mut Reader::new(v1_0::Text, ion)?, &mut output)?;
+        Ok(output)
+    }
+
+    #[rstest]
+    #[case::null("null.string", &[0xC0])]
+    #[case::bool("true", &[0xC3])]
+    #[case::small_int("5", &[0x05])]
+    #[case::negative_int("-1", &[0xFF])]
+    #[case::large_uint("18446744073709551615", &[0xCF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF])]
+    #[case::float("1.5e0", &[0xCB, 0x3F, 0xF8, 0, 0, 0, 0, 0, 0])]
+    #[case::decimal("1.50", &[0xA4, b'1', b'.', b'5', b'0'])]
+    #[case::symbol("hi", &[0xA2, b'h', b'i'])]
+    #[case::clob(r#"{{"hi"}}"#, &[0xC4, 0x02, b'h', b'i'])]
+    #[case::sexp("(a 1)", &[0x92, 0xA1, b'a', 0x01])]
+    #[case::structure("x::{a: 1}", &[0x81, 0xA1, b'a', 0x01])]
+    #[case::timestamp_32("1970-01-01T00:00:01Z", &[0xD6, 0xFF, 0, 0, 0, 1])]
+    #[case::timestamp_64("1970-01-01T00:00:01.000000001Z", &[0xD7, 0xFF, 0, 0, 0, 0x04, 0, 0, 0, 1])]
+    #[case::timestamp_96(
+        "1969-12-31T23:59:59Z",
+        &[0xC7, 12, 0xFF, 0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]
+    )]
+    fn ion_to_msgpack_encoding(#[case] ion: &str, #[case] expected: &[u8]) -> IonResult<()> {
+        assert_eq!(to_msgpack(ion)?, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::scalars("null true -200 4000000000 2.5e0 \"text\" {{AAEC}}")]
+    #[case::containers("[1, [2, []], {a: {b: null}}] {}")]
+    #[case::timestamps("2024-05-01T12:00:00Z 2024-05-01T12:00:00.123456789Z 1900-01-01T00:00:00Z")]
+    fn round_trip(#[case] ion: &str) -> IonResult<()> {
+        let msgpack = to_msgpack(ion)?;
+        let ion_data = msgpack_to_ion(msgpack.as_slice(), v1_0::Binary, Vec::new())?;
+        assert_eq!(Element::read_all(ion_data)?, Element::read_all(ion)?);
+        Ok(())
+    }
+
+    #[test]
+    fn reads_narrow_encodings() -> IonResult<()> {
+        // A map containing a 32-bit float, an int16, and a str8
+        let msgpack: &[u8] = &[
+            0x83, 0xA1, b'f', 0xCA, 0x3F, 0xC0, 0, 0, 0xA1, b'i', 0xD1, 0xFF, 0x00, 0xA1, b's',
+            0xD9, 0x01, b'x',
+        ];
+        let element = read_msgpack(&mut &msgpack[..])?.unwrap();
+        assert_eq!(element, Element::read_one("{f: 1.5e0, i: -256, s: \"x\"}")?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::huge_int("123456789012345678901234567890")]
+    #[case::unknown_symbol("$0")]
+    fn cannot_write(#[case] ion: &str) {
+        assert!(to_msgpack(ion).is_err());
+    }
+
+    #[rstest]
+    #[case::non_string_key(&[0x81, 0x01, 0x01])]
+    #[case::unknown_extension(&[0xD4, 0x05, 0x00])]
+    #[case::truncated(&[0x92, 0x01])]
+    #[case::invalid_utf8(&[0xA1, 0xFF])]
+    #[case::reserved(&[0xC1])]
+    fn cannot_read(#[case] msgpack: &[u8]) {
+        assert!(msgpack_to_ion(msgpack, v1_0::Text, Vec::new()).is_err());
+    }
+}
@@ -0,0 +1,170 @@
+//! Converts between streams of Ion values and newline-delimited JSON (NDJSON), in which each line
+//! of the input or output holds a single JSON value.
+
+use std::io::{BufRead, Write};
+
+use crate::convert::json::JsonWriter;
+use crate::lazy::decoder::Decoder;
+use crate::lazy::encoder::writer::Writer;
+use crate::lazy::encoding::Encoding;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::result::IonFailure;
+use crate::write_config::WriteConfig;
+use crate::{v1_0, IonError, IonResult, Reader};
+
+/// Reads each of the remaining values in `reader` and writes its JSON representation to `output`
+/// as a line of text. Returns the number of lines written.
+///
+/// Values are down-converted as described in [the module documentation](crate::convert); each
+/// value is written to `output` as soon as it has been read.
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::convert::ndjson::ion_to_ndjson;
+/// use ion_rs::{AnyEncoding, Reader};
+///
+/// let mut reader = Reader::new(AnyEncoding, "{id: 1, tags: [a, b]} 2.50 2024T")?;
+/// let mut output = Vec::new();
+/// assert_eq!(ion_to_ndjson(&mut reader, &mut output)?, 3);
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "{\"id\":1,\"tags\":[\"a\",\"b\"]}\n2.50\n\"2024\"\n"
+/// );
+///# Ok(())
+///# }
+/// ```
+pub fn ion_to_ndjson<D: Decoder, I: IonInput>(
+    reader: &mut Reader<D, I>,
+    output: impl Write,
+) -> IonResult<usize> {
+    let mut writer = JsonWriter::new(output);
+    let mut num_lines = 0;
+    while let Some(value) = reader.next()? {
+        writer.write_value(value)?;
+        num_lines += 1;
+    }
+    writer.flush()?;
+    Ok(num_lines)
+}
+
+/// Reads each line of `input` as a JSON value and writes it to `output` as Ion using a [`Writer`]
+/// constructed from `config`. Blank lines are skipped. Returns `output` once the input has been
+/// exhausted.
+///
+/// Because Ion text is a superset of JSON, each line is read as Ion text: JSON integers become Ion
+/// ints, numbers with a fractional part but no exponent become decimals (preserving their
+/// precision), numbers with an exponent become floats, and strings, arrays, and objects become
+/// strings, lists, and structs. Each record is flushed to `output` after it is read.
+///
+/// Returns an `Err` identifying the line number if a line does not contain exactly one value.
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::convert::ndjson::ndjson_to_ion;
+/// use ion_rs::v1_0::Binary;
+/// use ion_rs::Element;
+///
+/// let ndjson = "{\"price\": 2.50, \"qty\": 3}\n\n[1e3, null]\n";
+/// let ion = ndjson_to_ion(ndjson.as_bytes(), Binary, Vec::new())?;
+/// assert_eq!(
+///     Element::read_all(ion)?,
+///     Element::read_all("{price: 2.50, qty: 3} [1000e0, null]")?
+/// );
+///# Ok(())
+///# }
+/// ```
+pub fn ndjson_to_ion<E: Encoding, W: Write>(
+    mut input: impl BufRead,
+    config: impl Into<WriteConfig<E>>,
+    output: W,
+) -> IonResult<W> {
+    let mut writer = Writer::new(config, output)?;
+    let mut line = String::new();
+    let mut line_number = 0;
+    loop {
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            break;
+        }
+        line_number += 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut reader =
+            Reader::new(v1_0::Text, line.as_bytes()).map_err(|e| line_error(line_number, e))?;
+        let Some(value) = reader.next().map_err(|e| line_error(line_number, e))? else {
+            return IonResult::decoding_error(format!("line {line_number}: expected a value"));
+        };
+        writer.write(value)?;
+        if reader
+            .next()
+            .map_err(|e| line_error(line_number, e))?
+            .is_some()
+        {
+            return IonResult::decoding_error(format!(
+                "line {line_number}: found more than one value"
+            ));
+        }
+        writer.flush()?;
+    }
+    writer.close()
+}
+
+fn line_error(line_number: usize, error: IonError) -> IonError {
+    IonError::decoding_error(format!("line {line_number}: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AnyEncoding, Element};
+
+    const NDJSON: &str = r#"{"id": 1, "name": "Widget é😀", "price": 9.99, "tags": ["a", "b"]}
+{"id": 2, "name": null, "ratio": 1.5e-3, "nested": {"ok": true, "items": []}}
+
+"just a string"
+"#;
+
+    #[test]
+    fn round_trip_through_ion() -> IonResult<()> {
+        let binary_ion = ndjson_to_ion(NDJSON.as_bytes(), v1_0::Binary, Vec::new())?;
+        let expected = Element::read_all(
+            r#"
+            {id: 1, name: "Widget é\U0001F600", price: 9.99, tags: ["a", "b"]}
+            {id: 2, name: null, ratio: 1.5e-3, nested: {ok: true, items: []}}
+            "just a string"
+        "#,
+        )?;
+        assert_eq!(Element::read_all(&binary_ion)?, expected);
+
+        let mut reader = Reader::new(AnyEncoding, binary_ion)?;
+        let mut ndjson = Vec::new();
+        assert_eq!(ion_to_ndjson(&mut reader, &mut ndjson)?, 3);
+        let ndjson = String::from_utf8(ndjson).unwrap();
+        assert_eq!(
+            ndjson,
+            concat!(
+                "{\"id\":1,\"name\":\"Widget é😀\",\"price\":9.99,\"tags\":[\"a\",\"b\"]}\n",
+                "{\"id\":2,\"name\":null,\"ratio\":0.0015,\"nested\":{\"ok\":true,\"items\":[]}}\n",
+                "\"just a string\"\n",
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn each_line_must_contain_one_value() {
+        for (ndjson, bad_line) in [
+            ("1\n2 3\n", "line 2"),
+            ("{\"a\": 1,\n\"b\": 2}\n", "line 1"),
+            ("[1]\n\n{\"a\":\n", "line 3"),
+        ] {
+            let error = ndjson_to_ion(ndjson.as_bytes(), v1_0::Text, Vec::new())
+                .expect_err("invalid NDJSON was accepted");
+            assert!(
+                error.to_string().contains(bad_line),
+                "error for {ndjson:?} did not mention {bad_line}: {error}"
+            );
+        }
+    }
+}
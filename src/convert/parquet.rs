@@ -0,0 +1,800 @@
+//! Writes a stream of flat Ion structs to a Parquet file.
+
+use parquet::basic::{LogicalType, Repetition, TimeUnit, Type as PhysicalType};
+use parquet::data_type::{
+    BoolType, ByteArray, ByteArrayType, DoubleType, FixedLenByteArray, FixedLenByteArrayType,
+    Int64Type,
+};
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::format::{MicroSeconds, MilliSeconds, NanoSeconds};
+use parquet::schema::types::Type;
+use std::io::Write;
+use std::sync::Arc;
+
+use crate::convert::{unix_time, unscaled_value};
+use crate::lazy::decoder::Decoder;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::result::IonFailure;
+use crate::types::CountDecimalDigits;
+use crate::{Decimal, Element, Int, IonError, IonResult, IonType, Reader, Timestamp};
+
+/// The largest precision supported for decimal columns.
+const MAX_DECIMAL_PRECISION: u32 = 38;
+/// Decimal columns with at most this precision are stored as 64-bit integers; larger ones are
+/// stored as 16-byte fixed-length byte arrays.
+const MAX_INT64_DECIMAL_PRECISION: u32 = 18;
+
+/// The resolution with which a timestamp column stores its values.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TimestampUnit {
+    Milliseconds,
+    /// The default.
+    #[default]
+    Microseconds,
+    Nanoseconds,
+}
+
+impl TimestampUnit {
+    fn per_second(self) -> i64 {
+        match self {
+            TimestampUnit::Milliseconds => 1_000,
+            TimestampUnit::Microseconds => 1_000_000,
+            TimestampUnit::Nanoseconds => 1_000_000_000,
+        }
+    }
+}
+
+/// The kind of data stored in a [`ParquetColumn`] and the Parquet type used to store it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParquetColumnType {
+    /// Bools, stored as `BOOLEAN`.
+    Bool,
+    /// Ints, stored as `INT64`.
+    Int,
+    /// Floats (or ints), stored as `DOUBLE`.
+    Float,
+    /// Decimals (or ints) with the given precision and scale, stored with the `DECIMAL` logical
+    /// type. Precisions up to 18 are stored as `INT64`; larger precisions (up to 38) are stored as
+    /// a 16-byte `FIXED_LEN_BYTE_ARRAY`.
+    Decimal { precision: u32, scale: u32 },
+    /// Timestamps, stored as `INT64` with the `TIMESTAMP` logical type, adjusted to UTC.
+    Timestamp(TimestampUnit),
+    /// Strings or symbols, stored as `BYTE_ARRAY` with the `STRING` logical type.
+    String,
+    /// Blobs or clobs, stored as `BYTE_ARRAY`.
+    Blob,
+}
+
+/// A column of a Parquet file, holding the values of a top-level field with the same name.
+///
+/// Every column is optional; a null value or a struct without the field produces a null.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParquetColumn {
+    name: String,
+    column_type: ParquetColumnType,
+}
+
+impl ParquetColumn {
+    pub fn new(name: impl Into<String>, column_type: ParquetColumnType) -> Self {
+        Self {
+            name: name.into(),
+            column_type,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn column_type(&self) -> ParquetColumnType {
+        self.column_type
+    }
+
+    fn parquet_type(&self) -> IonResult<Type> {
+        let (physical_type, logical_type) = match self.column_type {
+            ParquetColumnType::Bool => (PhysicalType::BOOLEAN, None),
+            ParquetColumnType::Int => (PhysicalType::INT64, None),
+            ParquetColumnType::Float => (PhysicalType::DOUBLE, None),
+            ParquetColumnType::Decimal { precision, scale } => {
+                if precision == 0 || precision > MAX_DECIMAL_PRECISION || scale > precision {
+                    return IonResult::illegal_operation(format!(
+                        "column '{}' has an invalid decimal precision ({precision}) or scale \
+                         ({scale})",
+                        self.name
+                    ));
+                }
+                let logical_type = LogicalType::Decimal {
+                    scale: scale as i32,
+                    precision: precision as i32,
+                };
+                let builder = if precision <= MAX_INT64_DECIMAL_PRECISION {
+                    Type::primitive_type_builder(&self.name, PhysicalType::INT64)
+                } else {
+                    Type::primitive_type_builder(&self.name, PhysicalType::FIXED_LEN_BYTE_ARRAY)
+                        .with_length(16)
+                };
+                return builder
+                    .with_repetition(Repetition::OPTIONAL)
+                    .with_logical_type(Some(logical_type))
+                    .with_precision(precision as i32)
+                    .with_scale(scale as i32)
+                    .build()
+                    .map_err(parquet_error);
+            }
+            ParquetColumnType::Timestamp(unit) => {
+                let unit = match unit {
+                    TimestampUnit::Milliseconds => TimeUnit::MILLIS(MilliSeconds {}),
+                    TimestampUnit::Microseconds => TimeUnit::MICROS(MicroSeconds {}),
+                    TimestampUnit::Nanoseconds => TimeUnit::NANOS(NanoSeconds {}),
+                };
+                let logical_type = LogicalType::Timestamp {
+                    is_adjusted_to_u_t_c: true,
+                    unit,
+                };
+                (PhysicalType::INT64, Some(logical_type))
+            }
+            ParquetColumnType::String => (PhysicalType::BYTE_ARRAY, Some(LogicalType::String)),
+            ParquetColumnType::Blob => (PhysicalType::BYTE_ARRAY, None),
+        };
+        Type::primitive_type_builder(&self.name, physical_type)
+            .with_repetition(Repetition::OPTIONAL)
+            .with_logical_type(logical_type)
+            .build()
+            .map_err(parquet_error)
+    }
+}
+
+/// Writes a stream of Ion structs to a Parquet file, one row per struct.
+///
+/// If the columns are not specified, they are inferred from a sample of the first structs in the
+/// stream (by default, 100 of them): there is one column for each top-level field name in the
+/// sample, in the order in which the names first appear. A column's type is chosen to hold every
+/// value of the field in the sample:
+/// * bools, ints, floats, timestamps, strings or symbols, and blobs or clobs each produce a column
+///   of the corresponding [`ParquetColumnType`]. Timestamps are stored with microsecond
+///   resolution.
+/// * A field holding both ints and floats produces a `Float` column.
+/// * A field holding decimals (and possibly ints) produces a `Decimal` column whose scale is the
+///   largest number of fractional digits in the sample and whose precision fits the largest
+///   integer part in the sample.
+/// * A field holding only nulls produces a `String` column.
+///
+/// Any other combination of types, or a list, s-expression, or struct value, causes an `Err`.
+/// Annotations are ignored.
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::convert::parquet::{ParquetColumnType, ParquetConverter};
+/// use ion_rs::{AnyEncoding, Reader};
+///
+/// let data = r#"
+///     {sku: "A-1", price: 9.99, added: 2024-01-15T10:30:00Z}
+///     {sku: "B-2", price: 125.5, qty: 3}
+/// "#;
+/// let mut reader = Reader::new(AnyEncoding, data)?;
+/// let converter = ParquetConverter::new();
+/// let mut parquet = Vec::new();
+/// assert_eq!(converter.convert(&mut reader, &mut parquet)?, 2);
+/// assert_eq!(&parquet[..4], b"PAR1");
+///
+/// // The inferred columns can be inspected (or adjusted) before converting.
+/// let sample = ion_rs::Element::read_all(data)?;
+/// let columns = ParquetConverter::infer_columns(&sample)?;
+/// assert_eq!(
+///     columns[1].column_type(),
+///     ParquetColumnType::Decimal { precision: 5, scale: 2 }
+/// );
+///# Ok(())
+///# }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ParquetConverter {
+    columns: Option<Vec<ParquetColumn>>,
+    sample_size: usize,
+    row_group_size: usize,
+}
+
+impl Default for ParquetConverter {
+    fn default() -> Self {
+        Self {
+            columns: None,
+            sample_size: 100,
+            row_group_size: 64 * 1024,
+        }
+    }
+}
+
+impl ParquetConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the columns to write instead of inferring them from the stream.
+    pub fn with_columns(mut self, columns: impl IntoIterator<Item = ParquetColumn>) -> Self {
+        self.columns = Some(columns.into_iter().collect());
+        self
+    }
+
+    /// Sets the number of structs read to infer the columns when they have not been specified.
+    /// Defaults to 100.
+    pub fn with_sample_size(mut self, sample_size: usize) -> Self {
+        self.sample_size = sample_size.max(1);
+        self
+    }
+
+    /// Sets the maximum number of rows in each row group of the output. Defaults to 65,536.
+    pub fn with_row_group_size(mut self, row_group_size: usize) -> Self {
+        self.row_group_size = row_group_size.max(1);
+        self
+    }
+
+    /// Infers a column for each top-level field name in `sample`, which must contain only structs.
+    /// See [`ParquetConverter`] for the rules used to choose each column's type.
+    pub fn infer_columns<'a>(
+        sample: impl IntoIterator<Item = &'a Element>,
+    ) -> IonResult<Vec<ParquetColumn>> {
+        let mut columns: Vec<InferredColumn> = Vec::new();
+        for record in sample {
+            let Some(strukt) = record.as_struct() else {
+                return not_a_struct(record);
+            };
+            for (name, value) in strukt {
+                let Some(name) = name.text() else {
+                    return IonResult::encoding_error(
+                        "field names with unknown text cannot be used as column names",
+                    );
+                };
+                let index = match columns.iter().position(|column| column.name == name) {
+                    Some(index) => index,
+                    None => {
+                        columns.push(InferredColumn {
+                            name: name.to_owned(),
+                            column_type: None,
+                            integer_digits: 0,
+                        });
+                        columns.len() - 1
+                    }
+                };
+                columns[index].add(value)?;
+            }
+        }
+        columns.into_iter().map(InferredColumn::finish).collect()
+    }
+
+    /// Reads each of the remaining values in `reader` and writes it to `output` as a row of a
+    /// Parquet file. Returns the number of rows written.
+    ///
+    /// Returns an `Err` if any value in the stream is not a struct, if a field's value cannot be
+    /// stored in its column without losing information, or if reading or writing fails.
+    pub fn convert<D: Decoder, I: IonInput>(
+        &self,
+        reader: &mut Reader<D, I>,
+        output: impl Write + Send,
+    ) -> IonResult<usize> {
+        let mut sample = Vec::new();
+        let columns = match &self.columns {
+            Some(columns) => columns.clone(),
+            None => {
+                while sample.len() < self.sample_size {
+                    match reader.next()? {
+                        Some(value) => sample.push(Element::try_from(value)?),
+                        None => break,
+                    }
+                }
+                Self::infer_columns(&sample)?
+            }
+        };
+        let fields = columns
+            .iter()
+            .map(|column| column.parquet_type().map(Arc::new))
+            .collect::<IonResult<Vec<_>>>()?;
+        let schema = Type::group_type_builder("schema")
+            .with_fields(fields)
+            .build()
+            .map_err(parquet_error)?;
+        let properties = Arc::new(WriterProperties::builder().build());
+        let mut writer = SerializedFileWriter::new(output, Arc::new(schema), properties)
+            .map_err(parquet_error)?;
+
+        let mut buffers: Vec<ColumnBuffer> = columns.iter().map(ColumnBuffer::new).collect();
+        let mut buffered_rows = 0;
+        let mut num_rows = 0;
+        let mut sample = sample.into_iter();
+        loop {
+            let record = match sample.next() {
+                Some(record) => record,
+                None => match reader.next()? {
+                    Some(value) => Element::try_from(value)?,
+                    None => break,
+                },
+            };
+            let Some(strukt) = record.as_struct() else {
+                return not_a_struct(&record);
+            };
+            for (column, buffer) in columns.iter().zip(buffers.iter_mut()) {
+                let value = strukt.get(&column.name).filter(|value| !value.is_null());
+                buffer
+                    .push(column, value)
+                    .map_err(|e| IonError::encoding_error(format!("record {num_rows}: {e}")))?;
+            }
+            buffered_rows += 1;
+            num_rows += 1;
+            if buffered_rows == self.row_group_size {
+                write_row_group(&mut writer, &mut buffers)?;
+                buffered_rows = 0;
+            }
+        }
+        if buffered_rows > 0 {
+            write_row_group(&mut writer, &mut buffers)?;
+        }
+        writer.close().map_err(parquet_error)?;
+        Ok(num_rows)
+    }
+}
+
+/// The state of a column while its type is being inferred from a sample.
+struct InferredColumn {
+    name: String,
+    column_type: Option<ParquetColumnType>,
+    /// The largest number of digits to the left of the decimal point in any int or decimal.
+    integer_digits: u32,
+}
+
+impl InferredColumn {
+    /// Widens the column's type, if necessary, so that it can hold `value`.
+    fn add(&mut self, value: &Element) -> IonResult<()> {
+        use ParquetColumnType::*;
+        if value.is_null() {
+            return Ok(());
+        }
+        let value_type = match value.ion_type() {
+            IonType::Bool => Bool,
+            IonType::Int => {
+                let digits = value.as_int().unwrap().count_decimal_digits();
+                self.integer_digits = self.integer_digits.max(digits);
+                Int
+            }
+            IonType::Float => Float,
+            IonType::Decimal => {
+                let (integer_digits, scale) = decimal_digits(&value.as_decimal().unwrap());
+                self.integer_digits = self.integer_digits.max(integer_digits);
+                // The precision is computed from `integer_digits` once the sample is exhausted.
+                Decimal {
+                    precision: 0,
+                    scale,
+                }
+            }
+            IonType::Timestamp => Timestamp(TimestampUnit::default()),
+            IonType::String | IonType::Symbol => String,
+            IonType::Blob | IonType::Clob => Blob,
+            ion_type => {
+                return IonResult::encoding_error(format!(
+                    "field '{}' contains a(n) {ion_type}, which cannot be stored in a column",
+                    self.name
+                ))
+            }
+        };
+        let merged = match (self.column_type, value_type) {
+            (None, value_type) => value_type,
+            (Some(column_type), value_type) if column_type == value_type => column_type,
+            (Some(Int), Float) | (Some(Float), Int) => Float,
+            (Some(Int), decimal @ Decimal { .. }) | (Some(decimal @ Decimal { .. }), Int) => {
+                decimal
+            }
+            (Some(Decimal { scale: s1, .. }), Decimal { scale: s2, .. }) => Decimal {
+                precision: 0,
+                scale: s1.max(s2),
+            },
+            (Some(column_type), _) => {
+                return IonResult::encoding_error(format!(
+                    "field '{}' contains both {column_type:?} values and a(n) {}",
+                    self.name,
+                    value.ion_type()
+                ))
+            }
+        };
+        self.column_type = Some(merged);
+        Ok(())
+    }
+
+    fn finish(self) -> IonResult<ParquetColumn> {
+        let column_type = match self.column_type {
+            Some(ParquetColumnType::Decimal { scale, .. }) => {
+                let precision = self.integer_digits.max(1) + scale;
+                if precision > MAX_DECIMAL_PRECISION {
+                    return IonResult::encoding_error(format!(
+                        "field '{}' requires a decimal precision of {precision}, which is more \
+                         than the maximum ({MAX_DECIMAL_PRECISION})",
+                        self.name
+                    ));
+                }
+                ParquetColumnType::Decimal { precision, scale }
+            }
+            Some(column_type) => column_type,
+            None => ParquetColumnType::String,
+        };
+        Ok(ParquetColumn::new(self.name, column_type))
+    }
+}
+
+/// Returns the number of digits to the left and right of the decimal point needed to write
+/// `decimal` positionally.
+fn decimal_digits(decimal: &Decimal) -> (u32, u32) {
+    let digits = decimal.coefficient().number_of_decimal_digits() as i64;
+    let exponent = decimal.exponent();
+    let scale = (-exponent).max(0);
+    let integer_digits = (digits + exponent).max(1);
+    (integer_digits as u32, scale as u32)
+}
+
+/// The values of a column that have not yet been written, along with their definition levels
+/// (`1` for a value, `0` for a null).
+struct ColumnBuffer {
+    values: ColumnValues,
+    definition_levels: Vec<i16>,
+}
+
+enum ColumnValues {
+    Bool(Vec<bool>),
+    Int64(Vec<i64>),
+    Double(Vec<f64>),
+    ByteArray(Vec<ByteArray>),
+    FixedLenByteArray(Vec<FixedLenByteArray>),
+}
+
+impl ColumnBuffer {
+    fn new(column: &ParquetColumn) -> Self {
+        let values = match column.column_type {
+            ParquetColumnType::Bool => ColumnValues::Bool(Vec::new()),
+            ParquetColumnType::Float => ColumnValues::Double(Vec::new()),
+            ParquetColumnType::Decimal { precision, .. }
+                if precision > MAX_INT64_DECIMAL_PRECISION =>
+            {
+                ColumnValues::FixedLenByteArray(Vec::new())
+            }
+            ParquetColumnType::Int
+            | ParquetColumnType::Decimal { .. }
+            | ParquetColumnType::Timestamp(_) => ColumnValues::Int64(Vec::new()),
+            ParquetColumnType::String | ParquetColumnType::Blob => {
+                ColumnValues::ByteArray(Vec::new())
+            }
+        };
+        Self {
+            values,
+            definition_levels: Vec::new(),
+        }
+    }
+
+    /// Appends `value` to the buffer, or a null if `value` is `None`.
+    fn push(&mut self, column: &ParquetColumn, value: Option<&Element>) -> IonResult<()> {
+        let Some(value) = value else {
+            self.definition_levels.push(0);
+            return Ok(());
+        };
+        let mismatch = || {
+            IonResult::encoding_error(format!(
+                "column '{}' ({:?}) cannot hold a(n) {}",
+                column.name,
+                column.column_type,
+                value.ion_type()
+            ))
+        };
+        match (&mut self.values, column.column_type) {
+            (ColumnValues::Bool(values), _) => match value.as_bool() {
+                Some(b) => values.push(b),
+                None => return mismatch(),
+            },
+            (ColumnValues::Double(values), _) => match (value.as_float(), value.as_int()) {
+                (Some(f), _) => values.push(f),
+                (_, Some(i)) => match i.as_i64() {
+                    Some(i) => values.push(i as f64),
+                    None => return out_of_range(value, column),
+                },
+                _ => return mismatch(),
+            },
+            (ColumnValues::Int64(values), ParquetColumnType::Int) => {
+                match value.as_int().map(Int::as_i64) {
+                    Some(Some(i)) => values.push(i),
+                    Some(None) => return out_of_range(value, column),
+                    None => return mismatch(),
+                }
+            }
+            (ColumnValues::Int64(values), ParquetColumnType::Timestamp(unit)) => {
+                let Some(timestamp) = value.as_timestamp() else {
+                    return mismatch();
+                };
+                match timestamp_value(&timestamp, unit) {
+                    Some(t) => values.push(t),
+                    None => return out_of_range(value, column),
+                }
+            }
+            (ColumnValues::Int64(values), ParquetColumnType::Decimal { precision, scale }) => {
+                let Some(unscaled) = unscaled_value(value, precision, scale) else {
+                    return mismatch_or_out_of_range(value, column);
+                };
+                values.push(unscaled as i64);
+            }
+            (ColumnValues::FixedLenByteArray(values), column_type) => {
+                let ParquetColumnType::Decimal { precision, scale } = column_type else {
+                    unreachable!("only decimal columns are stored as fixed-length byte arrays");
+                };
+                let Some(unscaled) = unscaled_value(value, precision, scale) else {
+                    return mismatch_or_out_of_range(value, column);
+                };
+                values.push(unscaled.to_be_bytes().to_vec().into());
+            }
+            (ColumnValues::ByteArray(values), ParquetColumnType::String) => match value.as_text() {
+                Some(text) => values.push(text.as_bytes().to_vec().into()),
+                None => return mismatch(),
+            },
+            (ColumnValues::ByteArray(values), _) => match value.as_lob() {
+                Some(bytes) => values.push(bytes.to_vec().into()),
+                None => return mismatch(),
+            },
+            (ColumnValues::Int64(_), _) => unreachable!("column buffer does not match its type"),
+        }
+        self.definition_levels.push(1);
+        Ok(())
+    }
+}
+
+/// Returns the number of `unit`s between the Unix epoch and `timestamp`, or `None` if the
+/// timestamp is out of range or is more precise than `unit`.
+fn timestamp_value(timestamp: &Timestamp, unit: TimestampUnit) -> Option<i64> {
+    let (seconds, nanoseconds) = unix_time(timestamp);
+    let nanoseconds_per_unit = 1_000_000_000 / unit.per_second() as u32;
+    if nanoseconds % nanoseconds_per_unit != 0 {
+        return None;
+    }
+    seconds
+        .checked_mul(unit.per_second())?
+        .checked_add((nanoseconds / nanoseconds_per_unit) as i64)
+}
+
+fn write_row_group<W: Write + Send>(
+    writer: &mut SerializedFileWriter<W>,
+    buffers: &mut [ColumnBuffer],
+) -> IonResult<()> {
+    let mut row_group = writer.next_row_group().map_err(parquet_error)?;
+    for buffer in buffers.iter_mut() {
+        let Some(mut column) = row_group.next_column().map_err(parquet_error)? else {
+            return IonResult::illegal_operation("the Parquet schema has too few columns");
+        };
+        let levels = Some(buffer.definition_levels.as_slice());
+        let result = match &mut buffer.values {
+            ColumnValues::Bool(values) => {
+                let result = column.typed::<BoolType>().write_batch(values, levels, None);
+                values.clear();
+                result
+            }
+            ColumnValues::Int64(values) => {
+                let result = column
+                    .typed::<Int64Type>()
+                    .write_batch(values, levels, None);
+                values.clear();
+                result
+            }
+            ColumnValues::Double(values) => {
+                let result = column
+                    .typed::<DoubleType>()
+                    .write_batch(values, levels, None);
+                values.clear();
+                result
+            }
+            ColumnValues::ByteArray(values) => {
+                let result = column
+                    .typed::<ByteArrayType>()
+                    .write_batch(values, levels, None);
+                values.clear();
+                result
+            }
+            ColumnValues::FixedLenByteArray(values) => {
+                let result = column
+                    .typed::<FixedLenByteArrayType>()
+                    .write_batch(values, levels, None);
+                values.clear();
+                result
+            }
+        };
+        result.map_err(parquet_error)?;
+        buffer.definition_levels.clear();
+        column.close().map_err(parquet_error)?;
+    }
+    row_group.close().map_err(parquet_error)?;
+    Ok(())
+}
+
+fn not_a_struct<T>(record: &Element) -> IonResult<T> {
+    IonResult::encoding_error(format!(
+        "expected each record to be a struct, but found a(n) {}",
+        record.ion_type()
+    ))
+}
+
+fn out_of_range<T>(value: &Element, column: &ParquetColumn) -> IonResult<T> {
+    IonResult::encoding_error(format!(
+        "{value} cannot be stored in column '{}' ({:?}) without losing information",
+        column.name, column.column_type
+    ))
+}
+
+fn mismatch_or_out_of_range<T>(value: &Element, column: &ParquetColumn) -> IonResult<T> {
+    match value.ion_type() {
+        IonType::Int | IonType::Decimal => out_of_range(value, column),
+        ion_type => IonResult::encoding_error(format!(
+            "column '{}' ({:?}) cannot hold a(n) {ion_type}",
+            column.name, column.column_type
+        )),
+    }
+}
+
+fn parquet_error(error: ParquetError) -> IonError {
+    match error {
+        ParquetError::External(e) => match e.downcast::<std::io::Error>() {
+            Ok(e) => (*e).into(),
+            Err(e) => IonError::encoding_error(e.to_string()),
+        },
+        e => IonError::encoding_error(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnyEncoding;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use parquet::record::RowAccessor;
+    use std::fs::File;
+    use std::io::{Seek, SeekFrom};
+
+    const DATA: &str = r#"
+        {id: 1, name: "Widget", price: 9.99, weight: 1.5e0, added: 2024-01-15T10:30:00.123Z, tag: {{AQI=}}}
+        {id: 2, name: gadget, price: 125.5, weight: 2, in_stock: true}
+        {id: 3, name: null, price: 7, notes: null}
+    "#;
+
+    /// Converts `ion` to a Parquet file and returns a reader for the file.
+    fn convert(
+        converter: &ParquetConverter,
+        ion: &str,
+    ) -> IonResult<(usize, SerializedFileReader<File>)> {
+        let mut file = tempfile::tempfile()?;
+        let num_rows = converter.convert(&mut Reader::new(AnyEncoding, ion)?, &mut file)?;
+        file.seek(SeekFrom::Start(0))?;
+        Ok((num_rows, SerializedFileReader::new(file).unwrap()))
+    }
+
+    fn rows(reader: &SerializedFileReader<File>) -> Vec<String> {
+        reader
+            .get_row_iter(None)
+            .unwrap()
+            .map(|row| row.unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn infer_columns() -> IonResult<()> {
+        let columns = ParquetConverter::infer_columns(&Element::read_all(DATA)?)?;
+        use ParquetColumnType::*;
+        let expected = [
+            ParquetColumn::new("id", Int),
+            ParquetColumn::new("name", String),
+            ParquetColumn::new(
+                "price",
+                Decimal {
+                    precision: 5,
+                    scale: 2,
+                },
+            ),
+            ParquetColumn::new("weight", Float),
+            ParquetColumn::new("added", Timestamp(TimestampUnit::Microseconds)),
+            ParquetColumn::new("tag", Blob),
+            ParquetColumn::new("in_stock", Bool),
+            ParquetColumn::new("notes", String),
+        ];
+        assert_eq!(columns, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn infer_columns_rejects_conflicting_types() -> IonResult<()> {
+        for ion in ["{a: 1} {a: true}", "{a: 1.5e0} {a: 1.5}", "{a: [1]}", "5"] {
+            let sample = Element::read_all(ion)?;
+            assert!(
+                ParquetConverter::infer_columns(&sample).is_err(),
+                "inferred columns for {ion}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn convert_with_inferred_columns() -> IonResult<()> {
+        let converter = ParquetConverter::new().with_row_group_size(2);
+        let (num_rows, reader) = convert(&converter, DATA)?;
+        assert_eq!(num_rows, 3);
+        assert_eq!(reader.metadata().num_row_groups(), 2);
+        let schema = reader.metadata().file_metadata().schema_descr_ptr();
+        assert_eq!(schema.num_columns(), 8);
+        assert_eq!(
+            schema.column(2).logical_type(),
+            Some(LogicalType::Decimal {
+                scale: 2,
+                precision: 5
+            })
+        );
+        assert_eq!(
+            rows(&reader),
+            [
+                r#"{id: 1, name: "Widget", price: 9.99, weight: 1.5, added: 2024-01-15 10:30:00 +00:00, tag: [1, 2], in_stock: null, notes: null}"#,
+                r#"{id: 2, name: "gadget", price: 125.50, weight: 2.0, added: null, tag: null, in_stock: true, notes: null}"#,
+                r#"{id: 3, name: null, price: 7.00, weight: null, added: null, tag: null, in_stock: null, notes: null}"#,
+            ]
+        );
+        // The row's text omits fractional seconds, so check the stored value directly.
+        let first_row = reader.get_row_iter(None).unwrap().next().unwrap().unwrap();
+        assert_eq!(
+            first_row.get_timestamp_micros(4).unwrap(),
+            1_705_314_600_123_000
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn convert_with_explicit_columns() -> IonResult<()> {
+        let converter = ParquetConverter::new().with_columns([
+            ParquetColumn::new(
+                "amount",
+                ParquetColumnType::Decimal {
+                    precision: 30,
+                    scale: 4,
+                },
+            ),
+            ParquetColumn::new(
+                "at",
+                ParquetColumnType::Timestamp(TimestampUnit::Milliseconds),
+            ),
+        ]);
+        let (num_rows, reader) = convert(
+            &converter,
+            "{amount: 12345678901234567890.5, at: 2024-06-01T00:00:00-07:00, ignored: 1} {}",
+        )?;
+        assert_eq!(num_rows, 2);
+        assert_eq!(
+            rows(&reader),
+            [
+                "{amount: 12345678901234567890.5000, at: 2024-06-01 07:00:00 +00:00}",
+                "{amount: null, at: null}",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn values_that_do_not_fit_are_errors() {
+        use ParquetColumnType::*;
+        let decimal = Decimal {
+            precision: 4,
+            scale: 2,
+        };
+        for (column_type, ion) in [
+            (Int, "18446744073709551616"),
+            (Int, "1.5"),
+            (decimal, "1.234"),
+            (decimal, "100"),
+            (
+                Timestamp(TimestampUnit::Milliseconds),
+                "2024-01-01T00:00:00.0001Z",
+            ),
+            (String, "{{AQI=}}"),
+            (Bool, "[true]"),
+        ] {
+            let converter =
+                ParquetConverter::new().with_columns([ParquetColumn::new("a", column_type)]);
+            let result = convert(&converter, &format!("{{a: {ion}}}"));
+            assert!(result.is_err(), "stored {ion} in a {column_type:?} column");
+        }
+    }
+}
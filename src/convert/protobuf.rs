@@ -0,0 +1,722 @@
+//! Converts between Ion structs and protobuf messages described by a
+//! [`prost_reflect`] descriptor.
+//!
+//! A message becomes a struct with one field for each of the message's fields that is set, using
+//! the field names from the message's descriptor. Field values are mapped as follows:
+//! * Bools, integers, and floating point numbers become bools, ints, and floats.
+//! * `string` fields become strings, and `bytes` fields become blobs.
+//! * Enum values become symbols containing the name of the enum value. (Numbers that do not
+//!   correspond to a named value become ints.)
+//! * Repeated fields become lists, and map fields become structs.
+//! * Nested messages become structs, except for the following well-known types:
+//!   * `google.protobuf.Timestamp` becomes a UTC timestamp.
+//!   * `google.protobuf.Duration` becomes a decimal number of seconds.
+//!   * `google.protobuf.Struct`, `Value`, and `ListValue` become the Ion struct, scalar, or list
+//!     that they describe.
+//!   * Wrapper types like `google.protobuf.Int64Value` become the value that they wrap.
+//!
+//! When converting an Ion struct to a message, the inverse mapping is used. In addition, ints
+//! and decimals are accepted for floating point fields, symbols are accepted for `string`
+//! fields, clobs are accepted for `bytes` fields, ints are accepted for enum fields, and null
+//! fields are left unset. Fields that are not in the message's descriptor cause an error.
+
+use prost_reflect::{
+    DynamicMessage, FieldDescriptor, Kind, MapKey, MessageDescriptor, ReflectMessage, Value,
+};
+use std::collections::HashMap;
+
+use crate::convert::{unix_time, utc_timestamp};
+use crate::result::IonFailure;
+use crate::{Decimal, Element, Int, IonResult, IonType, List, Struct, Symbol};
+
+const TIMESTAMP: &str = "google.protobuf.Timestamp";
+const DURATION: &str = "google.protobuf.Duration";
+const STRUCT: &str = "google.protobuf.Struct";
+const VALUE: &str = "google.protobuf.Value";
+const LIST_VALUE: &str = "google.protobuf.ListValue";
+const WRAPPERS: &[&str] = &[
+    "google.protobuf.DoubleValue",
+    "google.protobuf.FloatValue",
+    "google.protobuf.Int64Value",
+    "google.protobuf.UInt64Value",
+    "google.protobuf.Int32Value",
+    "google.protobuf.UInt32Value",
+    "google.protobuf.BoolValue",
+    "google.protobuf.StringValue",
+    "google.protobuf.BytesValue",
+];
+
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Converts `message` to an Ion [`Element`]. See the [module documentation](self) for details.
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::convert::protobuf::message_to_element;
+/// use ion_rs::Element;
+/// use prost_reflect::{DescriptorPool, DynamicMessage, Value};
+///
+/// let pool = DescriptorPool::global();
+/// let descriptor = pool.get_message_by_name("google.protobuf.Duration").unwrap();
+/// let mut duration = DynamicMessage::new(descriptor);
+/// duration.set_field_by_name("seconds", Value::I64(90));
+/// duration.set_field_by_name("nanos", Value::I32(250_000_000));
+/// assert_eq!(message_to_element(&duration)?, Element::read_one("90.25")?);
+///# Ok(())
+///# }
+/// ```
+pub fn message_to_element(message: &DynamicMessage) -> IonResult<Element> {
+    let descriptor = message.descriptor();
+    let full_name = descriptor.full_name();
+    match full_name {
+        TIMESTAMP => {
+            let (seconds, nanos) = seconds_and_nanos(message);
+            let Ok(nanos) = u32::try_from(nanos) else {
+                return IonResult::decoding_error("Timestamp nanos must be non-negative");
+            };
+            return utc_timestamp(seconds, nanos).map(Element::from);
+        }
+        DURATION => {
+            let (seconds, nanos) = seconds_and_nanos(message);
+            let total = seconds as i128 * NANOSECONDS_PER_SECOND + nanos as i128;
+            return Ok(trim_zeros(total, -9).into());
+        }
+        STRUCT | VALUE | LIST_VALUE => return json_value_to_element(message),
+        _ if WRAPPERS.contains(&full_name) => {
+            let value = message.get_field_by_name("value").unwrap();
+            let field = descriptor.get_field_by_name("value").unwrap();
+            return value_to_element(&value, &field.kind());
+        }
+        _ => {}
+    }
+    let mut fields = Vec::new();
+    for (field, value) in message.fields() {
+        let element = if field.is_map() {
+            map_to_element(value, &field)?
+        } else if field.is_list() {
+            let Value::List(values) = value else {
+                return IonResult::decoding_error("repeated field did not contain a list");
+            };
+            values
+                .iter()
+                .map(|value| value_to_element(value, &field.kind()))
+                .collect::<IonResult<List>>()?
+                .into()
+        } else {
+            value_to_element(value, &field.kind())?
+        };
+        fields.push((field.name().to_owned(), element));
+    }
+    Ok(Struct::from_iter(fields).into())
+}
+
+fn seconds_and_nanos(message: &DynamicMessage) -> (i64, i32) {
+    let seconds = message
+        .get_field_by_name("seconds")
+        .and_then(|v| v.as_i64())
+        .unwrap_or_default();
+    let nanos = message
+        .get_field_by_name("nanos")
+        .and_then(|v| v.as_i32())
+        .unwrap_or_default();
+    (seconds, nanos)
+}
+
+/// Returns a decimal equal to `coefficient * 10^exponent` without any trailing zeros in its
+/// fractional part.
+fn trim_zeros(mut coefficient: i128, mut exponent: i64) -> Decimal {
+    while exponent < 0 && coefficient % 10 == 0 {
+        coefficient /= 10;
+        exponent += 1;
+    }
+    Decimal::new(coefficient, exponent)
+}
+
+fn map_to_element(value: &Value, field: &FieldDescriptor) -> IonResult<Element> {
+    let Value::Map(entries) = value else {
+        return IonResult::decoding_error("map field did not contain a map");
+    };
+    let Kind::Message(entry) = field.kind() else {
+        return IonResult::decoding_error("map field did not have a map entry type");
+    };
+    let value_kind = entry.map_entry_value_field().kind();
+    // Protobuf maps are unordered; sort the keys so that the output is deterministic.
+    let mut entries: Vec<_> = entries.iter().collect();
+    entries.sort_by_key(|&(key, _)| key);
+    let mut fields = Vec::with_capacity(entries.len());
+    for (key, value) in entries {
+        let name = match key {
+            MapKey::String(text) => text.clone(),
+            MapKey::Bool(b) => b.to_string(),
+            MapKey::I32(i) => i.to_string(),
+            MapKey::I64(i) => i.to_string(),
+            MapKey::U32(i) => i.to_string(),
+            MapKey::U64(i) => i.to_string(),
+        };
+        fields.push((name, value_to_element(value, &value_kind)?));
+    }
+    Ok(Struct::from_iter(fields).into())
+}
+
+fn value_to_element(value: &Value, kind: &Kind) -> IonResult<Element> {
+    let element = match value {
+        Value::Bool(b) => (*b).into(),
+        Value::I32(i) => (*i as i64).into(),
+        Value::I64(i) => (*i).into(),
+        Value::U32(i) => (*i as i64).into(),
+        Value::U64(i) => (*i).into(),
+        Value::F32(f) => (*f as f64).into(),
+        Value::F64(f) => (*f).into(),
+        Value::String(s) => s.as_str().into(),
+        Value::Bytes(b) => Element::blob(b),
+        Value::EnumNumber(number) => {
+            let name = match kind {
+                Kind::Enum(descriptor) => descriptor.get_value(*number),
+                _ => None,
+            };
+            match name {
+                Some(value) => Symbol::from(value.name()).into(),
+                None => (*number as i64).into(),
+            }
+        }
+        Value::Message(message) => message_to_element(message)?,
+        Value::List(_) | Value::Map(_) => {
+            return IonResult::decoding_error("found a nested repeated or map value")
+        }
+    };
+    Ok(element)
+}
+
+/// Converts one of the message types used to represent JSON values (`Struct`, `Value`, and
+/// `ListValue`) to the corresponding Ion value.
+fn json_value_to_element(message: &DynamicMessage) -> IonResult<Element> {
+    let descriptor = message.descriptor();
+    match descriptor.full_name() {
+        STRUCT => {
+            let field = descriptor.get_field_by_name("fields").unwrap();
+            map_to_element(&message.get_field(&field), &field)
+        }
+        LIST_VALUE => {
+            let values = message.get_field_by_name("values").unwrap();
+            let Value::List(values) = values.as_ref() else {
+                return IonResult::decoding_error("ListValue did not contain a list");
+            };
+            let elements = values
+                .iter()
+                .map(|value| match value {
+                    Value::Message(message) => json_value_to_element(message),
+                    _ => IonResult::decoding_error("ListValue contained a non-Value element"),
+                })
+                .collect::<IonResult<List>>()?;
+            Ok(elements.into())
+        }
+        _ => {
+            // A `Value` is a oneof; at most one of its fields is set.
+            let Some((field, value)) = message.fields().next() else {
+                return Ok(Element::null(IonType::Null));
+            };
+            match field.name() {
+                "null_value" => Ok(Element::null(IonType::Null)),
+                _ => value_to_element(value, &field.kind()),
+            }
+        }
+    }
+}
+
+/// Converts an Ion struct to a message of the type described by `descriptor`. See the
+/// [module documentation](self) for details.
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::convert::protobuf::element_to_message;
+/// use ion_rs::Element;
+/// use prost_reflect::{DescriptorPool, Value};
+///
+/// let pool = DescriptorPool::global();
+/// let descriptor = pool.get_message_by_name("google.protobuf.Timestamp").unwrap();
+/// let timestamp = Element::read_one("2024-01-01T00:00:01.5Z")?;
+/// let message = element_to_message(&timestamp, &descriptor)?;
+/// assert_eq!(message.get_field_by_name("seconds").unwrap().as_i64(), Some(1704067201));
+/// assert_eq!(message.get_field_by_name("nanos").unwrap().as_i32(), Some(500_000_000));
+///# Ok(())
+///# }
+/// ```
+pub fn element_to_message(
+    element: &Element,
+    descriptor: &MessageDescriptor,
+) -> IonResult<DynamicMessage> {
+    let mut message = DynamicMessage::new(descriptor.clone());
+    let full_name = descriptor.full_name();
+    match full_name {
+        TIMESTAMP => {
+            let Some(timestamp) = element.as_timestamp() else {
+                return mismatch(element, full_name);
+            };
+            let (seconds, nanos) = unix_time(&timestamp);
+            message.set_field_by_name("seconds", Value::I64(seconds));
+            message.set_field_by_name("nanos", Value::I32(nanos as i32));
+        }
+        DURATION => {
+            let Some(total) = element_to_nanoseconds(element) else {
+                return mismatch(element, full_name);
+            };
+            let Ok(seconds) = i64::try_from(total / NANOSECONDS_PER_SECOND) else {
+                return IonResult::encoding_error(format!("duration {element} is out of range"));
+            };
+            message.set_field_by_name("seconds", Value::I64(seconds));
+            let nanos = (total % NANOSECONDS_PER_SECOND) as i32;
+            message.set_field_by_name("nanos", Value::I32(nanos));
+        }
+        STRUCT => {
+            let Some(strukt) = element.as_struct() else {
+                return mismatch(element, full_name);
+            };
+            let field = descriptor.get_field_by_name("fields").unwrap();
+            let value_descriptor = descriptor.parent_pool().get_message_by_name(VALUE).unwrap();
+            let mut entries = HashMap::new();
+            for (name, value) in strukt {
+                let key = MapKey::String(field_name_text(name)?.to_owned());
+                let value = element_to_message(value, &value_descriptor)?;
+                entries.insert(key, Value::Message(value));
+            }
+            message.set_field(&field, Value::Map(entries));
+        }
+        VALUE => set_json_value(&mut message, element)?,
+        LIST_VALUE => {
+            let Some(sequence) = element.as_sequence() else {
+                return mismatch(element, full_name);
+            };
+            let value_descriptor = descriptor.parent_pool().get_message_by_name(VALUE).unwrap();
+            let values = sequence
+                .iter()
+                .map(|value| element_to_message(value, &value_descriptor).map(Value::Message))
+                .collect::<IonResult<Vec<_>>>()?;
+            message.set_field_by_name("values", Value::List(values));
+        }
+        _ if WRAPPERS.contains(&full_name) => {
+            let field = descriptor.get_field_by_name("value").unwrap();
+            if !element.is_null() {
+                message.set_field(&field, element_to_value(element, &field.kind())?);
+            }
+        }
+        _ => {
+            let Some(strukt) = element.as_struct() else {
+                return mismatch(element, full_name);
+            };
+            for (name, value) in strukt {
+                let name = field_name_text(name)?;
+                let Some(field) = descriptor.get_field_by_name(name) else {
+                    return IonResult::encoding_error(format!(
+                        "message type {full_name} has no field named '{name}'"
+                    ));
+                };
+                if value.is_null() {
+                    continue;
+                }
+                let value = if field.is_map() {
+                    element_to_map(value, &field)?
+                } else if field.is_list() {
+                    let Some(sequence) = value.as_sequence() else {
+                        return mismatch(value, "a repeated field");
+                    };
+                    let values = sequence
+                        .iter()
+                        .map(|value| element_to_value(value, &field.kind()))
+                        .collect::<IonResult<Vec<_>>>()?;
+                    Value::List(values)
+                } else {
+                    element_to_value(value, &field.kind())?
+                };
+                message.set_field(&field, value);
+            }
+        }
+    }
+    Ok(message)
+}
+
+/// Returns the number of nanoseconds represented by an int or decimal number of seconds.
+fn element_to_nanoseconds(element: &Element) -> Option<i128> {
+    if let Some(seconds) = element.as_int() {
+        return seconds.as_i128()?.checked_mul(NANOSECONDS_PER_SECOND);
+    }
+    let decimal = element.as_decimal()?;
+    if decimal.is_zero() {
+        return Some(0);
+    }
+    let coefficient = Int::try_from(decimal.coefficient()).ok()?.as_i128()?;
+    let exponent = decimal.exponent() + 9;
+    if exponent >= 0 {
+        coefficient.checked_mul(10i128.checked_pow(u32::try_from(exponent).ok()?)?)
+    } else {
+        // Durations cannot represent fractions of a nanosecond.
+        let divisor = 10i128.checked_pow(u32::try_from(-exponent).ok()?)?;
+        (coefficient % divisor == 0).then(|| coefficient / divisor)
+    }
+}
+
+fn element_to_map(element: &Element, field: &FieldDescriptor) -> IonResult<Value> {
+    let Some(strukt) = element.as_struct() else {
+        return mismatch(element, "a map field");
+    };
+    let Kind::Message(entry) = field.kind() else {
+        return IonResult::encoding_error("map field did not have a map entry type");
+    };
+    let key_kind = entry.map_entry_key_field().kind();
+    let value_kind = entry.map_entry_value_field().kind();
+    let mut entries = HashMap::new();
+    for (name, value) in strukt {
+        let name = field_name_text(name)?;
+        let key = match key_kind {
+            Kind::String => Some(MapKey::String(name.to_owned())),
+            Kind::Bool => name.parse().ok().map(MapKey::Bool),
+            Kind::Int32 | Kind::Sint32 | Kind::Sfixed32 => name.parse().ok().map(MapKey::I32),
+            Kind::Int64 | Kind::Sint64 | Kind::Sfixed64 => name.parse().ok().map(MapKey::I64),
+            Kind::Uint32 | Kind::Fixed32 => name.parse().ok().map(MapKey::U32),
+            Kind::Uint64 | Kind::Fixed64 => name.parse().ok().map(MapKey::U64),
+            _ => None,
+        };
+        let Some(key) = key else {
+            return IonResult::encoding_error(format!(
+                "'{name}' is not a valid key for map field {}",
+                field.name()
+            ));
+        };
+        entries.insert(key, element_to_value(value, &value_kind)?);
+    }
+    Ok(Value::Map(entries))
+}
+
+fn element_to_value(element: &Element, kind: &Kind) -> IonResult<Value> {
+    let value = match kind {
+        Kind::Double | Kind::Float => {
+            let number = match element.ion_type() {
+                IonType::Float => element.as_float(),
+                IonType::Int => element.as_int().and_then(|i| i.as_i64()).map(|i| i as f64),
+                IonType::Decimal => element.as_decimal().and_then(|d| decimal_to_f64(&d)),
+                _ => None,
+            };
+            match (number, kind) {
+                (Some(number), Kind::Float) => Value::F32(number as f32),
+                (Some(number), _) => Value::F64(number),
+                (None, _) => return mismatch(element, "a floating point field"),
+            }
+        }
+        Kind::Int32 | Kind::Sint32 | Kind::Sfixed32 => Value::I32(int_value(element)?),
+        Kind::Int64 | Kind::Sint64 | Kind::Sfixed64 => Value::I64(int_value(element)?),
+        Kind::Uint32 | Kind::Fixed32 => Value::U32(int_value(element)?),
+        Kind::Uint64 | Kind::Fixed64 => Value::U64(int_value(element)?),
+        Kind::Bool => match element.as_bool() {
+            Some(b) => Value::Bool(b),
+            None => return mismatch(element, "a bool field"),
+        },
+        Kind::String => match element.as_text() {
+            Some(text) => Value::String(text.to_owned()),
+            None => return mismatch(element, "a string field"),
+        },
+        Kind::Bytes => match element.as_lob() {
+            Some(bytes) => Value::Bytes(bytes.to_vec().into()),
+            None => return mismatch(element, "a bytes field"),
+        },
+        Kind::Enum(descriptor) => {
+            let number = match element.ion_type() {
+                IonType::Symbol | IonType::String => element
+                    .as_text()
+                    .and_then(|name| descriptor.get_value_by_name(name))
+                    .map(|value| value.number()),
+                IonType::Int => element.as_int().and_then(|i| i.as_i64()?.try_into().ok()),
+                _ => None,
+            };
+            match number {
+                Some(number) => Value::EnumNumber(number),
+                None => return mismatch(element, descriptor.full_name()),
+            }
+        }
+        Kind::Message(descriptor) => Value::Message(element_to_message(element, descriptor)?),
+    };
+    Ok(value)
+}
+
+/// Returns the float nearest to `decimal`.
+fn decimal_to_f64(decimal: &Decimal) -> Option<f64> {
+    // Parsing the decimal's text (with Ion's `d` exponent marker replaced) rounds correctly.
+    let text = decimal.to_string().replace('d', "e");
+    text.strip_suffix('.').unwrap_or(&text).parse().ok()
+}
+
+fn int_value<T: TryFrom<i128>>(element: &Element) -> IonResult<T> {
+    let Some(int) = element.as_int() else {
+        return mismatch(element, "an integer field");
+    };
+    int.as_i128()
+        .and_then(|i| T::try_from(i).ok())
+        .map(Ok)
+        .unwrap_or_else(|| IonResult::encoding_error(format!("int {int} is out of range")))
+}
+
+/// Populates a `google.protobuf.Value` with the JSON-like value that `element` represents.
+fn set_json_value(message: &mut DynamicMessage, element: &Element) -> IonResult<()> {
+    let pool = message.descriptor().parent_pool().clone();
+    if element.is_null() {
+        message.set_field_by_name("null_value", Value::EnumNumber(0));
+        return Ok(());
+    }
+    match element.ion_type() {
+        IonType::Bool => {
+            message.set_field_by_name("bool_value", Value::Bool(element.as_bool().unwrap()))
+        }
+        IonType::Int | IonType::Float | IonType::Decimal => {
+            let number = element_to_value(element, &Kind::Double)?;
+            message.set_field_by_name("number_value", number);
+        }
+        IonType::String | IonType::Symbol => {
+            let text = element.as_text().map(str::to_owned);
+            let Some(text) = text else {
+                return mismatch(element, VALUE);
+            };
+            message.set_field_by_name("string_value", Value::String(text));
+        }
+        IonType::List | IonType::SExp => {
+            let descriptor = pool.get_message_by_name(LIST_VALUE).unwrap();
+            let list = element_to_message(element, &descriptor)?;
+            message.set_field_by_name("list_value", Value::Message(list));
+        }
+        IonType::Struct => {
+            let descriptor = pool.get_message_by_name(STRUCT).unwrap();
+            let strukt = element_to_message(element, &descriptor)?;
+            message.set_field_by_name("struct_value", Value::Message(strukt));
+        }
+        _ => return mismatch(element, VALUE),
+    }
+    Ok(())
+}
+
+fn field_name_text(name: &Symbol) -> IonResult<&str> {
+    match name.text() {
+        Some(text) => Ok(text),
+        None => IonResult::encoding_error("field names with unknown text cannot be converted"),
+    }
+}
+
+fn mismatch<T>(element: &Element, target: &str) -> IonResult<T> {
+    IonResult::encoding_error(format!(
+        "a(n) {} cannot be converted to {target}",
+        element.ion_type()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost_reflect::prost_types::field_descriptor_proto::{Label, Type};
+    use prost_reflect::prost_types::{
+        DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto, FieldDescriptorProto,
+        FileDescriptorProto, MessageOptions,
+    };
+    use prost_reflect::DescriptorPool;
+
+    fn field(
+        name: &str,
+        number: i32,
+        label: Label,
+        kind: Type,
+        type_name: &str,
+    ) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.to_owned()),
+            number: Some(number),
+            label: Some(label as i32),
+            r#type: Some(kind as i32),
+            type_name: (!type_name.is_empty()).then(|| type_name.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    /// Builds a descriptor for:
+    /// ```text
+    /// message Order {
+    ///   int64 id = 1;
+    ///   string customer = 2;
+    ///   repeated double prices = 3;
+    ///   Status status = 4;
+    ///   google.protobuf.Timestamp placed = 5;
+    ///   google.protobuf.Duration ttl = 6;
+    ///   google.protobuf.Struct metadata = 7;
+    ///   map<string, uint32> counts = 8;
+    ///   bytes payload = 9;
+    ///   google.protobuf.Int32Value priority = 10;
+    ///   enum Status { UNKNOWN = 0; SHIPPED = 1; }
+    /// }
+    /// ```
+    fn order_descriptor() -> MessageDescriptor {
+        let counts_entry = DescriptorProto {
+            name: Some("CountsEntry".to_owned()),
+            field: vec![
+                field("key", 1, Label::Optional, Type::String, ""),
+                field("value", 2, Label::Optional, Type::Uint32, ""),
+            ],
+            options: Some(MessageOptions {
+                map_entry: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let status = EnumDescriptorProto {
+            name: Some("Status".to_owned()),
+            value: ["UNKNOWN", "SHIPPED"]
+                .iter()
+                .enumerate()
+                .map(|(number, name)| EnumValueDescriptorProto {
+                    name: Some(name.to_string()),
+                    number: Some(number as i32),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let order = DescriptorProto {
+            name: Some("Order".to_owned()),
+            field: vec![
+                field("id", 1, Label::Optional, Type::Int64, ""),
+                field("customer", 2, Label::Optional, Type::String, ""),
+                field("prices", 3, Label::Repeated, Type::Double, ""),
+                field(
+                    "status",
+                    4,
+                    Label::Optional,
+                    Type::Enum,
+                    ".test.Order.Status",
+                ),
+                field(
+                    "placed",
+                    5,
+                    Label::Optional,
+                    Type::Message,
+                    ".google.protobuf.Timestamp",
+                ),
+                field(
+                    "ttl",
+                    6,
+                    Label::Optional,
+                    Type::Message,
+                    ".google.protobuf.Duration",
+                ),
+                field(
+                    "metadata",
+                    7,
+                    Label::Optional,
+                    Type::Message,
+                    ".google.protobuf.Struct",
+                ),
+                field(
+                    "counts",
+                    8,
+                    Label::Repeated,
+                    Type::Message,
+                    ".test.Order.CountsEntry",
+                ),
+                field("payload", 9, Label::Optional, Type::Bytes, ""),
+                field(
+                    "priority",
+                    10,
+                    Label::Optional,
+                    Type::Message,
+                    ".google.protobuf.Int32Value",
+                ),
+            ],
+            nested_type: vec![counts_entry],
+            enum_type: vec![status],
+            ..Default::default()
+        };
+        let file = FileDescriptorProto {
+            name: Some("test/order.proto".to_owned()),
+            package: Some("test".to_owned()),
+            dependency: vec![
+                "google/protobuf/timestamp.proto".to_owned(),
+                "google/protobuf/duration.proto".to_owned(),
+                "google/protobuf/struct.proto".to_owned(),
+                "google/protobuf/wrappers.proto".to_owned(),
+            ],
+            message_type: vec![order],
+            syntax: Some("proto3".to_owned()),
+            ..Default::default()
+        };
+        let mut pool = DescriptorPool::global();
+        pool.add_file_descriptor_proto(file).unwrap();
+        pool.get_message_by_name("test.Order").unwrap()
+    }
+
+    #[test]
+    fn round_trip() -> IonResult<()> {
+        let order = Element::read_one(
+            r#"{
+                id: 42,
+                customer: "Alice",
+                prices: [1.5e0, 20.25e0],
+                status: SHIPPED,
+                placed: 2024-03-01T12:30:00.250000000Z,
+                ttl: 3600.5,
+                metadata: {source: "web", tags: ["a", "b"], score: 2.5e0, gift: true, note: null},
+                counts: {apples: 3, pears: 0},
+                payload: {{AQID}},
+                priority: 7,
+            }"#,
+        )?;
+        let message = element_to_message(&order, &order_descriptor())?;
+        assert_eq!(message.get_field_by_name("id").unwrap().as_i64(), Some(42));
+        assert_eq!(
+            message
+                .get_field_by_name("status")
+                .unwrap()
+                .as_enum_number(),
+            Some(1)
+        );
+        assert_eq!(message_to_element(&message)?, order);
+        Ok(())
+    }
+
+    #[test]
+    fn unset_and_null_fields_are_omitted() -> IonResult<()> {
+        let descriptor = order_descriptor();
+        let message =
+            element_to_message(&Element::read_one("{id: 1, customer: null}")?, &descriptor)?;
+        assert_eq!(message_to_element(&message)?, Element::read_one("{id: 1}")?);
+        Ok(())
+    }
+
+    #[test]
+    fn accepts_alternate_representations() -> IonResult<()> {
+        let descriptor = order_descriptor();
+        let message = element_to_message(
+            &Element::read_one("{customer: alice, prices: [1, 2.5], status: 1, ttl: 2}")?,
+            &descriptor,
+        )?;
+        assert_eq!(
+            message_to_element(&message)?,
+            Element::read_one(
+                r#"{customer: "alice", prices: [1e0, 2.5e0], status: SHIPPED, ttl: 2.}"#
+            )?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn conversion_errors() {
+        let descriptor = order_descriptor();
+        for ion in [
+            "{unknown_field: 1}",
+            "{id: 1.5}",
+            "{customer: 5}",
+            "{status: CANCELLED}",
+            "{counts: {apples: -1}}",
+            "{ttl: 0.0000000001}",
+            "{placed: \"2024-01-01\"}",
+            "[1, 2]",
+        ] {
+            let element = Element::read_one(ion).unwrap();
+            assert!(
+                element_to_message(&element, &descriptor).is_err(),
+                "converted invalid value {ion}"
+            );
+        }
+    }
+}
@@ -26,6 +26,7 @@ use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::hash::Hasher;
 use std::io;
+pub use stream::{ElementStream, ElementStreamError, ElementStreamOptions, StreamedElement};
 
 use crate::{ion_data, Decimal, Int, IonResult, IonType, Str, Symbol, SymbolRef, Timestamp};
 use crate::{Blob, Bytes, Clob, List, SExp, Struct};
@@ -33,6 +34,7 @@ use crate::{Blob, Bytes, Clob, List, SExp, Struct};
 use crate::element::builders::{SequenceBuilder, StructBuilder};
 use crate::element::reader::ElementReader;
 use crate::ion_data::{IonDataHash, IonDataOrd, IonEq};
+use crate::ion_path::{PathComponent, ToIonPath};
 use crate::lazy::any_encoding::AnyEncoding;
 use crate::lazy::encoding::Encoding;
 use crate::lazy::reader::Reader;
@@ -52,6 +54,7 @@ pub mod builders;
 pub mod element_writer;
 pub mod reader;
 mod sequence;
+mod stream;
 
 impl IonEq for Value {
     fn ion_eq(&self, other: &Self) -> bool {
@@ -833,6 +836,24 @@ impl Element {
         }
     }
 
+    /// Returns a mutable reference to this element's [`Sequence`] if it is a list or s-expression.
+    /// This allows the sequence's elements to be modified in place.
+    /// ```
+    /// use ion_rs::{ion_list, Element};
+    ///
+    /// let mut element: Element = ion_list![1, 2].into();
+    /// if let Some(sequence) = element.as_sequence_mut() {
+    ///     sequence.push(3);
+    /// }
+    /// assert_eq!(element, ion_list![1, 2, 3].into());
+    /// ```
+    pub fn as_sequence_mut(&mut self) -> Option<&mut Sequence> {
+        match &mut self.value {
+            Value::SExp(s) | Value::List(s) => Some(s),
+            _ => None,
+        }
+    }
+
     pub fn expect_sequence(&self) -> IonResult<&Sequence> {
         self.expected(self.as_sequence())
     }
@@ -887,6 +908,28 @@ impl Element {
         }
     }
 
+    /// Returns a mutable reference to this element's [`Struct`] if it is a struct. This allows the
+    /// struct's fields to be added, removed, renamed, or modified in place.
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::Element;
+    ///
+    /// let mut element = Element::read_one(r#"{name: "widget", tags: [a, b]}"#)?;
+    /// let fields = element.as_struct_mut().unwrap();
+    /// fields.rename_field("name", "title");
+    /// fields.get_mut("tags").and_then(Element::as_sequence_mut).unwrap().push(Element::symbol("c"));
+    /// assert_eq!(element, Element::read_one(r#"{title: "widget", tags: [a, b, c]}"#)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_struct_mut(&mut self) -> Option<&mut Struct> {
+        match &mut self.value {
+            Value::Struct(structure) => Some(structure),
+            _ => None,
+        }
+    }
+
     pub fn expect_struct(&self) -> IonResult<&Struct> {
         self.expected(self.as_struct())
     }
@@ -898,6 +941,43 @@ impl Element {
         }
     }
 
+    /// Returns the value nested within this one at the specified [`IonPath`], or `None` if there
+    /// is no such value. `path` can be an `IonPath` or text to be parsed as one; an `Err` is only
+    /// returned if the text is not a valid path.
+    ///
+    /// If a struct has more than one field with a name in the path, the last such field is
+    /// selected. (See [`Struct::get`].)
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::Element;
+    /// let element = Element::read_one(r#"{a: {b: [1, 2, {c: "hello"}]}}"#)?;
+    /// let hello = element.get_path("a.b[2].c")?;
+    /// assert_eq!(hello, Some(&Element::string("hello")));
+    /// assert_eq!(element.get_path("a.b[3]")?, None);
+    /// assert_eq!(element.get_path("a.b.c")?, None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_path(&self, path: impl ToIonPath) -> IonResult<Option<&Element>> {
+        let path = path.to_ion_path()?;
+        let mut current = self;
+        for component in path.components() {
+            let next = match (component, &current.value) {
+                (PathComponent::Field(name), Value::Struct(strukt)) => strukt.get(name.as_str()),
+                (PathComponent::Index(index), Value::List(seq) | Value::SExp(seq)) => {
+                    seq.get(*index)
+                }
+                _ => None,
+            };
+            let Some(next) = next else {
+                return Ok(None);
+            };
+            current = next;
+        }
+        Ok(Some(current))
+    }
+
     /// Reads a single Ion [`Element`] from the provided data source.
     ///
     /// If the data source is empty, returns `Ok(None)`.
@@ -935,6 +1015,36 @@ impl Element {
         Ok(Reader::new(AnyEncoding, source)?.into_elements())
     }
 
+    /// Returns an [`ElementStream`] over the top-level values in the provided Ion data source.
+    ///
+    /// Unlike [`Element::iter`], each item produced by the stream records the index of its
+    /// top-level value and the range of input bytes it occupied, as does each error. The
+    /// `options` can also limit the encoded size of the values that will be materialized, making
+    /// this a safer choice than [`Element::read_all`] for untrusted or very large inputs.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, ElementStreamOptions};
+    ///
+    /// let options = ElementStreamOptions::new().with_max_element_size(16);
+    /// let mut stream = Element::stream(r#"1 "this string is too long" 2"#, options)?;
+    /// assert_eq!(stream.next().unwrap()?.element(), &Element::from(1));
+    /// let error = stream.next().unwrap().unwrap_err();
+    /// assert_eq!(error.index(), 1);
+    /// assert_eq!(error.range(), Some(2..27));
+    /// let item = stream.next().unwrap()?;
+    /// assert_eq!((item.index(), item.range()), (2, Some(28..29)));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn stream<I: IonInput>(
+        source: I,
+        options: ElementStreamOptions,
+    ) -> IonResult<ElementStream<I>> {
+        ElementStream::new(source, options)
+    }
+
     /// Encodes this element as an Ion stream with itself as the only top-level value.
     /// If the stream's encoding is binary Ion, returns a `Vec<u8>` containing the encoded bytes.
     /// If the stream's encoding is text Ion, returns a `String` containing the UTF-8 encoded text.
@@ -1723,7 +1833,26 @@ mod value_tests {
     use crate::element::*;
     use crate::ion_data::IonEq;
     use crate::types::UInt;
-    use crate::{ion_list, ion_sexp, ion_struct, IonType};
+    use crate::{ion_list, ion_sexp, ion_struct, IonPath, IonResult, IonType};
+
+    #[test]
+    fn get_path() -> IonResult<()> {
+        let element = Element::read_one("{a: {b: [1, 2, (3 {c: 4})]}, a: 5}")?;
+        assert_eq!(element.get_path("")?, Some(&element));
+        // Like `Struct::get`, the last field with a repeated name is selected.
+        assert_eq!(element.get_path("a")?, Some(&Element::int(5)));
+        let path = IonPath::new().index(0);
+        assert_eq!(element.get_path(&path)?, None);
+
+        let inner = element.expect_struct()?.get_all("a").next().unwrap();
+        assert_eq!(inner.get_path("b[2][1].c")?, Some(&Element::int(4)));
+        assert_eq!(inner.get_path(String::from("b[1]"))?, Some(&Element::int(2)));
+        assert_eq!(inner.get_path("b[3]")?, None);
+        assert_eq!(inner.get_path("b.c")?, None);
+        assert_eq!(inner.get_path("b[0].c")?, None);
+        assert!(inner.get_path("b[").is_err());
+        Ok(())
+    }
 
     #[test]
     fn demonstrate_element_implements_send() {
@@ -23,11 +23,12 @@ pub trait ElementReader {
     /// Returns an iterator over the [Element]s in the data stream.
     fn elements(&mut self) -> Self::ElementIterator<'_>;
 
+    /// Converts the reader into an iterator over the [Element]s in the data stream.
     fn into_elements(self) -> OwnedElementIterator<Self>
     where
         Self: Sized,
     {
-        OwnedElementIterator { reader: self }
+        OwnedElementIterator::new(self)
     }
 
     /// Like [Self::read_next_element], this method reads the next Ion value in the input stream,
@@ -87,10 +88,28 @@ impl<R: ElementReader + ?Sized> Iterator for ElementIterator<'_, R> {
     }
 }
 
+/// Owns a given [ElementReader] implementation and yields one [Element] at a time until the stream
+/// is exhausted or invalid data is encountered. See [ElementReader::into_elements].
 pub struct OwnedElementIterator<R: ElementReader> {
     reader: R,
 }
 
+impl<R: ElementReader> OwnedElementIterator<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self { reader }
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn reader(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns the underlying reader, which will resume reading where the iterator left off.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
 impl<R: ElementReader> Iterator for OwnedElementIterator<R> {
     type Item = IonResult<Element>;
 
@@ -58,6 +58,12 @@ impl Sequence {
         self.elements.get(index)
     }
 
+    /// Returns a mutable reference to the element at the specified index, or `None` if the index
+    /// is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Element> {
+        self.elements.get_mut(index)
+    }
+
     pub fn len(&self) -> usize {
         self.elements.len()
     }
@@ -70,6 +76,44 @@ impl Sequence {
         self.elements()
     }
 
+    /// Returns an iterator that allows each element in the sequence to be modified in place.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Element> {
+        self.elements.iter_mut()
+    }
+
+    /// Appends an element to the end of the sequence.
+    ///
+    /// ```
+    /// use ion_rs::{ion_seq, Element};
+    ///
+    /// let mut sequence = ion_seq!(1 2);
+    /// sequence.push(3);
+    /// sequence.insert(0, "zero");
+    /// assert_eq!(sequence.remove(1), Some(Element::int(1)));
+    /// assert_eq!(sequence, ion_seq!("zero" 2 3));
+    /// ```
+    pub fn push<E: Into<Element>>(&mut self, element: E) {
+        self.elements.push(element.into());
+    }
+
+    /// Inserts an element at the specified index, shifting all of the elements after it to the
+    /// right.
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    pub fn insert<E: Into<Element>>(&mut self, index: usize, element: E) {
+        self.elements.insert(index, element.into());
+    }
+
+    /// Removes and returns the element at the specified index, shifting all of the elements after
+    /// it to the left. Returns `None` if the index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<Element> {
+        if index >= self.elements.len() {
+            return None;
+        }
+        Some(self.elements.remove(index))
+    }
+
     /// ```
     ///# use ion_rs::IonResult;
     ///# fn main() -> IonResult<()> {
@@ -235,7 +279,7 @@ impl Debug for OwnedSequenceIterator {
 
 #[cfg(test)]
 mod tests {
-    use crate::{ion_list, Element};
+    use crate::{ion_list, ion_seq, Element};
 
     #[test]
     fn owned_sequence() {
@@ -257,4 +301,18 @@ mod tests {
         assert_eq!(it.size_hint(), (0, Some(0)));
         assert_eq!(it.next(), None);
     }
+
+    #[test]
+    fn mutate_sequence_in_place() {
+        let mut seq = ion_seq!(1 2 3);
+        seq.push(4);
+        seq.insert(0, 0);
+        assert_eq!(seq.remove(2), Some(Element::int(2)));
+        assert_eq!(seq.remove(10), None);
+        *seq.get_mut(0).unwrap() = Element::string("zero");
+        for element in seq.iter_mut().skip(1) {
+            *element = Element::int(element.expect_i64().unwrap() * 10);
+        }
+        assert_eq!(seq, ion_seq!("zero" 10 30 40));
+    }
 }
@@ -0,0 +1,358 @@
+use std::ops::Range;
+
+use thiserror::Error;
+
+use crate::lazy::any_encoding::{AnyEncoding, IonVersion};
+use crate::lazy::decoder::Decoder;
+use crate::lazy::reader::Reader;
+use crate::lazy::reader_limits::ReaderLimits;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::result::IonFailure;
+use crate::{Element, IonError, IonResult};
+
+/// Configuration options for [`Element::stream`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ElementStreamOptions {
+    max_element_size: Option<usize>,
+}
+
+impl ElementStreamOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the maximum encoded size (in bytes) of a top-level value that the stream will
+    /// materialize, if one has been set.
+    pub fn max_element_size(&self) -> Option<usize> {
+        self.max_element_size
+    }
+
+    /// Sets the maximum encoded size (in bytes) of a top-level value that the stream will
+    /// materialize. Values that are larger than `max_bytes` produce an [`ElementStreamError`]
+    /// instead of an [`Element`]; the stream then moves on to the next value.
+    ///
+    /// The limit also caps the memory used to buffer input from a stream: once a value that is
+    /// still being read reaches `max_bytes`, the stream produces an error and then ends, since the
+    /// rest of the value would need to be read to find the one after it.
+    ///
+    /// Ion 1.1 e-expressions are subject to the same limit. Values that are produced by evaluating
+    /// a macro have no encoded size of their own; each one counts at least one byte per value it
+    /// contains plus the length of its text and lob data. Values that exceed `max_bytes` by that
+    /// measure produce an [`ElementStreamError`] and the stream moves on to the next value.
+    pub fn with_max_element_size(mut self, max_bytes: usize) -> Self {
+        self.max_element_size = Some(max_bytes);
+        self
+    }
+}
+
+/// An [`Element`] read by an [`ElementStream`] along with its position in the input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StreamedElement {
+    index: usize,
+    range: Option<Range<usize>>,
+    element: Element,
+}
+
+impl StreamedElement {
+    /// Returns the zero-based index of this element among the stream's top-level values.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the range of input bytes in which this element was encoded. Values produced by
+    /// Ion 1.1 e-expressions have no encoding of their own and return `None`.
+    pub fn range(&self) -> Option<Range<usize>> {
+        self.range.clone()
+    }
+
+    pub fn element(&self) -> &Element {
+        &self.element
+    }
+
+    pub fn into_element(self) -> Element {
+        self.element
+    }
+}
+
+/// An error raised by an [`ElementStream`], along with the position in the input at which it
+/// occurred.
+#[derive(Clone, Debug, Error, PartialEq)]
+#[error("top-level value {index}: {error}")]
+pub struct ElementStreamError {
+    index: usize,
+    range: Option<Range<usize>>,
+    #[source]
+    error: IonError,
+}
+
+impl ElementStreamError {
+    /// Returns the zero-based index of the top-level value that was being read when the error
+    /// occurred.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the range of input bytes of the value that caused the error, if the reader was able
+    /// to locate that value before the error occurred.
+    pub fn range(&self) -> Option<Range<usize>> {
+        self.range.clone()
+    }
+
+    pub fn error(&self) -> &IonError {
+        &self.error
+    }
+
+    pub fn into_error(self) -> IonError {
+        self.error
+    }
+}
+
+impl From<ElementStreamError> for IonError {
+    fn from(error: ElementStreamError) -> Self {
+        error.error
+    }
+}
+
+/// An iterator over the top-level values of an Ion data source that materializes each one as an
+/// [`Element`]. Constructed by calling [`Element::stream`].
+///
+/// If a value is larger than the configured
+/// [maximum size](ElementStreamOptions::with_max_element_size) or cannot be materialized, the
+/// stream produces an error for that value and continues with the next one. If the input itself
+/// is malformed, the stream produces an error and then ends.
+pub struct ElementStream<Input: IonInput> {
+    reader: Reader<AnyEncoding, Input>,
+    options: ElementStreamOptions,
+    index: usize,
+    is_done: bool,
+}
+
+impl<Input: IonInput> ElementStream<Input> {
+    pub(crate) fn new(source: Input, options: ElementStreamOptions) -> IonResult<Self> {
+        let mut limits = ReaderLimits::new();
+        if let Some(max_size) = options.max_element_size {
+            limits = limits.with_max_value_size(max_size);
+        }
+        Ok(Self {
+            reader: Reader::new(AnyEncoding.with_limits(limits), source)?,
+            options,
+            index: 0,
+            is_done: false,
+        })
+    }
+
+    pub fn options(&self) -> ElementStreamOptions {
+        self.options
+    }
+}
+
+impl<Input: IonInput> Iterator for ElementStream<Input> {
+    type Item = Result<StreamedElement, ElementStreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+        let index = self.index;
+        let value = match self.reader.next() {
+            Ok(Some(value)) => value,
+            Ok(None) => {
+                self.is_done = true;
+                return None;
+            }
+            Err(error) => {
+                // A value that exceeded the maximum size has been skipped, so the reader can
+                // continue after it. The reader cannot resume after any other failure.
+                let range = self.reader.skipped_value();
+                match range {
+                    Some(_) => self.index += 1,
+                    None => self.is_done = true,
+                }
+                return Some(Err(ElementStreamError {
+                    index,
+                    range,
+                    error,
+                }));
+            }
+        };
+        self.index += 1;
+
+        let range = value.expanded().range();
+        if let Some(max_size) = self.options.max_element_size {
+            // Values read from Ion 1.0 were checked against the limit before being read. Ion 1.1
+            // values may have been produced (in whole or in part) by macros and must be measured.
+            let needs_check = value
+                .raw()
+                .is_none_or(|raw| raw.encoding().version() == IonVersion::v1_1);
+            if needs_check {
+                let mut remaining = max_size;
+                if let Err(error) = check_expanded_size(value, &mut remaining) {
+                    return Some(Err(ElementStreamError {
+                        index,
+                        range,
+                        error,
+                    }));
+                }
+            }
+        }
+        Some(match Element::try_from(value) {
+            Ok(element) => Ok(StreamedElement {
+                index,
+                range,
+                element,
+            }),
+            Err(error) => Err(ElementStreamError {
+                index,
+                range,
+                error,
+            }),
+        })
+    }
+}
+
+/// Subtracts the expanded size of `value` from `remaining`, failing once the total exceeds it.
+/// Each value counts as at least one byte; text and lob values also count the length of their
+/// data.
+fn check_expanded_size<D: Decoder>(
+    value: LazyValue<'_, D>,
+    remaining: &mut usize,
+) -> IonResult<()> {
+    fn consume(remaining: &mut usize, num_bytes: usize) -> IonResult<()> {
+        *remaining = remaining.checked_sub(num_bytes).ok_or_else(|| {
+            IonError::decoding_error(
+                "the value produced by a macro exceeds the maximum element size",
+            )
+        })?;
+        Ok(())
+    }
+
+    consume(remaining, 1)?;
+    for annotation in value.annotations() {
+        consume(remaining, annotation?.text().map_or(0, str::len))?;
+    }
+    match value.read()? {
+        ValueRef::String(text) => consume(remaining, text.text().len()),
+        ValueRef::Symbol(symbol) => consume(remaining, symbol.text().map_or(0, str::len)),
+        ValueRef::Blob(bytes) | ValueRef::Clob(bytes) => consume(remaining, bytes.data().len()),
+        ValueRef::List(list) => list
+            .iter()
+            .try_for_each(|child| check_expanded_size(child?, remaining)),
+        ValueRef::SExp(sexp) => sexp
+            .iter()
+            .try_for_each(|child| check_expanded_size(child?, remaining)),
+        ValueRef::Struct(strukt) => strukt.iter().try_for_each(|field| {
+            let field = field?;
+            consume(remaining, field.name()?.text().map_or(0, str::len))?;
+            check_expanded_size(field.value(), remaining)
+        }),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::streaming_raw_reader::IonStream;
+    use crate::{v1_0, Writer};
+
+    #[test]
+    fn stream_reports_index_and_range() -> IonResult<()> {
+        let data = r#"foo {bar: [1, 2, 3]} "baz""#;
+        let streamed =
+            Element::stream(data, ElementStreamOptions::new())?.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(streamed.len(), 3);
+        for (index, item) in streamed.iter().enumerate() {
+            assert_eq!(item.index(), index);
+            let range = item.range().unwrap();
+            let element = Element::read_one(&data[range])?;
+            assert_eq!(&element, item.element());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn stream_enforces_max_element_size() -> IonResult<()> {
+        let data = r#"1 "a string that is too long" 2 [3, 4, 5, 6, 7, 8, 9] 10"#;
+        let options = ElementStreamOptions::new().with_max_element_size(10);
+        let results: Vec<_> = Element::stream(data, options)?.collect();
+        assert_eq!(results.len(), 5);
+        let error = results[1].as_ref().unwrap_err();
+        assert_eq!(error.index(), 1);
+        assert_eq!(error.range(), Some(2..29));
+        assert!(results[3].is_err());
+        let values = results
+            .into_iter()
+            .filter_map(Result::ok)
+            .map(|item| (item.index(), item.into_element().expect_i64().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![(0, 1), (2, 2), (4, 10)]);
+        Ok(())
+    }
+
+    #[test]
+    fn stream_enforces_max_element_size_in_binary() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer.write("small")?.write("x".repeat(100))?.write(5)?;
+        let data = writer.close()?;
+        let options = ElementStreamOptions::new().with_max_element_size(32);
+        let input = IonStream::new(data.as_slice());
+        let results: Vec<_> = Element::stream(input, options)?.collect();
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert_eq!(results[2].as_ref().unwrap().element(), &Element::from(5));
+        Ok(())
+    }
+
+    #[test]
+    fn stream_stops_buffering_large_values() -> IonResult<()> {
+        use std::io::Read;
+        // A string that never ends; reading all of it would exhaust the available memory.
+        let input = b"1 \"".chain(std::io::repeat(b'a'));
+        let options = ElementStreamOptions::new().with_max_element_size(1024);
+        let results: Vec<_> = Element::stream(IonStream::new(input), options)?.collect();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().element(), &Element::from(1));
+        assert!(matches!(
+            results[1].as_ref().unwrap_err().error(),
+            IonError::Decoding(_)
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn stream_enforces_max_element_size_on_macros() -> IonResult<()> {
+        // The first e-expression is too large to read. The second is small, but its output
+        // is not; the third produces many values that are each small enough.
+        let data = "$ion_1_1 (:values 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15) 1 [(:repeat 100 0)] (:repeat 3 0) 2";
+        let options = ElementStreamOptions::new().with_max_element_size(20);
+        let results: Vec<_> = Element::stream(data, options)?.collect();
+        assert_eq!(results.len(), 7);
+        assert_eq!(results[0].as_ref().unwrap_err().range(), Some(9..54));
+        assert_eq!(results[1].as_ref().unwrap().element(), &Element::from(1));
+        assert!(matches!(
+            results[2].as_ref().unwrap_err().error(),
+            IonError::Decoding(_)
+        ));
+        for result in &results[3..6] {
+            assert_eq!(result.as_ref().unwrap().element(), &Element::from(0));
+        }
+        assert_eq!(results[6].as_ref().unwrap().element(), &Element::from(2));
+        Ok(())
+    }
+
+    #[test]
+    fn stream_ends_after_malformed_input() -> IonResult<()> {
+        let data = "1 2 {foo: ] 3";
+        let results: Vec<_> = Element::stream(data, ElementStreamOptions::new())?.collect();
+        assert_eq!(results.len(), 3);
+        let error = results[2].as_ref().unwrap_err();
+        assert_eq!(error.index(), 2);
+        assert_eq!(error.range(), None);
+        assert!(matches!(error.error(), IonError::Decoding(_)));
+        Ok(())
+    }
+}
@@ -0,0 +1,1037 @@
+//! A C ABI for reading and writing Ion, allowing this crate to be embedded in C and C++ programs.
+//!
+//! `include/ion_rs.h` declares these functions for C. To produce a library that C programs can
+//! link against, build this crate as a `cdylib` or `staticlib` with the `ffi` feature enabled:
+//! ```text
+//! cargo rustc --release --features ffi --crate-type cdylib
+//! ```
+//!
+//! Every function returns an [`IonStatus`], and any other results are stored through
+//! out-pointers. The status codes correspond to the variants of [`IonError`], plus
+//! [`IonStatus::InvalidArgument`] for null pointers and invalid UTF-8 passed by the caller. After
+//! a function fails, [`ion_error_message`] describes the failure. If a function panics, the panic
+//! is caught before it can reach the caller and reported as [`IonStatus::Panic`]; the handle that
+//! was in use may be left in an inconsistent state and should only be freed.
+//!
+//! An [`IonReader`] reads each top-level value of its input as it is requested; the reader can step
+//! into the current value if it is a container. An [`IonWriter`] writes text or binary Ion to an
+//! in-memory buffer, which [`ion_writer_flush`] makes available to the caller.
+//!
+//! # Safety
+//!
+//! Handles must have been created by this module's `_new` functions and not yet freed. Each
+//! pointer to caller-owned data must be valid for the number of bytes indicated by the length
+//! passed with it; a null pointer is only permitted with a length of zero. Out-pointers must be
+//! valid for writes. Text and byte pointers stored through out-pointers borrow from the handle
+//! that produced them and remain valid until the next call that uses that handle. Handles are not
+//! thread-safe.
+
+// The safety requirements are the same for every function, so they are documented once above.
+#![allow(clippy::missing_safety_doc)]
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::{slice, str};
+
+use crate::lazy::encoder::writer::Writer;
+use crate::result::IonFailure;
+use crate::{
+    v1_0, AnyEncoding, Element, IonError, IonResult, IonType, List, Reader, SExp, Struct, Symbol,
+};
+
+/// The outcome of a call to one of this module's functions.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IonStatus {
+    Ok = 0,
+    /// See [`IonError::Io`].
+    IoError = 1,
+    /// See [`IonError::Incomplete`].
+    Incomplete = 2,
+    /// See [`IonError::Encoding`].
+    EncodingError = 3,
+    /// See [`IonError::Decoding`].
+    DecodingError = 4,
+    /// See [`IonError::IllegalOperation`].
+    IllegalOperation = 5,
+    /// See [`IonError::Conversion`].
+    ConversionError = 6,
+    /// The caller passed a null pointer or invalid UTF-8.
+    InvalidArgument = 7,
+    /// The library panicked while handling the call. This indicates a bug in the library.
+    Panic = 8,
+    /// See [`IonError::Cancelled`].
+    Cancelled = 9,
+}
+
+impl From<&IonError> for IonStatus {
+    fn from(error: &IonError) -> Self {
+        match error {
+            IonError::Io(_) => IonStatus::IoError,
+            IonError::Incomplete(_) => IonStatus::Incomplete,
+            IonError::Encoding(_) => IonStatus::EncodingError,
+            IonError::Decoding(_) => IonStatus::DecodingError,
+            IonError::IllegalOperation(_) => IonStatus::IllegalOperation,
+            IonError::Conversion(_) => IonStatus::ConversionError,
+            IonError::Cancelled(_) => IonStatus::Cancelled,
+        }
+    }
+}
+
+/// The type of a value, or [`IonTypeCode::None`] if there is no value.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IonTypeCode {
+    None = 0,
+    Null = 1,
+    Bool = 2,
+    Int = 3,
+    Float = 4,
+    Decimal = 5,
+    Timestamp = 6,
+    Symbol = 7,
+    String = 8,
+    Clob = 9,
+    Blob = 10,
+    List = 11,
+    SExp = 12,
+    Struct = 13,
+}
+
+impl From<IonType> for IonTypeCode {
+    fn from(ion_type: IonType) -> Self {
+        match ion_type {
+            IonType::Null => IonTypeCode::Null,
+            IonType::Bool => IonTypeCode::Bool,
+            IonType::Int => IonTypeCode::Int,
+            IonType::Float => IonTypeCode::Float,
+            IonType::Decimal => IonTypeCode::Decimal,
+            IonType::Timestamp => IonTypeCode::Timestamp,
+            IonType::Symbol => IonTypeCode::Symbol,
+            IonType::String => IonTypeCode::String,
+            IonType::Clob => IonTypeCode::Clob,
+            IonType::Blob => IonTypeCode::Blob,
+            IonType::List => IonTypeCode::List,
+            IonType::SExp => IonTypeCode::SExp,
+            IonType::Struct => IonTypeCode::Struct,
+        }
+    }
+}
+
+/// Converts a type code passed by the caller (as an integer, since C may pass any value) to an
+/// [`IonType`].
+fn ion_type_arg(code: u32) -> Result<IonType, FfiError> {
+    let ion_type = match code {
+        1 => IonType::Null,
+        2 => IonType::Bool,
+        3 => IonType::Int,
+        4 => IonType::Float,
+        5 => IonType::Decimal,
+        6 => IonType::Timestamp,
+        7 => IonType::Symbol,
+        8 => IonType::String,
+        9 => IonType::Clob,
+        10 => IonType::Blob,
+        11 => IonType::List,
+        12 => IonType::SExp,
+        13 => IonType::Struct,
+        _ => return Err(FfiError::InvalidArgument("not a valid Ion type code")),
+    };
+    Ok(ion_type)
+}
+
+enum FfiError {
+    Ion(IonError),
+    InvalidArgument(&'static str),
+}
+
+impl From<IonError> for FfiError {
+    fn from(error: IonError) -> Self {
+        FfiError::Ion(error)
+    }
+}
+
+type FfiResult<T> = Result<T, FfiError>;
+
+thread_local! {
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+fn set_last_error(message: impl Display) {
+    // Interior NUL bytes would truncate the message in C, so replace them.
+    let message = message.to_string().replace('\0', "\u{FFFD}");
+    let message = CString::new(message).unwrap_or_default();
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = message);
+}
+
+/// Runs `body`, recording the error message (if any) and converting its result to a status.
+///
+/// Unwinding across the C ABI is undefined behavior, so a panic in `body` is caught here and
+/// reported as [`IonStatus::Panic`].
+fn run(body: impl FnOnce() -> FfiResult<()>) -> IonStatus {
+    // Callers are told not to use a handle after a panic (other than to free it), so observing
+    // one in a partially updated state is acceptable.
+    match catch_panic(body) {
+        Ok(Ok(())) => IonStatus::Ok,
+        Ok(Err(FfiError::Ion(error))) => {
+            set_last_error(&error);
+            IonStatus::from(&error)
+        }
+        Ok(Err(FfiError::InvalidArgument(message))) => {
+            set_last_error(message);
+            IonStatus::InvalidArgument
+        }
+        Err(message) => {
+            set_last_error(format_args!("the library panicked: {message}"));
+            IonStatus::Panic
+        }
+    }
+}
+
+/// Calls `body`, returning the panic's message as an `Err` if it panics.
+fn catch_panic<T>(body: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(body)).map_err(|payload| {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "unknown cause".to_string()
+        }
+    })
+}
+
+unsafe fn handle<'a, T>(handle: *mut T) -> FfiResult<&'a mut T> {
+    handle
+        .as_mut()
+        .ok_or(FfiError::InvalidArgument("handle was null"))
+}
+
+unsafe fn bytes_arg<'a>(data: *const u8, length: usize) -> FfiResult<&'a [u8]> {
+    match (data.is_null(), length) {
+        (true, 0) => Ok(&[]),
+        (true, _) => Err(FfiError::InvalidArgument("data pointer was null")),
+        (false, _) => Ok(slice::from_raw_parts(data, length)),
+    }
+}
+
+unsafe fn text_arg<'a>(text: *const c_char, length: usize) -> FfiResult<&'a str> {
+    str::from_utf8(bytes_arg(text.cast(), length)?)
+        .map_err(|_| FfiError::InvalidArgument("text was not valid UTF-8"))
+}
+
+unsafe fn store<T>(out: *mut T, value: T) -> FfiResult<()> {
+    if out.is_null() {
+        return Err(FfiError::InvalidArgument("output pointer was null"));
+    }
+    out.write(value);
+    Ok(())
+}
+
+/// Stores a pointer to `bytes` and its length through the given out-pointers.
+unsafe fn store_bytes<T>(bytes: &[u8], data: *mut *const T, length: *mut usize) -> FfiResult<()> {
+    store(data, bytes.as_ptr().cast())?;
+    store(length, bytes.len())
+}
+
+/// Returns a description of the most recent failure on the calling thread, as a NUL-terminated
+/// string. The pointer remains valid until the next failure on the same thread.
+#[no_mangle]
+pub extern "C" fn ion_error_message() -> *const c_char {
+    catch_panic(|| LAST_ERROR.with(|last_error| last_error.borrow().as_ptr()))
+        .unwrap_or(c"".as_ptr())
+}
+
+/// Reads Ion values (text or binary) from a buffer. See the [module documentation](self).
+pub struct IonReader {
+    reader: Reader<AnyEncoding, Vec<u8>>,
+    /// The top-level value that the reader is on or inside of.
+    top_level: Option<Element>,
+    /// For each container that has been stepped into (outermost first), the number of its
+    /// children that have been visited. The current child is the one before that position.
+    cursors: Vec<usize>,
+    /// Whether the reader is positioned on a value at the current depth.
+    on_value: bool,
+    /// Holds the text returned by `ion_reader_read_ion_text`.
+    ion_text: String,
+}
+
+/// Returns the child of `container` at `index` and its field name, if it is in a struct.
+fn child_at(container: &Element, index: usize) -> Option<(Option<&Symbol>, &Element)> {
+    if let Some(strukt) = container.as_struct() {
+        strukt
+            .field_at(index)
+            .map(|(name, value)| (Some(name), value))
+    } else {
+        container
+            .as_sequence()
+            .and_then(|sequence| sequence.get(index))
+            .map(|value| (None, value))
+    }
+}
+
+impl IonReader {
+    /// Returns the container at the given depth, where the top-level value is at depth 0.
+    fn container(&self, depth: usize) -> Option<&Element> {
+        let mut container = self.top_level.as_ref()?;
+        for &visited in &self.cursors[..depth] {
+            container = child_at(container, visited - 1)?.1;
+        }
+        Some(container)
+    }
+
+    /// Returns the current value and its field name, if it is in a struct.
+    fn current_entry(&self) -> Option<(Option<&Symbol>, &Element)> {
+        if !self.on_value {
+            return None;
+        }
+        match self.cursors.last() {
+            None => self.top_level.as_ref().map(|value| (None, value)),
+            Some(&visited) => {
+                let container = self.container(self.cursors.len() - 1)?;
+                child_at(container, visited - 1)
+            }
+        }
+    }
+
+    fn next(&mut self) -> IonResult<IonTypeCode> {
+        match self.cursors.len() {
+            0 => {
+                self.top_level = match self.reader.next()? {
+                    Some(value) => Some(Element::try_from(value)?),
+                    None => None,
+                };
+                self.on_value = self.top_level.is_some();
+            }
+            depth => {
+                let visited = self.cursors[depth - 1];
+                self.on_value = self
+                    .container(depth - 1)
+                    .and_then(|container| child_at(container, visited))
+                    .is_some();
+                if self.on_value {
+                    self.cursors[depth - 1] += 1;
+                }
+            }
+        }
+        Ok(self
+            .current_entry()
+            .map_or(IonTypeCode::None, |(_, value)| value.ion_type().into()))
+    }
+
+    fn current(&self) -> IonResult<&Element> {
+        match self.current_entry() {
+            Some((_, value)) => Ok(value),
+            None => Err(IonError::illegal_operation(
+                "the reader is not positioned on a value",
+            )),
+        }
+    }
+
+    fn step_in(&mut self) -> IonResult<()> {
+        let value = self.current()?;
+        if value.is_null() || !value.ion_type().is_container() {
+            return Err(IonError::illegal_operation(format!(
+                "cannot step into a(n) {}",
+                if value.is_null() { "null" } else { "scalar" }
+            )));
+        }
+        self.cursors.push(0);
+        self.on_value = false;
+        Ok(())
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        if self.cursors.pop().is_none() {
+            return Err(IonError::illegal_operation(
+                "cannot step out of the top level",
+            ));
+        }
+        self.on_value = false;
+        Ok(())
+    }
+}
+
+/// Creates a reader over a copy of the `length` bytes at `data`, which may be text or binary
+/// Ion, and stores it in `*reader`. The reader must be freed with [`ion_reader_free`].
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_new(
+    data: *const u8,
+    length: usize,
+    reader: *mut *mut IonReader,
+) -> IonStatus {
+    run(|| {
+        let input = bytes_arg(data, length)?.to_vec();
+        let new_reader = Box::new(IonReader {
+            reader: Reader::new(AnyEncoding, input)?,
+            top_level: None,
+            cursors: Vec::new(),
+            on_value: false,
+            ion_text: String::new(),
+        });
+        store(reader, Box::into_raw(new_reader))
+    })
+}
+
+/// Frees a reader created by [`ion_reader_new`]. Passing null has no effect.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_free(reader: *mut IonReader) {
+    if !reader.is_null() {
+        let _ = catch_panic(|| drop(Box::from_raw(reader)));
+    }
+}
+
+/// Advances to the next value at the current depth and stores its type in `*ion_type`. At the
+/// end of the stream or of the current container, stores [`IonTypeCode::None`].
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_next(
+    reader: *mut IonReader,
+    ion_type: *mut IonTypeCode,
+) -> IonStatus {
+    run(|| {
+        let ion_type_code = handle(reader)?.next()?;
+        store(ion_type, ion_type_code)
+    })
+}
+
+/// Steps into the current value, which must be a non-null list, s-expression, or struct.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_step_in(reader: *mut IonReader) -> IonStatus {
+    run(|| Ok(handle(reader)?.step_in()?))
+}
+
+/// Steps out of the current container, skipping any of its values that have not been read.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_step_out(reader: *mut IonReader) -> IonStatus {
+    run(|| Ok(handle(reader)?.step_out()?))
+}
+
+/// Stores whether the current value is a null of any type.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_is_null(
+    reader: *mut IonReader,
+    is_null: *mut bool,
+) -> IonStatus {
+    run(|| {
+        let value = handle(reader)?.current()?.is_null();
+        store(is_null, value)
+    })
+}
+
+/// Stores the UTF-8 text of the current value's field name. Fails if the reader is not inside a
+/// struct or the field name's text is unknown.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_field_name(
+    reader: *mut IonReader,
+    text: *mut *const c_char,
+    length: *mut usize,
+) -> IonStatus {
+    run(|| {
+        let reader = handle(reader)?;
+        let name = match reader.current_entry() {
+            Some((Some(name), _)) => name.text(),
+            _ => {
+                return Err(IonError::illegal_operation(
+                    "the current value does not have a field name",
+                )
+                .into())
+            }
+        };
+        match name {
+            Some(name) => store_bytes(name.as_bytes(), text, length),
+            None => Err(IonError::illegal_operation("the field name's text is unknown").into()),
+        }
+    })
+}
+
+/// Stores the number of annotations on the current value.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_annotation_count(
+    reader: *mut IonReader,
+    count: *mut usize,
+) -> IonStatus {
+    run(|| {
+        let num_annotations = handle(reader)?.current()?.annotations().len();
+        store(count, num_annotations)
+    })
+}
+
+/// Stores the UTF-8 text of the current value's annotation at `index`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_annotation(
+    reader: *mut IonReader,
+    index: usize,
+    text: *mut *const c_char,
+    length: *mut usize,
+) -> IonStatus {
+    run(|| {
+        let annotations = handle(reader)?.current()?.annotations();
+        match annotations.iter().nth(index).map(Symbol::text) {
+            Some(Some(annotation)) => store_bytes(annotation.as_bytes(), text, length),
+            Some(None) => {
+                Err(IonError::illegal_operation("the annotation's text is unknown").into())
+            }
+            None => Err(FfiError::InvalidArgument("annotation index out of range")),
+        }
+    })
+}
+
+/// Stores the value of the current bool.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_read_bool(
+    reader: *mut IonReader,
+    value: *mut bool,
+) -> IonStatus {
+    run(|| {
+        let read = handle(reader)?.current()?.expect_bool()?;
+        store(value, read)
+    })
+}
+
+/// Stores the value of the current int, which must fit in an `int64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_read_i64(reader: *mut IonReader, value: *mut i64) -> IonStatus {
+    run(|| {
+        let read = handle(reader)?.current()?.expect_i64()?;
+        store(value, read)
+    })
+}
+
+/// Stores the value of the current float.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_read_f64(reader: *mut IonReader, value: *mut f64) -> IonStatus {
+    run(|| {
+        let read = handle(reader)?.current()?.expect_float()?;
+        store(value, read)
+    })
+}
+
+/// Stores the UTF-8 text of the current string or symbol. The text is not NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_read_text(
+    reader: *mut IonReader,
+    text: *mut *const c_char,
+    length: *mut usize,
+) -> IonStatus {
+    run(|| {
+        let text_value = handle(reader)?.current()?.expect_text()?;
+        store_bytes(text_value.as_bytes(), text, length)
+    })
+}
+
+/// Stores the bytes of the current blob or clob.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_read_bytes(
+    reader: *mut IonReader,
+    data: *mut *const u8,
+    length: *mut usize,
+) -> IonStatus {
+    run(|| {
+        let bytes = handle(reader)?.current()?.expect_lob()?;
+        store_bytes(bytes, data, length)
+    })
+}
+
+/// Stores the Ion text representation of the current value (of any type, including its
+/// annotations). This is the simplest way to read decimals, timestamps, and ints that do not fit
+/// in an `int64_t`. The text is not NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_read_ion_text(
+    reader: *mut IonReader,
+    text: *mut *const c_char,
+    length: *mut usize,
+) -> IonStatus {
+    run(|| {
+        let reader = handle(reader)?;
+        reader.ion_text = reader.current()?.to_string();
+        store_bytes(reader.ion_text.as_bytes(), text, length)
+    })
+}
+
+enum WriterOutput {
+    Text(Writer<v1_0::Text, Vec<u8>>),
+    Binary(Writer<v1_0::Binary, Vec<u8>>),
+}
+
+/// A container that has been stepped into but not yet stepped out of.
+struct PendingContainer {
+    ion_type: IonType,
+    field_name: Option<String>,
+    annotations: Vec<String>,
+    values: Vec<(Option<String>, Element)>,
+}
+
+/// Writes Ion values to an in-memory buffer. See the [module documentation](self).
+pub struct IonWriter {
+    output: WriterOutput,
+    containers: Vec<PendingContainer>,
+    field_name: Option<String>,
+    annotations: Vec<String>,
+}
+
+impl IonWriter {
+    /// Writes `value` with the pending field name and annotations, if any.
+    fn write(&mut self, value: Element) -> IonResult<()> {
+        let field_name = self.field_name.take();
+        let value = value.with_annotations(std::mem::take(&mut self.annotations));
+        let Some(container) = self.containers.last_mut() else {
+            if field_name.is_some() {
+                return Err(IonError::illegal_operation(
+                    "a field name was set outside of a struct",
+                ));
+            }
+            return match &mut self.output {
+                WriterOutput::Text(writer) => writer.write(&value).map(|_| ()),
+                WriterOutput::Binary(writer) => writer.write(&value).map(|_| ()),
+            };
+        };
+        match (container.ion_type, &field_name) {
+            (IonType::Struct, None) => Err(IonError::illegal_operation(
+                "values in a struct require a field name",
+            )),
+            (IonType::Struct, Some(_)) | (_, None) => {
+                container.values.push((field_name, value));
+                Ok(())
+            }
+            (_, Some(_)) => Err(IonError::illegal_operation(
+                "a field name was set outside of a struct",
+            )),
+        }
+    }
+
+    fn step_in(&mut self, ion_type: IonType) -> IonResult<()> {
+        if !ion_type.is_container() {
+            return Err(IonError::illegal_operation(format!(
+                "cannot step into a(n) {ion_type}"
+            )));
+        }
+        self.containers.push(PendingContainer {
+            ion_type,
+            field_name: self.field_name.take(),
+            annotations: std::mem::take(&mut self.annotations),
+            values: Vec::new(),
+        });
+        Ok(())
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        let Some(container) = self.containers.pop() else {
+            return Err(IonError::illegal_operation(
+                "cannot step out of the top level",
+            ));
+        };
+        let values = container.values.into_iter();
+        let value: Element = match container.ion_type {
+            IonType::List => List::from(values.map(|(_, v)| v).collect::<Vec<_>>()).into(),
+            IonType::SExp => SExp::from(values.map(|(_, v)| v).collect::<Vec<_>>()).into(),
+            _ => Struct::from_iter(values.map(|(name, v)| (name.unwrap_or_default(), v))).into(),
+        };
+        self.field_name = container.field_name;
+        self.annotations = container.annotations;
+        self.write(value)
+    }
+}
+
+unsafe fn write_with(
+    writer: *mut IonWriter,
+    value: impl FnOnce() -> FfiResult<Element>,
+) -> IonStatus {
+    run(|| {
+        let writer = handle(writer)?;
+        writer.write(value()?)?;
+        Ok(())
+    })
+}
+
+/// Creates a writer that produces binary Ion if `binary` is true, or text Ion otherwise, and
+/// stores it in `*writer`. The writer must be freed with [`ion_writer_free`].
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_new(binary: bool, writer: *mut *mut IonWriter) -> IonStatus {
+    run(|| {
+        let output = if binary {
+            WriterOutput::Binary(Writer::new(v1_0::Binary, Vec::new())?)
+        } else {
+            WriterOutput::Text(Writer::new(v1_0::Text, Vec::new())?)
+        };
+        let new_writer = Box::new(IonWriter {
+            output,
+            containers: Vec::new(),
+            field_name: None,
+            annotations: Vec::new(),
+        });
+        store(writer, Box::into_raw(new_writer))
+    })
+}
+
+/// Frees a writer created by [`ion_writer_new`]. Passing null has no effect.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_free(writer: *mut IonWriter) {
+    if !writer.is_null() {
+        let _ = catch_panic(|| drop(Box::from_raw(writer)));
+    }
+}
+
+/// Sets the field name of the next value, which must be written inside a struct.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_field_name(
+    writer: *mut IonWriter,
+    text: *const c_char,
+    length: usize,
+) -> IonStatus {
+    run(|| {
+        let name = text_arg(text, length)?.to_owned();
+        handle(writer)?.field_name = Some(name);
+        Ok(())
+    })
+}
+
+/// Adds an annotation to the next value (or container) written.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_annotation(
+    writer: *mut IonWriter,
+    text: *const c_char,
+    length: usize,
+) -> IonStatus {
+    run(|| {
+        let annotation = text_arg(text, length)?.to_owned();
+        handle(writer)?.annotations.push(annotation);
+        Ok(())
+    })
+}
+
+/// Writes a null of the given type, which must be one of the [`IonTypeCode`] values other than
+/// [`IonTypeCode::None`].
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_write_null(writer: *mut IonWriter, ion_type: u32) -> IonStatus {
+    write_with(writer, || Ok(Element::null(ion_type_arg(ion_type)?)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_write_bool(writer: *mut IonWriter, value: bool) -> IonStatus {
+    write_with(writer, || Ok(value.into()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_write_i64(writer: *mut IonWriter, value: i64) -> IonStatus {
+    write_with(writer, || Ok(value.into()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_write_f64(writer: *mut IonWriter, value: f64) -> IonStatus {
+    write_with(writer, || Ok(value.into()))
+}
+
+/// Writes a string containing the `length` bytes of UTF-8 text at `text`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_write_string(
+    writer: *mut IonWriter,
+    text: *const c_char,
+    length: usize,
+) -> IonStatus {
+    write_with(writer, || Ok(text_arg(text, length)?.into()))
+}
+
+/// Writes a symbol containing the `length` bytes of UTF-8 text at `text`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_write_symbol(
+    writer: *mut IonWriter,
+    text: *const c_char,
+    length: usize,
+) -> IonStatus {
+    write_with(writer, || Ok(Symbol::from(text_arg(text, length)?).into()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_write_blob(
+    writer: *mut IonWriter,
+    data: *const u8,
+    length: usize,
+) -> IonStatus {
+    write_with(writer, || Ok(Element::blob(bytes_arg(data, length)?)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_write_clob(
+    writer: *mut IonWriter,
+    data: *const u8,
+    length: usize,
+) -> IonStatus {
+    write_with(writer, || Ok(Element::clob(bytes_arg(data, length)?)))
+}
+
+/// Parses a single value from the `length` bytes of Ion text at `text` and writes it. This is
+/// the simplest way to write decimals, timestamps, and ints that do not fit in an `int64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_write_ion_text(
+    writer: *mut IonWriter,
+    text: *const c_char,
+    length: usize,
+) -> IonStatus {
+    write_with(writer, || Ok(Element::read_one(text_arg(text, length)?)?))
+}
+
+/// Begins writing a container of the given type, which must be [`IonTypeCode::List`],
+/// [`IonTypeCode::SExp`], or [`IonTypeCode::Struct`]. Values written until the matching call to
+/// [`ion_writer_step_out`] are added to the container.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_step_in(writer: *mut IonWriter, ion_type: u32) -> IonStatus {
+    run(|| {
+        let ion_type = ion_type_arg(ion_type)?;
+        Ok(handle(writer)?.step_in(ion_type)?)
+    })
+}
+
+/// Finishes writing the current container.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_step_out(writer: *mut IonWriter) -> IonStatus {
+    run(|| Ok(handle(writer)?.step_out()?))
+}
+
+/// Flushes every value written at the top level and stores a pointer to (and the length of) all of
+/// the output that the writer has produced so far. Fails if a container has not been stepped out
+/// of.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_flush(
+    writer: *mut IonWriter,
+    data: *mut *const u8,
+    length: *mut usize,
+) -> IonStatus {
+    run(|| {
+        let writer = handle(writer)?;
+        if !writer.containers.is_empty() {
+            return Err(IonError::illegal_operation(
+                "cannot flush while a container is being written",
+            )
+            .into());
+        }
+        let output = match &mut writer.output {
+            WriterOutput::Text(writer) => {
+                writer.flush()?;
+                writer.output()
+            }
+            WriterOutput::Binary(writer) => {
+                writer.flush()?;
+                writer.output()
+            }
+        };
+        store_bytes(output, data, length)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+    use std::ptr;
+
+    unsafe fn text_of(text: *const c_char, length: usize) -> &'static str {
+        str::from_utf8(slice::from_raw_parts(text.cast(), length)).unwrap()
+    }
+
+    #[test]
+    fn read_values() {
+        let data = b"{name: \"Alice\", tags: [a, b], age: 32} 1.5e0 price::3.50 {{AQI=}}";
+        unsafe {
+            let mut reader = ptr::null_mut();
+            assert_eq!(
+                ion_reader_new(data.as_ptr(), data.len(), &mut reader),
+                IonStatus::Ok
+            );
+            let mut ion_type = IonTypeCode::None;
+            let (mut text, mut length) = (ptr::null(), 0);
+
+            ion_reader_next(reader, &mut ion_type);
+            assert_eq!(ion_type, IonTypeCode::Struct);
+            assert_eq!(ion_reader_step_in(reader), IonStatus::Ok);
+            ion_reader_next(reader, &mut ion_type);
+            assert_eq!(ion_type, IonTypeCode::String);
+            ion_reader_field_name(reader, &mut text, &mut length);
+            assert_eq!(text_of(text, length), "name");
+            ion_reader_read_text(reader, &mut text, &mut length);
+            assert_eq!(text_of(text, length), "Alice");
+            ion_reader_next(reader, &mut ion_type);
+            assert_eq!(ion_type, IonTypeCode::List);
+            ion_reader_step_in(reader);
+            ion_reader_next(reader, &mut ion_type);
+            assert_eq!(ion_type, IonTypeCode::Symbol);
+            // Step out of the list before reading its second value.
+            assert_eq!(ion_reader_step_out(reader), IonStatus::Ok);
+            ion_reader_next(reader, &mut ion_type);
+            let mut int = 0;
+            assert_eq!(ion_reader_read_i64(reader, &mut int), IonStatus::Ok);
+            assert_eq!(int, 32);
+            ion_reader_next(reader, &mut ion_type);
+            assert_eq!(ion_type, IonTypeCode::None);
+            ion_reader_step_out(reader);
+
+            ion_reader_next(reader, &mut ion_type);
+            let mut float = 0.0;
+            ion_reader_read_f64(reader, &mut float);
+            assert_eq!(float, 1.5);
+
+            ion_reader_next(reader, &mut ion_type);
+            assert_eq!(ion_type, IonTypeCode::Decimal);
+            let mut count = 0;
+            ion_reader_annotation_count(reader, &mut count);
+            assert_eq!(count, 1);
+            ion_reader_annotation(reader, 0, &mut text, &mut length);
+            assert_eq!(text_of(text, length), "price");
+            ion_reader_read_ion_text(reader, &mut text, &mut length);
+            assert_eq!(text_of(text, length), "price::3.50");
+
+            ion_reader_next(reader, &mut ion_type);
+            let mut bytes = ptr::null();
+            ion_reader_read_bytes(reader, &mut bytes, &mut length);
+            assert_eq!(slice::from_raw_parts(bytes, length), &[1, 2]);
+
+            ion_reader_next(reader, &mut ion_type);
+            assert_eq!(ion_type, IonTypeCode::None);
+            ion_reader_free(reader);
+        }
+    }
+
+    #[test]
+    fn write_values() {
+        for binary in [false, true] {
+            unsafe {
+                let mut writer = ptr::null_mut();
+                assert_eq!(ion_writer_new(binary, &mut writer), IonStatus::Ok);
+                ion_writer_annotation(writer, c"event".as_ptr(), 5);
+                ion_writer_step_in(writer, IonTypeCode::Struct as u32);
+                ion_writer_field_name(writer, c"id".as_ptr(), 2);
+                ion_writer_write_i64(writer, 7);
+                ion_writer_field_name(writer, c"tags".as_ptr(), 4);
+                ion_writer_step_in(writer, IonTypeCode::List as u32);
+                ion_writer_write_symbol(writer, c"a".as_ptr(), 1);
+                ion_writer_write_null(writer, IonTypeCode::String as u32);
+                ion_writer_step_out(writer);
+                ion_writer_field_name(writer, c"at".as_ptr(), 2);
+                ion_writer_write_ion_text(writer, c"2024-01-01T".as_ptr(), 11);
+                assert_eq!(ion_writer_step_out(writer), IonStatus::Ok);
+                ion_writer_write_string(writer, c"done".as_ptr(), 4);
+                ion_writer_write_blob(writer, [1u8, 2].as_ptr(), 2);
+
+                let (mut data, mut length) = (ptr::null(), 0);
+                assert_eq!(
+                    ion_writer_flush(writer, &mut data, &mut length),
+                    IonStatus::Ok
+                );
+                let output = slice::from_raw_parts(data, length);
+                assert_eq!(
+                    Element::read_all(output).unwrap(),
+                    Element::read_all(
+                        "event::{id: 7, tags: [a, null.string], at: 2024-01-01T} \"done\" {{AQI=}}"
+                    )
+                    .unwrap()
+                );
+                ion_writer_free(writer);
+            }
+        }
+    }
+
+    #[test]
+    fn errors_are_reported() {
+        unsafe {
+            let mut reader = ptr::null_mut();
+            let data = b"{a: 1} [";
+            ion_reader_new(data.as_ptr(), data.len(), &mut reader);
+            let mut ion_type = IonTypeCode::None;
+            let mut flag = false;
+            assert_eq!(
+                ion_reader_read_bool(reader, &mut flag),
+                IonStatus::IllegalOperation
+            );
+            ion_reader_next(reader, &mut ion_type);
+            assert_eq!(
+                ion_reader_read_bool(reader, &mut flag),
+                IonStatus::ConversionError
+            );
+            let message = CStr::from_ptr(ion_error_message()).to_str().unwrap();
+            assert!(message.contains("bool"), "unexpected message: {message}");
+            assert_eq!(ion_reader_step_out(reader), IonStatus::IllegalOperation);
+            assert_ne!(ion_reader_next(reader, &mut ion_type), IonStatus::Ok);
+            assert_eq!(
+                ion_reader_next(ptr::null_mut(), &mut ion_type),
+                IonStatus::InvalidArgument
+            );
+            ion_reader_free(reader);
+
+            let mut writer = ptr::null_mut();
+            ion_writer_new(false, &mut writer);
+            assert_eq!(ion_writer_step_in(writer, 99), IonStatus::InvalidArgument);
+            assert_eq!(ion_writer_step_out(writer), IonStatus::IllegalOperation);
+            assert_eq!(
+                ion_writer_write_string(writer, [0xFFu8].as_ptr().cast(), 1),
+                IonStatus::InvalidArgument
+            );
+            ion_writer_step_in(writer, IonTypeCode::Struct as u32);
+            assert_eq!(ion_writer_write_i64(writer, 1), IonStatus::IllegalOperation);
+            let (mut data, mut length) = (ptr::null(), 0);
+            assert_eq!(
+                ion_writer_flush(writer, &mut data, &mut length),
+                IonStatus::IllegalOperation
+            );
+            ion_writer_free(writer);
+        }
+    }
+
+    #[test]
+    fn panics_are_reported() {
+        assert_eq!(run(|| panic!("unexpected state")), IonStatus::Panic);
+        let message = unsafe { CStr::from_ptr(ion_error_message()) }
+            .to_str()
+            .unwrap();
+        assert!(
+            message.contains("unexpected state"),
+            "unexpected message: {message}"
+        );
+    }
+
+    #[test]
+    fn step_into_nested_containers() {
+        let data = b"{a: [1, {b: 2}], c: 3} 4";
+        unsafe {
+            let mut reader = ptr::null_mut();
+            ion_reader_new(data.as_ptr(), data.len(), &mut reader);
+            let mut ion_type = IonTypeCode::None;
+            let (mut text, mut length) = (ptr::null(), 0);
+            let mut int = 0;
+
+            ion_reader_next(reader, &mut ion_type);
+            ion_reader_step_in(reader);
+            ion_reader_next(reader, &mut ion_type);
+            assert_eq!(ion_type, IonTypeCode::List);
+            ion_reader_step_in(reader);
+            ion_reader_next(reader, &mut ion_type);
+            ion_reader_next(reader, &mut ion_type);
+            assert_eq!(ion_type, IonTypeCode::Struct);
+            ion_reader_step_in(reader);
+            ion_reader_next(reader, &mut ion_type);
+            ion_reader_field_name(reader, &mut text, &mut length);
+            assert_eq!(text_of(text, length), "b");
+            ion_reader_read_i64(reader, &mut int);
+            assert_eq!(int, 2);
+            ion_reader_next(reader, &mut ion_type);
+            assert_eq!(ion_type, IonTypeCode::None);
+            // The reader is not on a value after stepping out until `next` is called.
+            ion_reader_step_out(reader);
+            assert_eq!(
+                ion_reader_read_i64(reader, &mut int),
+                IonStatus::IllegalOperation
+            );
+            ion_reader_next(reader, &mut ion_type);
+            assert_eq!(ion_type, IonTypeCode::None);
+            ion_reader_step_out(reader);
+            ion_reader_next(reader, &mut ion_type);
+            ion_reader_field_name(reader, &mut text, &mut length);
+            assert_eq!(text_of(text, length), "c");
+            ion_reader_step_out(reader);
+            ion_reader_next(reader, &mut ion_type);
+            ion_reader_read_i64(reader, &mut int);
+            assert_eq!(int, 4);
+            ion_reader_free(reader);
+        }
+    }
+}
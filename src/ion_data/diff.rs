@@ -0,0 +1,304 @@
+use crate::ion_data::IonEq;
+use crate::lazy::decoder::Decoder;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::{Element, IonPath, IonResult, Reader, Sequence, Struct, Symbol, Value};
+
+/// The way in which two values at the same path differ. See [`diff`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DiffKind {
+    /// The values have different annotations.
+    Annotations,
+    /// The values have different types, or are scalars that are not Ion equivalent.
+    Value,
+    /// The value only appears in the first document.
+    Removed,
+    /// The value only appears in the second document.
+    Added,
+}
+
+/// A single difference between two Ion documents, as reported by [`diff`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiffEntry {
+    path: IonPath,
+    kind: DiffKind,
+    left: Option<Element>,
+    right: Option<Element>,
+}
+
+impl DiffEntry {
+    /// Returns the path at which the documents differ.
+    pub fn path(&self) -> &IonPath {
+        &self.path
+    }
+
+    pub fn kind(&self) -> DiffKind {
+        self.kind
+    }
+
+    /// Returns the value found at [`path`](Self::path) in the first document, if any.
+    pub fn left(&self) -> Option<&Element> {
+        self.left.as_ref()
+    }
+
+    /// Returns the value found at [`path`](Self::path) in the second document, if any.
+    pub fn right(&self) -> Option<&Element> {
+        self.right.as_ref()
+    }
+}
+
+/// Compares two values and reports each path at which they differ.
+///
+/// Values are compared using Ion equivalence (see [`IonData`](crate::IonData)) rather than Rust
+/// equality: `1.0` and `1.00` differ because of their precision, `$0` differs from any symbol with
+/// known text, and annotations are reported separately from the values that they annotate.
+/// Containers of the same type are compared child by child, so that a difference deep within a
+/// document is reported at its own path rather than at the root.
+///
+/// List and s-expression children are matched up by position. Struct fields are matched up by
+/// name; when a field name repeats, values that are equivalent in both structs are paired first.
+///
+/// ```
+/// use ion_rs::{diff, DiffKind, Element, IonPath};
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// let a = Element::read_one("{name: \"widget\", price: 1.50, tags: [new]}")?;
+/// let b = Element::read_one("{name: \"widget\", price: 1.5, tags: [new, sale]}")?;
+///
+/// let differences = diff(&a, &b);
+/// assert_eq!(differences.len(), 2);
+/// assert_eq!(differences[0].path(), &IonPath::new().field("price"));
+/// assert_eq!(differences[0].kind(), DiffKind::Value);
+/// assert_eq!(differences[1].path(), &IonPath::new().field("tags").index(1));
+/// assert_eq!(differences[1].kind(), DiffKind::Added);
+///# Ok(())
+///# }
+/// ```
+pub fn diff(a: &Element, b: &Element) -> Vec<DiffEntry> {
+    let mut differences = Vec::new();
+    diff_values(IonPath::new(), a, b, &mut differences);
+    differences
+}
+
+/// Reads the top-level values of two streams in pairs and reports each path at which they differ.
+/// See [`diff`] for details.
+///
+/// Each path begins with the index of the top-level value that it is found within. Only one
+/// top-level value from each stream is materialized at a time.
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub fn diff_readers<D1: Decoder, I1: IonInput, D2: Decoder, I2: IonInput>(
+    a: &mut Reader<D1, I1>,
+    b: &mut Reader<D2, I2>,
+) -> IonResult<Vec<DiffEntry>> {
+    let mut differences = Vec::new();
+    let mut index = 0;
+    loop {
+        let left = a.next()?.map(Element::try_from).transpose()?;
+        let right = b.next()?.map(Element::try_from).transpose()?;
+        let path = IonPath::new().index(index);
+        match (left, right) {
+            (None, None) => break,
+            (Some(left), Some(right)) => diff_values(path, &left, &right, &mut differences),
+            (left, right) => differences.push(entry_for_unmatched(path, left, right)),
+        }
+        index += 1;
+    }
+    Ok(differences)
+}
+
+fn diff_values(path: IonPath, a: &Element, b: &Element, differences: &mut Vec<DiffEntry>) {
+    if a.annotations() != b.annotations() {
+        differences.push(DiffEntry {
+            path: path.clone(),
+            kind: DiffKind::Annotations,
+            left: Some(a.clone()),
+            right: Some(b.clone()),
+        });
+    }
+    match (a.value(), b.value()) {
+        (Value::List(_), Value::List(_)) | (Value::SExp(_), Value::SExp(_)) => diff_sequences(
+            path,
+            a.as_sequence().unwrap(),
+            b.as_sequence().unwrap(),
+            differences,
+        ),
+        (Value::Struct(a), Value::Struct(b)) => diff_structs(path, a, b, differences),
+        (a_value, b_value) => {
+            if !a_value.ion_eq(b_value) {
+                differences.push(DiffEntry {
+                    path,
+                    kind: DiffKind::Value,
+                    left: Some(a.clone()),
+                    right: Some(b.clone()),
+                });
+            }
+        }
+    }
+}
+
+fn diff_sequences(path: IonPath, a: &Sequence, b: &Sequence, differences: &mut Vec<DiffEntry>) {
+    let mut a_children = a.iter();
+    let mut b_children = b.iter();
+    let mut index = 0;
+    loop {
+        let child_path = path.clone().index(index);
+        match (a_children.next(), b_children.next()) {
+            (None, None) => break,
+            (Some(left), Some(right)) => diff_values(child_path, left, right, differences),
+            (left, right) => differences.push(entry_for_unmatched(
+                child_path,
+                left.cloned(),
+                right.cloned(),
+            )),
+        }
+        index += 1;
+    }
+}
+
+fn diff_structs(path: IonPath, a: &Struct, b: &Struct, differences: &mut Vec<DiffEntry>) {
+    // Visit each field name in the order that it first appears in `a`, then in `b`.
+    let mut names: Vec<&Symbol> = Vec::new();
+    for (name, _value) in a.fields().chain(b.fields()) {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    for name in names {
+        let field_path = path.clone().field(name.text().unwrap_or("$0"));
+        let mut left: Vec<&Element> = a.get_all(name).collect();
+        let mut right: Vec<&Element> = b.get_all(name).collect();
+        // If the field name repeats, set aside the values that appear in both structs.
+        if left.len() > 1 || right.len() > 1 {
+            left.retain(|l| match right.iter().position(|r| l.ion_eq(r)) {
+                Some(position) => {
+                    right.remove(position);
+                    false
+                }
+                None => true,
+            });
+        }
+        match (left.as_slice(), right.as_slice()) {
+            ([l], [r]) => diff_values(field_path, l, r, differences),
+            _ => {
+                for l in left {
+                    differences.push(entry_for_unmatched(
+                        field_path.clone(),
+                        Some(l.clone()),
+                        None,
+                    ));
+                }
+                for r in right {
+                    differences.push(entry_for_unmatched(
+                        field_path.clone(),
+                        None,
+                        Some(r.clone()),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Constructs the entry for a value that only appears in one of the documents.
+fn entry_for_unmatched(path: IonPath, left: Option<Element>, right: Option<Element>) -> DiffEntry {
+    let kind = if left.is_some() {
+        DiffKind::Removed
+    } else {
+        DiffKind::Added
+    };
+    DiffEntry {
+        path,
+        kind,
+        left,
+        right,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnyEncoding;
+    use rstest::rstest;
+
+    fn kinds_and_paths(a: &str, b: &str) -> IonResult<Vec<(DiffKind, String)>> {
+        let a = Element::read_one(a)?;
+        let b = Element::read_one(b)?;
+        Ok(diff(&a, &b)
+            .into_iter()
+            .map(|entry| (entry.kind(), entry.path().to_string()))
+            .collect())
+    }
+
+    #[rstest]
+    #[case::equal_scalars("1", "1")]
+    #[case::nan("nan", "nan")]
+    #[case::reordered_fields("{a: 1, b: 2}", "{b: 2, a: 1}")]
+    #[case::reordered_repeated_fields("{a: 1, a: 2}", "{a: 2, a: 1}")]
+    #[case::unknown_symbol_text("$0", "$0")]
+    #[case::nested("foo::[{a: (1 2)}]", "foo::[{a: (1 2)}]")]
+    fn equivalent_values(#[case] a: &str, #[case] b: &str) -> IonResult<()> {
+        assert_eq!(kinds_and_paths(a, b)?, vec![]);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::int("1", "2", DiffKind::Value, "")]
+    #[case::decimal_precision("1.0", "1.00", DiffKind::Value, "")]
+    #[case::negative_zero("0e0", "-0e0", DiffKind::Value, "")]
+    #[case::unknown_symbol_text("$0", "foo", DiffKind::Value, "")]
+    #[case::types("1", "\"1\"", DiffKind::Value, "")]
+    #[case::container_types("[1]", "(1)", DiffKind::Value, "")]
+    #[case::null_container("null.list", "[]", DiffKind::Value, "")]
+    #[case::annotations("a::1", "b::1", DiffKind::Annotations, "")]
+    #[case::annotation_order("a::b::1", "b::a::1", DiffKind::Annotations, "")]
+    #[case::nested("{a: {b: [1, 2]}}", "{a: {b: [1, 3]}}", DiffKind::Value, "a.b[1]")]
+    #[case::nested_annotations("[x::1]", "[1]", DiffKind::Annotations, "[0]")]
+    #[case::removed_child("[1, 2]", "[1]", DiffKind::Removed, "[1]")]
+    #[case::added_child("(1)", "(1 2)", DiffKind::Added, "[1]")]
+    #[case::removed_field("{a: 1, b: 2}", "{a: 1}", DiffKind::Removed, "b")]
+    #[case::added_field("{a: 1}", "{a: 1, 'b c': 2}", DiffKind::Added, "'b c'")]
+    #[case::repeated_field("{a: 1, a: 2}", "{a: 2, a: 3}", DiffKind::Value, "a")]
+    #[case::extra_repeated_field("{a: 1}", "{a: 2, a: 1}", DiffKind::Added, "a")]
+    fn single_difference(
+        #[case] a: &str,
+        #[case] b: &str,
+        #[case] expected_kind: DiffKind,
+        #[case] expected_path: &str,
+    ) -> IonResult<()> {
+        assert_eq!(
+            kinds_and_paths(a, b)?,
+            vec![(expected_kind, expected_path.to_string())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn annotations_and_value_differ() -> IonResult<()> {
+        let a = Element::read_one("a::1")?;
+        let b = Element::read_one("b::2")?;
+        let differences = diff(&a, &b);
+        assert_eq!(differences.len(), 2);
+        assert_eq!(differences[0].kind(), DiffKind::Annotations);
+        assert_eq!(differences[1].kind(), DiffKind::Value);
+        assert_eq!(differences[1].left(), Some(&a));
+        assert_eq!(differences[1].right(), Some(&b));
+        Ok(())
+    }
+
+    #[test]
+    fn diff_streams() -> IonResult<()> {
+        let mut a = Reader::new(AnyEncoding, "1 {a: 2} 3 4")?;
+        let mut b = Reader::new(AnyEncoding, "1 {a: 5} 3")?;
+        let differences: Vec<_> = diff_readers(&mut a, &mut b)?
+            .into_iter()
+            .map(|entry| (entry.kind(), entry.path().to_string()))
+            .collect();
+        assert_eq!(
+            differences,
+            vec![
+                (DiffKind::Value, "[1].a".to_string()),
+                (DiffKind::Removed, "[3]".to_string()),
+            ]
+        );
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod diff;
 mod ion_data_hash;
 mod ion_eq;
 mod ion_ord;
@@ -13,24 +13,35 @@
 //!   let elem = Element::read_one(b"\"hello world\"")?;
 //!   let digest = ion_hash::sha256(&elem);
 //!   println!("{:?}", digest);
+//!
+//!   // Struct fields are hashed independently of their order.
+//!   let a = Element::read_one("{name: \"widget\", price: 1.50}")?;
+//!   let b = Element::read_one("{price: 1.50, name: \"widget\"}")?;
+//!   assert_eq!(
+//!       ion_hash::hash_element(&a, sha2::Sha256::default())?,
+//!       ion_hash::hash_element(&b, sha2::Sha256::default())?
+//!   );
 //! # Ok(())
 //! # }
 //! # #[cfg(not(feature = "sha2"))]
 //! # fn main() {}
 //! ```
 
-use digest::{self, FixedOutput, Reset, Update};
+use digest::{self, FixedOutput, Output, Reset, Update};
 
 use crate::element::Element;
+use crate::lazy::decoder::Decoder;
+use crate::lazy::value::LazyValue;
 use crate::IonResult;
 use element_hasher::ElementHasher;
 
 mod element_hasher;
 mod representation;
 mod type_qualifier;
+mod value_digests;
+
+pub use value_digests::{DigestKind, DigestedValue, ValueDigests};
 
-#[cfg(feature = "sha2")]
-use digest::Output;
 #[cfg(feature = "sha2")]
 use sha2::Sha256;
 /// Utility to hash an [`Element`] using SHA-256 as the hash function.
@@ -39,6 +50,32 @@ pub fn sha256(elem: &Element) -> IonResult<Output<Sha256>> {
     Sha256::hash_element(elem)
 }
 
+/// Returns the Ion Hash of `elem`, using `digest` to compute the outermost hash.
+///
+/// `digest` is normally a newly constructed hasher. The hashes of nested struct fields are
+/// always computed using `D::default()`, as the spec requires each field to be hashed on its own.
+pub fn hash_element<D>(elem: &Element, digest: D) -> IonResult<Output<D>>
+where
+    D: Update + FixedOutput + Reset + Clone + Default,
+{
+    ElementHasher::new(digest).hash_element(elem)
+}
+
+/// Returns the Ion Hash of a value being read from a stream. See [`hash_element`].
+///
+/// The value is materialized before it is hashed, so its hash does not depend on how it was
+/// encoded: the same value read from Ion text and from Ion binary produces the same hash.
+pub fn hash_lazy_value<Encoding, D>(
+    value: LazyValue<'_, Encoding>,
+    digest: D,
+) -> IonResult<Output<D>>
+where
+    Encoding: Decoder,
+    D: Update + FixedOutput + Reset + Clone + Default,
+{
+    hash_element(&Element::try_from(value)?, digest)
+}
+
 /// Bytes markers as per the spec.
 struct Markers;
 impl Markers {
@@ -75,6 +112,65 @@ where
     /// Provides Ion hash over arbitrary [`Element`] instances with a given
     /// [`Digest`](digest::Digest) algorithm.
     fn hash_element(elem: &Element) -> IonResult<Self::Output> {
-        ElementHasher::new(D::default()).hash_element(elem)
+        hash_element(elem, D::default())
+    }
+}
+
+#[cfg(all(test, feature = "sha2"))]
+mod tests {
+    use sha2::Sha256;
+
+    use super::*;
+    use crate::element::element_writer::ElementWriter;
+    use crate::lazy::encoder::writer::Writer;
+    use crate::{v1_0, AnyEncoding, Reader};
+
+    fn hash(text: &str) -> IonResult<Output<Sha256>> {
+        hash_element(&Element::read_one(text)?, Sha256::default())
+    }
+
+    #[test]
+    fn struct_field_order_does_not_matter() -> IonResult<()> {
+        assert_eq!(hash("{a: 1, b: [2], a: 3}")?, hash("{a: 3, b: [2], a: 1}")?);
+        assert_ne!(hash("{a: 1, b: 2}")?, hash("{a: 2, b: 1}")?);
+        Ok(())
+    }
+
+    #[test]
+    fn annotations_are_hashed_in_order() -> IonResult<()> {
+        assert_ne!(hash("1")?, hash("a::1")?);
+        assert_ne!(hash("a::b::1")?, hash("b::a::1")?);
+        assert_eq!(hash("a::b::1")?, hash("'a'::'b'::1")?);
+        Ok(())
+    }
+
+    #[test]
+    fn hash_element_matches_ion_hasher() -> IonResult<()> {
+        let element = Element::read_one("foo::{bar: (baz 1.50 2024T)}")?;
+        assert_eq!(
+            hash_element(&element, Sha256::default())?,
+            Sha256::hash_element(&element)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn lazy_values_hash_like_elements() -> IonResult<()> {
+        let text = r#"foo::{a: 1, b: "two"} [1.0, 2e0] $0"#;
+        let elements = Element::read_all(text)?;
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer.write_elements(&elements)?;
+        let binary = writer.close()?;
+
+        let mut text_reader = Reader::new(AnyEncoding, text)?;
+        let mut binary_reader = Reader::new(AnyEncoding, binary.as_slice())?;
+        for element in elements.iter() {
+            let expected = hash_element(element, Sha256::default())?;
+            let text_value = text_reader.expect_next()?;
+            assert_eq!(hash_lazy_value(text_value, Sha256::default())?, expected);
+            let binary_value = binary_reader.expect_next()?;
+            assert_eq!(hash_lazy_value(binary_value, Sha256::default())?, expected);
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,250 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! Provides [`ValueDigests`], an iterator that pairs each top-level value in a stream with a
+//! digest of that value.
+
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+use digest::{FixedOutput, Output, Reset, Update};
+
+use crate::element::Element;
+use crate::ion_hash::IonHasher;
+use crate::lazy::any_encoding::AnyEncoding;
+use crate::lazy::reader::Reader;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::IonResult;
+
+/// The data from which a [`DigestedValue`]'s digest was computed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DigestKind {
+    /// The digest was computed over the bytes that encoded the value (including its annotations)
+    /// in the input stream.
+    ///
+    /// This does not require the value to be re-encoded, but equal values only produce equal
+    /// digests if they were written the same way. For example, `{a:1}` and `{ a: 1 }` produce
+    /// different digests, as do binary values encoded using different symbol tables.
+    EncodedSpan,
+    /// The digest is the value's [Ion Hash](https://amazon-ion.github.io/ion-hash/docs/spec.html),
+    /// which is the same for equal values regardless of how they were encoded.
+    IonHash,
+}
+
+/// A top-level value read by [`ValueDigests`] along with its digest.
+pub struct DigestedValue<D: Update + FixedOutput + Reset + Clone + Default> {
+    element: Element,
+    digest: Output<D>,
+    kind: DigestKind,
+}
+
+impl<D: Update + FixedOutput + Reset + Clone + Default> DigestedValue<D> {
+    pub fn element(&self) -> &Element {
+        &self.element
+    }
+
+    pub fn digest(&self) -> &Output<D> {
+        &self.digest
+    }
+
+    /// Returns the data from which the digest was computed. This may differ from the kind that
+    /// was requested; see [`ValueDigests::new`].
+    pub fn kind(&self) -> DigestKind {
+        self.kind
+    }
+
+    pub fn into_parts(self) -> (Element, Output<D>) {
+        (self.element, self.digest)
+    }
+}
+
+// These are implemented by hand because deriving them would require `D` itself to implement
+// each trait, even though `D` is only used to name the type of the digest.
+impl<D: Update + FixedOutput + Reset + Clone + Default> Clone for DigestedValue<D> {
+    fn clone(&self) -> Self {
+        Self {
+            element: self.element.clone(),
+            digest: self.digest.clone(),
+            kind: self.kind,
+        }
+    }
+}
+
+impl<D: Update + FixedOutput + Reset + Clone + Default> Debug for DigestedValue<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DigestedValue")
+            .field("element", &self.element)
+            .field("digest", &self.digest)
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+impl<D: Update + FixedOutput + Reset + Clone + Default> PartialEq for DigestedValue<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.element == other.element && self.digest == other.digest && self.kind == other.kind
+    }
+}
+
+/// An iterator over the top-level values of an Ion data source that materializes each one as an
+/// [`Element`] and computes its digest using `D`.
+///
+/// ```rust
+/// use ion_rs::IonResult;
+/// use ion_rs::ion_hash::{DigestKind, ValueDigests};
+///
+/// # #[cfg(feature = "sha2")]
+/// # fn main() -> IonResult<()> {
+/// use sha2::Sha256;
+///
+/// let data = r#"{a: 1} "hello" {a: 1}"#;
+/// let digests = ValueDigests::<Sha256, _>::new(data, DigestKind::EncodedSpan)?
+///     .map(|value| value.map(|value| value.digest().clone()))
+///     .collect::<IonResult<Vec<_>>>()?;
+/// assert_ne!(digests[0], digests[1]);
+/// assert_eq!(digests[0], digests[2]);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "sha2"))]
+/// # fn main() {}
+/// ```
+pub struct ValueDigests<D, Input: IonInput> {
+    reader: Reader<AnyEncoding, Input>,
+    kind: DigestKind,
+    is_done: bool,
+    spooky: PhantomData<D>,
+}
+
+impl<D, Input> ValueDigests<D, Input>
+where
+    D: Update + FixedOutput + Reset + Clone + Default,
+    Input: IonInput,
+{
+    /// Constructs an iterator that computes a digest of each top-level value in `source`.
+    ///
+    /// If `kind` is [`DigestKind::EncodedSpan`], values that do not have an encoding of their
+    /// own (like those produced by an Ion 1.1 e-expression) fall back to using
+    /// [`DigestKind::IonHash`]. Callers can check which was used with [`DigestedValue::kind`].
+    pub fn new(source: Input, kind: DigestKind) -> IonResult<Self> {
+        Ok(Self {
+            reader: Reader::new(AnyEncoding, source)?,
+            kind,
+            is_done: false,
+            spooky: PhantomData,
+        })
+    }
+
+    fn next_value(&mut self) -> IonResult<Option<DigestedValue<D>>> {
+        let Some(value) = self.reader.next()? else {
+            return Ok(None);
+        };
+        let span = match self.kind {
+            DigestKind::EncodedSpan => value.expanded().span(),
+            DigestKind::IonHash => None,
+        };
+        let element = Element::try_from(value)?;
+        let (digest, kind) = match span {
+            Some(span) => (
+                D::default().chain(span.bytes()).finalize_fixed(),
+                DigestKind::EncodedSpan,
+            ),
+            None => (D::hash_element(&element)?, DigestKind::IonHash),
+        };
+        Ok(Some(DigestedValue {
+            element,
+            digest,
+            kind,
+        }))
+    }
+}
+
+impl<D, Input> Iterator for ValueDigests<D, Input>
+where
+    D: Update + FixedOutput + Reset + Clone + Default,
+    Input: IonInput,
+{
+    type Item = IonResult<DigestedValue<D>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+        let result = self.next_value().transpose();
+        // The reader cannot resume after an error.
+        self.is_done = !matches!(result, Some(Ok(_)));
+        result
+    }
+}
+
+#[cfg(all(test, feature = "sha2"))]
+mod tests {
+    use sha2::{Digest, Sha256};
+
+    use super::*;
+    use crate::element::element_writer::ElementWriter;
+    use crate::lazy::encoder::writer::Writer;
+    use crate::{ion_hash, v1_0};
+
+    fn digests(data: impl IonInput, kind: DigestKind) -> IonResult<Vec<DigestedValue<Sha256>>> {
+        ValueDigests::<Sha256, _>::new(data, kind)?.collect()
+    }
+
+    #[test]
+    fn encoded_span_digests() -> IonResult<()> {
+        let data = r#"foo::{a: 1}   "hello"  foo::{a: 1} {a:1}"#;
+        let values = digests(data, DigestKind::EncodedSpan)?;
+        assert_eq!(values.len(), 4);
+        assert!(values.iter().all(|v| v.kind() == DigestKind::EncodedSpan));
+        assert_eq!(
+            values[0].digest(),
+            &Sha256::digest(br#"foo::{a: 1}"#),
+            "digest should cover the value's annotations"
+        );
+        assert_eq!(values[1].digest(), &Sha256::digest(br#""hello""#));
+        assert_eq!(values[0].digest(), values[2].digest());
+        // Equal values written differently have different span digests
+        assert_ne!(values[2].digest(), values[3].digest());
+        Ok(())
+    }
+
+    #[test]
+    fn ion_hash_digests() -> IonResult<()> {
+        let text = r#"foo::{a: 1} "hello" {a:1}"#;
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer.write_elements(&Element::read_all(text)?)?;
+        let binary = writer.close()?;
+
+        let text_values = digests(text, DigestKind::IonHash)?;
+        let binary_values = digests(binary.as_slice(), DigestKind::IonHash)?;
+        assert_eq!(text_values, binary_values);
+        for value in &text_values {
+            assert_eq!(value.kind(), DigestKind::IonHash);
+            assert_eq!(value.digest(), &ion_hash::sha256(value.element())?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn digests_end_after_malformed_input() -> IonResult<()> {
+        let results: Vec<_> =
+            ValueDigests::<Sha256, _>::new("1 2 {foo: ] 3", DigestKind::EncodedSpan)?.collect();
+        assert_eq!(results.len(), 3);
+        assert!(results[2].is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn macro_output_falls_back_to_ion_hash() -> IonResult<()> {
+        let data = r#"$ion_1_1 1 (:values 2) (:make_string "a" "b")"#;
+        let values = digests(data, DigestKind::EncodedSpan)?;
+        assert_eq!(values.len(), 3);
+        // Arguments passed through by a macro are still encoded in the stream...
+        assert_eq!(values[1].kind(), DigestKind::EncodedSpan);
+        assert_eq!(values[1].digest(), &Sha256::digest(b"2"));
+        // ...but values that the macro constructs are not.
+        assert_eq!(values[2].kind(), DigestKind::IonHash);
+        assert_eq!(values[2].digest(), &ion_hash::sha256(&Element::from("ab"))?);
+        Ok(())
+    }
+}
@@ -0,0 +1,279 @@
+//! Expressions that address a value nested within a container, like `a.b[2].c`.
+
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::result::IonFailure;
+use crate::{IonError, IonResult};
+
+/// A single step in an [`IonPath`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PathComponent {
+    /// Selects the field of a struct with the specified name.
+    Field(String),
+    /// Selects the child value of a list or s-expression at the specified (zero-based) index.
+    Index(usize),
+}
+
+/// A sequence of field names and indexes that addresses a value nested within a container.
+///
+/// Paths can be constructed programmatically:
+/// ```
+/// use ion_rs::IonPath;
+/// let path = IonPath::new().field("a").field("b").index(2).field("c");
+/// assert_eq!(path.to_string(), "a.b[2].c");
+/// ```
+///
+/// or parsed from text in which field names are separated by `.` and indexes are surrounded by
+/// `[]`. Field names that contain any characters besides ASCII letters, digits, `_`, and `$` must
+/// be enclosed in single quotes; within quotes, `\'` and `\\` represent `'` and `\`.
+/// ```
+/// # use ion_rs::IonResult;
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::{IonPath, PathComponent};
+/// let path = IonPath::parse("orders[0].'line items'.sku")?;
+/// assert_eq!(
+///     path.components(),
+///     &[
+///         PathComponent::Field("orders".to_string()),
+///         PathComponent::Index(0),
+///         PathComponent::Field("line items".to_string()),
+///         PathComponent::Field("sku".to_string()),
+///     ]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// An empty path addresses the value on which it is used.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IonPath {
+    components: Vec<PathComponent>,
+}
+
+impl IonPath {
+    /// Constructs an empty path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of this path with an additional step selecting the field `name`.
+    pub fn field(mut self, name: impl Into<String>) -> Self {
+        self.components.push(PathComponent::Field(name.into()));
+        self
+    }
+
+    /// Returns a copy of this path with an additional step selecting the child value at `index`.
+    pub fn index(mut self, index: usize) -> Self {
+        self.components.push(PathComponent::Index(index));
+        self
+    }
+
+    pub fn components(&self) -> &[PathComponent] {
+        &self.components
+    }
+
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Parses a path expression like `a.b[2].c`. See [`IonPath`] for a description of the syntax.
+    pub fn parse(text: &str) -> IonResult<Self> {
+        let mut components = Vec::new();
+        let mut remaining = text;
+        while let Some(first) = remaining.chars().next() {
+            let (component, rest) = match first {
+                '[' => Self::parse_index(text, &remaining[1..])?,
+                '.' if !components.is_empty() => Self::parse_field(text, &remaining[1..])?,
+                _ if components.is_empty() => Self::parse_field(text, remaining)?,
+                _ => return Self::error(text, "expected `.` or `[`"),
+            };
+            components.push(component);
+            remaining = rest;
+        }
+        Ok(Self { components })
+    }
+
+    fn parse_index<'a>(text: &str, input: &'a str) -> IonResult<(PathComponent, &'a str)> {
+        let Some((digits, rest)) = input.split_once(']') else {
+            return Self::error(text, "found an unterminated `[`");
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Self::error(text, "indexes must be non-negative integers");
+        }
+        let index = usize::from_str(digits).or_else(|_| Self::error(text, "index is too large"))?;
+        Ok((PathComponent::Index(index), rest))
+    }
+
+    fn parse_field<'a>(text: &str, input: &'a str) -> IonResult<(PathComponent, &'a str)> {
+        if let Some(quoted) = input.strip_prefix('\'') {
+            let mut name = String::new();
+            let mut chars = quoted.char_indices();
+            while let Some((index, c)) = chars.next() {
+                match c {
+                    '\'' => {
+                        return Ok((PathComponent::Field(name), &quoted[index + 1..]));
+                    }
+                    '\\' => match chars.next() {
+                        Some((_, escaped @ ('\'' | '\\'))) => name.push(escaped),
+                        _ => return Self::error(text, "only `\\'` and `\\\\` may be escaped"),
+                    },
+                    c => name.push(c),
+                }
+            }
+            return Self::error(text, "found an unterminated quoted field name");
+        }
+        let length = input
+            .find(|c: char| !Self::is_unquoted_field_char(c))
+            .unwrap_or(input.len());
+        if length == 0 {
+            return Self::error(text, "expected a field name");
+        }
+        let (name, rest) = input.split_at(length);
+        Ok((PathComponent::Field(name.to_owned()), rest))
+    }
+
+    fn is_unquoted_field_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == '_' || c == '$'
+    }
+
+    fn error<T>(text: &str, description: &str) -> IonResult<T> {
+        IonResult::illegal_operation(format!("invalid path '{text}': {description}"))
+    }
+}
+
+impl Display for IonPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (position, component) in self.components.iter().enumerate() {
+            match component {
+                PathComponent::Index(index) => write!(f, "[{index}]")?,
+                PathComponent::Field(name) => {
+                    if position > 0 {
+                        write!(f, ".")?;
+                    }
+                    if !name.is_empty() && name.chars().all(Self::is_unquoted_field_char) {
+                        write!(f, "{name}")?;
+                    } else {
+                        let escaped = name.replace('\\', "\\\\").replace('\'', "\\'");
+                        write!(f, "'{escaped}'")?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for IonPath {
+    type Err = IonError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl TryFrom<&str> for IonPath {
+    type Error = IonError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::parse(text)
+    }
+}
+
+impl FromIterator<PathComponent> for IonPath {
+    fn from_iter<T: IntoIterator<Item = PathComponent>>(iter: T) -> Self {
+        Self {
+            components: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Types that can be used to address a nested value: an [`IonPath`] or path text to be parsed.
+pub trait ToIonPath {
+    fn to_ion_path(&self) -> IonResult<Cow<'_, IonPath>>;
+}
+
+impl ToIonPath for IonPath {
+    fn to_ion_path(&self) -> IonResult<Cow<'_, IonPath>> {
+        Ok(Cow::Borrowed(self))
+    }
+}
+
+impl ToIonPath for str {
+    fn to_ion_path(&self) -> IonResult<Cow<'_, IonPath>> {
+        IonPath::parse(self).map(Cow::Owned)
+    }
+}
+
+impl ToIonPath for String {
+    fn to_ion_path(&self) -> IonResult<Cow<'_, IonPath>> {
+        self.as_str().to_ion_path()
+    }
+}
+
+impl<T: ToIonPath + ?Sized> ToIonPath for &T {
+    fn to_ion_path(&self) -> IonResult<Cow<'_, IonPath>> {
+        (**self).to_ion_path()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::empty("", IonPath::new())]
+    #[case::field("a", IonPath::new().field("a"))]
+    #[case::fields("a.b.c", IonPath::new().field("a").field("b").field("c"))]
+    #[case::index("[3]", IonPath::new().index(3))]
+    #[case::mixed("a.b[2].c", IonPath::new().field("a").field("b").index(2).field("c"))]
+    #[case::consecutive_indexes("a[0][1]", IonPath::new().field("a").index(0).index(1))]
+    #[case::symbol_id_like("$10._x", IonPath::new().field("$10").field("_x"))]
+    #[case::quoted("'a.b'.c", IonPath::new().field("a.b").field("c"))]
+    #[case::quoted_after_dot("x.'a b'", IonPath::new().field("x").field("a b"))]
+    #[case::escapes(r"'it\'s\\'", IonPath::new().field(r"it's\"))]
+    #[case::empty_name("''", IonPath::new().field(""))]
+    fn parse(#[case] text: &str, #[case] expected: IonPath) -> IonResult<()> {
+        let path = IonPath::parse(text)?;
+        assert_eq!(path, expected);
+        // The display format can be parsed to produce an equivalent path.
+        assert_eq!(IonPath::parse(&path.to_string())?, path);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::leading_dot(".a")]
+    #[case::double_dot("a..b")]
+    #[case::trailing_dot("a.")]
+    #[case::missing_dot("a'b'")]
+    #[case::unterminated_index("a[1")]
+    #[case::empty_index("a[]")]
+    #[case::negative_index("a[-1]")]
+    #[case::field_in_brackets("a[b]")]
+    #[case::unterminated_quote("'abc")]
+    #[case::bad_escape(r"'\n'")]
+    #[case::whitespace("a. b")]
+    fn parse_invalid(#[case] text: &str) {
+        assert!(
+            IonPath::parse(text).is_err(),
+            "parsed invalid path '{text}'"
+        );
+    }
+
+    #[test]
+    fn display() {
+        let path = IonPath::new()
+            .index(0)
+            .field("a")
+            .field("hello world")
+            .field("")
+            .index(12);
+        assert_eq!(path.to_string(), "[0].a.'hello world'.''[12]");
+    }
+}
@@ -0,0 +1,466 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use crate::element::Value;
+use crate::ion_path::{IonPath, ToIonPath};
+use crate::lazy::decoder::Decoder;
+use crate::lazy::predicate::{compare_values, to_decimal, to_f64};
+use crate::lazy::reader::Reader;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::value::LazyValue;
+use crate::result::IonFailure;
+use crate::{Decimal, Element, IonData, IonResult, IonType};
+
+/// A statistic that an [`Aggregator`] can compute over the values found at a path.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AggregateKind {
+    /// The number of values.
+    Count,
+    /// The lowest value.
+    Min,
+    /// The highest value.
+    Max,
+    /// The sum of the numeric values.
+    Sum,
+    /// The number of distinct values.
+    DistinctCount,
+}
+
+impl AggregateKind {
+    /// Returns the name of the field in which an [`Aggregator`] reports this statistic.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AggregateKind::Count => "count",
+            AggregateKind::Min => "min",
+            AggregateKind::Max => "max",
+            AggregateKind::Sum => "sum",
+            AggregateKind::DistinctCount => "distinct_count",
+        }
+    }
+}
+
+impl Display for AggregateKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Computes statistics over the values found at one or more paths in each top-level value of a
+/// stream.
+///
+/// [`aggregate`](Self::aggregate) reads the stream once, navigating to each path lazily, and
+/// returns a struct of the form:
+/// ```ion
+/// {
+///     count: 3,               // The number of top-level values that were read
+///     paths: {
+///         price: {            // One field for each path, named using its text
+///             min: 1.50,      // One field for each statistic, named by `AggregateKind::name`
+///             max: 9.25,
+///         },
+///     }
+/// }
+/// ```
+///
+/// Missing and null values are ignored by every statistic. Statistics that have no values to
+/// report (for example, the `min` of a path that was never found) are `null`.
+///
+/// * `count` is the number of values found at the path.
+/// * `min` and `max` order ints, floats, and decimals by their numeric value, strings and symbols by
+///   their text, and timestamps by the instant they represent. Values that cannot be compared this
+///   way (for example, an int and a string) are ordered using [`IonData`]'s ordering, which sorts
+///   them by type.
+/// * `sum` adds the ints, floats, and decimals found at the path and ignores other values. The sum
+///   is a float if any float was found, a decimal if any decimal was found, and an int otherwise.
+///   If an int or decimal sum overflows, `aggregate` returns an `Err`.
+/// * `distinct_count` is the number of distinct values found at the path, where values are
+///   compared using [`IonData`]'s equivalence.
+///
+/// Each statistic uses a constant amount of memory except for `distinct_count`, which stores a
+/// 64-bit hash of each distinct value. Because it compares hashes, the count may be slightly low
+/// in the unlikely event of a hash collision.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{Aggregator, Element, Reader};
+/// use ion_rs::v1_0::Text;
+///
+/// let mut reader = Reader::new(Text, r#"
+///     {item: "widget", price: 5, color: red}
+///     {item: "gadget", price: 25, color: blue}
+///     {item: "gizmo", color: red}
+/// "#)?;
+/// let summary = Aggregator::new()
+///     .min("price")?
+///     .max("price")?
+///     .sum("price")?
+///     .distinct_count("color")?
+///     .aggregate(&mut reader)?;
+///
+/// let expected = Element::read_one(r#"
+///     {
+///         count: 3,
+///         paths: {
+///             price: {min: 5, max: 25, sum: 30},
+///             color: {distinct_count: 2},
+///         }
+///     }
+/// "#)?;
+/// assert_eq!(summary, expected);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Aggregator {
+    paths: Vec<(IonPath, Vec<AggregateKind>)>,
+}
+
+impl Aggregator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a statistic to compute over the values found at `path`. Statistics are reported in
+    /// the order in which they were added; adding the same statistic twice has no effect.
+    pub fn with(mut self, kind: AggregateKind, path: impl ToIonPath) -> IonResult<Self> {
+        let path = path.to_ion_path()?;
+        match self
+            .paths
+            .iter_mut()
+            .find(|(existing, _)| *existing == *path)
+        {
+            Some((_, kinds)) if kinds.contains(&kind) => {}
+            Some((_, kinds)) => kinds.push(kind),
+            None => self.paths.push((path.into_owned(), vec![kind])),
+        }
+        Ok(self)
+    }
+
+    /// Adds a count of the values found at `path`.
+    pub fn count(self, path: impl ToIonPath) -> IonResult<Self> {
+        self.with(AggregateKind::Count, path)
+    }
+
+    /// Adds the lowest value found at `path`.
+    pub fn min(self, path: impl ToIonPath) -> IonResult<Self> {
+        self.with(AggregateKind::Min, path)
+    }
+
+    /// Adds the highest value found at `path`.
+    pub fn max(self, path: impl ToIonPath) -> IonResult<Self> {
+        self.with(AggregateKind::Max, path)
+    }
+
+    /// Adds the sum of the numeric values found at `path`.
+    pub fn sum(self, path: impl ToIonPath) -> IonResult<Self> {
+        self.with(AggregateKind::Sum, path)
+    }
+
+    /// Adds a count of the distinct values found at `path`.
+    pub fn distinct_count(self, path: impl ToIonPath) -> IonResult<Self> {
+        self.with(AggregateKind::DistinctCount, path)
+    }
+
+    /// Reads each of the remaining top-level values in `reader` and returns a struct containing
+    /// the configured statistics. See [`Aggregator`] for a description of its contents.
+    pub fn aggregate<D: Decoder, Input: IonInput>(
+        &self,
+        reader: &mut Reader<D, Input>,
+    ) -> IonResult<Element> {
+        let mut accumulators: Vec<Vec<Accumulator>> = self
+            .paths
+            .iter()
+            .map(|(_, kinds)| kinds.iter().copied().map(Accumulator::new).collect())
+            .collect();
+        let mut num_values: u64 = 0;
+        while let Some(value) = reader.next()? {
+            num_values += 1;
+            for ((path, _), accumulators) in self.paths.iter().zip(&mut accumulators) {
+                let Some(target) = value.get_path(path)? else {
+                    continue;
+                };
+                if target.is_null() {
+                    continue;
+                }
+                // Only read the value if one of the statistics needs it.
+                let mut element = None;
+                for accumulator in accumulators.iter_mut() {
+                    accumulator.add(path, target, &mut element)?;
+                }
+            }
+        }
+
+        let mut paths = Element::struct_builder();
+        for ((path, _), accumulators) in self.paths.iter().zip(accumulators) {
+            let mut statistics = Element::struct_builder();
+            for accumulator in accumulators {
+                statistics = statistics.with_field(accumulator.kind().name(), accumulator.finish());
+            }
+            paths = paths.with_field(path.to_string(), statistics.build());
+        }
+        Ok(Element::struct_builder()
+            .with_field("count", num_values)
+            .with_field("paths", paths.build())
+            .build()
+            .into())
+    }
+}
+
+/// The running state of a single statistic.
+enum Accumulator {
+    Count(u64),
+    Min(Option<Element>),
+    Max(Option<Element>),
+    Sum(Option<Sum>),
+    DistinctCount(HashSet<u64>),
+}
+
+impl Accumulator {
+    fn new(kind: AggregateKind) -> Self {
+        match kind {
+            AggregateKind::Count => Accumulator::Count(0),
+            AggregateKind::Min => Accumulator::Min(None),
+            AggregateKind::Max => Accumulator::Max(None),
+            AggregateKind::Sum => Accumulator::Sum(None),
+            AggregateKind::DistinctCount => Accumulator::DistinctCount(HashSet::new()),
+        }
+    }
+
+    fn kind(&self) -> AggregateKind {
+        match self {
+            Accumulator::Count(_) => AggregateKind::Count,
+            Accumulator::Min(_) => AggregateKind::Min,
+            Accumulator::Max(_) => AggregateKind::Max,
+            Accumulator::Sum(_) => AggregateKind::Sum,
+            Accumulator::DistinctCount(_) => AggregateKind::DistinctCount,
+        }
+    }
+
+    /// Adds the non-null `value` to the statistic. `element` caches the materialized value so that
+    /// it is read at most once for all of the statistics at a path.
+    fn add<D: Decoder>(
+        &mut self,
+        path: &IonPath,
+        value: LazyValue<'_, D>,
+        element: &mut Option<Element>,
+    ) -> IonResult<()> {
+        if let Accumulator::Count(count) = self {
+            *count += 1;
+            return Ok(());
+        }
+        let element = match element {
+            Some(element) => element,
+            None => element.insert(Element::try_from(value)?),
+        };
+        match self {
+            Accumulator::Count(_) => unreachable!("counts are handled above"),
+            Accumulator::Min(min) => keep_if(min, element, Ordering::Less),
+            Accumulator::Max(max) => keep_if(max, element, Ordering::Greater),
+            Accumulator::Sum(sum) => {
+                let value = element.value();
+                if !matches!(value, Value::Int(_) | Value::Float(_) | Value::Decimal(_)) {
+                    return Ok(());
+                }
+                let Some(new_sum) = sum.unwrap_or(Sum::Int(0)).add(value) else {
+                    return IonResult::illegal_operation(format!(
+                        "the sum of the values at '{path}' overflowed"
+                    ));
+                };
+                *sum = Some(new_sum);
+            }
+            Accumulator::DistinctCount(hashes) => {
+                let mut hasher = DefaultHasher::new();
+                IonData::from(&*element).hash(&mut hasher);
+                hashes.insert(hasher.finish());
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Element {
+        match self {
+            Accumulator::Count(count) => count.into(),
+            Accumulator::Min(value) | Accumulator::Max(value) => {
+                value.unwrap_or_else(|| Element::null(IonType::Null))
+            }
+            Accumulator::Sum(Some(Sum::Int(sum))) => sum.into(),
+            Accumulator::Sum(Some(Sum::Decimal(sum))) => sum.into(),
+            Accumulator::Sum(Some(Sum::Float(sum))) => sum.into(),
+            Accumulator::Sum(None) => Element::null(IonType::Null),
+            Accumulator::DistinctCount(hashes) => (hashes.len() as u64).into(),
+        }
+    }
+}
+
+/// Replaces `current` with `candidate` if there is no current value or if `candidate` compares to
+/// it with the ordering `keep`.
+fn keep_if(current: &mut Option<Element>, candidate: &Element, keep: Ordering) {
+    let should_keep = match current {
+        None => true,
+        Some(current) => {
+            compare_values(candidate.value(), current.value())
+                .unwrap_or_else(|| IonData::from(candidate).cmp(&IonData::from(&*current)))
+                == keep
+        }
+    };
+    if should_keep {
+        *current = Some(candidate.clone());
+    }
+}
+
+/// A running sum, which is widened from int to decimal to float as values of those types are
+/// added.
+#[derive(Copy, Clone, Debug)]
+enum Sum {
+    Int(i128),
+    Decimal(Decimal),
+    Float(f64),
+}
+
+impl Sum {
+    /// Returns the sum of `self` and the numeric `value`, or `None` if the sum overflowed.
+    fn add(self, value: &Value) -> Option<Sum> {
+        let sum = match (self, value) {
+            (Sum::Float(sum), value) => Sum::Float(sum + to_f64(value)?),
+            (Sum::Int(sum), Value::Float(float)) => Sum::Float(sum as f64 + float),
+            (Sum::Decimal(sum), Value::Float(float)) => {
+                Sum::Float(to_f64(&Value::Decimal(sum))? + float)
+            }
+            (Sum::Int(sum), Value::Int(int)) => Sum::Int(sum.checked_add(int.as_i128()?)?),
+            (Sum::Int(sum), value) => {
+                Sum::Decimal(checked_add(Decimal::new(sum, 0), to_decimal(value)?)?)
+            }
+            (Sum::Decimal(sum), value) => Sum::Decimal(checked_add(sum, to_decimal(value)?)?),
+        };
+        Some(sum)
+    }
+}
+
+/// Adds two decimals, returning `None` if the result's coefficient would not fit in an `i128`.
+fn checked_add(left: Decimal, right: Decimal) -> Option<Decimal> {
+    let exponent = left.exponent().min(right.exponent());
+    // Scales the coefficient of `decimal` so that it can be used with `exponent`.
+    let scaled_coefficient = |decimal: Decimal| -> Option<i128> {
+        let coefficient = decimal.coefficient();
+        let magnitude = i128::try_from(coefficient.magnitude().as_u128()?).ok()?;
+        let scale = u32::try_from(decimal.exponent() - exponent).ok()?;
+        let scaled = magnitude.checked_mul(10i128.checked_pow(scale)?)?;
+        Some(if coefficient.is_negative() {
+            -scaled
+        } else {
+            scaled
+        })
+    };
+    let coefficient = scaled_coefficient(left)?.checked_add(scaled_coefficient(right)?)?;
+    Some(Decimal::new(coefficient, exponent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::encoding::Encoding;
+    use crate::{v1_0, AnyEncoding, IonError};
+
+    fn aggregate(aggregator: &Aggregator, data: &str) -> IonResult<Element> {
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        aggregator.aggregate(&mut reader)
+    }
+
+    fn all_statistics(path: &str) -> IonResult<Aggregator> {
+        Aggregator::new()
+            .count(path)?
+            .min(path)?
+            .max(path)?
+            .sum(path)?
+            .distinct_count(path)
+    }
+
+    #[test]
+    fn aggregate_numbers() -> IonResult<()> {
+        let data = "{n: 3} {n: 1.50} {n: null} {m: 4} {n: 3} {n: -2}";
+        // Missing and null values are ignored.
+        let summary = aggregate(&all_statistics("n")?, data)?;
+        let expected = Element::read_one(
+            "{count: 6, paths: {n: {count: 4, min: -2, max: 3, sum: 5.50, distinct_count: 3}}}",
+        )?;
+        assert_eq!(summary, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn sum_widens_to_float() -> IonResult<()> {
+        let summary = aggregate(&Aggregator::new().sum("")?, "1 2.5 0.5e0 foo")?;
+        assert_eq!(
+            summary,
+            Element::read_one("{count: 4, paths: {'': {sum: 4e0}}}")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn aggregate_text_and_nested_paths() -> IonResult<()> {
+        let data = r#"
+            {a: {b: "pear"}, tags: [x, y]}
+            {a: {b: apple}, tags: [x]}
+            {a: {b: "zucchini"}, tags: x}
+            {a: 5}
+        "#;
+        let aggregator = Aggregator::new()
+            .min("a.b")?
+            .max("a.b")?
+            .distinct_count("tags[0]")?
+            .count("tags")?;
+        let summary = aggregate(&aggregator, data)?;
+        let expected = Element::read_one(
+            r#"{
+                count: 4,
+                paths: {
+                    'a.b': {min: apple, max: "zucchini"},
+                    'tags[0]': {distinct_count: 1},
+                    tags: {count: 3},
+                }
+            }"#,
+        )?;
+        assert_eq!(summary, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn empty_input() -> IonResult<()> {
+        let summary = aggregate(&all_statistics("n")?, "")?;
+        let expected = Element::read_one(
+            "{count: 0, paths: {n: {count: 0, min: null, max: null, sum: null, distinct_count: 0}}}",
+        )?;
+        assert_eq!(summary, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn aggregate_binary() -> IonResult<()> {
+        let data = v1_0::Binary::encode_all(&Element::read_all("{t: 2024T} {t: 2023-06T} {}")?)?;
+        let mut reader = Reader::new(AnyEncoding, data.as_slice())?;
+        let summary = Aggregator::new()
+            .min("t")?
+            .max("t")?
+            .aggregate(&mut reader)?;
+        let expected = Element::read_one("{count: 3, paths: {t: {min: 2023-06T, max: 2024T}}}")?;
+        assert_eq!(summary, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn sum_overflow_is_an_error() -> IonResult<()> {
+        let data = format!("{} {}", i128::MAX, 1);
+        let result = aggregate(&Aggregator::new().sum("")?, &data);
+        assert!(matches!(result, Err(IonError::IllegalOperation(_))));
+        Ok(())
+    }
+}
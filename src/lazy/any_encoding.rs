@@ -455,11 +455,16 @@ pub struct LazyRawAnyReader<'data> {
 }
 
 impl LazyRawAnyReader<'_> {
-    fn detect_encoding(data: &[u8]) -> IonEncoding {
+    /// Inspects `data` for a binary IVM. If none is found, returns `default_encoding`.
+    fn detect_encoding(data: &[u8], default_encoding: IonEncoding) -> IonEncoding {
         match *data {
             [0xE0, 0x01, 0x00, 0xEA, ..] => IonEncoding::Binary_1_0,
             [0xE0, 0x01, 0x01, 0xEA, ..] => IonEncoding::Binary_1_1,
-            _ => IonEncoding::Text_1_0,
+            // The data ends partway through what may be a binary IVM. A binary reader will report
+            // that the data is incomplete, giving a streaming reader the chance to read more and
+            // then detect the encoding again.
+            [0xE0] | [0xE0, 0x01] | [0xE0, 0x01, 0x00 | 0x01] => IonEncoding::Binary_1_0,
+            _ => default_encoding,
         }
     }
 }
@@ -605,7 +610,7 @@ impl<'data> From<LazyRawBinaryReader_1_1<'data>> for LazyRawAnyReader<'data> {
 
 impl<'data> LazyRawReader<'data, AnyEncoding> for LazyRawAnyReader<'data> {
     fn new(context: EncodingContextRef<'data>, data: &'data [u8], is_final_data: bool) -> Self {
-        let encoding = Self::detect_encoding(data);
+        let encoding = Self::detect_encoding(data, IonEncoding::Text_1_0);
         let state = RawReaderState::new(data, 0, is_final_data, encoding);
         LazyRawAnyReader {
             new_encoding: None,
@@ -618,8 +623,10 @@ impl<'data> LazyRawReader<'data, AnyEncoding> for LazyRawAnyReader<'data> {
         let data = saved_state.data();
         if offset == 0 {
             // If we're at the beginning of the stream, the saved state's encoding may be a
-            // default. We need to inspect the bytes to see if we should override it.
-            saved_state.set_encoding(Self::detect_encoding(data));
+            // default. We need to inspect the bytes to see if we should override it. If the
+            // reader was resumed partway through a stream, its encoding is already known and will
+            // only be overridden by an IVM.
+            saved_state.set_encoding(Self::detect_encoding(data, saved_state.encoding()));
         }
         match saved_state.encoding() {
             IonEncoding::Text_1_0 => LazyRawTextReader_1_0::resume(context, saved_state).into(),
@@ -4,7 +4,10 @@ use crate::lazy::binary::binary_buffer::BinaryBuffer;
 use crate::lazy::binary::raw::value::LazyRawBinaryValue_1_0;
 use crate::lazy::decoder::{HasRange, LazyRawFieldExpr, LazyRawReader};
 use crate::lazy::encoding::BinaryEncoding_1_0;
-use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem, RawStreamItem};
+use crate::lazy::raw_stream_item::{
+    items_including_nops, EndPosition, LazyRawStreamItem, LazyRawStreamItemOrNop, NopPad,
+    RawStreamItem, RawStreamItemOrNop,
+};
 use crate::result::IonFailure;
 use crate::{Encoding, IonResult};
 
@@ -109,6 +112,36 @@ impl<'data> LazyRawBinaryReader_1_0<'data> {
         self.read_value(buffer)
     }
 
+    /// Like [`Self::next`], but if the next item in the stream is preceded by NOP padding, returns
+    /// each NOP pad as its own item instead of skipping over it.
+    #[cfg_attr(not(feature = "experimental-tooling-apis"), allow(dead_code))]
+    pub fn next_including_nops(
+        &mut self,
+    ) -> IonResult<LazyRawStreamItemOrNop<'data, BinaryEncoding_1_0>> {
+        let buffer = self.data.advance_to_next_item()?;
+        if !buffer.is_empty() && buffer.peek_type_descriptor()?.is_nop() {
+            let (nop_pad_length, _remaining) = buffer.read_nop_pad()?;
+            self.data.buffer = buffer;
+            self.data.bytes_to_skip = nop_pad_length;
+            return Ok(RawStreamItemOrNop::NopPad(NopPad::new(
+                buffer.offset(),
+                buffer.bytes_range(0, nop_pad_length),
+            )));
+        }
+        self.next().map(RawStreamItemOrNop::Item)
+    }
+
+    /// Returns an iterator over the remaining items in the stream, including NOP pads, in the
+    /// order they appear. Together, the items' ranges cover every byte of the input. The iterator
+    /// ends when the end of the stream is reached or after it yields an error.
+    #[cfg_attr(not(feature = "experimental-tooling-apis"), allow(dead_code))]
+    pub fn items_including_nops(
+        &mut self,
+    ) -> impl Iterator<Item = IonResult<LazyRawStreamItemOrNop<'data, BinaryEncoding_1_0>>> + '_
+    {
+        items_including_nops(|| self.next_including_nops())
+    }
+
     pub fn context(&self) -> EncodingContextRef<'data> {
         self.context
     }
@@ -252,7 +285,7 @@ impl<'data> DataSource<'data> {
 mod tests {
     use crate::lazy::binary::raw::reader::LazyRawBinaryReader_1_0;
     use crate::lazy::binary::test_utilities::to_binary_ion;
-    use crate::lazy::decoder::{LazyRawFieldName, RawVersionMarker};
+    use crate::lazy::decoder::{HasRange, LazyRawFieldName, RawVersionMarker};
     use crate::lazy::raw_stream_item::RawStreamItem;
     use crate::raw_symbol_ref::AsRawSymbolRef;
     use crate::{EncodingContext, IonResult, IonType, RawSymbolRef};
@@ -401,6 +434,66 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn nop_pads_as_items() -> IonResult<()> {
+        let data: Vec<u8> = vec![
+            0xe0, 0x01, 0x00, 0xea, // IVM
+            0x00, // 1-byte NOP
+            0x01, 0xff, // 2-byte NOP
+            0x0f, // null
+            0x02, 0xff, 0xff, // 3-byte NOP
+        ];
+
+        let context = EncodingContext::empty();
+        let mut reader = LazyRawBinaryReader_1_0::new(context.get_ref(), &data);
+        let mut ranges = Vec::new();
+        loop {
+            let item = reader.next_including_nops()?;
+            ranges.push((item.nop_pad().is_some(), item.range()));
+            if matches!(item.item(), Some(RawStreamItem::EndOfStream(_))) {
+                break;
+            }
+        }
+        assert_eq!(
+            ranges,
+            vec![
+                (false, 0..4),
+                (true, 4..5),
+                (true, 5..7),
+                (false, 7..8),
+                (true, 8..11),
+                (false, 11..11),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn items_including_nops_cover_every_byte() -> IonResult<()> {
+        let data: Vec<u8> = vec![
+            0xe0, 0x01, 0x00, 0xea, // IVM
+            0x01, 0xff, // 2-byte NOP
+            0x21, 0x05, // 5
+            0x00, // 1-byte NOP
+        ];
+
+        let context = EncodingContext::empty();
+        let mut reader = LazyRawBinaryReader_1_0::new(context.get_ref(), &data);
+        let ranges = reader
+            .items_including_nops()
+            .map(|item| item.map(|item| item.range()))
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(ranges, vec![0..4, 4..6, 6..8, 8..9]);
+
+        // A truncated NOP pad is reported once, then the iterator ends.
+        let mut reader = LazyRawBinaryReader_1_0::new(context.get_ref(), &data[..5]);
+        let mut items = reader.items_including_nops();
+        assert!(items.next().unwrap().is_ok());
+        assert!(items.next().unwrap().is_err());
+        assert!(items.next().is_none());
+        Ok(())
+    }
+
     #[test]
     fn ivm_after_nop() -> IonResult<()> {
         let data: Vec<u8> = vec![
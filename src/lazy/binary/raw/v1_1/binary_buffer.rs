@@ -312,6 +312,9 @@ impl<'a> BinaryBuffer<'a> {
             // We have a flexuint telling us how long our nop is.
             let after_header = self.consume(1);
             let (len, rest) = after_header.read_flex_uint()?;
+            if rest.len() < len.value() as usize {
+                return IonResult::incomplete("a NOP", rest.offset());
+            }
             (
                 len.value() as usize + len.size_in_bytes(),
                 rest.consume(len.value() as usize),
@@ -1681,4 +1684,71 @@ mod tests {
         );
         Ok(())
     }
+
+    #[cfg(feature = "experimental-tooling-apis")]
+    #[rstest]
+    #[rustfmt::skip]
+    #[case::values_and_group(
+        0b01_10_01,
+        &[0x61, 0x01, 0x61, 0x02, 0x05, 0x61, 0x03, 0x61, 0x04],
+        &[
+            (ArgGrouping::ValueExprLiteral, 0..2),
+            (ArgGrouping::ValueExprLiteral, 2..4),
+            (ArgGrouping::ArgGroup, 4..7),
+            (ArgGrouping::ValueExprLiteral, 7..9),
+        ]
+    )]
+    #[rustfmt::skip]
+    #[case::empty_args(
+        0b01_00_00,
+        &[0x61, 0x01, 0x61, 0x04],
+        &[
+            (ArgGrouping::ValueExprLiteral, 0..2),
+            (ArgGrouping::Empty, 2..2),
+            (ArgGrouping::Empty, 2..2),
+            (ArgGrouping::ValueExprLiteral, 2..4),
+        ]
+    )]
+    fn read_eexp_arg_encodings(
+        #[values(false, true)] length_prefixed: bool,
+        #[case] bitmap: u8,
+        #[case] args: &[u8],
+        #[case] expected: &[(ArgGrouping, Range<usize>)],
+    ) -> IonResult<()> {
+        use crate::{HasRange, HasSpan};
+        let mut context = EncodingContext::for_ion_version(IonVersion::v1_1);
+        let template_macro = TemplateCompiler::compile_from_source(
+            context.macro_table(),
+            "(macro m (a b? c* d+) [(%a), (%b), (%c), (%d)])",
+        )?;
+        let address = context
+            .macro_table_mut()
+            .add_template_macro(template_macro)?;
+        let mut data = if length_prefixed {
+            let args_length = args.len() + 1; // Includes the bitmap
+            vec![0xF5, (address * 2 + 1) as u8, (args_length * 2 + 1) as u8]
+        } else {
+            vec![address as u8]
+        };
+        data.push(bitmap);
+        let args_offset = data.len();
+        data.extend_from_slice(args);
+
+        let buffer = BinaryBuffer::new(context.get_ref(), &data);
+        let eexp = buffer.read_e_expression(Opcode::from_byte(data[0]))?.0;
+        let eexp = eexp.resolve(context.get_ref())?;
+        assert_eq!(eexp.raw_invocation().has_length_prefix(), length_prefixed);
+        assert_eq!(eexp.raw_invocation().bitmap_span().bytes(), &[bitmap]);
+
+        let arg_encodings = eexp.arg_encodings()?;
+        assert_eq!(arg_encodings.len(), expected.len());
+        for (arg_encoding, (grouping, range)) in arg_encodings.iter().zip(expected) {
+            assert_eq!(arg_encoding.grouping(), *grouping);
+            assert_eq!(arg_encoding.has_bitmap_entry(), arg_encoding.parameter().name() != "a");
+            let expected_range = range.start + args_offset..range.end + args_offset;
+            assert_eq!(arg_encoding.range(), expected_range.clone());
+            assert_eq!(arg_encoding.span().bytes(), &data[expected_range]);
+        }
+        Ok(())
+    }
 }
@@ -118,6 +118,92 @@ impl<'top> BinaryEExpression_1_1<'top> {
     }
 }
 
+#[cfg(feature = "experimental-tooling-apis")]
+impl<'top> BinaryEExpression_1_1<'top> {
+    /// Decodes this e-expression's argument encoding bitmap, returning an entry for each of the
+    /// invoked macro's parameters that describes how the corresponding argument was encoded and
+    /// which bytes it occupies. Required parameters have no bitmap entry; their arguments are
+    /// always reported as [`ArgGrouping::ValueExprLiteral`].
+    pub fn arg_encodings(&'top self) -> IonResult<Vec<BinaryEExpArgEncoding<'top>>> {
+        let signature = self.macro_ref.signature();
+        let mut groupings =
+            ArgGroupingBitmapIterator::new(signature.num_variadic_params(), self.bitmap_bits);
+        self.raw_arguments()
+            .map(|arg| {
+                let arg = arg?;
+                let parameter = arg.encoding();
+                let grouping = if parameter.is_variadic() {
+                    // The bitmap has exactly one entry for each variadic parameter.
+                    groupings.next().unwrap()?
+                } else {
+                    ArgGrouping::ValueExprLiteral
+                };
+                let range = arg.expr().range();
+                let input = self
+                    .input
+                    .slice(range.start - self.input.offset(), range.len());
+                Ok(BinaryEExpArgEncoding {
+                    parameter,
+                    grouping,
+                    span: input.into(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Describes how a single argument of a binary Ion 1.1 e-expression was encoded, as indicated
+/// by the e-expression's argument encoding bitmap.
+#[cfg(feature = "experimental-tooling-apis")]
+#[derive(Debug, Copy, Clone)]
+pub struct BinaryEExpArgEncoding<'top> {
+    parameter: &'top Parameter,
+    grouping: ArgGrouping,
+    span: Span<'top>,
+}
+
+#[cfg(feature = "experimental-tooling-apis")]
+impl<'top> BinaryEExpArgEncoding<'top> {
+    /// The parameter to which this argument was passed.
+    pub fn parameter(&self) -> &'top Parameter {
+        self.parameter
+    }
+
+    pub fn grouping(&self) -> ArgGrouping {
+        self.grouping
+    }
+
+    /// Returns `true` if the argument's encoding was specified in the bitmap. This is the case
+    /// for all variadic (`?`, `*`, and `+`) parameters.
+    pub fn has_bitmap_entry(&self) -> bool {
+        self.parameter.is_variadic()
+    }
+
+    /// Returns `true` if the bitmap indicated that no argument was passed.
+    pub fn is_empty(&self) -> bool {
+        self.grouping == ArgGrouping::Empty
+    }
+
+    /// Returns `true` if the argument was encoded as an expression group.
+    pub fn is_arg_group(&self) -> bool {
+        self.grouping == ArgGrouping::ArgGroup
+    }
+}
+
+#[cfg(feature = "experimental-tooling-apis")]
+impl<'top> HasSpan<'top> for BinaryEExpArgEncoding<'top> {
+    fn span(&self) -> Span<'top> {
+        self.span
+    }
+}
+
+#[cfg(feature = "experimental-tooling-apis")]
+impl HasRange for BinaryEExpArgEncoding<'_> {
+    fn range(&self) -> Range<usize> {
+        self.span.range()
+    }
+}
+
 impl<'top> HasSpan<'top> for &'top BinaryEExpression_1_1<'top> {
     fn span(&self) -> Span<'top> {
         Span::with_offset(self.input.offset(), self.input.bytes())
@@ -6,7 +6,10 @@ use crate::lazy::decoder::{LazyRawReader, RawValueExpr};
 use crate::lazy::encoder::private::Sealed;
 use crate::lazy::encoding::BinaryEncoding_1_1;
 use crate::lazy::expanded::EncodingContextRef;
-use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem, RawStreamItem};
+use crate::lazy::raw_stream_item::{
+    items_including_nops, EndPosition, LazyRawStreamItem, LazyRawStreamItemOrNop, NopPad,
+    RawStreamItem, RawStreamItemOrNop,
+};
 use crate::lazy::streaming_raw_reader::RawReaderState;
 use crate::{Encoding, IonResult};
 
@@ -75,6 +78,39 @@ impl<'data> LazyRawBinaryReader_1_1<'data> {
         let (item, _remaining) = self.read_value_expr()?;
         Ok(item)
     }
+
+    /// Like [`Self::next`], but if the next item in the stream is preceded by NOP padding, returns
+    /// each NOP pad as its own item instead of skipping over it.
+    #[cfg_attr(not(feature = "experimental-tooling-apis"), allow(dead_code))]
+    pub fn next_including_nops(
+        &mut self,
+    ) -> IonResult<LazyRawStreamItemOrNop<'data, BinaryEncoding_1_1>> {
+        if self
+            .input
+            .peek_opcode()
+            .is_some_and(|opcode| opcode.is_nop())
+        {
+            let (nop_pad_length, remaining) = self.input.read_nop_pad()?;
+            let nop_pad = NopPad::new(
+                self.input.offset(),
+                self.input.bytes_range(0, nop_pad_length),
+            );
+            self.input = remaining;
+            return Ok(RawStreamItemOrNop::NopPad(nop_pad));
+        }
+        self.next().map(RawStreamItemOrNop::Item)
+    }
+
+    /// Returns an iterator over the remaining items in the stream, including NOP pads, in the
+    /// order they appear. Together, the items' ranges cover every byte of the input. The iterator
+    /// ends when the end of the stream is reached or after it yields an error.
+    #[cfg_attr(not(feature = "experimental-tooling-apis"), allow(dead_code))]
+    pub fn items_including_nops(
+        &mut self,
+    ) -> impl Iterator<Item = IonResult<LazyRawStreamItemOrNop<'data, BinaryEncoding_1_1>>> + '_
+    {
+        items_including_nops(|| self.next_including_nops())
+    }
 }
 
 impl Sealed for LazyRawBinaryReader_1_1<'_> {}
@@ -119,8 +155,9 @@ mod tests {
     use rstest::*;
 
     use crate::lazy::binary::raw::v1_1::reader::LazyRawBinaryReader_1_1;
-    use crate::lazy::decoder::LazyRawSequence;
+    use crate::lazy::decoder::{HasRange, HasSpan, LazyRawSequence};
     use crate::lazy::expanded::EncodingContext;
+    use crate::lazy::raw_stream_item::RawStreamItem;
     use crate::raw_symbol_ref::RawSymbolRef;
     use crate::{IonResult, IonType};
 
@@ -148,6 +185,67 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn nop_pads_as_items() -> IonResult<()> {
+        let data: Vec<u8> = vec![
+            0xE0, 0x01, 0x01, 0xEA, // IVM
+            0xEC, // 1-Byte NOP
+            0xED, 0x05, 0x00, 0x00, // 4-Byte NOP
+            0xEA, // null.null
+            0xEC, // 1-Byte NOP
+        ];
+
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let mut reader = LazyRawBinaryReader_1_1::new(context, &data);
+        let mut items = Vec::new();
+        loop {
+            let item = reader.next_including_nops()?;
+            items.push((item.nop_pad().map(|nop| nop.span().bytes()), item.range()));
+            if matches!(item.item(), Some(RawStreamItem::EndOfStream(_))) {
+                break;
+            }
+        }
+        assert_eq!(
+            items,
+            vec![
+                (None, 0..4),
+                (Some(&[0xEC][..]), 4..5),
+                (Some(&[0xED, 0x05, 0x00, 0x00][..]), 5..9),
+                (None, 9..10),
+                (Some(&[0xEC][..]), 10..11),
+                (None, 11..11),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn items_including_nops_cover_every_byte() -> IonResult<()> {
+        let data: Vec<u8> = vec![
+            0xE0, 0x01, 0x01, 0xEA, // IVM
+            0xED, 0x05, 0x00, 0x00, // 4-byte NOP
+            0x6E, // true
+            0xEC, // 1-byte NOP
+        ];
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let mut reader = LazyRawBinaryReader_1_1::new(context, &data);
+        let ranges = reader
+            .items_including_nops()
+            .map(|item| item.map(|item| item.range()))
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(ranges, vec![0..4, 4..8, 8..9, 9..10]);
+
+        // A truncated NOP pad is reported once, then the iterator ends.
+        let mut reader = LazyRawBinaryReader_1_1::new(context, &data[..6]);
+        let mut items = reader.items_including_nops();
+        assert!(items.next().unwrap().is_ok());
+        assert!(items.next().unwrap().is_err());
+        assert!(items.next().is_none());
+        Ok(())
+    }
+
     #[test]
     fn bools() -> IonResult<()> {
         let data: Vec<u8> = vec![
@@ -336,8 +434,8 @@ mod tests {
             // 0e0
             0x6A,
 
-            // 3.14 (half-precision)
-            // 0x6B, 0x42, 0x47,
+            // 3.140625 (half-precision; the closest value to 3.14)
+            0x6B, 0x48, 0x42,
 
             // 3.1415927 (single-precision)
             0x6C, 0xdb, 0x0F, 0x49, 0x40,
@@ -352,8 +450,10 @@ mod tests {
 
         assert_eq!(reader.next()?.expect_value()?.read()?.expect_float()?, 0.0);
 
-        // TODO: Implement Half-precision.
-        // assert_eq!(reader.next()?.expect_value()?.read()?.expect_float()?, 3.14);
+        assert_eq!(
+            reader.next()?.expect_value()?.read()?.expect_float()?,
+            3.140625
+        );
 
         assert_eq!(
             reader.next()?.expect_value()?.read()?.expect_float()? as f32,
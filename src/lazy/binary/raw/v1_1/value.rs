@@ -3,6 +3,7 @@
 use std::fmt::Debug;
 use std::ops::Range;
 
+use half::f16;
 use num_traits::PrimInt;
 
 use crate::lazy::binary::raw::v1_1::binary_buffer::AnnotationsEncoding;
@@ -503,7 +504,13 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
 
                 f32::from_le_bytes(buffer).into()
             }
-            2 => todo!("implement half-precision floats"),
+            2 => {
+                let mut buffer = [0; 2];
+                let val_bytes = self.value_body_buffer().bytes_range(0, 2);
+                buffer[..2].copy_from_slice(val_bytes);
+
+                f16::from_le_bytes(buffer).into()
+            }
             0 => 0.0f64,
             _ => unreachable!("found a float value with illegal byte size"),
         };
@@ -702,7 +709,8 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
         let value_bytes = self.value_body();
 
         // Year is biased offset by 1970, and is held in the lower 7 bits of the first byte.
-        let ts_builder = Timestamp::with_year((value_bytes[0] & 0x7F) as u32 + 1970);
+        let ts_builder = Timestamp::with_year((value_bytes[0] & 0x7F) as u32 + 1970)
+            .with_leap_second_handling(self.input.context().leap_second_handling);
 
         // Year Precision.
         if length_code == 0 {
@@ -769,7 +777,8 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
         }
 
         let year = u16::from_le_bytes(value_bytes[0..=1].try_into().unwrap()) & YEAR_MASK_16BIT;
-        let ts_builder = Timestamp::with_year(year.into());
+        let ts_builder = Timestamp::with_year(year.into())
+            .with_leap_second_handling(self.input.context().leap_second_handling);
         if value_length == 2 {
             return ts_builder.build();
         }
@@ -974,7 +983,7 @@ impl<'top> BinaryValueLiteral<'top, BinaryEncoding_1_1> for &'top LazyRawBinaryV
     fn annotations_sequence_length_span(&self) -> Span<'top> {
         let header_span = self.annotations_header_span();
         let sequence_length_offset = header_span.range().start + 1;
-        let sequence_length_bytes = &header_span.bytes()[sequence_length_offset..];
+        let sequence_length_bytes = &header_span.bytes()[1..];
         Span::with_offset(sequence_length_offset, sequence_length_bytes)
     }
 
@@ -276,26 +276,32 @@ impl<'top> BinaryValueLiteral<'top, BinaryEncoding_1_0> for &'top LazyRawBinaryV
         let header_span = self.annotations_header_span();
         let wrapper_length_span = self.annotations_wrapper_length_span();
         let sequence_length_offset = wrapper_length_span.range().end;
-        let sequence_length_bytes = &header_span.bytes()[sequence_length_offset..];
+        let local_offset = sequence_length_offset - header_span.range().start;
+        let sequence_length_bytes = &header_span.bytes()[local_offset..];
         Span::with_offset(sequence_length_offset, sequence_length_bytes)
     }
 
     fn annotations_wrapper_length_span(&self) -> Span<'top> {
-        let annotations_span = self.annotations_span();
-        let wrapper_length_input = &annotations_span.bytes()[1..];
-        // Don't read the VarUInt, but skim along looking for the END flag
-        let mut num_varuint_bytes = 1;
-        for &byte in wrapper_length_input {
-            if byte >= 0b1000_0000 {
-                break;
-            }
-            num_varuint_bytes += 1;
-        }
-        let sequence_length_bytes = &wrapper_length_input[..num_varuint_bytes];
-        Span::with_offset(annotations_span.range().start + 1, sequence_length_bytes)
+        wrapper_length_span(self.annotations_span())
     }
 }
 
+/// Returns the span of the given annotations wrapper that contains its trailing wrapper length.
+/// If the wrapper length was encoded in the opcode's low nibble, the returned span is empty.
+fn wrapper_length_span(annotations_span: Span<'_>) -> Span<'_> {
+    let bytes = annotations_span.bytes();
+    let start = annotations_span.range().start + 1;
+    if bytes[0] & 0x0F != 0x0E {
+        return Span::with_offset(start, &[]);
+    }
+    // Don't read the VarUInt, but skim along looking for the END flag
+    let num_varuint_bytes = bytes[1..]
+        .iter()
+        .position(|byte| *byte >= 0b1000_0000)
+        .map_or(bytes.len() - 1, |index| index + 1);
+    Span::with_offset(start, &bytes[1..1 + num_varuint_bytes])
+}
+
 #[derive(Copy, Clone)]
 pub struct EncodedBinaryAnnotations_1_0<'a, 'top> {
     value: &'a LazyRawBinaryValue_1_0<'top>,
@@ -345,7 +351,22 @@ impl<'top> EncodedBinaryAnnotations_1_0<'_, 'top> {
         Span::with_offset(range.start, bytes)
     }
 
-    // TODO: separate span accessors for the wrapper length and sequence length?
+    /// Returns the encoded bytes representing the annotations wrapper's length as a `VarUInt`.
+    /// If the wrapper length was able to be encoded directly in the opcode, the slice returned
+    /// will be empty.
+    pub fn wrapper_length_span(&self) -> Span<'top> {
+        wrapper_length_span(self.span())
+    }
+
+    /// Returns the encoded bytes representing the length of the annotations sequence as a `VarUInt`.
+    pub fn sequence_length_span(&self) -> Span<'top> {
+        let header_span = self.header_span();
+        let local_start = 1 + self.wrapper_length_span().len();
+        Span::with_offset(
+            header_span.range().start + local_start,
+            &header_span.bytes()[local_start..],
+        )
+    }
 
     /// Returns the encoded bytes representing the annotations wrapper's annotations sequence.
     pub fn sequence_span(&self) -> Span<'top> {
@@ -384,7 +405,7 @@ impl<'top> EncodedBinaryValueData_1_0<'_, 'top> {
 
     /// Returns the input stream index range that contains the bytes representing the
     /// value's opcode. In Ion 1.0, this is always a range of a single byte.
-    fn opcode_range(&self) -> Range<usize> {
+    pub fn opcode_range(&self) -> Range<usize> {
         let offset = self.range().start;
         offset..offset + 1
     }
@@ -397,6 +418,15 @@ impl<'top> EncodedBinaryValueData_1_0<'_, 'top> {
         Span::with_offset(stream_range.start, bytes)
     }
 
+    /// Returns the encoded bytes representing the value's header (that is: its opcode and, if
+    /// present, its trailing length).
+    pub fn header_span(&self) -> Span<'top> {
+        let stream_range = self.range();
+        let header_length = self.value.encoded_value.header_length();
+        let bytes = &self.span().bytes()[..header_length];
+        Span::with_offset(stream_range.start, bytes)
+    }
+
     /// Returns the input stream index range that contains the bytes representing the
     /// value's length as a `VarUInt`. If the value's length was able to be encoded directly in
     /// the type descriptor byte, the range returned will be empty.
@@ -553,11 +583,9 @@ impl<'top> LazyRawBinaryValue_1_0<'top> {
         let value = match representation {
             0 => false,
             1 => true,
-            invalid => {
-                return IonResult::decoding_error(format!(
-                    "found a boolean value with an illegal representation (must be 0 or 1): {invalid}",
-                ))
-            }
+            invalid => return IonResult::decoding_error(format!(
+                "found a boolean value with an illegal representation (must be 0 or 1): {invalid}",
+            )),
         };
         Ok(RawValueRef::Bool(value))
     }
@@ -641,7 +669,8 @@ impl<'top> LazyRawBinaryValue_1_0<'top> {
 
         // Year precision
 
-        let builder = Timestamp::with_year(year);
+        let builder = Timestamp::with_year(year)
+            .with_leap_second_handling(self.context().leap_second_handling);
         if input.is_empty() {
             let timestamp = builder.build()?;
             return Ok(RawValueRef::Timestamp(timestamp));
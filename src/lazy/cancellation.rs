@@ -0,0 +1,128 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::result::IonFailure;
+use crate::IonResult;
+
+/// A handle that can be used to stop a long-running read, write, or conversion early.
+///
+/// Clones of a token share the same state, so a clone can be handed to a
+/// [`Reader`](crate::Reader) or [`Writer`](crate::Writer) and the original cancelled later from
+/// another thread (for example, when a request times out or the user presses Ctrl-C). Readers
+/// check their token before advancing to each top-level value, and writers check theirs before
+/// each top-level value is written and before each flush. Once the token has been cancelled, those
+/// operations return [`IonError::Cancelled`](crate::IonError::Cancelled). Utilities built on
+/// readers and writers, like the converters in [`convert`](crate::convert), stop at the next
+/// value boundary and return the same error.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{v1_0, CancellationToken, IonError, Reader};
+///
+/// let token = CancellationToken::new();
+/// let mut reader = Reader::new(v1_0::Text, "1 2 3")?;
+/// reader.set_cancellation_token(token.clone());
+/// assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 1);
+/// token.cancel();
+/// assert!(matches!(reader.next(), Err(IonError::Cancelled(_))));
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels this token and all of its clones. Cancelling a token more than once has no
+    /// additional effect.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Returns an `Err(IonError::Cancelled)` if this token has been cancelled.
+    pub(crate) fn check(&self) -> IonResult<()> {
+        if self.is_cancelled() {
+            return IonResult::cancelled();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "experimental-reader-writer")]
+    use crate::convert::{csv::CsvConverter, ndjson::ndjson_to_ion};
+    #[cfg(feature = "experimental-reader-writer")]
+    use crate::write_config::WriteConfig;
+    use crate::{v1_0, AnyEncoding, IonError, Reader, SequenceWriter, Writer};
+
+    fn is_cancelled<T>(result: IonResult<T>) -> bool {
+        matches!(result, Err(IonError::Cancelled(_)))
+    }
+
+    #[test]
+    fn cancelled_reader_stops_at_next_value() -> IonResult<()> {
+        let token = CancellationToken::new();
+        let mut reader = Reader::new(AnyEncoding, "1 [2, 3] 4")?;
+        reader.set_cancellation_token(token.clone());
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 1);
+        let list = reader.expect_next()?.read()?.expect_list()?;
+        token.cancel();
+        // Values the reader has already returned can still be read...
+        assert_eq!(list.iter().count(), 2);
+        // ...but the reader will not advance.
+        assert!(is_cancelled(reader.next()));
+        assert!(is_cancelled(reader.next()));
+        Ok(())
+    }
+
+    #[test]
+    fn cancelled_writer_stops_writing_and_flushing() -> IonResult<()> {
+        let token = CancellationToken::new();
+        let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+        writer.set_cancellation_token(token.clone());
+        writer.write_all([1, 2])?;
+        writer.flush()?;
+        token.cancel();
+        assert!(is_cancelled(writer.write(3)));
+        assert!(is_cancelled(writer.flush()));
+        assert!(is_cancelled(writer.close()));
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-reader-writer")]
+    #[test]
+    fn cancelled_conversions() -> IonResult<()> {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let mut reader = Reader::new(AnyEncoding, "{a: 1} {a: 2}")?;
+        reader.set_cancellation_token(token.clone());
+        let mut csv = Vec::new();
+        assert!(is_cancelled(
+            CsvConverter::default().convert(&mut reader, &mut csv)
+        ));
+
+        let config = WriteConfig::<v1_0::Text>::default().with_cancellation_token(token);
+        assert!(is_cancelled(ndjson_to_ion(
+            "{\"a\": 1}\n".as_bytes(),
+            config,
+            Vec::new()
+        )));
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use std::fmt::Debug;
 use std::io::Write;
 use std::ops::Range;
 
+use crate::catalog::EmptyCatalog;
 use crate::lazy::any_encoding::{IonEncoding, IonVersion};
 use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
 use crate::lazy::encoder::text::v1_1::writer::LazyRawTextWriter_1_1;
@@ -13,10 +14,12 @@ use crate::lazy::expanded::macro_evaluator::RawEExpression;
 use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::raw_stream_item::LazyRawStreamItem;
 use crate::lazy::raw_value_ref::RawValueRef;
+use crate::lazy::reader_limits::ReaderLimits;
 use crate::lazy::span::Span;
 use crate::lazy::streaming_raw_reader::RawReaderState;
 use crate::read_config::ReadConfig;
 use crate::result::IonFailure;
+use crate::LeapSecondHandling;
 use crate::{
     v1_0, v1_1, Catalog, Encoding, FieldExpr, IonResult, IonType, LazyExpandedFieldName,
     LazyExpandedValue, LazyRawAnyFieldName, LazyRawWriter, MacroExpr, RawSymbolRef, ValueExpr,
@@ -85,6 +88,29 @@ pub trait Decoder: 'static + Sized + Debug + Clone + Copy {
     fn with_catalog(self, catalog: impl Catalog + 'static) -> ReadConfig<Self> {
         ReadConfig::new_with_catalog(self, catalog)
     }
+
+    /// Creates a [`ReadConfig`] for this encoding. See
+    /// [`ReadConfig::with_deferred_timestamp_parsing`] for details.
+    fn with_deferred_timestamp_parsing(self, defer: bool) -> ReadConfig<Self> {
+        ReadConfig::new_with_catalog(self, EmptyCatalog).with_deferred_timestamp_parsing(defer)
+    }
+
+    /// Creates a [`ReadConfig`] for this encoding. See
+    /// [`ReadConfig::with_leap_second_handling`] for details.
+    fn with_leap_second_handling(self, handling: LeapSecondHandling) -> ReadConfig<Self> {
+        ReadConfig::new_with_catalog(self, EmptyCatalog).with_leap_second_handling(handling)
+    }
+
+    /// Creates a [`ReadConfig`] for this encoding. See [`ReadConfig::with_strict_utf8`] for
+    /// details.
+    fn with_strict_utf8(self, strict: bool) -> ReadConfig<Self> {
+        ReadConfig::new_with_catalog(self, EmptyCatalog).with_strict_utf8(strict)
+    }
+
+    /// Creates a [`ReadConfig`] for this encoding. See [`ReadConfig::with_limits`] for details.
+    fn with_limits(self, limits: ReaderLimits) -> ReadConfig<Self> {
+        ReadConfig::new_with_catalog(self, EmptyCatalog).with_limits(limits)
+    }
 }
 
 pub trait RawVersionMarker<'top>: Debug + Copy + Clone + HasSpan<'top> {
@@ -0,0 +1,294 @@
+use bumpalo::collections::Vec as BumpVec;
+
+use crate::element::builders::StructBuilder;
+use crate::lazy::bytes_ref::BytesRef;
+use crate::lazy::decoder::Decoder;
+use crate::lazy::str_ref::StrRef;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::{
+    Annotations, Decimal, Element, Int, IntoAnnotatedElement, IonError, IonResult, IonType,
+    Sequence, SymbolRef, Timestamp, Value,
+};
+
+/// A fully-read Ion value that borrows its text and lob data instead of copying it.
+///
+/// Converting a [`LazyValue`] to an [`Element`] copies every string, symbol, and lob it contains
+/// into its own heap allocation. Converting it to an `ElementRef` instead refers to those bytes
+/// where they already are (usually in the reader's input buffer), and stores the children of
+/// containers in the reader's bump allocator. Both are released in bulk when the reader advances to
+/// the next top-level value; [`Element::from`] copies an `ElementRef` into a value that outlives
+/// the reader.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{v1_0, Element, ElementRef, ElementValueRef, Reader};
+///
+/// let mut reader = Reader::new(v1_0::Text, r#"{name: "widget", tags: [a, b]}"#)?;
+/// let element = ElementRef::try_from(reader.expect_next()?)?;
+/// let Some(ElementValueRef::String(name)) = element.get("name").map(|name| name.value()) else {
+///     panic!("expected a string");
+/// };
+/// assert_eq!(name.text(), "widget");
+/// assert_eq!(element.get("tags").unwrap().as_sequence().unwrap().len(), 2);
+/// assert_eq!(Element::from(element), Element::read_one(r#"{name: "widget", tags: [a, b]}"#)?);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ElementRef<'top> {
+    annotations: &'top [SymbolRef<'top>],
+    value: ElementValueRef<'top>,
+}
+
+/// The value of an [`ElementRef`]. Scalar variants are the same as those of
+/// [`ValueRef`](crate::ValueRef); container variants hold the container's already-read children.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ElementValueRef<'top> {
+    Null(IonType),
+    Bool(bool),
+    Int(Int),
+    Float(f64),
+    Decimal(Decimal),
+    Timestamp(Timestamp),
+    String(StrRef<'top>),
+    Symbol(SymbolRef<'top>),
+    Blob(BytesRef<'top>),
+    Clob(BytesRef<'top>),
+    SExp(&'top [ElementRef<'top>]),
+    List(&'top [ElementRef<'top>]),
+    Struct(&'top [(SymbolRef<'top>, ElementRef<'top>)]),
+}
+
+impl<'top> ElementRef<'top> {
+    pub fn annotations(&self) -> &'top [SymbolRef<'top>] {
+        self.annotations
+    }
+
+    pub fn value(&self) -> ElementValueRef<'top> {
+        self.value
+    }
+
+    pub fn ion_type(&self) -> IonType {
+        use ElementValueRef::*;
+        match self.value {
+            Null(ion_type) => ion_type,
+            Bool(_) => IonType::Bool,
+            Int(_) => IonType::Int,
+            Float(_) => IonType::Float,
+            Decimal(_) => IonType::Decimal,
+            Timestamp(_) => IonType::Timestamp,
+            String(_) => IonType::String,
+            Symbol(_) => IonType::Symbol,
+            Blob(_) => IonType::Blob,
+            Clob(_) => IonType::Clob,
+            SExp(_) => IonType::SExp,
+            List(_) => IonType::List,
+            Struct(_) => IonType::Struct,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self.value, ElementValueRef::Null(_))
+    }
+
+    /// If this is a list or s-expression, returns its children.
+    pub fn as_sequence(&self) -> Option<&'top [ElementRef<'top>]> {
+        match self.value {
+            ElementValueRef::List(children) | ElementValueRef::SExp(children) => Some(children),
+            _ => None,
+        }
+    }
+
+    /// If this is a struct, returns its fields in the order they were read.
+    pub fn as_struct(&self) -> Option<&'top [(SymbolRef<'top>, ElementRef<'top>)]> {
+        match self.value {
+            ElementValueRef::Struct(fields) => Some(fields),
+            _ => None,
+        }
+    }
+
+    /// If this is a struct, returns the value of the first field named `name`.
+    pub fn get(&self, name: &str) -> Option<ElementRef<'top>> {
+        self.as_struct()?
+            .iter()
+            .find(|(field_name, _)| field_name.text() == Some(name))
+            .map(|(_, value)| *value)
+    }
+}
+
+impl From<ElementRef<'_>> for Element {
+    /// Copies `element` into an owned [`Element`].
+    fn from(element: ElementRef<'_>) -> Self {
+        use ElementValueRef::*;
+        let value = match element.value {
+            Null(ion_type) => Value::Null(ion_type),
+            Bool(b) => Value::Bool(b),
+            Int(i) => Value::Int(i),
+            Float(f) => Value::Float(f),
+            Decimal(d) => Value::Decimal(d),
+            Timestamp(t) => Value::Timestamp(t),
+            String(s) => Value::String(s.to_owned()),
+            Symbol(s) => Value::Symbol(s.to_owned()),
+            Blob(b) => Value::Blob(b.data().into()),
+            Clob(c) => Value::Clob(c.data().into()),
+            SExp(children) => Value::SExp(sequence_from(children)),
+            List(children) => Value::List(sequence_from(children)),
+            Struct(fields) => {
+                let mut builder = StructBuilder::new();
+                for (name, value) in fields {
+                    builder = builder.with_field((*name).to_owned(), Element::from(*value));
+                }
+                Value::Struct(builder.build())
+            }
+        };
+        let annotations: Annotations = element
+            .annotations
+            .iter()
+            .map(|annotation| (*annotation).to_owned())
+            .collect::<Vec<_>>()
+            .into();
+        value.with_annotations(annotations)
+    }
+}
+
+fn sequence_from(children: &[ElementRef<'_>]) -> Sequence {
+    children
+        .iter()
+        .copied()
+        .map(Element::from)
+        .collect::<Vec<_>>()
+        .into()
+}
+
+impl<'top, D: Decoder> TryFrom<LazyValue<'top, D>> for ElementRef<'top> {
+    type Error = IonError;
+
+    /// Reads `lazy_value` in its entirety. Text and lob data are borrowed from the reader, and
+    /// annotations and the children of containers are stored in the reader's bump allocator.
+    fn try_from(lazy_value: LazyValue<'top, D>) -> IonResult<Self> {
+        let allocator = lazy_value.context().allocator();
+        let mut annotations = BumpVec::new_in(allocator);
+        for annotation in lazy_value.annotations() {
+            annotations.push(annotation?);
+        }
+        let value = match lazy_value.read()? {
+            ValueRef::Null(ion_type) => ElementValueRef::Null(ion_type),
+            ValueRef::Bool(b) => ElementValueRef::Bool(b),
+            ValueRef::Int(i) => ElementValueRef::Int(i),
+            ValueRef::Float(f) => ElementValueRef::Float(f),
+            ValueRef::Decimal(d) => ElementValueRef::Decimal(d),
+            ValueRef::Timestamp(t) => ElementValueRef::Timestamp(t),
+            ValueRef::String(s) => ElementValueRef::String(s),
+            ValueRef::Symbol(s) => ElementValueRef::Symbol(s),
+            ValueRef::Blob(b) => ElementValueRef::Blob(b),
+            ValueRef::Clob(c) => ElementValueRef::Clob(c),
+            ValueRef::SExp(sexp) => {
+                let mut children = BumpVec::new_in(allocator);
+                for child in sexp {
+                    children.push(ElementRef::try_from(child?)?);
+                }
+                ElementValueRef::SExp(children.into_bump_slice())
+            }
+            ValueRef::List(list) => {
+                let mut children = BumpVec::new_in(allocator);
+                for child in list {
+                    children.push(ElementRef::try_from(child?)?);
+                }
+                ElementValueRef::List(children.into_bump_slice())
+            }
+            ValueRef::Struct(strukt) => {
+                let mut fields = BumpVec::new_in(allocator);
+                for field in strukt {
+                    let field = field?;
+                    fields.push((field.name()?, ElementRef::try_from(field.value())?));
+                }
+                ElementValueRef::Struct(fields.into_bump_slice())
+            }
+        };
+        Ok(ElementRef {
+            annotations: annotations.into_bump_slice(),
+            value,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{v1_0, Reader};
+
+    const DATA: &str = r#"
+        a::b::{
+            name: "widget",
+            tags: [red, 'big deal', null.symbol],
+            dims: (1.5e0 2.25 -3),
+            data: {{aGVsbG8=}},
+            notes: {{"a clob"}},
+            when: 2025-01-01T,
+            escaped: "line\nbreak",
+            empty: {},
+        }
+        null.list
+        42
+    "#;
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn round_trip_through_element_ref() -> IonResult<()> {
+        use crate::{v1_1, AnyEncoding};
+
+        let expected = Element::read_all(DATA)?;
+        for data in [
+            DATA.as_bytes().to_vec(),
+            expected.encode_as(v1_0::Binary)?,
+            expected.encode_as(v1_1::Binary)?,
+        ] {
+            let mut reader = Reader::new(AnyEncoding, data.as_slice())?;
+            let mut actual = Vec::new();
+            while let Some(value) = reader.next()? {
+                actual.push(Element::from(ElementRef::try_from(value)?));
+            }
+            assert_eq!(Sequence::from(actual), expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn text_is_borrowed_from_the_input() -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, DATA)?;
+        let element = ElementRef::try_from(reader.expect_next()?)?;
+        assert_eq!(element.ion_type(), IonType::Struct);
+        assert_eq!(element.annotations(), &["a", "b"]);
+        let input = DATA.as_bytes().as_ptr_range();
+
+        let ElementValueRef::String(name) = element.get("name").unwrap().value() else {
+            panic!("expected a string");
+        };
+        assert_eq!(name.text(), "widget");
+        assert!(input.contains(&name.text().as_ptr()));
+
+        let tags = element.get("tags").unwrap().as_sequence().unwrap();
+        assert_eq!(tags.len(), 3);
+        assert_eq!(
+            tags[1].value(),
+            ElementValueRef::Symbol(SymbolRef::from("big deal"))
+        );
+        assert!(tags[2].is_null());
+
+        // Strings with escapes are unescaped into the bump allocator.
+        let ElementValueRef::String(escaped) = element.get("escaped").unwrap().value() else {
+            panic!("expected a string");
+        };
+        assert_eq!(escaped.text(), "line\nbreak");
+        assert!(!input.contains(&escaped.text().as_ptr()));
+
+        assert!(element.get("missing").is_none());
+        assert_eq!(element.get("empty").unwrap().as_struct(), Some(&[][..]));
+        Ok(())
+    }
+}
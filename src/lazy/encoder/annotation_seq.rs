@@ -1,3 +1,5 @@
+use std::ops::Deref;
+
 use smallvec::SmallVec;
 
 use crate::raw_symbol_ref::SystemSymbol_1_1;
@@ -8,11 +10,69 @@ use crate::{Annotations, RawSymbolRef, SymbolId};
 /// When the sequence is two or fewer annotations, it will not require a heap allocation.
 pub type AnnotationsVec<'a> = SmallVec<[RawSymbolRef<'a>; 2]>;
 
+/// A sequence of annotations that is either borrowed from the caller or owned by the writer.
+///
+/// Borrowed annotations are passed to the encoder as-is; they are only copied if the writer needs
+/// to modify them (for example, to replace their text with symbol IDs).
+#[derive(Clone, Debug)]
+pub enum AnnotationsBuf<'a> {
+    Borrowed(&'a [RawSymbolRef<'a>]),
+    Owned(AnnotationsVec<'a>),
+}
+
+impl<'a> AnnotationsBuf<'a> {
+    /// Returns a mutable reference to the annotations, copying them first if they are borrowed.
+    pub fn to_mut(&mut self) -> &mut AnnotationsVec<'a> {
+        if let AnnotationsBuf::Borrowed(annotations) = self {
+            *self = AnnotationsBuf::Owned(AnnotationsVec::from_slice(annotations));
+        }
+        match self {
+            AnnotationsBuf::Owned(annotations) => annotations,
+            AnnotationsBuf::Borrowed(_) => unreachable!("borrowed annotations were just copied"),
+        }
+    }
+
+    /// Returns the annotations as an `AnnotationsVec`, copying them if they are borrowed.
+    pub fn into_owned(self) -> AnnotationsVec<'a> {
+        match self {
+            AnnotationsBuf::Borrowed(annotations) => AnnotationsVec::from_slice(annotations),
+            AnnotationsBuf::Owned(annotations) => annotations,
+        }
+    }
+}
+
+impl<'a> Deref for AnnotationsBuf<'a> {
+    type Target = [RawSymbolRef<'a>];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            AnnotationsBuf::Borrowed(annotations) => annotations,
+            AnnotationsBuf::Owned(annotations) => annotations.as_slice(),
+        }
+    }
+}
+
+impl Default for AnnotationsBuf<'_> {
+    fn default() -> Self {
+        AnnotationsBuf::Owned(AnnotationsVec::new())
+    }
+}
+
 /// Types that can be viewed as an annotations sequence.
 ///
 /// Examples include `SymbolId`, `&str`, and iterables of those types.
 pub trait AnnotationSeq<'a> {
     fn into_annotations_vec(self) -> AnnotationsVec<'a>;
+
+    /// Converts the value into an [`AnnotationsBuf`]. Types that already hold a slice of
+    /// `RawSymbolRef`s can lend it to the writer instead of copying it; by default, the
+    /// annotations are collected into an `AnnotationsVec`.
+    fn into_annotations_buf(self) -> AnnotationsBuf<'a>
+    where
+        Self: Sized,
+    {
+        AnnotationsBuf::Owned(self.into_annotations_vec())
+    }
 }
 
 impl<'a> AnnotationSeq<'a> for &'a str {
@@ -78,6 +138,28 @@ impl<'a> AnnotationSeq<'a> for AnnotationsVec<'a> {
     }
 }
 
+impl<'a> AnnotationSeq<'a> for AnnotationsBuf<'a> {
+    fn into_annotations_vec(self) -> AnnotationsVec<'a> {
+        self.into_owned()
+    }
+
+    fn into_annotations_buf(self) -> AnnotationsBuf<'a> {
+        self
+    }
+}
+
+// Allows an `AnnotationsVec` to be reused as a buffer when writing many annotated values. The
+// writer borrows its contents rather than copying them.
+impl<'a> AnnotationSeq<'a> for &'a AnnotationsVec<'_> {
+    fn into_annotations_vec(self) -> AnnotationsVec<'a> {
+        AnnotationsVec::from_slice(self)
+    }
+
+    fn into_annotations_buf(self) -> AnnotationsBuf<'a> {
+        AnnotationsBuf::Borrowed(self.as_slice())
+    }
+}
+
 impl<'a, T> AnnotationSeq<'a> for Vec<T>
 where
     T: Into<RawSymbolRef<'a>>,
@@ -139,3 +221,24 @@ impl<'a> AnnotationSeq<'a> for &'a Annotations {
         annotations
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrowed_annotations_are_copied_on_write() {
+        let buffer: AnnotationsVec<'_> = ["a", "b", "c"]
+            .into_iter()
+            .map(RawSymbolRef::from)
+            .collect();
+        let mut annotations = (&buffer).into_annotations_buf();
+        assert!(matches!(annotations, AnnotationsBuf::Borrowed(_)));
+        assert_eq!(&*annotations, buffer.as_slice());
+
+        annotations.to_mut()[1] = RawSymbolRef::SymbolId(10);
+        assert!(matches!(annotations, AnnotationsBuf::Owned(_)));
+        assert_eq!(annotations[1], RawSymbolRef::SymbolId(10));
+        assert_eq!(buffer[1], RawSymbolRef::Text("b"));
+    }
+}
@@ -9,7 +9,7 @@ use crate::binary::timestamp::TimestampBinaryEncoder;
 use crate::binary::uint;
 use crate::binary::uint::DecodedUInt;
 use crate::binary::var_uint::VarUInt;
-use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsVec};
+use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsBuf};
 use crate::lazy::encoder::binary::v1_0::container_writers::{
     BinaryListWriter_1_0, BinarySExpWriter_1_0, BinaryStructWriter_1_0,
 };
@@ -41,6 +41,12 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
         }
     }
 
+    /// Appends an already-encoded value to the encoding buffer.
+    pub(crate) fn write_encoded(mut self, encoded: &[u8]) -> IonResult<()> {
+        self.push_bytes(encoded);
+        Ok(())
+    }
+
     #[inline]
     fn push_byte(&mut self, byte: u8) {
         self.encoding_buffer.push(byte);
@@ -250,6 +256,10 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
             self.encoding_buffer,
         ))
     }
+
+    fn eexp_writer<'a>(self, _macro_id: impl MacroIdLike<'a>) -> IonResult<Never> {
+        IonResult::encoding_error("binary Ion 1.0 does not support macros")
+    }
 }
 
 impl Sealed for BinaryValueWriter_1_0<'_, '_> {}
@@ -269,7 +279,7 @@ impl<'top> AnnotatableWriter for BinaryValueWriter_1_0<'_, 'top> {
     {
         Ok(BinaryAnnotatedValueWriter_1_0::new(
             self.allocator,
-            annotations.into_annotations_vec(),
+            annotations.into_annotations_buf(),
             self.encoding_buffer,
         ))
     }
@@ -286,7 +296,7 @@ impl<'value, 'top> ValueWriter for BinaryValueWriter_1_0<'value, 'top> {
 }
 
 pub struct BinaryAnnotatedValueWriter_1_0<'value, 'top> {
-    annotations: AnnotationsVec<'value>,
+    annotations: AnnotationsBuf<'value>,
     allocator: &'top BumpAllocator,
     output_buffer: &'value mut BumpVec<'top, u8>,
 }
@@ -294,7 +304,7 @@ pub struct BinaryAnnotatedValueWriter_1_0<'value, 'top> {
 impl<'value, 'top> BinaryAnnotatedValueWriter_1_0<'value, 'top> {
     pub fn new(
         allocator: &'top BumpAllocator,
-        annotations: AnnotationsVec<'value>,
+        annotations: AnnotationsBuf<'value>,
         encoding_buffer: &'value mut BumpVec<'top, u8>,
     ) -> BinaryAnnotatedValueWriter_1_0<'value, 'top> {
         BinaryAnnotatedValueWriter_1_0 {
@@ -365,7 +375,7 @@ impl BinaryAnnotatedValueWriter_1_0<'_, '_> {
     }
 
     fn encode_annotations_sequence(&self, buffer: &'_ mut BumpVec<'_, u8>) -> IonResult<()> {
-        for annotation in &self.annotations {
+        for annotation in self.annotations.iter() {
             let RawSymbolRef::SymbolId(sid) = annotation.as_raw_symbol_ref() else {
                 return Err(IonError::Encoding(EncodingError::new(
                     "binary Ion 1.0 cannot encode text literal annotations",
@@ -395,7 +405,7 @@ impl<'top> AnnotatableWriter for BinaryAnnotatedValueWriter_1_0<'_, 'top> {
         Self: 'a,
     {
         Ok(BinaryAnnotatedValueWriter_1_0 {
-            annotations: annotations.into_annotations_vec(),
+            annotations: annotations.into_annotations_buf(),
             allocator: self.allocator,
             output_buffer: self.output_buffer,
         })
@@ -427,15 +437,15 @@ impl<'value, 'top> ValueWriter for BinaryAnnotatedValueWriter_1_0<'value, 'top>
 
     fn list_writer(self) -> IonResult<Self::ListWriter> {
         BinaryListWriter_1_0::new(self.allocator, self.output_buffer)
-            .with_annotations(self.annotations)
+            .with_annotations(self.annotations.iter())
     }
     fn sexp_writer(self) -> IonResult<Self::SExpWriter> {
         BinarySExpWriter_1_0::new(self.allocator, self.output_buffer)
-            .with_annotations(self.annotations)
+            .with_annotations(self.annotations.iter())
     }
     fn struct_writer(self) -> IonResult<Self::StructWriter> {
         BinaryStructWriter_1_0::new(self.allocator, self.output_buffer)
-            .with_annotations(self.annotations)
+            .with_annotations(self.annotations.iter())
     }
     fn eexp_writer<'a>(self, _macro_id: impl MacroIdLike<'a>) -> IonResult<Self::EExpWriter> {
         IonResult::encoding_error("binary Ion 1.0 does not support macros")
@@ -159,6 +159,10 @@ impl<W: Write> LazyRawWriter<W> for LazyRawBinaryWriter_1_0<W> {
         Ok(())
     }
 
+    fn write_encoded_value(&mut self, encoded: &[u8]) -> IonResult<()> {
+        self.value_writer().write_encoded(encoded)
+    }
+
     fn macro_table(&self) -> &WriterMacroTable {
         &EMPTY_MACRO_TABLE
     }
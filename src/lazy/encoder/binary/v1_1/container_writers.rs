@@ -10,8 +10,9 @@ use crate::lazy::encoder::value_writer::{EExpWriter, SequenceWriter, StructWrite
 use crate::lazy::encoder::value_writer_config::ValueWriterConfig;
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::expanded::macro_table::MacroRef;
-use crate::lazy::expanded::template::{Parameter, SignatureIterator};
+use crate::lazy::expanded::template::{Parameter, ParameterEncoding, SignatureIterator};
 use crate::raw_symbol_ref::AsRawSymbolRef;
+use crate::result::IonFailure;
 use crate::{v1_1, ContextWriter, Encoding, IonResult, MacroTable, UInt};
 
 /// A helper type that holds fields and logic that is common to [`BinaryListWriter_1_1`],
@@ -431,13 +432,18 @@ impl StructWriter for BinaryStructWriter_1_1<'_, '_> {
 pub struct BinaryEExpWriter_1_1<'value, 'top> {
     allocator: &'top BumpAllocator,
     buffer: &'value mut BumpVec<'top, u8>,
+    // If the e-expression is length-prefixed, its arguments are encoded here so that their length
+    // can be written to `buffer` ahead of them when the e-expression is closed.
+    args_buffer: Option<BumpVec<'top, u8>>,
+    // The position of the argument encoding bitmap in the buffer to which arguments are encoded.
+    // Its bytes are reserved when the writer is created and filled in as expression groups are
+    // closed; parameters that are not passed an expression group are left empty (`0b00`).
+    bitmap_position: usize,
     value_writer_config: ValueWriterConfig,
     macros: &'value MacroTable,
+    // Each argument is written by a writer that checks it against the next parameter in this
+    // signature; see `BinaryEExpParameterValueWriter_1_1`.
     signature_iter: SignatureIterator<'value>,
-    // TODO: Hold a reference to the macro signature and advance to the next parameter on
-    //       each method call. Any time a value is written:
-    //       * compare its type to the parameter to make sure it's legal.
-    //       * see if the parameter's cardinality requires an update to the arg encoding bitmap
 }
 
 impl<'value, 'top> BinaryEExpWriter_1_1<'value, 'top> {
@@ -447,16 +453,71 @@ impl<'value, 'top> BinaryEExpWriter_1_1<'value, 'top> {
         value_writer_config: ValueWriterConfig,
         macros: &'value MacroTable,
         invoked_macro: MacroRef<'value>,
+    ) -> Self {
+        Self::with_args_buffer(
+            allocator,
+            buffer,
+            None,
+            value_writer_config,
+            macros,
+            invoked_macro,
+        )
+    }
+
+    /// Constructs a writer for an e-expression whose opcode and macro address have already been
+    /// written to `buffer` in the length-prefixed form.
+    pub fn new_length_prefixed(
+        allocator: &'top BumpAllocator,
+        buffer: &'value mut BumpVec<'top, u8>,
+        value_writer_config: ValueWriterConfig,
+        macros: &'value MacroTable,
+        invoked_macro: MacroRef<'value>,
+    ) -> Self {
+        const DEFAULT_CAPACITY: usize = 128;
+        Self::with_args_buffer(
+            allocator,
+            buffer,
+            Some(BumpVec::with_capacity_in(DEFAULT_CAPACITY, allocator)),
+            value_writer_config,
+            macros,
+            invoked_macro,
+        )
+    }
+
+    fn with_args_buffer(
+        allocator: &'top BumpAllocator,
+        buffer: &'value mut BumpVec<'top, u8>,
+        mut args_buffer: Option<BumpVec<'top, u8>>,
+        value_writer_config: ValueWriterConfig,
+        macros: &'value MacroTable,
+        invoked_macro: MacroRef<'value>,
     ) -> Self {
         let signature_iter = invoked_macro.iter_signature();
+        let target_buffer = Self::target_buffer(&mut args_buffer, buffer);
+        let bitmap_position = target_buffer.len();
+        let bitmap_size_in_bytes = signature_iter.signature().bitmap_size_in_bytes();
+        target_buffer.resize(bitmap_position + bitmap_size_in_bytes, 0);
         Self {
             allocator,
             buffer,
+            args_buffer,
+            bitmap_position,
             value_writer_config,
             macros,
             signature_iter,
         }
     }
+
+    /// The buffer to which arguments are encoded.
+    fn target_buffer<'a>(
+        args_buffer: &'a mut Option<BumpVec<'top, u8>>,
+        buffer: &'a mut BumpVec<'top, u8>,
+    ) -> &'a mut BumpVec<'top, u8> {
+        match args_buffer {
+            Some(args_buffer) => args_buffer,
+            None => buffer,
+        }
+    }
 }
 
 impl<'top> ContextWriter for BinaryEExpWriter_1_1<'_, 'top> {
@@ -471,7 +532,7 @@ impl MakeValueWriter for BinaryEExpWriter_1_1<'_, '_> {
         let param = self.signature_iter.expect_next_parameter().ok();
         BinaryEExpParameterValueWriter_1_1::new(
             self.allocator,
-            self.buffer,
+            Self::target_buffer(&mut self.args_buffer, self.buffer),
             self.value_writer_config,
             self.macros,
             param,
@@ -483,8 +544,11 @@ impl SequenceWriter for BinaryEExpWriter_1_1<'_, '_> {
     type Resources = ();
 
     fn close(self) -> IonResult<Self::Resources> {
-        // Nothing to do
-        // TODO: When we have length-prefixed macro invocations, this will require a step to flush the buffered encoding.
+        self.signature_iter.expect_complete()?;
+        if let Some(args_buffer) = self.args_buffer {
+            FlexUInt::write(self.buffer, args_buffer.len())?;
+            self.buffer.extend_from_slice_copy(args_buffer.as_slice());
+        }
         Ok(())
     }
 }
@@ -510,22 +574,43 @@ impl<'top> EExpWriter for BinaryEExpWriter_1_1<'_, 'top> {
     }
 
     fn write_flex_uint(&mut self, value: impl Into<UInt>) -> IonResult<()> {
-        FlexUInt::write(self.buffer, value)?;
+        self.signature_iter
+            .expect_next_parameter()
+            .and_then(|p| p.expect_encoding(&ParameterEncoding::FlexUInt))?;
+        FlexUInt::write(
+            Self::target_buffer(&mut self.args_buffer, self.buffer),
+            value,
+        )?;
         Ok(())
     }
 
     fn write_fixed_uint8(&mut self, value: impl Into<u8>) -> IonResult<()> {
-        self.buffer.push(value.into());
+        self.signature_iter
+            .expect_next_parameter()
+            .and_then(|p| p.expect_encoding(&ParameterEncoding::UInt8))?;
+        Self::target_buffer(&mut self.args_buffer, self.buffer).push(value.into());
         Ok(())
     }
 
     fn expr_group_writer(&mut self) -> IonResult<Self::ExprGroupWriter<'_>> {
+        let bitmap_index = self.signature_iter.num_variadic_params_passed();
         let param = self.signature_iter.expect_next_parameter()
             .and_then(|p| p.expect_variadic())?;
+        if *param.encoding() != ParameterEncoding::Tagged {
+            // The members of a group are encoded like the parameter's other arguments, but only
+            // groups of tagged values can be written at this time.
+            return IonResult::encoding_error(format!(
+                "cannot write an expression group for parameter '{}'; groups of {} arguments are not yet supported",
+                param.name(),
+                param.encoding()
+            ));
+        }
 
         let writer = BinaryExprGroupWriter::new(
             self.allocator,
-            self.buffer,
+            Self::target_buffer(&mut self.args_buffer, self.buffer),
+            self.bitmap_position + bitmap_index / 4,
+            (bitmap_index % 4) * 2,
             self.value_writer_config,
             self.macros,
             param,
@@ -535,29 +620,51 @@ impl<'top> EExpWriter for BinaryEExpWriter_1_1<'_, 'top> {
     }
 }
 
+/// Writes an expression group for a variadic parameter of a binary e-expression.
+///
+/// The group's expressions are buffered until it is closed. At that point, the group is encoded
+/// in the most compact form that its number of expressions allows: an empty group is omitted,
+/// a single expression is written on its own, and larger groups are length-prefixed. The
+/// corresponding entry in the e-expression's argument encoding bitmap is set to match.
 pub struct BinaryExprGroupWriter<'group, 'top> {
     allocator: &'top BumpAllocator,
+    // The buffer to which the e-expression's arguments are being written.
     buffer: &'group mut BumpVec<'top, u8>,
+    // The index in `buffer` of the bitmap byte holding this group's entry, and the entry's offset
+    // within that byte.
+    bitmap_byte_position: usize,
+    bitmap_bit_offset: usize,
+    expressions_buffer: BumpVec<'top, u8>,
     value_writer_config: ValueWriterConfig,
     macros: &'group MacroTable,
-    // TODO: validate parameter type when writing group members.
-    _parameter: &'group Parameter,
+    // The parameter's encoding is validated when the group writer is created. Because only
+    // groups of tagged values are supported, any value can be written as a member. The number of
+    // members is validated against the parameter's cardinality when the group is closed.
+    parameter: &'group Parameter,
+    num_expressions: usize,
 }
 
 impl<'group, 'top> BinaryExprGroupWriter<'group, 'top> {
     fn new(
         allocator: &'top BumpAllocator,
         buffer: &'group mut BumpVec<'top, u8>,
+        bitmap_byte_position: usize,
+        bitmap_bit_offset: usize,
         value_writer_config: ValueWriterConfig,
         macros: &'group MacroTable,
         parameter: &'group Parameter,
     ) -> Self {
+        const DEFAULT_CAPACITY: usize = 128;
         Self {
             allocator,
             buffer,
+            bitmap_byte_position,
+            bitmap_bit_offset,
+            expressions_buffer: BumpVec::with_capacity_in(DEFAULT_CAPACITY, allocator),
             value_writer_config,
             macros,
-            _parameter: parameter,
+            parameter,
+            num_expressions: 0,
         }
     }
 }
@@ -571,9 +678,10 @@ impl<'top> ContextWriter for BinaryExprGroupWriter<'_, 'top> {
 
 impl MakeValueWriter for BinaryExprGroupWriter<'_, '_> {
     fn make_value_writer(&mut self) -> Self::NestedValueWriter<'_> {
+        self.num_expressions += 1;
         BinaryValueWriter_1_1::new(
             self.allocator,
-            self.buffer,
+            &mut self.expressions_buffer,
             self.value_writer_config,
             self.macros,
         )
@@ -584,6 +692,24 @@ impl SequenceWriter for BinaryExprGroupWriter<'_, '_> {
     type Resources = ();
 
     fn close(self) -> IonResult<Self::Resources> {
+        self.parameter.expect_group_size(self.num_expressions)?;
+        // Each entry in the argument encoding bitmap is `0b00` for an empty group, `0b01` for a
+        // single expression, or `0b10` for a length-prefixed group of expressions.
+        let bits = match self.num_expressions {
+            0 => 0b00,
+            1 => {
+                self.buffer
+                    .extend_from_slice_copy(self.expressions_buffer.as_slice());
+                0b01
+            }
+            _ => {
+                FlexUInt::write(self.buffer, self.expressions_buffer.len())?;
+                self.buffer
+                    .extend_from_slice_copy(self.expressions_buffer.as_slice());
+                0b10
+            }
+        };
+        self.buffer[self.bitmap_byte_position] |= bits << self.bitmap_bit_offset;
         Ok(())
     }
 }
@@ -46,6 +46,15 @@ impl FlexInt {
     ///          an appropriate error message if reading fails.
     #[inline]
     pub fn read(input: &[u8], offset: usize) -> IonResult<FlexInt> {
+        // In the common case, the encoding is 8 bytes or fewer and can be loaded as a single word.
+        // Shifting the encoding left until its highest bit is in the word's sign bit and then
+        // arithmetic-shifting it back discards both the continuation bits and any trailing bytes,
+        // sign-extending the value as it goes.
+        if let Some((num_encoded_bytes, word)) = FlexUInt::read_encoded_word(input) {
+            let unused_bits = FlexUInt::unused_bits(num_encoded_bytes);
+            let value = ((word << unused_bits) as i64) >> (unused_bits + num_encoded_bytes);
+            return Ok(FlexInt::new(num_encoded_bytes, value));
+        }
         // A FlexInt has the same structure as a FlexUInt. We can read a FlexUInt and then re-interpret
         // its unsigned bytes as two's complement bytes.
         let flex_uint = FlexUInt::read_flex_primitive_as_uint(input, offset, "reading a FlexInt")?;
@@ -68,17 +77,24 @@ impl FlexInt {
         Ok(FlexInt::new(flex_uint.size_in_bytes(), signed_value))
     }
 
+    /// Returns the number of bytes needed to encode `value` as a `FlexInt`.
+    #[inline]
+    pub fn encoded_size(value: i64) -> usize {
+        let index = if value < 0 {
+            value.leading_ones()
+        } else {
+            value.leading_zeros()
+        };
+        BYTES_NEEDED_CACHE[index as usize] as usize
+    }
+
     // This is equivalent to calling `write_i64(my_bump_vec).unwrap()`, but optimized for writing
     // to a `BumpVec` instead of a `W: Write`. Writing to a BumpVec cannot fail (barring out-of-
     // memory errors and the like), which eliminates some branching, a loop inside
     // `io::Write::write_all`, and the construction of a return value.
     #[inline]
     pub fn encode_i64(output: &mut BumpVec<'_, u8>, value: i64) {
-        let encoded_size_in_bytes = if value < 0 {
-            BYTES_NEEDED_CACHE[value.leading_ones() as usize]
-        } else {
-            BYTES_NEEDED_CACHE[value.leading_zeros() as usize]
-        } as usize;
+        let encoded_size_in_bytes = Self::encoded_size(value);
         if encoded_size_in_bytes <= 8 {
             // The entire encoding (including continuation bits) will fit in a u64.
             // `encoded_size_in_bytes` is also the number of continuation bits we need to include
@@ -93,13 +109,10 @@ impl FlexInt {
         }}
     }
 
+    /// Encodes `value` as a `FlexInt` and writes it to `output`, returning the number of bytes written.
     #[inline]
     pub fn write_i64<W: Write>(output: &mut W, value: i64) -> IonResult<usize> {
-        let encoded_size_in_bytes = if value < 0 {
-            BYTES_NEEDED_CACHE[value.leading_ones() as usize]
-        } else {
-            BYTES_NEEDED_CACHE[value.leading_zeros() as usize]
-        } as usize;
+        let encoded_size_in_bytes = Self::encoded_size(value);
         if encoded_size_in_bytes <= 8 {
             // The entire encoding (including continuation bits) will fit in a u64.
             // `encoded_size_in_bytes` is also the number of continuation bits we need to include
@@ -156,9 +169,12 @@ impl FlexInt {
         Ok(encoded_size_in_bytes)
     }
 
+    /// Returns the signed integer that was encoded.
     pub fn value(&self) -> i64 {
         self.value
     }
+
+    /// Returns the number of bytes used to encode this `FlexInt`.
     pub fn size_in_bytes(&self) -> usize {
         self.size_in_bytes
     }
@@ -340,6 +356,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn decode_flex_int_with_trailing_bytes() -> IonResult<()> {
+        // Bytes that follow the encoding must not affect the decoded value, regardless of whether
+        // they are loaded alongside it.
+        for trailing_byte in [0x00, 0xFF] {
+            for (expected_value, encoding) in FLEX_INT_TEST_CASES {
+                let mut input = encoding.to_vec();
+                input.extend_from_slice(&[trailing_byte; 8]);
+                let flex_int = FlexInt::read(&input, 0)?;
+                assert_eq!(flex_int.value(), *expected_value, "for encoding {input:x?}");
+                assert_eq!(flex_int.size_in_bytes(), encoding.len());
+            }
+        }
+        Ok(())
+    }
+
     #[test]
     fn encode_flex_int() -> IonResult<()> {
         for (value, expected_encoding) in FLEX_INT_TEST_CASES {
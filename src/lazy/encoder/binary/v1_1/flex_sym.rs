@@ -1,4 +1,5 @@
 use core::cmp::Ordering;
+use std::io::Write;
 
 use bumpalo::collections::Vec as BumpVec;
 use ice_code::ice as cold_path;
@@ -6,10 +7,13 @@ use ice_code::ice as cold_path;
 use crate::lazy::binary::raw::v1_1::type_descriptor::Opcode;
 use crate::lazy::binary::raw::v1_1::ION_1_1_OPCODES;
 use crate::lazy::encoder::binary::v1_1::flex_int::FlexInt;
+use crate::constants::v1_1::system_symbols::EMPTY_TEXT;
 use crate::raw_symbol_ref::{AsRawSymbolRef, SystemSymbol_1_1};
 use crate::IonResult;
 use crate::RawSymbolRef;
 
+/// The value encoded by a [`FlexSym`]: either a symbol or, in contexts that permit it,
+/// an opcode (for example, the end of a delimited struct).
 #[derive(Debug, Clone, Copy)]
 pub enum FlexSymValue<'top> {
     SymbolRef(RawSymbolRef<'top>),
@@ -47,20 +51,46 @@ impl<'top> FlexSym<'top> {
         };
     }
 
+    /// Encodes the provided `symbol` as a FlexSym and writes it to `output`, returning the number
+    /// of bytes written.
+    pub fn write<W: Write>(output: &mut W, symbol: impl AsRawSymbolRef) -> IonResult<usize> {
+        let symbol_token = symbol.as_raw_symbol_ref();
+        use RawSymbolRef::*;
+        match symbol_token {
+            SymbolId(sid) if sid != 0 => FlexInt::write_i64(output, sid as i64),
+            Text(text) if !text.is_empty() => {
+                let negated_num_bytes = -(text.len() as i64);
+                let flex_int_size = FlexInt::write_i64(output, negated_num_bytes)?;
+                output.write_all(text.as_bytes())?;
+                Ok(flex_int_size + text.len())
+            }
+            _ => {
+                let encoding = Self::special_case_encoding(symbol_token);
+                output.write_all(&encoding)?;
+                Ok(encoding.len())
+            }
+        }
+    }
+
     /// Encodes the empty string, symbol ID zero, or a system symbol as a FlexSym. The caller is
     /// responsible for confirming that `symbol` is one of these three cases before calling.
     fn encode_special_case(output: &mut BumpVec<'_, u8>, symbol: RawSymbolRef<'_>) {
+        output.extend_from_slice_copy(&Self::special_case_encoding(symbol));
+    }
+
+    /// Returns the encoding of the empty string, symbol ID zero, or a system symbol. The caller is
+    /// responsible for confirming that `symbol` is one of these three cases before calling.
+    fn special_case_encoding(symbol: RawSymbolRef<'_>) -> [u8; 2] {
         use RawSymbolRef::*;
-        let encoding: &[u8] = match symbol {
+        match symbol {
             // Per this method's preconditions, this branch must be SymbolId zero.
-            SymbolId(_zero) => &[FlexSym::ZERO, 0x60],
+            SymbolId(_zero) => [FlexSym::ZERO, 0x60],
             SystemSymbol_1_1(system_symbol) => {
-                &[FlexSym::ZERO, 0x60 + system_symbol.address() as u8]
+                [FlexSym::ZERO, 0x60 + system_symbol.address() as u8]
             }
             // Per this method's preconditions, this branch's text must be the empty string.
-            Text(_empty_string) => &[FlexSym::ZERO, 0x75],
-        };
-        output.extend_from_slice_copy(encoding);
+            Text(_empty_string) => [FlexSym::ZERO, 0x60 + EMPTY_TEXT.address() as u8],
+        }
     }
 
     /// Reads a [`FlexSym`] from the beginning of `input`.
@@ -108,9 +138,11 @@ impl<'top> FlexSym<'top> {
                     )),
                     0xF0 => FlexSymValue::Opcode(ION_1_1_OPCODES[byte as usize]),
                     other => {
-                        // This branch covers both e-expression encodings (not yet implemented)
-                        // and detection of illegal escape codes.
-                        todo!("FlexSym escape with byte {other:#X?}")
+                        // This branch covers both e-expression encodings (not yet supported)
+                        // and illegal escape codes.
+                        return IonResult::decoding_error(format!(
+                            "found FlexSym with unsupported escape byte {other:#X?}"
+                        ));
                     }
                 };
                 (flex_sym_value, flex_int_len + 1)
@@ -131,3 +163,49 @@ impl<'top> FlexSym<'top> {
         self.size_in_bytes
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::lazy::encoder::binary::v1_1::flex_sym::{FlexSym, FlexSymValue};
+    use crate::raw_symbol_ref::SystemSymbol_1_1;
+    use crate::{IonResult, RawSymbolRef};
+
+    fn round_trip(symbol: RawSymbolRef<'_>, expected_encoding: &[u8]) -> IonResult<()> {
+        let mut output = Vec::new();
+        let bytes_written = FlexSym::write(&mut output, symbol)?;
+        assert_eq!(bytes_written, expected_encoding.len());
+        assert_eq!(output.as_slice(), expected_encoding);
+
+        let bump = bumpalo::Bump::new();
+        let mut bump_output = bumpalo::collections::Vec::new_in(&bump);
+        FlexSym::encode_symbol(&mut bump_output, symbol);
+        assert_eq!(bump_output.as_slice(), expected_encoding);
+
+        let flex_sym = FlexSym::read(&output, 0)?;
+        assert_eq!(flex_sym.size_in_bytes(), expected_encoding.len());
+        let FlexSymValue::SymbolRef(actual) = flex_sym.value() else {
+            panic!("expected a symbol but found {:?}", flex_sym.value());
+        };
+        assert_eq!(actual, symbol);
+        Ok(())
+    }
+
+    #[test]
+    fn write_flex_syms() -> IonResult<()> {
+        round_trip(RawSymbolRef::SymbolId(4), &[0b00001001])?;
+        round_trip(RawSymbolRef::SymbolId(729), &[0b01100110, 0b00001011])?;
+        round_trip(RawSymbolRef::Text("foo"), &[0b11111011, b'f', b'o', b'o'])?;
+        round_trip(RawSymbolRef::SymbolId(0), &[FlexSym::ZERO, 0x60])?;
+        round_trip(RawSymbolRef::Text(""), &[FlexSym::ZERO, 0x81])?;
+        round_trip(
+            RawSymbolRef::SystemSymbol_1_1(SystemSymbol_1_1::new_unchecked(4)),
+            &[FlexSym::ZERO, 0x64],
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn read_unsupported_escape_is_an_error() {
+        assert!(FlexSym::read(&[FlexSym::ZERO, 0xE5], 0).is_err());
+    }
+}
@@ -23,6 +23,23 @@ const fn init_bytes_needed_cache() -> [u8; 129] {
 
 static BYTES_NEEDED_CACHE: [u8; 129] = init_bytes_needed_cache();
 
+// Compile-time mapping from the first byte of a FlexUInt or FlexInt to the number of bytes in its
+// encoding, which is one more than the number of trailing zeros in that byte. A first byte of zero
+// indicates that the encoding is longer than 8 bytes and that the next byte must be consulted;
+// its entry is zero so readers can detect this (uncommon) case and use the general-purpose path.
+// This is used when fewer than 8 bytes of input remain and a full word cannot be loaded.
+const fn init_encoded_size_lookup() -> [u8; 256] {
+    let mut table = [0u8; 256];
+    let mut first_byte = 1usize;
+    while first_byte < 256 {
+        table[first_byte] = (first_byte as u8).trailing_zeros() as u8 + 1;
+        first_byte += 1;
+    }
+    table
+}
+
+static ENCODED_SIZE_LOOKUP: [u8; 256] = init_encoded_size_lookup();
+
 /// An Ion 1.1 encoding primitive that represents a variable-length unsigned integer.
 #[derive(Debug)]
 pub struct FlexUInt {
@@ -45,43 +62,52 @@ impl FlexUInt {
     ///          an appropriate error message if reading fails.
     #[inline(always)]
     pub fn read(input: &[u8], offset: usize) -> IonResult<FlexUInt> {
-        const COMMON_CASE_INPUT_BYTES_NEEDED: usize = 4;
-        // We want to minimize the number of branches that happen in the common case. To do this,
-        // we perform a single length check, making sure that the buffer contains enough data to
-        // represent a FlexUInt whose continuation bits fit in a single byte (i.e. one with 7 or
-        // fewer bytes of magnitude). If the buffer doesn't have at least 4 bytes in it or the
-        // FlexUInt we find requires more than 4 bytes to represent, we'll fall back to the general
-        // case.
-        if input.len() >= COMMON_CASE_INPUT_BYTES_NEEDED {
-            'common_case: {
-                let num_encoded_bytes = input[0].trailing_zeros() as usize + 1;
-                // By branching on particular values, we make the value of `num_encoded_bytes` in their
-                // corresponding arm `const`. This allows us to use `read_n_bytes` to optimize for those
-                // sizes.
-                let mut buffer = [0u8; size_of::<u64>()];
-                match num_encoded_bytes {
-                    1 => Self::read_n_bytes::<1>(input, &mut buffer),
-                    2 => Self::read_n_bytes::<2>(input, &mut buffer),
-                    3 => Self::read_n_bytes::<3>(input, &mut buffer),
-                    4 => Self::read_n_bytes::<4>(input, &mut buffer),
-                    // If the number of encoded bytes isn't 1-4, fall back to the general-purpose
-                    // reading logic.
-                    _ => break 'common_case,
-                };
-                let value = u64::from_le_bytes(buffer).wrapping_shr(num_encoded_bytes as u32);
-                let flex_uint = FlexUInt::new(num_encoded_bytes, value);
-                return Ok(flex_uint);
-            }
+        // In the common case, the FlexUInt's continuation bits fit in its first byte (i.e. it has
+        // 8 or fewer encoded bytes). We can load the encoding as a single word, determine its size,
+        // and then shift away the continuation bits and any trailing bytes that follow it in
+        // the input.
+        if let Some((num_encoded_bytes, word)) = Self::read_encoded_word(input) {
+            let value = (word << Self::unused_bits(num_encoded_bytes))
+                >> (Self::unused_bits(num_encoded_bytes) + num_encoded_bytes);
+            return Ok(FlexUInt::new(num_encoded_bytes, value));
         }
         // General-purpose FlexUInt reading logic. Checks for empty input and supports FlexUInts
         // up to U64::MAX.
         Self::read_flex_primitive_as_uint(input, offset, "reading a FlexUInt")
     }
 
-    #[inline]
-    pub fn read_n_bytes<const NUM_BYTES: usize>(bytes: &[u8], buffer: &mut [u8; size_of::<u64>()]) {
-        let input: [u8; NUM_BYTES] = *(bytes.first_chunk::<NUM_BYTES>().unwrap());
-        *buffer.first_chunk_mut::<NUM_BYTES>().unwrap() = input;
+    /// If the FlexUInt/FlexInt encoding at the head of `input` is 8 bytes or fewer and is
+    /// entirely present, returns its encoded size along with a little-endian word whose low bytes
+    /// hold the encoding. The word's high bytes may contain data that follows the encoding in
+    /// the input; callers are responsible for discarding them.
+    ///
+    /// Returns `None` if the input is empty, incomplete, or holds an encoding longer than 8 bytes.
+    #[inline(always)]
+    pub(crate) fn read_encoded_word(input: &[u8]) -> Option<(usize, u64)> {
+        if let Some(bytes) = input.first_chunk::<{ size_of::<u64>() }>() {
+            // If there are at least 8 bytes available, load them all with a single (unaligned) read.
+            let word = u64::from_le_bytes(*bytes);
+            // If the first byte is non-zero, the word's trailing zeros are all in the first byte.
+            // Computing the size from the word we already loaded (rather than consulting
+            // `ENCODED_SIZE_LOOKUP`) keeps a second memory access off of this hot path.
+            let num_encoded_bytes = word.trailing_zeros() as usize + 1;
+            return (num_encoded_bytes <= size_of::<u64>()).then_some((num_encoded_bytes, word));
+        }
+        // Otherwise, we're near the end of the input. Look up the encoding's size and, if it's
+        // complete, copy it into a zero-padded buffer.
+        let num_encoded_bytes = ENCODED_SIZE_LOOKUP[*input.first()? as usize] as usize;
+        if num_encoded_bytes == 0 || num_encoded_bytes > input.len() {
+            return None;
+        }
+        let mut buffer = [0u8; size_of::<u64>()];
+        buffer[..input.len()].copy_from_slice(input);
+        Some((num_encoded_bytes, u64::from_le_bytes(buffer)))
+    }
+
+    /// Returns the number of bits in a `u64` that are not part of an encoding `num_encoded_bytes` long.
+    #[inline(always)]
+    pub(crate) const fn unused_bits(num_encoded_bytes: usize) -> usize {
+        (size_of::<u64>() - num_encoded_bytes) * 8
     }
 
     pub(crate) fn read_flex_primitive_as_uint(
@@ -147,6 +173,16 @@ impl FlexUInt {
     // magnitude should be sufficient for all but the most extreme use cases.
     const MAX_FLEX_UINT_ENCODED_SIZE_IN_BYTES: usize = size_of::<u128>();
 
+    /// Returns the number of bytes needed to encode `value` as a `FlexUInt`.
+    #[inline]
+    pub fn encoded_size(value: impl Into<UInt>) -> usize {
+        let leading_zeros = value.into().data.leading_zeros();
+        BYTES_NEEDED_CACHE[leading_zeros as usize] as usize
+    }
+
+    /// Encodes `value` as a `FlexUInt` and writes it to `output`, returning the number of bytes
+    /// written. Values with more than 112 bits of magnitude are not supported and will produce
+    /// an encoding error.
     #[inline]
     pub fn write<W: Write>(output: &mut W, value: impl Into<UInt>) -> IonResult<usize> {
         let value = value.into().data;
@@ -162,10 +198,12 @@ impl FlexUInt {
         IonResult::encoding_error("found a FlexUInt that was larger than the current limit")
     }
 
+    /// Returns the unsigned integer that was encoded.
     pub fn value(&self) -> u64 {
         self.value
     }
 
+    /// Returns the number of bytes used to encode this `FlexUInt`.
     pub fn size_in_bytes(&self) -> usize {
         self.size_in_bytes
     }
@@ -268,6 +306,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn decode_flex_uint_with_trailing_bytes() -> IonResult<()> {
+        // Bytes that follow the encoding must not affect the decoded value, regardless of whether
+        // they are loaded alongside it.
+        for trailing_byte in [0x00, 0xFF] {
+            for (expected_value, encoding) in FLEX_UINT_TEST_CASES {
+                let mut input = encoding.to_vec();
+                input.extend_from_slice(&[trailing_byte; 8]);
+                let flex_uint = FlexUInt::read(&input, 0)?;
+                assert_eq!(flex_uint.value(), *expected_value, "for encoding {input:x?}");
+                assert_eq!(flex_uint.size_in_bytes(), encoding.len());
+            }
+        }
+        Ok(())
+    }
+
     #[test]
     fn encode_flex_uint() -> IonResult<()> {
         for (value, expected_encoding) in FLEX_UINT_TEST_CASES {
@@ -3,7 +3,7 @@ use bumpalo::collections::Vec as BumpVec;
 use bumpalo::Bump as BumpAllocator;
 use ice_code::ice as cold_path;
 
-use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsVec};
+use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsBuf};
 use crate::lazy::encoder::binary::v1_1::container_writers::{
     BinaryEExpWriter_1_1, BinaryListWriter_1_1, BinarySExpWriter_1_1, BinaryStructWriter_1_1,
 };
@@ -15,8 +15,8 @@ use crate::lazy::encoder::private::Sealed;
 use crate::lazy::encoder::value_writer::ValueWriter;
 use crate::lazy::encoder::value_writer::{delegate_value_writer_to_self, AnnotatableWriter};
 use crate::lazy::encoder::value_writer_config::{
-    AnnotationsEncoding, ContainerEncoding, FieldNameEncoding, SymbolValueEncoding,
-    ValueWriterConfig,
+    AnnotationsEncoding, ContainerEncoding, EExpEncoding, FieldNameEncoding, ScalarEncoding,
+    SymbolValueEncoding, ValueWriterConfig,
 };
 use crate::lazy::expanded::template::Parameter;
 use crate::lazy::text::raw::v1_1::reader::{MacroIdLike, ModuleKind};
@@ -51,6 +51,12 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
         self.value_writer_config
     }
 
+    /// Appends an already-encoded value to the encoding buffer.
+    pub(crate) fn write_encoded(self, encoded: &[u8]) -> IonResult<()> {
+        self.encoding_buffer.extend_from_slice_copy(encoded);
+        Ok(())
+    }
+
     pub fn with_delimited_containers(mut self) -> Self {
         self.value_writer_config = self.value_writer_config.with_delimited_containers();
         self
@@ -70,6 +76,18 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
         self
     }
 
+    pub fn with_scalar_encoding(mut self, scalar_encoding: ScalarEncoding) -> Self {
+        self.value_writer_config = self
+            .value_writer_config
+            .with_scalar_encoding(scalar_encoding);
+        self
+    }
+
+    pub fn with_eexp_encoding(mut self, eexp_encoding: EExpEncoding) -> Self {
+        self.value_writer_config = self.value_writer_config.with_eexp_encoding(eexp_encoding);
+        self
+    }
+
     pub fn with_symbol_value_encoding(
         mut self,
         symbol_value_encoding: SymbolValueEncoding,
@@ -193,7 +211,10 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
             FloatRepr::Zero => {
                 self.push_byte(0x6A);
             }
-            // TODO: FloatRepr::Half => ...0x6B...
+            FloatRepr::Half(f) => {
+                self.push_byte(0x6B);
+                self.push_bytes(&f.to_le_bytes());
+            }
             FloatRepr::Single(f) => {
                 self.push_byte(0x6C);
                 self.push_bytes(&f.to_le_bytes());
@@ -208,7 +229,10 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
             FloatRepr::Zero => {
                 self.push_byte(0x6A);
             }
-            // TODO: FloatRepr::Half => ...0x6B...
+            FloatRepr::Half(f) => {
+                self.push_byte(0x6B);
+                self.push_bytes(&f.to_le_bytes());
+            }
             FloatRepr::Single(f) => {
                 self.push_byte(0x6C);
                 self.push_bytes(&f.to_le_bytes());
@@ -232,8 +256,15 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
         // and is non-trivial, so we compute it up front and store the result.
         let is_positive_zero = value.coefficient().is_positive_zero();
 
+        let long_form = self.value_writer_config.has_long_form_scalars();
+
         // If the value is 0.0, then the encoding has no body. The 0x60 opcode is the complete encoding.
         if value.exponent() == 0 && is_positive_zero {
+            if long_form {
+                // The long form of 0.0 is the 0xF7 opcode followed by a FlexUInt length of 0.
+                self.encoding_buffer[opcode_index] = 0xF7;
+                self.push_byte(0x01);
+            }
             return Ok(());
         }
 
@@ -262,13 +293,14 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
         };
 
         match encoded_body_size {
-            0..=15 => {
+            0..=15 if !long_form => {
                 // In the common case, the body of a decimal will require fewer than 16 bytes to encode.
                 // In this case, we can write the encoded body length in the low nibble of the opcode we already wrote.
                 self.encoding_buffer[opcode_index] |= encoded_body_size as u8;
             }
             _ => {
-                // If the encoded size ends up being unusually large, we will splice in a corrected header.
+                // If the encoded size ends up being unusually large (or the writer has been configured
+                // to use long-form encodings), we will splice in a corrected header.
                 // Start by overwriting our original opcode with 0xF6, which indicates a Decimal with a FlexUInt length.
                 self.encoding_buffer[opcode_index] = 0xF7;
                 // We'll use an `ArrayVec` as our encoding buffer because it's stack-allocated and implements `io::Write`.
@@ -314,7 +346,8 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
             };
 
         // If the timestamp does not meet the above criteria, we must instead encode it as a long-form timestamp.
-        if !is_short_form_eligible {
+        // We also use the long form if the writer has been configured to do so.
+        if !is_short_form_eligible || self.value_writer_config.has_long_form_scalars() {
             return self.write_long_form_timestamp(value);
         }
 
@@ -546,7 +579,17 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
     pub fn write_string<A: AsRef<str>>(mut self, value: A) -> IonResult<()> {
         const STRING_OPCODE: u8 = 0x90;
         const STRING_FLEX_UINT_LEN_OPCODE: u8 = 0xF9;
-        self.write_text(STRING_OPCODE, STRING_FLEX_UINT_LEN_OPCODE, value.as_ref());
+        let text = value.as_ref();
+        if self.value_writer_config.has_long_form_scalars() {
+            FlexUInt::encode_opcode_and_length(
+                self.encoding_buffer,
+                STRING_FLEX_UINT_LEN_OPCODE,
+                text.len() as u64,
+            );
+            self.push_bytes(text.as_bytes());
+        } else {
+            self.write_text(STRING_OPCODE, STRING_FLEX_UINT_LEN_OPCODE, text);
+        }
         Ok(())
     }
 
@@ -706,6 +749,22 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
 
         let macro_ref = macro_id.resolve(self.macros)?;
         let address = macro_ref.address();
+        if self.config().eexp_encoding() == EExpEncoding::LengthPrefixed
+            && macro_ref.module() == ModuleKind::Default
+        {
+            // Opcode, FlexUInt address. The FlexUInt length of the arguments will be written when
+            // the e-expression is closed.
+            const LENGTH_PREFIXED_EEXP_OPCODE: u8 = 0xF5;
+            self.encoding_buffer.push(LENGTH_PREFIXED_EEXP_OPCODE);
+            FlexUInt::write(self.encoding_buffer, address)?;
+            return Ok(BinaryEExpWriter_1_1::new_length_prefixed(
+                self.allocator,
+                self.encoding_buffer,
+                self.config(),
+                self.macros,
+                macro_ref,
+            ));
+        }
         match macro_ref.module() {
             ModuleKind::Default => {
                 match address {
@@ -751,6 +810,9 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
                         .expect("system macro addresses are always smaller than 256"),
                 ]); // System e-expression
             }
+            ModuleKind::Named => {
+                unreachable!("a writer's macro table cannot resolve macros in named modules")
+            }
         }
 
         Ok(BinaryEExpWriter_1_1::new(
@@ -781,7 +843,7 @@ impl<'top> AnnotatableWriter for BinaryValueWriter_1_1<'_, 'top> {
         Ok(BinaryAnnotatedValueWriter_1_1::new(
             self.allocator,
             self.encoding_buffer,
-            annotations.into_annotations_vec(),
+            annotations.into_annotations_buf(),
             self.config(),
             self.macros,
         ))
@@ -823,7 +885,7 @@ macro_rules! annotate_and_delegate_1_1 {
 }
 
 pub struct BinaryAnnotatedValueWriter_1_1<'value, 'top> {
-    annotations: AnnotationsVec<'value>,
+    annotations: AnnotationsBuf<'value>,
     allocator: &'top BumpAllocator,
     buffer: &'value mut BumpVec<'top, u8>,
     value_writer_config: ValueWriterConfig,
@@ -832,7 +894,7 @@ pub struct BinaryAnnotatedValueWriter_1_1<'value, 'top> {
 
 impl BinaryAnnotatedValueWriter_1_1<'_, '_> {
     fn encode_annotations(&mut self) {
-        match self.annotations.as_slice() {
+        match &*self.annotations {
             [] => {
                 // There are no annotations; nothing to do.
             }
@@ -857,7 +919,7 @@ impl BinaryAnnotatedValueWriter_1_1<'_, '_> {
     fn write_length_prefixed_flex_sym_annotation_sequence(&mut self) {
         // Create a temporary buffer and encode all of the annotations into it
         let mut annotations_buffer = BumpVec::new_in(self.allocator);
-        for annotation in &self.annotations {
+        for annotation in self.annotations.iter() {
             FlexSym::encode_symbol(&mut annotations_buffer, annotation);
         }
         // Write the opcode for a length-prefixed FlexSym annotations sequence
@@ -889,7 +951,7 @@ impl<'top> AnnotatableWriter for BinaryAnnotatedValueWriter_1_1<'_, 'top> {
         Ok(BinaryAnnotatedValueWriter_1_1::new(
             self.allocator,
             self.buffer,
-            annotations.into_annotations_vec(),
+            annotations.into_annotations_buf(),
             self.value_writer_config,
             self.macros,
         ))
@@ -947,7 +1009,7 @@ impl<'value, 'top> BinaryAnnotatedValueWriter_1_1<'value, 'top> {
     pub fn new(
         allocator: &'top BumpAllocator,
         buffer: &'value mut BumpVec<'top, u8>,
-        annotations: AnnotationsVec<'value>,
+        annotations: AnnotationsBuf<'value>,
         value_writer_config: ValueWriterConfig,
         macros: &'value MacroTable,
     ) -> Self {
@@ -972,29 +1034,17 @@ impl<'value, 'top> BinaryAnnotatedValueWriter_1_1<'value, 'top> {
 
 macro_rules! validate_parameter_and_delegate {
     () => {};
-    ($value_type:ty => $method:ident, $($rest:tt)*) => {
+    ($value_type:ty => $method:ident as $value_kind:literal, $($rest:tt)*) => {
         fn $method(self, value: $value_type) -> IonResult<()> {
-            use $crate::IonError;
-            use $crate::lazy::expanded::template::ParameterEncoding;
-            let _param = self
-                .parameter
-                .ok_or(IonError::encoding_error("unexpected parameter provided"))
-                .and_then(|p| p.expect_encoding(&ParameterEncoding::Tagged))
-                .and_then(|p| p.expect_single_expression())?;
-
-            let value_writer = $crate::lazy::encoder::binary::v1_1::value_writer::BinaryValueWriter_1_1::new(
-                self.allocator,
-                self.buffer,
-                self.value_writer_config,
-                self.macros,
-            );
-            value_writer.$method(value)?;
-            Ok(())
+            self.expect_tagged_parameter($value_kind)?;
+            self.value_writer().$method(value)
         }
         validate_parameter_and_delegate!($($rest)*);
     };
 }
 
+/// Writes an argument of a binary e-expression, confirming that the value being written can be
+/// encoded as specified by the corresponding parameter in the invoked macro's signature.
 pub struct BinaryEExpParameterValueWriter_1_1<'value, 'top> {
     allocator: &'top BumpAllocator,
     buffer: &'value mut BumpVec<'top, u8>,
@@ -1019,51 +1069,41 @@ impl<'value, 'top> BinaryEExpParameterValueWriter_1_1<'value, 'top> {
             parameter,
         }
     }
-}
-
-impl<'value, 'top> ValueWriter for BinaryEExpParameterValueWriter_1_1<'value, 'top> {
-    type ListWriter = BinaryListWriter_1_1<'value, 'top>;
-    type SExpWriter = BinarySExpWriter_1_1<'value, 'top>;
-    type StructWriter = BinaryStructWriter_1_1<'value, 'top>;
-    type EExpWriter = BinaryEExpWriter_1_1<'value, 'top>;
-
-    validate_parameter_and_delegate!(
-        IonType => write_null,
-        bool => write_bool,
-        &Decimal => write_decimal,
-        &Timestamp => write_timestamp,
-        impl AsRef<str> => write_string,
-        impl AsRef<[u8]> => write_clob,
-        impl AsRef<[u8]> => write_blob,
-    );
-
-    // These types need to have ranges validated to ensure we can write the value to a tagless
-    // parameter if the parameter is tagless.
 
-    fn write_symbol(self, value: impl AsRawSymbolRef) -> IonResult<()> {
-        use crate::IonError;
-        use crate::lazy::expanded::template::ParameterEncoding;
+    /// Returns the parameter for which this writer's argument is being written. If the macro's
+    /// signature has no more parameters, returns an `Err` that describes the value being written
+    /// as `value_kind`.
+    fn expect_parameter(&self, value_kind: &str) -> IonResult<&'value Parameter> {
+        match self.parameter {
+            Some(parameter) => Ok(parameter),
+            None => cold_path! {
+                IonResult::encoding_error(format!(
+                    "cannot write {value_kind} as an e-expression argument; the macro takes no more parameters"
+                ))
+            },
+        }
+    }
 
-        // TODO: Support tagless types.
-        let _param = self
-            .parameter
-            .ok_or(IonError::encoding_error("unexpected parameter provided"))
-            .and_then(|p| p.expect_encoding(&ParameterEncoding::Tagged))
-            .and_then(|p| p.expect_single_expression())?;
+    /// Confirms that the parameter accepts a single tagged value.
+    fn expect_tagged_parameter(&self, value_kind: &str) -> IonResult<&'value Parameter> {
+        self.expect_parameter(value_kind)?
+            .expect_tagged_argument(value_kind)
+    }
 
-        let value_writer = BinaryValueWriter_1_1::new(
+    fn value_writer(self) -> BinaryValueWriter_1_1<'value, 'top> {
+        BinaryValueWriter_1_1::new(
             self.allocator,
             self.buffer,
             self.value_writer_config,
             self.macros,
-        );
-        value_writer.write_symbol(value)
+        )
     }
 
-    fn write_i64(self, value: i64) -> IonResult<()> {
+    /// Writes an integer argument, using the parameter's tagless encoding if it has one.
+    fn write_integer(self, value: &Int) -> IonResult<()> {
+        use crate::lazy::expanded::template::ParameterEncoding as PE;
         use crate::IonError;
         use crate::UInt;
-        use crate::lazy::expanded::template::ParameterEncoding as PE;
 
         #[inline(never)]
         fn error_context(name: &str, err: impl std::error::Error) -> IonError {
@@ -1071,9 +1111,8 @@ impl<'value, 'top> ValueWriter for BinaryEExpParameterValueWriter_1_1<'value, 't
         }
 
         let param = self
-            .parameter
-            .ok_or(IonError::encoding_error("unexpected parameter provided"))
-            .and_then(|p| p.expect_single_expression())?;
+            .expect_parameter("an int")?
+            .expect_single_expression()?;
 
         let result = match param.encoding() {
             PE::UInt8 => value
@@ -1092,15 +1131,7 @@ impl<'value, 'top> ValueWriter for BinaryEExpParameterValueWriter_1_1<'value, 't
                 .try_into()
                 .and_then(|uint: UInt| FlexUInt::write(self.buffer, uint))
                 .map(|_| ()),
-            PE::Tagged => {
-                let value_writer = BinaryValueWriter_1_1::new(
-                    self.allocator,
-                    self.buffer,
-                    self.value_writer_config,
-                    self.macros,
-                );
-                value_writer.write_i64(value)
-            }
+            PE::Tagged => self.value_writer().write_int(value),
             encoding => IonResult::encoding_error(
                 format!("value does not satisfy encoding type {encoding}")
             ),
@@ -1108,177 +1139,60 @@ impl<'value, 'top> ValueWriter for BinaryEExpParameterValueWriter_1_1<'value, 't
 
         result.map_err(|err| error_context(param.name(), err))
     }
+}
 
-    fn write_int(self, value: &Int) -> IonResult<()> {
-        use crate::lazy::expanded::template::ParameterEncoding as PE;
-        use crate::UInt;
-        use crate::IonError;
-
-        #[inline(never)]
-        fn error_context(name: &str, err: impl std::error::Error) -> IonError {
-            IonError::encoding_error(format!("error with value provided for '{name}': {err}"))
-        }
-
-        let param = self
-            .parameter
-            .ok_or(IonError::encoding_error("unexpected parameter provided"))
-            .and_then(|p| p.expect_single_expression())?;
-
-        let result = match param.encoding() {
-            PE::UInt8 => value
-                .try_into()
-                .and_then(|uint: UInt| FixedUInt::write_as_uint::<u8>(self.buffer, uint)),
-            PE::UInt16 => value
-                .try_into()
-                .and_then(|uint: UInt| FixedUInt::write_as_uint::<u16>(self.buffer, uint)),
-            PE::UInt32 => value
-                .try_into()
-                .and_then(|uint: UInt| FixedUInt::write_as_uint::<u32>(self.buffer, uint)),
-            PE::UInt64 => value
-                .try_into()
-                .and_then(|uint: UInt| FixedUInt::write_as_uint::<u64>(self.buffer, uint)),
-            PE::FlexUInt => value
-                .try_into()
-                .and_then(|uint: UInt| FlexUInt::write(self.buffer, uint))
-                .map(|_| ()),
-            PE::Tagged => {
-                let value_writer = BinaryValueWriter_1_1::new(
-                    self.allocator,
-                    self.buffer,
-                    self.value_writer_config,
-                    self.macros,
-                );
-                value_writer.write_int(value)
-            }
-            encoding => IonResult::encoding_error(
-                format!("value does not satisfy encoding type {encoding}")
-            ),
-        };
-
-        result.map_err(|err| error_context(param.name(), err))
-    }
+impl<'value, 'top> ValueWriter for BinaryEExpParameterValueWriter_1_1<'value, 'top> {
+    type ListWriter = BinaryListWriter_1_1<'value, 'top>;
+    type SExpWriter = BinarySExpWriter_1_1<'value, 'top>;
+    type StructWriter = BinaryStructWriter_1_1<'value, 'top>;
+    type EExpWriter = BinaryEExpWriter_1_1<'value, 'top>;
 
-    fn write_f32(self, value: f32) -> IonResult<()> {
-        use crate::IonError;
-        use crate::lazy::expanded::template::ParameterEncoding;
+    // TODO: Support tagless encodings for types other than integers.
+    validate_parameter_and_delegate!(
+        IonType => write_null as "a null",
+        bool => write_bool as "a bool",
+        f32 => write_f32 as "a float",
+        f64 => write_f64 as "a float",
+        &Decimal => write_decimal as "a decimal",
+        &Timestamp => write_timestamp as "a timestamp",
+        impl AsRef<str> => write_string as "a string",
+        impl AsRawSymbolRef => write_symbol as "a symbol",
+        impl AsRef<[u8]> => write_clob as "a clob",
+        impl AsRef<[u8]> => write_blob as "a blob",
+    );
 
-        // TODO: Support tagless types.
-        let _param = self
-            .parameter
-            .ok_or(IonError::encoding_error("unexpected parameter provided"))
-            .and_then(|p| p.expect_encoding(&ParameterEncoding::Tagged))
-            .and_then(|p| p.expect_single_expression())?;
+    // Integers can be written to tagless parameters, provided they are within the range that the
+    // parameter's encoding can represent.
 
-        let value_writer = BinaryValueWriter_1_1::new(
-            self.allocator,
-            self.buffer,
-            self.value_writer_config,
-            self.macros,
-        );
-        value_writer.write_f32(value)
+    fn write_i64(self, value: i64) -> IonResult<()> {
+        self.write_integer(&value.into())
     }
 
-    fn write_f64(self, value: f64) -> IonResult<()> {
-        use crate::IonError;
-        use crate::lazy::expanded::template::ParameterEncoding;
-
-        // TODO: Support tagless types.
-        let _param = self
-            .parameter
-            .ok_or(IonError::encoding_error("unexpected parameter provided"))
-            .and_then(|p| p.expect_encoding(&ParameterEncoding::Tagged))
-            .and_then(|p| p.expect_single_expression())?;
-
-        let value_writer = BinaryValueWriter_1_1::new(
-            self.allocator,
-            self.buffer,
-            self.value_writer_config,
-            self.macros,
-        );
-        value_writer.write_f64(value)
+    fn write_int(self, value: &Int) -> IonResult<()> {
+        self.write_integer(value)
     }
 
     fn list_writer(self) -> IonResult<Self::ListWriter> {
-        use crate::IonError;
-        use crate::lazy::expanded::template::ParameterEncoding;
-
-        let _param = self
-            .parameter
-            .ok_or(IonError::encoding_error("unexpected parameter provided"))
-            .and_then(|p| p.expect_encoding(&ParameterEncoding::Tagged))
-            .and_then(|p| p.expect_single_expression())?;
-
-        let value_writer = BinaryValueWriter_1_1::new(
-            self.allocator,
-            self.buffer,
-            self.value_writer_config,
-            self.macros,
-        );
-
-        value_writer.list_writer()
+        self.expect_tagged_parameter("a list")?;
+        self.value_writer().list_writer()
     }
 
     fn sexp_writer(self) -> IonResult<Self::SExpWriter> {
-        use crate::IonError;
-        use crate::lazy::expanded::template::ParameterEncoding;
-
-        let _param = self
-            .parameter
-            .ok_or(IonError::encoding_error("unexpected parameter provided"))
-            .and_then(|p| p.expect_encoding(&ParameterEncoding::Tagged))
-            .and_then(|p| p.expect_single_expression())?;
-
-        let value_writer = BinaryValueWriter_1_1::new(
-            self.allocator,
-            self.buffer,
-            self.value_writer_config,
-            self.macros,
-        );
-
-        value_writer.sexp_writer()
+        self.expect_tagged_parameter("an s-expression")?;
+        self.value_writer().sexp_writer()
     }
 
     fn struct_writer(self) -> IonResult<Self::StructWriter> {
-        use crate::IonError;
-        use crate::lazy::expanded::template::ParameterEncoding;
-
-        let _param = self
-            .parameter
-            .ok_or(IonError::encoding_error("unexpected parameter provided"))
-            .and_then(|p| p.expect_encoding(&ParameterEncoding::Tagged))
-            .and_then(|p| p.expect_single_expression())?;
-
-        let value_writer = BinaryValueWriter_1_1::new(
-            self.allocator,
-            self.buffer,
-            self.value_writer_config,
-            self.macros,
-        );
-
-        value_writer.struct_writer()
+        self.expect_tagged_parameter("a struct")?;
+        self.value_writer().struct_writer()
     }
 
     fn eexp_writer<'a>(self, macro_id: impl MacroIdLike<'a>) -> IonResult<Self::EExpWriter>
         where
             Self: 'a
     {
-        use crate::IonError;
-        use crate::lazy::expanded::template::ParameterEncoding;
-
-        let _param = self
-            .parameter
-            .ok_or(IonError::encoding_error("unexpected parameter provided"))
-            .and_then(|p| p.expect_encoding(&ParameterEncoding::Tagged))
-            .and_then(|p| p.expect_single_expression())?;
-
-        let value_writer = BinaryValueWriter_1_1::new(
-            self.allocator,
-            self.buffer,
-            self.value_writer_config,
-            self.macros,
-        );
-
-        value_writer.eexp_writer(macro_id)
+        self.expect_tagged_parameter("an e-expression")?;
+        self.value_writer().eexp_writer(macro_id)
     }
 }
 
@@ -1295,10 +1209,12 @@ impl<'top> AnnotatableWriter for BinaryEExpParameterValueWriter_1_1<'_, 'top> {
     where
         Self: 'a,
     {
+        // Only tagged encodings can represent annotations.
+        self.expect_tagged_parameter("an annotated value")?;
         Ok(BinaryAnnotatedValueWriter_1_1::new(
                 self.allocator,
                 self.buffer,
-                annotations.into_annotations_vec(),
+                annotations.into_annotations_buf(),
                 self.value_writer_config,
                 self.macros,
         ))
@@ -1316,7 +1232,7 @@ mod tests {
     use crate::lazy::encoder::annotation_seq::AnnotationSeq;
     use crate::lazy::encoder::binary::v1_1::writer::LazyRawBinaryWriter_1_1;
     use crate::lazy::encoder::value_writer::ValueWriter;
-    use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter};
+    use crate::lazy::encoder::value_writer::{EExpWriter, SequenceWriter, StructWriter};
     use crate::lazy::encoder::write_as_ion::{WriteAsIon, WriteAsSExp};
     use crate::lazy::text::raw::v1_1::reader::{system_macros, MacroIdRef};
     use crate::raw_symbol_ref::AsRawSymbolRef;
@@ -1425,18 +1341,33 @@ mod tests {
 
     #[test]
     fn write_f32s() -> IonResult<()> {
-        let test_f64s: &[f32] = &[
-            1.0,
-            1.5,
-            -1.5,
-            10.0,
-            10.5,
-            -10.5,
-            f32::INFINITY,
-            f32::NEG_INFINITY,
-            f32::NAN,
+        // Values that can be represented losslessly in 16 bits use the half-precision encoding.
+        let test_f16s: &[(f32, [u8; 2])] = &[
+            (1.0, [0x00, 0x3C]),
+            (1.5, [0x00, 0x3E]),
+            (-1.5, [0x00, 0xBE]),
+            (10.0, [0x00, 0x49]),
+            (10.5, [0x40, 0x49]),
+            (-10.5, [0x40, 0xC9]),
+            (-0.0, [0x00, 0x80]),
+            (65504.0, [0xFF, 0x7B]),
+            (f32::INFINITY, [0x00, 0x7C]),
+            (f32::NEG_INFINITY, [0x00, 0xFC]),
         ];
-        for value in test_f64s {
+        for (value, bytes) in test_f16s {
+            let mut expected_encoding = vec![0x6B];
+            expected_encoding.extend_from_slice(&bytes[..]);
+            encoding_test(
+                |writer: &mut LazyRawBinaryWriter_1_1<&mut Vec<u8>>| {
+                    writer.write(value)?;
+                    Ok(())
+                },
+                expected_encoding.as_slice(),
+            )?;
+        }
+        // Values that would lose precision or range in 16 bits use the single-precision encoding.
+        let test_f32s: &[f32] = &[1.1, 65520.0, 1.0e-10, f32::PI(), f32::MAX];
+        for value in test_f32s {
             let mut expected_encoding = vec![0x6C];
             expected_encoding.extend_from_slice(&value.to_le_bytes()[..]);
             encoding_test(
@@ -1450,6 +1381,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn write_nans() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_1::new(&mut buffer)?;
+        writer.write(f32::NAN)?;
+        writer.write(f64::NAN)?;
+        writer.flush()?;
+        // Both NaNs are written in half precision. The payload bits are not specified.
+        assert_eq!(buffer[4], 0x6B);
+        assert_eq!(buffer[7], 0x6B);
+        assert_eq!(buffer.len(), 10);
+        Ok(())
+    }
+
     #[test]
     fn write_f64s() -> IonResult<()> {
         let test_f64s: &[f64] = &[
@@ -1472,6 +1417,10 @@ mod tests {
                 FloatRepr::Zero => {
                     expected_encoding.push(0x6A);
                 }
+                FloatRepr::Half(f) => {
+                    expected_encoding.push(0x6B);
+                    expected_encoding.extend_from_slice(&f.to_le_bytes()[..]);
+                }
                 FloatRepr::Single(f) => {
                     expected_encoding.push(0x6C);
                     expected_encoding.extend_from_slice(&f.to_le_bytes()[..]);
@@ -1610,6 +1559,59 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn write_long_form_scalars() -> IonResult<()> {
+        use crate::lazy::encoder::value_writer_config::ScalarEncoding;
+        let timestamp = Timestamp::with_year(2024).build()?;
+        let test_cases: &[(Element, &[u8])] = &[
+            (Element::string(""), &[0xF9, 0x01]),
+            //                                      f     o     o
+            (Element::string("foo"), &[0xF9, 0x07, 0x66, 0x6F, 0x6F]),
+            (Decimal::new(0, 0).into(), &[0xF7, 0x01]),
+            (Decimal::new(7, 4).into(), &[0xF7, 0x05, 0x09, 0x07]),
+            //                       FlexUInt length: 2, year: 2024
+            (timestamp.into(), &[0xF8, 0x05, 0xE8, 0x07]),
+        ];
+        for (value, expected_encoding) in test_cases {
+            encoding_test(
+                |writer: &mut LazyRawBinaryWriter_1_1<&mut Vec<u8>>| {
+                    value.write_as_ion(
+                        writer
+                            .value_writer()
+                            .with_scalar_encoding(ScalarEncoding::LongForm),
+                    )
+                },
+                expected_encoding,
+            )?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn long_form_scalars_round_trip() -> IonResult<()> {
+        use crate::lazy::encoder::value_writer_config::ScalarEncoding;
+        use crate::WriteConfig;
+        let expected = Element::read_all(
+            r#"
+                "" "foo" "foo bar baz quux quuz"
+                0. 0d3 -0d-3 7d4 3.1415926535
+                2024T 2024-06-15 2024-06-15T12:30Z 2024-06-15T12:30:45.123-05:00
+                1969-01-01T00:00:00.123456789Z
+            "#,
+        )?;
+        let config =
+            WriteConfig::<v1_1::Binary>::new().with_scalar_encoding(ScalarEncoding::LongForm);
+        let long_form = config.encode_all(&expected)?;
+        let short_form = WriteConfig::<v1_1::Binary>::new().encode_all(&expected)?;
+        assert!(long_form.len() > short_form.len());
+        let actual = Element::read_all(long_form)?;
+        assert!(
+            expected.ion_eq(&actual),
+            "expected: {expected:?}\nactual: {actual:?}"
+        );
+        Ok(())
+    }
+
     #[test]
     fn write_timestamps() -> IonResult<()> {
         let test_cases: &[(&str, &[u8])] = &[
@@ -3248,7 +3250,7 @@ mod tests {
             &[
                 0xE8, // Two FlexSym annotations follow
                 0x01, // FlexSymOpcode follows
-                0x75, // String of length 0
+                0x81, // System symbol $33: empty text
                 0x01, // FlexSymOpcode follows
                 0x60, // FlexSymOpcode: $0
                 0x60, // Integer 0
@@ -3280,6 +3282,75 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn write_length_prefixed_macro_invocations() -> IonResult<()> {
+        use crate::lazy::encoder::value_writer_config::EExpEncoding;
+        encoding_test(
+            |writer: &mut LazyRawBinaryWriter_1_1<&mut Vec<u8>>| {
+                let mut args = writer
+                    .value_writer()
+                    .with_eexp_encoding(EExpEncoding::LengthPrefixed)
+                    .eexp_writer(7)?; // sum
+                args
+                    .write_i64(5)?
+                    .write_i64(6)?;
+                args.close()?;
+                // System macro invocations cannot be length-prefixed.
+                let mut args = writer
+                    .value_writer()
+                    .with_eexp_encoding(EExpEncoding::LengthPrefixed)
+                    .eexp_writer(MacroIdRef::SystemAddress(system_macros::SUM))?;
+                args
+                    .write_i64(5)?
+                    .write_i64(6)?;
+                args.close()
+            },
+            &[
+                0xF5, // Length-prefixed e-expression
+                0x0F, // FlexUInt macro address 7
+                0x09, // FlexUInt length 4
+                0x61, 0x05, // 5
+                0x61, 0x06, // 6
+                0xEF, 0x07, // Invoke system macro address 7 (sum)
+                0x61, 0x05, // 5
+                0x61, 0x06, // 6
+            ],
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn write_macro_invocations_with_expr_groups() -> IonResult<()> {
+        encoding_test(
+            |writer: &mut LazyRawBinaryWriter_1_1<&mut Vec<u8>>| {
+                let values = MacroIdRef::SystemAddress(system_macros::VALUES);
+                for group in [&[][..], &[5][..], &[5, 6][..]] {
+                    let mut args = writer.eexp_writer(values)?;
+                    let mut group_writer = args.expr_group_writer()?;
+                    for value in group {
+                        group_writer.write_i64(*value)?;
+                    }
+                    group_writer.close()?;
+                    args.close()?;
+                }
+                Ok(())
+            },
+            &[
+                0xEF, 0x01, // Invoke system macro address 1 (values)
+                0x00, // Bitmap: empty group
+                0xEF, 0x01, // Invoke system macro address 1 (values)
+                0x01, // Bitmap: single expression
+                0x61, 0x05, // 5
+                0xEF, 0x01, // Invoke system macro address 1 (values)
+                0x02, // Bitmap: expression group
+                0x09, // FlexUInt length 4
+                0x61, 0x05, // 5
+                0x61, 0x06, // 6
+            ],
+        )?;
+        Ok(())
+    }
+
     #[test]
     fn write_system_macro_invocation() -> IonResult<()> {
         encoding_test(
@@ -30,6 +30,8 @@ pub struct LazyRawBinaryWriter_1_1<W: Write> {
     // definitions when writing e-expressions. As such, it owns its macro table and callers can
     // use `macro_table_mut()` to modify it as needed.
     macros: WriterMacroTable,
+    // The configuration used for each top-level value writer.
+    value_writer_config: ValueWriterConfig,
     // A pointer to the bump-allocated top-level encoding buffer, if set.
     //
     // This buffer is constructed in `allocator` above, a region of memory over which we have
@@ -56,6 +58,8 @@ impl<W: Write> LazyRawBinaryWriter_1_1<W> {
             output,
             allocator: BumpAllocator::with_capacity(DEFAULT_BUMP_SIZE),
             macros: WriterMacroTable::new(MacroTable::with_system_macros(IonVersion::v1_1)),
+            // By default, writers use length-prefixed encodings.
+            value_writer_config: ValueWriterConfig::default(),
             encoding_buffer_ptr: None,
         })
     }
@@ -119,8 +123,7 @@ impl<W: Write> LazyRawBinaryWriter_1_1<W> {
         BinaryValueWriter_1_1::new(
             &self.allocator,
             top_level,
-            // By default, writers use length-prefixed encodings.
-            ValueWriterConfig::default(),
+            self.value_writer_config,
             &self.macros,
         )
     }
@@ -141,7 +144,11 @@ impl<W: Write> LazyRawWriter<W> for LazyRawBinaryWriter_1_1<W> {
             WriteConfigKind::Text(_) => {
                 unreachable!("Text writer can not be created from binary encoding")
             }
-            WriteConfigKind::Binary(_) => LazyRawBinaryWriter_1_1::new(output),
+            WriteConfigKind::Binary(_) => {
+                let mut writer = LazyRawBinaryWriter_1_1::new(output)?;
+                writer.value_writer_config = config.value_writer_config();
+                Ok(writer)
+            }
         }
     }
 
@@ -171,6 +178,10 @@ impl<W: Write> LazyRawWriter<W> for LazyRawBinaryWriter_1_1<W> {
         self.output.write_all(&[0xE0, 0x01, 0x01, 0xEA])?;
         Ok(())
     }
+
+    fn write_encoded_value(&mut self, encoded: &[u8]) -> IonResult<()> {
+        self.value_writer().write_encoded(encoded)
+    }
 }
 
 impl<W: Write> ContextWriter for LazyRawBinaryWriter_1_1<W> {
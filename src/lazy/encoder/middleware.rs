@@ -0,0 +1,765 @@
+//! A layer of hooks that can be wrapped around any [`SequenceWriter`] to inspect or alter every
+//! value that passes through it.
+//!
+//! A [`MiddlewareWriter`] forwards each value it is given to the writer it wraps, invoking its
+//! [`WriterMiddleware`] for every value along the way--including values nested inside containers.
+//! This allows cross-cutting concerns like redacting fields, stamping annotations onto values,
+//! counting values, or enforcing the shape of the data to be applied without changing the code
+//! that does the writing. Because the layer is built on the [`ValueWriter`] and [`StructWriter`]
+//! traits, it works with every encoding.
+
+use std::cell::Cell;
+
+use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsBuf, AnnotationsVec};
+use crate::lazy::encoder::value_writer::internal::{FieldEncoder, MakeValueWriter};
+use crate::lazy::encoder::value_writer::{
+    AnnotatableWriter, ContextWriter, SequenceWriter, StructWriter, ValueWriter,
+};
+use crate::lazy::encoder::value_writer_config::ValueWriterConfig;
+use crate::lazy::text::raw::v1_1::reader::MacroIdLike;
+use crate::raw_symbol_ref::AsRawSymbolRef;
+use crate::result::IonFailure;
+use crate::{Decimal, Element, Int, IonResult, IonType, RawSymbolRef, Symbol, Timestamp};
+
+/// Hooks that a [`MiddlewareWriter`] invokes as values are written.
+///
+/// Every method has a default implementation that leaves the data unchanged, so implementations
+/// only need to override the hooks they are interested in. Hooks take `&self`; middleware that
+/// keeps state (like a counter) should use interior mutability.
+///
+/// Middleware can be composed by wrapping a tuple: the hooks of `(A, B)` run those of `A` and
+/// then those of `B`.
+///
+/// Values written as part of a [`FieldAction::Replace`] and the arguments of e-expressions are
+/// passed to the underlying writer as-is.
+pub trait WriterMiddleware {
+    /// Adds annotations to the value described by `context`. They are written after any
+    /// annotations that the application provided.
+    fn add_annotations<'a>(
+        &'a self,
+        _context: &ValueContext<'_>,
+        _annotations: &mut AnnotationsVec<'a>,
+    ) {
+    }
+
+    /// Called before each value is written, after [`add_annotations`](Self::add_annotations). If
+    /// this returns an `Err`, the value is not written and the error is returned to the caller.
+    fn on_value(&self, _context: &ValueContext<'_>) -> IonResult<()> {
+        Ok(())
+    }
+
+    /// Decides what to do with a struct field named `name` in a struct at the given `depth`. If
+    /// this returns an `Err`, the field is not written and the error is returned to the caller.
+    fn on_field(&self, _name: RawSymbolRef<'_>, _depth: usize) -> IonResult<FieldAction> {
+        Ok(FieldAction::Write)
+    }
+}
+
+impl<A: WriterMiddleware, B: WriterMiddleware> WriterMiddleware for (A, B) {
+    fn add_annotations<'a>(
+        &'a self,
+        context: &ValueContext<'_>,
+        annotations: &mut AnnotationsVec<'a>,
+    ) {
+        self.0.add_annotations(context, annotations);
+        self.1.add_annotations(context, annotations);
+    }
+
+    fn on_value(&self, context: &ValueContext<'_>) -> IonResult<()> {
+        self.0.on_value(context)?;
+        self.1.on_value(context)
+    }
+
+    fn on_field(&self, name: RawSymbolRef<'_>, depth: usize) -> IonResult<FieldAction> {
+        match self.0.on_field(name, depth)? {
+            FieldAction::Write => self.1.on_field(name, depth),
+            action => Ok(action),
+        }
+    }
+}
+
+/// Describes a value that is about to be written.
+#[derive(Copy, Clone, Debug)]
+pub struct ValueContext<'a> {
+    depth: usize,
+    ion_type: IonType,
+    annotations: &'a [RawSymbolRef<'a>],
+}
+
+impl<'a> ValueContext<'a> {
+    /// Returns the number of containers the value is nested in. Top-level values have a depth
+    /// of `0`.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn ion_type(&self) -> IonType {
+        self.ion_type
+    }
+
+    /// Returns the value's annotations. When passed to
+    /// [`WriterMiddleware::add_annotations`], these are the annotations provided by the
+    /// application. When passed to [`WriterMiddleware::on_value`], they also include any
+    /// annotations added by the middleware.
+    pub fn annotations(&self) -> &'a [RawSymbolRef<'a>] {
+        self.annotations
+    }
+}
+
+/// What a [`MiddlewareWriter`] should do with a struct field.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldAction {
+    /// Write the field as-is.
+    Write,
+    /// Leave the field out of the struct.
+    Omit,
+    /// Write the field with the provided value in place of the application's.
+    Replace(Element),
+}
+
+/// A [`SequenceWriter`] that passes each value through a [`WriterMiddleware`] on its way to
+/// another `SequenceWriter`, typically a [`Writer`](crate::Writer).
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{v1_0, Element, MiddlewareWriter, RedactFields, SequenceWriter, StampAnnotations, StructWriter, ValueWriter, Writer};
+///
+/// let writer = Writer::new(v1_0::Text, Vec::new())?;
+/// let middleware = (
+///     RedactFields::replace(["password"], "********"),
+///     StampAnnotations::new(["audited"]),
+/// );
+/// let mut writer = MiddlewareWriter::new(writer, middleware);
+/// let mut user = writer.struct_writer()?;
+/// user.write("name", "alice")?.write("password", "hunter2")?;
+/// user.close()?;
+/// let data = writer.close()?;
+///
+/// let expected = Element::read_one(r#"audited::{name: "alice", password: "********"}"#)?;
+/// assert_eq!(Element::read_one(data)?, expected);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub struct MiddlewareWriter<W, M> {
+    writer: W,
+    middleware: M,
+}
+
+impl<W: SequenceWriter, M: WriterMiddleware> MiddlewareWriter<W, M> {
+    pub fn new(writer: W, middleware: M) -> Self {
+        Self { writer, middleware }
+    }
+
+    pub fn middleware(&self) -> &M {
+        &self.middleware
+    }
+
+    /// Returns a reference to the wrapped writer. Values written to it directly bypass the
+    /// middleware.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns a mutable reference to the wrapped writer. Values written to it directly bypass
+    /// the middleware.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    pub fn into_parts(self) -> (W, M) {
+        (self.writer, self.middleware)
+    }
+}
+
+impl<W: SequenceWriter, M: WriterMiddleware> ContextWriter for MiddlewareWriter<W, M> {
+    type NestedValueWriter<'a>
+        = MiddlewareValueWriter<'a, W::NestedValueWriter<'a>, M>
+    where
+        Self: 'a;
+}
+
+impl<W: SequenceWriter, M: WriterMiddleware> MakeValueWriter for MiddlewareWriter<W, M> {
+    fn make_value_writer(&mut self) -> Self::NestedValueWriter<'_> {
+        MiddlewareValueWriter::new(Some(self.writer.make_value_writer()), &self.middleware, 0)
+    }
+}
+
+impl<W: SequenceWriter, M: WriterMiddleware> SequenceWriter for MiddlewareWriter<W, M> {
+    type Resources = W::Resources;
+
+    fn close(self) -> IonResult<Self::Resources> {
+        self.writer.close()
+    }
+}
+
+/// The [`ValueWriter`] used for each value written through a [`MiddlewareWriter`].
+pub struct MiddlewareValueWriter<'m, V, M> {
+    // `None` if the value belongs to a field that the middleware omitted. The value is accepted
+    // and then discarded.
+    value_writer: Option<V>,
+    annotations: AnnotationsBuf<'m>,
+    middleware: &'m M,
+    depth: usize,
+}
+
+impl<'m, V: ValueWriter + 'm, M: WriterMiddleware> MiddlewareValueWriter<'m, V, M> {
+    fn new(value_writer: Option<V>, middleware: &'m M, depth: usize) -> Self {
+        Self {
+            value_writer,
+            annotations: AnnotationsBuf::default(),
+            middleware,
+            depth,
+        }
+    }
+
+    /// Runs the middleware's hooks for a value of the given type, returning the underlying writer
+    /// with the complete set of annotations applied. Returns `None` if the value is being
+    /// discarded.
+    fn prepare(self, ion_type: IonType) -> IonResult<Option<V::AnnotatedValueWriter<'m>>> {
+        let Some(value_writer) = self.value_writer else {
+            return Ok(None);
+        };
+        let mut annotations = self.annotations;
+        let mut added = AnnotationsVec::new();
+        self.middleware.add_annotations(
+            &ValueContext {
+                depth: self.depth,
+                ion_type,
+                annotations: &annotations,
+            },
+            &mut added,
+        );
+        if !added.is_empty() {
+            annotations.to_mut().extend(added);
+        }
+        self.middleware.on_value(&ValueContext {
+            depth: self.depth,
+            ion_type,
+            annotations: &annotations,
+        })?;
+        value_writer.with_annotations(annotations).map(Some)
+    }
+
+    fn write_scalar(
+        self,
+        ion_type: IonType,
+        write: impl FnOnce(V::AnnotatedValueWriter<'m>) -> IonResult<()>,
+    ) -> IonResult<()> {
+        match self.prepare(ion_type)? {
+            Some(value_writer) => write(value_writer),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<V: ValueWriter, M: WriterMiddleware> AnnotatableWriter for MiddlewareValueWriter<'_, V, M> {
+    type AnnotatedValueWriter<'a>
+        = MiddlewareValueWriter<'a, V, M>
+    where
+        Self: 'a;
+
+    fn with_annotations<'a>(
+        self,
+        annotations: impl AnnotationSeq<'a>,
+    ) -> IonResult<Self::AnnotatedValueWriter<'a>>
+    where
+        Self: 'a,
+    {
+        Ok(MiddlewareValueWriter {
+            value_writer: self.value_writer,
+            annotations: annotations.into_annotations_buf(),
+            middleware: self.middleware,
+            depth: self.depth,
+        })
+    }
+}
+
+impl<'m, V: ValueWriter + 'm, M: WriterMiddleware> ValueWriter for MiddlewareValueWriter<'m, V, M> {
+    type ListWriter =
+        MiddlewareSequenceWriter<'m, <V::AnnotatedValueWriter<'m> as ValueWriter>::ListWriter, M>;
+    type SExpWriter =
+        MiddlewareSequenceWriter<'m, <V::AnnotatedValueWriter<'m> as ValueWriter>::SExpWriter, M>;
+    type StructWriter =
+        MiddlewareStructWriter<'m, <V::AnnotatedValueWriter<'m> as ValueWriter>::StructWriter, M>;
+    // E-expression arguments are not passed through the middleware.
+    type EExpWriter = V::EExpWriter;
+
+    fn write_null(self, ion_type: IonType) -> IonResult<()> {
+        self.write_scalar(ion_type, |writer| writer.write_null(ion_type))
+    }
+
+    fn write_bool(self, value: bool) -> IonResult<()> {
+        self.write_scalar(IonType::Bool, |writer| writer.write_bool(value))
+    }
+
+    fn write_i64(self, value: i64) -> IonResult<()> {
+        self.write_scalar(IonType::Int, |writer| writer.write_i64(value))
+    }
+
+    fn write_int(self, value: &Int) -> IonResult<()> {
+        self.write_scalar(IonType::Int, |writer| writer.write_int(value))
+    }
+
+    fn write_f32(self, value: f32) -> IonResult<()> {
+        self.write_scalar(IonType::Float, |writer| writer.write_f32(value))
+    }
+
+    fn write_f64(self, value: f64) -> IonResult<()> {
+        self.write_scalar(IonType::Float, |writer| writer.write_f64(value))
+    }
+
+    fn write_decimal(self, value: &Decimal) -> IonResult<()> {
+        self.write_scalar(IonType::Decimal, |writer| writer.write_decimal(value))
+    }
+
+    fn write_timestamp(self, value: &Timestamp) -> IonResult<()> {
+        self.write_scalar(IonType::Timestamp, |writer| writer.write_timestamp(value))
+    }
+
+    fn write_string(self, value: impl AsRef<str>) -> IonResult<()> {
+        self.write_scalar(IonType::String, |writer| writer.write_string(value))
+    }
+
+    fn write_symbol(self, value: impl AsRawSymbolRef) -> IonResult<()> {
+        self.write_scalar(IonType::Symbol, |writer| writer.write_symbol(value))
+    }
+
+    fn write_clob(self, value: impl AsRef<[u8]>) -> IonResult<()> {
+        self.write_scalar(IonType::Clob, |writer| writer.write_clob(value))
+    }
+
+    fn write_blob(self, value: impl AsRef<[u8]>) -> IonResult<()> {
+        self.write_scalar(IonType::Blob, |writer| writer.write_blob(value))
+    }
+
+    fn list_writer(self) -> IonResult<Self::ListWriter> {
+        let (middleware, depth) = (self.middleware, self.depth);
+        let list_writer = match self.prepare(IonType::List)? {
+            Some(value_writer) => Some(value_writer.list_writer()?),
+            None => None,
+        };
+        Ok(MiddlewareSequenceWriter::new(
+            list_writer,
+            middleware,
+            depth + 1,
+        ))
+    }
+
+    fn sexp_writer(self) -> IonResult<Self::SExpWriter> {
+        let (middleware, depth) = (self.middleware, self.depth);
+        let sexp_writer = match self.prepare(IonType::SExp)? {
+            Some(value_writer) => Some(value_writer.sexp_writer()?),
+            None => None,
+        };
+        Ok(MiddlewareSequenceWriter::new(
+            sexp_writer,
+            middleware,
+            depth + 1,
+        ))
+    }
+
+    fn struct_writer(self) -> IonResult<Self::StructWriter> {
+        let (middleware, depth) = (self.middleware, self.depth);
+        let struct_writer = match self.prepare(IonType::Struct)? {
+            Some(value_writer) => Some(value_writer.struct_writer()?),
+            None => None,
+        };
+        Ok(MiddlewareStructWriter::new(
+            struct_writer,
+            middleware,
+            depth + 1,
+        ))
+    }
+
+    fn eexp_writer<'a>(self, macro_id: impl MacroIdLike<'a>) -> IonResult<Self::EExpWriter>
+    where
+        Self: 'a,
+    {
+        if !self.annotations.is_empty() {
+            return IonResult::illegal_operation("e-expressions cannot be annotated");
+        }
+        match self.value_writer {
+            Some(value_writer) => value_writer.eexp_writer(macro_id),
+            None => IonResult::illegal_operation(
+                "cannot write an e-expression as the value of an omitted field",
+            ),
+        }
+    }
+
+    fn inline_sexp_writer(self) -> IonResult<Self::SExpWriter> {
+        let (middleware, depth) = (self.middleware, self.depth);
+        let sexp_writer = match self.prepare(IonType::SExp)? {
+            Some(value_writer) => Some(value_writer.inline_sexp_writer()?),
+            None => None,
+        };
+        Ok(MiddlewareSequenceWriter::new(
+            sexp_writer,
+            middleware,
+            depth + 1,
+        ))
+    }
+}
+
+/// The writer used for lists and s-expressions written through a [`MiddlewareWriter`].
+pub struct MiddlewareSequenceWriter<'m, S, M> {
+    // `None` if the container is being discarded.
+    sequence_writer: Option<S>,
+    middleware: &'m M,
+    // The depth of the container's child values.
+    depth: usize,
+}
+
+impl<'m, S: SequenceWriter, M: WriterMiddleware> MiddlewareSequenceWriter<'m, S, M> {
+    fn new(sequence_writer: Option<S>, middleware: &'m M, depth: usize) -> Self {
+        Self {
+            sequence_writer,
+            middleware,
+            depth,
+        }
+    }
+}
+
+impl<S: SequenceWriter, M: WriterMiddleware> ContextWriter for MiddlewareSequenceWriter<'_, S, M> {
+    type NestedValueWriter<'a>
+        = MiddlewareValueWriter<'a, S::NestedValueWriter<'a>, M>
+    where
+        Self: 'a;
+}
+
+impl<S: SequenceWriter, M: WriterMiddleware> MakeValueWriter
+    for MiddlewareSequenceWriter<'_, S, M>
+{
+    fn make_value_writer(&mut self) -> Self::NestedValueWriter<'_> {
+        let value_writer = self
+            .sequence_writer
+            .as_mut()
+            .map(|writer| writer.make_value_writer());
+        MiddlewareValueWriter::new(value_writer, self.middleware, self.depth)
+    }
+}
+
+impl<S: SequenceWriter<Resources = ()>, M: WriterMiddleware> SequenceWriter
+    for MiddlewareSequenceWriter<'_, S, M>
+{
+    type Resources = ();
+
+    fn close(self) -> IonResult<Self::Resources> {
+        match self.sequence_writer {
+            Some(writer) => writer.close(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The [`StructWriter`] used for structs written through a [`MiddlewareWriter`].
+pub struct MiddlewareStructWriter<'m, S, M> {
+    // `None` if the struct is being discarded.
+    struct_writer: Option<S>,
+    middleware: &'m M,
+    // The depth of the struct's field values.
+    depth: usize,
+    // Set when the middleware omits or replaces a field; the application's value for that field
+    // is then discarded.
+    discard_next_value: bool,
+}
+
+impl<'m, S: StructWriter, M: WriterMiddleware> MiddlewareStructWriter<'m, S, M> {
+    fn new(struct_writer: Option<S>, middleware: &'m M, depth: usize) -> Self {
+        Self {
+            struct_writer,
+            middleware,
+            depth,
+            discard_next_value: false,
+        }
+    }
+}
+
+impl<S: StructWriter, M: WriterMiddleware> ContextWriter for MiddlewareStructWriter<'_, S, M> {
+    type NestedValueWriter<'a>
+        = MiddlewareValueWriter<'a, S::NestedValueWriter<'a>, M>
+    where
+        Self: 'a;
+}
+
+impl<S: StructWriter, M: WriterMiddleware> MakeValueWriter for MiddlewareStructWriter<'_, S, M> {
+    fn make_value_writer(&mut self) -> Self::NestedValueWriter<'_> {
+        let discard = std::mem::take(&mut self.discard_next_value);
+        let value_writer = match self.struct_writer.as_mut() {
+            Some(writer) if !discard => Some(writer.make_value_writer()),
+            _ => None,
+        };
+        MiddlewareValueWriter::new(value_writer, self.middleware, self.depth)
+    }
+}
+
+impl<S: StructWriter, M: WriterMiddleware> FieldEncoder for MiddlewareStructWriter<'_, S, M> {
+    fn encode_field_name(&mut self, name: impl AsRawSymbolRef) -> IonResult<()> {
+        let Some(struct_writer) = self.struct_writer.as_mut() else {
+            // The struct is being discarded; there's nothing to decide.
+            return Ok(());
+        };
+        let name = name.as_raw_symbol_ref();
+        match self.middleware.on_field(name, self.depth)? {
+            FieldAction::Write => struct_writer.encode_field_name(name),
+            FieldAction::Omit => {
+                self.discard_next_value = true;
+                Ok(())
+            }
+            FieldAction::Replace(element) => {
+                struct_writer.encode_field_name(name)?;
+                struct_writer.make_value_writer().write(&element)?;
+                self.discard_next_value = true;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<S: StructWriter, M: WriterMiddleware> StructWriter for MiddlewareStructWriter<'_, S, M> {
+    fn close(self) -> IonResult<()> {
+        match self.struct_writer {
+            Some(writer) => writer.close(),
+            None => Ok(()),
+        }
+    }
+
+    fn config(&self) -> ValueWriterConfig {
+        self.struct_writer
+            .as_ref()
+            .map(|writer| writer.config())
+            .unwrap_or_default()
+    }
+}
+
+/// Middleware that omits or replaces the value of every struct field with one of the provided
+/// names, at any depth. Only field names written as text are matched.
+#[derive(Clone, Debug)]
+pub struct RedactFields {
+    field_names: Vec<String>,
+    replacement: Option<Element>,
+}
+
+impl RedactFields {
+    /// Leaves fields with any of the provided names out of the data.
+    pub fn omit<I: IntoIterator<Item = S>, S: Into<String>>(field_names: I) -> Self {
+        Self {
+            field_names: field_names.into_iter().map(Into::into).collect(),
+            replacement: None,
+        }
+    }
+
+    /// Writes `replacement` in place of the value of fields with any of the provided names.
+    pub fn replace<I: IntoIterator<Item = S>, S: Into<String>>(
+        field_names: I,
+        replacement: impl Into<Element>,
+    ) -> Self {
+        Self {
+            field_names: field_names.into_iter().map(Into::into).collect(),
+            replacement: Some(replacement.into()),
+        }
+    }
+}
+
+impl WriterMiddleware for RedactFields {
+    fn on_field(&self, name: RawSymbolRef<'_>, _depth: usize) -> IonResult<FieldAction> {
+        let RawSymbolRef::Text(text) = name else {
+            return Ok(FieldAction::Write);
+        };
+        if !self.field_names.iter().any(|field_name| field_name == text) {
+            return Ok(FieldAction::Write);
+        }
+        Ok(match &self.replacement {
+            Some(replacement) => FieldAction::Replace(replacement.clone()),
+            None => FieldAction::Omit,
+        })
+    }
+}
+
+/// Middleware that adds the provided annotations to every top-level value.
+#[derive(Clone, Debug)]
+pub struct StampAnnotations {
+    annotations: Vec<Symbol>,
+}
+
+impl StampAnnotations {
+    pub fn new<I: IntoIterator<Item = A>, A: Into<Symbol>>(annotations: I) -> Self {
+        Self {
+            annotations: annotations.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl WriterMiddleware for StampAnnotations {
+    fn add_annotations<'a>(
+        &'a self,
+        context: &ValueContext<'_>,
+        annotations: &mut AnnotationsVec<'a>,
+    ) {
+        if context.depth() == 0 {
+            annotations.extend(self.annotations.iter().map(RawSymbolRef::from));
+        }
+    }
+}
+
+/// Middleware that counts the values that are written.
+#[derive(Clone, Debug, Default)]
+pub struct CountValues {
+    num_values: Cell<usize>,
+    num_top_level_values: Cell<usize>,
+}
+
+impl CountValues {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of values that have been written at any depth.
+    pub fn num_values(&self) -> usize {
+        self.num_values.get()
+    }
+
+    pub fn num_top_level_values(&self) -> usize {
+        self.num_top_level_values.get()
+    }
+}
+
+impl WriterMiddleware for CountValues {
+    fn on_value(&self, context: &ValueContext<'_>) -> IonResult<()> {
+        self.num_values.set(self.num_values.get() + 1);
+        if context.depth() == 0 {
+            self.num_top_level_values
+                .set(self.num_top_level_values.get() + 1);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "experimental-ion-1-1")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{v1_0, v1_1, Encoding, IonError, Sequence, WriteConfig, Writer};
+
+    fn write_through<E: Encoding, M: WriterMiddleware>(
+        encoding: impl Into<WriteConfig<E>>,
+        middleware: M,
+        data: &str,
+    ) -> IonResult<(Sequence, M)> {
+        let writer = Writer::new(encoding, Vec::new())?;
+        let mut writer = MiddlewareWriter::new(writer, middleware);
+        for element in Element::read_all(data)? {
+            writer.write(&element)?;
+        }
+        let (writer, middleware) = writer.into_parts();
+        let output = writer.close()?;
+        Ok((Element::read_all(output)?, middleware))
+    }
+
+    fn assert_written_as<M: WriterMiddleware + Clone>(
+        middleware: M,
+        data: &str,
+        expected: &str,
+    ) -> IonResult<()> {
+        let expected = Element::read_all(expected)?;
+        let (text_1_0, _) = write_through(v1_0::Text, middleware.clone(), data)?;
+        let (binary_1_0, _) = write_through(v1_0::Binary, middleware.clone(), data)?;
+        let (text_1_1, _) = write_through(v1_1::Text, middleware.clone(), data)?;
+        let (binary_1_1, _) = write_through(v1_1::Binary, middleware, data)?;
+        assert_eq!(text_1_0, expected);
+        assert_eq!(binary_1_0, expected);
+        assert_eq!(text_1_1, expected);
+        assert_eq!(binary_1_1, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn omit_fields() -> IonResult<()> {
+        assert_written_as(
+            RedactFields::omit(["secret"]),
+            r#"
+                {name: "a", secret: [1, {x: 2}], inner: {secret: foo::"b", ok: 3}}
+                [{secret: 4}]
+                secret
+            "#,
+            r#"
+                {name: "a", inner: {ok: 3}}
+                [{}]
+                secret
+            "#,
+        )
+    }
+
+    #[test]
+    fn replace_fields() -> IonResult<()> {
+        assert_written_as(
+            RedactFields::replace(["ssn", "pin"], "[redacted]"),
+            r#"{ssn: "123-45-6789", pin: (1 2 3 4), name: "a"}"#,
+            r#"{ssn: "[redacted]", pin: "[redacted]", name: "a"}"#,
+        )
+    }
+
+    #[test]
+    fn stamp_annotations() -> IonResult<()> {
+        assert_written_as(
+            StampAnnotations::new(["v2", "audited"]),
+            r#"1 a::[2] {b: 3}"#,
+            r#"v2::audited::1 a::v2::audited::[2] v2::audited::{b: 3}"#,
+        )
+    }
+
+    #[test]
+    fn count_values() -> IonResult<()> {
+        let data = r#"1 [2, 3, {a: 4, b: (5)}] {secret: [6, 7]}"#;
+        let (_, counter) = write_through(v1_1::Binary, CountValues::new(), data)?;
+        assert_eq!(counter.num_top_level_values(), 3);
+        assert_eq!(counter.num_values(), 12);
+
+        // Omitted values are not counted.
+        let middleware = (RedactFields::omit(["secret"]), CountValues::new());
+        let (_, (_, counter)) = write_through(v1_0::Text, middleware, data)?;
+        assert_eq!(counter.num_values(), 9);
+        Ok(())
+    }
+
+    /// Middleware that requires every top-level value to be a struct with an `id` field.
+    struct RequireIds;
+
+    impl WriterMiddleware for RequireIds {
+        fn on_value(&self, context: &ValueContext<'_>) -> IonResult<()> {
+            if context.depth() == 0 && context.ion_type() != IonType::Struct {
+                return IonResult::encoding_error("top-level values must be structs");
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn schema_check_rejects_values() -> IonResult<()> {
+        let (written, _) = write_through(v1_0::Text, RequireIds, "{id: 1} {id: 2, tags: [a]}")?;
+        assert_eq!(written.len(), 2);
+        let result = write_through(v1_0::Binary, RequireIds, "{id: 1} [2]");
+        assert!(matches!(result, Err(IonError::Encoding(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn composed_middleware_sees_added_annotations() -> IonResult<()> {
+        struct RequireStamp;
+        impl WriterMiddleware for RequireStamp {
+            fn on_value(&self, context: &ValueContext<'_>) -> IonResult<()> {
+                if context.depth() == 0 && context.annotations() != [RawSymbolRef::Text("v2")] {
+                    return IonResult::encoding_error("value is missing its stamp");
+                }
+                Ok(())
+            }
+        }
+        let middleware = (StampAnnotations::new(["v2"]), RequireStamp);
+        let (written, _) = write_through(v1_1::Text, middleware, "1 {a: [2]}")?;
+        assert_eq!(written, Element::read_all("v2::1 v2::{a: [2]}")?);
+        Ok(())
+    }
+}
@@ -7,17 +7,22 @@ use value_writer::SequenceWriter;
 
 use crate::lazy::encoder::writer::WriterMacroTable;
 use crate::lazy::encoding::Encoding;
+use crate::lazy::expanded::encoding_module::ModuleBindings;
 use crate::lazy::text::raw::v1_1::reader::{ModuleKind, QualifiedAddress};
 use crate::result::IonFailure;
 use crate::v1_1::Macro;
 use crate::write_config::WriteConfig;
 use crate::{
-    AnyEncoding, IonError, IonInput, IonResult, MacroDef, MacroTable, Reader, TemplateCompiler,
+    AnyEncoding, IonError, IonInput, IonResult, MacroDef, MacroTable, Reader, SymbolTable,
+    TemplateCompiler,
 };
 
 pub mod annotate;
 pub mod annotation_seq;
 pub mod binary;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub mod middleware;
+pub(crate) mod system_writer;
 pub mod text;
 pub mod value_writer;
 pub mod value_writer_config;
@@ -80,6 +85,11 @@ pub trait LazyRawWriter<W: Write>: SequenceWriter<Resources = W> {
 
     fn write_version_marker(&mut self) -> IonResult<()>;
 
+    /// Writes a top-level value that has already been encoded in this writer's format. The caller
+    /// is responsible for ensuring that `encoded` contains exactly one complete value (with any
+    /// annotations) and that any symbol IDs it contains are valid in the writer's symbol table.
+    fn write_encoded_value(&mut self, encoded: &[u8]) -> IonResult<()>;
+
     /// Returns a read-only reference to the current macro table.
     // This avoids returning an Option<_> because reading from the macro table is on the hot path.
     // In Ion 1.0 implementations, this returns an empty macro table.
@@ -91,6 +101,11 @@ pub trait LazyRawWriter<W: Write>: SequenceWriter<Resources = W> {
     // working with Ion 1.1.
     fn macro_table_mut(&mut self) -> Option<&mut WriterMacroTable>;
 
+    /// Sets the symbol table that a text writer uses to find the text of the symbol IDs it is asked
+    /// to write when it is configured with a [`SymbolIdFormat`](crate::SymbolIdFormat) other than
+    /// the default. Binary writers write symbol IDs as-is and ignore it.
+    fn set_symbol_id_table(&mut self, _symbol_table: &SymbolTable) {}
+
     /// Takes a TDL expression representing a macro definition and returns a `Macro` that can
     /// later be invoked by passing it to [`ValueWriter::eexp_writer`](crate::ValueWriter::eexp_writer).
     fn compile_macro(&mut self, source: impl IonInput) -> IonResult<Macro> {
@@ -100,6 +115,7 @@ pub trait LazyRawWriter<W: Write>: SequenceWriter<Resources = W> {
         let template_macro = TemplateCompiler::compile_from_sexp(
             self.macro_table(),
             &MacroTable::empty(),
+            &ModuleBindings::new(),
             macro_def_sexp,
         )?;
 
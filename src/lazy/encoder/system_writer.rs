@@ -0,0 +1,533 @@
+use std::io::Write;
+
+use crate::lazy::decoder::{
+    Decoder, LazyRawFieldName, LazyRawSequence, LazyRawStruct, LazyRawValue,
+};
+use crate::lazy::encoder::annotation_seq::AnnotationsVec;
+use crate::lazy::encoder::value_writer::{EExpWriter, SequenceWriter, StructWriter, ValueWriter};
+use crate::lazy::encoder::write_as_ion::WriteAsIon;
+use crate::lazy::encoder::writer::WriterMacroTable;
+use crate::lazy::encoder::LazyRawWriter;
+use crate::lazy::encoding::Encoding;
+use crate::lazy::expanded::e_expression::{EExpArgGroup, EExpression};
+use crate::lazy::expanded::macro_evaluator::{MacroExprKind, RawEExpression, ValueExpr};
+use crate::lazy::expanded::r#struct::{ExpandedStructSource, FieldExpr, LazyExpandedFieldName};
+use crate::lazy::expanded::sequence::{ExpandedListSource, ExpandedSExpSource};
+use crate::lazy::expanded::{
+    ExpandedStreamItem, ExpandedValueRef, ExpandedValueSource, LazyExpandedValue,
+};
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::system_reader::SystemReader;
+use crate::lazy::system_stream_item::SystemStreamEvent;
+use crate::lazy::text::raw::v1_1::reader::{MacroIdRef, ModuleKind, SystemMacroAddress};
+use crate::result::IonFailure;
+use crate::{
+    IonResult, LazyValue, RawSymbolRef, RawVersionMarker, SymbolIdFormat, SymbolRef, WriteConfig,
+};
+
+/// Transcribes the items read by a [`SystemReader`] without expanding their macros.
+///
+/// A [`Writer`](crate::Writer) only sees the values that e-expressions expand to, and manages its
+/// own symbol and macro tables. A `SystemWriter` instead writes each item as it appeared in the
+/// input: e-expressions are written as invocations (including any that are nested in containers
+/// or passed as arguments), and encoding directives, symbol tables, and version markers are
+/// written as they were read. This makes it possible to (for example) transcribe a binary Ion 1.1
+/// stream to text and see the `(:make_list ...)` invocations that it contains rather than their
+/// expansions.
+///
+/// Symbols are written using their resolved text, so the output does not depend on symbol IDs
+/// having the same meaning in the new encoding. A text `SystemWriter` can instead be configured
+/// with a [`SymbolIdFormat`](crate::SymbolIdFormat) that preserves the symbol IDs found in the
+/// input. Macros are invoked by name when they have one.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(all(feature = "experimental-tooling-apis", feature = "experimental-ion-1-1"))]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{v1_1, AnyEncoding, Element, ElementReader, Reader, SequenceWriter, SystemReader, SystemWriter};
+///
+/// let mut writer = ion_rs::Writer::new(v1_1::Binary, Vec::new())?;
+/// let point = writer.compile_macro("(macro point (x y) {x: (%x), y: (%y)})")?;
+/// let mut eexp = writer.eexp_writer(&point)?;
+/// eexp.write(1)?.write(2)?;
+/// eexp.close()?;
+/// let binary = writer.close()?;
+///
+/// let mut reader = SystemReader::new(AnyEncoding, binary.as_slice());
+/// let mut writer = SystemWriter::new(v1_1::Text, Vec::new())?;
+/// writer.transcribe(&mut reader)?;
+/// let text = String::from_utf8(writer.close()?).unwrap();
+/// assert!(text.contains("(:point 1 2 )"));
+///
+/// // The text still expands to the same data.
+/// let expanded = Reader::new(AnyEncoding, text)?.read_all_elements()?;
+/// assert_eq!(expanded, Element::read_all("{x: 1, y: 2}")?);
+///# Ok(())
+///# }
+///# #[cfg(not(all(feature = "experimental-tooling-apis", feature = "experimental-ion-1-1")))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[cfg_attr(feature = "experimental-tooling-apis", visibility::make(pub))]
+pub(crate) struct SystemWriter<E: Encoding, Output: Write> {
+    writer: E::Writer<Output>,
+    // Whether any item has been written. The raw writer begins its output with an IVM, so an IVM
+    // at the head of the input does not need to be written again.
+    has_written_items: bool,
+    // Whether the encoding context may have changed since the writer's macro table (and, if
+    // symbol IDs are being preserved, its symbol table) was last copied from it.
+    macros_are_stale: bool,
+    // Whether symbols that were encoded as symbol IDs in the input should be written as symbol IDs,
+    // leaving it to the raw writer's `SymbolIdFormat` to decide how to represent them.
+    preserve_symbol_ids: bool,
+}
+
+#[cfg_attr(not(feature = "experimental-tooling-apis"), allow(dead_code))]
+impl<E: Encoding, Output: Write> SystemWriter<E, Output> {
+    pub fn new(config: impl Into<WriteConfig<E>>, output: Output) -> IonResult<Self> {
+        let config = config.into();
+        let preserve_symbol_ids = config.symbol_id_format() != SymbolIdFormat::Omit;
+        Ok(Self {
+            writer: E::Writer::build(config, output)?,
+            has_written_items: false,
+            macros_are_stale: true,
+            preserve_symbol_ids,
+        })
+    }
+
+    /// Writes each remaining item in `reader` to the output.
+    pub fn transcribe<D: Decoder, Input: IonInput>(
+        &mut self,
+        reader: &mut SystemReader<D, Input>,
+    ) -> IonResult<()> {
+        loop {
+            let event = reader.next_event()?;
+            if let ExpandedStreamItem::EndOfStream(_) = event.item() {
+                return Ok(());
+            }
+            self.write_event(&event)?;
+        }
+    }
+
+    /// Writes the item in `event` to the output. Ephemeral items (those that were produced by
+    /// evaluating an e-expression) are skipped, as the e-expression itself has already been written.
+    pub fn write_event<D: Decoder>(&mut self, event: &SystemStreamEvent<'_, D>) -> IonResult<()> {
+        use ExpandedStreamItem::*;
+        if event.is_ephemeral() {
+            if let EncodingDirective(_) | SymbolTable(_) = event.item() {
+                self.macros_are_stale = true;
+            }
+            return Ok(());
+        }
+        // The encoding context is only modified between top-level items, so the context in which
+        // this item was read is also the one that its e-expressions must be resolved in.
+        if self.macros_are_stale {
+            if let Some(macro_table) = self.writer.macro_table_mut() {
+                *macro_table = WriterMacroTable::new(event.macro_table().clone());
+            }
+            if self.preserve_symbol_ids {
+                self.writer.set_symbol_id_table(event.symbol_table());
+            }
+            self.macros_are_stale = false;
+        }
+        match event.item() {
+            VersionMarker(marker) => {
+                if marker.major_minor() != E::ion_version().major_minor() {
+                    let (major, minor) = marker.major_minor();
+                    return IonResult::encoding_error(format!(
+                        "cannot transcribe an Ion {major}.{minor} version marker using an Ion {} writer",
+                        E::name()
+                    ));
+                }
+                self.macros_are_stale = true;
+                if self.has_written_items {
+                    self.writer.write_version_marker()?;
+                }
+            }
+            EExp(eexp) => write_eexp(self.writer.value_writer(), eexp, self.preserve_symbol_ids)?,
+            Value(value) => write_value(
+                self.writer.value_writer(),
+                value.expanded(),
+                self.preserve_symbol_ids,
+            )?,
+            SymbolTable(symbol_table) => {
+                self.macros_are_stale = true;
+                write_value(
+                    self.writer.value_writer(),
+                    symbol_table.as_value().expanded(),
+                    self.preserve_symbol_ids,
+                )?
+            }
+            EncodingDirective(directive) => {
+                self.macros_are_stale = true;
+                write_value(
+                    self.writer.value_writer(),
+                    directive.as_value().expanded(),
+                    self.preserve_symbol_ids,
+                )?
+            }
+            EndOfStream(_) => return Ok(()),
+        }
+        self.has_written_items = true;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> IonResult<()> {
+        self.writer.flush()
+    }
+
+    pub fn output(&self) -> &Output {
+        self.writer.output()
+    }
+
+    pub fn close(self) -> IonResult<Output> {
+        self.writer.close()
+    }
+}
+
+/// If `preserve_symbol_ids` is `true` and `raw_token` is a symbol ID, returns that symbol ID.
+/// Otherwise, returns the token's resolved text (`resolved`).
+fn symbol_token<'a>(
+    preserve_symbol_ids: bool,
+    raw_token: RawSymbolRef<'_>,
+    resolved: SymbolRef<'a>,
+) -> RawSymbolRef<'a> {
+    match raw_token {
+        RawSymbolRef::SymbolId(sid) if preserve_symbol_ids => RawSymbolRef::SymbolId(sid),
+        _ => resolved.into(),
+    }
+}
+
+/// Writes `value` along with its annotations. If `value` is a container that was read from the
+/// input, any e-expressions within it are written as invocations.
+fn write_value<D: Decoder, V: ValueWriter>(
+    writer: V,
+    value: LazyExpandedValue<'_, D>,
+    preserve_symbol_ids: bool,
+) -> IonResult<()> {
+    if !value.has_annotations() {
+        return write_value_body(writer, value, preserve_symbol_ids);
+    }
+    let mut annotations = AnnotationsVec::new();
+    let resolved_annotations = LazyValue::new(value).annotations();
+    match value.source() {
+        ExpandedValueSource::ValueLiteral(raw_value) if preserve_symbol_ids => {
+            for (raw_annotation, annotation) in raw_value.annotations().zip(resolved_annotations) {
+                annotations.push(symbol_token(true, raw_annotation?, annotation?));
+            }
+        }
+        _ => {
+            for annotation in resolved_annotations {
+                annotations.push(annotation?.into());
+            }
+        }
+    }
+    write_value_body(
+        writer.with_annotations(annotations)?,
+        value,
+        preserve_symbol_ids,
+    )
+}
+
+fn write_value_body<D: Decoder, V: ValueWriter>(
+    writer: V,
+    value: LazyExpandedValue<'_, D>,
+    preserve_symbol_ids: bool,
+) -> IonResult<()> {
+    let context = value.context();
+    match value.read()? {
+        ExpandedValueRef::List(list) => {
+            let ExpandedListSource::ValueLiteral(raw_list) = list.source() else {
+                return LazyValue::new(value).read()?.write_as_ion(writer);
+            };
+            let mut list_writer = writer.list_writer()?;
+            for expr in raw_list.iter() {
+                write_value_expr(
+                    list_writer.value_writer(),
+                    expr?.resolve(context)?,
+                    preserve_symbol_ids,
+                )?;
+            }
+            list_writer.close()
+        }
+        ExpandedValueRef::SExp(sexp) => {
+            let ExpandedSExpSource::ValueLiteral(raw_sexp) = sexp.source() else {
+                return LazyValue::new(value).read()?.write_as_ion(writer);
+            };
+            let mut sexp_writer = writer.sexp_writer()?;
+            for expr in raw_sexp.iter() {
+                write_value_expr(
+                    sexp_writer.value_writer(),
+                    expr?.resolve(context)?,
+                    preserve_symbol_ids,
+                )?;
+            }
+            sexp_writer.close()
+        }
+        ExpandedValueRef::Struct(strukt) => {
+            let ExpandedStructSource::ValueLiteral(raw_struct) = strukt.source() else {
+                return LazyValue::new(value).read()?.write_as_ion(writer);
+            };
+            let mut struct_writer = writer.struct_writer()?;
+            for field in raw_struct.iter() {
+                match field?.resolve(context)? {
+                    FieldExpr::NameValue(name, value) => write_value(
+                        struct_writer.field_writer(field_name(name, preserve_symbol_ids)?),
+                        value,
+                        preserve_symbol_ids,
+                    )?,
+                    FieldExpr::NameMacro(name, invocation) => match invocation.source() {
+                        MacroExprKind::EExp(eexp) => write_eexp(
+                            struct_writer.field_writer(field_name(name, preserve_symbol_ids)?),
+                            eexp,
+                            preserve_symbol_ids,
+                        )?,
+                        _ => unreachable!(
+                            "a struct field in the input can only invoke an e-expression"
+                        ),
+                    },
+                    FieldExpr::EExp(_) => {
+                        return IonResult::encoding_error(
+                            "writing e-expressions in field name position is not supported",
+                        )
+                    }
+                }
+            }
+            struct_writer.close()
+        }
+        ExpandedValueRef::Symbol(RawSymbolRef::SymbolId(sid))
+            if preserve_symbol_ids
+                && matches!(value.source(), ExpandedValueSource::ValueLiteral(_)) =>
+        {
+            writer.write_symbol(sid)
+        }
+        _ => LazyValue::new(value).read()?.write_as_ion(writer),
+    }
+}
+
+/// Returns the token to write for a field name found in the input.
+fn field_name<'top, D: Decoder>(
+    name: LazyExpandedFieldName<'top, D>,
+    preserve_symbol_ids: bool,
+) -> IonResult<RawSymbolRef<'top>> {
+    let resolved = name.read()?;
+    match name.raw() {
+        Some(raw_name) => Ok(symbol_token(
+            preserve_symbol_ids,
+            raw_name.read()?,
+            resolved,
+        )),
+        None => Ok(resolved.into()),
+    }
+}
+
+fn write_value_expr<D: Decoder, V: ValueWriter>(
+    writer: V,
+    expr: ValueExpr<'_, D>,
+    preserve_symbol_ids: bool,
+) -> IonResult<()> {
+    match expr {
+        ValueExpr::ValueLiteral(value) => write_value(writer, value, preserve_symbol_ids),
+        ValueExpr::MacroInvocation(invocation) => match invocation.source() {
+            MacroExprKind::EExp(eexp) => write_eexp(writer, eexp, preserve_symbol_ids),
+            _ => unreachable!("a value expression in the input can only invoke an e-expression"),
+        },
+    }
+}
+
+fn write_eexp<D: Decoder, V: ValueWriter>(
+    writer: V,
+    eexp: EExpression<'_, D>,
+    preserve_symbol_ids: bool,
+) -> IonResult<()> {
+    let invoked_macro = eexp.invoked_macro();
+    let macro_id = match invoked_macro.module() {
+        ModuleKind::System => {
+            MacroIdRef::SystemAddress(SystemMacroAddress::new_unchecked(invoked_macro.address()))
+        }
+        ModuleKind::Default => match invoked_macro.name() {
+            Some(name) => MacroIdRef::LocalName(name),
+            None => MacroIdRef::LocalAddress(invoked_macro.address()),
+        },
+        ModuleKind::Named => {
+            return IonResult::encoding_error(format!(
+                "cannot write an invocation of macro {} in a named module",
+                eexp.raw_invocation().id()
+            ))
+        }
+    };
+    let mut eexp_writer = writer.eexp_writer(macro_id)?;
+    for arg in eexp.arguments() {
+        let arg = arg?;
+        if let ValueExpr::MacroInvocation(invocation) = arg {
+            if let MacroExprKind::EExpArgGroup(group) = invocation.source() {
+                write_arg_group(&mut eexp_writer, group, preserve_symbol_ids)?;
+                continue;
+            }
+        }
+        // The binary encoding can pass a single expression to a variadic parameter without an
+        // expression group, but the writer expects variadic arguments to be written as groups.
+        let is_variadic = eexp_writer
+            .current_parameter()
+            .is_some_and(|parameter| parameter.is_variadic());
+        if is_variadic {
+            let mut group_writer = eexp_writer.expr_group_writer()?;
+            write_value_expr(group_writer.value_writer(), arg, preserve_symbol_ids)?;
+            group_writer.close()?;
+        } else {
+            write_value_expr(eexp_writer.value_writer(), arg, preserve_symbol_ids)?;
+        }
+    }
+    eexp_writer.close()
+}
+
+fn write_arg_group<D: Decoder, W: EExpWriter>(
+    eexp_writer: &mut W,
+    group: EExpArgGroup<'_, D>,
+    preserve_symbol_ids: bool,
+) -> IonResult<()> {
+    let mut group_writer = eexp_writer.expr_group_writer()?;
+    for expr in group.expressions() {
+        write_value_expr(group_writer.value_writer(), expr?, preserve_symbol_ids)?;
+    }
+    group_writer.close()?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "experimental-ion-1-1"))]
+mod tests {
+    use super::*;
+    use crate::{v1_0, v1_1, AnyEncoding, Element, ElementReader, Reader, Sequence};
+
+    /// Transcribes `data` to Ion 1.1 text, confirming that the text expands to the same values as
+    /// `data` does.
+    fn transcribe(data: impl IonInput + Clone) -> IonResult<String> {
+        let mut reader = SystemReader::new(AnyEncoding, data.clone());
+        let mut writer = SystemWriter::new(v1_1::Text, Vec::new())?;
+        writer.transcribe(&mut reader)?;
+        let text = String::from_utf8(writer.close()?).unwrap();
+        let expected = Reader::new(AnyEncoding, data)?.read_all_elements()?;
+        let actual: Sequence = Reader::new(AnyEncoding, text.as_str())?.read_all_elements()?;
+        assert_eq!(actual, expected, "transcribed text:\n{text}");
+        Ok(text)
+    }
+
+    #[test]
+    fn preserve_eexps_from_text() -> IonResult<()> {
+        let text = transcribe(
+            r#"
+                $ion_1_1
+                $ion::(module _
+                    (macro_table
+                        $ion
+                        (macro pair (a b) [(%a), (%b)])
+                        (macro many (xs*) (.values (%xs)))))
+                (:pair 1 (:pair 2 3))
+                [a, (:pair x y), {f: (:pair 4 5), g: foo::[(:make_string "a" "b")]}]
+                (:many (:: 1 2 3))
+                (:many)
+            "#,
+        )?;
+        assert!(text.contains("$ion::(module _"));
+        assert!(text.contains("(:pair 1 (:pair 2 3 ) )"));
+        assert!(text.contains("[a, (:pair x y ), {f: (:pair 4 5 ), g: foo::[(:make_string"));
+        assert!(text.contains("(:many (::1 2 3 ) )"));
+        Ok(())
+    }
+
+    #[test]
+    fn preserve_eexps_from_binary() -> IonResult<()> {
+        let mut writer = crate::Writer::new(v1_1::Binary, Vec::new())?;
+        let pair = writer.compile_macro("(macro pair (a b) [(%a), (%b)])")?;
+        let mut eexp = writer.eexp_writer(&pair)?;
+        eexp.write("foo")?;
+        let mut nested = eexp.eexp_writer(&pair)?;
+        nested.write(1)?.write(2)?;
+        nested.close()?;
+        eexp.close()?;
+        writer.write_symbol("bar")?;
+        let binary = writer.close()?;
+
+        let text = transcribe(binary.as_slice())?;
+        assert!(text.contains(r#"(:pair "foo" (:pair 1 2 ) )"#));
+        Ok(())
+    }
+
+    #[test]
+    fn preserve_system_eexps_and_version_markers() -> IonResult<()> {
+        let text = transcribe(
+            r#"
+                $ion_1_1
+                (:$ion::set_symbols a b)
+                $1
+                $ion_1_1
+                (:make_list (1 2) [3])
+            "#,
+        )?;
+        // The IVM at the head of the stream is not duplicated.
+        assert_eq!(text.matches("$ion_1_1").count(), 2);
+        assert!(text.contains("(:$ion::"));
+        assert!(text.contains("(:make_list"));
+        Ok(())
+    }
+
+    #[test]
+    fn reject_version_marker_for_another_version() -> IonResult<()> {
+        let mut reader = SystemReader::new(AnyEncoding, "$ion_1_0 1 $ion_1_0 2");
+        let mut writer = SystemWriter::new(v1_1::Text, Vec::new())?;
+        assert!(writer.transcribe(&mut reader).is_err());
+
+        let mut reader = SystemReader::new(AnyEncoding, "$ion_1_0 1 $ion_1_0 2");
+        let mut writer = SystemWriter::new(v1_0::Text, Vec::new())?;
+        writer.transcribe(&mut reader)?;
+        let output = writer.close()?;
+        assert_eq!(Element::read_all(output)?, Element::read_all("1 2")?);
+        Ok(())
+    }
+
+    /// Transcribes `data` to Ion 1.0 text using the specified `SymbolIdFormat`.
+    fn transcribe_with_symbol_ids(
+        data: impl IonInput,
+        symbol_id_format: SymbolIdFormat,
+    ) -> IonResult<String> {
+        let config = WriteConfig::<v1_0::Text>::new(crate::TextFormat::Compact)
+            .with_symbol_id_format(symbol_id_format);
+        let mut reader = SystemReader::new(AnyEncoding, data);
+        let mut writer = SystemWriter::new(config, Vec::new())?;
+        writer.transcribe(&mut reader)?;
+        Ok(String::from_utf8(writer.close()?).unwrap())
+    }
+
+    #[test]
+    fn write_symbol_ids_as_comments() -> IonResult<()> {
+        let mut writer = crate::Writer::new(v1_0::Binary, Vec::new())?;
+        writer.write(&Element::read_one("foo::{bar: baz, quux: name}")?)?;
+        let binary = writer.close()?;
+
+        let text = transcribe_with_symbol_ids(binary.as_slice(), SymbolIdFormat::Comment)?;
+        assert!(text.contains("foo /*$10*/::{"), "{text}");
+        assert!(text.contains("bar /*$11*/: baz /*$12*/"), "{text}");
+        // System symbols are encoded as symbol IDs, too.
+        assert!(text.contains("name /*$4*/"), "{text}");
+        // The comments do not change the data.
+        assert_eq!(Element::read_all(&text)?, Element::read_all(binary)?);
+
+        let text = transcribe_with_symbol_ids(text.as_str(), SymbolIdFormat::PreserveUnknown)?;
+        assert!(!text.contains("/*"), "{text}");
+        assert!(text.contains("foo::{bar: baz"), "{text}");
+        Ok(())
+    }
+
+    #[test]
+    fn preserve_symbol_ids_with_unknown_text() -> IonResult<()> {
+        let data = r#"
+            $ion_symbol_table::{symbols: [null, null, "known"]}
+            $10::{$11: $12}
+        "#;
+        let text = transcribe_with_symbol_ids(data, SymbolIdFormat::PreserveUnknown)?;
+        assert!(text.contains("$10::{$11: known,"), "{text}");
+        // By default, symbols with unknown text are written as `$0`.
+        let text = transcribe_with_symbol_ids(data, SymbolIdFormat::Omit)?;
+        assert!(text.contains("$0::{$0: known,"), "{text}");
+        Ok(())
+    }
+}
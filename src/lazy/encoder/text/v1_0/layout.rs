@@ -0,0 +1,232 @@
+use std::io::Write;
+
+use crate::{IonResult, PrettyConfig};
+
+/// Holds the text of a top-level container until it is complete so the writer can decide how to
+/// lay out the containers inside it.
+///
+/// While a container is open, the writer's output is sent to this buffer. Whitespace and
+/// delimiters whose presence depends on the layout are recorded as [`LayoutBreak`]s instead of
+/// being written; when the top-level container is closed, each container is measured and the
+/// breaks are rendered for either a multi-line or a single-line layout.
+pub(crate) struct LayoutBuffer {
+    max_inline_width: usize,
+    trailing_commas: bool,
+    text: Vec<u8>,
+    breaks: Vec<LayoutBreak>,
+    containers: Vec<ContainerLayout>,
+    // Indexes (in `containers`) of the containers that have been opened but not closed.
+    open_containers: Vec<usize>,
+}
+
+struct LayoutBreak {
+    // The offset in `text` at which the break appears.
+    offset: usize,
+    // The index of the innermost container that was open when the break was recorded.
+    container: usize,
+    kind: LayoutBreakKind,
+}
+
+enum LayoutBreakKind {
+    /// Whitespace that follows a container's opening delimiter or one of its values.
+    Space {
+        multiline: &'static str,
+        inline: &'static str,
+    },
+    /// Indentation preceding a value or a closing delimiter.
+    Indentation {
+        indentation: &'static str,
+        depth: usize,
+    },
+    /// The delimiter that follows a value in a list or struct.
+    Delimiter(&'static str),
+}
+
+struct ContainerLayout {
+    // The offsets in `text` at which the container begins and ends.
+    start: usize,
+    end: usize,
+    // The range of `breaks` that were recorded between the container's start and end.
+    first_break: usize,
+    end_break: usize,
+    // The indexes of the last space and delimiter that belong to this container, which are
+    // omitted from a single-line layout.
+    last_space: Option<usize>,
+    last_delimiter: Option<usize>,
+    inline: bool,
+}
+
+impl LayoutBuffer {
+    pub fn new(pretty_config: PrettyConfig) -> Self {
+        Self {
+            max_inline_width: pretty_config.max_inline_width(),
+            trailing_commas: pretty_config.writes_trailing_commas(),
+            text: Vec::new(),
+            breaks: Vec::new(),
+            containers: Vec::new(),
+            open_containers: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if a container is open, in which case the writer's output should be sent to
+    /// this buffer.
+    pub fn is_recording(&self) -> bool {
+        !self.open_containers.is_empty()
+    }
+
+    pub fn text_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.text
+    }
+
+    /// Records the start of a container. If `inline` is `true`, the container is written on a
+    /// single line regardless of its width.
+    pub fn begin_container(&mut self, inline: bool) {
+        self.open_containers.push(self.containers.len());
+        self.containers.push(ContainerLayout {
+            start: self.text.len(),
+            end: usize::MAX,
+            first_break: self.breaks.len(),
+            end_break: usize::MAX,
+            last_space: None,
+            last_delimiter: None,
+            inline,
+        });
+    }
+
+    /// Records the end of the innermost open container. If it was the outermost container, its
+    /// text is written to `output` and the buffer is cleared.
+    pub fn end_container(&mut self, output: &mut impl Write) -> IonResult<()> {
+        let index = self
+            .open_containers
+            .pop()
+            .expect("ended a container that was not started");
+        let container = &mut self.containers[index];
+        container.end = self.text.len();
+        container.end_break = self.breaks.len();
+        if self.open_containers.is_empty() {
+            self.render(output)?;
+        }
+        Ok(())
+    }
+
+    pub fn push_space(&mut self, multiline: &'static str, inline: &'static str) {
+        let index = self.push_break(LayoutBreakKind::Space { multiline, inline });
+        self.current_container().last_space = Some(index);
+    }
+
+    pub fn push_indentation(&mut self, indentation: &'static str, depth: usize) {
+        self.push_break(LayoutBreakKind::Indentation { indentation, depth });
+    }
+
+    pub fn push_delimiter(&mut self, delimiter: &'static str) {
+        let index = self.push_break(LayoutBreakKind::Delimiter(delimiter));
+        self.current_container().last_delimiter = Some(index);
+    }
+
+    fn push_break(&mut self, kind: LayoutBreakKind) -> usize {
+        let container = *self
+            .open_containers
+            .last()
+            .expect("recorded a layout break outside of a container");
+        self.breaks.push(LayoutBreak {
+            offset: self.text.len(),
+            container,
+            kind,
+        });
+        self.breaks.len() - 1
+    }
+
+    fn current_container(&mut self) -> &mut ContainerLayout {
+        let index = *self.open_containers.last().unwrap();
+        &mut self.containers[index]
+    }
+
+    /// Returns `true` if the break at `index` is the last of its kind in its container.
+    fn is_last_in_container(&self, index: usize) -> bool {
+        let layout_break = &self.breaks[index];
+        let container = &self.containers[layout_break.container];
+        match layout_break.kind {
+            LayoutBreakKind::Space { .. } => container.last_space == Some(index),
+            LayoutBreakKind::Delimiter(_) => container.last_delimiter == Some(index),
+            LayoutBreakKind::Indentation { .. } => false,
+        }
+    }
+
+    /// Returns the width of the break at `index` if its container is written on a single line.
+    fn inline_width(&self, index: usize) -> usize {
+        if self.is_last_in_container(index) {
+            return 0;
+        }
+        match self.breaks[index].kind {
+            LayoutBreakKind::Space { inline, .. } => inline.len(),
+            LayoutBreakKind::Indentation { .. } => 0,
+            LayoutBreakKind::Delimiter(delimiter) => delimiter.len(),
+        }
+    }
+
+    /// Decides which containers to write on a single line, then writes the buffered text.
+    fn render(&mut self, output: &mut impl Write) -> IonResult<()> {
+        // The total single-line width of the breaks that precede each break.
+        let mut break_widths = Vec::with_capacity(self.breaks.len() + 1);
+        break_widths.push(0);
+        for index in 0..self.breaks.len() {
+            break_widths.push(break_widths[index] + self.inline_width(index));
+        }
+        for index in 0..self.containers.len() {
+            let container = &self.containers[index];
+            if container.inline {
+                continue;
+            }
+            let text = &self.text[container.start..container.end];
+            let breaks_width =
+                break_widths[container.end_break] - break_widths[container.first_break];
+            self.containers[index].inline = fits(text, breaks_width, self.max_inline_width);
+        }
+
+        let mut position = 0;
+        for (index, layout_break) in self.breaks.iter().enumerate() {
+            output.write_all(&self.text[position..layout_break.offset])?;
+            position = layout_break.offset;
+            let inline = self.containers[layout_break.container].inline;
+            let is_last = self.is_last_in_container(index);
+            match layout_break.kind {
+                LayoutBreakKind::Space { .. } if inline && is_last => {}
+                LayoutBreakKind::Space { inline: space, .. } if inline => {
+                    output.write_all(space.as_bytes())?
+                }
+                LayoutBreakKind::Space { multiline, .. } => {
+                    output.write_all(multiline.as_bytes())?
+                }
+                LayoutBreakKind::Indentation { .. } if inline => {}
+                LayoutBreakKind::Indentation { indentation, depth } => {
+                    for _ in 0..depth {
+                        output.write_all(indentation.as_bytes())?;
+                    }
+                }
+                LayoutBreakKind::Delimiter(_) if is_last && (inline || !self.trailing_commas) => {}
+                LayoutBreakKind::Delimiter(delimiter) => output.write_all(delimiter.as_bytes())?,
+            }
+        }
+        output.write_all(&self.text[position..])?;
+
+        self.text.clear();
+        self.breaks.clear();
+        self.containers.clear();
+        Ok(())
+    }
+}
+
+/// Returns `true` if `text` and breaks totaling `breaks_width` bytes can be written in
+/// `max_width` characters.
+fn fits(text: &[u8], breaks_width: usize, max_width: usize) -> bool {
+    let Some(budget) = max_width.checked_sub(breaks_width) else {
+        return false;
+    };
+    if text.len() <= budget {
+        return true;
+    }
+    // The text is too long if each byte is a character, but multibyte characters may bring it
+    // under the limit. Count the bytes that begin a character, stopping once the limit is passed.
+    let is_char_start = |byte: &&u8| (**byte as i8) >= -0x40;
+    text.iter().filter(is_char_start).take(budget + 1).count() <= budget
+}
@@ -4,6 +4,7 @@ use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
 use crate::lazy::encoder::{Encoder, SymbolCreationPolicy};
 use crate::lazy::encoding::TextEncoding_1_0;
 
+pub(crate) mod layout;
 pub mod value_writer;
 pub mod writer;
 
@@ -4,7 +4,7 @@ use std::io::Write;
 
 use delegate::delegate;
 
-use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsVec};
+use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsBuf};
 use crate::lazy::encoder::private::Sealed;
 use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
 use crate::lazy::encoder::value_writer::internal::{FieldEncoder, MakeValueWriter};
@@ -15,8 +15,11 @@ use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::never::Never;
 use crate::raw_symbol_ref::AsRawSymbolRef;
 use crate::result::IonFailure;
-use crate::text::text_formatter::{FmtValueFormatter, IoValueFormatter};
-use crate::text::whitespace_config::WhitespaceConfig;
+use crate::text::float_format::FormattedFloat;
+use crate::text::text_formatter::{
+    find_next_text_escape, FmtValueFormatter, IoValueFormatter, TEXT_ESCAPE_SEQUENCES,
+};
+use crate::text::whitespace_config::{WhitespaceConfig, COMPACT_WHITESPACE_CONFIG};
 use crate::types::{ContainerType, ParentType};
 use crate::{
     v1_0, ContextWriter, Decimal, Encoding, Int, IonResult, IonType, RawSymbolRef, Timestamp,
@@ -35,7 +38,7 @@ pub struct TextValueWriter_1_0<'value, W: Write> {
     pub(crate) has_annotations: bool,
 }
 
-pub(crate) fn write_symbol_token<O: Write, A: AsRawSymbolRef>(
+pub(crate) fn write_symbol_token<O: Write + ?Sized, A: AsRawSymbolRef>(
     output: &mut O,
     token: A,
 ) -> IonResult<()> {
@@ -46,13 +49,19 @@ pub(crate) fn write_symbol_token<O: Write, A: AsRawSymbolRef>(
 
 /// Writes the body (i.e. no start or end delimiters) of a string or symbol with any illegal
 /// characters escaped.
-pub(crate) fn write_escaped_text_body<O: Write, S: AsRef<str>>(
+pub(crate) fn write_escaped_text_body<O: Write + ?Sized, S: AsRef<str>>(
     output: &mut O,
     value: S,
 ) -> IonResult<()> {
-    let mut io_shim = IoValueFormatter::new(output);
-    let _ = io_shim.value_formatter().format_escaped_text_body(value);
-    io_shim.into_result()
+    let mut remaining = value.as_ref().as_bytes();
+    while let Some(index) = find_next_text_escape(remaining) {
+        // Copy the run of bytes that don't need escaping in bulk, followed by the escape sequence.
+        output.write_all(&remaining[..index])?;
+        output.write_all(TEXT_ESCAPE_SEQUENCES[remaining[index] as usize].as_bytes())?;
+        remaining = &remaining[index + 1..];
+    }
+    output.write_all(remaining)?;
+    Ok(())
 }
 
 impl<'value, W: Write + 'value> TextValueWriter_1_0<'value, W> {
@@ -73,7 +82,6 @@ impl<'value, W: Write + 'value> TextValueWriter_1_0<'value, W> {
 
     /// Writes the `indentation` string set in the whitespace config to output `depth` times.
     fn write_indentation(&mut self) -> IonResult<()> {
-        let indentation = self.whitespace_config().indentation;
         if self.parent_type == ParentType::Struct || self.has_annotations {
             // If this value is part of a struct field, the indentation was written before the
             // field name.
@@ -81,22 +89,13 @@ impl<'value, W: Write + 'value> TextValueWriter_1_0<'value, W> {
             // Either way, there's nothing to do here.
             return Ok(());
         }
-        if !indentation.is_empty() {
-            for _ in 0..self.depth {
-                write!(self.output(), "{indentation}")?;
-            }
-        }
-        Ok(())
+        self.writer.write_indentation(self.depth)
     }
 }
 
 impl<W: Write> TextValueWriter_1_0<'_, W> {
-    fn output(&mut self) -> &mut W {
-        &mut self.writer.output
-    }
-
-    fn whitespace_config(&self) -> &WhitespaceConfig {
-        self.writer.whitespace_config
+    fn output(&mut self) -> &mut dyn Write {
+        self.writer.sink()
     }
 
     pub fn delimiter(&self) -> &'static str {
@@ -105,18 +104,13 @@ impl<W: Write> TextValueWriter_1_0<'_, W> {
 
     #[inline]
     fn write_delimiter_text(&mut self) -> IonResult<()> {
-        let space_between = match self.depth {
-            0 => self.whitespace_config().space_between_top_level_values,
-            _ => self.whitespace_config().space_between_nested_values,
-        };
-        let value_delimiter = self.value_delimiter;
-        write!(self.output(), "{value_delimiter}{space_between}")?;
-        Ok(())
+        self.writer
+            .write_value_separator(self.value_delimiter, self.depth)
     }
 }
 
 pub struct TextAnnotatedValueWriter_1_0<'value, W: Write> {
-    pub(crate) annotations: AnnotationsVec<'value>,
+    pub(crate) annotations: AnnotationsBuf<'value>,
     pub(crate) value_writer: TextValueWriter_1_0<'value, W>,
 }
 
@@ -128,14 +122,13 @@ impl<'value, W: Write> TextAnnotatedValueWriter_1_0<'value, W> {
         // After indenting, we set the `has_annotations` flag to `true` so the value won't write
         // indentation a second time.
         self.value_writer.has_annotations = !self.annotations.is_empty();
-        let output = &mut self.value_writer.writer.output;
-        for annotation in self.annotations {
+        let writer = &mut *self.value_writer.writer;
+        for annotation in self.annotations.iter() {
             match annotation.as_raw_symbol_ref() {
-                RawSymbolRef::Text(token) => {
-                    write_symbol_token(output, token)?;
-                    write!(output, "::")
+                token @ (RawSymbolRef::Text(_) | RawSymbolRef::SymbolId(_)) => {
+                    writer.write_symbol_token(token)?;
+                    write!(writer.sink(), "::")
                 }
-                RawSymbolRef::SymbolId(sid) => write!(output, "${sid}::"),
                 RawSymbolRef::SystemSymbol_1_1(_symbol) => {
                     return IonResult::encoding_error(
                         "the Ion 1.0 text writer does not support encoding Ion 1.1 system symbols",
@@ -168,6 +161,9 @@ pub(crate) struct TextContainerWriter_1_0<'a, W: Write> {
     pub(crate) container_type: ContainerType,
     value_delimiter: &'static str,
     trailing_delimiter: &'static str,
+    // If this container is being written on a single line, the whitespace config to restore when
+    // it is closed.
+    outer_whitespace_config: Option<WhitespaceConfig>,
 }
 
 impl<'a, W: Write> TextContainerWriter_1_0<'a, W> {
@@ -179,6 +175,31 @@ impl<'a, W: Write> TextContainerWriter_1_0<'a, W> {
         opening_delimiter: &str,
         value_delimiter: &'static str,
         trailing_delimiter: &'static str,
+    ) -> IonResult<Self> {
+        Self::with_layout(
+            writer,
+            depth,
+            parent_type,
+            container_type,
+            opening_delimiter,
+            value_delimiter,
+            trailing_delimiter,
+            false,
+        )
+    }
+
+    /// Like [`Self::new`], but if `inline` is `true` the container and everything nested inside
+    /// it are written on a single line, regardless of the writer's whitespace config.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_layout(
+        writer: &'a mut LazyRawTextWriter_1_0<W>,
+        depth: usize,
+        parent_type: ParentType,
+        container_type: ContainerType,
+        opening_delimiter: &str,
+        value_delimiter: &'static str,
+        trailing_delimiter: &'static str,
+        inline: bool,
     ) -> IonResult<Self> {
         let mut container_writer = Self {
             writer,
@@ -187,30 +208,27 @@ impl<'a, W: Write> TextContainerWriter_1_0<'a, W> {
             has_been_closed: false,
             value_delimiter,
             trailing_delimiter,
+            outer_whitespace_config: None,
         };
         if parent_type != ParentType::Struct {
             container_writer.write_indentation(depth)?;
         }
-        let space_after_container_start = container_writer
+        if inline {
+            container_writer.outer_whitespace_config =
+                Some(container_writer.writer.whitespace_config);
+            container_writer.writer.whitespace_config = COMPACT_WHITESPACE_CONFIG;
+        }
+        container_writer.writer.begin_container(inline);
+        write!(container_writer.output(), "{opening_delimiter}")?;
+        container_writer
             .writer
-            .whitespace_config
-            .space_after_container_start;
-        write!(
-            container_writer.writer.output,
-            "{opening_delimiter}{space_after_container_start}"
-        )?;
+            .write_space_after_container_start()?;
         Ok(container_writer)
     }
 
     /// Writes the `indentation` string set in the whitespace config to output `depth` times.
     fn write_indentation(&mut self, depth: usize) -> IonResult<()> {
-        let indentation = self.whitespace_config().indentation;
-        if !indentation.is_empty() {
-            for _ in 0..depth {
-                write!(self.output(), "{indentation}")?;
-            }
-        }
-        Ok(())
+        self.writer.write_indentation(depth)
     }
 
     /// Writes the provided value to output using its implementation of `WriteAsIon`, then writes
@@ -223,26 +241,28 @@ impl<'a, W: Write> TextContainerWriter_1_0<'a, W> {
 
     /// Finalizes the container, preventing further values from being written.
     pub fn close(mut self, closing_delimiter: &str) -> IonResult<()> {
-        let space_between = match self.depth {
-            0 => self.whitespace_config().space_between_top_level_values,
-            _ => self.whitespace_config().space_between_nested_values,
-        };
-        let trailing_delimiter = self.trailing_delimiter;
-        self.write_indentation(self.depth)?;
-        write!(
-            self.output(),
-            "{closing_delimiter}{trailing_delimiter}{space_between}"
-        )?;
+        match self.outer_whitespace_config {
+            // If the container was written on a single line, the closing delimiter stays on that
+            // line. The outer whitespace config is restored for the space that follows it.
+            Some(outer_whitespace_config) => {
+                self.writer.whitespace_config = outer_whitespace_config
+            }
+            None => self.write_indentation(self.depth)?,
+        }
+        write!(self.output(), "{closing_delimiter}")?;
+        self.writer.end_container()?;
+        self.writer
+            .write_value_separator(self.trailing_delimiter, self.depth)?;
         self.has_been_closed = true;
         Ok(())
     }
 
-    fn output(&mut self) -> &mut W {
-        &mut self.writer.output
+    fn output(&mut self) -> &mut dyn Write {
+        self.writer.sink()
     }
 
     fn whitespace_config(&self) -> &WhitespaceConfig {
-        self.writer.whitespace_config
+        &self.writer.whitespace_config
     }
 
     #[inline]
@@ -331,7 +351,18 @@ impl<'a, W: Write> TextSExpWriter_1_0<'a, W> {
         parent_type: ParentType,
         trailing_delimiter: &'static str,
     ) -> IonResult<Self> {
-        let container_writer = TextContainerWriter_1_0::new(
+        Self::with_layout(writer, depth, parent_type, trailing_delimiter, false)
+    }
+
+    /// Like [`Self::new`], but if `inline` is `true` the s-expression is written on a single line.
+    pub(crate) fn with_layout(
+        writer: &'a mut LazyRawTextWriter_1_0<W>,
+        depth: usize,
+        parent_type: ParentType,
+        trailing_delimiter: &'static str,
+        inline: bool,
+    ) -> IonResult<Self> {
+        let container_writer = TextContainerWriter_1_0::with_layout(
             writer,
             depth,
             parent_type,
@@ -339,6 +370,7 @@ impl<'a, W: Write> TextSExpWriter_1_0<'a, W> {
             "(",
             "",
             trailing_delimiter,
+            inline,
         )?;
         Ok(Self { container_writer })
     }
@@ -419,7 +451,7 @@ impl<W: Write> FieldEncoder for TextStructWriter_1_0<'_, W> {
         self.container_writer
             .write_indentation(self.container_writer.depth + 1)?;
         // Write the field name
-        write_symbol_token(self.container_writer.output(), name)?;
+        self.container_writer.writer.write_symbol_token(name)?;
         let space_after_field_name = self
             .container_writer
             .whitespace_config()
@@ -473,7 +505,7 @@ impl<'value, W: Write + 'value> AnnotatableWriter for TextAnnotatedValueWriter_1
         Self: 'a,
     {
         Ok(TextAnnotatedValueWriter_1_0 {
-            annotations: annotations.into_annotations_vec(),
+            annotations: annotations.into_annotations_buf(),
             value_writer: self.value_writer,
         })
     }
@@ -504,7 +536,7 @@ impl<W: Write> AnnotatableWriter for TextValueWriter_1_0<'_, W> {
         Self: 'a,
     {
         Ok(TextAnnotatedValueWriter_1_0 {
-            annotations: annotations.into_annotations_vec(),
+            annotations: annotations.into_annotations_buf(),
             value_writer: self,
         })
     }
@@ -563,35 +595,25 @@ impl<'value, W: Write> ValueWriter for TextValueWriter_1_0<'value, W> {
         self.write_delimiter_text()
     }
 
-    fn write_f32(self, value: f32) -> IonResult<()> {
-        self.write_f64(value as f64)
+    fn write_f32(mut self, value: f32) -> IonResult<()> {
+        self.write_indentation()?;
+        let float_format = self.writer.float_format;
+        write!(
+            self.output(),
+            "{}",
+            FormattedFloat::new(value, float_format)
+        )?;
+        self.write_delimiter_text()
     }
 
     fn write_f64(mut self, value: f64) -> IonResult<()> {
         self.write_indentation()?;
-        if value.is_nan() {
-            write!(self.output(), "nan")?;
-            return self.write_delimiter_text();
-        }
-
-        if value.is_infinite() {
-            if value.is_sign_positive() {
-                write!(self.output(), "+inf")?;
-            } else {
-                write!(self.output(), "-inf")?;
-            }
-            return self.write_delimiter_text();
-        }
-
-        // The {:e} formatter provided by the Display trait writes floats using scientific
-        // notation. It works for all floating point values except -0.0 (it drops the sign).
-        // See: https://github.com/rust-lang/rust/issues/20596
-        if value == 0.0f64 && value.is_sign_negative() {
-            write!(self.output(), "-0e0")?;
-            return self.write_delimiter_text();
-        }
-
-        write!(self.output(), "{value:e}")?;
+        let float_format = self.writer.float_format;
+        write!(
+            self.output(),
+            "{}",
+            FormattedFloat::new(value, float_format)
+        )?;
         self.write_delimiter_text()
     }
 
@@ -617,7 +639,7 @@ impl<'value, W: Write> ValueWriter for TextValueWriter_1_0<'value, W> {
 
     fn write_symbol(mut self, value: impl AsRawSymbolRef) -> IonResult<()> {
         self.write_indentation()?;
-        write_symbol_token(self.output(), value)?;
+        self.writer.write_symbol_token(value)?;
         self.write_delimiter_text()
     }
 
@@ -645,7 +667,11 @@ impl<'value, W: Write> ValueWriter for TextValueWriter_1_0<'value, W> {
         // * A {} pair used by the format string to indicate where the base64-encoded bytes
         //   should be inserted.
         // * The closing }} from a text Ion blob, with each brace doubled to escape it.
-        write!(self.output(), "{{{{{}}}}}", base64::encode(value))?;
+        write!(
+            self.output(),
+            "{{{{{}}}}}",
+            crate::text::base64::encode(value)
+        )?;
         self.write_delimiter_text()
     }
 
@@ -665,6 +691,16 @@ impl<'value, W: Write> ValueWriter for TextValueWriter_1_0<'value, W> {
             self.value_delimiter,
         )
     }
+
+    fn inline_sexp_writer(self) -> IonResult<Self::SExpWriter> {
+        TextSExpWriter_1_0::with_layout(
+            self.writer,
+            self.depth,
+            self.parent_type,
+            self.value_delimiter,
+            true,
+        )
+    }
     fn struct_writer(self) -> IonResult<Self::StructWriter> {
         TextStructWriter_1_0::new(
             self.writer,
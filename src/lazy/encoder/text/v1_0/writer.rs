@@ -2,7 +2,8 @@ use std::io::Write;
 
 use delegate::delegate;
 
-use crate::lazy::encoder::text::v1_0::value_writer::TextValueWriter_1_0;
+use crate::lazy::encoder::text::v1_0::layout::LayoutBuffer;
+use crate::lazy::encoder::text::v1_0::value_writer::{write_symbol_token, TextValueWriter_1_0};
 use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
 use crate::lazy::encoder::value_writer::SequenceWriter;
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
@@ -10,17 +11,30 @@ use crate::lazy::encoder::writer::WriterMacroTable;
 use crate::lazy::encoder::LazyRawWriter;
 use crate::lazy::encoding::{Encoding, TextEncoding_1_0};
 use crate::lazy::expanded::macro_table::EMPTY_MACRO_TABLE;
-use crate::text::whitespace_config::{
-    WhitespaceConfig, COMPACT_WHITESPACE_CONFIG, LINES_WHITESPACE_CONFIG, PRETTY_WHITESPACE_CONFIG,
-};
+use crate::raw_symbol_ref::AsRawSymbolRef;
+use crate::text::whitespace_config::WhitespaceConfig;
 use crate::types::ParentType;
 use crate::write_config::WriteConfigKind;
-use crate::{ContextWriter, IonResult, TextFormat, WriteConfig};
+use crate::{
+    ContextWriter, FloatFormat, IonResult, MacroFormat, RawSymbolRef, SymbolIdFormat, SymbolTable,
+    TextFormat, WriteConfig,
+};
 
 /// A raw text Ion 1.0 writer.
 pub struct LazyRawTextWriter_1_0<W: Write> {
     pub(crate) output: W,
-    pub(crate) whitespace_config: &'static WhitespaceConfig,
+    pub(crate) whitespace_config: WhitespaceConfig,
+    // If the writer's `PrettyConfig` chooses each container's layout based on its contents,
+    // containers are written to this buffer until the top-level container is complete.
+    pub(crate) layout: Option<Box<LayoutBuffer>>,
+    pub(crate) float_format: FloatFormat,
+    // Only Ion 1.1 has macros, but the 1.1 writer's containers and e-expressions are written by
+    // this type.
+    pub(crate) macro_format: MacroFormat,
+    pub(crate) symbol_id_format: SymbolIdFormat,
+    // The symbol table used to find the text of symbol IDs that the writer is asked to write. This
+    // is only set when `symbol_id_format` is not the default.
+    pub(crate) symbol_id_table: Option<Box<SymbolTable>>,
 }
 
 impl<W: Write> LazyRawTextWriter_1_0<W> {
@@ -41,6 +55,97 @@ impl<W: Write> LazyRawTextWriter_1_0<W> {
         Ok(())
     }
 
+    /// Writes a symbol value, field name, or annotation. If `token` is a symbol ID, the writer's
+    /// [`SymbolIdFormat`] determines whether it is written as text, as a symbol ID, or both.
+    pub(crate) fn write_symbol_token(&mut self, token: impl AsRawSymbolRef) -> IonResult<()> {
+        let token = token.as_raw_symbol_ref();
+        let (RawSymbolRef::SymbolId(sid), Some(symbol_table)) = (token, &self.symbol_id_table)
+        else {
+            return write_symbol_token(self.sink(), token);
+        };
+        let sink = text_sink(&mut self.output, &mut self.layout);
+        match (symbol_table.text_for(sid), self.symbol_id_format) {
+            (None, _) | (_, SymbolIdFormat::Omit) => write_symbol_token(sink, token),
+            (Some(text), SymbolIdFormat::PreserveUnknown) => write_symbol_token(sink, text),
+            (Some(text), SymbolIdFormat::Comment) => {
+                write_symbol_token(sink, text)?;
+                write!(sink, " /*${sid}*/")?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the destination for encoded text. While a container is being laid out, this is the
+    /// layout buffer rather than the output.
+    pub(crate) fn sink(&mut self) -> &mut dyn Write {
+        text_sink(&mut self.output, &mut self.layout)
+    }
+
+    /// Writes the whitespace config's `indentation` string `depth` times.
+    pub(crate) fn write_indentation(&mut self, depth: usize) -> IonResult<()> {
+        let indentation = self.whitespace_config.indentation;
+        if indentation.is_empty() || depth == 0 {
+            return Ok(());
+        }
+        match &mut self.layout {
+            Some(layout) if layout.is_recording() => layout.push_indentation(indentation, depth),
+            _ => {
+                for _ in 0..depth {
+                    write!(self.output, "{indentation}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the whitespace that follows a container's opening delimiter.
+    pub(crate) fn write_space_after_container_start(&mut self) -> IonResult<()> {
+        let space = self.whitespace_config.space_after_container_start;
+        match &mut self.layout {
+            Some(layout) if layout.is_recording() => layout.push_space(space, ""),
+            _ => write!(self.output, "{space}")?,
+        }
+        Ok(())
+    }
+
+    /// Writes the delimiter (if any) and whitespace that follow a value at the specified depth.
+    pub(crate) fn write_value_separator(
+        &mut self,
+        delimiter: &'static str,
+        depth: usize,
+    ) -> IonResult<()> {
+        let space = match depth {
+            0 => self.whitespace_config.space_between_top_level_values,
+            _ => self.whitespace_config.space_between_nested_values,
+        };
+        match &mut self.layout {
+            Some(layout) if layout.is_recording() => {
+                if !delimiter.is_empty() {
+                    layout.push_delimiter(delimiter);
+                }
+                layout.push_space(space, " ");
+            }
+            _ => write!(self.output, "{delimiter}{space}")?,
+        }
+        Ok(())
+    }
+
+    /// Records the start of a container. If `inline` is `true`, the container is written on a
+    /// single line. Otherwise, its layout is decided by the writer's whitespace config.
+    pub(crate) fn begin_container(&mut self, inline: bool) {
+        if let Some(layout) = &mut self.layout {
+            layout.begin_container(inline);
+        }
+    }
+
+    /// Records the end of a container.
+    pub(crate) fn end_container(&mut self) -> IonResult<()> {
+        match &mut self.layout {
+            Some(layout) => layout.end_container(&mut self.output),
+            None => Ok(()),
+        }
+    }
+
     /// Helper method to construct this format's `ValueWriter` implementation.
     #[inline]
     fn value_writer(&mut self) -> TextValueWriter_1_0<'_, W> {
@@ -53,6 +158,19 @@ impl<W: Write> LazyRawTextWriter_1_0<W> {
     }
 }
 
+/// Returns `layout`'s buffer if it is recording a container, or `output` otherwise. This is a
+/// function of the writer's fields rather than the writer itself so that callers can hold other
+/// fields while writing.
+fn text_sink<'a, W: Write>(
+    output: &'a mut W,
+    layout: &'a mut Option<Box<LayoutBuffer>>,
+) -> &'a mut dyn Write {
+    match layout {
+        Some(layout) if layout.is_recording() => layout.text_mut(),
+        _ => output,
+    }
+}
+
 impl<W: Write> SequenceWriter for LazyRawTextWriter_1_0<W> {
     type Resources = W;
 
@@ -86,17 +204,15 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_0<W> {
     /// Build text writer based on given writer configuration
     fn build<E: Encoding>(config: WriteConfig<E>, output: W) -> IonResult<Self> {
         match &config.kind {
-            WriteConfigKind::Text(text_config) => {
-                let whitespace_config = match text_config.text_kind {
-                    TextFormat::Compact => &COMPACT_WHITESPACE_CONFIG,
-                    TextFormat::Lines => &LINES_WHITESPACE_CONFIG,
-                    TextFormat::Pretty => &PRETTY_WHITESPACE_CONFIG,
-                };
-                Ok(LazyRawTextWriter_1_0 {
-                    output,
-                    whitespace_config,
-                })
-            }
+            WriteConfigKind::Text(text_config) => Ok(LazyRawTextWriter_1_0 {
+                output,
+                whitespace_config: text_config.whitespace_config(),
+                layout: text_config.layout_buffer(),
+                float_format: text_config.float_format,
+                macro_format: text_config.macro_format,
+                symbol_id_format: text_config.symbol_id_format,
+                symbol_id_table: None,
+            }),
             WriteConfigKind::Binary(_) => {
                 unreachable!("Binary writer can not be created from text encoding")
             }
@@ -123,6 +239,12 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_0<W> {
         &EMPTY_MACRO_TABLE
     }
 
+    fn set_symbol_id_table(&mut self, symbol_table: &SymbolTable) {
+        if self.symbol_id_format != SymbolIdFormat::Omit {
+            self.symbol_id_table = Some(Box::new(symbol_table.clone()));
+        }
+    }
+
     fn macro_table_mut(&mut self) -> Option<&mut WriterMacroTable> {
         None
     }
@@ -132,12 +254,24 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_0<W> {
         write!(self.output, "$ion_1_0{space_between}")?;
         Ok(())
     }
+
+    fn write_encoded_value(&mut self, encoded: &[u8]) -> IonResult<()> {
+        let space_between = self.whitespace_config.space_between_top_level_values;
+        self.output.write_all(encoded)?;
+        self.output.write_all(space_between.as_bytes())?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
-    use crate::{v1_1, Annotatable, ElementReader, IonData, IonResult, Reader, SequenceWriter};
+    use crate::lazy::encoder::LazyRawWriter;
+    use crate::lazy::encoding::TextEncoding_1_0;
+    use crate::{
+        v1_1, Annotatable, Element, ElementReader, FloatFormat, IonData, IonResult, PrettyConfig,
+        Reader, SequenceWriter, TextFormat, WriteConfig, Writer,
+    };
 
     #[test]
     fn write_annotated_values() -> IonResult<()> {
@@ -169,4 +303,88 @@ mod tests {
         assert!(IonData::eq(&expected, &actual));
         Ok(())
     }
+
+    fn write_floats(float_format: FloatFormat) -> IonResult<String> {
+        let config = WriteConfig::<TextEncoding_1_0>::new(TextFormat::Compact)
+            .with_float_format(float_format);
+        let mut writer = LazyRawTextWriter_1_0::build(config, vec![])?;
+        writer
+            .write(1234.5f64)?
+            .write(0.001f64)?
+            .write(0.1f32)?
+            .write(f64::NAN)?;
+        Ok(String::from_utf8(writer.close()?).unwrap())
+    }
+
+    #[test]
+    fn write_floats_with_float_format() -> IonResult<()> {
+        assert_eq!(
+            write_floats(FloatFormat::Scientific)?,
+            "1.2345e3 1e-3 1.0000000149011612e-1 nan "
+        );
+        assert_eq!(
+            write_floats(FloatFormat::Shortest)?,
+            "1234.5e0 1e-3 0.10000000149011612e0 nan "
+        );
+        assert_eq!(
+            write_floats(FloatFormat::FixedPrecision(2))?,
+            "1.23e3 1.00e-3 1.00e-1 nan "
+        );
+        Ok(())
+    }
+
+    fn write_pretty(pretty_config: PrettyConfig) -> IonResult<String> {
+        let config = WriteConfig::<TextEncoding_1_0>::new(TextFormat::Pretty)
+            .with_pretty_config(pretty_config);
+        let mut writer = Writer::new(config, vec![])?;
+        let data = Element::read_all(r#"{a: [1, 2], b: ann::(x y [])} 3 ["ééé", "éé"]"#)?;
+        for element in &data {
+            writer.write(element)?;
+        }
+        let text = String::from_utf8(writer.close()?).unwrap();
+        assert_eq!(Element::read_all(&text)?, data, "{text}");
+        Ok(text)
+    }
+
+    #[test]
+    fn write_with_pretty_config() -> IonResult<()> {
+        let pretty_config = PrettyConfig::new()
+            .with_indentation("\t")
+            .with_space_after_colon(false);
+        assert_eq!(
+            write_pretty(pretty_config)?,
+            "{\n\ta:[\n\t\t1,\n\t\t2,\n\t],\n\tb:ann::(\n\t\tx\n\t\ty\n\t\t[\n\t\t]\n\t),\n}\n\
+             3\n\
+             [\n\t\"ééé\",\n\t\"éé\",\n]\n"
+        );
+        assert_eq!(
+            write_pretty(PrettyConfig::new().with_trailing_commas(false))?,
+            "{\n  a: [\n    1,\n    2\n  ],\n  b: ann::(\n    x\n    y\n    [\n    ]\n  )\n}\n\
+             3\n\
+             [\n  \"ééé\",\n  \"éé\"\n]\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn write_inline_containers_with_pretty_config() -> IonResult<()> {
+        // The last list is 13 characters wide, but its UTF-8 encoding is 18 bytes.
+        assert_eq!(
+            write_pretty(PrettyConfig::new().with_max_inline_width(12))?,
+            "{\n  a: [1, 2],\n  b: ann::(x y []),\n}\n\
+             3\n\
+             [\n  \"ééé\",\n  \"éé\",\n]\n"
+        );
+        assert_eq!(
+            write_pretty(PrettyConfig::new().with_max_inline_width(13))?,
+            "{\n  a: [1, 2],\n  b: ann::(x y []),\n}\n\
+             3\n\
+             [\"ééé\", \"éé\"]\n"
+        );
+        assert_eq!(
+            write_pretty(PrettyConfig::new().with_max_inline_width(100))?,
+            "{a: [1, 2], b: ann::(x y [])}\n3\n[\"ééé\", \"éé\"]\n"
+        );
+        Ok(())
+    }
 }
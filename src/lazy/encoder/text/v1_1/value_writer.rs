@@ -11,13 +11,13 @@ use crate::lazy::encoder::value_writer::{
     AnnotatableWriter, EExpWriter, SequenceWriter, StructWriter, ValueWriter,
 };
 use crate::lazy::expanded::macro_table::MacroRef;
-use crate::lazy::expanded::template::{Parameter, SignatureIterator};
+use crate::lazy::expanded::template::{Parameter, ParameterEncoding, SignatureIterator};
 use crate::lazy::text::raw::v1_1::reader::{MacroIdLike, MacroIdRef};
 use crate::raw_symbol_ref::AsRawSymbolRef;
 use crate::result::IonFailure;
 use crate::types::{ContainerType, ParentType};
 use crate::{
-    v1_1, ContextWriter, Decimal, Encoding, Int, IonResult, IonType, MacroTable, Timestamp,
+    v1_1, ContextWriter, Decimal, Encoding, Int, IonResult, IonType, MacroTable, Timestamp, UInt,
     ValueWriterConfig,
 };
 use compact_str::format_compact;
@@ -92,6 +92,13 @@ impl<'value, W: Write + 'value> ValueWriter for TextValueWriter_1_1<'value, W> {
         })
     }
 
+    fn inline_sexp_writer(self) -> IonResult<Self::SExpWriter> {
+        Ok(TextSExpWriter_1_1 {
+            writer_1_0: self.value_writer_1_0.inline_sexp_writer()?,
+            macros: self.macros,
+        })
+    }
+
     fn struct_writer(self) -> IonResult<Self::StructWriter> {
         Ok(TextStructWriter_1_1 {
             writer_1_0: self.value_writer_1_0.struct_writer()?,
@@ -104,13 +111,21 @@ impl<'value, W: Write + 'value> ValueWriter for TextValueWriter_1_1<'value, W> {
         Self: 'a,
     {
         let macro_ref = macro_id.resolve(self.macros)?;
+        let macro_format = self.value_writer_1_0.writer.macro_format;
         let opening_text = match macro_id.prefer_name() {
             MacroIdRef::LocalName(name) => format_compact!("(:{} ", name),
             MacroIdRef::LocalAddress(address) => format_compact!("(:{} ", address),
-            MacroIdRef::SystemAddress(system_address) => {
-                format_compact!("(:$ion::{} ", system_address.as_usize())
+            MacroIdRef::SystemAddress(system_address) => match macro_ref.name() {
+                Some(name) if macro_format.writes_system_macros_by_name() => {
+                    format_compact!("(:$ion::{} ", name)
+                }
+                _ => format_compact!("(:$ion::{} ", system_address.as_usize()),
+            },
+            qualified_id @ (MacroIdRef::QualifiedName(..) | MacroIdRef::QualifiedAddress(..)) => {
+                format_compact!("(:{} ", qualified_id)
             }
         };
+        let inline = macro_format.is_inline(macro_ref.signature().len());
         TextEExpWriter_1_1::new(
             self.value_writer_1_0.writer,
             self.value_writer_1_0.depth,
@@ -119,6 +134,7 @@ impl<'value, W: Write + 'value> ValueWriter for TextValueWriter_1_1<'value, W> {
             opening_text.as_str(),
             self.macros,
             macro_ref,
+            inline,
         )
     }
 }
@@ -303,14 +319,15 @@ impl<'value, W: Write> TextEExpWriter_1_1<'value, W> {
         opening_text: &str,
         macros: &'value MacroTable,
         invoked_macro: MacroRef<'value>,
+        inline: bool,
     ) -> IonResult<Self> {
         let trailing_delimiter = match parent_type {
             ParentType::Struct | ParentType::List => ",",
             _ => "",
         };
-        let value_delimiter = " ";
+        let value_delimiter = "";
 
-        let container_writer = TextContainerWriter_1_0::new(
+        let container_writer = TextContainerWriter_1_0::with_layout(
             writer,
             depth,
             parent_type,
@@ -319,6 +336,7 @@ impl<'value, W: Write> TextEExpWriter_1_1<'value, W> {
             opening_text,
             value_delimiter,
             trailing_delimiter,
+            inline,
         )?;
         let signature_iter = invoked_macro.iter_signature();
         Ok(Self {
@@ -333,22 +351,28 @@ impl<'value, W: Write + 'value> SequenceWriter for TextEExpWriter_1_1<'value, W>
     type Resources = ();
 
     fn close(self) -> IonResult<Self::Resources> {
+        self.signature_iter.expect_complete()?;
         self.container_writer.close(")")
     }
 }
 
 impl<'value, W: Write + 'value> ContextWriter for TextEExpWriter_1_1<'value, W> {
     type NestedValueWriter<'a>
-        = TextValueWriter_1_1<'a, W>
+        = TextEExpParameterValueWriter_1_1<'a, W>
     where
         Self: 'a;
 }
 
 impl<'value, W: Write + 'value> MakeValueWriter for TextEExpWriter_1_1<'value, W> {
     fn make_value_writer(&mut self) -> Self::NestedValueWriter<'_> {
-        TextValueWriter_1_1 {
-            value_writer_1_0: self.container_writer.value_writer(),
-            macros: self.macros,
+        // Each value written to the e-expression is the argument for the next parameter.
+        let parameter = self.signature_iter.expect_next_parameter().ok();
+        TextEExpParameterValueWriter_1_1 {
+            value_writer: TextValueWriter_1_1 {
+                value_writer_1_0: self.container_writer.value_writer(),
+                macros: self.macros,
+            },
+            parameter,
         }
     }
 }
@@ -373,23 +397,190 @@ impl<'eexp, W: Write + 'eexp> EExpWriter for TextEExpWriter_1_1<'eexp, W> {
         self.signature_iter.current_parameter()
     }
 
+    // Text has no tagless encodings; arguments for tagless parameters are written as integers.
+
+    fn write_flex_uint(&mut self, value: impl Into<UInt>) -> IonResult<()> {
+        self.signature_iter
+            .expect_next_parameter()
+            .and_then(|p| p.expect_encoding(&ParameterEncoding::FlexUInt))?;
+        let value: Int = value.into().try_into()?;
+        self.container_writer.value_writer().write_int(&value)
+    }
+
+    fn write_fixed_uint8(&mut self, value: impl Into<u8>) -> IonResult<()> {
+        self.signature_iter
+            .expect_next_parameter()
+            .and_then(|p| p.expect_encoding(&ParameterEncoding::UInt8))?;
+        self.container_writer
+            .value_writer()
+            .write_i64(i64::from(value.into()))
+    }
+
     fn expr_group_writer(&mut self) -> IonResult<Self::ExprGroupWriter<'_>> {
+        let parameter = self
+            .signature_iter
+            .expect_next_parameter()
+            .and_then(|p| p.expect_variadic())?;
         TextExprGroupWriter::new(
             self.container_writer.writer,
             self.container_writer.depth,
             self.container_writer.container_type.into(),
-            " ",
+            "",
             self.macros,
+            parameter,
         )
     }
     // Default SequenceWriter methods
 }
 
+macro_rules! validate_parameter_and_delegate {
+    () => {};
+    ($value_type:ty => $method:ident as $value_kind:literal, $($rest:tt)*) => {
+        fn $method(self, value: $value_type) -> IonResult<()> {
+            self.expect_tagged_parameter($value_kind)?;
+            self.value_writer.$method(value)
+        }
+        validate_parameter_and_delegate!($($rest)*);
+    };
+}
+
+/// Writes an argument of a text e-expression, confirming that the value being written is valid
+/// for the corresponding parameter in the invoked macro's signature. This applies the same rules
+/// as the binary writer so that an e-expression which can be written in one encoding can also be
+/// written in the other.
+pub struct TextEExpParameterValueWriter_1_1<'value, W: Write> {
+    value_writer: TextValueWriter_1_1<'value, W>,
+    parameter: Option<&'value Parameter>,
+}
+
+impl<'value, W: Write> TextEExpParameterValueWriter_1_1<'value, W> {
+    /// Returns the parameter for which this writer's argument is being written. If the macro's
+    /// signature has no more parameters, returns an `Err` that describes the value being written
+    /// as `value_kind`.
+    fn expect_parameter(&self, value_kind: &str) -> IonResult<&'value Parameter> {
+        match self.parameter {
+            Some(parameter) => Ok(parameter),
+            None => IonResult::encoding_error(format!(
+                "cannot write {value_kind} as an e-expression argument; the macro takes no more parameters"
+            )),
+        }
+    }
+
+    /// Confirms that the parameter accepts a single tagged value.
+    fn expect_tagged_parameter(&self, value_kind: &str) -> IonResult<&'value Parameter> {
+        self.expect_parameter(value_kind)?
+            .expect_tagged_argument(value_kind)
+    }
+
+    /// Writes an integer argument. If the parameter has a tagless encoding, the value must be
+    /// within the range that the encoding can represent.
+    fn write_integer(self, value: &Int) -> IonResult<()> {
+        use ParameterEncoding as PE;
+
+        let param = self
+            .expect_parameter("an int")?
+            .expect_single_expression()?;
+
+        let validation = match param.encoding() {
+            PE::Tagged => Ok(()),
+            PE::FlexUInt => UInt::try_from(value).map(|_| ()),
+            PE::UInt8 => UInt::try_from(value).and_then(u8::try_from).map(|_| ()),
+            PE::UInt16 => UInt::try_from(value).and_then(u16::try_from).map(|_| ()),
+            PE::UInt32 => UInt::try_from(value).and_then(u32::try_from).map(|_| ()),
+            PE::UInt64 => UInt::try_from(value).and_then(u64::try_from).map(|_| ()),
+            encoding => IonResult::encoding_error(format!(
+                "value does not satisfy encoding type {encoding}"
+            )),
+        };
+        if let Err(err) = validation {
+            return IonResult::encoding_error(format!(
+                "error with value provided for '{}': {err}",
+                param.name()
+            ));
+        }
+        self.value_writer.write_int(value)
+    }
+}
+
+impl<'value, W: Write + 'value> AnnotatableWriter for TextEExpParameterValueWriter_1_1<'value, W> {
+    type AnnotatedValueWriter<'a>
+        = TextAnnotatedValueWriter_1_1<'a, W>
+    where
+        Self: 'a;
+
+    fn with_annotations<'a>(
+        self,
+        annotations: impl AnnotationSeq<'a>,
+    ) -> IonResult<Self::AnnotatedValueWriter<'a>>
+    where
+        Self: 'a,
+    {
+        // Only tagged encodings can represent annotations.
+        self.expect_tagged_parameter("an annotated value")?;
+        self.value_writer.with_annotations(annotations)
+    }
+}
+
+impl<'value, W: Write + 'value> ValueWriter for TextEExpParameterValueWriter_1_1<'value, W> {
+    type ListWriter = TextListWriter_1_1<'value, W>;
+    type SExpWriter = TextSExpWriter_1_1<'value, W>;
+    type StructWriter = TextStructWriter_1_1<'value, W>;
+    type EExpWriter = TextEExpWriter_1_1<'value, W>;
+
+    validate_parameter_and_delegate!(
+        IonType => write_null as "a null",
+        bool => write_bool as "a bool",
+        f32 => write_f32 as "a float",
+        f64 => write_f64 as "a float",
+        &Decimal => write_decimal as "a decimal",
+        &Timestamp => write_timestamp as "a timestamp",
+        impl AsRef<str> => write_string as "a string",
+        impl AsRawSymbolRef => write_symbol as "a symbol",
+        impl AsRef<[u8]> => write_clob as "a clob",
+        impl AsRef<[u8]> => write_blob as "a blob",
+    );
+
+    fn write_i64(self, value: i64) -> IonResult<()> {
+        self.write_integer(&value.into())
+    }
+
+    fn write_int(self, value: &Int) -> IonResult<()> {
+        self.write_integer(value)
+    }
+
+    fn list_writer(self) -> IonResult<Self::ListWriter> {
+        self.expect_tagged_parameter("a list")?;
+        self.value_writer.list_writer()
+    }
+
+    fn sexp_writer(self) -> IonResult<Self::SExpWriter> {
+        self.expect_tagged_parameter("an s-expression")?;
+        self.value_writer.sexp_writer()
+    }
+
+    fn struct_writer(self) -> IonResult<Self::StructWriter> {
+        self.expect_tagged_parameter("a struct")?;
+        self.value_writer.struct_writer()
+    }
+
+    fn eexp_writer<'a>(self, macro_id: impl MacroIdLike<'a>) -> IonResult<Self::EExpWriter>
+    where
+        Self: 'a,
+    {
+        self.expect_tagged_parameter("an e-expression")?;
+        self.value_writer.eexp_writer(macro_id)
+    }
+}
+
 pub struct TextExprGroupWriter<'group, W: Write> {
     // There is no expr group writer in 1.0 to which we can delegate,
     // but we can re-use the TextContainerWriter_1_0 for a lot of the formatting.
     container_writer: TextContainerWriter_1_0<'group, W>,
     macros: &'group MacroTable,
+    // The number of members is validated against the parameter's cardinality when the group is
+    // closed.
+    parameter: &'group Parameter,
+    num_expressions: usize,
 }
 
 impl<'group, W: Write> TextExprGroupWriter<'group, W> {
@@ -399,6 +590,7 @@ impl<'group, W: Write> TextExprGroupWriter<'group, W> {
         parent_type: ParentType,
         trailing_delimiter: &'static str,
         macros: &'group MacroTable,
+        parameter: &'group Parameter,
     ) -> IonResult<Self> {
         let container_writer = TextContainerWriter_1_0::new(
             writer,
@@ -406,18 +598,21 @@ impl<'group, W: Write> TextExprGroupWriter<'group, W> {
             parent_type,
             ContainerType::SExp,
             "(::",
-            " ",
+            "",
             trailing_delimiter,
         )?;
         Ok(Self {
             container_writer,
             macros,
+            parameter,
+            num_expressions: 0,
         })
     }
 }
 
 impl<W: Write> MakeValueWriter for TextExprGroupWriter<'_, W> {
     fn make_value_writer(&mut self) -> <Self as ContextWriter>::NestedValueWriter<'_> {
+        self.num_expressions += 1;
         TextValueWriter_1_1 {
             value_writer_1_0: self.container_writer.value_writer(),
             macros: self.macros,
@@ -429,6 +624,7 @@ impl<W: Write> SequenceWriter for TextExprGroupWriter<'_, W> {
     type Resources = ();
 
     fn close(self) -> IonResult<Self::Resources> {
+        self.parameter.expect_group_size(self.num_expressions)?;
         self.container_writer.close(")")
     }
 }
@@ -7,11 +7,10 @@ use crate::lazy::encoder::value_writer::SequenceWriter;
 use crate::lazy::encoder::writer::WriterMacroTable;
 use crate::lazy::encoder::LazyRawWriter;
 use crate::lazy::encoding::{Encoding, TextEncoding_1_1};
-use crate::text::whitespace_config::{
-    COMPACT_WHITESPACE_CONFIG, LINES_WHITESPACE_CONFIG, PRETTY_WHITESPACE_CONFIG,
-};
 use crate::write_config::WriteConfigKind;
-use crate::{ContextWriter, IonResult, IonVersion, MacroTable, TextFormat, WriteConfig};
+use crate::{
+    ContextWriter, IonResult, IonVersion, MacroTable, SymbolTable, TextFormat, WriteConfig,
+};
 // Text Ion 1.1 is a syntactic superset of Ion 1.0. The types comprising this writer implementation
 // delegate nearly all of their functionality to the 1.0 text writer.
 
@@ -63,11 +62,7 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_1<W> {
     {
         match &config.kind {
             WriteConfigKind::Text(text_config) => {
-                let whitespace_config = match text_config.text_kind {
-                    TextFormat::Compact => &COMPACT_WHITESPACE_CONFIG,
-                    TextFormat::Lines => &LINES_WHITESPACE_CONFIG,
-                    TextFormat::Pretty => &PRETTY_WHITESPACE_CONFIG,
-                };
+                let whitespace_config = text_config.whitespace_config();
                 write!(
                     output,
                     "$ion_1_1{}",
@@ -77,6 +72,11 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_1<W> {
                     writer_1_0: LazyRawTextWriter_1_0 {
                         output,
                         whitespace_config,
+                        layout: text_config.layout_buffer(),
+                        float_format: text_config.float_format,
+                        macro_format: text_config.macro_format,
+                        symbol_id_format: text_config.symbol_id_format,
+                        symbol_id_table: None,
                     },
                     macros: WriterMacroTable::new(MacroTable::with_system_macros(IonVersion::v1_1)),
                 })
@@ -107,6 +107,10 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_1<W> {
         Some(&mut self.macros)
     }
 
+    fn set_symbol_id_table(&mut self, symbol_table: &SymbolTable) {
+        self.writer_1_0.set_symbol_id_table(symbol_table)
+    }
+
     fn write_version_marker(&mut self) -> IonResult<()> {
         let space_between = self
             .writer_1_0
@@ -115,6 +119,10 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_1<W> {
         write!(self.writer_1_0.output, "$ion_1_1{space_between}")?;
         Ok(())
     }
+
+    fn write_encoded_value(&mut self, encoded: &[u8]) -> IonResult<()> {
+        self.writer_1_0.write_encoded_value(encoded)
+    }
 }
 
 #[cfg(feature = "experimental-ion-1-1")]
@@ -129,11 +137,12 @@ mod tests {
     use crate::lazy::expanded::compiler::TemplateCompiler;
     use crate::lazy::expanded::macro_evaluator::RawEExpression;
     use crate::lazy::expanded::EncodingContext;
-    use crate::lazy::text::raw::v1_1::reader::{LazyRawTextReader_1_1, MacroIdRef};
+    use crate::lazy::text::raw::v1_1::reader::{system_macros, LazyRawTextReader_1_1, MacroIdRef};
     use crate::symbol_ref::AsSymbolRef;
     use crate::{
-        v1_1, Annotatable, Decimal, EExpWriter, ElementReader, IonData, IonResult, IonType, Null,
-        RawSymbolRef, Reader, Timestamp, Writer,
+        v1_1, Annotatable, Decimal, EExpWriter, Element, ElementReader, IonData, IonResult,
+        IonType, MacroFormat, Null, PrettyConfig, RawSymbolRef, Reader, TextFormat, Timestamp,
+        WriteConfig, Writer,
     };
 
     #[test]
@@ -388,4 +397,63 @@ mod tests {
         assert_eq!("foobarbaz+++", element.unwrap().as_string().unwrap());
         Ok(())
     }
+
+    fn write_with_macro_format(format: MacroFormat) -> IonResult<String> {
+        write_eexps(WriteConfig::<v1_1::Text>::new(TextFormat::Pretty).with_macro_format(format))
+    }
+
+    fn write_eexps(config: WriteConfig<v1_1::Text>) -> IonResult<String> {
+        let mut writer = Writer::new(config, vec![])?;
+        let foo =
+            writer.compile_macro("(macro foo (x y*) (.values (%x) (.make_string (%y) \"z\")))")?;
+        let mut eexp = writer.eexp_writer(&foo)?;
+        eexp.write(1)?;
+        let mut group = eexp.expr_group_writer()?;
+        group.write("a")?.write("b")?;
+        group.close()?;
+        eexp.close()?;
+        let mut eexp = writer.eexp_writer(system_macros::MAKE_STRING)?;
+        let mut group = eexp.expr_group_writer()?;
+        group.write("c")?;
+        group.close()?;
+        eexp.close()?;
+        Ok(String::from_utf8(writer.close()?).unwrap())
+    }
+
+    #[test]
+    fn macro_format_lays_out_eexps_and_tdl() -> IonResult<()> {
+        let default_text = write_with_macro_format(MacroFormat::default())?;
+        assert!(default_text.contains("(:foo \n  1\n"), "{default_text}");
+        assert!(default_text.contains("(:$ion::9 \n"), "{default_text}");
+
+        let format = MacroFormat::new()
+            .with_max_inline_args(2)
+            .with_indented_values(false)
+            .with_system_macros_by_name(true);
+        let text = write_with_macro_format(format)?;
+        assert!(text.contains("(x y '*' )"), "{text}");
+        assert!(text.contains("('.' values "), "{text}");
+        assert!(text.contains("(:foo 1 (::\"a\" \"b\" ) )"), "{text}");
+        assert!(text.contains("(:$ion::make_string"), "{text}");
+
+        // Both layouts read back as the same data.
+        let expected = Element::read_all("1 \"abz\" \"c\"")?;
+        assert_eq!(Element::read_all(&default_text)?, expected);
+        assert_eq!(Element::read_all(&text)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn pretty_config_lays_out_eexps() -> IonResult<()> {
+        let pretty_config = PrettyConfig::new()
+            .with_max_inline_width(24)
+            .with_trailing_commas(false);
+        let config =
+            WriteConfig::<v1_1::Text>::new(TextFormat::Pretty).with_pretty_config(pretty_config);
+        let text = write_eexps(config)?;
+        assert!(text.contains("(:foo 1 (::\"a\" \"b\"))"), "{text}");
+        let expected = Element::read_all("1 \"abz\" \"c\"")?;
+        assert_eq!(Element::read_all(&text)?, expected);
+        Ok(())
+    }
 }
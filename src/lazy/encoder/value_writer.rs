@@ -1,10 +1,11 @@
-use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsVec};
+use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsBuf, AnnotationsVec};
 use crate::lazy::encoder::value_writer::internal::{
     EExpWriterInternal, FieldEncoder, MakeValueWriter,
 };
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
-use crate::lazy::text::raw::v1_1::reader::MacroIdLike;
+use crate::lazy::text::raw::v1_1::reader::{system_macros, MacroIdLike};
 use crate::raw_symbol_ref::AsRawSymbolRef;
+use crate::result::IonFailure;
 use crate::{Decimal, Int, IonResult, IonType, RawSymbolRef, Timestamp, UInt};
 
 // This module is `pub(crate)` to deter crates from providing their own implementations of these traits.
@@ -85,6 +86,42 @@ pub trait AnnotatableWriter {
     ) -> IonResult<Self::AnnotatedValueWriter<'a>>
     where
         Self: 'a;
+
+    /// Like [`with_annotations`](Self::with_annotations), but takes the annotations from an
+    /// iterator. This allows a dynamic list of annotations to be written without first
+    /// collecting it into a `Vec`; sequences of two or fewer annotations do not allocate.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_0, AnnotatableWriter, Element, SequenceWriter, ValueWriter, Writer};
+    ///
+    /// let tags = vec![String::from("foo"), String::from("bar")];
+    /// let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+    /// writer
+    ///     .value_writer()
+    ///     .with_annotations_iter(tags.iter().rev())?
+    ///     .write_i64(42)?;
+    /// let output = writer.close()?;
+    /// assert_eq!(Element::read_one(output)?, Element::read_one("bar::foo::42")?);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    fn with_annotations_iter<'a, I>(
+        self,
+        annotations: I,
+    ) -> IonResult<Self::AnnotatedValueWriter<'a>>
+    where
+        Self: 'a + Sized,
+        I: IntoIterator,
+        I::Item: Into<RawSymbolRef<'a>>,
+    {
+        let annotations: AnnotationsVec<'a> = annotations.into_iter().map(Into::into).collect();
+        self.with_annotations(annotations)
+    }
 }
 
 pub trait ValueWriter: AnnotatableWriter + Sized {
@@ -113,6 +150,13 @@ pub trait ValueWriter: AnnotatableWriter + Sized {
     where
         Self: 'a;
 
+    /// Returns a writer for an s-expression that should be written on a single line, even if the
+    /// writer would otherwise put nested values on their own lines. Writers for which layout is
+    /// not meaningful (like binary writers) return an ordinary s-expression writer.
+    fn inline_sexp_writer(self) -> IonResult<Self::SExpWriter> {
+        self.sexp_writer()
+    }
+
     fn write(self, value: impl WriteAsIon) -> IonResult<()> {
         value.write_as_ion(self)
     }
@@ -137,6 +181,73 @@ pub trait ValueWriter: AnnotatableWriter + Sized {
         strukt.write_all(values)?;
         strukt.close()
     }
+
+    /// Writes an invocation of the Ion 1.1 system macro `delta` that expands to the provided
+    /// integers. The first integer is written as-is and each one after it is written as its
+    /// difference from the one before, which is often much smaller for sorted or slowly changing
+    /// data like timestamps and IDs.
+    ///
+    /// Returns an `Err` if the writer does not support e-expressions (as in Ion 1.0) or if the
+    /// difference between two consecutive integers is outside the range of an `i128`.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-ion-1-1")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_1, Element, SequenceWriter, ValueWriter, Writer};
+    ///
+    /// let mut writer = Writer::new(v1_1::Text, Vec::new())?;
+    /// writer.value_writer().write_delta_encoded([1000, 1001, 1003, 1003, 998])?;
+    /// let output = writer.close()?;
+    /// assert!(std::str::from_utf8(&output).unwrap().contains("1000 1 2 0 -5"));
+    /// assert_eq!(Element::read_all(output)?, Element::read_all("1000 1001 1003 1003 998")?);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-ion-1-1"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    fn write_delta_encoded<I>(self, values: I) -> IonResult<()>
+    where
+        I: IntoIterator,
+        I::Item: Into<Int>,
+    {
+        // Compute every delta before opening the e-expression so that an out-of-range difference
+        // does not leave a partially written invocation in the output.
+        let mut deltas = Vec::new();
+        let mut previous: Option<Int> = None;
+        for value in values {
+            let value: Int = value.into();
+            let delta = match previous {
+                None => value,
+                Some(previous) => match value.data.checked_sub(previous.data) {
+                    Some(delta) => Int::from(delta),
+                    None => {
+                        return IonResult::encoding_error(format!(
+                            "the difference between {previous} and {value} is too large to delta-encode"
+                        ))
+                    }
+                },
+            };
+            deltas.push(delta);
+            previous = Some(value);
+        }
+        let mut eexp_writer = self.eexp_writer(system_macros::DELTA)?;
+        let mut group_writer = eexp_writer.expr_group_writer()?;
+        for delta in &deltas {
+            group_writer.write_int(delta)?;
+        }
+        group_writer.close()?;
+        eexp_writer.close()
+    }
+
+    /// Writes an invocation of the Ion 1.1 system macro `sum`, which expands to `a + b`.
+    ///
+    /// Returns an `Err` if the writer does not support e-expressions (as in Ion 1.0).
+    fn write_sum(self, a: impl Into<Int>, b: impl Into<Int>) -> IonResult<()> {
+        let mut eexp_writer = self.eexp_writer(system_macros::SUM)?;
+        eexp_writer.write_int(&a.into())?.write_int(&b.into())?;
+        eexp_writer.close()
+    }
 }
 
 /// There are several implementations of `ValueWriter` that simply delegate calls to an expression.
@@ -298,11 +409,16 @@ impl<'field, StructWriterType: StructWriter> ValueWriter for FieldWriter<'field,
         let value_writer = self_.struct_writer.make_value_writer();
         IonResult::Ok(value_writer)
     });
+
+    fn inline_sexp_writer(self) -> IonResult<Self::SExpWriter> {
+        self.struct_writer.encode_field_name(self.name)?;
+        self.struct_writer.make_value_writer().inline_sexp_writer()
+    }
 }
 
 pub struct AnnotatedFieldWriter<'field, StructWriterType> {
     name: RawSymbolRef<'field>,
-    annotations: AnnotationsVec<'field>,
+    annotations: AnnotationsBuf<'field>,
     struct_writer: &'field mut StructWriterType,
 }
 
@@ -314,7 +430,7 @@ impl<'field, StructWriterType: StructWriter> AnnotatedFieldWriter<'field, Struct
     ) -> Self {
         Self {
             name,
-            annotations: annotations.into_annotations_vec(),
+            annotations: annotations.into_annotations_buf(),
             struct_writer,
         }
     }
@@ -337,7 +453,7 @@ impl<StructWriterType: StructWriter> AnnotatableWriter
     {
         Ok(AnnotatedFieldWriter {
             name: self.name,
-            annotations: annotations.into_annotations_vec(),
+            annotations: annotations.into_annotations_buf(),
             struct_writer: self.struct_writer,
         })
     }
@@ -360,6 +476,14 @@ impl<'field, StructWriterType: StructWriter> ValueWriter
         let value_writer = self_.struct_writer.make_value_writer().with_annotations(self_.annotations)?;
         IonResult::Ok(value_writer)
     });
+
+    fn inline_sexp_writer(self) -> IonResult<Self::SExpWriter> {
+        self.struct_writer.encode_field_name(self.name)?;
+        self.struct_writer
+            .make_value_writer()
+            .with_annotations(self.annotations)?
+            .inline_sexp_writer()
+    }
 }
 
 pub trait StructWriter: FieldEncoder + MakeValueWriter + Sized {
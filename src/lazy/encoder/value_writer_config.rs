@@ -1,3 +1,5 @@
+use crate::TimestampPrecisionPolicy;
+
 /// Configuration options available to Ion 1.1 value writers.
 ///
 /// The default configuration aligns closely with Ion 1.0's encoding. All symbols, field names, and
@@ -6,12 +8,21 @@
 pub struct ValueWriterConfig {
     // How nested containers should be encoded.
     container_encoding: ContainerEncoding,
+    // Whether decimals, timestamps, and strings may use their compact short-form encodings.
+    scalar_encoding: ScalarEncoding,
+    // Whether e-expressions should be written with the length of their arguments.
+    eexp_encoding: EExpEncoding,
+    // How the fractional seconds of timestamps should be adjusted before they are encoded.
+    timestamp_precision: TimestampPrecisionPolicy,
     // How symbol values should be encoded.
     symbol_value_encoding: SymbolValueEncoding,
     // How annotation sequences should be encoded
     annotations_encoding: AnnotationsEncoding,
     // If this writer emits a struct, the struct will encode its field names according to this setting.
     field_name_encoding: FieldNameEncoding,
+    // Which new symbol text will be added to the symbol table when one of the above settings is
+    // `SymbolIds`.
+    symbol_interning_policy: SymbolInterningPolicy,
 }
 
 /// Configuration options for encoding containers.
@@ -26,6 +37,33 @@ pub enum ContainerEncoding {
     Delimited,
 }
 
+/// Configuration options for encoding decimals, timestamps, and strings, each of which has both a
+/// short form (length or layout in the opcode) and a long form (length as a `FlexUInt`).
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum ScalarEncoding {
+    /// Each value will use its short-form encoding if it is eligible, falling back to the
+    /// long form only when necessary.
+    #[default]
+    ShortFormWhenPossible,
+    /// Each value will use its long-form encoding, even if a short form would fit. This produces
+    /// larger output, but the encoded size of a value no longer depends on whether a short form
+    /// is available, which is useful for generating test vectors or patching values in place.
+    LongForm,
+}
+
+/// Configuration options for encoding e-expressions.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum EExpEncoding {
+    /// Each e-expression will begin with the address of the invoked macro. The reader must
+    /// consult the macro's signature and read each argument to find the end of the invocation.
+    #[default]
+    Unprefixed,
+    /// Each e-expression will be written with the length of its encoded arguments, allowing the
+    /// reader to skip over it without reading its arguments. The writer must buffer the arguments
+    /// until the e-expression is closed. Invocations of system macros are always unprefixed.
+    LengthPrefixed,
+}
+
 // ===== Symbol text encoding policies =====
 //
 // The types below are very similar to one another. They have been kept distinct for two reasons:
@@ -78,14 +116,50 @@ pub enum FieldNameEncoding {
     NewSymbolsAsInlineText,
 }
 
+/// Decides which new symbol text a writer adds to its symbol table.
+///
+/// When a symbol value, field name, or annotation whose encoding is `SymbolIds` has text that is
+/// not yet in the symbol table, the writer adds the text to the table only if the policy accepts
+/// it; otherwise, the text is written inline. Text that is already in the symbol table is written
+/// as a symbol ID regardless of the policy.
+#[derive(Copy, Clone, Debug, Default)]
+pub enum SymbolInterningPolicy {
+    /// Add all new symbol text to the symbol table.
+    #[default]
+    InternAll,
+    /// Do not add any new symbol text to the symbol table.
+    InternNone,
+    /// Add new symbol text to the symbol table only if it is longer than the specified number
+    /// of bytes. Shorter text is nearly as compact inline as its symbol ID would be.
+    InternAboveLength(usize),
+    /// Add new symbol text to the symbol table only if the provided function returns `true`.
+    Custom(fn(&str) -> bool),
+}
+
+impl SymbolInterningPolicy {
+    /// Returns `true` if `text` should be added to the symbol table.
+    pub fn should_intern(&self, text: &str) -> bool {
+        match self {
+            SymbolInterningPolicy::InternAll => true,
+            SymbolInterningPolicy::InternNone => false,
+            SymbolInterningPolicy::InternAboveLength(length) => text.len() > *length,
+            SymbolInterningPolicy::Custom(predicate) => predicate(text),
+        }
+    }
+}
+
 impl ValueWriterConfig {
     /// Constructs a `ValueWriterConfig` that writes all symbol tokens as inline text.
     pub const fn text() -> Self {
         ValueWriterConfig {
             container_encoding: ContainerEncoding::Delimited,
+            scalar_encoding: ScalarEncoding::ShortFormWhenPossible,
+            eexp_encoding: EExpEncoding::Unprefixed,
+            timestamp_precision: TimestampPrecisionPolicy::Preserve,
             symbol_value_encoding: SymbolValueEncoding::InlineText,
             annotations_encoding: AnnotationsEncoding::InlineText,
             field_name_encoding: FieldNameEncoding::InlineText,
+            symbol_interning_policy: SymbolInterningPolicy::InternAll,
         }
     }
 
@@ -93,9 +167,13 @@ impl ValueWriterConfig {
     pub const fn binary() -> Self {
         ValueWriterConfig {
             container_encoding: ContainerEncoding::LengthPrefixed,
+            scalar_encoding: ScalarEncoding::ShortFormWhenPossible,
+            eexp_encoding: EExpEncoding::Unprefixed,
+            timestamp_precision: TimestampPrecisionPolicy::Preserve,
             symbol_value_encoding: SymbolValueEncoding::SymbolIds,
             annotations_encoding: AnnotationsEncoding::SymbolIds,
             field_name_encoding: FieldNameEncoding::SymbolIds,
+            symbol_interning_policy: SymbolInterningPolicy::InternAll,
         }
     }
 
@@ -103,6 +181,18 @@ impl ValueWriterConfig {
         self.container_encoding
     }
 
+    pub const fn scalar_encoding(&self) -> ScalarEncoding {
+        self.scalar_encoding
+    }
+
+    pub const fn eexp_encoding(&self) -> EExpEncoding {
+        self.eexp_encoding
+    }
+
+    pub const fn timestamp_precision(&self) -> TimestampPrecisionPolicy {
+        self.timestamp_precision
+    }
+
     pub const fn symbol_value_encoding(&self) -> SymbolValueEncoding {
         self.symbol_value_encoding
     }
@@ -115,6 +205,10 @@ impl ValueWriterConfig {
         self.annotations_encoding
     }
 
+    pub const fn symbol_interning_policy(&self) -> SymbolInterningPolicy {
+        self.symbol_interning_policy
+    }
+
     /// Returns `true` if this value writer will write nested containers with a delimited encoding.
     pub const fn has_delimited_containers(&self) -> bool {
         matches!(self.container_encoding, ContainerEncoding::Delimited)
@@ -134,6 +228,36 @@ impl ValueWriterConfig {
         self
     }
 
+    /// Returns `true` if this value writer will write decimals, timestamps, and strings using
+    /// their long-form encodings.
+    pub const fn has_long_form_scalars(&self) -> bool {
+        matches!(self.scalar_encoding, ScalarEncoding::LongForm)
+    }
+
+    /// Configures whether this value writer will write decimals, timestamps, and strings using
+    /// their short-form encodings when possible or always use their long-form encodings.
+    pub const fn with_scalar_encoding(mut self, scalar_encoding: ScalarEncoding) -> Self {
+        self.scalar_encoding = scalar_encoding;
+        self
+    }
+
+    /// Configures whether this value writer will write e-expressions with the length of their
+    /// arguments.
+    pub const fn with_eexp_encoding(mut self, eexp_encoding: EExpEncoding) -> Self {
+        self.eexp_encoding = eexp_encoding;
+        self
+    }
+
+    /// Configures how this value writer will adjust the fractional seconds of timestamps before
+    /// encoding them.
+    pub const fn with_timestamp_precision(
+        mut self,
+        timestamp_precision: TimestampPrecisionPolicy,
+    ) -> Self {
+        self.timestamp_precision = timestamp_precision;
+        self
+    }
+
     /// Configures this value writer to write symbol values and annotations with their UTF-8 text
     /// inline.
     pub const fn with_symbol_value_encoding(
@@ -162,4 +286,14 @@ impl ValueWriterConfig {
         self.field_name_encoding = field_name_encoding;
         self
     }
+
+    /// Configures which new symbol text this value writer will add to the symbol table when it
+    /// encodes symbol values, field names, or annotations as symbol IDs.
+    pub const fn with_symbol_interning_policy(
+        mut self,
+        symbol_interning_policy: SymbolInterningPolicy,
+    ) -> Self {
+        self.symbol_interning_policy = symbol_interning_policy;
+        self
+    }
 }
@@ -6,7 +6,8 @@ use std::sync::Arc;
 
 use crate::constants::v1_0::system_symbol_ids;
 use crate::constants::v1_1;
-use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsVec};
+use crate::lazy::cancellation::CancellationToken;
+use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsBuf};
 use crate::lazy::encoder::binary::v1_1::value_writer::BinaryValueWriter_1_1;
 use crate::lazy::encoder::value_writer::internal::{
     EExpWriterInternal, FieldEncoder, MakeValueWriter,
@@ -15,23 +16,28 @@ use crate::lazy::encoder::value_writer::{
     AnnotatableWriter, EExpWriter, FieldWriter, SequenceWriter, StructWriter, ValueWriter,
 };
 use crate::lazy::encoder::value_writer_config::{
-    AnnotationsEncoding, ContainerEncoding, FieldNameEncoding, SymbolValueEncoding,
-    ValueWriterConfig,
+    AnnotationsEncoding, ContainerEncoding, EExpEncoding, FieldNameEncoding, ScalarEncoding,
+    SymbolInterningPolicy, SymbolValueEncoding, ValueWriterConfig,
 };
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoder::LazyRawWriter;
 use crate::lazy::encoding::{
     BinaryEncoding_1_0, BinaryEncoding_1_1, Encoding, TextEncoding_1_0, TextEncoding_1_1,
 };
-use crate::lazy::expanded::macro_table::{Macro, MacroRef, ION_1_1_SYSTEM_MACROS};
-use crate::lazy::expanded::template::{Parameter, ParameterEncoding};
-use crate::lazy::text::raw::v1_1::reader::{MacroIdLike, MacroIdRef, ModuleKind, QualifiedAddress};
+use crate::lazy::expanded::macro_table::{
+    FormattedTemplateMacro, Macro, MacroRef, ION_1_1_SYSTEM_MACROS,
+};
+use crate::lazy::expanded::template::Parameter;
+use crate::lazy::progress::{Progress, ProgressTracker};
+use crate::lazy::text::raw::v1_1::reader::{
+    system_macros, MacroIdLike, MacroIdRef, ModuleKind, QualifiedAddress,
+};
 use crate::raw_symbol_ref::AsRawSymbolRef;
 use crate::result::IonFailure;
-use crate::write_config::WriteConfig;
+use crate::write_config::{SymbolTableLimit, WriteConfig};
 use crate::{
     ContextWriter, Decimal, Element, ElementWriter, Int, IonError, IonInput, IonResult, IonType,
-    IonVersion, MacroDef, MacroTable, RawSymbolRef, Symbol, SymbolId, SymbolTable, TemplateMacro,
+    IonVersion, MacroDef, MacroFormat, MacroTable, RawSymbolRef, SharedSymbolTable, Symbol, SymbolId, SymbolTable, TemplateMacro,
     Timestamp, UInt, Value,
 };
 
@@ -40,6 +46,8 @@ use crate::{
 pub(crate) struct WriterSymbolTable {
     symbols: SymbolTable,
     num_pending: usize,
+    // The number of symbols the table started with, which never need to be written.
+    num_initial: usize,
 }
 
 impl WriterSymbolTable {
@@ -47,6 +55,26 @@ impl WriterSymbolTable {
         self.num_pending = 0;
     }
 
+    /// Marks every symbol that has been added to the table as pending. This is used to write the
+    /// symbols' definitions again after an IVM.
+    pub fn mark_all_pending(&mut self) {
+        self.num_pending = self.symbols.len() - self.num_initial;
+    }
+
+    /// Removes the symbols at or above `new_len` and marks the rest as pending. This is used to
+    /// start a smaller symbol table after an IVM.
+    pub fn reset_to(&mut self, new_len: usize) {
+        self.symbols.truncate(new_len);
+        self.mark_all_pending();
+    }
+
+    /// Removes the symbols at or above `new_len`, none of which may have been written yet.
+    pub fn truncate(&mut self, new_len: usize) {
+        let num_removed = self.symbols.len().saturating_sub(new_len);
+        self.symbols.truncate(new_len);
+        self.num_pending -= num_removed;
+    }
+
     pub fn num_pending(&self) -> usize {
         self.num_pending
     }
@@ -62,6 +90,7 @@ impl WriterSymbolTable {
 
     pub fn new(symbols: SymbolTable) -> Self {
         Self {
+            num_initial: symbols.len(),
             symbols,
             num_pending: 0,
         }
@@ -82,11 +111,14 @@ impl Deref for WriterSymbolTable {
 pub struct WriterMacroTable {
     macros: MacroTable,
     num_pending: usize,
+    // The number of macros the table started with, which never need to be written.
+    num_initial: usize,
 }
 
 impl WriterMacroTable {
     pub fn new(macros: MacroTable) -> Self {
         Self {
+            num_initial: macros.len(),
             macros,
             num_pending: 0,
         }
@@ -109,6 +141,12 @@ impl WriterMacroTable {
         self.num_pending = 0;
     }
 
+    /// Marks every macro that has been added to the table as pending. This is used to write the
+    /// macros' definitions again after an IVM.
+    pub fn mark_all_pending(&mut self) {
+        self.num_pending = self.macros.len() - self.num_initial;
+    }
+
     pub fn num_pending(&self) -> usize {
         self.num_pending
     }
@@ -135,6 +173,33 @@ pub(crate) struct Writer<E: Encoding, Output: Write> {
     directive_writer: E::Writer<Vec<u8>>,
     output: Output,
     value_writer_config: ValueWriterConfig,
+    // The layout used when writing macro definitions in text encoding directives.
+    macro_format: MacroFormat,
+    // The number of bytes that have been flushed to `output`.
+    num_bytes_written: u64,
+    progress: Option<ProgressTracker>,
+    cancellation_token: Option<CancellationToken>,
+    // If set, the largest number of bytes that each self-contained segment of output may contain.
+    max_segment_size: Option<usize>,
+    // If set, the largest number of symbols the symbol table may contain before it is replaced.
+    symbol_table_limit: Option<SymbolTableLimit>,
+    // The counts of symbols and macros (total and pending) for which the encoded size of the
+    // pending directives was last computed, along with that size.
+    directives_len_cache: Option<([usize; 4], usize)>,
+    // Shared symbol tables whose symbols begin the writer's symbol table, in the order they were
+    // imported.
+    shared_symbol_tables: Vec<SharedSymbolTable>,
+    // Whether the next symbol table directive must import `shared_symbol_tables`. This is true
+    // at the start of the stream and after each IVM.
+    imports_pending: bool,
+}
+
+/// The state of a writer before a top-level value is written, which allows the value to be removed
+/// if it does not fit in the current segment or symbol table.
+#[derive(Copy, Clone)]
+struct SegmentCheckpoint {
+    data_len: usize,
+    num_symbols: usize,
 }
 
 // These aliases are used for selectively re-exporting writer types in lib.rs.
@@ -152,21 +217,44 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
     /// Constructs a writer for the requested encoding using the provided configuration.
     pub fn new(config: impl Into<WriteConfig<E>>, output: Output) -> IonResult<Self> {
         let config = config.into();
+        let macro_format = config.macro_format();
+        let cancellation_token = config.cancellation_token().cloned();
+        let max_segment_size = config.max_segment_size();
+        let symbol_table_limit = config.symbol_table_limit();
+        let value_writer_config = config.value_writer_config();
+        let shared_symbol_tables = config.shared_symbol_tables().to_vec();
         let directive_writer = E::Writer::build(config.clone(), vec![])?;
         let mut data_writer = E::Writer::build(config, vec![])?;
         // Erase the IVM that's created by default
         data_writer.output_mut().clear();
         // TODO: LazyEncoder should define a method to construct a new symtab and/or macro table
         let ion_version = E::ion_version();
-        let symbols = WriterSymbolTable::new(SymbolTable::new(ion_version));
+        let mut symbol_table = SymbolTable::new(ion_version);
+        for symbol in shared_symbol_tables.iter().flat_map(|t| t.symbols()) {
+            symbol_table.add_symbol(symbol.clone());
+        }
+        let symbols = WriterSymbolTable::new(symbol_table);
+        let imports_pending = !shared_symbol_tables.is_empty();
         let mut writer = Writer {
             symbols,
             data_writer,
             directive_writer,
             output,
-            value_writer_config: E::default_value_writer_config(),
+            value_writer_config,
+            macro_format,
+            num_bytes_written: 0,
+            progress: None,
+            cancellation_token,
+            max_segment_size,
+            symbol_table_limit,
+            directives_len_cache: None,
+            shared_symbol_tables,
+            imports_pending,
         };
-        writer.flush()?;
+        // A segmented writer writes the IVM as part of its first segment.
+        if writer.max_segment_size.is_none() {
+            writer.flush()?;
+        }
         Ok(writer)
     }
 
@@ -180,11 +268,37 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
 
     /// Takes a TDL expression representing a macro definition and returns a `Macro` that can
     /// later be invoked by passing it to [`Writer::eexp_writer()`].
+    ///
+    /// The new macro is appended to the default module's macro table. Before any more values are
+    /// written to the output, the writer emits an `add_macros` directive that defines it for
+    /// readers of the stream.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-ion-1-1")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_1, Element, ElementReader, Reader, SequenceWriter, Writer};
+    ///
+    /// let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+    /// let point = writer.compile_macro("(macro point (x y) {x: (%x), y: (%y)})")?;
+    /// let mut eexp = writer.eexp_writer(&point)?;
+    /// eexp.write(1)?.write(2)?;
+    /// eexp.close()?;
+    /// let output = writer.close()?;
+    ///
+    /// let mut reader = Reader::new(v1_1::Binary, output)?;
+    /// assert_eq!(reader.read_one_element()?, Element::read_one("{x: 1, y: 2}")?);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-ion-1-1"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
     pub fn compile_macro(&mut self, source: impl IonInput) -> IonResult<Macro> {
         self.data_writer.compile_macro(source)
     }
 
-    /// Register a previously compiled `Macro` for use in this `Writer`.
+    /// Register a previously compiled `Macro` for use in this `Writer`. Like macros defined using
+    /// [`Writer::compile_macro()`], it is appended to the default module's macro table.
     pub fn register_macro(&mut self, macro_: &Macro) -> IonResult<Macro> {
         self.data_writer.register_macro(macro_.definition())
     }
@@ -209,11 +323,18 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
             MacroIdRef::SystemAddress(address) => {
                 QualifiedAddress::new(ModuleKind::System, address.as_usize())
             }
+            MacroIdRef::QualifiedName(..) | MacroIdRef::QualifiedAddress(..) => {
+                let macro_ref = id.resolve(macro_table)?;
+                QualifiedAddress::new(macro_ref.module(), macro_ref.address())
+            }
         };
 
         let macro_table: &MacroTable = match qualified_address.module() {
             ModuleKind::Default => self.macro_table(),
             ModuleKind::System => &ION_1_1_SYSTEM_MACROS,
+            ModuleKind::Named => {
+                unreachable!("a writer's macro table cannot resolve macros in named modules")
+            }
         };
 
         let macro_def = macro_table
@@ -240,35 +361,123 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
         <Self as SequenceWriter>::write(self, value)
     }
 
+    /// Writes a top-level value that has already been encoded in this writer's format. See
+    /// [`LazyRawWriter::write_encoded_value`].
+    pub(crate) fn write_encoded_value(&mut self, encoded: &[u8]) -> IonResult<&mut Self> {
+        self.write_top_level(|writer| {
+            writer.data_writer.write_encoded_value(encoded)?;
+            if let Some(progress) = &mut writer.progress {
+                progress.record_value(writer.num_bytes_written);
+            }
+            Ok(())
+        })
+    }
+
+    /// Registers a callback that will be passed the writer's [`Progress`] each time another
+    /// `every_n_values` top-level values are written, and again after each call to
+    /// [`flush`](Self::flush) that writes more data. Replaces any callback that was registered
+    /// previously.
+    ///
+    /// The reported byte count only includes data that has been flushed to the output.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    /// use ion_rs::{v1_0, Progress, SequenceWriter, Writer};
+    ///
+    /// let latest = Rc::new(Cell::new(Progress::default()));
+    /// let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+    /// let sink = Rc::clone(&latest);
+    /// writer.set_progress_callback(100, move |progress| sink.set(progress));
+    /// writer.write_all([1, 2, 3])?;
+    /// writer.flush()?;
+    /// assert_eq!(latest.get().num_values(), 3);
+    /// assert_eq!(latest.get().num_bytes() as usize, writer.output().len());
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn set_progress_callback(
+        &mut self,
+        every_n_values: usize,
+        callback: impl FnMut(Progress) + 'static,
+    ) {
+        self.progress = Some(ProgressTracker::new(every_n_values, callback));
+    }
+
+    /// Sets the token that the writer will check before writing each top-level value and before
+    /// each flush, replacing any token from the writer's configuration. Once the token has been
+    /// cancelled, those operations return [`IonError::Cancelled`]. See [`CancellationToken`] for
+    /// details.
+    pub fn set_cancellation_token(&mut self, token: CancellationToken) {
+        self.cancellation_token = Some(token);
+    }
+
+    fn check_cancellation(&self) -> IonResult<()> {
+        match &self.cancellation_token {
+            Some(token) => token.check(),
+            None => Ok(()),
+        }
+    }
+
     /// Writes bytes of previously encoded values to the output stream.
+    ///
+    /// If the writer has a maximum segment size (see
+    /// [`WriteConfig::with_max_segment_size`]), this finishes the current segment.
     pub fn flush(&mut self) -> IonResult<()> {
-        if self.symbols.num_pending() > 0 {
+        self.check_cancellation()?;
+        if self.max_segment_size.is_some() {
+            return self.finish_segment();
+        }
+        self.write_buffered_data()
+    }
+
+    /// Writes the directives for any pending symbols and macros to the directive writer. Their
+    /// pending counts are not reset.
+    fn write_pending_directives(&mut self) -> IonResult<()> {
+        if self.symbols.num_pending() > 0 || self.imports_pending {
             match E::ion_version() {
                 IonVersion::v1_0 => self.write_lst_append()?,
                 IonVersion::v1_1 => self.write_append_symbols_directive()?,
             }
-            self.symbols.reset_num_pending();
         }
 
         // TODO: In Ion 1.1, new symbols and new macros could be added using the same directive.
         if self.macro_table().num_pending() > 0 {
             self.write_append_macros_directive()?;
-            self.macro_table_mut()
-                .expect("the pending macro count is >0")
-                .reset_num_pending();
+        }
+        Ok(())
+    }
+
+    /// Writes the pending directives and all buffered values to the output.
+    fn write_buffered_data(&mut self) -> IonResult<()> {
+        self.write_pending_directives()?;
+        self.symbols.reset_num_pending();
+        self.imports_pending = false;
+        if let Some(macros) = self.macro_table_mut() {
+            macros.reset_num_pending();
         }
 
         self.directive_writer.flush()?;
         self.output
             .write_all(self.directive_writer.output().as_slice())?;
+        self.num_bytes_written += self.directive_writer.output().len() as u64;
         self.directive_writer.output_mut().clear();
 
         self.data_writer.flush()?;
         self.output
             .write_all(self.data_writer.output().as_slice())?;
+        self.num_bytes_written += self.data_writer.output().len() as u64;
         self.data_writer.output_mut().clear();
 
         self.output.flush()?;
+        if let Some(progress) = &mut self.progress {
+            progress.report(self.num_bytes_written);
+        }
         Ok(())
     }
 
@@ -277,6 +486,181 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
         Ok(self.output)
     }
 
+    /// Returns `true` if the writer has a maximum segment size or symbol table size.
+    fn has_limits(&self) -> bool {
+        self.max_segment_size.is_some() || self.symbol_table_limit.is_some()
+    }
+
+    /// Checks for cancellation and then writes a top-level value using `write_value`. See
+    /// [`write_within_limits`](Self::write_within_limits).
+    fn write_top_level(
+        &mut self,
+        write_value: impl FnMut(&mut Self) -> IonResult<()>,
+    ) -> IonResult<&mut Self> {
+        self.check_cancellation()?;
+        self.write_within_limits(write_value)
+    }
+
+    /// Writes a top-level value using `write_value`. If the value does not fit in the current
+    /// segment or would grow the symbol table past its maximum size, the writer begins a new
+    /// segment (rotating the symbol table if necessary) and writes the value again there.
+    ///
+    /// Because the value may be written more than once, it must be written using a value writer
+    /// from [`top_level_value_writer`](Self::top_level_value_writer). Value writers handed out by
+    /// [`value_writer`](SequenceWriter::value_writer) cannot be checked, so a writer with limits
+    /// refuses to write anything with them.
+    fn write_within_limits(
+        &mut self,
+        mut write_value: impl FnMut(&mut Self) -> IonResult<()>,
+    ) -> IonResult<&mut Self> {
+        if !self.has_limits() {
+            write_value(self)?;
+            return Ok(self);
+        }
+        let mut checkpoint = self.segment_checkpoint()?;
+        write_value(self)?;
+        // Starting over only helps if it would remove some of the data or symbols that came
+        // before the value.
+        let rotate_symbols = self.symbol_table_overflow().is_some()
+            && self.num_symbols_after_rotation() < checkpoint.num_symbols;
+        let new_segment = self.segment_overflow()?.is_some() && checkpoint.data_len > 0;
+        if rotate_symbols || new_segment {
+            self.roll_back(checkpoint)?;
+            self.start_new_segment(rotate_symbols)?;
+            checkpoint = self.segment_checkpoint()?;
+            write_value(self)?;
+        }
+        let overflow = match self.symbol_table_overflow() {
+            Some(message) => Some(message),
+            None => self.segment_overflow()?,
+        };
+        if let Some(message) = overflow {
+            self.roll_back(checkpoint)?;
+            return IonResult::encoding_error(message);
+        }
+        Ok(self)
+    }
+
+    /// If the writer has a maximum symbol table size and the symbol table has grown past it,
+    /// returns a description of the problem.
+    fn symbol_table_overflow(&self) -> Option<String> {
+        let max_symbols = self.symbol_table_limit?.max_symbols;
+        let num_symbols = self.symbols.len() - self.symbols.num_initial;
+        (num_symbols > max_symbols).then(|| {
+            format!(
+                "writing this value would add {num_symbols} symbols to the symbol table, which \
+                 exceeds the maximum symbol table size ({max_symbols} symbols)"
+            )
+        })
+    }
+
+    /// If the writer has a maximum segment size and the current segment has grown past it,
+    /// returns a description of the problem.
+    fn segment_overflow(&mut self) -> IonResult<Option<String>> {
+        let Some(max_segment_size) = self.max_segment_size else {
+            return Ok(None);
+        };
+        let segment_size = self.segment_size()?;
+        Ok((segment_size > max_segment_size).then(|| {
+            format!(
+                "a segment containing this value would be {segment_size} bytes, which exceeds \
+                 the maximum segment size ({max_segment_size} bytes)"
+            )
+        }))
+    }
+
+    /// Returns the number of symbols (including system symbols) that the symbol table will
+    /// contain after it is rotated.
+    fn num_symbols_after_rotation(&self) -> usize {
+        match self.symbol_table_limit {
+            Some(limit) => self.symbols.num_initial + limit.num_retained(),
+            None => self.symbols.len(),
+        }
+    }
+
+    fn segment_checkpoint(&mut self) -> IonResult<SegmentCheckpoint> {
+        self.data_writer.flush()?;
+        Ok(SegmentCheckpoint {
+            data_len: self.data_writer.output().len(),
+            num_symbols: self.symbols.len(),
+        })
+    }
+
+    /// Removes everything that was written after `checkpoint` was taken.
+    fn roll_back(&mut self, checkpoint: SegmentCheckpoint) -> IonResult<()> {
+        self.data_writer.flush()?;
+        self.data_writer.output_mut().truncate(checkpoint.data_len);
+        self.symbols.truncate(checkpoint.num_symbols);
+        self.directives_len_cache = None;
+        if let Some(progress) = &mut self.progress {
+            progress.discard_value();
+        }
+        Ok(())
+    }
+
+    /// Returns the number of bytes the current segment would occupy if it were finished now.
+    fn segment_size(&mut self) -> IonResult<usize> {
+        self.data_writer.flush()?;
+        let directives_len = self.pending_directives_len()?;
+        // Until the segment is finished, the directive writer's output only contains its IVM.
+        Ok(self.directive_writer.output().len() + directives_len + self.data_writer.output().len())
+    }
+
+    /// Returns the number of bytes needed to encode the directives for the pending symbols and
+    /// macros.
+    fn pending_directives_len(&mut self) -> IonResult<usize> {
+        let macros = self.macro_table();
+        let key = [
+            self.symbols.len(),
+            self.symbols.num_pending(),
+            macros.len(),
+            macros.num_pending(),
+        ];
+        match self.directives_len_cache {
+            Some((cached_key, len)) if cached_key == key => return Ok(len),
+            _ => {}
+        }
+        // Encode the directives and then discard them.
+        let header_len = self.directive_writer.output().len();
+        self.write_pending_directives()?;
+        self.directive_writer.flush()?;
+        let len = self.directive_writer.output().len() - header_len;
+        self.directive_writer.output_mut().truncate(header_len);
+        self.directives_len_cache = Some((key, len));
+        Ok(len)
+    }
+
+    /// Writes the current segment to the output and begins a new one, unless the current segment
+    /// is empty.
+    fn finish_segment(&mut self) -> IonResult<()> {
+        self.data_writer.flush()?;
+        if self.data_writer.output().is_empty() {
+            return Ok(());
+        }
+        self.start_new_segment(false)
+    }
+
+    /// Writes the current segment to the output and begins a new one. Each segment after the
+    /// first starts with an IVM and defines all of the writer's symbols and macros again, so it can
+    /// be read on its own. If `rotate_symbols` is true, the new segment's symbol table only
+    /// contains the symbols that the writer's rotation policy keeps.
+    fn start_new_segment(&mut self, rotate_symbols: bool) -> IonResult<()> {
+        self.write_buffered_data()?;
+        self.directive_writer.write_version_marker()?;
+        self.imports_pending = !self.shared_symbol_tables.is_empty();
+        if rotate_symbols {
+            let num_symbols = self.num_symbols_after_rotation();
+            self.symbols.reset_to(num_symbols);
+        } else {
+            self.symbols.mark_all_pending();
+        }
+        if let Some(macros) = self.macro_table_mut() {
+            macros.mark_all_pending();
+        }
+        self.directives_len_cache = None;
+        Ok(())
+    }
+
     #[cfg(feature = "experimental-reader-writer")]
     #[inline]
     pub fn symbol_table(&self) -> &SymbolTable {
@@ -289,11 +673,15 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
         &self.symbols
     }
 
-    /// Helper method to encode an LST append containing pending symbols.
+    /// Helper method to encode an LST containing pending symbols. If the shared symbol table
+    /// imports have not been written since the last IVM, the LST imports them; otherwise, it
+    /// appends to the current symbol table.
     fn write_lst_append(&mut self) -> IonResult<()> {
         let Self {
             symbols,
             directive_writer,
+            shared_symbol_tables,
+            imports_pending,
             ..
         } = self;
 
@@ -302,8 +690,21 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
             .with_annotations(system_symbol_ids::ION_SYMBOL_TABLE)?
             .struct_writer()?;
 
-        lst.field_writer(system_symbol_ids::IMPORTS)
-            .write_symbol(system_symbol_ids::ION_SYMBOL_TABLE)?;
+        if *imports_pending {
+            let mut imports = lst.field_writer(system_symbol_ids::IMPORTS).list_writer()?;
+            for table in shared_symbol_tables.iter() {
+                let mut import = imports.struct_writer()?;
+                import
+                    .write(system_symbol_ids::NAME, table.name())?
+                    .write(system_symbol_ids::VERSION, table.version() as i64)?
+                    .write(system_symbol_ids::MAX_ID, table.symbols().len() as i64)?;
+                import.close()?;
+            }
+            imports.close()?;
+        } else {
+            lst.field_writer(system_symbol_ids::IMPORTS)
+                .write_symbol(system_symbol_ids::ION_SYMBOL_TABLE)?;
+        }
 
         let mut new_symbol_list = lst.field_writer(system_symbol_ids::SYMBOLS).list_writer()?;
 
@@ -315,71 +716,91 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
         lst.close()
     }
 
+    /// Helper method to encode an `add_macros` invocation containing the pending macros. The
+    /// directive appends the pending macros to the default module's macro table, leaving its
+    /// symbols and any macros that were already defined in place.
     fn write_append_macros_directive(&mut self) -> IonResult<()> {
         let Self {
             data_writer,
             directive_writer,
+            macro_format,
             ..
         } = self;
 
         let macros = data_writer.macro_table();
 
-        // TODO: Once expression group serialization is complete, this can be replaced by a call
-        //       to the `add_macros` system macro.
-        let mut directive = directive_writer
-            .value_writer()
-            .with_annotations("$ion")?
-            .sexp_writer()?;
-
-        directive
-            .write_symbol(v1_1::system_symbols::MODULE)?
-            .write_symbol(v1_1::constants::DEFAULT_MODULE_NAME)?;
-
-        let mut symbols_sexp = directive.sexp_writer()?;
-        symbols_sexp
-            .write_symbol(v1_1::system_symbols::SYMBOL_TABLE)?
-            .write_symbol(v1_1::constants::DEFAULT_MODULE_NAME)?;
-        symbols_sexp.close()?;
-
         let pending_macros = macros
             .pending()
             .iter()
             // Only user-defined template macros can be added to the macro table.
-            .map(|m| m.require_template());
-
-        let mut macro_table = directive.sexp_writer()?;
-        macro_table
-            .write_symbol(v1_1::system_symbols::MACRO_TABLE)?
-            .write_symbol(v1_1::constants::DEFAULT_MODULE_NAME)?
-            .write_all(pending_macros)?;
-        macro_table.close()?;
-        directive.close()
+            .map(|m| FormattedTemplateMacro::new(m.require_template(), *macro_format));
+
+        let mut add_macros = directive_writer
+            .eexp_writer(MacroIdRef::SystemAddress(system_macros::ADD_MACROS))?;
+        let mut macro_definitions = add_macros.expr_group_writer()?;
+        macro_definitions.write_all(pending_macros)?;
+        macro_definitions.close()?;
+        add_macros.close()
     }
 
-    /// Helper method to encode an LST append containing pending symbols.
+    /// Helper method to encode an LST append containing pending symbols. If the shared symbol
+    /// table imports have not been written since the last IVM, the directive imports each table
+    /// as a module and starts the symbol table with their symbols instead of appending.
     fn write_append_symbols_directive(&mut self) -> IonResult<()> {
         let Self {
             symbols,
             directive_writer,
+            shared_symbol_tables,
+            imports_pending,
             ..
         } = self;
 
+        // The text writer cannot encode Ion 1.1 system symbols as annotations, so it writes `$ion`
+        // as text.
+        let annotation: RawSymbolRef<'_> = if E::is_binary() {
+            RawSymbolRef::SystemSymbol_1_1(v1_1::system_symbols::ION)
+        } else {
+            "$ion".into()
+        };
         let mut directive = directive_writer
             .value_writer()
-            .with_annotations(v1_1::system_symbols::ION)?
+            .with_annotations(annotation)?
             .sexp_writer()?;
 
         directive
             .write_symbol(v1_1::system_symbols::MODULE)?
             .write_symbol(v1_1::constants::DEFAULT_MODULE_NAME)?;
 
+        // Each imported table is bound to a module name that is only visible in this directive.
+        let import_names: Vec<String> = (0..shared_symbol_tables.len())
+            .map(|index| format!("import_{index}"))
+            .collect();
+        if *imports_pending {
+            for (table, name) in shared_symbol_tables.iter().zip(&import_names) {
+                let mut import = directive.sexp_writer()?;
+                import
+                    .write_symbol("import")?
+                    .write_symbol(name.as_str())?
+                    .write_string(table.name())?
+                    .write_i64(table.version() as i64)?;
+                import.close()?;
+            }
+        }
+
         let pending_symbols = symbols.pending().iter().map(Symbol::text);
 
+        // The imported symbols follow the current symbol table, which only contains the system
+        // symbols when the imports are pending.
         let mut symbol_table = directive.sexp_writer()?;
         symbol_table
             .write_symbol(v1_1::system_symbols::SYMBOL_TABLE)?
-            .write_symbol(v1_1::constants::DEFAULT_MODULE_NAME)?
-            .write_list(pending_symbols)?;
+            .write_symbol(v1_1::constants::DEFAULT_MODULE_NAME)?;
+        if *imports_pending {
+            for name in &import_names {
+                symbol_table.write_symbol(name.as_str())?;
+            }
+        }
+        symbol_table.write_list(pending_symbols)?;
         symbol_table.close()?;
         directive.close()
     }
@@ -392,8 +813,15 @@ impl<E: Encoding, Output: Write> ContextWriter for Writer<E, Output> {
         Self: 'a;
 }
 
-impl<E: Encoding, Output: Write> MakeValueWriter for Writer<E, Output> {
-    fn make_value_writer(&mut self) -> Self::NestedValueWriter<'_> {
+impl<E: Encoding, Output: Write> Writer<E, Output> {
+    /// Returns a value writer for a top-level value. Unlike the one returned by
+    /// [`make_value_writer`](MakeValueWriter::make_value_writer), it can be used when the writer
+    /// has limits; callers are responsible for checking them using
+    /// [`write_within_limits`](Self::write_within_limits).
+    fn top_level_value_writer(&mut self) -> <Self as ContextWriter>::NestedValueWriter<'_> {
+        if let Some(progress) = &mut self.progress {
+            progress.record_value(self.num_bytes_written);
+        }
         let raw_value_writer = self.data_writer.make_value_writer();
         let symbols = &mut self.symbols;
 
@@ -401,23 +829,258 @@ impl<E: Encoding, Output: Write> MakeValueWriter for Writer<E, Output> {
             raw_value_writer,
             symbols,
             value_writer_config: self.value_writer_config,
+            limits_unchecked: false,
         }
     }
 }
 
+impl<E: Encoding, Output: Write> MakeValueWriter for Writer<E, Output> {
+    fn make_value_writer(&mut self) -> Self::NestedValueWriter<'_> {
+        let limits_unchecked = self.has_limits();
+        let mut value_writer = self.top_level_value_writer();
+        value_writer.limits_unchecked = limits_unchecked;
+        value_writer
+    }
+}
+
 impl<E: Encoding, Output: Write> SequenceWriter for Writer<E, Output> {
     type Resources = Output;
 
+    fn write<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self> {
+        self.write_top_level(|writer| value.write_as_ion(writer.top_level_value_writer()))
+    }
+
+    // The scalar helpers are overridden so that, like `write`, they check the values they write
+    // against the writer's limits.
+
+    fn write_null(&mut self, value: IonType) -> IonResult<&mut Self> {
+        self.write_top_level(|writer| writer.top_level_value_writer().write_null(value))
+    }
+
+    fn write_bool(&mut self, value: bool) -> IonResult<&mut Self> {
+        self.write_top_level(|writer| writer.top_level_value_writer().write_bool(value))
+    }
+
+    fn write_i64(&mut self, value: i64) -> IonResult<&mut Self> {
+        self.write_top_level(|writer| writer.top_level_value_writer().write_i64(value))
+    }
+
+    fn write_int(&mut self, value: &Int) -> IonResult<&mut Self> {
+        self.write_top_level(|writer| writer.top_level_value_writer().write_int(value))
+    }
+
+    fn write_f32(&mut self, value: f32) -> IonResult<&mut Self> {
+        self.write_top_level(|writer| writer.top_level_value_writer().write_f32(value))
+    }
+
+    fn write_f64(&mut self, value: f64) -> IonResult<&mut Self> {
+        self.write_top_level(|writer| writer.top_level_value_writer().write_f64(value))
+    }
+
+    fn write_decimal(&mut self, value: &Decimal) -> IonResult<&mut Self> {
+        self.write_top_level(|writer| writer.top_level_value_writer().write_decimal(value))
+    }
+
+    fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<&mut Self> {
+        self.write_top_level(|writer| writer.top_level_value_writer().write_timestamp(value))
+    }
+
+    fn write_string(&mut self, value: impl AsRef<str>) -> IonResult<&mut Self> {
+        let value = value.as_ref();
+        self.write_top_level(|writer| writer.top_level_value_writer().write_string(value))
+    }
+
+    fn write_symbol(&mut self, value: impl AsRawSymbolRef) -> IonResult<&mut Self> {
+        let value = value.as_raw_symbol_ref();
+        self.write_top_level(|writer| writer.top_level_value_writer().write_symbol(value))
+    }
+
+    fn write_clob(&mut self, value: impl AsRef<[u8]>) -> IonResult<&mut Self> {
+        let value = value.as_ref();
+        self.write_top_level(|writer| writer.top_level_value_writer().write_clob(value))
+    }
+
+    fn write_blob(&mut self, value: impl AsRef<[u8]>) -> IonResult<&mut Self> {
+        let value = value.as_ref();
+        self.write_top_level(|writer| writer.top_level_value_writer().write_blob(value))
+    }
+
     fn close(mut self) -> IonResult<Self::Resources> {
         self.flush()?;
         Ok(self.output)
     }
 }
 
+/// The number of encoded bytes that a [`StreamingSequenceWriter`] will buffer before writing them
+/// to the output.
+const STREAMING_FLUSH_THRESHOLD: usize = 64 * 1024;
+
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+impl<Output: Write> Writer<BinaryEncoding_1_1, Output> {
+    /// Begins a top-level list whose elements are written to the output as they are encoded
+    /// instead of being buffered until the list is closed. See [`StreamingSequenceWriter`].
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-ion-1-1")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_1, Element, SequenceWriter, Writer};
+    ///
+    /// let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+    /// let mut list = writer.streaming_list_writer()?;
+    /// for i in 0..100_000 {
+    ///     list.write(i)?;
+    /// }
+    /// list.close()?;
+    /// let output = writer.close()?;
+    ///
+    /// let list = Element::read_one(output)?;
+    /// assert_eq!(list.as_sequence().unwrap().len(), 100_000);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-ion-1-1"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn streaming_list_writer(&mut self) -> IonResult<StreamingSequenceWriter<'_, Output>> {
+        StreamingSequenceWriter::new(self, IonType::List)
+    }
+
+    /// Begins a top-level s-expression whose elements are written to the output as they are
+    /// encoded instead of being buffered until the s-expression is closed. See
+    /// [`StreamingSequenceWriter`].
+    pub fn streaming_sexp_writer(&mut self) -> IonResult<StreamingSequenceWriter<'_, Output>> {
+        StreamingSequenceWriter::new(self, IonType::SExp)
+    }
+}
+
+/// Writes a top-level list or s-expression as a delimited Ion 1.1 container, sending its elements
+/// to the output in batches as they are written. Unlike a writer returned by
+/// [`SequenceWriter::list_writer`], which holds the entire container in memory until it is
+/// closed, this bounds the memory used to write sequences of any length.
+///
+/// Any data that the [`Writer`] had buffered is written to the output before the container begins.
+/// Because a symbol table cannot be modified in the middle of a container, symbols whose text is
+/// not already in the writer's symbol table are written as inline text.
+///
+/// Encoded elements are written to the output each time the buffer grows past 64KB, each time
+/// [`flush`](Self::flush) is called, and when the container is closed. The container must be
+/// closed by calling [`close`](SequenceWriter::close) before the `Writer` can be used again;
+/// dropping it without closing it leaves an incomplete container in the output.
+pub struct StreamingSequenceWriter<'a, Output: Write> {
+    writer: &'a mut Writer<BinaryEncoding_1_1, Output>,
+    // The writer's configuration, modified so that new symbols are not added to the symbol table.
+    value_writer_config: ValueWriterConfig,
+}
+
+impl<'a, Output: Write> StreamingSequenceWriter<'a, Output> {
+    fn new(
+        writer: &'a mut Writer<BinaryEncoding_1_1, Output>,
+        ion_type: IonType,
+    ) -> IonResult<Self> {
+        const DELIMITED_LIST_OPCODE: u8 = 0xF1;
+        const DELIMITED_SEXP_OPCODE: u8 = 0xF2;
+        writer.check_cancellation()?;
+        if writer.max_segment_size.is_some() {
+            return IonResult::illegal_operation(
+                "a writer with a maximum segment size cannot write a streaming container",
+            );
+        }
+        // Directives for any pending symbols and macros must precede the container.
+        writer.write_buffered_data()?;
+        let opcode = match ion_type {
+            IonType::List => DELIMITED_LIST_OPCODE,
+            IonType::SExp => DELIMITED_SEXP_OPCODE,
+            _ => unreachable!("streaming containers are always lists or s-expressions"),
+        };
+        writer.data_writer.output_mut().push(opcode);
+        let value_writer_config = writer
+            .value_writer_config
+            .with_symbol_interning_policy(SymbolInterningPolicy::InternNone);
+        Ok(Self {
+            writer,
+            value_writer_config,
+        })
+    }
+
+    /// Writes the elements that have been encoded so far to the output.
+    pub fn flush(&mut self) -> IonResult<()> {
+        let writer = &mut *self.writer;
+        writer.check_cancellation()?;
+        if writer.macro_table().num_pending() > 0 {
+            return IonResult::illegal_operation(
+                "macros cannot be added to the macro table while a streaming container is open",
+            );
+        }
+        writer.data_writer.flush()?;
+        let encoded = writer.data_writer.output_mut();
+        writer.output.write_all(encoded)?;
+        writer.num_bytes_written += encoded.len() as u64;
+        encoded.clear();
+        writer.output.flush()?;
+        Ok(())
+    }
+
+    fn flush_if_full(&mut self) -> IonResult<()> {
+        self.writer.data_writer.flush()?;
+        if self.writer.data_writer.output().len() >= STREAMING_FLUSH_THRESHOLD {
+            self.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl<Output: Write> ContextWriter for StreamingSequenceWriter<'_, Output> {
+    type NestedValueWriter<'a>
+        = ApplicationValueWriter<'a, BinaryValueWriter_1_1<'a, 'a>>
+    where
+        Self: 'a;
+}
+
+impl<Output: Write> MakeValueWriter for StreamingSequenceWriter<'_, Output> {
+    fn make_value_writer(&mut self) -> Self::NestedValueWriter<'_> {
+        let writer = &mut *self.writer;
+        ApplicationValueWriter::new(
+            &mut writer.symbols,
+            self.value_writer_config,
+            writer.data_writer.make_value_writer(),
+        )
+    }
+}
+
+impl<Output: Write> SequenceWriter for StreamingSequenceWriter<'_, Output> {
+    type Resources = ();
+
+    fn write<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self> {
+        self.writer.check_cancellation()?;
+        value.write_as_ion(self.make_value_writer())?;
+        self.flush_if_full()?;
+        Ok(self)
+    }
+
+    fn close(mut self) -> IonResult<Self::Resources> {
+        const DELIMITED_END_OPCODE: u8 = 0xF0;
+        self.writer.data_writer.flush()?;
+        self.writer
+            .data_writer
+            .output_mut()
+            .push(DELIMITED_END_OPCODE);
+        self.flush()?;
+        // The whole container counts as a single top-level value.
+        let writer = &mut *self.writer;
+        if let Some(progress) = &mut writer.progress {
+            progress.record_value(writer.num_bytes_written);
+        }
+        Ok(())
+    }
+}
+
 pub struct ApplicationValueWriter<'a, V: ValueWriter> {
     symbols: &'a mut WriterSymbolTable,
     raw_value_writer: V,
     value_writer_config: ValueWriterConfig,
+    // Whether this writes a top-level value for a `Writer` with a maximum segment size or symbol
+    // table size. The writer cannot check such a value against its limits, so it is refused.
+    limits_unchecked: bool,
 }
 
 impl<'a, V: ValueWriter> ApplicationValueWriter<'a, V> {
@@ -430,9 +1093,29 @@ impl<'a, V: ValueWriter> ApplicationValueWriter<'a, V> {
             symbols,
             value_writer_config,
             raw_value_writer,
+            limits_unchecked: false,
         }
     }
 
+    /// Returns an `Err` if this writer's value would bypass the limits of the `Writer` it came
+    /// from.
+    fn check_limits(&self) -> IonResult<()> {
+        if self.limits_unchecked {
+            return IonResult::illegal_operation(
+                "a writer with a maximum segment size or symbol table size can only write \
+                 top-level values using `write` or the scalar methods built on it",
+            );
+        }
+        Ok(())
+    }
+
+    /// Checks the `Writer`'s limits (see [`check_limits`](Self::check_limits)) and then returns
+    /// the raw value writer.
+    fn checked_raw_value_writer(self) -> IonResult<V> {
+        self.check_limits()?;
+        Ok(self.raw_value_writer)
+    }
+
     #[cfg(feature = "experimental-reader-writer")]
     #[inline]
     pub fn symbol_table(&self) -> &SymbolTable {
@@ -463,6 +1146,18 @@ impl ApplicationValueWriter<'_, BinaryValueWriter_1_1<'_, '_>> {
         self
     }
 
+    pub fn with_scalar_encoding(mut self, scalar_encoding: ScalarEncoding) -> Self {
+        self.value_writer_config = self
+            .value_writer_config
+            .with_scalar_encoding(scalar_encoding);
+        self
+    }
+
+    pub fn with_eexp_encoding(mut self, eexp_encoding: EExpEncoding) -> Self {
+        self.value_writer_config = self.value_writer_config.with_eexp_encoding(eexp_encoding);
+        self
+    }
+
     pub fn with_symbol_value_encoding(
         mut self,
         symbol_value_encoding: SymbolValueEncoding,
@@ -497,7 +1192,8 @@ impl<V: ValueWriter> AnnotatableWriter for ApplicationValueWriter<'_, V> {
     where
         Self: 'a,
     {
-        let mut annotations = annotations.into_annotations_vec();
+        self.check_limits()?;
+        let mut annotations = annotations.into_annotations_buf();
         match self.value_writer_config.annotations_encoding() {
             AnnotationsEncoding::SymbolIds => {
                 // Intern all text so everything we write is a symbol ID
@@ -505,7 +1201,7 @@ impl<V: ValueWriter> AnnotatableWriter for ApplicationValueWriter<'_, V> {
             }
             AnnotationsEncoding::InlineText => {
                 // Validate the symbol IDs, write the text as-is
-                self.validate_all_symbol_ids(&mut annotations)?
+                self.validate_all_symbol_ids(&annotations)?
             }
             AnnotationsEncoding::NewSymbolsAsInlineText => {
                 // Map all known strings to symbol IDs, leave new text as is.
@@ -517,6 +1213,7 @@ impl<V: ValueWriter> AnnotatableWriter for ApplicationValueWriter<'_, V> {
             symbols: self.symbols,
             raw_value_writer: self.raw_value_writer.with_annotations(annotations)?,
             value_writer_config: self.value_writer_config,
+            limits_unchecked: false,
         })
     }
 }
@@ -525,12 +1222,13 @@ impl<V: ValueWriter> ApplicationValueWriter<'_, V> {
     /// Converts each annotation in `annotations` to a symbol ID, adding symbols to the symbol table
     /// as necessary. If one of the annotations is a symbol ID that is not in the symbol table,
     /// returns an `Err`.
-    fn intern_all_annotations<'a>(&mut self, annotations: &mut AnnotationsVec<'a>) -> IonResult<()>
+    fn intern_all_annotations<'a>(&mut self, annotations: &mut AnnotationsBuf<'a>) -> IonResult<()>
     where
         Self: 'a,
     {
-        for annotation in annotations {
-            match annotation.as_raw_symbol_ref() {
+        // Borrowed annotations are only copied if one of them needs to be replaced.
+        for index in 0..annotations.len() {
+            match annotations[index] {
                 // The token is already a symbol ID.
                 RawSymbolRef::SymbolId(sid) => {
                     if !self.symbol_table().sid_is_valid(sid) {
@@ -549,12 +1247,20 @@ impl<V: ValueWriter> ApplicationValueWriter<'_, V> {
                             //...that was already in the symbol table.
                             sid
                         }
-                        None => {
+                        None if self
+                            .value_writer_config
+                            .symbol_interning_policy()
+                            .should_intern(text) =>
+                        {
                             // ...that we need to add to the symbol table.
                             self.symbols.add_symbol_for_text(text)
                         }
+                        None => {
+                            // ...that the interning policy leaves inline.
+                            continue;
+                        }
                     };
-                    *annotation = RawSymbolRef::SymbolId(sid);
+                    annotations.to_mut()[index] = RawSymbolRef::SymbolId(sid);
                 }
             };
         }
@@ -564,12 +1270,12 @@ impl<V: ValueWriter> ApplicationValueWriter<'_, V> {
     /// Confirms all SIDs are in the symbol table while leaving text annotations as-is.
     pub(crate) fn validate_all_symbol_ids<'a>(
         &mut self,
-        annotations: &mut AnnotationsVec<'a>,
+        annotations: &AnnotationsBuf<'a>,
     ) -> IonResult<()>
     where
         Self: 'a,
     {
-        for annotation in annotations {
+        for annotation in annotations.iter() {
             if let RawSymbolRef::SymbolId(sid) = annotation.as_raw_symbol_ref() {
                 if !self.symbol_table().sid_is_valid(sid) {
                     return IonResult::encoding_error(format!(
@@ -586,13 +1292,13 @@ impl<V: ValueWriter> ApplicationValueWriter<'_, V> {
     /// returns an `Err`.
     fn map_known_symbols_to_symbol_ids<'a>(
         &mut self,
-        annotations: &mut AnnotationsVec<'a>,
+        annotations: &mut AnnotationsBuf<'a>,
     ) -> IonResult<()>
     where
         Self: 'a,
     {
-        for annotation in annotations {
-            match annotation.as_raw_symbol_ref() {
+        for index in 0..annotations.len() {
+            match annotations[index] {
                 // The token is already a symbol ID.
                 RawSymbolRef::SymbolId(sid) => {
                     if !self.symbol_table().sid_is_valid(sid) {
@@ -609,7 +1315,7 @@ impl<V: ValueWriter> ApplicationValueWriter<'_, V> {
                     match self.symbols.sid_for(text) {
                         Some(sid) => {
                             //...that was already in the symbol table.
-                            *annotation = RawSymbolRef::SymbolId(sid);
+                            annotations.to_mut()[index] = RawSymbolRef::SymbolId(sid);
                         }
                         None => {
                             // ...that is not in the symbol table. Leave it as-is.
@@ -629,7 +1335,7 @@ impl<'value, V: ValueWriter> ValueWriter for ApplicationValueWriter<'value, V> {
     type EExpWriter = ApplicationEExpWriter<'value, V>;
 
     delegate! {
-        to self.raw_value_writer {
+        to self.checked_raw_value_writer()? {
             fn write_null(self, ion_type: IonType) -> IonResult<()> ;
             fn write_bool(self, value: bool) -> IonResult<()>;
             fn write_i64(self, value: i64) -> IonResult<()>;
@@ -637,17 +1343,23 @@ impl<'value, V: ValueWriter> ValueWriter for ApplicationValueWriter<'value, V> {
             fn write_f32(self, value: f32) -> IonResult<()>;
             fn write_f64(self, value: f64) -> IonResult<()>;
             fn write_decimal(self, value: &Decimal) -> IonResult<()>;
-            fn write_timestamp(self, value: &Timestamp) -> IonResult<()>;
             fn write_string(self, value: impl AsRef<str>) -> IonResult<()>;
             fn write_clob(self, value: impl AsRef<[u8]>) -> IonResult<()>;
             fn write_blob(self, value: impl AsRef<[u8]>) -> IonResult<()>;
         }
     }
 
+    fn write_timestamp(self, value: &Timestamp) -> IonResult<()> {
+        self.check_limits()?;
+        let timestamp = self.value_writer_config.timestamp_precision().apply(value);
+        self.raw_value_writer.write_timestamp(&timestamp)
+    }
+
     fn write_symbol(self, value: impl AsRawSymbolRef) -> IonResult<()> {
         use RawSymbolRef::*;
         use SymbolValueEncoding::*;
 
+        self.check_limits()?;
         let Self {
             symbols,
             raw_value_writer,
@@ -675,8 +1387,15 @@ impl<'value, V: ValueWriter> ValueWriter for ApplicationValueWriter<'value, V> {
                         match symbols.sid_for(text) {
                             // If it's already in the symbol table, use that SID.
                             Some(symbol_id) => SymbolId(symbol_id),
-                            // Otherwise, add it to the symbol table.
-                            None => SymbolId(symbols.add_symbol_for_text(text)),
+                            // Otherwise, add it to the symbol table if the interning policy allows.
+                            None if value_writer_config
+                                .symbol_interning_policy()
+                                .should_intern(text) =>
+                            {
+                                SymbolId(symbols.add_symbol_for_text(text))
+                            }
+                            // If it doesn't, write the text inline.
+                            None => Text(text),
                         }
                     }
                     NewSymbolsAsInlineText => {
@@ -696,6 +1415,7 @@ impl<'value, V: ValueWriter> ValueWriter for ApplicationValueWriter<'value, V> {
     }
 
     fn list_writer(self) -> IonResult<Self::ListWriter> {
+        self.check_limits()?;
         Ok(ApplicationListWriter::new(
             self.symbols,
             self.value_writer_config,
@@ -704,6 +1424,7 @@ impl<'value, V: ValueWriter> ValueWriter for ApplicationValueWriter<'value, V> {
     }
 
     fn sexp_writer(self) -> IonResult<Self::SExpWriter> {
+        self.check_limits()?;
         Ok(ApplicationSExpWriter::new(
             self.symbols,
             self.value_writer_config,
@@ -711,7 +1432,17 @@ impl<'value, V: ValueWriter> ValueWriter for ApplicationValueWriter<'value, V> {
         ))
     }
 
+    fn inline_sexp_writer(self) -> IonResult<Self::SExpWriter> {
+        self.check_limits()?;
+        Ok(ApplicationSExpWriter::new(
+            self.symbols,
+            self.value_writer_config,
+            self.raw_value_writer.inline_sexp_writer()?,
+        ))
+    }
+
     fn struct_writer(self) -> IonResult<Self::StructWriter> {
+        self.check_limits()?;
         let config = self.value_writer_config;
         Ok(ApplicationStructWriter::new(
             self.symbols,
@@ -724,6 +1455,7 @@ impl<'value, V: ValueWriter> ValueWriter for ApplicationValueWriter<'value, V> {
     where
         Self: 'a,
     {
+        self.check_limits()?;
         Ok(ApplicationEExpWriter::new(
             self.symbols,
             self.value_writer_config,
@@ -816,7 +1548,11 @@ impl<V: ValueWriter> FieldEncoder for ApplicationStructWriter<'_, V> {
             // If it's not but the struct writer is configured to intern new text, add it to the
             // symbol table.
             None if self.value_writer_config.field_name_encoding()
-                == FieldNameEncoding::SymbolIds =>
+                == FieldNameEncoding::SymbolIds
+                && self
+                    .value_writer_config
+                    .symbol_interning_policy()
+                    .should_intern(text) =>
             {
                 self.symbols.add_symbol_for_text(text).into()
             }
@@ -1021,23 +1757,13 @@ impl<V: ValueWriter> EExpWriter for ApplicationEExpWriter<'_, V> {
         Self::current_parameter(self) // Delegate to the inherent impl
     }
 
-    fn write_flex_uint(&mut self, value: impl Into<UInt>) -> IonResult<()> {
-        self.expect_next_parameter()
-            .and_then(|p| p.expect_encoding(&ParameterEncoding::FlexUInt))?;
-        self.raw_eexp_writer.write_flex_uint(value)
-    }
-
-    fn write_fixed_uint8(&mut self, value: impl Into<u8>) -> IonResult<()> {
-        self.expect_next_parameter()
-            .and_then(|p| p.expect_encoding(&ParameterEncoding::UInt8))?;
-        self.raw_eexp_writer.write_fixed_uint8(value)
-    }
-
-    fn expr_group_writer(&mut self) -> IonResult<Self::ExprGroupWriter<'_>> {
-        let _param = self.expect_next_parameter()
-            .and_then(|p| p.expect_variadic())?;
-        // TODO: Pass `Parameter` to group writer so it can do its own validation
-        self.raw_eexp_writer.expr_group_writer()
+    // The raw writer advances to the next parameter and validates each argument against it.
+    delegate! {
+        to self.raw_eexp_writer {
+            fn write_flex_uint(&mut self, value: impl Into<UInt>) -> IonResult<()>;
+            fn write_fixed_uint8(&mut self, value: impl Into<u8>) -> IonResult<()>;
+            fn expr_group_writer(&mut self) -> IonResult<Self::ExprGroupWriter<'_>>;
+        }
     }
 }
 
@@ -1056,12 +1782,19 @@ impl<S: SequenceWriter> ElementWriter for S {
 #[cfg(feature = "experimental-ion-1-1")]
 #[cfg(test)]
 mod tests {
+    use crate::lazy::decoder::Decoder;
+    use crate::lazy::encoder::annotation_seq::AnnotationsVec;
     use crate::lazy::encoder::value_writer::AnnotatableWriter;
-    use crate::lazy::encoder::value_writer_config::{AnnotationsEncoding, SymbolValueEncoding};
+    use crate::lazy::encoder::value_writer_config::{
+        AnnotationsEncoding, SymbolInterningPolicy, SymbolValueEncoding,
+    };
+    use crate::lazy::encoding::Encoding;
     use crate::raw_symbol_ref::AsRawSymbolRef;
     use crate::{
-        v1_0, v1_1, EExpWriter, Element, FieldNameEncoding, HasSpan, IonResult, LazyRawValue,
-        RawSymbolRef, SequenceWriter, StructWriter, SystemReader, TextFormat, ValueWriter, Writer,
+        v1_0, v1_1, AnyEncoding, EExpWriter, Element, FieldNameEncoding, HasRange, HasSpan,
+        IonError, IonResult, LazyRawValue, MapCatalog, RawSymbolRef, Reader, Sequence,
+        SequenceWriter, SharedSymbolTable, StructWriter, SymbolTableRotation, SystemReader,
+        TextFormat, ValueWriter, WriteConfig, Writer,
     };
     use std::io::BufWriter;
 
@@ -1217,6 +1950,45 @@ mod tests {
         )
     }
 
+    /// Writes `rows` as annotated ints using `with_annotations_iter`, and then again using a
+    /// single reused `AnnotationsVec`.
+    fn write_dynamic_annotations<E: Encoding>(
+        config: impl Into<WriteConfig<E>>,
+        rows: &[Vec<String>],
+    ) -> IonResult<()> {
+        let mut writer = Writer::new(config, Vec::new())?;
+        for (index, tags) in rows.iter().enumerate() {
+            writer
+                .value_writer()
+                .with_annotations_iter(tags)?
+                .write_i64(index as i64)?;
+        }
+        let mut buffer = AnnotationsVec::new();
+        for (index, tags) in rows.iter().enumerate() {
+            buffer.clear();
+            buffer.extend(tags.iter().map(RawSymbolRef::from));
+            writer
+                .value_writer()
+                .with_annotations(&buffer)?
+                .write_i64(index as i64)?;
+        }
+        let output = writer.close()?;
+        let expected = Element::read_all("0 a::1 a::b::c::d::2 0 a::1 a::b::c::d::2")?;
+        assert_eq!(Element::read_all(output)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn write_dynamic_annotations_without_vecs() -> IonResult<()> {
+        let rows = [vec![], vec!["a"], vec!["a", "b", "c", "d"]]
+            .map(|tags| tags.into_iter().map(String::from).collect::<Vec<_>>());
+        write_dynamic_annotations(v1_0::Text, &rows)?;
+        write_dynamic_annotations(v1_0::Binary, &rows)?;
+        write_dynamic_annotations(v1_1::Text, &rows)?;
+        write_dynamic_annotations(v1_1::Binary, &rows)?;
+        Ok(())
+    }
+
     #[test]
     #[rustfmt::skip]
     fn write_text_annotations_as_is() -> IonResult<()> {
@@ -1238,6 +2010,103 @@ mod tests {
         )
     }
 
+    #[test]
+    fn write_config_symbol_interning_policy() -> IonResult<()> {
+        let value = Element::read_one("unit::{id: ok, description: uuid_f47ac10b}")?;
+        let all_text = ["unit", "id", "ok", "description", "uuid_f47ac10b"];
+        let test_cases: &[(SymbolInterningPolicy, &[&str])] = &[
+            (SymbolInterningPolicy::InternAll, &all_text),
+            (SymbolInterningPolicy::InternNone, &[]),
+            (
+                SymbolInterningPolicy::InternAboveLength(4),
+                &["description", "uuid_f47ac10b"],
+            ),
+            (
+                SymbolInterningPolicy::Custom(|text| !text.starts_with("uuid_")),
+                &["unit", "id", "ok", "description"],
+            ),
+        ];
+        for (policy, expected_interned) in test_cases {
+            let config = WriteConfig::<v1_1::Binary>::new().with_symbol_interning_policy(*policy);
+            let mut writer = Writer::new(config, Vec::new())?;
+            writer.write(&value)?;
+            for text in all_text {
+                assert_eq!(
+                    writer.symbol_table().sid_for(text).is_some(),
+                    expected_interned.contains(&text),
+                    "unexpected interning of '{text}' with {policy:?}"
+                );
+            }
+            // Text that was not interned is written inline, so the value reads back the same.
+            let bytes = writer.close()?;
+            assert_eq!(Element::read_one(bytes)?, value);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn write_config_annotations_encoding() -> IonResult<()> {
+        let config = WriteConfig::<v1_1::Binary>::new()
+            .with_annotations_encoding(AnnotationsEncoding::InlineText);
+        let mut writer = Writer::new(config, Vec::new())?;
+        let mut struct_writer = writer
+            .value_writer()
+            .with_annotations("foo")?
+            .struct_writer()?;
+        struct_writer.write("quux", 0)?;
+        struct_writer.close()?;
+        let bytes = writer.close()?;
+        let mut reader = SystemReader::new(v1_1::Binary, bytes.as_slice());
+        let value = reader.expect_next_value()?;
+        let raw_value = value.raw().unwrap();
+        // The annotation is written as inline text...
+        #[rustfmt::skip]
+        let expected_annotations: &[u8] = &[
+            0xE7, // Opcode: One annotation with FlexSym follows
+            0xFB, // FlexSym: 3 UTF-8 bytes
+            // f     o     o
+            0x66, 0x6F, 0x6F,
+        ];
+        assert_eq!(raw_value.annotations_span().bytes(), expected_annotations);
+        // ...while the field name is interned and encoded as a symbol ID.
+        let field = value.read()?.expect_struct()?.get_expected("quux")?;
+        assert_eq!(field.expect_i64()?, 0);
+        let field_name_offset = bytes.windows(4).position(|w| w == b"quux").unwrap();
+        assert!(
+            field_name_offset < raw_value.range().start,
+            "field name was not interned"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn write_config_timestamp_precision() -> IonResult<()> {
+        use crate::ion_data::IonEq;
+        use crate::TimestampPrecisionPolicy;
+        let input = Element::read_all(
+            "2024-06-15T12:30:45.123456Z [2024-06-15T12:30:45Z] {a: 2024-06-15T12:30:45.5Z} 2024T",
+        )?;
+        let expected = Element::read_all(
+            "2024-06-15T12:30:45.123Z [2024-06-15T12:30:45.000Z] {a: 2024-06-15T12:30:45.500Z} 2024T",
+        )?;
+        let policy = TimestampPrecisionPolicy::FractionalDigits(3);
+
+        let text = WriteConfig::<v1_0::Text>::new(TextFormat::Compact)
+            .with_timestamp_precision(policy)
+            .encode_all(&input)?;
+        let binary_1_0 = WriteConfig::<v1_0::Binary>::new()
+            .with_timestamp_precision(policy)
+            .encode_all(&input)?;
+        let binary_1_1 = WriteConfig::<v1_1::Binary>::new()
+            .with_timestamp_precision(policy)
+            .encode_all(&input)?;
+
+        assert!(expected.ion_eq(&Element::read_all(text)?));
+        assert!(expected.ion_eq(&Element::read_all(binary_1_0)?));
+        assert!(expected.ion_eq(&Element::read_all(binary_1_1)?));
+        Ok(())
+    }
+
     /// Writes a struct with all of the provided field names using the requested field name encoding.
     /// For simplicity, the value for each field is the integer 0.
     fn struct_field_encoding_test(
@@ -1361,6 +2230,76 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn write_delta_and_sum() -> IonResult<()> {
+        fn write_with_system_macros<E: Encoding>(encoding: E) -> IonResult<Sequence>
+        where
+            WriteConfig<E>: From<E>,
+        {
+            let mut writer = Writer::new(encoding, Vec::new())?;
+            writer.value_writer().write_delta_encoded([5, 3, 3, 10])?;
+            writer.value_writer().write_delta_encoded(Vec::<i64>::new())?;
+            writer
+                .value_writer()
+                .write_delta_encoded([i64::MIN, i64::MAX])?;
+            writer.value_writer().write_sum(40, 2)?;
+            let output = writer.close()?;
+            Element::read_all(output)
+        }
+        let expected = Element::read_all(format!("5 3 3 10 {} {} 42", i64::MIN, i64::MAX))?;
+        assert_eq!(write_with_system_macros(v1_1::Text)?, expected);
+        assert_eq!(write_with_system_macros(v1_1::Binary)?, expected);
+
+        // The difference between these values does not fit in an `i128`.
+        let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+        let result = writer
+            .value_writer()
+            .write_delta_encoded([i128::MIN, i128::MAX]);
+        assert!(matches!(result, Err(IonError::Encoding(_))));
+        // The failed invocation did not leave anything behind in the stream.
+        writer.write(7)?;
+        assert_eq!(Element::read_all(writer.close()?)?, Element::read_all("7")?);
+
+        // Ion 1.0 does not have macros.
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        assert!(writer.value_writer().write_delta_encoded([1, 2]).is_err());
+        assert!(writer.value_writer().write_sum(1, 2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn define_macros_across_flushes() -> IonResult<()> {
+        fn write_with_macros<E: Encoding>(encoding: E) -> IonResult<Vec<u8>>
+        where
+            WriteConfig<E>: From<E>,
+        {
+            let mut writer = Writer::new(encoding, Vec::new())?;
+            let pair = writer.compile_macro("(macro pair (a b) [(%a), (%b)])")?;
+            let mut eexp_writer = writer.eexp_writer(&pair)?;
+            eexp_writer.write(1)?.write(2)?;
+            eexp_writer.close()?;
+            writer.flush()?;
+
+            // A macro defined after a flush is appended to the macro table; `pair` remains
+            // available.
+            let twice = writer.compile_macro("(macro twice (x) (.values (%x) (%x)))")?;
+            let mut eexp_writer = writer.eexp_writer(&twice)?;
+            eexp_writer.write("foo")?;
+            eexp_writer.close()?;
+            let mut eexp_writer = writer.eexp_writer(&pair)?;
+            eexp_writer.write(3)?.write(4)?;
+            eexp_writer.close()?;
+            writer.close()
+        }
+
+        let expected = Element::read_all(r#"[1, 2] "foo" "foo" [3, 4]"#)?;
+        let text = write_with_macros(v1_1::Text)?;
+        assert_eq!(Element::read_all(text)?, expected);
+        let binary = write_with_macros(v1_1::Binary)?;
+        assert_eq!(Element::read_all(binary)?, expected);
+        Ok(())
+    }
+
     #[test]
     fn flush_underlying_sink() -> IonResult<()> {
         // The final output destination
@@ -1383,6 +2322,371 @@ mod tests {
         Ok(())
     }
 
+    /// An output that treats the data written between calls to `flush` as a separate segment.
+    #[derive(Default)]
+    struct SegmentCollector {
+        segments: Vec<Vec<u8>>,
+        current: Vec<u8>,
+    }
+
+    impl std::io::Write for SegmentCollector {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.current.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            if !self.current.is_empty() {
+                self.segments.push(std::mem::take(&mut self.current));
+            }
+            Ok(())
+        }
+    }
+
+    fn segmented_round_trip<E: Encoding>(config: impl Into<WriteConfig<E>>) -> IonResult<()> {
+        const MAX_SEGMENT_SIZE: usize = 300;
+        let data: String = (0..60)
+            .map(|i| format!("{{id: {i}, tag_{}: \"value {i}\"}} ", i % 9))
+            .collect();
+        let values = Element::read_all(data)?;
+        let config = config.into().with_max_segment_size(MAX_SEGMENT_SIZE);
+        let mut writer = Writer::new(config, SegmentCollector::default())?;
+        writer.write_all(&values)?;
+        let segments = writer.close()?.segments;
+
+        assert!(segments.len() > 1);
+        let mut values_read = Vec::new();
+        for segment in &segments {
+            assert!(segment.len() <= MAX_SEGMENT_SIZE, "{} bytes", segment.len());
+            // Each segment can be read without the ones before it.
+            values_read.extend(Element::read_all(segment)?);
+        }
+        assert_eq!(Sequence::from(values_read), values);
+        Ok(())
+    }
+
+    #[test]
+    fn segmented_output() -> IonResult<()> {
+        segmented_round_trip(v1_0::Text)?;
+        segmented_round_trip(v1_0::Binary)?;
+        segmented_round_trip(v1_1::Text)?;
+        segmented_round_trip(v1_1::Binary)
+    }
+
+    #[test]
+    fn segment_size_limit_rejects_large_values() -> IonResult<()> {
+        let config = WriteConfig::<v1_0::Binary>::new().with_max_segment_size(32);
+        let mut writer = Writer::new(config, SegmentCollector::default())?;
+        writer.write(1)?;
+        let result = writer.write("a string that is far too long for a 32-byte segment");
+        assert!(matches!(result, Err(IonError::Encoding(_))));
+        // The value was not written, and the writer can still be used. Trying to make room for
+        // the value finished the first segment.
+        writer.write(2)?.write(3)?;
+        let segments = writer.close()?.segments;
+        assert_eq!(segments.len(), 2);
+        assert_eq!(Element::read_all(&segments[0])?, Element::read_all("1")?);
+        assert_eq!(Element::read_all(&segments[1])?, Element::read_all("2 3")?);
+        Ok(())
+    }
+
+    #[test]
+    fn segment_size_limit_applies_to_every_top_level_value() -> IonResult<()> {
+        const MAX_SEGMENT_SIZE: usize = 64;
+        let config = WriteConfig::<v1_0::Binary>::new().with_max_segment_size(MAX_SEGMENT_SIZE);
+        let mut writer = Writer::new(config, SegmentCollector::default())?;
+        // The writer cannot move a value written with a value writer into a new segment, so it
+        // refuses to write one.
+        assert!(matches!(
+            writer.struct_writer(),
+            Err(IonError::IllegalOperation(_))
+        ));
+        assert!(matches!(
+            writer.list_writer(),
+            Err(IonError::IllegalOperation(_))
+        ));
+        assert!(matches!(
+            writer.value_writer().write_i64(1),
+            Err(IonError::IllegalOperation(_))
+        ));
+        // Values written with `write` and the scalar helpers are checked instead.
+        let mut expected = Vec::new();
+        for i in 0..20 {
+            let value = Element::read_one(format!("{{id: {i}, name: \"name {i}\"}}"))?;
+            writer.write(&value)?.write_string("done")?;
+            expected.push(value);
+            expected.push(Element::string("done"));
+        }
+        let segments = writer.close()?.segments;
+        assert!(segments.len() > 1);
+        let mut values_read = Vec::new();
+        for segment in &segments {
+            assert!(segment.len() <= MAX_SEGMENT_SIZE, "{} bytes", segment.len());
+            values_read.extend(Element::read_all(segment)?);
+        }
+        assert_eq!(Sequence::from(values_read), Sequence::from(expected));
+        Ok(())
+    }
+
+    fn rotating_round_trip<E: Encoding>(
+        config: impl Into<WriteConfig<E>>,
+        rotation: SymbolTableRotation,
+    ) -> IonResult<()> {
+        const MAX_SYMBOLS: usize = 10;
+        let data: String = (0..50)
+            .map(|i| format!("{{id: {i}, field_{i}: name_{i}}} "))
+            .collect();
+        let values = Element::read_all(data)?;
+        let config = config
+            .into()
+            .with_max_symbol_table_size(MAX_SYMBOLS, rotation);
+        let mut writer = Writer::new(config, Vec::new())?;
+        let num_system_symbols = writer.symbol_table().len();
+        let mut id_sid = None;
+        for value in &values {
+            writer.write(value)?;
+            let num_symbols = writer.symbol_table().len() - num_system_symbols;
+            assert!(num_symbols <= MAX_SYMBOLS, "{num_symbols} symbols");
+            let sid = writer.symbol_table().sid_for("id");
+            if rotation != SymbolTableRotation::Reset {
+                // Kept symbols retain their symbol IDs.
+                assert_eq!(*id_sid.get_or_insert(sid), sid);
+            }
+        }
+        let output = writer.close()?;
+        assert_eq!(Element::read_all(output)?, values);
+        Ok(())
+    }
+
+    #[test]
+    fn rotating_symbol_tables() -> IonResult<()> {
+        for rotation in [
+            SymbolTableRotation::Reset,
+            SymbolTableRotation::KeepFirst(1),
+        ] {
+            rotating_round_trip(v1_0::Binary, rotation)?;
+            rotating_round_trip(v1_1::Binary, rotation)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rotating_symbol_tables_in_segments() -> IonResult<()> {
+        let data: String = (0..40).map(|i| format!("field_{i}::{i} ")).collect();
+        let values = Element::read_all(data)?;
+        let config = WriteConfig::<v1_0::Binary>::new()
+            .with_max_segment_size(200)
+            .with_max_symbol_table_size(5, SymbolTableRotation::Reset);
+        let mut writer = Writer::new(config, SegmentCollector::default())?;
+        writer.write_all(&values)?;
+        let segments = writer.close()?.segments;
+        assert!(segments.len() >= 8);
+        let mut values_read = Vec::new();
+        for segment in &segments {
+            values_read.extend(Element::read_all(segment)?);
+        }
+        assert_eq!(Sequence::from(values_read), values);
+        Ok(())
+    }
+
+    fn shared_symbol_table() -> SharedSymbolTable {
+        SharedSymbolTable::new("com.example.orders", 2, ["customer_id", "order_total"]).unwrap()
+    }
+
+    /// Reads `data` using a catalog containing [`shared_symbol_table`].
+    fn read_with_shared_symbols(data: &[u8]) -> IonResult<Sequence> {
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(shared_symbol_table());
+        let mut reader = Reader::new(AnyEncoding.with_catalog(catalog), data)?;
+        let mut values = Vec::new();
+        while let Some(value) = reader.next()? {
+            values.push(Element::try_from(value)?);
+        }
+        Ok(values.into())
+    }
+
+    fn shared_symbol_table_round_trip<E: Encoding>(
+        config: impl Into<WriteConfig<E>>,
+    ) -> IonResult<Vec<u8>> {
+        let values = Element::read_all("{customer_id: 7, order_total: 12.5, note: customer_id}")?;
+        let config = config
+            .into()
+            .with_shared_symbol_table_imports([shared_symbol_table()]);
+        let mut writer = Writer::new(config, Vec::new())?;
+        writer.write_all(&values)?;
+        let output = writer.close()?;
+        assert_eq!(read_with_shared_symbols(&output)?, values);
+        Ok(output)
+    }
+
+    #[test]
+    fn shared_symbol_table_imports() -> IonResult<()> {
+        shared_symbol_table_round_trip(v1_0::Text)?;
+        shared_symbol_table_round_trip(v1_1::Text)?;
+        for output in [
+            shared_symbol_table_round_trip(v1_0::Binary)?,
+            shared_symbol_table_round_trip(v1_1::Binary)?,
+        ] {
+            // Symbols from the shared table are encoded as symbol IDs without being defined...
+            assert!(!output.windows(11).any(|w| w == b"customer_id"));
+            // ...while new symbols are still added to the local symbol table.
+            assert!(output.windows(4).any(|w| w == b"note"));
+            // Without the shared table, the imports cannot be resolved.
+            assert!(Element::read_all(&output).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn shared_symbol_table_imports_are_repeated_in_each_segment() -> IonResult<()> {
+        let data: String = (0..40)
+            .map(|i| format!("{{customer_id: {i}, field_{i}: order_total}} "))
+            .collect();
+        let values = Element::read_all(data)?;
+        let config = WriteConfig::<v1_0::Binary>::new()
+            .with_max_segment_size(200)
+            .with_max_symbol_table_size(5, SymbolTableRotation::Reset)
+            .with_shared_symbol_table_imports([shared_symbol_table()]);
+        let mut writer = Writer::new(config, SegmentCollector::default())?;
+        writer.write_all(&values)?;
+        let segments = writer.close()?.segments;
+        assert!(segments.len() > 1);
+        // Each segment imports the shared table, so it can be read on its own.
+        let mut values_read = Vec::new();
+        for segment in &segments {
+            values_read.extend(read_with_shared_symbols(segment)?);
+        }
+        assert_eq!(Sequence::from(values_read), values);
+
+        // Imported symbols are kept when the symbol table is rotated.
+        let config = WriteConfig::<v1_1::Binary>::new()
+            .with_max_symbol_table_size(5, SymbolTableRotation::KeepFirst(2))
+            .with_shared_symbol_table_imports([shared_symbol_table()]);
+        let mut writer = Writer::new(config, Vec::new())?;
+        writer.write_all(&values)?;
+        let output = writer.close()?;
+        assert_eq!(read_with_shared_symbols(&output)?, values);
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_table_limit_rejects_values_with_too_many_symbols() -> IonResult<()> {
+        let config = WriteConfig::<v1_0::Binary>::new()
+            .with_max_symbol_table_size(2, SymbolTableRotation::Reset);
+        let mut writer = Writer::new(config, Vec::new())?;
+        writer.write(&Element::read_one("{a: b}")?)?;
+        let result = writer.write(&Element::read_one("{c: d, e: f}")?);
+        assert!(matches!(result, Err(IonError::Encoding(_))));
+        // The value was not written, and the writer can still be used.
+        writer.write(&Element::read_one("g")?)?;
+        let output = writer.close()?;
+        assert_eq!(Element::read_all(output)?, Element::read_all("{a: b} g")?);
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_table_limit_applies_to_every_top_level_value() -> IonResult<()> {
+        const MAX_SYMBOLS: usize = 5;
+        let config = WriteConfig::<v1_0::Binary>::new()
+            .with_max_symbol_table_size(MAX_SYMBOLS, SymbolTableRotation::Reset);
+        let mut writer = Writer::new(config, Vec::new())?;
+        let num_system_symbols = writer.symbol_table().len();
+        // A struct writer could add any number of field names to the symbol table before the
+        // writer had a chance to rotate it, so the writer refuses to create one.
+        assert!(matches!(
+            writer.struct_writer(),
+            Err(IonError::IllegalOperation(_))
+        ));
+        assert!(matches!(
+            writer.value_writer().with_annotations("tag"),
+            Err(IonError::IllegalOperation(_))
+        ));
+        assert_eq!(writer.symbol_table().len(), num_system_symbols);
+        // Structs with many distinct field names written with `write` rotate the symbol table
+        // as needed.
+        let mut expected = Vec::new();
+        for i in 0..20 {
+            let value = Element::read_one(format!("{{field_{i}: 1, other_{i}: 2}}"))?;
+            writer
+                .write(&value)?
+                .write_symbol(format!("symbol_{i}").as_str())?;
+            expected.push(value);
+            expected.push(Element::symbol(format!("symbol_{i}")));
+            let num_symbols = writer.symbol_table().len() - num_system_symbols;
+            assert!(num_symbols <= MAX_SYMBOLS, "{num_symbols} symbols");
+        }
+        let output = writer.close()?;
+        assert_eq!(Element::read_all(output)?, Sequence::from(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn streaming_list_is_written_incrementally() -> IonResult<()> {
+        let mut writer = Writer::new(v1_1::Binary, SegmentCollector::default())?;
+        writer.write_symbol("before")?;
+        let mut list = writer.streaming_list_writer()?;
+        for i in 0..20_000 {
+            list.write(format!("value {i}"))?;
+        }
+        list.write_symbol("before")?.write_symbol("during")?;
+        list.close()?;
+        writer.write_symbol("after")?;
+        let segments = writer.close()?.segments;
+        // The IVM, the data written before the list, several batches of the list, and the rest
+        // of the stream were each flushed separately.
+        assert!(segments.len() > 4, "only {} segments", segments.len());
+
+        let output = segments.concat();
+        let values = Element::read_all(&output)?;
+        assert_eq!(values.len(), 3);
+        let list = values.get(1).unwrap().expect_list()?;
+        assert_eq!(list.len(), 20_002);
+        assert_eq!(list.get(19_999), Some(&Element::string("value 19999")));
+        assert_eq!(list.get(20_000), Some(&Element::symbol("before")));
+        assert_eq!(list.get(20_001), Some(&Element::symbol("during")));
+        assert_eq!(values.get(2), Some(&Element::symbol("after")));
+
+        // The list uses the symbol ID for `before`, which was already in the symbol table, and
+        // writes `during` as inline text. `after` was added to the symbol table after the list.
+        let mut reader = Reader::new(v1_1::Binary, output.as_slice())?;
+        let _ = reader.expect_next()?;
+        let list = reader.expect_next()?.read()?.expect_list()?;
+        let symbols: Vec<_> = list.iter().skip(20_000).collect::<IonResult<_>>()?;
+        let raw_symbols = symbols
+            .iter()
+            .map(|symbol| symbol.raw().unwrap().read()?.expect_symbol())
+            .collect::<IonResult<Vec<_>>>()?;
+        assert!(matches!(raw_symbols[0], RawSymbolRef::SymbolId(_)));
+        assert_eq!(raw_symbols[1], RawSymbolRef::Text("during"));
+        Ok(())
+    }
+
+    #[test]
+    fn streaming_sexp_of_containers() -> IonResult<()> {
+        let values = Element::read_all("(a b) {c: [d, 1]} e::2 null.list")?;
+        let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+        let mut sexp = writer.streaming_sexp_writer()?;
+        sexp.write_all(&values)?;
+        sexp.flush()?;
+        sexp.close()?;
+        let output = writer.close()?;
+        let sexp = Element::read_one(output)?;
+        assert_eq!(sexp.ion_type(), crate::IonType::SExp);
+        assert_eq!(sexp.as_sequence(), Some(&values));
+        Ok(())
+    }
+
+    #[test]
+    fn streaming_container_rejects_segmented_writer() -> IonResult<()> {
+        let config = WriteConfig::<v1_1::Binary>::new().with_max_segment_size(100);
+        let mut writer = Writer::new(config, Vec::new())?;
+        assert!(matches!(
+            writer.streaming_list_writer(),
+            Err(IonError::IllegalOperation(_))
+        ));
+        Ok(())
+    }
+
     mod eexp_parameter_validation {
         use super::*;
         use num_traits::{PrimInt, Unsigned};
@@ -1406,6 +2710,51 @@ mod tests {
             Ok(())
         }
 
+        #[test]
+        fn length_prefixed_eexps_round_trip() -> IonResult<()> {
+            use crate::lazy::encoder::value_writer_config::EExpEncoding;
+            let config =
+                WriteConfig::<v1_1::Binary>::new().with_eexp_encoding(EExpEncoding::LengthPrefixed);
+            let mut writer = Writer::new(config, Vec::new())?;
+            let foo = writer.compile_macro("(macro foo (a flex_uint::b c) [(%a), (%b), (%c)])")?;
+            let mut eexp_writer = writer.eexp_writer(&foo)?;
+            eexp_writer.write("hello")?;
+            eexp_writer.write_flex_uint(42usize)?;
+            // A nested invocation is buffered along with the rest of the outer one's arguments.
+            let mut nested = eexp_writer.eexp_writer(&foo)?;
+            nested.write("nested")?.write_flex_uint(7usize)?;
+            nested.write(true)?;
+            nested.close()?;
+            eexp_writer.close()?;
+            let bytes = writer.close()?;
+            assert!(bytes.contains(&0xF5));
+            let actual = Element::read_all(&bytes)?;
+            let expected = Element::read_all(r#"["hello", 42, ["nested", 7, true]]"#)?;
+            assert_eq!(actual, expected);
+            Ok(())
+        }
+
+        #[test]
+        fn expr_group_with_nested_eexps_round_trips() -> IonResult<()> {
+            let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+            let pair = writer.compile_macro("(macro pair (a b) [(%a), (%b)])")?;
+            let foo = writer.compile_macro("(macro foo (x* y) (.values (%x) (%y)))")?;
+            let mut eexp_writer = writer.eexp_writer(&foo)?;
+            let mut group_writer = eexp_writer.expr_group_writer()?;
+            group_writer.write(1)?;
+            let mut nested = group_writer.eexp_writer(&pair)?;
+            nested.write(2)?.write(3)?;
+            nested.close()?;
+            group_writer.close()?;
+            eexp_writer.write("done")?;
+            eexp_writer.close()?;
+            let bytes = writer.close()?;
+            let actual = Element::read_all(&bytes)?;
+            let expected = Element::read_all(r#"1 [2, 3] "done""#)?;
+            assert_eq!(actual, expected);
+            Ok(())
+        }
+
         #[test]
         fn tagged_parameter_rejects_flex_uint() -> IonResult<()> {
             let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
@@ -1449,22 +2798,299 @@ mod tests {
             Ok(())
         }
 
+        #[rstest]
+        #[case::string("\"hello\"", "a string")]
+        #[case::symbol("hello", "a symbol")]
+        #[case::bool("true", "a bool")]
+        #[case::float("1.5e0", "a float")]
+        #[case::decimal("1.5", "a decimal")]
+        #[case::timestamp("2024-01-01T", "a timestamp")]
+        #[case::blob("{{aGVsbG8=}}", "a blob")]
+        #[case::null("null.int", "a null")]
+        #[case::list("[1, 2]", "a list")]
+        #[case::sexp("(1 2)", "an s-expression")]
+        #[case::struct_("{a: 1}", "a struct")]
+        #[case::annotated_int("meters::5", "an annotated value")]
+        fn tagless_parameter_rejects_tagged_value(
+            #[case] value: &str,
+            #[case] value_kind: &str,
+            #[values("flex_uint", "uint8", "uint64")] encoding: &str,
+        ) -> IonResult<()> {
+            let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+            let foo = writer.compile_macro(format!("(macro foo ({encoding}::x) (%x))"))?;
+            let mut eexp_writer = writer.eexp_writer(&foo)?;
+            let value = Element::read_one(value)?;
+            let error = eexp_writer
+                .write(&value)
+                .map(|_| ())
+                .expect_err("tagged value was accepted by a tagless parameter");
+            let expected = format!("cannot write {value_kind} for parameter 'x'; it requires a {encoding} argument");
+            assert!(
+                error.to_string().contains(&expected),
+                "unexpected error message: {error}"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn tagless_parameter_rejects_expr_group() -> IonResult<()> {
+            let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+            let foo = writer.compile_macro("(macro foo (flex_uint::x*) (%x))")?;
+            let mut eexp_writer = writer.eexp_writer(&foo)?;
+            let error = eexp_writer
+                .expr_group_writer()
+                .map(|_| ())
+                .expect_err("expression group of tagless values was accepted");
+            assert!(
+                error.to_string().contains("groups of flex_uint arguments are not yet supported"),
+                "unexpected error message: {error}"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn surplus_argument_is_rejected() -> IonResult<()> {
+            let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+            let foo = writer.compile_macro("(macro foo (x) (%x))")?;
+            let mut eexp_writer = writer.eexp_writer(&foo)?;
+            eexp_writer.write("hello")?;
+            let error = eexp_writer
+                .write("world")
+                .map(|_| ())
+                .expect_err("surplus argument was accepted");
+            assert!(
+                error.to_string().contains("the macro takes no more parameters"),
+                "unexpected error message: {error}"
+            );
+            Ok(())
+        }
+
+        /// Writes an e-expression invoking `macro_source` with the provided expression groups using
+        /// both the text and binary Ion 1.1 writers, returning the result of closing each one.
+        fn close_eexp_with_groups(macro_source: &str, groups: &[Vec<&str>]) -> Vec<IonResult<()>> {
+            fn close_eexp<E: crate::Encoding>(
+                encoding: E,
+                macro_source: &str,
+                groups: &[Vec<&str>],
+            ) -> IonResult<()>
+            where
+                crate::WriteConfig<E>: From<E>,
+            {
+                let mut writer = Writer::new(encoding, Vec::new())?;
+                let foo = writer.compile_macro(macro_source)?;
+                let mut eexp_writer = writer.eexp_writer(&foo)?;
+                for group in groups {
+                    let mut group_writer = eexp_writer.expr_group_writer()?;
+                    group_writer.write_all(group.iter())?;
+                    group_writer.close()?;
+                }
+                eexp_writer.close()
+            }
+            vec![
+                close_eexp(v1_1::Text, macro_source, groups),
+                close_eexp(v1_1::Binary, macro_source, groups),
+            ]
+        }
+
+        #[rstest]
+        #[case::missing_exactly_one("(macro foo (x) (%x))", vec![], "no argument was provided for required parameter 'x'")]
+        #[case::missing_one_or_more("(macro foo (x+) (%x))", vec![], "no argument was provided for required parameter 'x'")]
+        #[case::missing_after_optional("(macro foo (x? y) (%x))", vec![vec!["a"]], "no argument was provided for required parameter 'y'")]
+        #[case::zero_or_one_with_two("(macro foo (x?) (%x))", vec![vec!["a", "b"]], "contained 2 expressions; its cardinality is zero-or-one")]
+        #[case::empty_one_or_more("(macro foo (x+) (%x))", vec![vec![]], "was empty; its cardinality is one-or-more")]
+        fn eexp_cardinality_is_enforced(
+            #[case] macro_source: &str,
+            #[case] groups: Vec<Vec<&str>>,
+            #[case] expected: &str,
+        ) {
+            for result in close_eexp_with_groups(macro_source, &groups) {
+                let error = result.expect_err("e-expression with invalid arity was accepted");
+                assert!(
+                    error.to_string().contains(expected),
+                    "unexpected error message: {error}"
+                );
+            }
+        }
+
+        #[rstest]
+        #[case::trailing_optional_omitted("(macro foo (x?) (%x))", vec![])]
+        #[case::trailing_variadic_omitted("(macro foo (x? y*) (%x))", vec![vec!["a"]])]
+        #[case::zero_or_one_with_one("(macro foo (x?) (%x))", vec![vec!["a"]])]
+        #[case::one_or_more_with_two("(macro foo (x+) (%x))", vec![vec!["a", "b"]])]
+        fn eexp_with_valid_arity_is_accepted(
+            #[case] macro_source: &str,
+            #[case] groups: Vec<Vec<&str>>,
+        ) -> IonResult<()> {
+            for result in close_eexp_with_groups(macro_source, &groups) {
+                result?;
+            }
+            Ok(())
+        }
+
+        /// Writes an e-expression invoking `macro_source` with the provided arguments using the
+        /// binary writer with each e-expression encoding, then reads each stream back and compares
+        /// its expansion to `expected`.
+        #[rstest]
+        #[case::empty_group("(macro foo (x*) [(%x)])", vec![vec![]], "[]")]
+        #[case::single_value_group("(macro foo (x*) [(%x)])", vec![vec!["a"]], "[a]")]
+        #[case::multi_value_group("(macro foo (x+) [(%x)])", vec![vec!["a", "b", "c"]], "[a, b, c]")]
+        #[case::optional("(macro foo (x? y) [(%x), (%y)])", vec![vec!["a"], vec!["b"]], "[a, b]")]
+        #[case::omitted_optional("(macro foo (x? y) [(%x), (%y)])", vec![vec![], vec!["b"]], "[b]")]
+        // Six variadic parameters need a two-byte argument encoding bitmap.
+        #[case::mixed_groups(
+            "(macro foo (a b* c+ d? e* f) [(%a), (%b), (%c), (%d), (%e), (%f)])",
+            vec![vec!["a"], vec!["b1", "b2"], vec!["c"], vec![], vec!["e1", "e2", "e3"], vec!["f"]],
+            "[a, b1, b2, c, e1, e2, e3, f]"
+        )]
+        fn expr_groups_round_trip(
+            #[case] macro_source: &str,
+            #[case] groups: Vec<Vec<&str>>,
+            #[case] expected: &str,
+        ) -> IonResult<()> {
+            use crate::lazy::encoder::value_writer_config::EExpEncoding;
+            fn write_eexp<E: crate::Encoding>(
+                config: WriteConfig<E>,
+                macro_source: &str,
+                groups: &[Vec<&str>],
+            ) -> IonResult<Sequence> {
+                let mut writer = Writer::new(config, Vec::new())?;
+                let foo = writer.compile_macro(macro_source)?;
+                let mut eexp_writer = writer.eexp_writer(&foo)?;
+                for (group, parameter) in groups.iter().zip(foo.signature().parameters()) {
+                    // Arguments for exactly-one parameters are written on their own.
+                    if !parameter.is_variadic() {
+                        eexp_writer.write_symbol(group[0])?;
+                        continue;
+                    }
+                    let mut group_writer = eexp_writer.expr_group_writer()?;
+                    for value in group {
+                        group_writer.write_symbol(*value)?;
+                    }
+                    group_writer.close()?;
+                }
+                eexp_writer.close()?;
+                let output = writer.close()?;
+                Element::read_all(output)
+            }
+            let expected = Element::read_all(expected)?;
+            for actual in [
+                write_eexp(v1_1::Binary.into(), macro_source, &groups)?,
+                write_eexp(
+                    WriteConfig::<v1_1::Binary>::new()
+                        .with_eexp_encoding(EExpEncoding::LengthPrefixed),
+                    macro_source,
+                    &groups,
+                )?,
+            ] {
+                assert_eq!(actual, expected);
+            }
+            Ok(())
+        }
+
+        /// Writes an e-expression invoking `macro_source` with `args` using both the text and
+        /// binary Ion 1.1 writers. Returns the result from each writer; if the e-expression was
+        /// written successfully, the result is the stream's values after expansion.
+        fn write_eexp_in_each_encoding(
+            macro_source: &str,
+            args: &[Element],
+        ) -> Vec<IonResult<Vec<Element>>> {
+            fn write_eexp<E: crate::Encoding>(
+                encoding: E,
+                macro_source: &str,
+                args: &[Element],
+            ) -> IonResult<Vec<Element>>
+            where
+                crate::WriteConfig<E>: From<E>,
+            {
+                let mut writer = Writer::new(encoding, Vec::new())?;
+                let foo = writer.compile_macro(macro_source)?;
+                let mut eexp_writer = writer.eexp_writer(&foo)?;
+                eexp_writer.write_all(args)?;
+                eexp_writer.close()?;
+                let output = writer.close()?;
+                Ok(Element::read_all(output)?.into_iter().collect())
+            }
+            vec![
+                write_eexp(v1_1::Text, macro_source, args),
+                write_eexp(v1_1::Binary, macro_source, args),
+            ]
+        }
+
+        #[rstest]
+        #[case::tagged("(macro foo (x) (%x))", "\"hello\"")]
+        #[case::flex_uint("(macro foo (flex_uint::x) (%x))", "5")]
+        #[case::uint8("(macro foo (uint8::x) (%x))", "100")]
+        #[case::uint64("(macro foo (uint64::x) (%x))", "1024")]
+        #[case::annotated("(macro foo (x) (%x))", "meters::5")]
+        fn text_and_binary_accept_same_arguments(
+            #[case] macro_source: &str,
+            #[case] arg: &str,
+        ) -> IonResult<()> {
+            let arg = Element::read_one(arg)?;
+            for result in write_eexp_in_each_encoding(macro_source, std::slice::from_ref(&arg)) {
+                assert_eq!(result?, vec![arg.clone()]);
+            }
+            Ok(())
+        }
+
+        #[rstest]
+        #[case::tagged_for_tagless("(macro foo (flex_uint::x) (%x))", "\"hello\"", "cannot write a string for parameter 'x'; it requires a flex_uint argument")]
+        #[case::negative_for_uint("(macro foo (uint8::x) (%x))", "-1", "error with value provided for 'x'")]
+        #[case::too_large_for_uint8("(macro foo (uint8::x) (%x))", "256", "error with value provided for 'x'")]
+        #[case::single_value_for_variadic("(macro foo (x*) (%x))", "1", "its cardinality requires an expression group")]
+        #[case::surplus("(macro foo () 1)", "1", "the macro takes no more parameters")]
+        fn text_and_binary_reject_same_arguments(
+            #[case] macro_source: &str,
+            #[case] arg: &str,
+            #[case] expected: &str,
+        ) -> IonResult<()> {
+            let arg = Element::read_one(arg)?;
+            for result in write_eexp_in_each_encoding(macro_source, &[arg]) {
+                let error = result.expect_err("invalid argument was accepted");
+                assert!(
+                    error.to_string().contains(expected),
+                    "unexpected error message: {error}"
+                );
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn text_writer_writes_tagless_arguments_as_integers() -> IonResult<()> {
+            let mut writer = Writer::new(v1_1::Text, Vec::new())?;
+            let foo = writer.compile_macro("(macro foo (flex_uint::a uint8::b) (.values (%a) (%b)))")?;
+            let mut eexp_writer = writer.eexp_writer(&foo)?;
+            assert_eq!(eexp_writer.current_parameter().map(|p| p.name()), Some("a"));
+            eexp_writer.write_flex_uint(42usize)?;
+            assert_eq!(eexp_writer.current_parameter().map(|p| p.name()), Some("b"));
+            eexp_writer.write_fixed_uint8(7)?;
+            eexp_writer.close()?;
+            let output = writer.close()?;
+            assert_eq!(Element::read_all(output)?, Element::read_all("42 7")?);
+
+            let mut writer = Writer::new(v1_1::Text, Vec::new())?;
+            let foo = writer.compile_macro("(macro foo (flex_uint::a uint8::b) (.values (%a) (%b)))")?;
+            let mut eexp_writer = writer.eexp_writer(&foo)?;
+            eexp_writer.write_flex_uint(42usize)?;
+            // `b` is a uint8 parameter, so a flex_uint argument is rejected.
+            assert!(eexp_writer.write_flex_uint(7usize).is_err());
+            Ok(())
+        }
+
         #[test]
         fn tagless_uint8_encoding() -> IonResult<()> {
             let macro_source = "(macro foo (uint8::x) (%x))";
             let expected: &[u8] = &[
                 0xE0, 0x01, 0x01, 0xEA,                       // IVM
-                0xE7, 0xF9, 0x24, 0x69, 0x6F, 0x6E,           // $ion::
-                0xFC, 0x55, 0xEE, 0x10, 0xA1, 0x5F,           // (module _
-                0xC4, 0xEE, 0x0F, 0xA1, 0x5F,                 //   (symbol_table _ )
-                0xFC, 0x3F, 0xEE, 0x0E, 0xA1, 0x5F,           //   (macro_table _
-                0xFC, 0x33,                                   //      (
-                0xA5, 0x6d, 0x61, 0x63, 0x72, 0x6F,           //        macro
-                0xA3, 0x66, 0x6F, 0x6F,                       //        foo
-                0xC9,                                         //        (
-                0xE7, 0xF7, 0x75, 0x69, 0x6E, 0x74, 0x38,     //          uint8::
-                0xA1, 0x78,                                   //          x )
-                0xC4, 0xA1, 0x25, 0xA1, 0x78,                 //        ('%' x))))
+                0xEF, 0x16,                                   // (:$ion::add_macros
+                0x01,                                         //   <single expression>
+                0xFC, 0x33,                                   //   (
+                0xA5, 0x6d, 0x61, 0x63, 0x72, 0x6F,           //     macro
+                0xA3, 0x66, 0x6F, 0x6F,                       //     foo
+                0xC9,                                         //     (
+                0xE7, 0xF7, 0x75, 0x69, 0x6E, 0x74, 0x38,     //       uint8::
+                0xA1, 0x78,                                   //       x )
+                0xC4, 0xA1, 0x25, 0xA1, 0x78,                 //     ('%' x)))
                 0x18, 0x05,                                   //  (:foo 5)
 
             ];
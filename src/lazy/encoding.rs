@@ -309,6 +309,14 @@ pub trait TextEncoding:
             let mut iterator_input = *input;
             // Confirm that the input begins with the expected opening token, consuming it in the process.
             let _head = opening_token.parse_next(&mut iterator_input)?;
+            let context = input.context;
+            let Some(_depth_guard) = context
+                .limits
+                .enter_text_container(&context.text_container_depth)
+            else {
+                let message = context.limits.depth_exceeded_message();
+                return input.invalid(message).context(label).cut();
+            };
             let iterator = make_iterator(iterator_input);
             // Bump-allocate a space to store any child expressions we encounter as we traverse this
             // container.
@@ -3,6 +3,7 @@
 use crate::constants::v1_1;
 use crate::element::iterators::SymbolsIterator;
 use crate::lazy::decoder::Decoder;
+use crate::lazy::expanded::encoding_module::ModuleBindings;
 use crate::lazy::expanded::macro_table::ION_1_1_SYSTEM_MACROS;
 use crate::lazy::expanded::template::{
     ExprRange, MacroSignature, Parameter, ParameterCardinality, ParameterEncoding,
@@ -223,7 +224,12 @@ impl TemplateCompiler {
         let mut reader = Reader::new(AnyEncoding, source)?;
         let macro_def_sexp = reader.expect_next()?.read()?.expect_sexp()?;
 
-        Self::compile_from_sexp(active_macros, &MacroTable::empty(), macro_def_sexp)
+        Self::compile_from_sexp(
+            active_macros,
+            &MacroTable::empty(),
+            &ModuleBindings::new(),
+            macro_def_sexp,
+        )
     }
 
     /// Pulls the next value from the provided source and confirms that it is a symbol whose
@@ -323,6 +329,7 @@ impl TemplateCompiler {
     fn encoding_for<Encoding: Decoder>(
         active_macros: &MacroTable,
         pending_macros: &MacroTable,
+        modules: &ModuleBindings,
         parameter: LazyValue<'_, Encoding>,
     ) -> IonResult<ParameterEncoding> {
         // * If the parameter has no annotations, it uses the default encoding.
@@ -391,12 +398,13 @@ impl TemplateCompiler {
         // At this point we know that we have a qualified name. Look it up in the active encoding
         // context.
         let (module_name, encoding_name) = (annotation1, annotation2.unwrap());
-        let macro_ref = Self::resolve_qualified_macro_id(active_macros, module_name, encoding_name)
-            .ok_or_else(|| {
-                IonError::decoding_error(format!(
-                    "unrecognized encoding '{encoding_name}' specified for parameter"
-                ))
-            })?;
+        let macro_ref =
+            Self::resolve_qualified_macro_id(active_macros, modules, module_name, encoding_name)
+                .ok_or_else(|| {
+                    IonError::decoding_error(format!(
+                        "unrecognized encoding '{encoding_name}' specified for parameter"
+                    ))
+                })?;
         Self::validate_macro_shape_for_encoding(&macro_ref)?;
         Ok(ParameterEncoding::MacroShaped(macro_ref))
     }
@@ -429,6 +437,7 @@ impl TemplateCompiler {
 
     pub fn resolve_qualified_macro_id<'a>(
         macro_table: &MacroTable,
+        modules: &ModuleBindings,
         module_name: &'a str,
         macro_id: impl Into<MacroIdRef<'a>>,
     ) -> Option<Arc<MacroDef>> {
@@ -438,13 +447,18 @@ impl TemplateCompiler {
             "$ion" => ION_1_1_SYSTEM_MACROS.clone_macro_with_id(macro_id),
             // If the module is `_`, this refers to the active encoding module.
             v1_1::constants::DEFAULT_MODULE_NAME => macro_table.clone_macro_with_id(macro_id),
-            _ => todo!("qualified references to modules other than `_` (found `{module_name}`"),
+            // Otherwise, it refers to a module bound in the encoding context.
+            _ => modules
+                .get(module_name)?
+                .macro_table()
+                .clone_macro_with_id(macro_id),
         }
     }
 
     pub fn compile_from_sexp<'a: 'b, 'b, Encoding: Decoder>(
         active_macros: &'b MacroTable,
         pending_macros: &'b MacroTable,
+        modules: &'b ModuleBindings,
         macro_def_sexp: LazySExp<'a, Encoding>,
     ) -> Result<TemplateMacro, IonError> {
         let mut values = macro_def_sexp.iter();
@@ -458,8 +472,12 @@ impl TemplateCompiler {
         // The `params` clause of the macro definition is an s-expression enumerating the parameters
         // that the macro accepts. For example: `(flex_uint::x, y*, z?)`.
         let params_clause = Self::expect_sexp("an s-expression defining parameters", &mut values)?;
-        let signature =
-            Self::compile_signature_from_sexp(active_macros, pending_macros, params_clause)?;
+        let signature = Self::compile_signature_from_sexp(
+            active_macros,
+            pending_macros,
+            modules,
+            params_clause,
+        )?;
         let body = Self::expect_next("the template body", &mut values)?;
         let expansion_analysis = Self::analyze_body_expr(body)?;
         let mut compiled_body = TemplateBody {
@@ -470,6 +488,7 @@ impl TemplateCompiler {
         let tdl_context = TdlContext {
             active_macros,
             pending_macros,
+            modules,
             signature: &signature,
         };
         Self::compile_value(
@@ -506,12 +525,18 @@ impl TemplateCompiler {
         let mut reader = Reader::new(AnyEncoding, source)?;
         let empty_macro_table = MacroTable::empty();
         let params_clause = reader.expect_next()?.read()?.expect_sexp()?;
-        Self::compile_signature_from_sexp(context.macro_table(), &empty_macro_table, params_clause)
+        Self::compile_signature_from_sexp(
+            context.macro_table(),
+            &empty_macro_table,
+            context.modules(),
+            params_clause,
+        )
     }
 
     fn compile_signature_from_sexp<D: Decoder>(
         active_macros: &MacroTable,
         pending_macros: &MacroTable,
+        modules: &ModuleBindings,
         params_clause: LazySExp<'_, D>,
     ) -> IonResult<MacroSignature> {
         let mut compiled_params = Vec::new();
@@ -527,7 +552,8 @@ impl TemplateCompiler {
         while let Some(item) = param_items.next().transpose()? {
             is_final_parameter |= param_items.peek().is_none();
             let name = Self::expect_symbol_text("a parameter name", item)?.to_owned();
-            let parameter_encoding = Self::encoding_for(active_macros, pending_macros, item)?;
+            let parameter_encoding =
+                Self::encoding_for(active_macros, pending_macros, modules, item)?;
 
             use ParameterCardinality::*;
             let mut cardinality = ExactlyOne;
@@ -1106,6 +1132,7 @@ impl TemplateCompiler {
         let maybe_macro = if let Some(module_name) = annotations.next().transpose()? {
             Self::resolve_qualified_macro_id(
                 tdl_context.active_macros,
+                tdl_context.modules,
                 module_name.expect_text()?,
                 macro_id,
             )
@@ -1456,6 +1483,8 @@ struct TdlContext<'top> {
     // Macros that were defined in the same encoding directive. They can be referenced by new
     // macros being defined, but have not yet been added to the encoding context.
     pub pending_macros: &'top MacroTable,
+    // The named modules that qualified macro names can refer to.
+    pub modules: &'top ModuleBindings,
     // The signature of the macro that owns the body expression we're compiling. If we find any
     // variables in the body expression, we can resolve them to an offset in the signature.
     pub signature: &'top MacroSignature,
@@ -5,6 +5,8 @@ use std::fmt::{Debug, Formatter};
 use std::ops::Range;
 
 use crate::element::iterators::SymbolsIterator;
+#[cfg(feature = "experimental-tooling-apis")]
+use crate::lazy::binary::raw::v1_1::e_expression::BinaryEExpArgEncoding;
 use crate::lazy::decoder::{Decoder, RawValueExpr};
 use crate::lazy::expanded::compiler::{ExpansionAnalysis, ExpansionSingleton};
 use crate::lazy::expanded::macro_evaluator::{
@@ -19,6 +21,8 @@ use crate::lazy::expanded::template::TemplateMacroRef;
 use crate::lazy::expanded::{EncodingContextRef, LazyExpandedValue};
 use crate::lazy::text::raw::v1_1::arg_group::{EExpArg, EExpArgExpr};
 use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
+#[cfg(feature = "experimental-tooling-apis")]
+use crate::v1_1;
 use crate::{try_next, try_or_some_err, Environment, HasRange, HasSpan, IonResult, Span};
 
 /// An `ArgGroup` is a collection of expressions found in e-expression argument position.
@@ -221,6 +225,16 @@ impl<'top, D: Decoder> EExpression<'top, D> {
     }
 }
 
+#[cfg(feature = "experimental-tooling-apis")]
+impl<'top> EExpression<'top, v1_1::Binary> {
+    /// Returns the decoded argument encoding bitmap of this binary Ion 1.1 e-expression: for each
+    /// of the invoked macro's parameters, whether its argument was present, empty, or an
+    /// expression group, along with the span of bytes that encoded the argument.
+    pub fn arg_encodings(&self) -> IonResult<Vec<BinaryEExpArgEncoding<'top>>> {
+        self.raw_invocation.arg_encodings()
+    }
+}
+
 impl<D: Decoder> Debug for EExpression<'_, D> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
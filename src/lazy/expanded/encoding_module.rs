@@ -1,5 +1,8 @@
+use crate::constants::v1_1;
 use crate::lazy::expanded::macro_table::MacroTable;
-use crate::SymbolTable;
+use crate::result::IonFailure;
+use crate::{IonResult, SequenceWriter, SymbolRef, SymbolTable, ValueWriter, WriteAsIon};
+use std::rc::Rc;
 
 #[derive(Debug, Clone)]
 pub struct EncodingModule {
@@ -41,3 +44,78 @@ impl EncodingModule {
         self.symbol_table = symbol_table;
     }
 }
+
+/// Writes the module as a `(module name (symbol_table [...]) (macro_table ...))` definition.
+/// Annotating the definition with `$ion` turns it into an encoding directive.
+impl WriteAsIon for EncodingModule {
+    fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        let mut module_writer = writer.sexp_writer()?;
+        module_writer
+            .write_symbol("module")?
+            .write_symbol(self.name())?;
+        let mut symbol_table_writer = module_writer.sexp_writer()?;
+        symbol_table_writer.write_symbol("symbol_table")?;
+        let mut symbols_writer = symbol_table_writer.list_writer()?;
+        for symbol in self.symbol_table.application_symbols() {
+            match symbol.text() {
+                Some(text) => symbols_writer.write_string(text)?,
+                None => symbols_writer.write_symbol(SymbolRef::with_unknown_text())?,
+            };
+        }
+        symbols_writer.close()?;
+        symbol_table_writer.close()?;
+        module_writer.write(&self.macro_table)?;
+        module_writer.close()
+    }
+}
+
+/// The named modules that are bound in an encoding context, in addition to the system module
+/// (`$ion`) and the default module (`_`).
+///
+/// Macros and symbols in a bound module can be referred to with a qualified name like
+/// `my_module::my_macro`. Binding a module whose name is already bound shadows the earlier module.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleBindings {
+    // Modules are shared by the encoding contexts (and directives being processed) that can see
+    // them, so binding a new module does not copy the others' symbol and macro tables.
+    modules: Vec<Rc<EncodingModule>>,
+}
+
+impl ModuleBindings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    /// Returns the module bound to `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&EncodingModule> {
+        self.modules
+            .iter()
+            .find(|module| module.name() == name)
+            .map(|module| module.as_ref())
+    }
+
+    /// Binds `module` to its name, shadowing any module that was previously bound to that name.
+    /// The names of the system module (`$ion`) and the default module (`_`) cannot be rebound.
+    pub fn bind(&mut self, module: EncodingModule) -> IonResult<()> {
+        let name = module.name();
+        if name == v1_1::system_symbols::ION.text() || name == v1_1::constants::DEFAULT_MODULE_NAME
+        {
+            return IonResult::decoding_error(format!("cannot bind a module to the name '{name}'"));
+        }
+        self.modules.retain(|bound| bound.name() != name);
+        self.modules.push(Rc::new(module));
+        Ok(())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &EncodingModule> {
+        self.modules.iter().map(|module| module.as_ref())
+    }
+}
@@ -32,7 +32,7 @@ use crate::lazy::expanded::LazyExpandedValue;
 use crate::lazy::expanded::{EncodingContextRef, TemplateVariableReference};
 use crate::lazy::str_ref::StrRef;
 use crate::lazy::text::raw::v1_1::arg_group::EExpArg;
-use crate::lazy::text::raw::v1_1::reader::{MacroIdLike, MacroIdRef};
+use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
 use crate::result::IonFailure;
 use crate::{
     Decimal, ExpandedValueRef, ExpandedValueSource, Int, IonError, IonResult,
@@ -96,7 +96,7 @@ where
     /// If the ID cannot be found in the `EncodingContext`, returns `Err`.
     #[inline]
     fn resolve(self, context: EncodingContextRef<'top>) -> IonResult<EExpression<'top, D>> {
-        let invoked_macro = self.id().resolve(context.macro_table())?;
+        let invoked_macro = context.resolve_macro_id(self.id())?;
         Ok(EExpression::new(self, invoked_macro))
     }
 
@@ -586,8 +586,11 @@ impl<'top, D: Decoder> MacroExpansion<'top, D> {
         use MacroExpansionKind::*;
         let context = self.context;
         let environment = self.environment;
+        let steps = context.macro_steps.get() + 1;
+        context.macro_steps.set(steps);
+        context.limits.check_macro_steps(steps)?;
         // Delegate the call to `next()` based on the macro kind.
-        match &mut self.kind {
+        let step = match &mut self.kind {
             Template(template_expansion) => template_expansion.next(context, environment),
             ExprGroup(expr_group_expansion) => expr_group_expansion.next(context, environment),
             MakeDecimal(make_decimal_expansion) => make_decimal_expansion.next(context, environment),
@@ -603,8 +606,14 @@ impl<'top, D: Decoder> MacroExpansion<'top, D> {
             Sum(sum_expansion) => sum_expansion.next(context, environment),
             // `none` is trivial and requires no delegation
             None => Ok(MacroExpansionStep::FinalStep(Option::None)),
+        }?
+        .via_variable(self.variable_ref);
+        if let Some(ValueExpr::ValueLiteral(_)) = step.value_expr() {
+            let num_values = context.macro_output.get() + 1;
+            context.macro_output.set(num_values);
+            context.limits.check_macro_output(num_values)?;
         }
-        .map(|expansion| expansion.via_variable(self.variable_ref))
+        Ok(step)
     }
 }
 
@@ -907,6 +916,8 @@ impl<'top, D: Decoder> StackedMacroEvaluator<'top, D> {
     pub fn push(&mut self, invocation: impl Into<MacroExpr<'top, D>>) -> IonResult<()> {
         let macro_expr = invocation.into();
         let expansion = macro_expr.expand()?;
+        let limits = expansion.context().limits;
+        limits.check_macro_depth(self.macro_stack.len() + 1)?;
         self.macro_stack.push(expansion);
         Ok(())
     }
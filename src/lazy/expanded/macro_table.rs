@@ -1,17 +1,22 @@
+use crate::constants::v1_1::constants::DEFAULT_MODULE_NAME;
 use crate::lazy::encoder::writer::WriterMacroTable;
 use crate::lazy::expanded::compiler::ExpansionAnalysis;
+use crate::lazy::expanded::encoding_module::ModuleBindings;
 use crate::lazy::expanded::template::{
     MacroSignature, ParameterCardinality, ParameterEncoding, SignatureIterator, TemplateBody,
     TemplateElement, TemplateMacro, TemplateMacroRef, TemplateValue,
 };
+use crate::lazy::reader::Reader;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::system_reader::SystemReader;
 use crate::lazy::text::raw::v1_1::reader::{
     MacroAddress, MacroIdRef, ModuleKind, QualifiedAddress, SystemMacroAddress,
 };
 use crate::result::IonFailure;
 use crate::{
-    AnnotatableWriter, EncodingContext, IonResult, IonType, IonVersion, SequenceWriter,
-    StructWriter, SymbolRef, TemplateBodyExpr, TemplateBodyExprKind, TemplateCompiler, ValueWriter,
-    WriteAsIon,
+    AnnotatableWriter, AnyEncoding, EncodingContext, IonResult, IonType, IonVersion, MacroFormat,
+    SequenceWriter, StructWriter, SymbolRef, TemplateBodyExpr, TemplateBodyExprKind,
+    TemplateCompiler, ValueWriter, WriteAsIon,
 };
 use compact_str::CompactString;
 use delegate::delegate;
@@ -135,27 +140,70 @@ impl MacroDef {
 
 impl WriteAsIon for TemplateMacroRef<'_> {
     fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        FormattedTemplateMacro::new(*self, MacroFormat::default()).write_as_ion(writer)
+    }
+}
+
+/// Writes a template macro's definition using the layout specified by a [`MacroFormat`].
+#[derive(Copy, Clone)]
+pub(crate) struct FormattedTemplateMacro<'a> {
+    template_macro: TemplateMacroRef<'a>,
+    format: MacroFormat,
+}
+
+impl<'a> FormattedTemplateMacro<'a> {
+    pub(crate) fn new(template_macro: TemplateMacroRef<'a>, format: MacroFormat) -> Self {
+        Self {
+            template_macro,
+            format,
+        }
+    }
+}
+
+impl WriteAsIon for FormattedTemplateMacro<'_> {
+    fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        let template_macro = self.template_macro;
+        let format = &self.format;
         let mut outer_sexp = writer.sexp_writer()?;
         outer_sexp.write_symbol("macro")?;
-        if let Some(name) = &self.name {
+        if let Some(name) = &template_macro.name {
             outer_sexp.write_symbol(name.as_str())?;
         }
         // If there isn't a name, it's an anonymous macro. Move on to writing the signature.
-        write_macro_signature_as_ion(outer_sexp.value_writer(), self.signature())?;
-        let body = self.body();
+        write_macro_signature_as_ion(
+            outer_sexp.value_writer(),
+            template_macro.signature(),
+            format,
+        )?;
+        let body = template_macro.body();
         // The first expression on the compiled 'tape' version of the body contains all of the subexpressions.
         let root_expr = body.expressions().first().expect("empty body");
         debug_assert!(root_expr.expr_range().len() == body.expressions.len());
-        write_body_expr_as_ion(outer_sexp.value_writer(), *self, root_expr)?;
+        write_body_expr_as_ion(outer_sexp.value_writer(), template_macro, root_expr, format)?;
         outer_sexp.close()
     }
 }
 
+/// Returns a writer for an s-expression with `num_args` arguments (not counting its operator),
+/// which will be written on a single line if the format calls for it.
+fn args_sexp_writer<V: ValueWriter>(
+    value_writer: V,
+    num_args: usize,
+    format: &MacroFormat,
+) -> IonResult<V::SExpWriter> {
+    if format.is_inline(num_args) {
+        value_writer.inline_sexp_writer()
+    } else {
+        value_writer.sexp_writer()
+    }
+}
+
 fn write_macro_signature_as_ion<V: ValueWriter>(
     value_writer: V,
     macro_signature: &MacroSignature,
+    format: &MacroFormat,
 ) -> IonResult<()> {
-    let mut signature = value_writer.sexp_writer()?;
+    let mut signature = args_sexp_writer(value_writer, macro_signature.len(), format)?;
     for param in macro_signature.parameters() {
         let value_writer = signature.value_writer();
         match param.encoding() {
@@ -194,16 +242,17 @@ fn write_body_expr_as_ion<V: ValueWriter>(
     value_writer: V,
     template_macro: TemplateMacroRef<'_>,
     body_expr: &TemplateBodyExpr,
+    format: &MacroFormat,
 ) -> IonResult<()> {
     use TemplateBodyExprKind::*;
     let expr_range = body_expr.expr_range();
     match body_expr.kind() {
         Element(body_element) => {
             let element = TemplateElement::new(template_macro, body_element, expr_range);
-            write_template_element_as_ion(value_writer, element)
+            write_template_element_as_ion(value_writer, element, format)
         }
         Variable(variable) => {
-            let mut sexp_writer = value_writer.sexp_writer()?;
+            let mut sexp_writer = args_sexp_writer(value_writer, 1, format)?;
             let parameter = &template_macro.signature().parameters()[variable.signature_index()];
             sexp_writer
                 .write_symbol("%")?
@@ -211,16 +260,12 @@ fn write_body_expr_as_ion<V: ValueWriter>(
             sexp_writer.close()
         }
         MacroInvocation(invocation_expr) => {
-            let mut sexp_writer = value_writer.sexp_writer()?;
             let Some(macro_name) = &invocation_expr.invoked_macro.name else {
                 // TODO: When compiling the macro, store the address of the macro invocation in the
                 //       TemplateBodyMacroInvocation along with the Arc<MacroDef>. If the macro is
                 //       anonymous, we can use the address instead.
                 todo!("serializing invocations of anonymous macros")
             };
-            sexp_writer
-                .write_symbol(".")?
-                .write_symbol(macro_name.as_str())?;
 
             // All of the expressions after the first one are arguments to the invocation.
             let macro_args_start = expr_range.start() + 1;
@@ -228,6 +273,15 @@ fn write_body_expr_as_ion<V: ValueWriter>(
             let macro_args_end = macro_args_start + num_arg_exprs;
             let arg_exprs = &template_macro.body().expressions()[macro_args_start..macro_args_end];
 
+            let mut sexp_writer = if macro_name.as_str() == "values" && !format.indents_values() {
+                value_writer.inline_sexp_writer()?
+            } else {
+                args_sexp_writer(value_writer, count_invocation_args(arg_exprs), format)?
+            };
+            sexp_writer
+                .write_symbol(".")?
+                .write_symbol(macro_name.as_str())?;
+
             let mut arg_expr_index: usize = 0;
             while arg_expr_index < num_arg_exprs {
                 let arg_expr = arg_exprs.get(arg_expr_index).unwrap_or_else(|| {
@@ -242,12 +296,22 @@ fn write_body_expr_as_ion<V: ValueWriter>(
                     && arg_expr.expr_range().end() >= macro_args_end
                 {
                     // ...then we can write all of the expressions inline, taking advantage of rest syntax.
-                    let nested_exprs = &arg_exprs[1..];
-                    write_sequence_contents(&mut sexp_writer, template_macro, nested_exprs)?;
+                    let nested_exprs = &arg_exprs[arg_expr_index + 1..];
+                    write_sequence_contents(
+                        &mut sexp_writer,
+                        template_macro,
+                        nested_exprs,
+                        format,
+                    )?;
                     arg_expr_index += arg_expr.num_expressions();
                     continue;
                 }
-                write_body_expr_as_ion(sexp_writer.value_writer(), template_macro, arg_expr)?;
+                write_body_expr_as_ion(
+                    sexp_writer.value_writer(),
+                    template_macro,
+                    arg_expr,
+                    format,
+                )?;
                 arg_expr_index += arg_expr.num_expressions();
             }
             sexp_writer.close()
@@ -256,17 +320,49 @@ fn write_body_expr_as_ion<V: ValueWriter>(
             let nested_exprs_start = expr_range.start() + 1;
             let group_end = expr_range.end();
             let expressions = &template_macro.body().expressions()[nested_exprs_start..group_end];
-            let mut sexp_writer = value_writer.sexp_writer()?;
+            let mut sexp_writer =
+                args_sexp_writer(value_writer, count_expressions(expressions), format)?;
             sexp_writer.write_symbol("..")?;
-            write_sequence_contents(&mut sexp_writer, template_macro, expressions)?;
+            write_sequence_contents(&mut sexp_writer, template_macro, expressions, format)?;
             sexp_writer.close()
         }
     }
 }
 
+/// Returns the number of expressions in `expressions` that are not nested inside another one.
+fn count_expressions(expressions: &[TemplateBodyExpr]) -> usize {
+    let mut count = 0;
+    let mut index = 0;
+    while let Some(expression) = expressions.get(index) {
+        count += 1;
+        index += expression.num_expressions();
+    }
+    count
+}
+
+/// Returns the number of arguments that `write_body_expr_as_ion` will write for a macro invocation
+/// whose argument expressions are `arg_exprs`. A trailing expression group is written using rest
+/// syntax, so each of its expressions is counted as an argument.
+fn count_invocation_args(arg_exprs: &[TemplateBodyExpr]) -> usize {
+    let mut count = 0;
+    let mut index = 0;
+    while let Some(arg_expr) = arg_exprs.get(index) {
+        let next_index = index + arg_expr.num_expressions();
+        if matches!(arg_expr.kind(), TemplateBodyExprKind::ExprGroup(_))
+            && next_index >= arg_exprs.len()
+        {
+            return count + count_expressions(&arg_exprs[index + 1..]);
+        }
+        count += 1;
+        index = next_index;
+    }
+    count
+}
+
 fn write_template_element_as_ion<V: ValueWriter>(
     value_writer: V,
     element: TemplateElement<'_>,
+    format: &MacroFormat,
 ) -> IonResult<()> {
     let annotations = element.annotations();
     use TemplateValue::*;
@@ -286,13 +382,15 @@ fn write_template_element_as_ion<V: ValueWriter>(
             value_writer.list_writer()?,
             element.template(),
             element.nested_expressions(),
+            format,
         ),
         SExp => write_template_sequence_element(
             value_writer.sexp_writer()?,
             element.template(),
             element.nested_expressions(),
+            format,
         ),
-        Struct(_field_index) => write_template_struct_element(element, value_writer),
+        Struct(_field_index) => write_template_struct_element(element, value_writer, format),
     }
 }
 
@@ -300,8 +398,9 @@ fn write_template_sequence_element<S: SequenceWriter>(
     mut sequence: S,
     template_macro: TemplateMacroRef<'_>,
     expressions: &[TemplateBodyExpr],
+    format: &MacroFormat,
 ) -> IonResult<S::Resources> {
-    write_sequence_contents(&mut sequence, template_macro, expressions)?;
+    write_sequence_contents(&mut sequence, template_macro, expressions, format)?;
     sequence.close()
 }
 
@@ -309,13 +408,19 @@ fn write_sequence_contents<S: SequenceWriter>(
     parent_writer: &mut S,
     template_macro: TemplateMacroRef<'_>,
     expressions: &[TemplateBodyExpr],
+    format: &MacroFormat,
 ) -> IonResult<()> {
     let mut expr_index: usize = 0;
     while expr_index < expressions.len() {
         let expression = expressions
             .get(expr_index)
             .expect("expr group expr out of bounds");
-        write_body_expr_as_ion(parent_writer.value_writer(), template_macro, expression)?;
+        write_body_expr_as_ion(
+            parent_writer.value_writer(),
+            template_macro,
+            expression,
+            format,
+        )?;
         expr_index += expression.num_expressions();
     }
     Ok(())
@@ -324,6 +429,7 @@ fn write_sequence_contents<S: SequenceWriter>(
 fn write_template_struct_element<V: ValueWriter>(
     element: TemplateElement<'_>,
     value_writer: V,
+    format: &MacroFormat,
 ) -> IonResult<()> {
     let mut struct_writer = value_writer.struct_writer()?;
     let mut expr_index: usize = 0;
@@ -349,6 +455,7 @@ fn write_template_struct_element<V: ValueWriter>(
             struct_writer.field_writer(name),
             element.template(),
             value_expr,
+            format,
         )?;
 
         // Move beyond the current value expression.
@@ -391,6 +498,8 @@ pub enum MacroKind {
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct MacroRef<'top> {
     qualified_address: QualifiedAddress,
+    // If the macro belongs to a named module, the name of that module. Otherwise, empty.
+    module_name: &'top str,
     def: &'top MacroDef,
 }
 
@@ -398,6 +507,20 @@ impl<'top> MacroRef<'top> {
     pub(crate) fn new(qualified_address: QualifiedAddress, def: &'top MacroDef) -> Self {
         Self {
             qualified_address,
+            module_name: "",
+            def,
+        }
+    }
+
+    /// Constructs a reference to the macro at `address` in the module bound to `module_name`.
+    pub(crate) fn in_named_module(
+        module_name: &'top str,
+        address: MacroAddress,
+        def: &'top MacroDef,
+    ) -> Self {
+        Self {
+            qualified_address: QualifiedAddress::new(ModuleKind::Named, address),
+            module_name,
             def,
         }
     }
@@ -426,6 +549,9 @@ impl<'top> MacroRef<'top> {
             ModuleKind::System => MacroIdRef::SystemAddress(SystemMacroAddress::new_unchecked(
                 self.qualified_address.address(),
             )),
+            ModuleKind::Named => {
+                MacroIdRef::QualifiedAddress(self.module_name, self.qualified_address.address())
+            }
         }
     }
 
@@ -498,11 +624,19 @@ impl Macro {
 
 /// Allows callers to resolve a macro ID (that is: name or address) to a [`MacroKind`], confirming
 /// its validity and allowing evaluation to begin.
+///
+/// A table can [extend](MacroTable::extending) an immutable table that is shared behind an
+/// [`Arc`]. The shared table's macros occupy the lowest addresses and are never copied, so many
+/// tables (including those used by readers and writers on other threads) can refer to the same
+/// compiled macros while adding their own.
 #[derive(Debug, Clone)]
 pub struct MacroTable {
-    // Stores `Rc` references to the macro definitions to make cloning the table's contents cheaper.
+    // An immutable table whose macros occupy this table's lowest addresses.
+    shared: Option<Arc<MacroTable>>,
+    // The macros that were added to this table, whose addresses follow those of the shared macros.
+    // Stores `Arc` references to the macro definitions to make cloning the table's contents cheaper.
     macros_by_address: Vec<Arc<MacroDef>>,
-    // Maps names to an address that can be used to query the Vec above.
+    // Maps the names of the macros in the Vec above to their addresses.
     macros_by_name: FxHashMap<CompactString, usize>,
 }
 
@@ -737,11 +871,20 @@ impl MacroTable {
                        )
                     "#,
             ),
-            builtin(
-                "use",
-                "(catalog_key version)",
-                MacroKind::ToDo,
-                ExpansionAnalysis::directive(),
+            template(
+                r#"
+                       (macro use (catalog_key version?)
+                           $ion::
+                           (module _
+                               // Import the shared module from the reader's catalog
+                               (import the_module (%catalog_key) (.default (%version) 1))
+                               // Append its symbols to the active encoding module symbols
+                               (symbol_table _ the_module)
+                               // Append its macros to the active encoding module macros
+                               (macro_table _ the_module)
+                           )
+                       )
+                    "#,
             ),
         ]
     }
@@ -757,6 +900,7 @@ impl MacroTable {
             // Anonymous macros are not entered into the macros_by_name lookup table
         }
         Self {
+            shared: None,
             macros_by_address: macros_by_id,
             macros_by_name,
         }
@@ -775,13 +919,44 @@ impl MacroTable {
 
     pub fn empty() -> Self {
         Self {
+            shared: None,
             macros_by_address: Vec::new(),
             macros_by_name: FxHashMap::default(),
         }
     }
 
+    /// Returns an empty table that extends `shared`. The shared table's macros are assigned the
+    /// same addresses in the new table, and macros added to the new table are assigned the
+    /// addresses that follow.
+    ///
+    /// `shared` is not copied; any number of tables can extend it.
+    pub fn extending(shared: Arc<MacroTable>) -> Self {
+        Self {
+            shared: Some(shared),
+            ..Self::empty()
+        }
+    }
+
+    /// Returns the shared table that this table extends, if any.
+    pub fn shared(&self) -> Option<&Arc<MacroTable>> {
+        self.shared.as_ref()
+    }
+
+    fn num_shared_macros(&self) -> usize {
+        self.shared.as_ref().map_or(0, |shared| shared.len())
+    }
+
+    fn macro_arc_at_address(&self, address: usize) -> Option<&Arc<MacroDef>> {
+        match &self.shared {
+            Some(shared) if address < shared.len() => shared.macro_arc_at_address(address),
+            _ => self
+                .macros_by_address
+                .get(address - self.num_shared_macros()),
+        }
+    }
+
     pub fn len(&self) -> usize {
-        self.macros_by_address.len()
+        self.num_shared_macros() + self.macros_by_address.len()
     }
 
     pub fn is_empty(&self) -> bool {
@@ -796,29 +971,47 @@ impl MacroTable {
             MacroIdRef::SystemAddress(system_address) => {
                 ION_1_1_SYSTEM_MACROS.macro_at_address(system_address.as_usize())
             }
+            MacroIdRef::QualifiedName(..) | MacroIdRef::QualifiedAddress(..) => {
+                let (module_name, id) = id.split_module()?;
+                match module_name {
+                    "$ion" => ION_1_1_SYSTEM_MACROS.macro_with_id(id),
+                    DEFAULT_MODULE_NAME => self.macro_with_id(id),
+                    // Other modules are bound in an `EncodingContext`, not in a macro table.
+                    _ => None,
+                }
+            }
         }
     }
 
     pub fn address_for_id<'a, 'b, I: Into<MacroIdRef<'b>>>(&'a self, id: I) -> Option<usize> {
         let id = id.into();
         match id {
-            MacroIdRef::LocalName(name) => self.macros_by_name.get(name).copied(),
-            MacroIdRef::LocalAddress(address) if address >= self.macros_by_address.len() => None,
+            MacroIdRef::LocalName(name) => self.address_for_name(name),
+            MacroIdRef::LocalAddress(address) if address >= self.len() => None,
             MacroIdRef::LocalAddress(address) => Some(address),
             // If they're asking the user table for a system address, report that we couldn't find it.
             // TODO: Replace this enum variant with a `QualifiedAddress`.
             // We cannot look the macro up in the system macro table because the meaning of the
             // returned address would be ambiguous—into which macro table does the `usize` index?
             MacroIdRef::SystemAddress(_system_address) => None,
+            // For the same reason, only qualified IDs in the default module are supported.
+            MacroIdRef::QualifiedName(DEFAULT_MODULE_NAME, name) => self.address_for_id(name),
+            MacroIdRef::QualifiedAddress(DEFAULT_MODULE_NAME, address) => {
+                self.address_for_id(address)
+            }
+            MacroIdRef::QualifiedName(..) | MacroIdRef::QualifiedAddress(..) => None,
         }
     }
 
     pub fn macro_at_address(&self, address: usize) -> Option<&MacroDef> {
-        Some(self.macros_by_address.get(address)?)
+        Some(self.macro_arc_at_address(address)?)
     }
 
     pub fn address_for_name(&self, name: &str) -> Option<usize> {
-        self.macros_by_name.get(name).copied()
+        match self.macros_by_name.get(name) {
+            Some(address) => Some(*address),
+            None => self.shared.as_ref()?.address_for_name(name),
+        }
     }
 
     pub fn macro_with_name(&self, name: &str) -> Option<&MacroDef> {
@@ -827,13 +1020,12 @@ impl MacroTable {
     }
 
     pub(crate) fn clone_macro_with_name(&self, name: &str) -> Option<Arc<MacroDef>> {
-        let address = *self.macros_by_name.get(name)?;
-        let reference = self.macros_by_address.get(address)?;
-        Some(Arc::clone(reference))
+        let address = self.address_for_name(name)?;
+        self.clone_macro_with_address(address)
     }
 
     pub(crate) fn clone_macro_with_address(&self, address: usize) -> Option<Arc<MacroDef>> {
-        let reference = self.macros_by_address.get(address)?;
+        let reference = self.macro_arc_at_address(address)?;
         Some(Arc::clone(reference))
     }
 
@@ -845,14 +1037,22 @@ impl MacroTable {
             SystemAddress(system_address) => {
                 ION_1_1_SYSTEM_MACROS.clone_macro_with_address(system_address.as_usize())
             }
+            QualifiedName(..) | QualifiedAddress(..) => {
+                let (module_name, id) = macro_id.split_module()?;
+                match module_name {
+                    "$ion" => ION_1_1_SYSTEM_MACROS.clone_macro_with_id(id),
+                    DEFAULT_MODULE_NAME => self.clone_macro_with_id(id),
+                    _ => None,
+                }
+            }
         }
     }
 
     pub fn add_template_macro(&mut self, template: TemplateMacro) -> IonResult<usize> {
-        let id = self.macros_by_address.len();
+        let id = self.len();
         // If the macro has a name, make sure that name is not already in use and then add it.
         if let Some(name) = &template.name {
-            if self.macros_by_name.contains_key(name.as_str()) {
+            if self.address_for_name(name.as_str()).is_some() {
                 return IonResult::decoding_error(format!("macro named '{name}' already exists"));
             }
             self.macros_by_name.insert(name.clone(), id);
@@ -872,7 +1072,7 @@ impl MacroTable {
     pub(crate) fn append_macro(&mut self, macro_ref: &Arc<MacroDef>) -> IonResult<()> {
         let next_id = self.len();
         if let Some(name) = macro_ref.clone_name() {
-            if self.macros_by_name.contains_key(name.as_str()) {
+            if self.address_for_name(name.as_str()).is_some() {
                 return IonResult::decoding_error(format!("macro named '{name}' already exists"));
             }
             self.macros_by_name.insert(name, next_id);
@@ -882,18 +1082,44 @@ impl MacroTable {
     }
 
     pub(crate) fn append_all_macros_from(&mut self, other: &MacroTable) -> IonResult<()> {
-        for macro_ref in &other.macros_by_address {
-            self.append_macro(macro_ref)?
+        for address in 0..other.len() {
+            self.append_macro(other.macro_arc_at_address(address).unwrap())?
         }
         Ok(())
     }
 
     pub(crate) fn reset_to_system_macros(&mut self) {
+        self.shared = None;
         self.macros_by_name.clear();
         self.macros_by_address.clear();
         self.append_all_macros_from(&ION_1_1_SYSTEM_MACROS).unwrap()
     }
 
+    /// Removes the macros at or above address `new_len`.
+    pub(crate) fn truncate(&mut self, new_len: usize) {
+        if new_len >= self.len() {
+            return;
+        }
+        let num_shared_macros = self.num_shared_macros();
+        if new_len < num_shared_macros {
+            // The shared table cannot be modified. Copy the macros that remain into this table.
+            let remaining_macros = Vec::from_iter(
+                (0..new_len).map(|address| Arc::clone(self.macro_arc_at_address(address).unwrap())),
+            );
+            *self = Self::empty();
+            for macro_ref in &remaining_macros {
+                self.append_macro(macro_ref)
+                    .expect("macros from a valid table have unique names");
+            }
+            return;
+        }
+        self.macros_by_address.truncate(new_len - num_shared_macros);
+        self.macros_by_name
+            .retain(|_name, address| *address < new_len);
+    }
+
+    /// Returns the last `num_tail_macros` macros that were added to this table. They cannot
+    /// include macros from the shared table that it extends.
     pub(crate) fn macros_tail(&self, num_tail_macros: usize) -> &[Arc<MacroDef>] {
         let num_macros = self.macros_by_address.len();
         &self.macros_by_address[num_macros - num_tail_macros..]
@@ -902,9 +1128,8 @@ impl MacroTable {
     // This method only exists to support the `ion_tests` feature.
     // See: https://github.com/amazon-ion/ion-rust/issues/967
     pub fn iter(&self) -> impl Iterator<Item = Macro> + '_ {
-        self.macros_by_address
-            .iter()
-            .enumerate()
+        (0..self.len())
+            .map(move |index| (index, self.macro_arc_at_address(index).unwrap()))
             .map(move |(index, macro_def)| {
                 Macro::new(
                     Arc::clone(macro_def),
@@ -915,16 +1140,77 @@ impl MacroTable {
                 )
             })
     }
+
+    /// If this table begins with the Ion 1.1 system macros, returns the number of system macros.
+    /// Otherwise, returns `0`.
+    fn num_leading_system_macros(&self) -> usize {
+        let system_macros = &*ION_1_1_SYSTEM_MACROS;
+        let starts_with_system_macros = self.len() >= system_macros.len()
+            && (0..system_macros.len()).all(|address| {
+                Arc::ptr_eq(
+                    self.macro_arc_at_address(address).unwrap(),
+                    system_macros.macro_arc_at_address(address).unwrap(),
+                )
+            });
+        if starts_with_system_macros {
+            system_macros.len()
+        } else {
+            0
+        }
+    }
+
+    /// Reads a macro table from a `(macro_table ...)` clause, which is the first value in `input`.
+    ///
+    /// This is the inverse of the table's [`WriteAsIon`] implementation. Macro definitions can
+    /// invoke the system macros by name only if `input` is an Ion 1.1 stream, so tables that were
+    /// written with [`encode_as`](WriteAsIon::encode_as) should use an Ion 1.1 encoding.
+    pub fn from_ion<Input: IonInput>(input: Input) -> IonResult<MacroTable> {
+        let mut reader = Reader::new(AnyEncoding, input)?;
+        let clause = reader.expect_next()?.read()?.expect_sexp()?;
+        SystemReader::<AnyEncoding, Input>::process_macro_table_definition(
+            clause,
+            &ModuleBindings::new(),
+        )
+    }
+}
+
+/// Writes the table as a `(macro_table ...)` clause, which can be used in an encoding directive or
+/// read back with [`MacroTable::from_ion`].
+///
+/// If the table begins with the system macros, the clause refers to them as `$ion` instead of
+/// writing their definitions. Any other macro must be a template macro.
+impl WriteAsIon for MacroTable {
+    fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        let mut sexp_writer = writer.sexp_writer()?;
+        sexp_writer.write_symbol("macro_table")?;
+        let num_system_macros = self.num_leading_system_macros();
+        if num_system_macros > 0 {
+            sexp_writer.write_symbol("$ion")?;
+        }
+        for address in num_system_macros..self.len() {
+            let macro_def = self.macro_arc_at_address(address).unwrap();
+            let MacroKind::Template(body) = macro_def.kind() else {
+                return IonResult::encoding_error(format!(
+                    "cannot write the definition of non-template macro {:?} as Ion",
+                    macro_def.name().unwrap_or("<anonymous>")
+                ));
+            };
+            sexp_writer.write(TemplateMacroRef::new(macro_def, body))?;
+        }
+        sexp_writer.close()
+    }
 }
 
 #[cfg(all(test, feature = "experimental-ion-1-1"))]
 mod tests {
+    use crate::lazy::expanded::macro_table::ION_1_1_SYSTEM_MACROS;
     use crate::lazy::expanded::template::TemplateMacroRef;
     use crate::{
-        v1_1, Element, EncodingContext, IonResult, IonVersion, MacroDef, TemplateCompiler,
-        WriteAsIon,
+        v1_1, AnyEncoding, Element, EncodingContext, IonResult, IonVersion, MacroDef, MacroTable,
+        MapCatalog, ReadConfig, Reader, SharedSymbolTable, TemplateCompiler, WriteAsIon, Writer,
     };
     use rstest::rstest;
+    use std::sync::Arc;
 
     fn serialization_test(macro_source: &str) -> IonResult<()> {
         // Read the macro source directly to get the expected Ion.
@@ -974,4 +1260,202 @@ mod tests {
     fn serialize_template_macro(#[case] macro_source: &str) -> IonResult<()> {
         serialization_test(macro_source)
     }
+
+    #[test]
+    fn macro_table_round_trip() -> IonResult<()> {
+        let mut macro_table = MacroTable::with_system_macros(IonVersion::v1_1);
+        let greet = TemplateCompiler::compile_from_source(
+            &macro_table,
+            r#"(macro greet (name) (.make_string "Hello, " (%name)))"#,
+        )?;
+        macro_table.add_template_macro(greet)?;
+        let pi = TemplateCompiler::compile_from_source(&macro_table, "(macro pi () 3.14159)")?;
+        macro_table.add_template_macro(pi)?;
+
+        let encoded_text = macro_table.encode_as(v1_1::Text)?;
+        // The system macros are included by name rather than by definition.
+        assert!(encoded_text.contains("(macro_table $ion (macro greet"));
+        let encoded_binary = macro_table.encode_as(v1_1::Binary)?;
+        for encoded in [encoded_text.as_bytes(), encoded_binary.as_slice()] {
+            let actual = MacroTable::from_ion(encoded)?;
+            assert_eq!(actual.len(), macro_table.len());
+            assert_eq!(
+                actual.num_leading_system_macros(),
+                MacroTable::NUM_SYSTEM_MACROS
+            );
+            assert_eq!(
+                actual.address_for_name("greet"),
+                Some(MacroTable::FIRST_USER_MACRO_ID)
+            );
+            assert_eq!(
+                actual.address_for_name("pi"),
+                Some(MacroTable::FIRST_USER_MACRO_ID + 1)
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn encoding_context_round_trip() -> IonResult<()> {
+        let context = EncodingContext::from_directives(
+            r#"
+                $ion_1_1
+                $ion::(module m (macro_table (macro seventeen () 17)))
+                $ion::
+                (module _
+                    (symbol_table ["foo", "bar"])
+                    (macro_table $ion (macro greet (name) (.make_string "Hello, " (%name)))))
+            "#,
+        )?;
+        assert_eq!(context.symbol_table().application_symbols().len(), 2);
+        assert_eq!(
+            context.macro_table().len(),
+            MacroTable::NUM_SYSTEM_MACROS + 1
+        );
+        assert!(context.modules().get("m").is_some());
+
+        let mut writer = Writer::new(v1_1::Text, Vec::new())?;
+        context.write_directives(&mut writer)?;
+        let encoded = String::from_utf8(writer.close()?).unwrap();
+
+        // The directives can be read back on their own...
+        let restored = EncodingContext::from_directives(encoded.as_str())?;
+        assert_eq!(
+            restored.symbol_table().application_symbols(),
+            context.symbol_table().application_symbols()
+        );
+        assert_eq!(restored.macro_table().len(), context.macro_table().len());
+        assert!(restored.modules().get("m").is_some());
+
+        // ...or used as the beginning of a stream whose values depend on them.
+        let actual = Element::read_all(format!(
+            r#"{encoded} $1 (:greet "Waldo") (:m::seventeen) $2"#
+        ))?;
+        let expected = Element::read_all(r#"foo "Hello, Waldo" 17 bar"#)?;
+        assert_eq!(actual, expected);
+
+        // Application values are not allowed.
+        assert!(EncodingContext::from_directives("$ion_1_1 1").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn encoding_context_builder() -> IonResult<()> {
+        let shared_table = SharedSymbolTable::new("shared", 1, ["foo", "bar"])?;
+        let context = EncodingContext::builder(IonVersion::v1_1)
+            .with_symbols(["baz"])
+            .with_import(&shared_table)
+            .with_macro_source(r#"(macro greet (name) (.make_string "Hello, " (%name)))"#)
+            .with_macro_source(r#"(macro greet_waldo () (.greet "Waldo"))"#)
+            .build()?;
+        // The symbols follow the system symbols, as they would at the beginning of a stream.
+        let symbol_table = context.symbol_table();
+        let bar_sid = symbol_table.len() - 1;
+        assert_eq!(symbol_table.symbols_tail(3), &["baz", "foo", "bar"]);
+        assert_eq!(
+            context.macro_table().address_for_name("greet_waldo"),
+            Some(MacroTable::FIRST_USER_MACRO_ID + 1)
+        );
+
+        // The context is equivalent to the directives it writes.
+        let mut writer = Writer::new(v1_1::Text, Vec::new())?;
+        context.write_directives(&mut writer)?;
+        let encoded = String::from_utf8(writer.close()?).unwrap();
+        let actual = Element::read_all(format!("{encoded} ${bar_sid} (:greet_waldo)"))?;
+        assert_eq!(actual, Element::read_all(r#"bar "Hello, Waldo""#)?);
+
+        // Each macro source must be a valid definition that only refers to earlier macros.
+        let builder = EncodingContext::builder(IonVersion::v1_1);
+        assert!(builder
+            .clone()
+            .with_macro_source("(macro)")
+            .build()
+            .is_err());
+        assert!(builder
+            .with_macro_source("(macro greet_waldo () (.greet \"Waldo\"))")
+            .with_macro_source("(macro greet (name) (%name))")
+            .build()
+            .is_err());
+        // Ion 1.0 does not support macros.
+        assert!(EncodingContext::builder(IonVersion::v1_0)
+            .with_macro_source("(macro seventeen () 17)")
+            .build()
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn tables_can_extend_a_shared_table() -> IonResult<()> {
+        let mut shared = MacroTable::with_system_macros(IonVersion::v1_1);
+        let greet = TemplateCompiler::compile_from_source(
+            &shared,
+            r#"(macro greet (name) (.make_string "Hello, " (%name)))"#,
+        )?;
+        let greet_address = shared.add_template_macro(greet)?;
+        let shared = Arc::new(shared);
+
+        let threads = Vec::from_iter((0..4).map(|thread_number| {
+            let shared = Arc::clone(&shared);
+            std::thread::spawn(move || -> IonResult<()> {
+                let mut table = MacroTable::extending(Arc::clone(&shared));
+                let local = TemplateCompiler::compile_from_source(
+                    &table,
+                    format!("(macro local () (.greet {thread_number}))"),
+                )?;
+                let local_address = table.add_template_macro(local)?;
+                assert_eq!(local_address, shared.len());
+                assert_eq!(table.len(), shared.len() + 1);
+                // The shared macros are not copied.
+                assert!(Arc::ptr_eq(
+                    &table.clone_macro_with_name("greet").unwrap(),
+                    &shared.clone_macro_with_name("greet").unwrap()
+                ));
+                assert_eq!(table.address_for_name("greet"), Some(greet_address));
+                assert_eq!(table.address_for_name("local"), Some(local_address));
+                // Names in the shared table cannot be reused.
+                let duplicate =
+                    TemplateCompiler::compile_from_source(&table, "(macro greet () 1)")?;
+                assert!(table.add_template_macro(duplicate).is_err());
+                // Truncating the table can remove shared macros without modifying the shared table.
+                table.truncate(greet_address);
+                assert_eq!(table.len(), greet_address);
+                assert!(table.macro_with_name("greet").is_none());
+                assert!(shared.macro_with_name("greet").is_some());
+                Ok(())
+            })
+        }));
+        for thread in threads {
+            thread.join().unwrap()?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn readers_on_several_threads_can_share_a_catalog() -> IonResult<()> {
+        let mut shared_macros = MacroTable::empty();
+        let greet = TemplateCompiler::compile_from_source(
+            &ION_1_1_SYSTEM_MACROS,
+            r#"(macro greet (name) (.make_string "Hello, " (%name)))"#,
+        )?;
+        shared_macros.add_template_macro(greet)?;
+        let mut catalog = MapCatalog::new();
+        catalog.insert_macro_table("greetings", 1, shared_macros);
+        let catalog = Arc::new(catalog);
+
+        let threads = Vec::from_iter((0..4).map(|thread_number| {
+            let catalog = Arc::clone(&catalog);
+            std::thread::spawn(move || -> IonResult<()> {
+                let input = format!("$ion_1_1 (:use greetings) (:greet \"{thread_number}\")");
+                let config = ReadConfig::new_with_catalog(AnyEncoding, catalog);
+                let mut reader = Reader::new(config, input)?;
+                let greeting = reader.expect_next()?.read()?.expect_string()?.to_owned();
+                assert_eq!(greeting, format!("Hello, {thread_number}"));
+                Ok(())
+            })
+        }));
+        for thread in threads {
+            thread.join().unwrap()?;
+        }
+        Ok(())
+    }
 }
@@ -38,7 +38,9 @@ use std::cell::{Cell, UnsafeCell};
 use std::fmt::{Debug, Formatter};
 use std::ops::{Deref, Range};
 use std::rc::Rc;
+use std::sync::Arc;
 
+use crate::constants::v1_1;
 use crate::element::iterators::SymbolsIterator;
 use crate::lazy::any_encoding::{IonEncoding, IonVersion};
 use crate::lazy::bytes_ref::BytesRef;
@@ -46,27 +48,34 @@ use crate::lazy::decoder::{Decoder, LazyRawValue};
 use crate::lazy::encoding::RawValueLiteral;
 use crate::lazy::expanded::compiler::TemplateCompiler;
 use crate::lazy::expanded::e_expression::EExpression;
+use crate::lazy::expanded::encoding_module::{EncodingModule, ModuleBindings};
 use crate::lazy::expanded::macro_evaluator::{MacroEvaluator, RawEExpression};
-use crate::lazy::expanded::macro_table::{MacroDef, MacroTable};
+use crate::lazy::expanded::macro_table::{MacroDef, MacroRef, MacroTable, ION_1_1_SYSTEM_MACROS};
 use crate::lazy::expanded::r#struct::LazyExpandedStruct;
 use crate::lazy::expanded::sequence::Environment;
 use crate::lazy::expanded::template::{TemplateElement, TemplateMacro, TemplateValue};
 use crate::lazy::r#struct::LazyStruct;
 use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem};
 use crate::lazy::raw_value_ref::RawValueRef;
+use crate::lazy::reader_limits::ReaderLimits;
+use crate::lazy::reader_state::ReaderState;
 use crate::lazy::sequence::{LazyList, LazySExp};
 use crate::lazy::str_ref::StrRef;
-use crate::lazy::streaming_raw_reader::{IoBuffer, IoBufferHandle, IonInput, StreamingRawReader};
+use crate::lazy::streaming_raw_reader::{
+    IoBuffer, IoBufferHandle, IonInput, IonSlice, StreamingRawReader,
+};
 use crate::lazy::system_reader::{PendingContextChanges, SystemReader};
 use crate::lazy::system_stream_item::SystemStreamItem;
-use crate::lazy::text::raw::v1_1::reader::MacroAddress;
+use crate::lazy::table_growth::TableGrowth;
+use crate::lazy::text::raw::v1_1::reader::{MacroAddress, MacroIdLike, MacroIdRef};
 use crate::lazy::value::LazyValue;
 use crate::location::SourceLocation;
 use crate::raw_symbol_ref::AsRawSymbolRef;
 use crate::result::IonFailure;
 use crate::{
-    Catalog, Decimal, HasRange, HasSpan, Int, IonResult, IonType, RawStreamItem, RawSymbolRef,
-    RawVersionMarker, Span, SymbolRef, SymbolTable, Timestamp, ValueRef,
+    AnnotatableWriter, AnyEncoding, Catalog, Decimal, HasRange, HasSpan, Int, IonError, IonResult,
+    IonType, LeapSecondHandling, RawStreamItem, RawSymbolRef, RawVersionMarker, SequenceWriter,
+    SharedSymbolTable, Span, Symbol, SymbolRef, SymbolTable, Timestamp, ValueRef, ValueWriter,
 };
 
 // All of these modules (and most of their types) are currently `pub` as the lazy reader is gated
@@ -121,9 +130,48 @@ pub struct EncodingContext {
     //      but the macro table does not, the two values can still point to the same macro table instance.
     pub(crate) macro_table: Rc<MacroTable>,
     pub(crate) symbol_table: Rc<SymbolTable>,
+    // The named modules (other than `$ion` and `_`) that qualified names can refer to.
+    pub(crate) modules: Rc<ModuleBindings>,
     pub(crate) allocator: Rc<BumpAllocator>,
 
     pub(crate) io_buffer_source: UnsafeCell<IoBufferSource>,
+    // If `true`, text readers will only identify the extent of each timestamp, leaving its
+    // components to be validated when (and if) the timestamp is read.
+    pub(crate) defer_timestamp_parsing: bool,
+    // How readers handle timestamps whose seconds field is `60`.
+    pub(crate) leap_second_handling: LeapSecondHandling,
+    // If `true`, text readers reject escape sequences that encode UTF-16 surrogates.
+    pub(crate) strict_utf8: bool,
+    // The resources that the reader will commit to the stream.
+    pub(crate) limits: ReaderLimits,
+    // The number of macro evaluation steps taken since the last top-level expression began.
+    pub(crate) macro_steps: Cell<usize>,
+    // The number of values produced by macro expansions since the last top-level expression began.
+    pub(crate) macro_output: Cell<usize>,
+    // The number of text containers (and e-expressions) that the text parser is currently nested
+    // within.
+    pub(crate) text_container_depth: Cell<usize>,
+}
+
+impl EncodingContext {
+    /// Returns a copy of this context that shares its tables and allocator but does not refer to
+    /// any reader's input. Unlike [`Clone::clone`], this does not copy the current input buffer.
+    pub(crate) fn clone_without_input(&self) -> Self {
+        Self {
+            macro_table: self.macro_table.clone(),
+            symbol_table: self.symbol_table.clone(),
+            modules: self.modules.clone(),
+            allocator: self.allocator.clone(),
+            io_buffer_source: IoBufferSource::None.into(),
+            defer_timestamp_parsing: self.defer_timestamp_parsing,
+            leap_second_handling: self.leap_second_handling,
+            strict_utf8: self.strict_utf8,
+            limits: self.limits,
+            macro_steps: Cell::new(0),
+            macro_output: Cell::new(0),
+            text_container_depth: Cell::new(0),
+        }
+    }
 }
 
 impl Clone for EncodingContext {
@@ -134,12 +182,127 @@ impl Clone for EncodingContext {
         Self {
             macro_table: self.macro_table.clone(),
             symbol_table: self.symbol_table.clone(),
+            modules: self.modules.clone(),
             allocator: self.allocator.clone(),
             io_buffer_source: IoBufferSource::IoBuffer(io_buffer).into(),
+            defer_timestamp_parsing: self.defer_timestamp_parsing,
+            leap_second_handling: self.leap_second_handling,
+            strict_utf8: self.strict_utf8,
+            limits: self.limits,
+            macro_steps: Cell::new(0),
+            macro_output: Cell::new(0),
+            text_container_depth: Cell::new(0),
         }
     }
 }
 
+/// Constructs an [`EncodingContext`] whose symbol and macro tables are preloaded, for use with
+/// raw readers and writers in tests and tools. See [`EncodingContext::builder`].
+///
+/// Symbols (including those imported from shared symbol tables) are added to the symbol table in
+/// the order they are provided, following the system symbols. Macros are compiled from TDL source
+/// when [`build`](Self::build) is called; each macro can invoke the macros defined before it.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{EncodingContext, IonVersion, SharedSymbolTable};
+///
+/// let shared_table = SharedSymbolTable::new("greetings", 1, ["hello", "goodbye"])?;
+/// let context = EncodingContext::builder(IonVersion::v1_1)
+///     .with_import(&shared_table)
+///     .with_symbols(["world"])
+///     .with_macro_source("(macro greet (name) (.make_string hello \" \" (%name)))")
+///     .with_macro_source("(macro greet_world () (.greet world))")
+///     .build()?;
+///
+/// let symbol_table = context.symbol_table();
+/// let first_application_sid = symbol_table.len() - 3;
+/// assert_eq!(symbol_table.sid_for("hello"), Some(first_application_sid));
+/// assert_eq!(symbol_table.sid_for("world"), Some(first_application_sid + 2));
+/// assert!(context.macro_table().macro_with_id("greet_world").is_some());
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct EncodingContextBuilder {
+    ion_version: IonVersion,
+    symbols: Vec<Symbol>,
+    macro_sources: Vec<String>,
+}
+
+impl EncodingContextBuilder {
+    /// Returns a builder for a context containing only the system symbols and macros of
+    /// `ion_version`.
+    pub fn new(ion_version: IonVersion) -> Self {
+        Self {
+            ion_version,
+            symbols: Vec::new(),
+            macro_sources: Vec::new(),
+        }
+    }
+
+    /// Appends each of the given symbols to the symbol table.
+    pub fn with_symbols<S: Into<Symbol>>(mut self, symbols: impl IntoIterator<Item = S>) -> Self {
+        self.symbols.extend(symbols.into_iter().map(Into::into));
+        self
+    }
+
+    /// Appends the symbols in `shared_table` to the symbol table, as an import of the table would.
+    pub fn with_import(mut self, shared_table: &SharedSymbolTable) -> Self {
+        self.symbols.extend_from_slice(shared_table.symbols());
+        self
+    }
+
+    /// Adds the macro defined by `source`, a TDL `(macro name (parameters...) body)` expression,
+    /// to the macro table.
+    pub fn with_macro_source(mut self, source: impl Into<String>) -> Self {
+        self.macro_sources.push(source.into());
+        self
+    }
+
+    /// Constructs the context, compiling each of the macro sources in the order they were added.
+    ///
+    /// Returns an error if a macro source is not a valid macro definition or defines a name that
+    /// is already in use, or if macros were added to an Ion 1.0 context.
+    pub fn build(self) -> IonResult<EncodingContext> {
+        if self.ion_version == IonVersion::v1_0 && !self.macro_sources.is_empty() {
+            return IonResult::illegal_operation("Ion 1.0 encoding contexts cannot hold macros");
+        }
+        let mut context = EncodingContext::for_ion_version(self.ion_version);
+        let symbol_table = Rc::make_mut(&mut context.symbol_table);
+        for symbol in self.symbols {
+            symbol_table.add_symbol(symbol);
+        }
+        for source in &self.macro_sources {
+            context.register_template_src(source)?;
+        }
+        Ok(context)
+    }
+}
+
+/// The state of an [`EncodingContext`] at a point in time, recorded by
+/// [`EncodingContext::snapshot`].
+///
+/// Symbol tables and macro tables only grow between encoding directives that replace them, so a
+/// snapshot only needs to remember how long each table was. A reader that processes a series of
+/// independent requests can take a snapshot after its shared setup and restore it after each
+/// request, preventing symbols and macros from one request from being visible in the next.
+#[derive(Debug, Clone)]
+pub struct EncodingContextSnapshot {
+    ion_version: IonVersion,
+    symbol_table_len: usize,
+    // The last symbol and macro in each table, which `restore` uses to confirm that the tables
+    // were appended to rather than replaced.
+    last_symbol: Option<Symbol>,
+    macro_table_len: usize,
+    last_macro: Option<Arc<MacroDef>>,
+    modules: Rc<ModuleBindings>,
+}
+
 impl Debug for EncodingContext {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let symbol_table = self.symbol_table();
@@ -159,8 +322,16 @@ impl EncodingContext {
         Self {
             macro_table: Rc::new(macro_table),
             symbol_table: Rc::new(symbol_table),
+            modules: Rc::new(ModuleBindings::new()),
             allocator: Rc::new(allocator),
             io_buffer_source: IoBufferSource::None.into(),
+            defer_timestamp_parsing: false,
+            leap_second_handling: LeapSecondHandling::Reject,
+            strict_utf8: false,
+            limits: ReaderLimits::new(),
+            macro_steps: Cell::new(0),
+            macro_output: Cell::new(0),
+            text_container_depth: Cell::new(0),
         }
     }
 
@@ -177,10 +348,132 @@ impl EncodingContext {
         )
     }
 
+    /// Returns a builder for a context with preloaded symbols and macros. See
+    /// [`EncodingContextBuilder`].
+    pub fn builder(ion_version: IonVersion) -> EncodingContextBuilder {
+        EncodingContextBuilder::new(ion_version)
+    }
+
     pub fn get_ref(&self) -> EncodingContextRef<'_> {
         EncodingContextRef { context: self }
     }
 
+    /// Writes Ion 1.1 encoding directives that recreate this context's named modules, symbol
+    /// table, and macro table.
+    ///
+    /// Each named module is written as a `$ion::(module name ...)` directive, followed by a
+    /// `$ion::(module _ ...)` directive that defines the default module. Writing them at the
+    /// beginning of an Ion 1.1 stream gives readers of that stream the same context, and
+    /// [`EncodingContext::from_directives`] can read them back on their own.
+    pub fn write_directives<W: SequenceWriter>(&self, writer: &mut W) -> IonResult<()> {
+        for module in self.modules.iter() {
+            writer
+                .value_writer()
+                .with_annotations("$ion")?
+                .write(module)?;
+        }
+        let default_module = EncodingModule::new(
+            v1_1::constants::DEFAULT_MODULE_NAME.to_owned(),
+            self.macro_table().clone(),
+            self.symbol_table().clone(),
+        );
+        writer
+            .value_writer()
+            .with_annotations("$ion")?
+            .write(&default_module)?;
+        Ok(())
+    }
+
+    /// Reads a stream of encoding directives, like those written by
+    /// [`EncodingContext::write_directives`], and returns the encoding context that they define.
+    ///
+    /// Returns an error if the stream contains any application values.
+    pub fn from_directives<Input: IonInput>(input: Input) -> IonResult<Self> {
+        let mut reader = SystemReader::new(AnyEncoding, input);
+        loop {
+            match reader.next_item()? {
+                SystemStreamItem::EndOfStream(_) => break,
+                SystemStreamItem::Value(value) => {
+                    return IonResult::decoding_error(format!(
+                        "expected only encoding directives, but found a(n) {} value",
+                        value.ion_type()
+                    ))
+                }
+                _ => {}
+            }
+        }
+        let context = reader.expanding_reader.context();
+        let mut new_context = Self::new(
+            context.macro_table().clone(),
+            context.symbol_table().clone(),
+            BumpAllocator::new(),
+        );
+        new_context.modules = Rc::new(context.modules().clone());
+        Ok(new_context)
+    }
+
+    /// Records the current lengths of the symbol table and macro table, along with the bound
+    /// modules, so that [`EncodingContext::restore`] can later roll the context back to this state.
+    ///
+    /// Taking a snapshot does not copy either table.
+    pub fn snapshot(&self) -> EncodingContextSnapshot {
+        EncodingContextSnapshot {
+            ion_version: self.symbol_table.ion_version(),
+            symbol_table_len: self.symbol_table.len(),
+            last_symbol: self.symbol_table.symbols().last().cloned(),
+            macro_table_len: self.macro_table.len(),
+            last_macro: self
+                .macro_table
+                .len()
+                .checked_sub(1)
+                .and_then(|address| self.macro_table.clone_macro_with_address(address)),
+            modules: Rc::clone(&self.modules),
+        }
+    }
+
+    /// Rolls the context back to the state recorded in `snapshot`, discarding any symbols and
+    /// macros that were appended since it was taken and restoring the module bindings.
+    ///
+    /// Returns an error if the symbol table or macro table no longer begins with the contents it
+    /// had when the snapshot was taken; for example, if an encoding directive replaced it.
+    pub fn restore(&mut self, snapshot: &EncodingContextSnapshot) -> IonResult<()> {
+        if self.symbol_table.ion_version() != snapshot.ion_version {
+            return IonResult::illegal_operation(
+                "cannot restore the encoding context; its Ion version has changed since the snapshot was taken",
+            );
+        }
+        let symbols = self.symbol_table.symbols();
+        let symbols_were_kept = symbols.len() >= snapshot.symbol_table_len
+            && symbols[..snapshot.symbol_table_len].last() == snapshot.last_symbol.as_ref();
+        if !symbols_were_kept {
+            return IonResult::illegal_operation(
+                "cannot restore the encoding context; its symbol table has been replaced since the snapshot was taken",
+            );
+        }
+        let macros_were_kept = self.macro_table.len() >= snapshot.macro_table_len
+            && match &snapshot.last_macro {
+                Some(last_macro) => self
+                    .macro_table
+                    .clone_macro_with_address(snapshot.macro_table_len - 1)
+                    .is_some_and(|macro_def| Arc::ptr_eq(&macro_def, last_macro)),
+                None => true,
+            };
+        if !macros_were_kept {
+            return IonResult::illegal_operation(
+                "cannot restore the encoding context; its macro table has been replaced since the snapshot was taken",
+            );
+        }
+        // Only take mutable references (which may clone a shared table) if something changed.
+        if self.symbol_table.len() > snapshot.symbol_table_len {
+            Rc::make_mut(&mut self.symbol_table).truncate(snapshot.symbol_table_len);
+        }
+        if self.macro_table.len() > snapshot.macro_table_len {
+            Rc::make_mut(&mut self.macro_table).truncate(snapshot.macro_table_len);
+        }
+        self.modules = Rc::clone(&snapshot.modules);
+        Ok(())
+    }
+
     pub fn save_io_buffer(&self) -> IoBuffer {
         match unsafe { &*self.io_buffer_source.get() } {
             IoBufferSource::IoBuffer(ref buffer) => buffer.clone(),
@@ -217,6 +510,52 @@ impl EncodingContext {
         &self.symbol_table
     }
 
+    /// Returns the named modules that have been bound in this context.
+    pub fn modules(&self) -> &ModuleBindings {
+        &self.modules
+    }
+
+    /// If there is only one strong reference to the module bindings, returns a mutable reference
+    /// to them. Otherwise, clones the bindings, allowing the other referents to continue using the
+    /// previous copy.
+    pub fn modules_mut(&mut self) -> &mut ModuleBindings {
+        Rc::make_mut(&mut self.modules)
+    }
+
+    /// Returns the macro table of the module with the given name. `$ion` refers to the system
+    /// module and `_` refers to the default module.
+    pub fn module_macro_table(&self, module_name: &str) -> Option<&MacroTable> {
+        match module_name {
+            "$ion" => Some(&ION_1_1_SYSTEM_MACROS),
+            v1_1::constants::DEFAULT_MODULE_NAME => Some(self.macro_table()),
+            _ => self
+                .modules
+                .get(module_name)
+                .map(|module| module.macro_table()),
+        }
+    }
+
+    /// Looks up the macro with the given ID. Unqualified IDs refer to the default module; qualified
+    /// IDs can refer to the system module, the default module, or any module bound in this context.
+    pub fn resolve_macro_id<'a>(&'a self, id: MacroIdRef<'_>) -> IonResult<MacroRef<'a>> {
+        // The macro table can resolve unqualified IDs and system addresses on its own.
+        let Some((module_name, id_in_module)) = id.split_module() else {
+            return id.resolve(self.macro_table());
+        };
+        let Some(module) = self.modules.get(module_name) else {
+            // This isn't a module that was bound in this context. If it's `$ion` or `_`, the
+            // macro table can resolve it.
+            return id.resolve(self.macro_table());
+        };
+        let macro_table = module.macro_table();
+        let address = macro_table.address_for_id(id_in_module).ok_or_else(|| {
+            IonError::decoding_error(format!("module '{module_name}' has no macro with ID {id}"))
+        })?;
+        // `address_for_id` confirmed that there is a macro at this address.
+        let macro_def = macro_table.macro_at_address(address).unwrap();
+        Ok(MacroRef::in_named_module(module.name(), address, macro_def))
+    }
+
     pub fn allocator(&self) -> &BumpAllocator {
         &self.allocator
     }
@@ -246,13 +585,20 @@ impl EncodingContext {
         self.macro_table_mut().add_template_macro(template_macro)
     }
 
-    pub(crate) fn tables_mut(&mut self) -> (&mut MacroTable, &mut SymbolTable) {
+    pub(crate) fn tables_mut(
+        &mut self,
+    ) -> (&mut MacroTable, &mut SymbolTable, &mut ModuleBindings) {
         let Self {
             macro_table,
             symbol_table,
+            modules,
             ..
         } = self;
-        (Rc::make_mut(macro_table), Rc::make_mut(symbol_table))
+        (
+            Rc::make_mut(macro_table),
+            Rc::make_mut(symbol_table),
+            Rc::make_mut(modules),
+        )
     }
 }
 
@@ -278,6 +624,15 @@ impl<'top> EncodingContextRef<'top> {
         &self.context.macro_table
     }
 
+    pub fn modules(&self) -> &'top ModuleBindings {
+        &self.context.modules
+    }
+
+    /// Resolves `id` to a macro, looking up module-qualified IDs in the module bound to that name.
+    pub fn resolve_macro_id(&self, id: MacroIdRef<'_>) -> IonResult<MacroRef<'top>> {
+        self.context.resolve_macro_id(id)
+    }
+
     pub fn location_for_span(&self, span: Option<Span<'_>>) -> Option<SourceLocation> {
         match unsafe { &*self.io_buffer_source.get() } {
             IoBufferSource::IoBuffer(ref buffer) => Some(
@@ -340,6 +695,11 @@ pub(crate) struct ExpandingReader<Encoding: Decoder, Input: IonInput> {
     // it back at dereference time when a 'top lifetime is available.
     evaluator_ptr: Cell<Option<*mut ()>>,
 
+    // When `true`, the bump allocator is NOT cleared between top-level expressions. This allows
+    // a batch of values (see `Reader::read_batch`) to share a single allocator rather than each
+    // value forcing the reader to allocate a fresh one.
+    retain_allocations: Cell<bool>,
+
     // XXX: The `UnsafeCell` wrappers around the fields below are a workaround for
     //      a limitation in rustc's borrow checker that prevents mutable references from being
     //      conditionally returned in a loop.
@@ -360,7 +720,10 @@ pub(crate) struct ExpandingReader<Encoding: Decoder, Input: IonInput> {
     // to the encoding context the next time the reader is between top-level expressions.
     pending_context_changes: UnsafeCell<PendingContextChanges>,
     encoding_context: UnsafeCell<EncodingContext>,
-    catalog: Box<dyn Catalog>,
+    // Reference counted so that cloned readers can share it.
+    catalog: Rc<dyn Catalog>,
+    // Counts the changes that have been made to the symbol and macro tables.
+    table_growth: Cell<TableGrowth>,
 }
 
 impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
@@ -369,13 +732,135 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         catalog: Box<dyn Catalog>,
     ) -> Self {
         let encoding = raw_reader.encoding();
+        let encoding_context = EncodingContext::for_ion_version(encoding.version());
+        let table_growth = TableGrowth::new(
+            encoding_context.symbol_table(),
+            encoding_context.macro_table(),
+        );
         Self {
             raw_reader: raw_reader.into(),
             evaluator_ptr: None.into(),
-            encoding_context: EncodingContext::for_ion_version(encoding.version()).into(),
+            retain_allocations: false.into(),
+            encoding_context: encoding_context.into(),
             pending_context_changes: PendingContextChanges::new().into(),
-            catalog,
+            catalog: catalog.into(),
+            table_growth: table_growth.into(),
+        }
+    }
+
+    /// Returns a copy of this reader that will resume reading from the same position in its own
+    /// copy of the input. The copy shares its symbol table, macro table, and catalog with `self`
+    /// until either reader modifies them.
+    ///
+    /// If the reader is partway through the expansion of an e-expression, returns an `Err`; the
+    /// state of a macro evaluation cannot be cloned.
+    pub(crate) fn try_clone(&self) -> IonResult<Self>
+    where
+        Input::DataSource: Clone,
+    {
+        if self.evaluator_ptr.get().is_some() {
+            return IonResult::illegal_operation(
+                "cannot clone a reader that is partway through expanding an e-expression",
+            );
         }
+        // SAFETY: The raw reader, encoding context, and pending changes are only modified via
+        //         `&mut self` or while a `'top` borrow of the reader is active. Holding `&self`
+        //         guarantees that neither is in progress.
+        let (raw_reader, context, pending_changes) = unsafe {
+            (
+                &*self.raw_reader.get(),
+                &*self.encoding_context.get(),
+                &*self.pending_context_changes.get(),
+            )
+        };
+        Ok(Self {
+            raw_reader: raw_reader.clone_with_input().into(),
+            evaluator_ptr: None.into(),
+            retain_allocations: self.retain_allocations.get().into(),
+            pending_context_changes: pending_changes.clone().into(),
+            encoding_context: context.clone_without_input().into(),
+            catalog: Rc::clone(&self.catalog),
+            table_growth: self.table_growth.get().into(),
+        })
+    }
+
+    /// Captures the reader's position in the overall stream and its encoding context.
+    ///
+    /// If the reader is partway through the expansion of an e-expression or has encoding
+    /// directives that it has not yet applied, returns an `Err`.
+    pub(crate) fn save_state(&self) -> IonResult<ReaderState> {
+        if self.evaluator_ptr.get().is_some() {
+            return IonResult::illegal_operation(
+                "cannot save the state of a reader that is partway through expanding an e-expression",
+            );
+        }
+        if self.pending_context_changes().has_changes() {
+            return IonResult::illegal_operation(
+                "cannot save the state of a reader whose encoding context has pending changes",
+            );
+        }
+        // SAFETY: As in `detected_encoding`, we only need an immutable reference to the field.
+        let raw_reader = unsafe { &*self.raw_reader.get() };
+        ReaderState::new(
+            raw_reader.input_offset() + raw_reader.stream_position(),
+            raw_reader.encoding(),
+            self.context().context,
+        )
+    }
+
+    /// Prepares a newly constructed reader to continue reading from the point in a stream at
+    /// which `state` was saved. The reader's input is expected to begin at that point.
+    pub(crate) fn resume_from_state(&mut self, state: &ReaderState) -> IonResult<()> {
+        let saved_context = state.encoding_context()?;
+        let encoding = state.encoding();
+        self.raw_reader
+            .get_mut()
+            .resume_at(state.stream_position(), encoding);
+        self.install_saved_context(encoding, saved_context);
+        Ok(())
+    }
+
+    /// Repositions a reader over a fixed slice of input at `offset` in the stream, replacing its
+    /// encoding context with the one recorded in `state`. The position recorded in `state` is
+    /// not used.
+    pub(crate) fn seek_to<Buffer: AsRef<[u8]>>(
+        &mut self,
+        offset: usize,
+        state: &ReaderState,
+    ) -> IonResult<()>
+    where
+        Input: IonInput<DataSource = IonSlice<Buffer>>,
+    {
+        // Recreate the saved context before moving so that a failure leaves the reader unchanged.
+        let saved_context = state.encoding_context()?;
+        let encoding = state.encoding();
+        self.raw_reader.get_mut().seek_to(offset, encoding)?;
+        // Abandon any e-expression that the reader was partway through expanding and any encoding
+        // directive that it had not yet applied.
+        self.evaluator_ptr.set(None);
+        *self.pending_context_changes.get_mut() = PendingContextChanges::new();
+        self.install_saved_context(encoding, saved_context);
+        Ok(())
+    }
+
+    /// Replaces the reader's symbol table, macro table, and modules with those in `saved_context`.
+    fn install_saved_context(&mut self, encoding: IonEncoding, saved_context: EncodingContext) {
+        // Symbols and macros that the stream defined before the state was saved are counted as
+        // though this reader had read their definitions.
+        let system_context = EncodingContext::for_ion_version(encoding.version());
+        let mut growth =
+            TableGrowth::new(system_context.symbol_table(), system_context.macro_table());
+        growth.record_change(
+            saved_context.symbol_table(),
+            saved_context.macro_table(),
+            0,
+            0,
+        );
+        self.table_growth.set(growth);
+        let context = self.context_mut();
+        context.symbol_table = saved_context.symbol_table;
+        context.macro_table = saved_context.macro_table;
+        context.modules = saved_context.modules;
     }
 
     // TODO: This method is temporary. It will be removed when the ability to read 1.1 encoding
@@ -410,6 +895,21 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         self.encoding_context.get_mut()
     }
 
+    /// Rolls the encoding context back to `snapshot`, discarding any changes that have not been
+    /// applied yet.
+    pub fn restore_context(&mut self, snapshot: &EncodingContextSnapshot) -> IonResult<()> {
+        *self.pending_context_changes.get_mut() = PendingContextChanges::new();
+        self.context_mut().restore(snapshot)
+    }
+
+    /// When `retain` is `true`, the reader will stop clearing its bump allocator between top-level
+    /// expressions. Values that were previously converted to `LazyElement`s will continue to
+    /// share the same allocator instead of causing the reader to allocate a new one.
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub(crate) fn set_retain_allocations(&self, retain: bool) {
+        self.retain_allocations.set(retain);
+    }
+
     // SAFETY: This method takes an immutable reference to `self` and then modifies the
     //         EncodingContext's bump allocator via `UnsafeCell`. This should only be called from
     //         `between_top_level_values`, and the caller must confirm that nothing else holds a
@@ -419,6 +919,14 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         context.allocator_mut().reset();
     }
 
+    /// Returns counters describing how the symbol and macro tables have grown.
+    pub(crate) fn table_growth(&self) -> TableGrowth {
+        let context = self.context();
+        self.table_growth
+            .get()
+            .with_current_sizes(context.symbol_table(), context.macro_table())
+    }
+
     pub fn pending_context_changes(&self) -> &PendingContextChanges {
         // If the user is able to call this method, the PendingLst is not being modified and it's
         // safe to immutably reference.
@@ -453,10 +961,14 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         pending_changes: &mut PendingContextChanges,
         symbol_table: &mut SymbolTable,
         macro_table: &mut MacroTable,
+        modules: &mut ModuleBindings,
+        growth: &mut TableGrowth,
     ) {
         if let Some(new_version) = pending_changes.switch_to_version.take() {
             symbol_table.reset_to_version(new_version);
             macro_table.reset_to_system_macros();
+            *modules = ModuleBindings::new();
+            growth.record_reset(symbol_table, macro_table);
             pending_changes.has_changes = false;
             pending_changes.is_lst_append = false;
             // If we're switching to a new version, the last stream item was a version marker
@@ -464,14 +976,32 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
             return;
         }
 
+        let new_modules = pending_changes.take_new_module_bindings();
+        if let Some(new_modules) = &new_modules {
+            *modules = new_modules.clone();
+        }
+
         if let Some(mut module) = pending_changes.take_new_active_module() {
             std::mem::swap(symbol_table, module.symbol_table_mut());
             std::mem::swap(macro_table, module.macro_table_mut());
+            // A new module usually extends the one it replaces, so only its additional symbols and
+            // macros are counted as new definitions.
+            let num_new_symbols = symbol_table
+                .len()
+                .saturating_sub(module.symbol_table().len());
+            let num_new_macros = macro_table.len().saturating_sub(module.macro_table().len());
+            growth.record_change(symbol_table, macro_table, num_new_symbols, num_new_macros);
             pending_changes.has_changes = false;
             pending_changes.is_lst_append = false;
             return;
         }
 
+        if new_modules.is_some() {
+            // The directive only bound new modules; the default module is unchanged.
+            pending_changes.has_changes = false;
+            return;
+        }
+
         // If the symbol table's `imports` field had a value of `$ion_symbol_table`, then we're
         // appending the symbols it defined to the end of our existing local symbol table.
         // Otherwise, we need to clear the existing table before appending the new symbols.
@@ -479,6 +1009,8 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
             // We're setting the symbols list, not appending to it.
             symbol_table.reset_to_prefix_only();
         }
+        let num_new_symbols =
+            pending_changes.imported_symbols.len() + pending_changes.symbols.len();
         // `drain()` empties the pending `imported_symbols` and `symbols` lists
         for symbol in pending_changes.imported_symbols.drain(..) {
             symbol_table.add_symbol(symbol);
@@ -486,6 +1018,7 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         for symbol in pending_changes.symbols.drain(..) {
             symbol_table.add_symbol(symbol);
         }
+        growth.record_change(symbol_table, macro_table, num_new_symbols, 0);
         pending_changes.is_lst_append = false;
         pending_changes.has_changes = false;
     }
@@ -526,7 +1059,11 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
             && SystemReader::<_, Input>::is_encoding_directive_sexp(&value)?
         {
             let pending_changes = unsafe { &mut *self.pending_context_changes.get() };
-            SystemReader::<_, Input>::process_encoding_directive(pending_changes, value)?;
+            SystemReader::<_, Input>::process_encoding_directive(
+                pending_changes,
+                &*self.catalog,
+                value,
+            )?;
             pending_changes.has_changes = true;
             let lazy_sexp = LazySExp {
                 expanded_sexp: value.read()?.expect_sexp()?,
@@ -555,7 +1092,7 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
     /// single expression.
     ///
     /// This is the reader's opportunity to make any pending changes to the encoding context.
-    fn between_top_level_expressions(&self) {
+    fn between_top_level_expressions(&self) -> IonResult<()> {
         // We're going to clear the bump allocator, so drop our reference to the evaluator that
         // lives there.
         self.evaluator_ptr.set(None);
@@ -565,8 +1102,10 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         unsafe {
             // If we're holding a reference to the input data source, drop it.
             (*self.encoding_context.get()).io_buffer_source = IoBufferSource::None.into();
-            // Clear the bump allocator.
-            self.reset_bump_allocator();
+            // Clear the bump allocator unless a batch of values is currently sharing it.
+            if !self.retain_allocations.get() {
+                self.reset_bump_allocator();
+            }
         }
 
         // If the pending LST has changes to apply, do so.
@@ -578,9 +1117,26 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
             // SAFETY: Nothing else holds a reference to the `EncodingContext`'s contents, so we can use the
             //         `UnsafeCell` to get a mutable reference to its symbol table.
             let encoding_context_ref = unsafe { &mut *self.encoding_context.get() };
-            let (macro_table, symbol_table) = encoding_context_ref.tables_mut();
-            Self::apply_pending_context_changes(pending_lst, symbol_table, macro_table);
+            let (macro_table, symbol_table, modules) = encoding_context_ref.tables_mut();
+            let mut growth = self.table_growth.get();
+            Self::apply_pending_context_changes(
+                pending_lst,
+                symbol_table,
+                macro_table,
+                modules,
+                &mut growth,
+            );
+            self.table_growth.set(growth);
+            let num_symbols = encoding_context_ref
+                .symbol_table
+                .application_symbols()
+                .len();
+            encoding_context_ref.limits.check_symbols(num_symbols)?;
         }
+        // Each top-level expression has its own budget of macro evaluation steps and output.
+        self.context().macro_steps.set(0);
+        self.context().macro_output.set(0);
+        Ok(())
     }
 
     /// Returns the next application-level value.
@@ -605,12 +1161,50 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         unsafe { &*self.raw_reader.get() }.encoding()
     }
 
+    /// Returns the number of bytes of input that have been consumed.
+    pub(crate) fn stream_position(&self) -> usize {
+        // SAFETY: As in `detected_encoding`, we only need an immutable reference to the field.
+        unsafe { &*self.raw_reader.get() }.stream_position()
+    }
+
+    /// See [`StreamingRawReader::skipped_value`].
+    pub(crate) fn skipped_value(&self) -> Option<Range<usize>> {
+        // SAFETY: As in `detected_encoding`, we only need an immutable reference to the field.
+        unsafe { &*self.raw_reader.get() }.skipped_value()
+    }
+
     /// Returns the next IVM, value, or system value as an `ExpandedStreamItem`.
     ///
     /// This path is less optimized than `next_system_item` because it needs to surface additional
     /// items that do not impact the application. However, it's useful for tooling that needs more
     /// visibility into the expansion process.
     pub fn next_item(&mut self) -> IonResult<ExpandedStreamItem<'_, Encoding>> {
+        self.next_item_and_origin()
+            .map(|(item, _is_expansion)| item)
+    }
+
+    /// Like [`next_item`](Self::next_item), but also returns a reference to the encoding context
+    /// that was used to read the item and whether the item was produced by evaluating an
+    /// e-expression. Changes that the item makes to the encoding context (for example, if it is a
+    /// symbol table) are not reflected until the reader advances again.
+    pub fn next_item_with_context(
+        &mut self,
+    ) -> IonResult<(
+        ExpandedStreamItem<'_, Encoding>,
+        EncodingContextRef<'_>,
+        bool,
+    )> {
+        let context_ptr = self.encoding_context.get();
+        let (item, is_expansion) = self.next_item_and_origin()?;
+        // SAFETY: The encoding context is only modified in `between_top_level_expressions`, which
+        //         cannot run while `item` and this reference (which share a lifetime) are in use.
+        let context = unsafe { (*context_ptr).get_ref() };
+        Ok((item, context, is_expansion))
+    }
+
+    /// Returns the next item along with `true` if it was produced by the active macro evaluator or
+    /// `false` if it was read from the input.
+    fn next_item_and_origin(&mut self) -> IonResult<(ExpandedStreamItem<'_, Encoding>, bool)> {
         // If there's already an active macro evaluator, that means the reader is still in the process
         // of expanding a macro invocation it previously encountered. See if it has a value to give us.
         if let Some(ptr) = self.evaluator_ptr.get() {
@@ -623,7 +1217,7 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
                         // further.
                         self.evaluator_ptr.set(None);
                     }
-                    return Ok(self.interpret_value(value)?.as_expanded_stream_item());
+                    return Ok((self.interpret_value(value)?.as_expanded_stream_item(), true));
                 }
                 Ok(None) => {}
                 Err(e) => return Err(e),
@@ -632,7 +1226,7 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
 
         // Otherwise, we're now between top level expressions. Take this opportunity to apply any
         // pending changes to the encoding context and reset state as needed.
-        self.between_top_level_expressions();
+        self.between_top_level_expressions()?;
 
         // See if the raw reader can get another expression from the input stream. It's possible
         // to find an expression that yields no values (for example: `(:none)`), so we perform this
@@ -645,12 +1239,16 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         match raw_reader.next(context_ref)? {
             VersionMarker(marker) => {
                 let _system_item = self.interpret_ivm(marker)?;
-                Ok(ExpandedStreamItem::VersionMarker(marker))
+                Ok((ExpandedStreamItem::VersionMarker(marker), false))
             }
             // We got our value; return it.
             Value(raw_value) => {
+                context_ref.limits.check_containers::<Encoding>(raw_value)?;
                 let value = LazyExpandedValue::from_literal(context_ref, raw_value);
-                Ok(self.interpret_value(value)?.as_expanded_stream_item())
+                Ok((
+                    self.interpret_value(value)?.as_expanded_stream_item(),
+                    false,
+                ))
             }
             // It's another macro invocation, we'll add it to the evaluator so it will be evaluated
             // on the next call and then we'll return the e-expression itself.
@@ -676,9 +1274,9 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
                 self.evaluator_ptr
                     .set(Some(Self::evaluator_to_ptr(evaluator)));
 
-                Ok(ExpandedStreamItem::EExp(resolved_e_exp))
+                Ok((ExpandedStreamItem::EExp(resolved_e_exp), false))
             }
-            EndOfStream(end_position) => Ok(ExpandedStreamItem::EndOfStream(end_position)),
+            EndOfStream(end_position) => Ok((ExpandedStreamItem::EndOfStream(end_position), false)),
         }
     }
 
@@ -711,7 +1309,7 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
 
         // Otherwise, we're now between top level expressions. Take this opportunity to apply any
         // pending changes to the encoding context and reset state as needed.
-        self.between_top_level_expressions();
+        self.between_top_level_expressions()?;
 
         // See if the raw reader can get another expression from the input stream. It's possible
         // to find an expression that yields no values (for example: `(:none)`), so we perform this
@@ -728,47 +1326,18 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
                 }
                 // We got our value; return it.
                 Value(raw_value) => {
+                    context_ref.limits.check_containers::<Encoding>(raw_value)?;
                     let value = LazyExpandedValue::from_literal(context_ref, raw_value);
                     return self.interpret_value(value);
                 }
                 // It's another macro invocation, we'll start evaluating it.
                 EExp(e_exp) => {
-                    let resolved_e_exp = e_exp.resolve(context_ref)?;
-
-                    // If this e-expression invokes a template with a non-system, singleton expansion, we can use the
-                    // e-expression to back a LazyExpandedValue. It will only be evaluated if the user calls `read()`.
-                    if let Some(value) = LazyExpandedValue::try_from_e_expression(resolved_e_exp) {
-                        // Because the expansion is guaranteed not to be a system value, we do not need to interpret it.
-                        return Ok(SystemStreamItem::Value(LazyValue::new(value)));
-                    }
-                    let new_evaluator = MacroEvaluator::for_eexp(resolved_e_exp)?;
-                    // Get the current evaluator or make a new one
-                    let evaluator = match self.evaluator_ptr.get() {
-                        // If there's already an evaluator in the bump, it's empty. Overwrite it with our new one.
-                        Some(ptr) => {
-                            let bump_evaluator_ref = Self::ptr_to_evaluator(ptr);
-                            *bump_evaluator_ref = new_evaluator;
-                            bump_evaluator_ref
-                        }
-                        // If there's not an evaluator in the bump, make a new one.
-                        None => context_ref.allocator.alloc_with(|| new_evaluator),
-                    };
-
-                    // Try to get a value by starting to evaluate the e-expression.
-                    if let Some(value) = evaluator.next()? {
-                        // If we get a value and the evaluator isn't empty yet, save its pointer
-                        // so we can try to get more out of it when `next_at_or_above_depth` is called again.
-                        if !evaluator.is_empty() {
-                            self.evaluator_ptr
-                                .set(Some(Self::evaluator_to_ptr(evaluator)));
-                        }
-                        // If we get a value, return it.
-                        return self.interpret_value(value);
-                    } else {
-                        // If the expression was equivalent to `(:none)`, return to the top of
-                        // the loop and get the next expression.
-                        continue;
+                    if let Some(item) = self.start_evaluating(e_exp.resolve(context_ref)?)? {
+                        return Ok(item);
                     }
+                    // If the expression was equivalent to `(:none)`, return to the top of
+                    // the loop and get the next expression.
+                    continue;
                 }
                 EndOfStream(end_position) => {
                     return Ok(SystemStreamItem::EndOfStream(end_position));
@@ -776,6 +1345,93 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
             };
         }
     }
+
+    /// Begins evaluating `resolved_e_exp`, returning the first item of its expansion or `None` if
+    /// it does not produce any values.
+    fn start_evaluating<'top>(
+        &'top self,
+        resolved_e_exp: EExpression<'top, Encoding>,
+    ) -> IonResult<Option<SystemStreamItem<'top, Encoding>>> {
+        // If this e-expression invokes a template with a non-system, singleton expansion, we can use the
+        // e-expression to back a LazyExpandedValue. It will only be evaluated if the user calls `read()`.
+        if let Some(value) = LazyExpandedValue::try_from_e_expression(resolved_e_exp) {
+            // Because the expansion is guaranteed not to be a system value, we do not need to interpret it.
+            return Ok(Some(SystemStreamItem::Value(LazyValue::new(value))));
+        }
+        let context_ref = self.context();
+        let new_evaluator = MacroEvaluator::for_eexp(resolved_e_exp)?;
+        // Get the current evaluator or make a new one
+        let evaluator = match self.evaluator_ptr.get() {
+            // If there's already an evaluator in the bump, it's empty. Overwrite it with our new one.
+            Some(ptr) => {
+                let bump_evaluator_ref = Self::ptr_to_evaluator(ptr);
+                *bump_evaluator_ref = new_evaluator;
+                bump_evaluator_ref
+            }
+            // If there's not an evaluator in the bump, make a new one.
+            None => context_ref.allocator.alloc_with(|| new_evaluator),
+        };
+
+        // Try to get a value by starting to evaluate the e-expression.
+        let Some(value) = evaluator.next()? else {
+            return Ok(None);
+        };
+        // If we get a value and the evaluator isn't empty yet, save its pointer
+        // so we can try to get more out of it when `next_system_item` is called again.
+        if !evaluator.is_empty() {
+            self.evaluator_ptr
+                .set(Some(Self::evaluator_to_ptr(evaluator)));
+        }
+        self.interpret_value(value).map(Some)
+    }
+
+    /// Advances past the next application value without constructing it, returning `false` if the
+    /// end of the stream was reached instead.
+    ///
+    /// A value literal that cannot be a system value (that is: anything other than an annotated
+    /// struct or s-expression) is skipped as soon as the raw reader has found its end. In binary
+    /// Ion, that only requires reading its header. E-expressions and possible system values are
+    /// processed as they are by [`next_system_item`](Self::next_system_item).
+    pub(crate) fn skip_next_value(&mut self) -> IonResult<bool> {
+        use crate::lazy::raw_stream_item::RawStreamItem::*;
+        loop {
+            // If a macro is still being evaluated, take its next value in the usual way.
+            if self.evaluator_ptr.get().is_some() {
+                match self.next_system_item()? {
+                    SystemStreamItem::Value(_) => return Ok(true),
+                    SystemStreamItem::EndOfStream(_) => return Ok(false),
+                    _ => continue,
+                }
+            }
+
+            self.between_top_level_expressions()?;
+            let context_ref = self.context();
+            let raw_reader = unsafe { &mut *self.raw_reader.get() };
+            match raw_reader.next(context_ref)? {
+                VersionMarker(marker) => {
+                    self.interpret_ivm(marker)?;
+                }
+                Value(raw_value) => {
+                    if !raw_value.has_annotations()
+                        || !matches!(raw_value.ion_type(), IonType::Struct | IonType::SExp)
+                    {
+                        return Ok(true);
+                    }
+                    let value = LazyExpandedValue::from_literal(context_ref, raw_value);
+                    if let SystemStreamItem::Value(_) = self.fully_interpret_value(value)? {
+                        return Ok(true);
+                    }
+                }
+                EExp(e_exp) => {
+                    let item = self.start_evaluating(e_exp.resolve(context_ref)?)?;
+                    if let Some(SystemStreamItem::Value(_)) = item {
+                        return Ok(true);
+                    }
+                }
+                EndOfStream(_) => return Ok(false),
+            }
+        }
+    }
 }
 
 /// The source of data backing a [`LazyExpandedValue`].
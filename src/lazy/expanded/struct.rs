@@ -1,6 +1,8 @@
 use crate::element::iterators::SymbolsIterator;
 use crate::lazy::decoder::private::RawStructFieldExprIterator;
-use crate::lazy::decoder::{Decoder, LazyRawFieldName, LazyRawStruct};
+use crate::lazy::decoder::{
+    Decoder, LazyRawContainer, LazyRawFieldName, LazyRawStruct, LazyRawValue,
+};
 use crate::lazy::expanded::macro_evaluator::{
     MacroEvaluator, MacroExpr, MacroExprArgsIterator, ValueExpr,
 };
@@ -16,6 +18,10 @@ use crate::lazy::expanded::{
 };
 use crate::result::IonFailure;
 use crate::{try_next, try_or_some_err, EExpression, HasRange, IonResult, SymbolRef};
+use bumpalo::collections::Vec as BumpVec;
+use rustc_hash::FxBuildHasher;
+use std::cell::OnceCell;
+use std::hash::BuildHasher;
 use std::ops::Range;
 
 /// A unified type embodying all possible field representations coming from both input data
@@ -154,9 +160,112 @@ pub enum ExpandedStructSource<'top, D: Decoder> {
 pub struct LazyExpandedStruct<'top, D: Decoder> {
     pub(crate) context: EncodingContextRef<'top>,
     pub(crate) source: ExpandedStructSource<'top, D>,
+    // Large struct literals are given a field index that is populated the first time `find` is
+    // called. Other structs always use a linear scan.
+    field_index: Option<&'top LiteralStructFieldIndex<'top, D>>,
 }
 
-#[cfg(feature = "experimental-tooling-apis")]
+/// The fields of an indexed struct literal, stored in an open-addressing hash table.
+#[derive(Copy, Clone)]
+struct IndexedFields<'top, D: Decoder> {
+    // Each field with a known name, in the order in which it was encoded.
+    fields: &'top [(&'top str, LazyExpandedValue<'top, D>)],
+    // A hash table whose length is a power of two and at least twice the number of fields. Each
+    // slot is either `EMPTY_SLOT` or the position in `fields` of the first field with a given name.
+    slots: &'top [usize],
+}
+
+/// An index of the fields in a struct literal, mapping each field name to the first value with
+/// that name. The index is populated by a single pass over the struct the first time it is
+/// consulted, allowing subsequent lookups to perform a hash table lookup instead of a linear scan.
+///
+/// Like the struct itself, the index lives in the bump allocator and is discarded when the
+/// reader advances to the next top-level value.
+pub(crate) struct LiteralStructFieldIndex<'top, D: Decoder> {
+    // If the inner value is `None`, the struct could not be indexed (e.g. because one of its
+    // fields was malformed) and lookups will fall back to a linear scan.
+    fields: OnceCell<Option<IndexedFields<'top, D>>>,
+}
+
+impl<'top, D: Decoder> LiteralStructFieldIndex<'top, D> {
+    /// Struct literals whose encoding is at least this many bytes long will be given an index.
+    const MIN_STRUCT_BYTE_LENGTH: usize = 128;
+
+    const EMPTY_SLOT: usize = usize::MAX;
+
+    fn new() -> Self {
+        Self {
+            fields: OnceCell::new(),
+        }
+    }
+
+    /// Returns the value of the first field with the specified name, populating the index if
+    /// necessary. If the struct could not be indexed, returns `None`.
+    fn find(
+        &self,
+        lazy_struct: &LazyExpandedStruct<'top, D>,
+        name: &str,
+    ) -> Option<Option<LazyExpandedValue<'top, D>>> {
+        let IndexedFields { fields, slots } = (*self
+            .fields
+            .get_or_init(|| Self::index_fields(lazy_struct).ok()))?;
+        let mask = slots.len() - 1;
+        let mut slot = Self::hash(name) & mask;
+        // The table is never more than half full, so probing always reaches an empty slot.
+        loop {
+            let position = slots[slot];
+            if position == Self::EMPTY_SLOT {
+                return Some(None);
+            }
+            let (field_name, value) = fields[position];
+            if field_name == name {
+                return Some(Some(value));
+            }
+            slot = (slot + 1) & mask;
+        }
+    }
+
+    fn hash(name: &str) -> usize {
+        FxBuildHasher.hash_one(name) as usize
+    }
+
+    fn index_fields(
+        lazy_struct: &LazyExpandedStruct<'top, D>,
+    ) -> IonResult<IndexedFields<'top, D>> {
+        let allocator = lazy_struct.context.allocator();
+        let mut fields = BumpVec::new_in(allocator);
+        for field_result in lazy_struct.iter() {
+            let field = field_result?;
+            // Fields whose names have unknown text can never match a lookup by text.
+            if let Some(name) = field.name().read()?.text() {
+                fields.push((name, field.value));
+            }
+        }
+        let fields = fields.into_bump_slice();
+
+        let slots = allocator
+            .alloc_slice_fill_copy((fields.len() * 2).next_power_of_two(), Self::EMPTY_SLOT);
+        let mask = slots.len() - 1;
+        for (position, (name, _value)) in fields.iter().enumerate() {
+            let mut slot = Self::hash(name) & mask;
+            loop {
+                let existing = slots[slot];
+                if existing == Self::EMPTY_SLOT {
+                    slots[slot] = position;
+                    break;
+                }
+                // When a name is repeated, the first field with that name is retained.
+                if fields[existing].0 == *name {
+                    break;
+                }
+                slot = (slot + 1) & mask;
+            }
+        }
+        Ok(IndexedFields { fields, slots })
+    }
+}
+
+#[cfg_attr(not(feature = "experimental-tooling-apis"), allow(dead_code))]
 impl<'top, D: Decoder> LazyExpandedStruct<'top, D> {
     pub fn context(&self) -> EncodingContextRef<'top> {
         self.context
@@ -171,8 +280,15 @@ impl<'top, D: Decoder> LazyExpandedStruct<'top, D> {
         context: EncodingContextRef<'top>,
         sexp: D::Struct<'top>,
     ) -> LazyExpandedStruct<'top, D> {
+        let field_index = (sexp.as_value().value_span().len()
+            >= LiteralStructFieldIndex::<D>::MIN_STRUCT_BYTE_LENGTH)
+            .then(|| &*context.allocator().alloc_with(LiteralStructFieldIndex::new));
         let source = ExpandedStructSource::ValueLiteral(sexp);
-        Self { source, context }
+        Self {
+            source,
+            context,
+            field_index,
+        }
     }
 
     pub fn from_template(
@@ -182,7 +298,11 @@ impl<'top, D: Decoder> LazyExpandedStruct<'top, D> {
         index: &'top TemplateStructIndex,
     ) -> LazyExpandedStruct<'top, D> {
         let source = ExpandedStructSource::Template(environment, *element, index);
-        Self { source, context }
+        Self {
+            source,
+            context,
+            field_index: None,
+        }
     }
 
     pub fn from_make_struct(
@@ -192,7 +312,11 @@ impl<'top, D: Decoder> LazyExpandedStruct<'top, D> {
     ) -> LazyExpandedStruct<'top, D> {
         let arguments_ref = context.allocator().alloc_with(|| arguments);
         let source = ExpandedStructSource::MakeStruct(environment, arguments_ref);
-        Self { source, context }
+        Self {
+            source,
+            context,
+            field_index: None,
+        }
     }
 
     pub fn from_make_field(
@@ -201,7 +325,11 @@ impl<'top, D: Decoder> LazyExpandedStruct<'top, D> {
     ) -> LazyExpandedStruct<'top, D> {
         let field_ref = context.allocator().alloc_with(|| field);
         let source = ExpandedStructSource::MakeField(field_ref);
-        Self { source, context }
+        Self {
+            source,
+            context,
+            field_index: None,
+        }
     }
 
     pub fn annotations(&self) -> ExpandedAnnotationsIterator<'top, D> {
@@ -312,9 +440,12 @@ impl<'top, D: Decoder> LazyExpandedStruct<'top, D> {
                     }
                 }
             }
-            // For any other kind of struct, do a linear scan over its fields until we encounter
-            // one with the requested name.
+            // For any other kind of struct, consult its field index if it has one. Otherwise,
+            // do a linear scan over its fields until we encounter one with the requested name.
             ValueLiteral(..) | MakeField(..) | MakeStruct(..) => {
+                if let Some(result) = self.field_index.and_then(|index| index.find(self, name)) {
+                    return Ok(result);
+                }
                 for field_result in self.iter() {
                     let field = field_result?;
                     if field.name().read()?.text() == Some(name) {
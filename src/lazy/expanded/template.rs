@@ -175,7 +175,7 @@ impl Parameter {
             #[inline(never)]
             || {
                 IonResult::encoding_error(format!(
-                    "cannot write a tagged value for parameter {}; its cardinality requires an expression group",
+                    "cannot write an expression group for parameter {}; it has a cardinality of exactly-one",
                     self.name
                 ))
             },
@@ -193,12 +193,61 @@ impl Parameter {
             #[inline(never)]
             || {
                 IonResult::encoding_error(format!(
-                    "cannot write an expression group for parameter {}; it has a cardinality of exactly-one",
+                    "cannot write a tagged value for parameter {}; its cardinality requires an expression group",
                     self.name
                 ))
             },
         )()
     }
+
+    /// If this parameter accepts a single tagged value, returns `Ok`. Otherwise, returns an `Err`
+    /// that describes the value being written as `value_kind` (for example, "a string").
+    ///
+    /// Tagged values are self-describing, so a tagged parameter accepts a value of any Ion type.
+    /// Tagless parameters only accept values written using a method that supports their encoding,
+    /// like `write_i64` for a `flex_uint` parameter.
+    pub(crate) fn expect_tagged_argument(&self, value_kind: &str) -> IonResult<&Self> {
+        if !self.is_tagged() {
+            return std::convert::identity(
+                #[inline(never)]
+                || {
+                    IonResult::encoding_error(format!(
+                        "cannot write {value_kind} for parameter '{}'; it requires a {} argument",
+                        self.name(),
+                        self.encoding()
+                    ))
+                },
+            )();
+        }
+        self.expect_single_expression()
+    }
+
+    /// If an expression group containing `num_expressions` expressions is a valid argument for
+    /// this parameter, returns `Ok`. Otherwise, returns an `Err`.
+    pub fn expect_group_size(&self, num_expressions: usize) -> IonResult<&Self> {
+        use ParameterCardinality::*;
+        match (self.cardinality, num_expressions) {
+            (ExactlyOne | ZeroOrOne, 0..=1) | (ZeroOrMore, _) | (OneOrMore, 1..) => Ok(self),
+            (OneOrMore, _) => IonResult::encoding_error(format!(
+                "the expression group for parameter '{}' was empty; its cardinality is one-or-more",
+                self.name
+            )),
+            (cardinality, _) => IonResult::encoding_error(format!(
+                "the expression group for parameter '{}' contained {num_expressions} expressions; its cardinality is {}",
+                self.name,
+                if cardinality == ExactlyOne { "exactly-one" } else { "zero-or-one" }
+            )),
+        }
+    }
+
+    /// Returns `true` if an e-expression must pass an argument for this parameter; that is, if
+    /// its cardinality is exactly-one (`!`) or one-or-more (`+`).
+    pub fn is_required(&self) -> bool {
+        matches!(
+            self.cardinality,
+            ParameterCardinality::ExactlyOne | ParameterCardinality::OneOrMore
+        )
+    }
 }
 
 /// The encoding used to serialize and deserialize the associated parameter.
@@ -360,6 +409,31 @@ impl<'a> SignatureIterator<'a> {
             .get(self.index)
     }
 
+    /// Returns the number of variadic parameters that have already been passed an argument. This
+    /// is the position of the next variadic parameter's entry in the argument encoding bitmap.
+    pub fn num_variadic_params_passed(&self) -> usize {
+        self.signature().parameters()[..self.index]
+            .iter()
+            .filter(|p| p.is_variadic())
+            .count()
+    }
+
+    /// If every parameter that has not yet been passed an argument is optional, returns `Ok`.
+    /// Otherwise, returns an `Err` naming the first required parameter that is missing.
+    pub fn expect_complete(&self) -> IonResult<()> {
+        let remaining = self.signature().parameters().get(self.index..).unwrap_or_default();
+        match remaining.iter().find(|p| p.is_required()) {
+            None => Ok(()),
+            Some(missing) => {
+                let macro_name = self.macro_def.name().unwrap_or("<anonymous>");
+                IonResult::encoding_error(format!(
+                    "cannot close e-expression invoking '{macro_name}'; no argument was provided for required parameter '{}'",
+                    missing.name()
+                ))
+            }
+        }
+    }
+
     pub fn expect_next_parameter(&mut self) -> IonResult<&Parameter> {
         self.next()
             .ok_or_else(
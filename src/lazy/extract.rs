@@ -0,0 +1,85 @@
+use crate::lazy::decoder::Decoder;
+use crate::lazy::value::LazyValue;
+use crate::{Bytes, Decimal, Element, Int, IonResult, Str, Symbol, Timestamp};
+
+/// Rust types that can be read from a [`LazyValue`] of the corresponding Ion type.
+///
+/// This is used by [`Reader::extract_field`](crate::Reader::extract_field) to convert each of the
+/// values it finds into the requested type. Conversions are strict: reading a `float` as an `i64`,
+/// for example, will return an `Err`. Null values are handled by the caller and are never passed
+/// to [`FromLazyValue::from_lazy_value`].
+pub trait FromLazyValue: Sized {
+    fn from_lazy_value<D: Decoder>(value: LazyValue<'_, D>) -> IonResult<Self>;
+}
+
+impl FromLazyValue for bool {
+    fn from_lazy_value<D: Decoder>(value: LazyValue<'_, D>) -> IonResult<Self> {
+        value.read()?.expect_bool()
+    }
+}
+
+impl FromLazyValue for i64 {
+    fn from_lazy_value<D: Decoder>(value: LazyValue<'_, D>) -> IonResult<Self> {
+        value.read()?.expect_i64()
+    }
+}
+
+impl FromLazyValue for u64 {
+    fn from_lazy_value<D: Decoder>(value: LazyValue<'_, D>) -> IonResult<Self> {
+        value.read()?.expect_int()?.expect_u64()
+    }
+}
+
+impl FromLazyValue for Int {
+    fn from_lazy_value<D: Decoder>(value: LazyValue<'_, D>) -> IonResult<Self> {
+        value.read()?.expect_int()
+    }
+}
+
+impl FromLazyValue for f64 {
+    fn from_lazy_value<D: Decoder>(value: LazyValue<'_, D>) -> IonResult<Self> {
+        value.read()?.expect_float()
+    }
+}
+
+impl FromLazyValue for Decimal {
+    fn from_lazy_value<D: Decoder>(value: LazyValue<'_, D>) -> IonResult<Self> {
+        value.read()?.expect_decimal()
+    }
+}
+
+impl FromLazyValue for Timestamp {
+    fn from_lazy_value<D: Decoder>(value: LazyValue<'_, D>) -> IonResult<Self> {
+        value.read()?.expect_timestamp()
+    }
+}
+
+impl FromLazyValue for String {
+    fn from_lazy_value<D: Decoder>(value: LazyValue<'_, D>) -> IonResult<Self> {
+        Ok(value.read()?.expect_string()?.text().to_owned())
+    }
+}
+
+impl FromLazyValue for Str {
+    fn from_lazy_value<D: Decoder>(value: LazyValue<'_, D>) -> IonResult<Self> {
+        Ok(value.read()?.expect_string()?.to_owned())
+    }
+}
+
+impl FromLazyValue for Symbol {
+    fn from_lazy_value<D: Decoder>(value: LazyValue<'_, D>) -> IonResult<Self> {
+        Ok(value.read()?.expect_symbol()?.to_owned())
+    }
+}
+
+impl FromLazyValue for Bytes {
+    fn from_lazy_value<D: Decoder>(value: LazyValue<'_, D>) -> IonResult<Self> {
+        Ok(value.read()?.expect_lob()?.to_owned())
+    }
+}
+
+impl FromLazyValue for Element {
+    fn from_lazy_value<D: Decoder>(value: LazyValue<'_, D>) -> IonResult<Self> {
+        Element::try_from(value)
+    }
+}
@@ -0,0 +1,460 @@
+use std::io::Read;
+
+use winnow::combinator::opt;
+use winnow::Parser;
+
+use crate::lazy::decoder::{LazyRawValueExpr, RawValueExpr, RawVersionMarker};
+use crate::lazy::encoding::TextEncoding_1_0;
+use crate::lazy::expanded::{EncodingContext, EncodingContextRef, LazyExpandedValue};
+use crate::lazy::raw_stream_item::RawStreamItem;
+use crate::lazy::system_reader::SystemReader;
+use crate::lazy::system_stream_item::SystemStreamItem;
+use crate::lazy::text::buffer::TextBuffer;
+use crate::lazy::text::parse_result::WithContext;
+use crate::lazy::text::raw::sequence::{RawTextListIterator, RawTextSExpIterator};
+use crate::lazy::text::value::RawTextAnnotationsIterator;
+use crate::lazy::value::LazyValue;
+use crate::result::IonFailure;
+use crate::{Element, IonError, IonResult, IonType, IonVersion, Symbol};
+
+/// The number of bytes requested from the input each time the buffer runs out of data.
+const READ_CHUNK_SIZE: usize = 16 * 1024;
+
+/// Reads the children of a text Ion list or s-expression, yielding each child as soon as it has
+/// been read from the input.
+///
+/// A [`Reader`](crate::Reader) only yields a top-level value once the entire value is in its
+/// buffer, so a document made of a single enormous list has to be held in memory all at once.
+/// An `IncrementalSequenceReader` instead expects its input to begin with a list or s-expression
+/// and returns the container's children one at a time. Input is read in chunks, and the bytes of
+/// each child are discarded once it has been returned, so only the child being read needs to fit
+/// in memory.
+///
+/// Only Ion 1.0 text is supported; binary input and Ion 1.1 text (including a `$ion_1_1` version
+/// marker) return an `Err`. The container may be annotated and may be preceded by whitespace,
+/// comments, `$ion_1_0` version markers, and local symbol tables. Symbol IDs in the container are
+/// resolved using the symbol table those define, which is the system symbol table if there are
+/// none. Any other value before the container returns an `Err`, and any data that follows the end
+/// of the container is not read.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{Element, IncrementalSequenceReader, IonType};
+///
+/// let data = "readings::[{id: 1, value: 2.5e0}, {id: 2, value: 3.5e0}]";
+/// let mut reader = IncrementalSequenceReader::new(data.as_bytes());
+/// let mut ids = Vec::new();
+/// while let Some(child) = reader.next()? {
+///     ids.push(child.as_struct().unwrap().get("id").unwrap().expect_i64()?);
+/// }
+/// assert_eq!(ids, vec![1, 2]);
+/// assert_eq!(reader.container_type(), Some(IonType::List));
+/// assert_eq!(reader.annotations(), &["readings"]);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub struct IncrementalSequenceReader<R: Read> {
+    input: R,
+    buffer: Vec<u8>,
+    // The index in `buffer` of the first byte that has not been read yet.
+    position: usize,
+    // Whether `input` has reported the end of the stream.
+    is_eof: bool,
+    state: SequenceState,
+    annotations: Vec<Symbol>,
+    // The version markers and symbol tables that precede the container, which are read once the
+    // container is found.
+    prelude: Vec<u8>,
+    context: EncodingContext,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SequenceState {
+    NotStarted,
+    Reading(IonType),
+    Finished(IonType),
+}
+
+impl<R: Read> IncrementalSequenceReader<R> {
+    /// Constructs a reader for the list or s-expression at the beginning of `input`. Nothing is
+    /// read from `input` until the first call to [`next`](Self::next).
+    pub fn new(input: R) -> Self {
+        Self {
+            input,
+            buffer: Vec::new(),
+            position: 0,
+            is_eof: false,
+            state: SequenceState::NotStarted,
+            annotations: Vec::new(),
+            prelude: Vec::new(),
+            context: EncodingContext::for_ion_version(IonVersion::v1_0),
+        }
+    }
+
+    /// Returns the type of the container (a list or s-expression), or `None` if the beginning of
+    /// the container has not been read yet.
+    pub fn container_type(&self) -> Option<IonType> {
+        match self.state {
+            SequenceState::NotStarted => None,
+            SequenceState::Reading(ion_type) | SequenceState::Finished(ion_type) => Some(ion_type),
+        }
+    }
+
+    /// Returns the container's annotations. This is empty until the beginning of the container
+    /// has been read.
+    pub fn annotations(&self) -> &[Symbol] {
+        &self.annotations
+    }
+
+    /// Returns the next child of the container, or `Ok(None)` once the end of the container has
+    /// been reached. Returns an `Err` if the input does not begin with a list or s-expression, if
+    /// a child is malformed, or if the input ends before the container does.
+    #[allow(clippy::should_implement_trait)]
+    // ^-- This returns an `IonResult<Option<_>>` like `Reader::next`; the `Iterator` impl wraps it.
+    pub fn next(&mut self) -> IonResult<Option<Element>> {
+        loop {
+            let ion_type = match self.state {
+                SequenceState::NotStarted => {
+                    if !self.read_container_start()? {
+                        self.read_more()?;
+                    }
+                    continue;
+                }
+                SequenceState::Reading(ion_type) => ion_type,
+                SequenceState::Finished(_) => return Ok(None),
+            };
+            match self.read_child(ion_type) {
+                Ok(Some(element)) => return Ok(Some(element)),
+                Ok(None) => {
+                    self.state = SequenceState::Finished(ion_type);
+                    return Ok(None);
+                }
+                Err(IonError::Incomplete(_)) if !self.is_eof => self.read_more()?,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Looks for the (possibly annotated) opening delimiter of the container, setting aside any
+    /// version markers and symbol tables that precede it. Returns `false` if more data is needed
+    /// to find it.
+    fn read_container_start(&mut self) -> IonResult<bool> {
+        loop {
+            let mut input = TextBuffer::with_offset(
+                self.context.get_ref(),
+                self.position,
+                &self.buffer[self.position..],
+                self.is_eof,
+            );
+            let header = (|| {
+                input.match_optional_comments_and_whitespace()?;
+                let annotations = opt(TextBuffer::match_annotations).parse_next(&mut input)?;
+                input.match_optional_comments_and_whitespace()?;
+                Ok::<_, winnow::error::ErrMode<_>>((annotations, input.peek_byte()?))
+            })();
+            let (annotations, ion_type) = match header {
+                Ok((annotations, b'[')) => (annotations, IonType::List),
+                Ok((annotations, b'(')) => (annotations, IonType::SExp),
+                // Anything else has to be a system value that we can set aside.
+                Ok(_) => match self.read_prelude_item()? {
+                    true => continue,
+                    false => return Ok(false),
+                },
+                // Until the end of the stream, the data we have may be the beginning of something
+                // that will turn out to be valid, like an annotation.
+                _ if !self.is_eof => return Ok(false),
+                _ => {
+                    return IonResult::decoding_error(
+                        "expected the input to begin with a list or s-expression",
+                    )
+                }
+            };
+            // The annotations can only be resolved once the symbol tables have been applied.
+            let annotations = annotations.map(|a| a.offset()..a.offset() + a.len());
+            // Skip the opening delimiter.
+            self.position = input.offset() + 1;
+            self.apply_prelude()?;
+            if let Some(range) = annotations {
+                let context = self.context.get_ref();
+                let matched =
+                    TextBuffer::with_offset(context, range.start, &self.buffer[range], true);
+                self.annotations = Self::resolve_annotations(context, matched)?;
+            }
+            self.state = SequenceState::Reading(ion_type);
+            return Ok(true);
+        }
+    }
+
+    /// Copies the version marker or symbol table at the front of the buffer into the prelude.
+    /// Returns `false` if more data is needed to read it in full.
+    fn read_prelude_item(&mut self) -> IonResult<bool> {
+        let mut input = TextBuffer::with_offset(
+            self.context.get_ref(),
+            self.position,
+            &self.buffer[self.position..],
+            self.is_eof,
+        );
+        let item = input
+            .match_top_level_item_1_0()
+            .with_context("reading a value before the list or s-expression", input);
+        match item {
+            Ok(RawStreamItem::VersionMarker(marker)) if marker.major_minor() != (1, 0) => {
+                return IonResult::decoding_error(
+                    "an IncrementalSequenceReader can only read Ion 1.0 text",
+                )
+            }
+            Ok(RawStreamItem::VersionMarker(_) | RawStreamItem::Value(_)) => {}
+            Ok(RawStreamItem::EndOfStream(_)) if !self.is_eof => return Ok(false),
+            Ok(RawStreamItem::EndOfStream(_)) => {
+                return IonResult::decoding_error(
+                    "expected the input to begin with a list or s-expression",
+                )
+            }
+            Err(IonError::Incomplete(_)) if !self.is_eof => return Ok(false),
+            Err(e) => return Err(e),
+        }
+        let end = input.offset();
+        self.prelude
+            .extend_from_slice(&self.buffer[self.position..end]);
+        // Keep the items apart in case the next one begins right where this one ends.
+        self.prelude.push(b'\n');
+        self.position = end;
+        Ok(true)
+    }
+
+    /// Reads the items set aside by [`read_prelude_item`](Self::read_prelude_item) and replaces
+    /// the context with one holding the symbol table that they define.
+    fn apply_prelude(&mut self) -> IonResult<()> {
+        if self.prelude.is_empty() {
+            return Ok(());
+        }
+        let prelude = std::mem::take(&mut self.prelude);
+        let mut reader = SystemReader::new(TextEncoding_1_0, prelude.as_slice());
+        loop {
+            match reader.next_item()? {
+                SystemStreamItem::VersionMarker(_) | SystemStreamItem::SymbolTable(_) => {}
+                SystemStreamItem::EndOfStream(_) => break,
+                _ => return IonResult::decoding_error(
+                    "only version markers and symbol tables can precede the list or s-expression",
+                ),
+            }
+        }
+        let symbols = reader.symbol_table().application_symbols();
+        self.context = EncodingContext::builder(IonVersion::v1_0)
+            .with_symbols(symbols.iter().cloned())
+            .build()?;
+        Ok(())
+    }
+
+    fn resolve_annotations(
+        context: EncodingContextRef<'_>,
+        matched: TextBuffer<'_>,
+    ) -> IonResult<Vec<Symbol>> {
+        // The annotations have been matched in full, so they can be read as final data.
+        let input = TextBuffer::with_offset(context, matched.offset(), matched.bytes(), true);
+        RawTextAnnotationsIterator::new(input)
+            .map(|annotation| Ok(annotation?.resolve("an annotation", context)?.to_owned()))
+            .collect()
+    }
+
+    /// Reads the next child of the container, returning `Ok(None)` at the end of the container.
+    fn read_child(&mut self, ion_type: IonType) -> IonResult<Option<Element>> {
+        self.context.allocator_mut().reset();
+        let context = self.context.get_ref();
+        let input = TextBuffer::with_offset(
+            context,
+            self.position,
+            &self.buffer[self.position..],
+            self.is_eof,
+        );
+        let (child, remaining) = if ion_type == IonType::List {
+            let mut children = RawTextListIterator::<TextEncoding_1_0>::new(input);
+            (children.next(), children.remaining_input())
+        } else {
+            let mut children = RawTextSExpIterator::<TextEncoding_1_0>::new(input);
+            (children.next(), children.remaining_input())
+        };
+        let Some(child) = child else {
+            return Ok(None);
+        };
+        let element = Self::to_element(context, child?)?;
+        self.position = remaining.offset();
+        Ok(Some(element))
+    }
+
+    fn to_element<'top>(
+        context: EncodingContextRef<'top>,
+        child: LazyRawValueExpr<'top, TextEncoding_1_0>,
+    ) -> IonResult<Element> {
+        // Ion 1.0 does not have e-expressions.
+        let RawValueExpr::ValueLiteral(raw_value) = child;
+        let value = LazyExpandedValue::<TextEncoding_1_0>::from_literal(context, raw_value);
+        Element::try_from(LazyValue::new(value))
+    }
+
+    /// Discards the bytes that have already been read and appends another chunk of input to the
+    /// buffer.
+    fn read_more(&mut self) -> IonResult<()> {
+        if self.is_eof {
+            return IonResult::incomplete("reading a list or s-expression", self.buffer.len());
+        }
+        self.buffer.drain(..self.position);
+        self.position = 0;
+        let num_buffered = self.buffer.len();
+        self.buffer.resize(num_buffered + READ_CHUNK_SIZE, 0);
+        let num_read = self.input.read(&mut self.buffer[num_buffered..])?;
+        self.buffer.truncate(num_buffered + num_read);
+        self.is_eof = num_read == 0;
+        Ok(())
+    }
+}
+
+impl<R: Read> Iterator for IncrementalSequenceReader<R> {
+    type Item = IonResult<Element>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Sequence;
+    use std::io;
+
+    /// An input that returns at most `chunk_size` bytes from each call to `read`.
+    struct Trickle<'a> {
+        data: &'a [u8],
+        chunk_size: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = self.chunk_size.min(buf.len()).min(self.data.len());
+            buf[..len].copy_from_slice(&self.data[..len]);
+            self.data = &self.data[len..];
+            Ok(len)
+        }
+    }
+
+    fn read_children(data: &str, chunk_size: usize) -> IonResult<Vec<Element>> {
+        let input = Trickle {
+            data: data.as_bytes(),
+            chunk_size,
+        };
+        IncrementalSequenceReader::new(input).collect()
+    }
+
+    #[test]
+    fn read_list_in_small_chunks() -> IonResult<()> {
+        let data = r#"
+            // A comment before the list
+            [1, 2.5, "three", 'four', {five: [5]}, (six 6), 2024-01-01T, 123456789, null.int]
+        "#;
+        let expected = Element::read_one(data)?;
+        for chunk_size in [1, 2, 7, 1000] {
+            let children = read_children(data, chunk_size)?;
+            assert_eq!(
+                Sequence::from(children),
+                *expected.as_sequence().unwrap(),
+                "chunk size {chunk_size}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_annotated_sexp() -> IonResult<()> {
+        let data = "a::'b c'::(+ 1 foo::2 \"three\") trailing data is not read";
+        let mut reader = IncrementalSequenceReader::new(Trickle {
+            data: data.as_bytes(),
+            chunk_size: 3,
+        });
+        let mut children = Vec::new();
+        while let Some(child) = reader.next()? {
+            children.push(child);
+        }
+        assert_eq!(reader.container_type(), Some(IonType::SExp));
+        assert_eq!(reader.annotations(), &["a", "b c"]);
+        assert_eq!(
+            Sequence::from(children),
+            Element::read_all("'+' 1 foo::2 \"three\"")?
+        );
+        assert!(reader.next()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_symbols_from_preceding_symbol_tables() -> IonResult<()> {
+        let data = r#"
+            $ion_1_0
+            $ion_symbol_table::{symbols: ["alpha", "beta"]}
+            // Appends to the table above.
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["gamma"]}
+            $10::[$11, {$12: $10}, $ion_symbol_table]
+        "#;
+        for chunk_size in [1, 3, 1000] {
+            let mut reader = IncrementalSequenceReader::new(Trickle {
+                data: data.as_bytes(),
+                chunk_size,
+            });
+            let mut children = Vec::new();
+            while let Some(child) = reader.next()? {
+                children.push(child);
+            }
+            assert_eq!(reader.annotations(), &["alpha"]);
+            assert_eq!(
+                Sequence::from(children),
+                Element::read_all("beta {gamma: alpha} $ion_symbol_table")?
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn buffer_only_holds_the_current_child() -> IonResult<()> {
+        let num_children = 10_000;
+        let data = format!(
+            "[{}]",
+            (0..num_children)
+                .map(|i| format!("{{id: {i}, name: \"child {i}\"}}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let mut reader = IncrementalSequenceReader::new(data.as_bytes());
+        let mut count = 0;
+        while let Some(child) = reader.next()? {
+            assert_eq!(
+                child.as_struct().unwrap().get("id").unwrap().expect_i64()?,
+                count
+            );
+            assert!(reader.buffer.len() <= 2 * READ_CHUNK_SIZE);
+            count += 1;
+        }
+        assert_eq!(count, num_children);
+        Ok(())
+    }
+
+    #[test]
+    fn report_malformed_input() {
+        let cases = [
+            "{a: 1}",
+            "",
+            "[1, 2",
+            "[1, 2 3]",
+            "(1 2",
+            "$ion_symbol_table::{symbols: [\"a\"]}",
+            "1 [2]",
+            "$ion_1_1 [1]",
+        ];
+        for data in cases {
+            let result = read_children(data, 2);
+            assert!(result.is_err(), "{data:?} was accepted");
+        }
+    }
+}
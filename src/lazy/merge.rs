@@ -0,0 +1,304 @@
+use std::cmp::Ordering;
+use std::io::Write;
+
+use crate::ion_path::{IonPath, ToIonPath};
+use crate::lazy::decoder::Decoder;
+use crate::lazy::encoder::writer::Writer;
+use crate::lazy::encoding::Encoding;
+use crate::lazy::reader::Reader;
+use crate::lazy::split::copyable_raw_value;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::result::IonFailure;
+use crate::{Element, HasSpan, IonData, IonResult};
+
+/// The number of values that [`merge`] or [`merge_by`] wrote to its output.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MergeSummary {
+    values_per_reader: Vec<usize>,
+    num_copied: usize,
+}
+
+impl MergeSummary {
+    /// Returns the number of values that were read from each reader, in the order the readers
+    /// were provided.
+    pub fn values_per_reader(&self) -> &[usize] {
+        &self.values_per_reader
+    }
+
+    /// Returns the number of values that were written by copying their encoded bytes from the
+    /// input rather than re-encoding them.
+    pub fn num_copied(&self) -> usize {
+        self.num_copied
+    }
+
+    /// Returns the total number of values written to the output.
+    pub fn num_values(&self) -> usize {
+        self.values_per_reader.iter().sum()
+    }
+}
+
+/// Merges the top-level values of several readers whose values are each sorted by the value found
+/// at `key_path`, writing them to `writer` in sorted order.
+///
+/// Keys are compared using the total ordering provided by [`IonData`]; values that do not have a
+/// value at `key_path` sort before all others. Values with equal keys are written in the order of
+/// the readers that produced them. To compare keys in a different order, use [`merge_by`].
+///
+/// If a reader produces a value whose key sorts before that of the previous value from the same
+/// reader, `merge` returns an `Err`.
+///
+/// As in [`split`](crate::split), values whose encoding can be used as-is by `writer` are copied
+/// to the output rather than being re-encoded.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{merge, v1_0, Element, Reader, Writer};
+///
+/// let mut readers = vec![
+///     Reader::new(v1_0::Text, "{id: 1, name: a} {id: 4, name: d}")?,
+///     Reader::new(v1_0::Text, "{id: 2, name: b} {id: 3, name: c}")?,
+/// ];
+/// let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+/// let summary = merge(&mut readers, &mut writer, "id")?;
+/// assert_eq!(summary.values_per_reader(), &[2, 2]);
+///
+/// let merged = Element::read_all(writer.close()?)?;
+/// let expected = Element::read_all(
+///     "{id: 1, name: a} {id: 2, name: b} {id: 3, name: c} {id: 4, name: d}"
+/// )?;
+/// assert_eq!(merged, expected);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub fn merge<D, Input, E, Output>(
+    readers: &mut [Reader<D, Input>],
+    writer: &mut Writer<E, Output>,
+    key_path: impl ToIonPath,
+) -> IonResult<MergeSummary>
+where
+    D: Decoder,
+    Input: IonInput,
+    E: Encoding,
+    Output: Write,
+{
+    merge_by(readers, writer, key_path, |key1, key2| {
+        IonData::from(key1).cmp(&IonData::from(key2))
+    })
+}
+
+/// Like [`merge`], but compares keys using the provided function. The readers' values must be
+/// sorted in the order defined by `compare`.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{merge_by, v1_0, Element, Reader, Writer};
+///
+/// // Each stream is sorted by descending timestamp.
+/// let mut readers = vec![
+///     Reader::new(v1_0::Text, "{at: 2024-03-01T} {at: 2024-01-01T}")?,
+///     Reader::new(v1_0::Text, "{at: 2024-02-01T}")?,
+/// ];
+/// let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+/// merge_by(&mut readers, &mut writer, "at", |a, b| {
+///     let (a, b) = (a.as_timestamp().unwrap(), b.as_timestamp().unwrap());
+///     b.cmp(&a)
+/// })?;
+///
+/// let merged = Element::read_all(writer.close()?)?;
+/// let expected = Element::read_all("{at: 2024-03-01T} {at: 2024-02-01T} {at: 2024-01-01T}")?;
+/// assert_eq!(merged, expected);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub fn merge_by<D, Input, E, Output, F>(
+    readers: &mut [Reader<D, Input>],
+    writer: &mut Writer<E, Output>,
+    key_path: impl ToIonPath,
+    mut compare: F,
+) -> IonResult<MergeSummary>
+where
+    D: Decoder,
+    Input: IonInput,
+    E: Encoding,
+    Output: Write,
+    F: FnMut(&Element, &Element) -> Ordering,
+{
+    let key_path = key_path.to_ion_path()?;
+    let mut compare_keys = |key1: &Option<Element>, key2: &Option<Element>| match (key1, key2) {
+        (Some(key1), Some(key2)) => compare(key1, key2),
+        // Values without a key sort first.
+        _ => key1.is_some().cmp(&key2.is_some()),
+    };
+
+    let mut summary = MergeSummary {
+        values_per_reader: vec![0; readers.len()],
+        num_copied: 0,
+    };
+    // The next value from each reader, or `None` if the reader has been exhausted.
+    let mut heads = readers
+        .iter_mut()
+        .map(|reader| PendingValue::read_next::<D, Input, E>(reader, &key_path))
+        .collect::<IonResult<Vec<_>>>()?;
+
+    loop {
+        // Find the reader whose next value has the lowest key. The number of readers is expected
+        // to be small, so a linear scan is used instead of a heap.
+        let mut lowest: Option<usize> = None;
+        for (index, head) in heads.iter().enumerate() {
+            let Some(head) = head else { continue };
+            let is_lower = match lowest.and_then(|lowest| heads[lowest].as_ref()) {
+                // Only a strictly lower key replaces the current choice, keeping the merge stable.
+                Some(current) => compare_keys(&head.key, &current.key) == Ordering::Less,
+                None => true,
+            };
+            if is_lower {
+                lowest = Some(index);
+            }
+        }
+        let Some(index) = lowest else {
+            break;
+        };
+
+        let next = PendingValue::read_next::<D, Input, E>(&mut readers[index], &key_path)?;
+        if let Some(next) = &next {
+            let previous = heads[index].as_ref().expect("the lowest head is present");
+            if compare_keys(&next.key, &previous.key) == Ordering::Less {
+                return IonResult::illegal_operation(format!(
+                    "the values of reader {index} are not sorted: value {} sorts before the one before it",
+                    summary.values_per_reader[index] + 1
+                ));
+            }
+        }
+        let head = std::mem::replace(&mut heads[index], next).expect("the lowest head is present");
+        match head.value {
+            PendingValueKind::Encoded(bytes) => {
+                writer.write_encoded_value(&bytes)?;
+                summary.num_copied += 1;
+            }
+            PendingValueKind::Element(element) => {
+                writer.write(&element)?;
+            }
+        }
+        summary.values_per_reader[index] += 1;
+    }
+    Ok(summary)
+}
+
+/// A value that has been read from one of the inputs to [`merge_by`] but not yet written.
+///
+/// Because each `LazyValue` borrows its reader, it cannot be held while the other readers advance.
+/// Instead, the value's key is materialized and the value itself is stored as a copy of its
+/// encoded bytes (if the output writer can copy them) or as an `Element`.
+struct PendingValue {
+    key: Option<Element>,
+    value: PendingValueKind,
+}
+
+enum PendingValueKind {
+    Encoded(Vec<u8>),
+    Element(Element),
+}
+
+impl PendingValue {
+    fn read_next<D: Decoder, Input: IonInput, E: Encoding>(
+        reader: &mut Reader<D, Input>,
+        key_path: &IonPath,
+    ) -> IonResult<Option<Self>> {
+        let Some(value) = reader.next()? else {
+            return Ok(None);
+        };
+        let key = value
+            .get_path(key_path)?
+            .map(Element::try_from)
+            .transpose()?;
+        let value = match copyable_raw_value::<D, E>(&value)? {
+            Some(raw_value) => PendingValueKind::Encoded(raw_value.span().bytes().to_vec()),
+            None => PendingValueKind::Element(Element::try_from(value)?),
+        };
+        Ok(Some(PendingValue { key, value }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{v1_0, AnyEncoding, IonError};
+
+    fn text_readers(inputs: &[&'static str]) -> IonResult<Vec<Reader<AnyEncoding, &'static str>>> {
+        inputs
+            .iter()
+            .map(|input| Reader::new(AnyEncoding, *input))
+            .collect()
+    }
+
+    #[test]
+    fn merge_text_streams() -> IonResult<()> {
+        let mut readers = text_readers(&[
+            "{k: 1, from: a} {k: 3, from: a} {k: 3, from: a2} {k: 7, from: a}",
+            "",
+            "{k: 2, from: c} {k: 3, from: c} {k: 8, from: c}",
+            "{from: d} {k: 5, from: d}",
+        ])?;
+        let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+        let summary = merge(&mut readers, &mut writer, "k")?;
+        assert_eq!(summary.values_per_reader(), &[4, 0, 3, 2]);
+        assert_eq!(summary.num_values(), 9);
+        assert_eq!(summary.num_copied(), 9);
+        let merged = Element::read_all(writer.close()?)?;
+        let expected = Element::read_all(
+            r#"
+            {from: d}
+            {k: 1, from: a}
+            {k: 2, from: c}
+            {k: 3, from: a} {k: 3, from: a2} {k: 3, from: c}
+            {k: 5, from: d}
+            {k: 7, from: a}
+            {k: 8, from: c}
+            "#,
+        )?;
+        assert_eq!(merged, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn merge_binary_streams() -> IonResult<()> {
+        let encode = |text: &str| -> IonResult<Vec<u8>> {
+            v1_0::Binary::encode_all(&Element::read_all(text)?)
+        };
+        let inputs = [
+            encode(r#"{version: 1} {version: 4, name: "x"}"#)?,
+            encode(r#"{version: 2, symbol: foo} {version: 3}"#)?,
+        ];
+        let mut readers = inputs
+            .iter()
+            .map(|input| Reader::new(AnyEncoding, input.as_slice()))
+            .collect::<IonResult<Vec<_>>>()?;
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        let summary = merge(&mut readers, &mut writer, "version")?;
+        // `{version: 2, symbol: foo}` uses symbols that are not in the system symbol table.
+        assert_eq!(summary.num_copied(), 3);
+        let merged = Element::read_all(writer.close()?)?;
+        let expected = Element::read_all(
+            r#"{version: 1} {version: 2, symbol: foo} {version: 3} {version: 4, name: "x"}"#,
+        )?;
+        assert_eq!(merged, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn merge_detects_unsorted_input() -> IonResult<()> {
+        let mut readers = text_readers(&["1 2 3", "2 1"])?;
+        let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+        let result = merge(&mut readers, &mut writer, "");
+        assert!(matches!(result, Err(IonError::IllegalOperation(_))));
+        Ok(())
+    }
+}
@@ -1,24 +1,61 @@
 //! Provides an ergonomic, lazy view of an Ion stream that permits random access within each
 //! top level value.
 
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod aggregate;
 pub(crate) mod any_encoding;
 pub(crate) mod binary;
 pub(crate) mod bytes_ref;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod cancellation;
 pub(crate) mod decoder;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod element_ref;
 pub(crate) mod encoder;
 pub(crate) mod encoding;
 pub(crate) mod expanded;
+pub(crate) mod extract;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod incremental;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod merge;
 mod never;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod owned_value_ref;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod path_extractor;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod pipeline;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod predicate;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod progress;
+pub(crate) mod projection;
 pub(crate) mod raw_stream_item;
 pub(crate) mod raw_value_ref;
 pub(crate) mod reader;
+pub(crate) mod reader_limits;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod reader_state;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod sample;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod sort;
 pub(crate) mod sequence;
 pub(crate) mod span;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod split;
 pub(crate) mod str_ref;
 pub(crate) mod streaming_raw_reader;
 pub(crate) mod r#struct;
 pub(crate) mod system_reader;
 pub(crate) mod system_stream_item;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod table_growth;
 pub(crate) mod text;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod transform;
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub(crate) mod validation;
 pub(crate) mod value;
 pub(crate) mod value_ref;
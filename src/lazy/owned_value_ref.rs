@@ -0,0 +1,325 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::element::Value;
+use crate::lazy::decoder::Decoder;
+use crate::lazy::expanded::lazy_element::LazyElement;
+use crate::lazy::value_ref::ValueRef;
+use crate::result::IonFailure;
+use crate::{AnyEncoding, Bytes, Decimal, Element, Int, IonError, IonResult, IonType, Str};
+use crate::{Symbol, Timestamp};
+
+/// An owned counterpart to [`ValueRef`] that does not borrow from the reader.
+///
+/// Scalar variants own their data, so they can be stored for as long as needed after the reader
+/// has advanced. Container variants hold a [`LazyElement`], which shares ownership of the reader's
+/// buffers; the container's contents are not read until the `LazyElement` is.
+///
+/// Because `LazyElement`s share resources that are not thread-safe, an `OwnedValueRef` cannot be
+/// sent to another thread. Scalars can be sent by first converting them to a [`Value`], which
+/// does not require any further decoding.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{v1_0, OwnedValueRef, Reader, Value};
+///
+/// let mut reader = Reader::new(v1_0::Text, r#""hello" [1, 2, 3]"#)?;
+/// let greeting = OwnedValueRef::from(reader.expect_next()?.read()?);
+/// let list = OwnedValueRef::from(reader.expect_next()?.read()?);
+/// assert!(reader.next()?.is_none());
+///
+/// // Both values remain readable after the reader has moved on.
+/// assert_eq!(greeting.expect_string()?, "hello");
+/// let numbers = list.expect_list()?;
+/// assert_eq!(numbers.read()?.expect_list()?.iter().count(), 3);
+///
+/// // Scalars can be converted to a `Value` to send them to another thread.
+/// let value = Value::try_from(greeting)?;
+/// std::thread::spawn(move || assert_eq!(value, Value::String("hello".into())))
+///     .join()
+///     .unwrap();
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub enum OwnedValueRef<D: Decoder = AnyEncoding> {
+    Null(IonType),
+    Bool(bool),
+    Int(Int),
+    Float(f64),
+    Decimal(Decimal),
+    Timestamp(Timestamp),
+    String(Str),
+    Symbol(Symbol),
+    Blob(Bytes),
+    Clob(Bytes),
+    SExp(LazyElement<D>),
+    List(LazyElement<D>),
+    Struct(LazyElement<D>),
+}
+
+impl<'top, D: Decoder> From<ValueRef<'top, D>> for OwnedValueRef<D> {
+    fn from(value: ValueRef<'top, D>) -> Self {
+        use ValueRef::*;
+        match value {
+            Null(ion_type) => OwnedValueRef::Null(ion_type),
+            Bool(b) => OwnedValueRef::Bool(b),
+            Int(i) => OwnedValueRef::Int(i),
+            Float(f) => OwnedValueRef::Float(f),
+            Decimal(d) => OwnedValueRef::Decimal(d),
+            Timestamp(t) => OwnedValueRef::Timestamp(t),
+            String(s) => OwnedValueRef::String(s.into()),
+            Symbol(s) => OwnedValueRef::Symbol(s.into()),
+            Blob(b) => OwnedValueRef::Blob(b.into()),
+            Clob(c) => OwnedValueRef::Clob(c.into()),
+            SExp(s) => OwnedValueRef::SExp(s.as_value().to_owned()),
+            List(l) => OwnedValueRef::List(l.as_value().to_owned()),
+            Struct(s) => OwnedValueRef::Struct(s.as_value().to_owned()),
+        }
+    }
+}
+
+impl<D: Decoder> TryFrom<OwnedValueRef<D>> for Value {
+    type Error = IonError;
+
+    /// Converts the `OwnedValueRef` to a `Value`. Containers are read in full; any annotations on
+    /// them are discarded.
+    fn try_from(value: OwnedValueRef<D>) -> Result<Self, Self::Error> {
+        use OwnedValueRef::*;
+        let value = match value {
+            Null(ion_type) => Value::Null(ion_type),
+            Bool(b) => Value::Bool(b),
+            Int(i) => Value::Int(i),
+            Float(f) => Value::Float(f),
+            Decimal(d) => Value::Decimal(d),
+            Timestamp(t) => Value::Timestamp(t),
+            String(s) => Value::String(s),
+            Symbol(s) => Value::Symbol(s),
+            Blob(b) => Value::Blob(b),
+            Clob(c) => Value::Clob(c),
+            SExp(element) | List(element) | Struct(element) => {
+                Element::try_from(element)?.value().clone()
+            }
+        };
+        Ok(value)
+    }
+}
+
+impl<D: Decoder> PartialEq for OwnedValueRef<D> {
+    fn eq(&self, other: &Self) -> bool {
+        use OwnedValueRef::*;
+        match (self, other) {
+            (Null(i1), Null(i2)) => i1 == i2,
+            (Bool(b1), Bool(b2)) => b1 == b2,
+            (Int(i1), Int(i2)) => i1 == i2,
+            (Float(f1), Float(f2)) => f1 == f2,
+            (Decimal(d1), Decimal(d2)) => d1 == d2,
+            (Timestamp(t1), Timestamp(t2)) => t1 == t2,
+            (String(s1), String(s2)) => s1 == s2,
+            (Symbol(s1), Symbol(s2)) => s1 == s2,
+            (Blob(b1), Blob(b2)) => b1 == b2,
+            (Clob(c1), Clob(c2)) => c1 == c2,
+            // As with `ValueRef`, lazy containers are not compared.
+            _ => false,
+        }
+    }
+}
+
+impl<D: Decoder> Debug for OwnedValueRef<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use OwnedValueRef::*;
+        match self {
+            Null(ion_type) => write!(f, "null.{ion_type}"),
+            Bool(b) => write!(f, "{b}"),
+            Int(i) => write!(f, "{i}"),
+            Float(float) => write!(f, "{float}"),
+            Decimal(d) => write!(f, "{d}"),
+            Timestamp(t) => write!(f, "{t}"),
+            String(s) => write!(f, "{s:?}"),
+            Symbol(s) => write!(f, "{}", s.text().unwrap_or("$0")),
+            Blob(b) => write!(f, "blob ({} bytes)", b.as_ref().len()),
+            Clob(c) => write!(f, "clob ({} bytes)", c.as_ref().len()),
+            SExp(element) | List(element) | Struct(element) => {
+                write!(f, "{:?}", *element.read()?)
+            }
+        }
+    }
+}
+
+impl<D: Decoder> OwnedValueRef<D> {
+    pub fn ion_type(&self) -> IonType {
+        use OwnedValueRef::*;
+        match self {
+            Null(ion_type) => *ion_type,
+            Bool(_) => IonType::Bool,
+            Int(_) => IonType::Int,
+            Float(_) => IonType::Float,
+            Decimal(_) => IonType::Decimal,
+            Timestamp(_) => IonType::Timestamp,
+            String(_) => IonType::String,
+            Symbol(_) => IonType::Symbol,
+            Blob(_) => IonType::Blob,
+            Clob(_) => IonType::Clob,
+            SExp(_) => IonType::SExp,
+            List(_) => IonType::List,
+            Struct(_) => IonType::Struct,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, OwnedValueRef::Null(_))
+    }
+
+    pub fn expect_bool(&self) -> IonResult<bool> {
+        if let OwnedValueRef::Bool(b) = self {
+            Ok(*b)
+        } else {
+            self.type_mismatch("a bool")
+        }
+    }
+
+    pub fn expect_int(&self) -> IonResult<&Int> {
+        if let OwnedValueRef::Int(i) = self {
+            Ok(i)
+        } else {
+            self.type_mismatch("an int")
+        }
+    }
+
+    pub fn expect_i64(&self) -> IonResult<i64> {
+        self.expect_int()?.expect_i64()
+    }
+
+    pub fn expect_float(&self) -> IonResult<f64> {
+        if let OwnedValueRef::Float(f) = self {
+            Ok(*f)
+        } else {
+            self.type_mismatch("a float")
+        }
+    }
+
+    pub fn expect_decimal(&self) -> IonResult<&Decimal> {
+        if let OwnedValueRef::Decimal(d) = self {
+            Ok(d)
+        } else {
+            self.type_mismatch("a decimal")
+        }
+    }
+
+    pub fn expect_timestamp(&self) -> IonResult<&Timestamp> {
+        if let OwnedValueRef::Timestamp(t) = self {
+            Ok(t)
+        } else {
+            self.type_mismatch("a timestamp")
+        }
+    }
+
+    pub fn expect_string(&self) -> IonResult<&str> {
+        if let OwnedValueRef::String(s) = self {
+            Ok(s.text())
+        } else {
+            self.type_mismatch("a string")
+        }
+    }
+
+    pub fn expect_symbol(&self) -> IonResult<&Symbol> {
+        if let OwnedValueRef::Symbol(s) = self {
+            Ok(s)
+        } else {
+            self.type_mismatch("a symbol")
+        }
+    }
+
+    pub fn expect_lob(&self) -> IonResult<&[u8]> {
+        match self {
+            OwnedValueRef::Blob(b) | OwnedValueRef::Clob(b) => Ok(b.as_ref()),
+            _ => self.type_mismatch("a blob or clob"),
+        }
+    }
+
+    pub fn expect_list(&self) -> IonResult<&LazyElement<D>> {
+        if let OwnedValueRef::List(l) = self {
+            Ok(l)
+        } else {
+            self.type_mismatch("a list")
+        }
+    }
+
+    pub fn expect_sexp(&self) -> IonResult<&LazyElement<D>> {
+        if let OwnedValueRef::SExp(s) = self {
+            Ok(s)
+        } else {
+            self.type_mismatch("an s-expression")
+        }
+    }
+
+    pub fn expect_struct(&self) -> IonResult<&LazyElement<D>> {
+        if let OwnedValueRef::Struct(s) = self {
+            Ok(s)
+        } else {
+            self.type_mismatch("a struct")
+        }
+    }
+
+    fn type_mismatch<T>(&self, expected: &str) -> IonResult<T> {
+        IonResult::decoding_error(format!(
+            "expected {expected} but found a(n) {}",
+            self.ion_type()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::encoding::Encoding;
+    use crate::{v1_0, Reader};
+
+    #[test]
+    fn owned_scalars_outlive_the_reader() -> IonResult<()> {
+        let data = r#"null.int true 7 2.5e0 1.50 2024-01-01T "text" sym {{aGk=}} {{"lob"}}"#;
+        let elements = Element::read_all(data)?;
+        let binary = v1_0::Binary::encode_all(&elements)?;
+        let owned = {
+            let mut reader = Reader::new(v1_0::Binary, binary.as_slice())?;
+            let mut owned = Vec::new();
+            while let Some(value) = reader.next()? {
+                owned.push(OwnedValueRef::from(value.read()?));
+            }
+            owned
+        };
+        assert_eq!(owned[0], OwnedValueRef::Null(IonType::Int));
+        assert!(owned[1].expect_bool()?);
+        assert_eq!(owned[2].expect_i64()?, 7);
+        assert_eq!(owned[3].expect_float()?, 2.5);
+        assert_eq!(owned[4].expect_decimal()?, &Decimal::new(150, -2));
+        assert_eq!(owned[6].expect_string()?, "text");
+        assert_eq!(owned[7].expect_symbol()?, &Symbol::from("sym"));
+        assert_eq!(owned[8].expect_lob()?, b"hi");
+        assert_eq!(owned[9].expect_lob()?, b"lob");
+        assert!(owned[6].expect_symbol().is_err());
+
+        for (owned_value, element) in owned.into_iter().zip(elements.iter()) {
+            assert_eq!(&Value::try_from(owned_value)?, element.value());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn owned_containers_are_read_lazily() -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, "{a: [1, (2 3)], b: c}")?;
+        let strukt = reader.expect_next()?.read()?.expect_struct()?;
+        let field = OwnedValueRef::from(strukt.get_expected("a")?);
+        assert_eq!(field.ion_type(), IonType::List);
+        let list = field.expect_list()?;
+        let sexp = list.read()?.expect_list()?.iter().nth(1).unwrap()?;
+        assert_eq!(OwnedValueRef::from(sexp.read()?).ion_type(), IonType::SExp);
+        assert_eq!(
+            Value::try_from(field)?,
+            Element::read_one("[1, (2 3)]")?.value().clone()
+        );
+        Ok(())
+    }
+}
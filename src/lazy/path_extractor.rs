@@ -0,0 +1,369 @@
+use rustc_hash::FxHashMap;
+
+use crate::ion_path::{IonPath, PathComponent, ToIonPath};
+use crate::lazy::decoder::Decoder;
+use crate::lazy::reader::Reader;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::IonResult;
+
+/// A set of [`IonPath`]s to be found in each value in a stream.
+///
+/// All of the paths are searched for in a single pass over each value. Only the containers along
+/// the paths are read: struct fields that are not on any path are skipped without being read,
+/// and list and s-expression children are not read past the largest index on any path. In binary
+/// Ion, skipped values are stepped over using their length prefixes.
+///
+/// If a struct contains several fields with a name on a path, each of them is searched. A value
+/// that is on more than one path is reported once for each path.
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{Reader, SearchPaths};
+/// use ion_rs::v1_0::Text;
+///
+/// let search_paths = SearchPaths::new(["user.name", "scores[1]"])?;
+/// let mut reader = Reader::new(Text, r#"{user: {id: 1, name: "alice"}, scores: [7, 9, 4]}"#)?;
+/// let matches = search_paths.matches(reader.expect_next()?)?;
+/// assert_eq!(matches.len(), 2);
+/// let (path_index, name) = matches[0];
+/// assert_eq!(search_paths.paths()[path_index].to_string(), "user.name");
+/// assert_eq!(name.read()?.expect_string()?.text(), "alice");
+/// assert_eq!(matches[1].1.read()?.expect_i64()?, 9);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SearchPaths {
+    paths: Vec<IonPath>,
+    root: SearchNode,
+}
+
+/// A node in the tree of path components formed by a set of search paths.
+#[derive(Clone, Debug, Default)]
+struct SearchNode {
+    // The indexes of the paths that end at this node.
+    path_indexes: Vec<usize>,
+    fields: FxHashMap<String, SearchNode>,
+    indexes: FxHashMap<usize, SearchNode>,
+    // The largest key in `indexes`, if any.
+    max_index: Option<usize>,
+}
+
+impl SearchPaths {
+    /// Constructs a `SearchPaths` that will look for each of the specified paths. Returns an `Err`
+    /// if any of the paths is invalid.
+    pub fn new<P: ToIonPath>(paths: impl IntoIterator<Item = P>) -> IonResult<Self> {
+        let mut search_paths = Self::default();
+        for path in paths {
+            search_paths.add(path)?;
+        }
+        Ok(search_paths)
+    }
+
+    /// Adds `path` to the set and returns its index in [`paths`](Self::paths).
+    pub fn add(&mut self, path: impl ToIonPath) -> IonResult<usize> {
+        let path = path.to_ion_path()?.into_owned();
+        let path_index = self.paths.len();
+        let mut node = &mut self.root;
+        for component in path.components() {
+            node = match component {
+                PathComponent::Field(name) => node.fields.entry(name.clone()).or_default(),
+                PathComponent::Index(index) => {
+                    node.max_index = node.max_index.max(Some(*index));
+                    node.indexes.entry(*index).or_default()
+                }
+            };
+        }
+        node.path_indexes.push(path_index);
+        self.paths.push(path);
+        Ok(path_index)
+    }
+
+    /// Returns the paths in the order in which they were added.
+    pub fn paths(&self) -> &[IonPath] {
+        &self.paths
+    }
+
+    /// Returns each of the values in `value` that is at the end of one of the paths, paired with
+    /// the index of that path. Matches are returned in the order in which they appear in the
+    /// data.
+    pub fn matches<'top, D: Decoder>(
+        &self,
+        value: LazyValue<'top, D>,
+    ) -> IonResult<Vec<(usize, LazyValue<'top, D>)>> {
+        let mut matches = Vec::new();
+        self.root.search(value, &mut |path_index, value| {
+            matches.push((path_index, value));
+            Ok(())
+        })?;
+        Ok(matches)
+    }
+}
+
+impl SearchNode {
+    fn search<'top, D: Decoder>(
+        &self,
+        value: LazyValue<'top, D>,
+        on_match: &mut impl FnMut(usize, LazyValue<'top, D>) -> IonResult<()>,
+    ) -> IonResult<()> {
+        for &path_index in &self.path_indexes {
+            on_match(path_index, value)?;
+        }
+        if self.fields.is_empty() && self.indexes.is_empty() {
+            return Ok(());
+        }
+        match value.read()? {
+            ValueRef::Struct(strukt) if !self.fields.is_empty() => {
+                for field in &strukt {
+                    let field = field?;
+                    let name = field.name()?;
+                    let Some(child) = name.text().and_then(|text| self.fields.get(text)) else {
+                        // This field is not on any path; its value will not be read.
+                        continue;
+                    };
+                    child.search(field.value(), on_match)?;
+                }
+            }
+            ValueRef::List(list) => self.search_sequence(list.iter(), on_match)?,
+            ValueRef::SExp(sexp) => self.search_sequence(sexp.iter(), on_match)?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn search_sequence<'top, D: Decoder + 'top>(
+        &self,
+        children: impl Iterator<Item = IonResult<LazyValue<'top, D>>>,
+        on_match: &mut impl FnMut(usize, LazyValue<'top, D>) -> IonResult<()>,
+    ) -> IonResult<()> {
+        let Some(max_index) = self.max_index else {
+            return Ok(());
+        };
+        for (index, child) in children.take(max_index + 1).enumerate() {
+            let child = child?;
+            if let Some(node) = self.indexes.get(&index) {
+                node.search(child, on_match)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+type PathCallback<'a, D> = Box<dyn FnMut(LazyValue<'_, D>) -> IonResult<()> + 'a>;
+
+/// Invokes a callback for each value in a stream that is at the end of one of a set of
+/// registered paths.
+///
+/// Paths are searched for as described in [`SearchPaths`]. Callbacks are invoked in the order in
+/// which their values appear in the stream; if a callback returns an `Err`, extraction stops and
+/// the error is returned.
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{PathExtractor, Reader};
+/// use ion_rs::v1_0::Text;
+///
+/// let data = r#"
+///     {order: {id: 1, items: [{sku: "a1"}, {sku: "b2"}]}, notes: "..."}
+///     {order: {id: 2, items: [{sku: "c3"}]}}
+/// "#;
+/// let mut ids = Vec::new();
+/// let mut first_skus = Vec::new();
+/// let mut extractor = PathExtractor::new()
+///     .register("order.id", |value| {
+///         ids.push(value.read()?.expect_i64()?);
+///         Ok(())
+///     })?
+///     .register("order.items[0].sku", |value| {
+///         first_skus.push(value.read()?.expect_string()?.text().to_owned());
+///         Ok(())
+///     })?;
+/// let num_matches = extractor.extract(&mut Reader::new(Text, data)?)?;
+/// drop(extractor);
+/// assert_eq!(num_matches, 4);
+/// assert_eq!(ids, vec![1, 2]);
+/// assert_eq!(first_skus, vec!["a1", "c3"]);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub struct PathExtractor<'a, D: Decoder> {
+    search_paths: SearchPaths,
+    // The callback for each path in `search_paths`, in the same order.
+    callbacks: Vec<PathCallback<'a, D>>,
+}
+
+impl<'a, D: Decoder> PathExtractor<'a, D> {
+    pub fn new() -> Self {
+        Self {
+            search_paths: SearchPaths::default(),
+            callbacks: Vec::new(),
+        }
+    }
+
+    /// Arranges for `callback` to be invoked with each value found at `path`. Returns an `Err`
+    /// if `path` is invalid.
+    pub fn register(
+        mut self,
+        path: impl ToIonPath,
+        callback: impl FnMut(LazyValue<'_, D>) -> IonResult<()> + 'a,
+    ) -> IonResult<Self> {
+        self.search_paths.add(path)?;
+        self.callbacks.push(Box::new(callback));
+        Ok(self)
+    }
+
+    pub fn search_paths(&self) -> &SearchPaths {
+        &self.search_paths
+    }
+
+    /// Searches `value` for the registered paths, invoking the callbacks for any values that are
+    /// found. Returns the number of values found.
+    pub fn extract_from(&mut self, value: LazyValue<'_, D>) -> IonResult<usize> {
+        let mut num_matches = 0;
+        let callbacks = &mut self.callbacks;
+        self.search_paths
+            .root
+            .search(value, &mut |path_index, value| {
+                num_matches += 1;
+                callbacks[path_index](value)
+            })?;
+        Ok(num_matches)
+    }
+
+    /// Searches each of the remaining values in `reader` for the registered paths, invoking the
+    /// callbacks for any values that are found. Returns the number of values found.
+    pub fn extract<Input: IonInput>(&mut self, reader: &mut Reader<D, Input>) -> IonResult<usize> {
+        let mut num_matches = 0;
+        while let Some(value) = reader.next()? {
+            num_matches += self.extract_from(value)?;
+        }
+        Ok(num_matches)
+    }
+}
+
+impl<D: Decoder> Default for PathExtractor<'_, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{v1_0, AnyEncoding, Element, IonType};
+
+    const DATA: &str = r#"
+        {a: {b: [0, {c: 1}, (2 {c: 3})]}, a: {b: [4, {c: 5}]}, d: 6}
+        {a: [1, 2], d: {e: 7}}
+        [{a: {b: 8}}, 9, 10]
+        d::11
+    "#;
+
+    fn search_texts(paths: &[&str], data: &[u8]) -> IonResult<Vec<(usize, Element)>> {
+        let search_paths = SearchPaths::new(paths.iter().copied())?;
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        let mut found = Vec::new();
+        while let Some(value) = reader.next()? {
+            for (path_index, value) in search_paths.matches(value)? {
+                found.push((path_index, Element::try_from(value)?));
+            }
+        }
+        Ok(found)
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn matches_are_found_in_data_order() -> IonResult<()> {
+        let paths = ["a.b[1].c", "d", "[1]", "a.b[2][1].c", "d.e", ""];
+        let expected = vec![
+            (
+                5,
+                "{a: {b: [0, {c: 1}, (2 {c: 3})]}, a: {b: [4, {c: 5}]}, d: 6}",
+            ),
+            (0, "1"),
+            (3, "3"),
+            (0, "5"),
+            (1, "6"),
+            (5, "{a: [1, 2], d: {e: 7}}"),
+            (1, "{e: 7}"),
+            (4, "7"),
+            (5, "[{a: {b: 8}}, 9, 10]"),
+            (2, "9"),
+            (5, "d::11"),
+        ]
+        .into_iter()
+        .map(|(index, text)| Ok((index, Element::read_one(text)?)))
+        .collect::<IonResult<Vec<_>>>()?;
+
+        let elements = Element::read_all(DATA)?;
+        for data in [
+            DATA.as_bytes().to_vec(),
+            elements.encode_as(v1_0::Binary)?,
+            elements.encode_as(crate::v1_1::Binary)?,
+        ] {
+            assert_eq!(search_texts(&paths, &data)?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_paths_are_each_reported() -> IonResult<()> {
+        let found = search_texts(&["d", "d"], DATA.as_bytes())?;
+        let path_indexes: Vec<usize> = found.iter().map(|(index, _)| *index).collect();
+        assert_eq!(path_indexes, vec![0, 1, 0, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn extractor_invokes_callbacks() -> IonResult<()> {
+        let mut types = Vec::new();
+        let mut count = 0;
+        let mut extractor = PathExtractor::new()
+            .register("d", |value| {
+                types.push(value.ion_type());
+                Ok(())
+            })?
+            .register("a.b", |_| {
+                count += 1;
+                Ok(())
+            })?;
+        let num_matches = extractor.extract(&mut Reader::new(v1_0::Text, DATA)?)?;
+        drop(extractor);
+        assert_eq!(num_matches, 4);
+        assert_eq!(types, vec![IonType::Int, IonType::Struct]);
+        assert_eq!(count, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn callback_errors_stop_extraction() -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, DATA)?;
+        let mut extractor = PathExtractor::new().register("d", |value| {
+            value.read()?.expect_i64()?;
+            Ok(())
+        })?;
+        assert!(extractor.extract(&mut reader).is_err());
+        // Extraction stopped in the second top-level value.
+        assert_eq!(
+            Element::try_from(reader.expect_next()?)?,
+            Element::read_one("[{a: {b: 8}}, 9, 10]")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_paths_are_rejected() {
+        assert!(SearchPaths::new(["a[b]"]).is_err());
+        assert!(PathExtractor::<v1_0::Text>::new()
+            .register("a..b", |_| Ok(()))
+            .is_err());
+    }
+}
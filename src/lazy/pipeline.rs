@@ -0,0 +1,251 @@
+use std::io::Write;
+
+use crate::lazy::decoder::Decoder;
+use crate::lazy::encoder::writer::Writer;
+use crate::lazy::encoding::Encoding;
+use crate::lazy::predicate::Predicate;
+use crate::lazy::reader::Reader;
+use crate::lazy::split::copyable_raw_value;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::value::LazyValue;
+use crate::{Element, HasSpan, IonResult};
+
+type LazyFilter<'p, D> = Box<dyn FnMut(&LazyValue<'_, D>) -> IonResult<bool> + 'p>;
+type ElementFilter<'p> = Box<dyn FnMut(&Element) -> IonResult<bool> + 'p>;
+type ElementMap<'p> = Box<dyn FnMut(Element) -> IonResult<Element> + 'p>;
+
+enum ElementStage<'p> {
+    Filter(ElementFilter<'p>),
+    Map(ElementMap<'p>),
+}
+
+/// A sequence of filter and map stages applied to each top-level value of a reader.
+///
+/// Filters added before the first [`map`](Self::map) stage are evaluated against each
+/// [`LazyValue`], so they only pay to read the parts of the value that they inspect. Values that
+/// pass these filters are only materialized as [`Element`]s if a `map` stage is added; otherwise,
+/// [`write_to`](Self::write_to) writes them directly from the input, copying their encoded bytes
+/// when possible (see [`split`](crate::split)).
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{v1_0, Element, Pipeline, Reader, Writer};
+///
+/// let reader = Reader::new(v1_0::Text, r#"
+///     {name: "widget", price: 5}
+///     {name: "gadget", price: 25}
+///     {name: "gizmo", price: 12}
+/// "#)?;
+/// let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+/// let num_written = Pipeline::from(reader)
+///     .filter(|value| {
+///         let price = value.read()?.expect_struct()?.get_expected("price")?;
+///         Ok(price.expect_i64()? > 10)
+///     })
+///     .map(|element| Ok(element.expect_struct()?.get("name").unwrap().clone()))
+///     .write_to(&mut writer)?;
+/// assert_eq!(num_written, 2);
+///
+/// let output = Element::read_all(writer.close()?)?;
+/// assert_eq!(output, Element::read_all(r#""gadget" "gizmo""#)?);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub struct Pipeline<'p, D: Decoder, Input: IonInput> {
+    reader: Reader<D, Input>,
+    filters: Vec<LazyFilter<'p, D>>,
+}
+
+impl<D: Decoder, Input: IonInput> From<Reader<D, Input>> for Pipeline<'_, D, Input> {
+    fn from(reader: Reader<D, Input>) -> Self {
+        Self {
+            reader,
+            filters: Vec::new(),
+        }
+    }
+}
+
+impl<'p, D: Decoder, Input: IonInput> Pipeline<'p, D, Input> {
+    /// Adds a stage that discards values for which `predicate` returns `false`.
+    pub fn filter(
+        mut self,
+        predicate: impl FnMut(&LazyValue<'_, D>) -> IonResult<bool> + 'p,
+    ) -> Self {
+        self.filters.push(Box::new(predicate));
+        self
+    }
+
+    /// Adds a stage that discards values that do not match `predicate`.
+    pub fn filter_where(self, predicate: Predicate) -> Self {
+        self.filter(move |value| predicate.matches(value))
+    }
+
+    /// Adds a stage that replaces each value with the result of calling `transform` on it. Values
+    /// are materialized as [`Element`]s before being passed to `transform`.
+    pub fn map(
+        self,
+        transform: impl FnMut(Element) -> IonResult<Element> + 'p,
+    ) -> MappedPipeline<'p, D, Input> {
+        MappedPipeline {
+            pipeline: self,
+            stages: vec![ElementStage::Map(Box::new(transform))],
+        }
+    }
+
+    /// Runs the pipeline, writing each value that passes all of its filters to `writer`. Returns
+    /// the number of values written.
+    ///
+    /// The writer is not flushed; callers should flush or close it afterward.
+    pub fn write_to<E: Encoding, Output: Write>(
+        mut self,
+        writer: &mut Writer<E, Output>,
+    ) -> IonResult<usize> {
+        let mut num_written = 0;
+        self.for_each_value(|value| {
+            match copyable_raw_value::<D, E>(&value)? {
+                Some(raw_value) => writer.write_encoded_value(raw_value.span().bytes())?,
+                None => writer.write(value)?,
+            };
+            num_written += 1;
+            Ok(())
+        })?;
+        Ok(num_written)
+    }
+
+    /// Calls `action` with each value that passes all of the pipeline's filters.
+    fn for_each_value(
+        &mut self,
+        mut action: impl FnMut(LazyValue<'_, D>) -> IonResult<()>,
+    ) -> IonResult<()> {
+        'values: while let Some(value) = self.reader.next()? {
+            for filter in &mut self.filters {
+                if !filter(&value)? {
+                    continue 'values;
+                }
+            }
+            action(value)?;
+        }
+        Ok(())
+    }
+}
+
+/// A [`Pipeline`] to which at least one `map` stage has been added. Later stages operate on
+/// [`Element`]s.
+pub struct MappedPipeline<'p, D: Decoder, Input: IonInput> {
+    pipeline: Pipeline<'p, D, Input>,
+    stages: Vec<ElementStage<'p>>,
+}
+
+impl<'p, D: Decoder, Input: IonInput> MappedPipeline<'p, D, Input> {
+    /// Adds a stage that discards values for which `predicate` returns `false`.
+    pub fn filter(mut self, predicate: impl FnMut(&Element) -> IonResult<bool> + 'p) -> Self {
+        self.stages.push(ElementStage::Filter(Box::new(predicate)));
+        self
+    }
+
+    /// Adds a stage that replaces each value with the result of calling `transform` on it.
+    pub fn map(mut self, transform: impl FnMut(Element) -> IonResult<Element> + 'p) -> Self {
+        self.stages.push(ElementStage::Map(Box::new(transform)));
+        self
+    }
+
+    /// Runs the pipeline, writing each value that passes all of its stages to `writer`. Returns
+    /// the number of values written.
+    ///
+    /// The writer is not flushed; callers should flush or close it afterward.
+    pub fn write_to<E: Encoding, Output: Write>(
+        mut self,
+        writer: &mut Writer<E, Output>,
+    ) -> IonResult<usize> {
+        let mut num_written = 0;
+        let Self { pipeline, stages } = &mut self;
+        pipeline.for_each_value(|value| {
+            let mut element = Element::try_from(value)?;
+            for stage in stages.iter_mut() {
+                match stage {
+                    ElementStage::Filter(predicate) => {
+                        if !predicate(&element)? {
+                            return Ok(());
+                        }
+                    }
+                    ElementStage::Map(transform) => element = transform(element)?,
+                }
+            }
+            writer.write(&element)?;
+            num_written += 1;
+            Ok(())
+        })?;
+        Ok(num_written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{v1_0, AnyEncoding, IonType};
+
+    #[test]
+    fn pipeline_without_stages_copies_values() -> IonResult<()> {
+        let reader = Reader::new(AnyEncoding, "1 foo::[2, 3] {a: 4}")?;
+        let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+        assert_eq!(Pipeline::from(reader).write_to(&mut writer)?, 3);
+        let output = writer.close()?;
+        assert_eq!(
+            Element::read_all(output)?,
+            Element::read_all("1 foo::[2, 3] {a: 4}")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn pipeline_filters_and_maps() -> IonResult<()> {
+        let reader = Reader::new(AnyEncoding, "1 two 3 four 5 6")?;
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        let num_written = Pipeline::from(reader)
+            .filter(|value| Ok(value.ion_type() == IonType::Int))
+            .map(|element| Ok(Element::from(element.expect_i64()? * 10)))
+            .filter(|element| Ok(element.expect_i64()? != 30))
+            .map(|element| Ok(Element::from(element.expect_i64()? + 1)))
+            .write_to(&mut writer)?;
+        assert_eq!(num_written, 3);
+        let output = writer.close()?;
+        assert_eq!(Element::read_all(output)?, Element::read_all("11 51 61")?);
+        Ok(())
+    }
+
+    #[test]
+    fn pipeline_filters_with_predicate() -> IonResult<()> {
+        let reader = Reader::new(AnyEncoding, "{a: 1} {a: 7, b: x} {a: 9} {a: 12, b: y}")?;
+        let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+        let num_written = Pipeline::from(reader)
+            .filter_where(Predicate::parse("a > 5 && exists(b)")?)
+            .write_to(&mut writer)?;
+        assert_eq!(num_written, 2);
+        assert_eq!(
+            Element::read_all(writer.close()?)?,
+            Element::read_all("{a: 7, b: x} {a: 12, b: y}")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn pipeline_stops_at_first_error() -> IonResult<()> {
+        let reader = Reader::new(AnyEncoding, "1 2 3")?;
+        let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+        let result = Pipeline::from(reader)
+            .map(|element| {
+                if element.expect_i64()? == 2 {
+                    return crate::result::IonFailure::decoding_error("no twos allowed");
+                }
+                Ok(element)
+            })
+            .write_to(&mut writer);
+        assert!(result.is_err());
+        assert_eq!(Element::read_all(writer.close()?)?, Element::read_all("1")?);
+        Ok(())
+    }
+}
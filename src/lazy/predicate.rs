@@ -0,0 +1,619 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::Not;
+use std::str::FromStr;
+
+use crate::element::Value;
+use crate::ion_path::{IonPath, ToIonPath};
+use crate::lazy::decoder::Decoder;
+use crate::lazy::value::LazyValue;
+use crate::result::IonFailure;
+use crate::{Decimal, Element, IonData, IonError, IonResult, IonType};
+
+/// An operator used to compare the value at a path with a literal value in a [`Predicate`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComparisonOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl ComparisonOp {
+    pub(crate) fn evaluate(self, actual: &Value, expected: &Value) -> bool {
+        let Some(ordering) = compare_values(actual, expected) else {
+            // Values that have no natural ordering can only be tested for equality.
+            let is_equal = IonData::eq(actual, expected);
+            return match self {
+                ComparisonOp::Eq => is_equal,
+                ComparisonOp::Ne => !is_equal,
+                _ => false,
+            };
+        };
+        match self {
+            ComparisonOp::Eq => ordering.is_eq(),
+            ComparisonOp::Ne => ordering.is_ne(),
+            ComparisonOp::Lt => ordering.is_lt(),
+            ComparisonOp::Le => ordering.is_le(),
+            ComparisonOp::Gt => ordering.is_gt(),
+            ComparisonOp::Ge => ordering.is_ge(),
+        }
+    }
+}
+
+impl Display for ComparisonOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            ComparisonOp::Eq => "==",
+            ComparisonOp::Ne => "!=",
+            ComparisonOp::Lt => "<",
+            ComparisonOp::Le => "<=",
+            ComparisonOp::Gt => ">",
+            ComparisonOp::Ge => ">=",
+        };
+        f.write_str(text)
+    }
+}
+
+/// A condition that can be tested against a [`LazyValue`] without materializing the parts of the
+/// value that it does not refer to.
+///
+/// Predicates can be parsed from expressions like `a.b > 5 && type(c) == string`, which may use:
+/// * `path OP value`, which compares the value at an [`IonPath`] with an Ion literal using one
+///   of `==`, `!=`, `<`, `<=`, `>`, or `>=`.
+/// * `type(path) == name` and `type(path) != name`, which test the [`IonType`] of the value at a
+///   path. `name` is one of `null`, `bool`, `int`, `float`, `decimal`, `timestamp`, `symbol`,
+///   `string`, `clob`, `blob`, `list`, `sexp`, or `struct`.
+/// * `exists(path)`, which tests whether there is a value at a path.
+/// * `true` and `false`.
+/// * `!`, `&&`, and `||` (in decreasing order of precedence), and parentheses.
+///
+/// Passing an empty path to `type()` or `exists()` tests the value itself.
+///
+/// Ints, floats, and decimals are compared by their numeric value, strings and symbols are compared
+/// by their text, and timestamps are compared by the instant that they represent. Values of any
+/// other type can only be tested for equality, which ignores annotations. If there is no value at
+/// the path or the values cannot be compared, the comparison is `false` for every operator,
+/// including `!=`.
+///
+/// Predicates can also be constructed programmatically:
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{ComparisonOp, IonType, Predicate, Reader};
+/// use ion_rs::v1_0::Text;
+///
+/// let parsed = Predicate::parse("a.b > 5 && type(c) == string")?;
+/// let built = Predicate::compare("a.b", ComparisonOp::Gt, 5)?
+///     .and(Predicate::has_type("c", IonType::String)?);
+/// assert_eq!(parsed, built);
+///
+/// let mut reader = Reader::new(Text, r#"{a: {b: 7}, c: "yes"} {a: {b: 3}, c: "no"}"#)?;
+/// assert!(parsed.matches(&reader.expect_next()?)?);
+/// assert!(!parsed.matches(&reader.expect_next()?)?);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Predicate {
+    expr: Expr,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Expr {
+    Constant(bool),
+    Exists(IonPath),
+    HasType(IonPath, IonType),
+    Compare(IonPath, ComparisonOp, Element),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+impl Predicate {
+    /// Constructs a predicate that is `true` if there is a value at `path`.
+    pub fn exists(path: impl ToIonPath) -> IonResult<Self> {
+        Ok(Expr::Exists(path.to_ion_path()?.into_owned()).into())
+    }
+
+    /// Constructs a predicate that is `true` if there is a value of type `ion_type` at `path`.
+    pub fn has_type(path: impl ToIonPath, ion_type: IonType) -> IonResult<Self> {
+        Ok(Expr::HasType(path.to_ion_path()?.into_owned(), ion_type).into())
+    }
+
+    /// Constructs a predicate that compares the value at `path` with `value`. See [`Predicate`]
+    /// for a description of how values are compared.
+    pub fn compare(
+        path: impl ToIonPath,
+        op: ComparisonOp,
+        value: impl Into<Element>,
+    ) -> IonResult<Self> {
+        Ok(Expr::Compare(path.to_ion_path()?.into_owned(), op, value.into()).into())
+    }
+
+    /// Returns a predicate that is `true` if both `self` and `other` are. `other` is only
+    /// evaluated if `self` is `true`.
+    pub fn and(self, other: Predicate) -> Self {
+        Expr::And(Box::new(self.expr), Box::new(other.expr)).into()
+    }
+
+    /// Returns a predicate that is `true` if either `self` or `other` is. `other` is only
+    /// evaluated if `self` is `false`.
+    pub fn or(self, other: Predicate) -> Self {
+        Expr::Or(Box::new(self.expr), Box::new(other.expr)).into()
+    }
+
+    /// Parses a predicate expression like `a.b > 5 && type(c) == string`. See [`Predicate`] for
+    /// a description of the syntax.
+    pub fn parse(text: &str) -> IonResult<Self> {
+        let mut parser = Parser {
+            text,
+            tokens: tokenize(text)?,
+            position: 0,
+        };
+        let expr = parser.parse_or()?;
+        if parser.position < parser.tokens.len() {
+            return parser.error("found unexpected input after the end of the expression");
+        }
+        Ok(expr.into())
+    }
+
+    /// Tests whether `value` satisfies this predicate. Containers along each path are navigated
+    /// lazily; only the values at the ends of the paths used in comparisons are read in full.
+    pub fn matches<D: Decoder>(&self, value: &LazyValue<'_, D>) -> IonResult<bool> {
+        self.expr.evaluate(value)
+    }
+}
+
+impl Expr {
+    fn evaluate<D: Decoder>(&self, value: &LazyValue<'_, D>) -> IonResult<bool> {
+        let result = match self {
+            Expr::Constant(result) => *result,
+            Expr::Exists(path) => value.get_path(path)?.is_some(),
+            Expr::HasType(path, ion_type) => value
+                .get_path(path)?
+                .is_some_and(|value| value.ion_type() == *ion_type),
+            Expr::Compare(path, op, expected) => {
+                let Some(actual) = value.get_path(path)? else {
+                    return Ok(false);
+                };
+                // Check the types before reading the value so that (for example) a large
+                // container is not materialized only to be compared with an int.
+                if type_group(actual.ion_type()) != type_group(expected.ion_type()) {
+                    return Ok(false);
+                }
+                let actual = Element::try_from(actual)?;
+                op.evaluate(actual.value(), expected.value())
+            }
+            Expr::Not(expr) => !expr.evaluate(value)?,
+            Expr::And(left, right) => left.evaluate(value)? && right.evaluate(value)?,
+            Expr::Or(left, right) => left.evaluate(value)? || right.evaluate(value)?,
+        };
+        Ok(result)
+    }
+}
+
+impl From<Expr> for Predicate {
+    fn from(expr: Expr) -> Self {
+        Predicate { expr }
+    }
+}
+
+impl From<bool> for Predicate {
+    fn from(value: bool) -> Self {
+        Expr::Constant(value).into()
+    }
+}
+
+impl Not for Predicate {
+    type Output = Predicate;
+
+    fn not(self) -> Self::Output {
+        Expr::Not(Box::new(self.expr)).into()
+    }
+}
+
+impl FromStr for Predicate {
+    type Err = IonError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl TryFrom<&str> for Predicate {
+    type Error = IonError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::parse(text)
+    }
+}
+
+/// Values can only be compared with values in the same group.
+fn type_group(ion_type: IonType) -> IonType {
+    match ion_type {
+        IonType::Float | IonType::Decimal => IonType::Int,
+        IonType::Symbol => IonType::String,
+        other => other,
+    }
+}
+
+/// Returns the ordering of two values of types that have a natural ordering, or `None` if they
+/// cannot be ordered.
+pub(crate) fn compare_values(actual: &Value, expected: &Value) -> Option<Ordering> {
+    match (actual, expected) {
+        (Value::String(actual), Value::String(expected)) => {
+            Some(actual.text().cmp(expected.text()))
+        }
+        (Value::String(_) | Value::Symbol(_), Value::String(_) | Value::Symbol(_)) => {
+            Some(text(actual)?.cmp(text(expected)?))
+        }
+        (Value::Timestamp(actual), Value::Timestamp(expected)) => Some(actual.cmp(expected)),
+        (Value::Float(actual), _) => actual.partial_cmp(&to_f64(expected)?),
+        (_, Value::Float(expected)) => to_f64(actual)?.partial_cmp(expected),
+        _ => Some(to_decimal(actual)?.cmp(&to_decimal(expected)?)),
+    }
+}
+
+fn text(value: &Value) -> Option<&str> {
+    match value {
+        Value::String(text) => Some(text.text()),
+        Value::Symbol(symbol) => symbol.text(),
+        _ => None,
+    }
+}
+
+pub(crate) fn to_decimal(value: &Value) -> Option<Decimal> {
+    match value {
+        Value::Int(int) => Some(Decimal::from(*int)),
+        Value::Decimal(decimal) => Some(*decimal),
+        _ => None,
+    }
+}
+
+pub(crate) fn to_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Float(float) => Some(*float),
+        Value::Int(int) => int.as_i128().map(|int| int as f64),
+        Value::Decimal(decimal) => {
+            // Formatting the decimal and parsing the result rounds it to the nearest f64.
+            let coefficient = decimal.coefficient();
+            let sign = if coefficient.is_negative() { "-" } else { "" };
+            let magnitude = coefficient.magnitude().as_u128()?;
+            f64::from_str(&format!("{sign}{magnitude}e{}", decimal.exponent())).ok()
+        }
+        _ => None,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Token<'a> {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    Op(ComparisonOp),
+    Word(&'a str),
+}
+
+/// Punctuation tokens, with longer tokens before any of their prefixes.
+const PUNCTUATION: &[(&str, Token<'static>)] = &[
+    ("&&", Token::And),
+    ("||", Token::Or),
+    ("==", Token::Op(ComparisonOp::Eq)),
+    ("!=", Token::Op(ComparisonOp::Ne)),
+    ("<=", Token::Op(ComparisonOp::Le)),
+    (">=", Token::Op(ComparisonOp::Ge)),
+    ("<", Token::Op(ComparisonOp::Lt)),
+    (">", Token::Op(ComparisonOp::Gt)),
+    ("!", Token::Not),
+    ("(", Token::Open),
+    (")", Token::Close),
+];
+
+/// Splits `text` into punctuation and words. A word is a path, a type name, or an Ion literal; it
+/// ends at whitespace or punctuation that is not inside quotes or brackets.
+fn tokenize(text: &str) -> IonResult<Vec<Token<'_>>> {
+    let mut tokens = Vec::new();
+    let mut remaining = text.trim_start();
+    while !remaining.is_empty() {
+        if let Some((punctuation, token)) = PUNCTUATION
+            .iter()
+            .find(|(punctuation, _)| remaining.starts_with(punctuation))
+        {
+            tokens.push(*token);
+            remaining = remaining[punctuation.len()..].trim_start();
+            continue;
+        }
+        let length = word_length(text, remaining)?;
+        if length == 0 {
+            return IonResult::illegal_operation(format!(
+                "invalid predicate '{text}': unexpected '{}'",
+                remaining.chars().next().unwrap_or_default()
+            ));
+        }
+        tokens.push(Token::Word(&remaining[..length]));
+        remaining = remaining[length..].trim_start();
+    }
+    Ok(tokens)
+}
+
+fn word_length(text: &str, input: &str) -> IonResult<usize> {
+    let mut quote: Option<char> = None;
+    let mut depth = 0usize;
+    let mut chars = input.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match (quote, c) {
+            (Some(_), '\\') => {
+                chars.next();
+            }
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '[' | '{') => depth += 1,
+            (None, ']' | '}') => depth = depth.saturating_sub(1),
+            (None, c) if depth == 0 && (c.is_whitespace() || "()!=<>&|".contains(c)) => {
+                return Ok(index);
+            }
+            _ => {}
+        }
+    }
+    if quote.is_some() {
+        return IonResult::illegal_operation(format!(
+            "invalid predicate '{text}': found an unterminated quote"
+        ));
+    }
+    Ok(input.len())
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    tokens: Vec<Token<'a>>,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        self.position += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> IonResult<Expr> {
+        let mut expr = self.parse_and()?;
+        while self.peek() == Some(Token::Or) {
+            self.position += 1;
+            expr = Expr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> IonResult<Expr> {
+        let mut expr = self.parse_unary()?;
+        while self.peek() == Some(Token::And) {
+            self.position += 1;
+            expr = Expr::And(Box::new(expr), Box::new(self.parse_unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_unary(&mut self) -> IonResult<Expr> {
+        if self.peek() == Some(Token::Not) {
+            self.position += 1;
+            return Ok(Expr::Not(Box::new(self.parse_unary()?)));
+        }
+        self.parse_condition()
+    }
+
+    fn parse_condition(&mut self) -> IonResult<Expr> {
+        let word = match self.next() {
+            Some(Token::Open) => {
+                let expr = self.parse_or()?;
+                self.expect(Token::Close, "expected `)`")?;
+                return Ok(expr);
+            }
+            Some(Token::Word(word)) => word,
+            _ => return self.error("expected a condition"),
+        };
+        let next = self.peek();
+        match word {
+            "exists" if next == Some(Token::Open) => Ok(Expr::Exists(self.parse_argument()?)),
+            "type" if next == Some(Token::Open) => {
+                let path = self.parse_argument()?;
+                let op = self.next();
+                let Some(Token::Word(name)) = self.next() else {
+                    return self.error("expected a type name after `type(...)`");
+                };
+                let Some(ion_type) = parse_ion_type(name) else {
+                    return self.error(&format!("'{name}' is not an Ion type"));
+                };
+                let expr = Expr::HasType(path, ion_type);
+                match op {
+                    Some(Token::Op(ComparisonOp::Eq)) => Ok(expr),
+                    Some(Token::Op(ComparisonOp::Ne)) => Ok(Expr::Not(Box::new(expr))),
+                    _ => self.error("types can only be compared using `==` or `!=`"),
+                }
+            }
+            "true" | "false" if !matches!(next, Some(Token::Op(_))) => {
+                Ok(Expr::Constant(word == "true"))
+            }
+            _ => {
+                let path = IonPath::parse(word)?;
+                let Some(Token::Op(op)) = self.next() else {
+                    return self.error(&format!("expected a comparison after '{word}'"));
+                };
+                let Some(Token::Word(literal)) = self.next() else {
+                    return self.error(&format!("expected a value after '{word} {op}'"));
+                };
+                let value = Element::read_one(literal)
+                    .or_else(|_| self.error(&format!("'{literal}' is not a valid Ion value")))?;
+                Ok(Expr::Compare(path, op, value))
+            }
+        }
+    }
+
+    /// Parses the parenthesized path passed to `exists` or `type`.
+    fn parse_argument(&mut self) -> IonResult<IonPath> {
+        self.expect(Token::Open, "expected `(`")?;
+        let path = match self.peek() {
+            Some(Token::Word(word)) => {
+                self.position += 1;
+                IonPath::parse(word)?
+            }
+            _ => IonPath::new(),
+        };
+        self.expect(Token::Close, "expected `)`")?;
+        Ok(path)
+    }
+
+    fn expect(&mut self, token: Token<'_>, description: &str) -> IonResult<()> {
+        if self.next() != Some(token) {
+            return self.error(description);
+        }
+        Ok(())
+    }
+
+    fn error<T>(&self, description: &str) -> IonResult<T> {
+        IonResult::illegal_operation(format!("invalid predicate '{}': {description}", self.text))
+    }
+}
+
+fn parse_ion_type(name: &str) -> Option<IonType> {
+    let ion_type = match name {
+        "null" => IonType::Null,
+        "bool" => IonType::Bool,
+        "int" => IonType::Int,
+        "float" => IonType::Float,
+        "decimal" => IonType::Decimal,
+        "timestamp" => IonType::Timestamp,
+        "symbol" => IonType::Symbol,
+        "string" => IonType::String,
+        "clob" => IonType::Clob,
+        "blob" => IonType::Blob,
+        "list" => IonType::List,
+        "sexp" => IonType::SExp,
+        "struct" => IonType::Struct,
+        _ => return None,
+    };
+    Some(ion_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::reader::Reader;
+    use crate::AnyEncoding;
+    use rstest::rstest;
+
+    const DATA: &str = r#"
+        annotated::{
+            id: 7,
+            price: 12.50,
+            ratio: 0.5e0,
+            name: "widget",
+            kind: gadget,
+            created: 2024-03-01T12:00Z,
+            tags: [red, "blue"],
+            dims: {w: 3, h: 4},
+            nothing: null.int,
+            flag: true,
+        }
+    "#;
+
+    #[rstest]
+    #[case::int_eq("id == 7", true)]
+    #[case::int_ne("id != 7", false)]
+    #[case::int_gt("id > 5", true)]
+    #[case::int_le("id <= 6", false)]
+    #[case::int_vs_decimal("id == 7.00", true)]
+    #[case::int_vs_float("id < 7.5e0", true)]
+    #[case::decimal_precision_ignored("price == 12.5", true)]
+    #[case::decimal_vs_float("price > 12.4e0", true)]
+    #[case::float_vs_decimal("ratio == 0.5", true)]
+    #[case::string_eq(r#"name == "widget""#, true)]
+    #[case::string_vs_symbol("name == widget", true)]
+    #[case::symbol_lt("kind < 'zebra'", true)]
+    #[case::string_with_punctuation(r#"name != "a && b""#, true)]
+    #[case::timestamp_same_instant("created == 2024-03-01T07:00-05:00", true)]
+    #[case::timestamp_gt("created > 2024-01-01T", true)]
+    #[case::nested("dims.h >= 4", true)]
+    #[case::index("tags[1] == blue", true)]
+    #[case::list_eq("tags == [red, \"blue\"]", true)]
+    #[case::list_eq_ignores_annotations("tags == x::[red, \"blue\"]", true)]
+    #[case::struct_eq("dims == {h: 4, w: 3}", true)]
+    #[case::containers_are_unordered("dims > {h: 4}", false)]
+    #[case::typed_null_eq("nothing == null.int", true)]
+    #[case::typed_null_is_not_ordered("nothing < 5", false)]
+    #[case::bool_eq("flag == true", true)]
+    #[case::mismatched_types("name == 7", false)]
+    #[case::mismatched_types_ne("name != 7", false)]
+    #[case::missing_field("missing == 1", false)]
+    #[case::missing_field_ne("missing != 1", false)]
+    #[case::not_missing_field("!(missing == 1)", true)]
+    #[case::exists("exists(dims.w)", true)]
+    #[case::not_exists("exists(dims.d)", false)]
+    #[case::type_eq("type(name) == string", true)]
+    #[case::type_ne("type(kind) != string", true)]
+    #[case::type_of_self("type() == struct", true)]
+    #[case::typed_null_type("type(nothing) == int", true)]
+    #[case::constant("true", true)]
+    #[case::and("id > 5 && type(name) == string", true)]
+    #[case::or("id > 50 || flag == true", true)]
+    #[case::precedence("false && false || true", true)]
+    #[case::parentheses("false && (false || true)", false)]
+    #[case::double_negation("!!exists(id)", true)]
+    #[case::no_whitespace("id>5&&name!=gizmo", true)]
+    #[case::field_named_like_keyword("exists(type) || type == 1", false)]
+    fn evaluate(#[case] expression: &str, #[case] expected: bool) -> IonResult<()> {
+        let predicate = Predicate::parse(expression)?;
+        let mut reader = Reader::new(AnyEncoding, DATA)?;
+        let value = reader.expect_next()?;
+        assert_eq!(
+            predicate.matches(&value)?,
+            expected,
+            "'{expression}' parsed as {predicate:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn builder_matches_parser() -> IonResult<()> {
+        let built = Predicate::compare("a.b", ComparisonOp::Le, 5)?
+            .and(!Predicate::exists("c")?)
+            .or(Predicate::has_type("[0]", IonType::Struct)?.and(Predicate::from(true)));
+        let parsed = Predicate::parse("a.b <= 5 && !exists(c) || type([0]) == struct && true")?;
+        assert_eq!(built, parsed);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::missing_value("a ==")]
+    #[case::missing_operator("a 5")]
+    #[case::single_equals("a = 5")]
+    #[case::dangling_and("a == 1 &&")]
+    #[case::single_ampersand("a == 1 & b == 2")]
+    #[case::unbalanced_parentheses("(a == 1")]
+    #[case::trailing_input("a == 1 b")]
+    #[case::invalid_path("a..b == 1")]
+    #[case::invalid_literal("a == {b")]
+    #[case::unterminated_string(r#"a == "b"#)]
+    #[case::unknown_type("type(a) == number")]
+    #[case::type_ordering("type(a) < int")]
+    fn parse_invalid(#[case] text: &str) {
+        assert!(
+            Predicate::parse(text).is_err(),
+            "parsed invalid predicate '{text}'"
+        );
+    }
+}
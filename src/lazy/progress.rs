@@ -0,0 +1,129 @@
+/// How much of a stream a [`Reader`](crate::Reader) or [`Writer`](crate::Writer) has processed,
+/// as reported to a progress callback.
+///
+/// For a reader, `num_bytes` is the number of bytes of input that have been consumed; for a
+/// writer, it is the number of bytes that have been flushed to the output. Both count every byte,
+/// including those of IVMs and symbol tables.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Progress {
+    num_bytes: u64,
+    num_values: u64,
+}
+
+impl Progress {
+    /// Returns the number of bytes that have been read or written.
+    pub fn num_bytes(&self) -> u64 {
+        self.num_bytes
+    }
+
+    /// Returns the number of top-level application values that have been read or written.
+    pub fn num_values(&self) -> u64 {
+        self.num_values
+    }
+}
+
+/// Counts the values processed by a reader or writer and periodically passes its [`Progress`] to a
+/// user-provided callback.
+pub(crate) struct ProgressTracker {
+    callback: Box<dyn FnMut(Progress)>,
+    every_n_values: u64,
+    num_values: u64,
+    last_reported: Option<Progress>,
+}
+
+impl ProgressTracker {
+    /// Constructs a tracker that calls `callback` each time `every_n_values` more values have been
+    /// processed. If `every_n_values` is zero, it is treated as one.
+    pub(crate) fn new(every_n_values: usize, callback: impl FnMut(Progress) + 'static) -> Self {
+        Self {
+            callback: Box::new(callback),
+            every_n_values: every_n_values.max(1) as u64,
+            num_values: 0,
+            last_reported: None,
+        }
+    }
+
+    /// Counts a value, reporting progress if a report is due. `num_bytes` is the total number of
+    /// bytes processed so far.
+    pub(crate) fn record_value(&mut self, num_bytes: u64) {
+        self.num_values += 1;
+        if self.num_values % self.every_n_values == 0 {
+            self.report(num_bytes);
+        }
+    }
+
+    /// Stops counting the most recently recorded value, which was not written after all.
+    pub(crate) fn discard_value(&mut self) {
+        self.num_values = self.num_values.saturating_sub(1);
+    }
+
+    /// Reports the current progress unless it is unchanged since the last report.
+    pub(crate) fn report(&mut self, num_bytes: u64) {
+        let progress = Progress {
+            num_bytes,
+            num_values: self.num_values,
+        };
+        if self.last_reported != Some(progress) {
+            (self.callback)(progress);
+            self.last_reported = Some(progress);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::encoding::Encoding;
+    use crate::{v1_0, AnyEncoding, Element, IonResult, Reader, SequenceWriter, Writer};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn recorder() -> (Rc<RefCell<Vec<Progress>>>, impl FnMut(Progress) + 'static) {
+        let reports = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&reports);
+        (reports, move |progress| sink.borrow_mut().push(progress))
+    }
+
+    #[test]
+    fn reader_reports_progress() -> IonResult<()> {
+        let elements = Element::read_all("1 2 foo {a: 4} [5] 6 7")?;
+        let data = v1_0::Binary::encode_all(&elements)?;
+        let mut reader = Reader::new(AnyEncoding, data.as_slice())?;
+        let (reports, callback) = recorder();
+        reader.set_progress_callback(3, callback);
+        while reader.next()?.is_some() {}
+        // A second read at the end of the stream does not repeat the final report.
+        assert!(reader.next()?.is_none());
+
+        let reports = reports.borrow();
+        let num_values: Vec<u64> = reports.iter().map(Progress::num_values).collect();
+        assert_eq!(num_values, vec![3, 6, 7]);
+        assert!(reports
+            .windows(2)
+            .all(|p| p[0].num_bytes() < p[1].num_bytes()));
+        assert_eq!(reports.last().unwrap().num_bytes(), data.len() as u64);
+        Ok(())
+    }
+
+    #[test]
+    fn writer_reports_progress() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        let (reports, callback) = recorder();
+        writer.set_progress_callback(2, callback);
+        writer.write_all([1, 2, 3])?;
+        assert_eq!(reports.borrow().len(), 1);
+        assert_eq!(reports.borrow()[0].num_values(), 2);
+        // Nothing has been flushed yet, so only the IVM has been written.
+        assert_eq!(reports.borrow()[0].num_bytes(), 4);
+
+        writer.flush()?;
+        // Flushing again without writing anything does not produce another report.
+        writer.flush()?;
+        let output = writer.close()?;
+        let reports = reports.borrow();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[1].num_values(), 3);
+        assert_eq!(reports[1].num_bytes(), output.len() as u64);
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use rustc_hash::FxHashMap;
+
+use crate::element::builders::StructBuilder;
+use crate::ion_path::{PathComponent, ToIonPath};
+use crate::lazy::decoder::Decoder;
+use crate::lazy::value::LazyValue;
+use crate::result::IonFailure;
+use crate::{Annotations, Element, IntoAnnotatedElement, IonResult, IonType};
+
+/// A set of field paths to be extracted from each value in a stream.
+///
+/// Applying a `Projection` to a struct produces a struct containing only the fields along the
+/// projection's paths. Fields that are not on any path are skipped without being read; in binary
+/// Ion, this means that their bodies are stepped over using their length prefixes. Values at the
+/// end of a path are read in full.
+///
+/// If a struct contains several fields with a projected name, all of them are kept. Nested fields
+/// that do not contain anything on a path (for example, because they are not structs) are omitted,
+/// and top-level values that are not structs produce an empty struct. The annotations of structs
+/// along each path are retained.
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{Element, Projection, Reader};
+/// use ion_rs::v1_0::Text;
+///
+/// let data = r#"
+///     {id: 1, name: "foo", address: {city: "Seattle", zip: 98101}, tags: [a, b]}
+///     {id: 2, name: "bar", address: {city: "Denver"}}
+/// "#;
+/// let projection = Projection::new(["id", "address.city"])?;
+/// let mut reader = Reader::new(Text, data)?;
+/// assert_eq!(
+///     reader.next_projected(&projection)?,
+///     Some(Element::read_one(r#"{id: 1, address: {city: "Seattle"}}"#)?)
+/// );
+/// assert_eq!(
+///     reader.next_projected(&projection)?,
+///     Some(Element::read_one(r#"{id: 2, address: {city: "Denver"}}"#)?)
+/// );
+/// assert_eq!(reader.next_projected(&projection)?, None);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Projection {
+    root: ProjectionNode,
+}
+
+/// A node in the tree of field names formed by a projection's paths.
+#[derive(Clone, Debug, Default)]
+struct ProjectionNode {
+    // If `true`, a path ends at this node; the value here should be kept in its entirety.
+    is_selected: bool,
+    children: FxHashMap<String, ProjectionNode>,
+}
+
+impl Projection {
+    /// Constructs a `Projection` that will keep the values at each of the specified paths.
+    /// Returns an `Err` if any of the paths is invalid or contains an index; only field names
+    /// are supported.
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub fn new<P: ToIonPath>(paths: impl IntoIterator<Item = P>) -> IonResult<Self> {
+        let mut root = ProjectionNode::default();
+        for path in paths {
+            let path = path.to_ion_path()?;
+            let mut node = &mut root;
+            for component in path.components() {
+                let PathComponent::Field(name) = component else {
+                    return IonResult::illegal_operation(format!(
+                        "projection paths can only contain field names, found '{path}'"
+                    ));
+                };
+                node = node.children.entry(name.clone()).or_default();
+            }
+            node.is_selected = true;
+        }
+        Ok(Self { root })
+    }
+
+    /// Produces an [`Element`] containing only the parts of `value` that lie along this
+    /// projection's paths.
+    pub fn apply<D: Decoder>(&self, value: LazyValue<'_, D>) -> IonResult<Element> {
+        if self.root.is_selected {
+            return Element::try_from(value);
+        }
+        if value.ion_type() != IonType::Struct || value.is_null() {
+            return Ok(StructBuilder::new().build().into());
+        }
+        self.root.project_struct(value).map(|(element, _)| element)
+    }
+}
+
+impl ProjectionNode {
+    /// Returns the projected form of `value`, or `None` if nothing in it was selected.
+    fn project<D: Decoder>(&self, value: LazyValue<'_, D>) -> IonResult<Option<Element>> {
+        if self.is_selected {
+            return Element::try_from(value).map(Some);
+        }
+        if value.ion_type() != IonType::Struct || value.is_null() {
+            return Ok(None);
+        }
+        let (element, found_any) = self.project_struct(value)?;
+        Ok(found_any.then_some(element))
+    }
+
+    /// Returns a struct containing the projected form of each of the selected fields in
+    /// `value`, which must be a struct, and whether any such fields were found.
+    fn project_struct<D: Decoder>(&self, value: LazyValue<'_, D>) -> IonResult<(Element, bool)> {
+        let lazy_struct = value.read()?.expect_struct()?;
+        let mut builder = StructBuilder::new();
+        let mut found_any = false;
+        for field in &lazy_struct {
+            let field = field?;
+            let name = field.name()?;
+            let Some(child) = name.text().and_then(|text| self.children.get(text)) else {
+                // This field is not on any path; its value will not be read.
+                continue;
+            };
+            if let Some(projected) = child.project(field.value())? {
+                builder = builder.with_field(name, projected);
+                found_any = true;
+            }
+        }
+        let annotations: Annotations = value.annotations().try_into()?;
+        Ok((builder.build().with_annotations(annotations), found_any))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::encoder::writer::Writer;
+    use crate::lazy::encoding::BinaryEncoding_1_0;
+    use crate::lazy::predicate::Predicate;
+    use crate::write_config::WriteConfig;
+    use crate::{v1_0, ElementWriter, Reader, Sequence};
+
+    const DATA: &str = r#"
+        {id: 1, name: "foo", address: {city: "Seattle", zip: 98101}, tags: [a, b]}
+        {id: 2, id: 3, address: location::{city: "Denver", city: "Boulder"}}
+        {id: 4, address: "unknown"}
+        {name: "bar"}
+        [1, 2, 3]
+        null.struct
+    "#;
+
+    fn expected() -> IonResult<Sequence> {
+        Element::read_all(
+            r#"
+            {id: 1, address: {city: "Seattle"}}
+            {id: 2, id: 3, address: location::{city: "Denver", city: "Boulder"}}
+            {id: 4}
+            {}
+            {}
+            {}
+        "#,
+        )
+    }
+
+    fn read_projected<D: Decoder>(
+        mut reader: Reader<D, &[u8]>,
+        projection: &Projection,
+    ) -> IonResult<Sequence> {
+        let mut elements = Vec::new();
+        while let Some(element) = reader.next_projected(projection)? {
+            elements.push(element);
+        }
+        Ok(elements.into())
+    }
+
+    #[test]
+    fn project_text() -> IonResult<()> {
+        let projection = Projection::new(["id", "address.city"])?;
+        let reader = Reader::new(v1_0::Text, DATA.as_bytes())?;
+        assert_eq!(read_projected(reader, &projection)?, expected()?);
+        Ok(())
+    }
+
+    #[test]
+    fn project_binary() -> IonResult<()> {
+        let mut writer = Writer::new(WriteConfig::<BinaryEncoding_1_0>::new(), Vec::new())?;
+        writer.write_elements(&Element::read_all(DATA)?)?;
+        let binary_data = writer.close()?;
+        let projection = Projection::new(["id", "address.city"])?;
+        let reader = Reader::new(v1_0::Binary, binary_data.as_slice())?;
+        assert_eq!(read_projected(reader, &projection)?, expected()?);
+        Ok(())
+    }
+
+    #[test]
+    fn project_whole_value() -> IonResult<()> {
+        let projection = Projection::new([""])?;
+        let mut reader = Reader::new(v1_0::Text, "[1, 2, 3]")?;
+        assert_eq!(
+            reader.next_projected(&projection)?,
+            Some(Element::read_one("[1, 2, 3]")?)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn project_matching_values() -> IonResult<()> {
+        let projection = Projection::new(["id"])?;
+        let predicate = Predicate::parse("type(address) == struct")?;
+        let mut reader = Reader::new(v1_0::Text, DATA.as_bytes())?;
+        let mut elements = Vec::new();
+        while let Some(element) = reader.next_projected_where(&projection, &predicate)? {
+            elements.push(element);
+        }
+        assert_eq!(
+            Sequence::from(elements),
+            Element::read_all("{id: 1} {id: 2, id: 3}")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn index_paths_are_rejected() {
+        assert!(Projection::new(["tags[0]"]).is_err());
+        assert!(Projection::new(["a.'b"]).is_err());
+    }
+}
@@ -127,6 +127,140 @@ impl<M: Copy + Debug, V: Copy + Debug, E: Copy + Debug> RawStreamItem<M, V, E> {
     }
 }
 
+/// A NOP pad: a region of a binary Ion stream that readers are expected to skip.
+///
+/// Raw readers do not return NOP pads from `next()`. Tools that need to account for every byte of
+/// their input can use a binary raw reader's `next_including_nops()` method instead, which returns
+/// each NOP pad as a [`RawStreamItemOrNop::NopPad`], or iterate over its
+/// `items_including_nops()`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NopPad<'top> {
+    offset: usize,
+    bytes: &'top [u8],
+}
+
+impl<'top> NopPad<'top> {
+    pub(crate) fn new(offset: usize, bytes: &'top [u8]) -> Self {
+        Self { offset, bytes }
+    }
+
+    /// Returns the number of bytes in the NOP pad, including its opcode.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+impl HasRange for NopPad<'_> {
+    fn range(&self) -> Range<usize> {
+        self.offset..self.offset + self.bytes.len()
+    }
+}
+
+impl<'top> HasSpan<'top> for NopPad<'top> {
+    fn span(&self) -> Span<'top> {
+        Span::with_offset(self.offset, self.bytes)
+    }
+}
+
+/// Either a [`RawStreamItem`] or a [`NopPad`] that preceded it in the stream.
+#[derive(Debug, Copy, Clone)]
+pub enum RawStreamItemOrNop<
+    'top,
+    M: Debug + Copy + Clone,
+    V: Debug + Copy + Clone,
+    E: Debug + Copy + Clone,
+> {
+    Item(RawStreamItem<M, V, E>),
+    NopPad(NopPad<'top>),
+}
+
+pub type LazyRawStreamItemOrNop<'top, D> = RawStreamItemOrNop<
+    'top,
+    <D as Decoder>::VersionMarker<'top>,
+    <D as Decoder>::Value<'top>,
+    <D as Decoder>::EExp<'top>,
+>;
+
+impl<
+        M: Debug + Copy + Clone + HasRange,
+        V: Debug + Copy + Clone + HasRange,
+        E: Debug + Copy + Clone + HasRange,
+    > HasRange for RawStreamItemOrNop<'_, M, V, E>
+{
+    fn range(&self) -> Range<usize> {
+        match self {
+            RawStreamItemOrNop::Item(item) => item.range(),
+            RawStreamItemOrNop::NopPad(nop_pad) => nop_pad.range(),
+        }
+    }
+}
+
+impl<
+        'top,
+        M: Debug + Copy + Clone + HasSpan<'top>,
+        V: Debug + Copy + Clone + HasSpan<'top>,
+        E: Debug + Copy + Clone + HasSpan<'top>,
+    > HasSpan<'top> for RawStreamItemOrNop<'top, M, V, E>
+{
+    fn span(&self) -> Span<'top> {
+        match self {
+            RawStreamItemOrNop::Item(item) => item.span(),
+            RawStreamItemOrNop::NopPad(nop_pad) => nop_pad.span(),
+        }
+    }
+}
+
+impl<'top, M: Copy + Debug, V: Copy + Debug, E: Copy + Debug> RawStreamItemOrNop<'top, M, V, E> {
+    /// If this is a stream item, returns `Some(item)`. Otherwise, returns `None`.
+    pub fn item(&self) -> Option<RawStreamItem<M, V, E>> {
+        match self {
+            RawStreamItemOrNop::Item(item) => Some(*item),
+            RawStreamItemOrNop::NopPad(_) => None,
+        }
+    }
+
+    /// If this is a NOP pad, returns `Some(nop_pad)`. Otherwise, returns `None`.
+    pub fn nop_pad(&self) -> Option<NopPad<'top>> {
+        match self {
+            RawStreamItemOrNop::Item(_) => None,
+            RawStreamItemOrNop::NopPad(nop_pad) => Some(*nop_pad),
+        }
+    }
+}
+
+/// Returns an iterator that calls `next_including_nops` until it returns the end of the stream,
+/// which is not yielded, or an error, which is yielded once.
+pub(crate) fn items_including_nops<'top, M, V, E>(
+    mut next_including_nops: impl FnMut() -> IonResult<RawStreamItemOrNop<'top, M, V, E>>,
+) -> impl Iterator<Item = IonResult<RawStreamItemOrNop<'top, M, V, E>>>
+where
+    M: Debug + Copy + Clone,
+    V: Debug + Copy + Clone,
+    E: Debug + Copy + Clone,
+{
+    let mut is_done = false;
+    std::iter::from_fn(move || {
+        if is_done {
+            return None;
+        }
+        match next_including_nops() {
+            Ok(RawStreamItemOrNop::Item(RawStreamItem::EndOfStream(_))) => {
+                is_done = true;
+                None
+            }
+            Ok(item) => Some(Ok(item)),
+            Err(error) => {
+                is_done = true;
+                Some(Err(error))
+            }
+        }
+    })
+}
+
 /// Represents the end of a raw input stream.
 ///
 /// This type implements [`HasRange`] and [`HasSpan`]. These traits aren't especially useful for the
@@ -1,14 +1,27 @@
 #![allow(non_camel_case_types)]
 
-use crate::element::reader::ElementReader;
+use crate::element::reader::{ElementReader, OwnedElementIterator};
 use crate::element::Element;
+use crate::ion_path::ToIonPath;
+use crate::lazy::cancellation::CancellationToken;
 use crate::lazy::decoder::Decoder;
-use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::expanded::EncodingContextSnapshot;
+use crate::lazy::extract::FromLazyValue;
+use crate::lazy::predicate::Predicate;
+use crate::lazy::progress::{Progress, ProgressTracker};
+use crate::lazy::projection::Projection;
+use crate::lazy::reader_state::ReaderState;
+use crate::lazy::streaming_raw_reader::{IonInput, IonSlice};
 use crate::lazy::system_reader::SystemReader;
+use crate::lazy::table_growth::{TableGrowth, TableGrowthMonitor, TableGrowthWarning};
+use crate::lazy::transform::Transform;
 use crate::lazy::value::LazyValue;
 use crate::read_config::ReadConfig;
 use crate::result::IonFailure;
-use crate::{try_or_some_err, IonError, IonResult, MacroTable, SymbolTable};
+use crate::{
+    try_or_some_err, AnyEncoding, IonEncoding, IonError, IonResult, MacroTable, SymbolTable,
+};
+use std::ops::Range;
 
 /// An Ion reader that only reads each value that it visits upon request (that is: lazily).
 ///
@@ -62,6 +75,9 @@ use crate::{try_or_some_err, IonError, IonResult, MacroTable, SymbolTable};
 /// ```
 pub struct Reader<Encoding: Decoder, Input: IonInput> {
     system_reader: SystemReader<Encoding, Input>,
+    progress: Option<ProgressTracker>,
+    cancellation_token: Option<CancellationToken>,
+    table_growth_monitor: Option<TableGrowthMonitor>,
 }
 
 impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
@@ -99,7 +115,42 @@ impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
     #[allow(clippy::should_implement_trait)]
     // ^-- Clippy objects that the method name `next` will be confused for `Iterator::next()`
     pub fn next(&mut self) -> IonResult<Option<LazyValue<'_, Encoding>>> {
-        self.system_reader.next_value()
+        if let Some(token) = &self.cancellation_token {
+            token.check()?;
+        }
+        if self.progress.is_none() && self.table_growth_monitor.is_none() {
+            return self.system_reader.next_value();
+        }
+        let (value, position, table_growth) = self.system_reader.next_value_with_stats()?;
+        Self::record_stats(
+            &mut self.progress,
+            &mut self.table_growth_monitor,
+            value.is_some(),
+            position,
+            &table_growth,
+        );
+        Ok(value)
+    }
+
+    /// Reports a call to `next` or `skip_next` to the progress and table growth callbacks.
+    fn record_stats(
+        progress: &mut Option<ProgressTracker>,
+        table_growth_monitor: &mut Option<TableGrowthMonitor>,
+        found_value: bool,
+        position: usize,
+        table_growth: &TableGrowth,
+    ) {
+        if let Some(progress) = progress {
+            if found_value {
+                progress.record_value(position as u64);
+            } else {
+                // Report any values read since the last report.
+                progress.report(position as u64);
+            }
+        }
+        if let Some(monitor) = table_growth_monitor {
+            monitor.check(table_growth);
+        }
     }
 
     /// Like [`Self::next`], but returns an `IonError` if there are no more values in the stream.
@@ -107,7 +158,325 @@ impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
         self.next()?
             .ok_or_else(|| IonError::decoding_error("expected another top-level value"))
     }
-    
+
+    /// Advances the reader past the next top-level value without reading its body. Returns
+    /// `Ok(true)` if a value was skipped or `Ok(false)` if the end of the stream was reached.
+    ///
+    /// In binary Ion, each value's header encodes its length, so skipping a value (even a large
+    /// container) jumps over its body without examining its contents. Annotated structs and
+    /// s-expressions (which may be system values like a symbol table) and Ion 1.1 e-expressions are
+    /// processed as they would be by [`next`](Self::next), since the reader needs their effects to
+    /// correctly interpret the rest of the stream. Because a [`LazyValue`] that is not read is
+    /// skipped in the same way, there is no need to skip values found within a container; simply
+    /// move on to the next one.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_0, Element, Reader};
+    ///
+    /// let data = Element::read_all("1 [2, 3] {four: 4} 5")?.encode_as(v1_0::Binary)?;
+    /// let mut reader = Reader::new(v1_0::Binary, data)?;
+    /// // Skip every value but the last.
+    /// for _ in 0..3 {
+    ///     assert!(reader.skip_next()?);
+    /// }
+    /// let last = reader.expect_next()?;
+    /// assert_eq!(last.read()?.expect_i64()?, 5);
+    /// assert!(!reader.skip_next()?);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub fn skip_next(&mut self) -> IonResult<bool> {
+        if let Some(token) = &self.cancellation_token {
+            token.check()?;
+        }
+        if self.progress.is_none() && self.table_growth_monitor.is_none() {
+            return self.system_reader.skip_next_value();
+        }
+        let (found, position, table_growth) = self.system_reader.skip_next_value_with_stats()?;
+        Self::record_stats(
+            &mut self.progress,
+            &mut self.table_growth_monitor,
+            found,
+            position,
+            &table_growth,
+        );
+        Ok(found)
+    }
+
+    /// Returns an independent copy of this reader that will resume reading from the same position.
+    ///
+    /// This allows a caller to read ahead speculatively: if reading from one copy fails or turns
+    /// out to be the wrong interpretation of the data, the other copy is still positioned where
+    /// the attempt began. Both copies share their symbol and macro tables until either one
+    /// changes them, so cloning is cheap as long as the input is cheap to clone; prefer inputs
+    /// like `&[u8]` or `Rc<[u8]>` over `Vec<u8>`.
+    ///
+    /// Only readers over a fixed slice of data can be cloned; a streaming input like a `File`
+    /// cannot be rewound. Returns an `Err` if the reader is partway through the values produced
+    /// by an Ion 1.1 e-expression.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_0, Reader};
+    ///
+    /// let mut reader = Reader::new(v1_0::Text, "1 two 3")?;
+    /// let checkpoint = reader.try_clone()?;
+    /// // Speculatively try to read every value as an integer...
+    /// let mut sum = 0;
+    /// let attempt: IonResult<()> = (|| {
+    ///     while let Some(value) = reader.next()? {
+    ///         sum += value.read()?.expect_i64()?;
+    ///     }
+    ///     Ok(())
+    /// })();
+    /// assert!(attempt.is_err());
+    /// // ...and fall back to the clone, which is still at the beginning of the stream.
+    /// let mut reader = checkpoint;
+    /// assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 1);
+    /// assert_eq!(reader.expect_next()?.read()?.expect_symbol()?, "two");
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub fn try_clone(&self) -> IonResult<Self>
+    where
+        Input::DataSource: Clone,
+    {
+        Ok(Reader {
+            system_reader: self.system_reader.try_clone()?,
+            progress: None,
+            cancellation_token: self.cancellation_token.clone(),
+            table_growth_monitor: None,
+        })
+    }
+
+    /// Captures the reader's position in the stream along with its symbol table, macro table, and
+    /// modules. The resulting [`ReaderState`] can be written out as Ion and later passed to
+    /// [`resume_from_state`](Self::resume_from_state) to continue reading after the last value
+    /// that this reader returned.
+    ///
+    /// If the reader is partway through the expansion of an e-expression, returns an `Err`.
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub fn save_state(&self) -> IonResult<ReaderState> {
+        self.system_reader.save_state()
+    }
+
+    /// Constructs a reader that continues reading a stream from the point at which `state` was
+    /// saved. `input` must begin at [`state.stream_position()`](ReaderState::stream_position)
+    /// within the original stream; for example, a file that has been seeked to that offset.
+    ///
+    /// The positions of values read by the new reader are relative to the beginning of `input`,
+    /// but the state that it saves refers to a position in the original stream.
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub fn resume_from_state(
+        config: impl Into<ReadConfig<Encoding>>,
+        input: Input,
+        state: &ReaderState,
+    ) -> IonResult<Reader<Encoding, Input>> {
+        Ok(Reader {
+            system_reader: SystemReader::resume_from_state(config, input, state)?,
+            progress: None,
+            cancellation_token: None,
+            table_growth_monitor: None,
+        })
+    }
+
+    /// Moves the reader to `offset` in the stream, which must be the beginning of a top-level
+    /// value (or other top-level item, like a symbol table). The reader continues from there
+    /// with the symbol table, macro table, and modules recorded in `context`, which can be any
+    /// state [saved](Self::save_state) while that encoding context was in effect; the position
+    /// recorded in `context` is ignored.
+    ///
+    /// Together with the offsets reported by [`LazyValue::raw_range`], this allows an
+    /// application to build an index of a large stream and later jump directly to any value in
+    /// it. Because a single saved state can be used for every value that shares an encoding
+    /// context, the index only needs to save a new state when the context changes.
+    ///
+    /// Only readers over a fixed slice of data can seek. If `offset` is not within the input,
+    /// returns an `Err` and leaves the reader unchanged. Any e-expression that the reader was
+    /// partway through expanding is abandoned.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_0, Element, Reader};
+    ///
+    /// let data = Element::read_all(r#"{name: "a"} {name: "b"} {name: "c"}"#)?
+    ///     .encode_as(v1_0::Binary)?;
+    /// let mut reader = Reader::new(v1_0::Binary, data.as_slice())?;
+    /// // Record the offset of each value...
+    /// let mut offsets = Vec::new();
+    /// while let Some(value) = reader.next()? {
+    ///     offsets.push(value.raw_range().unwrap().start);
+    /// }
+    /// // ...and the encoding context that was used to read them.
+    /// let context = reader.save_state()?;
+    ///
+    /// // Later, jump straight to the second value.
+    /// reader.seek_to(offsets[1], &context)?;
+    /// let value = reader.expect_next()?.read()?.expect_struct()?;
+    /// assert_eq!(value.get_expected("name")?.expect_string()?, "b");
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub fn seek_to<Buffer: AsRef<[u8]>>(
+        &mut self,
+        offset: usize,
+        context: &ReaderState,
+    ) -> IonResult<()>
+    where
+        Input: IonInput<DataSource = IonSlice<Buffer>>,
+    {
+        self.system_reader.seek_to(offset, context)
+    }
+
+    /// Sets the token that the reader will check before advancing to each top-level value. Once
+    /// the token has been cancelled, [`next`](Self::next) and the methods built on it return
+    /// [`IonError::Cancelled`]. See [`CancellationToken`] for details.
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub fn set_cancellation_token(&mut self, token: CancellationToken) {
+        self.cancellation_token = Some(token);
+    }
+
+    /// Registers a callback that will be passed the reader's [`Progress`] each time it reads
+    /// another `every_n_values` top-level values, and once more when it reaches the end of the
+    /// stream. This allows long-running jobs to display their progress or emit heartbeats.
+    /// Replaces any callback that was registered previously.
+    ///
+    /// Copies of the reader made by [`try_clone`](Self::try_clone) do not report progress or table
+    /// growth.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    /// use ion_rs::{v1_0, Progress, Reader};
+    ///
+    /// let reports = Rc::new(RefCell::new(Vec::new()));
+    /// let mut reader = Reader::new(v1_0::Text, "1 2 3 4 5")?;
+    /// let sink = Rc::clone(&reports);
+    /// reader.set_progress_callback(2, move |progress: Progress| {
+    ///     sink.borrow_mut().push(progress.num_values())
+    /// });
+    /// while reader.next()?.is_some() {}
+    /// assert_eq!(*reports.borrow(), vec![2, 4, 5]);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub fn set_progress_callback(
+        &mut self,
+        every_n_values: usize,
+        callback: impl FnMut(Progress) + 'static,
+    ) {
+        self.progress = Some(ProgressTracker::new(every_n_values, callback));
+    }
+
+    /// Returns counters describing how the reader's symbol and macro tables have grown so far.
+    /// See [`TableGrowth`] for details.
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub fn table_growth(&self) -> TableGrowth {
+        self.system_reader.table_growth()
+    }
+
+    /// Returns the Ion encoding that the reader has detected in its input so far.
+    #[cfg_attr(not(feature = "experimental-serde"), allow(dead_code))]
+    pub(crate) fn detected_encoding(&self) -> IonEncoding {
+        self.system_reader.detected_encoding()
+    }
+
+    /// If the most recent call to [`next`](Self::next) failed because the value it found exceeded
+    /// the reader's [maximum value size](crate::ReaderLimits::with_max_value_size), returns the
+    /// range of that value. The value has been skipped, so reading can continue.
+    pub(crate) fn skipped_value(&self) -> Option<Range<usize>> {
+        self.system_reader.skipped_value()
+    }
+
+    /// Registers a callback that will be passed a [`TableGrowthWarning`] when the reader's symbol
+    /// table grows to more than `max_symbols` symbols or its macro table grows to more than
+    /// `max_macros` macros. This allows operators to detect producers that define a new symbol
+    /// for every value they write. Replaces any callback that was registered previously.
+    ///
+    /// Each table is checked as the reader advances to each top-level value, and causes a warning
+    /// when it first exceeds its threshold. It will not cause another warning until it has been
+    /// replaced by a table that is within the threshold. Pass `usize::MAX` to only monitor one of
+    /// the tables.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    /// use ion_rs::{v1_0, Reader, TableGrowthWarning};
+    ///
+    /// let data = r#"
+    ///     $ion_symbol_table::{symbols: ["id_1", "id_2", "id_3"]}
+    ///     {id_1: 1}
+    /// "#;
+    /// let warnings = Rc::new(RefCell::new(Vec::new()));
+    /// let mut reader = Reader::new(v1_0::Text, data)?;
+    /// let sink = Rc::clone(&warnings);
+    /// reader.set_table_growth_callback(2, usize::MAX, move |warning| {
+    ///     sink.borrow_mut().push(warning)
+    /// });
+    /// while reader.next()?.is_some() {}
+    /// assert_eq!(
+    ///     *warnings.borrow(),
+    ///     vec![TableGrowthWarning::Symbols { num_symbols: 3, threshold: 2 }]
+    /// );
+    /// assert_eq!(reader.table_growth().num_symbols_defined(), 3);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub fn set_table_growth_callback(
+        &mut self,
+        max_symbols: usize,
+        max_macros: usize,
+        callback: impl FnMut(TableGrowthWarning) + 'static,
+    ) {
+        self.table_growth_monitor =
+            Some(TableGrowthMonitor::new(max_symbols, max_macros, callback));
+    }
+
+    /// Returns a snapshot of the reader's encoding context, which
+    /// [`restore_context`](Self::restore_context) can later roll the context back to.
+    ///
+    /// This allows a reader that processes a series of independent requests to set up a shared
+    /// context once and discard each request's symbols, macros, and modules when it is done.
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub fn context_snapshot(&self) -> EncodingContextSnapshot {
+        self.system_reader.context_snapshot()
+    }
+
+    /// Rolls the reader's encoding context back to `snapshot`. See
+    /// [`EncodingContext::restore`](crate::EncodingContext::restore) for details.
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub fn restore_context(&mut self, snapshot: &EncodingContextSnapshot) -> IonResult<()> {
+        self.system_reader.restore_context(snapshot)
+    }
+
     #[allow(dead_code)]
     pub fn symbol_table(&self) -> &SymbolTable {
         self.system_reader.symbol_table()
@@ -119,13 +488,287 @@ impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
     }
 }
 
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
+    /// Reads up to `max_values` top-level values from the stream and returns them as a
+    /// [`ValueBatch`]. If the stream has fewer than `max_values` values remaining, the batch will
+    /// contain only the values that were available; an empty batch indicates that the end of the
+    /// stream has been reached.
+    ///
+    /// Unlike the [`LazyValue`]s returned by [`Reader::next`], the values in a batch do not borrow
+    /// the reader and remain valid for as long as the batch does. All of the values in a batch
+    /// share a single set of backing resources, which avoids paying for that setup once per value.
+    ///
+    /// To reuse a batch's storage across calls, see [`Reader::read_batch_into`].
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{AnyEncoding, Reader};
+    ///
+    /// let mut reader = Reader::new(AnyEncoding, "1 2 3 4 5")?;
+    ///
+    /// let batch = reader.read_batch(3)?;
+    /// assert_eq!(batch.len(), 3);
+    /// let mut sum = 0;
+    /// for value in &batch {
+    ///     sum += value.read()?.expect_i64()?;
+    /// }
+    /// assert_eq!(sum, 6);
+    ///
+    /// let batch = reader.read_batch(3)?;
+    /// assert_eq!(batch.len(), 2);
+    /// assert!(reader.read_batch(3)?.is_empty());
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn read_batch(&mut self, max_values: usize) -> IonResult<ValueBatch<Encoding>> {
+        let mut batch = ValueBatch::with_capacity(max_values);
+        self.read_batch_into(&mut batch, max_values)?;
+        Ok(batch)
+    }
+
+    /// Like [`Reader::read_batch`], but stores the values in an existing [`ValueBatch`],
+    /// reusing its storage. Any values already in `batch` are dropped before reading begins.
+    ///
+    /// Returns the number of values that were read. If an error is encountered, `batch` will
+    /// contain the values that were successfully read before the error.
+    pub fn read_batch_into(
+        &mut self,
+        batch: &mut ValueBatch<Encoding>,
+        max_values: usize,
+    ) -> IonResult<usize> {
+        // Dropping the previous batch's values releases their hold on the reader's allocator,
+        // allowing it to be cleared and reused instead of replaced.
+        batch.clear();
+        let result = self.fill_batch(batch, max_values);
+        // Whether or not the batch completed successfully, resume clearing the allocator
+        // between top-level values.
+        self.system_reader
+            .expanding_reader
+            .set_retain_allocations(false);
+        result
+    }
+
+    /// Advances to the next top-level value and returns an [`Element`] containing only the parts
+    /// of it that lie along the paths in `projection`. Fields that are not on any path are
+    /// skipped without being read. Returns `Ok(None)` at the end of the stream.
+    ///
+    /// See [`Projection`] for details.
+    pub fn next_projected(&mut self, projection: &Projection) -> IonResult<Option<Element>> {
+        self.next()?
+            .map(|value| projection.apply(value))
+            .transpose()
+    }
+
+    /// Advances to the next top-level value that matches `predicate` and returns its projection,
+    /// as in [`next_projected`](Self::next_projected). Values that do not match are skipped.
+    /// Returns `Ok(None)` at the end of the stream.
+    pub fn next_projected_where(
+        &mut self,
+        projection: &Projection,
+        predicate: &Predicate,
+    ) -> IonResult<Option<Element>> {
+        while let Some(value) = self.next()? {
+            if predicate.matches(&value)? {
+                return projection.apply(value).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Advances to the next top-level value and returns an [`Element`] containing the value with
+    /// the changes in `transform` applied. The values of dropped fields are skipped without being
+    /// read. Returns `Ok(None)` at the end of the stream.
+    ///
+    /// See [`Transform`] for details.
+    pub fn next_transformed(&mut self, transform: &Transform) -> IonResult<Option<Element>> {
+        self.next()?.map(|value| transform.apply(value)).transpose()
+    }
+
+    /// Reads each of the remaining top-level values in the stream and returns a `Vec` containing
+    /// the value found at `path` in each of them, converted to `T`. The `Vec` will have one entry
+    /// per top-level value; if a value does not contain anything at `path` or the value there is
+    /// null, its entry will be `None`. Containers along the path are navigated lazily, so only the
+    /// values at the end of the path are read in full.
+    ///
+    /// Returns an `Err` if `path` is invalid, if the stream is malformed, or if a value at `path`
+    /// cannot be converted to `T`.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{AnyEncoding, Reader};
+    ///
+    /// let data = r#"
+    ///     {order: {id: 1, total: 20}}
+    ///     {order: {id: 2}}
+    ///     {order: {id: 3, total: null}}
+    ///     {order: {id: 4, total: 5}}
+    /// "#;
+    /// let mut reader = Reader::new(AnyEncoding, data)?;
+    /// let totals: Vec<Option<i64>> = reader.extract_field("order.total")?;
+    /// assert_eq!(totals, vec![Some(20), None, None, Some(5)]);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn extract_field<T: FromLazyValue>(
+        &mut self,
+        path: impl ToIonPath,
+    ) -> IonResult<Vec<Option<T>>> {
+        let path = path.to_ion_path()?;
+        let mut column = Vec::new();
+        while let Some(value) = self.next()? {
+            let entry = match value.get_path(path.as_ref())? {
+                Some(field_value) if !field_value.is_null() => {
+                    Some(T::from_lazy_value(field_value)?)
+                }
+                _ => None,
+            };
+            column.push(entry);
+        }
+        Ok(column)
+    }
+
+    fn fill_batch(
+        &mut self,
+        batch: &mut ValueBatch<Encoding>,
+        max_values: usize,
+    ) -> IonResult<usize> {
+        while batch.len() < max_values {
+            let Some(value) = self.next()? else {
+                break;
+            };
+            batch.values.push(value.to_owned());
+            // Now that the batch holds a reference to the allocator, keep the reader from
+            // clearing it when it advances to the next top-level value.
+            self.system_reader
+                .expanding_reader
+                .set_retain_allocations(true);
+        }
+        Ok(batch.len())
+    }
+}
+
 impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
     pub fn new(
         config: impl Into<ReadConfig<Encoding>>,
         ion_data: Input,
     ) -> IonResult<Reader<Encoding, Input>> {
         let system_reader = SystemReader::new(config, ion_data);
-        Ok(Reader { system_reader })
+        Ok(Reader {
+            system_reader,
+            progress: None,
+            cancellation_token: None,
+            table_growth_monitor: None,
+        })
+    }
+}
+
+impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
+    /// Returns an iterator that materializes each remaining top-level value in the stream as an
+    /// [`Element`]. The iterator borrows the reader, which can be used again once the iterator has
+    /// been dropped.
+    ///
+    /// This is equivalent to [`ElementReader::elements`], but does not require the trait to be in
+    /// scope.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{AnyEncoding, Element, Reader};
+    ///
+    /// let mut reader = Reader::new(AnyEncoding, "1 2 3")?;
+    /// let first_two = reader.elements().take(2).collect::<IonResult<Vec<Element>>>()?;
+    /// assert_eq!(first_two, vec![Element::from(1), Element::from(2)]);
+    /// assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 3);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn elements(&mut self) -> ElementIterator<'_, Encoding, Input> {
+        ElementIterator { lazy_reader: self }
+    }
+
+    /// Converts the reader into an iterator that materializes each remaining top-level value in the
+    /// stream as an [`Element`]. The iterator owns the reader, so it can be returned from a
+    /// function or stored without borrowing anything but the reader's input. The reader keeps its
+    /// configuration, such as its [`Catalog`](crate::Catalog) and [`ReaderLimits`].
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_0, Decoder, Element, Reader, ReaderLimits};
+    ///
+    /// fn read_shallow(ion_text: String) -> IonResult<impl Iterator<Item = IonResult<Element>>> {
+    ///     let config = v1_0::Text.with_limits(ReaderLimits::new().with_max_depth(1));
+    ///     Ok(Reader::new(config, ion_text)?.into_elements())
+    /// }
+    ///
+    /// let mut elements = read_shallow("[1] [[2]]".to_string())?;
+    /// assert_eq!(elements.next().unwrap()?, Element::read_one("[1]")?);
+    /// assert!(elements.next().unwrap().is_err());
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn into_elements(self) -> OwnedElementIterator<Self> {
+        OwnedElementIterator::new(self)
+    }
+}
+
+/// A [`Reader`] that owns the complete buffer of Ion data that it reads from.
+///
+/// Because an `OwnedReader` does not borrow its input, it can be stored in long-lived structs and
+/// moved freely. Each [`LazyValue`] it produces borrows from the reader until the next call to
+/// [`Reader::next`]. The buffer can be any type that implements `AsRef<[u8]>`, including
+/// `Vec<u8>`, `Box<[u8]>`, `Arc<[u8]>`, and memory-mapped files or reference-counted byte
+/// buffers from other crates.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{AnyEncoding, OwnedReader};
+///
+/// struct Session {
+///     reader: OwnedReader,
+/// }
+///
+/// let data: Vec<u8> = b"1 2 3".to_vec();
+/// let mut session = Session {
+///     reader: OwnedReader::from_buffer(AnyEncoding, data)?,
+/// };
+/// let mut sum = 0;
+/// while let Some(value) = session.reader.next()? {
+///     sum += value.read()?.expect_i64()?;
+/// }
+/// assert_eq!(sum, 6);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+pub type OwnedReader<Buffer = Vec<u8>, Encoding = AnyEncoding> = Reader<Encoding, IonSlice<Buffer>>;
+
+impl<Encoding: Decoder, Buffer: AsRef<[u8]>> Reader<Encoding, IonSlice<Buffer>> {
+    /// Constructs an [`OwnedReader`] that takes ownership of `buffer`, which must contain the
+    /// complete Ion stream to be read.
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub fn from_buffer(
+        config: impl Into<ReadConfig<Encoding>>,
+        buffer: Buffer,
+    ) -> IonResult<OwnedReader<Buffer, Encoding>> {
+        Reader::new(config, IonSlice::new(buffer))
     }
 }
 
@@ -164,6 +807,84 @@ impl<Encoding: Decoder, Input: IonInput> Iterator for Reader<Encoding, Input> {
     }
 }
 
+/// A group of top-level values read from a stream by [`Reader::read_batch`] or
+/// [`Reader::read_batch_into`].
+///
+/// Each value in the batch is a [`LazyElement`], which owns (a share of) the resources it needs
+/// to be read. The values in a batch share a single bump allocator; that allocator is released
+/// when the batch is cleared or dropped.
+pub struct ValueBatch<Encoding: Decoder = AnyEncoding> {
+    values: Vec<LazyElement<Encoding>>,
+}
+
+impl<Encoding: Decoder> ValueBatch<Encoding> {
+    /// Constructs an empty `ValueBatch`.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Constructs an empty `ValueBatch` with room for `capacity` values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of values in the batch.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the batch does not contain any values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the value at `index` if it exists.
+    pub fn get(&self, index: usize) -> Option<&LazyElement<Encoding>> {
+        self.values.get(index)
+    }
+
+    /// Returns an iterator over the values in the batch.
+    pub fn iter(&self) -> std::slice::Iter<'_, LazyElement<Encoding>> {
+        self.values.iter()
+    }
+
+    /// Returns the values in the batch as a slice.
+    pub fn as_slice(&self) -> &[LazyElement<Encoding>] {
+        self.values.as_slice()
+    }
+
+    /// Drops all of the values in the batch, retaining its storage for reuse.
+    pub fn clear(&mut self) {
+        self.values.clear()
+    }
+}
+
+impl<Encoding: Decoder> Default for ValueBatch<Encoding> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Encoding: Decoder> IntoIterator for &'a ValueBatch<Encoding> {
+    type Item = &'a LazyElement<Encoding>;
+    type IntoIter = std::slice::Iter<'a, LazyElement<Encoding>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
+
+impl<Encoding: Decoder> IntoIterator for ValueBatch<Encoding> {
+    type Item = LazyElement<Encoding>;
+    type IntoIter = std::vec::IntoIter<LazyElement<Encoding>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
 #[allow(dead_code)] // TODO: Evaluate
 pub struct LazyElementIterator<'iter, Encoding: Decoder, Input: IonInput> {
     lazy_reader: &'iter mut Reader<Encoding, Input>,
@@ -254,6 +975,8 @@ where
     // Uses default implementations
 }
 
+/// Materializes each remaining top-level value in a [`Reader`]'s stream as an [`Element`]. See
+/// [`Reader::elements`].
 pub struct ElementIterator<'iter, Encoding: Decoder, Input: IonInput> {
     lazy_reader: &'iter mut Reader<Encoding, Input>,
 }
@@ -286,7 +1009,7 @@ impl<Encoding: Decoder, Input: IonInput> ElementReader for Reader<Encoding, Inpu
     }
 
     fn elements(&mut self) -> Self::ElementIterator<'_> {
-        ElementIterator { lazy_reader: self }
+        Reader::elements(self)
     }
 }
 
@@ -298,7 +1021,10 @@ mod tests {
     use crate::lazy::encoding::BinaryEncoding_1_0;
     use crate::lazy::value_ref::ValueRef;
     use crate::write_config::WriteConfig;
-    use crate::{ion_list, ion_sexp, ion_struct, v1_0, Int, IonResult, IonType};
+    use crate::{
+        ion_list, ion_sexp, ion_struct, v1_0, Int, IonResult, IonType, LeapSecondHandling,
+        MapCatalog, ReaderLimits, SharedSymbolTable, Symbol,
+    };
 
     use super::*;
 
@@ -393,12 +1119,559 @@ mod tests {
         assert_eq!(reader.read_next_element()?, None);
         Ok(())
     }
+
+    #[test]
+    fn read_batches() -> IonResult<()> {
+        let data = r#"
+            foo
+            [1, 2, 3]
+            {a: 1, b: (2 3)}
+            $ion_symbol_table::{symbols: ["bar"]}
+            $10
+            "hello"
+            2025T
+        "#;
+        let expected = Element::read_all(data)?;
+        let mut reader = Reader::new(crate::AnyEncoding, data)?;
+        let mut batch = ValueBatch::new();
+        let mut actual = Vec::new();
+        // Hold on to each batch until the following one has been read to confirm that the
+        // values remain valid after the reader advances.
+        let mut previous_batch = reader.read_batch(2)?;
+        while reader.read_batch_into(&mut batch, 2)? > 0 {
+            for value in &previous_batch {
+                actual.push(Element::try_from(value)?);
+            }
+            previous_batch = std::mem::take(&mut batch);
+        }
+        for value in &previous_batch {
+            actual.push(Element::try_from(value)?);
+        }
+        assert!(expected.iter().eq(&actual));
+        Ok(())
+    }
+
+    #[test]
+    fn read_batch_into_clears_previous_values() -> IonResult<()> {
+        let mut reader = Reader::new(crate::AnyEncoding, "1 2 3")?;
+        let mut batch = ValueBatch::new();
+        assert_eq!(reader.read_batch_into(&mut batch, 2)?, 2);
+        assert_eq!(batch.get(1).unwrap().read()?.expect_i64()?, 2);
+        assert_eq!(reader.read_batch_into(&mut batch, 2)?, 1);
+        assert_eq!(batch.as_slice()[0].read()?.expect_i64()?, 3);
+        assert_eq!(reader.read_batch_into(&mut batch, 2)?, 0);
+        assert!(batch.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn skip_next_does_not_read_container_bodies() -> IonResult<()> {
+        #[rustfmt::skip]
+        let data = [
+            0xE0, 0x01, 0x00, 0xEA, // IVM
+            0xB2, 0x13, 0x13,       // A list containing two invalid booleans
+            0x21, 0x05,             // 5
+        ];
+        let mut reader = Reader::new(v1_0::Binary, data.as_slice())?;
+        // Reading the list's contents would fail...
+        let list = reader.expect_next()?.read()?.expect_list()?;
+        assert!(list.iter().next().unwrap().and_then(|v| v.read()).is_err());
+        // ...but skipping over it does not.
+        let mut reader = Reader::new(v1_0::Binary, data.as_slice())?;
+        assert!(reader.skip_next()?);
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 5);
+        assert!(!reader.skip_next()?);
+        Ok(())
+    }
+
+    #[test]
+    fn skip_next_processes_symbol_tables() -> IonResult<()> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let data = to_binary_ion(
+            r#"
+            foo
+            $ion_symbol_table::{symbols: ["bar"]}
+            annotated::{a: 1}
+            bar
+        "#,
+        )?;
+        let counts = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&counts);
+        let mut reader = Reader::new(v1_0::Binary, data.as_slice())?;
+        reader.set_progress_callback(1, move |progress| {
+            sink.borrow_mut().push(progress.num_values())
+        });
+        assert!(reader.skip_next()?);
+        assert!(reader.skip_next()?);
+        // The symbol table was processed even though the values around it were skipped.
+        assert_eq!(reader.expect_next()?.read()?.expect_symbol()?, "bar");
+        assert!(!reader.skip_next()?);
+        assert_eq!(*counts.borrow(), vec![1, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn deferred_timestamp_parsing() -> IonResult<()> {
+        let data = r#"
+            2025T
+            2025-03-14
+            2025-03-14T15:09:26.535-07:00
+            {when: 2025-03-14T15:09Z, name: foo}
+        "#;
+        let expected = Element::read_all(data)?;
+        let config = v1_0::Text.with_deferred_timestamp_parsing(true);
+        let actual = Reader::new(config, data)?.read_all_elements()?;
+        assert!(expected.iter().eq(&actual));
+        Ok(())
+    }
+
+    #[test]
+    fn deferred_timestamp_parsing_reports_errors_on_read() -> IonResult<()> {
+        // Month 13 does not exist
+        let data = "2025-13-14 {when: 2025-13-14} 5";
+        let config = v1_0::Text.with_deferred_timestamp_parsing(true);
+        let mut reader = Reader::new(config, data)?;
+        // The timestamp can be skipped...
+        let timestamp = reader.expect_next()?;
+        assert_eq!(timestamp.ion_type(), IonType::Timestamp);
+        // ...but not read.
+        assert!(timestamp.read().is_err());
+        let strukt = reader.expect_next()?.read()?.expect_struct()?;
+        assert!(strukt.get("when").is_err());
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 5);
+
+        // Without deferral, the malformed timestamp cannot be matched at all.
+        let mut reader = Reader::new(v1_0::Text, data)?;
+        assert!(reader.next().is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn invalid_utf8_is_reported_when_text_is_read() -> IonResult<()> {
+        // Each of these values contains the invalid UTF-8 byte 0xFF.
+        let values: [&[u8]; 7] = [
+            b"\"a\xFFb\"",
+            b"\"a\\n\xFFb\"",
+            b"'''a\xFF''' '''b'''",
+            b"'a\xFFb'",
+            b"'a\\n\xFFb'",
+            b"{'a\xFFb': 1}",
+            b"foo::'a\xFF'::1",
+        ];
+        for value in values {
+            let mut data = value.to_vec();
+            data.extend_from_slice(b" 5");
+            let mut reader = Reader::new(v1_0::Text, data.as_slice())?;
+            // The value can be skipped...
+            let lazy_value = reader.expect_next()?;
+            // ...but its text cannot be read.
+            assert!(
+                Element::try_from(lazy_value).is_err(),
+                "{}",
+                String::from_utf8_lossy(value)
+            );
+            assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 5);
+        }
+
+        for mut data in [
+            Element::read_all(r#""ok" 5"#)?.encode_as(v1_0::Binary)?,
+            Element::read_all(r#""ok" 5"#)?.encode_as(crate::v1_1::Binary)?,
+        ] {
+            let index = data.windows(2).position(|bytes| bytes == b"ok").unwrap();
+            data[index] = 0xFF;
+            let mut reader = Reader::new(AnyEncoding, data.as_slice())?;
+            assert!(reader.expect_next()?.read().is_err());
+            assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 5);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn owned_readers_can_be_moved_between_values() -> IonResult<()> {
+        // Stands in for a byte buffer type from another crate, like a memory-mapped file.
+        struct SharedBuffer(std::rc::Rc<Vec<u8>>);
+        impl AsRef<[u8]> for SharedBuffer {
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_slice()
+            }
+        }
+
+        struct Holder<Buffer: AsRef<[u8]>> {
+            reader: OwnedReader<Buffer>,
+        }
+
+        fn read_rest<Buffer: AsRef<[u8]>>(holder: &mut Holder<Buffer>) -> IonResult<Vec<i64>> {
+            let mut values = Vec::new();
+            while let Some(value) = holder.reader.next()? {
+                values.push(value.read()?.expect_i64()?);
+            }
+            Ok(values)
+        }
+
+        fn check<Buffer: AsRef<[u8]>>(buffer: Buffer) -> IonResult<()> {
+            let mut reader = OwnedReader::from_buffer(AnyEncoding, buffer)?;
+            assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 1);
+            // Move the reader partway through the stream.
+            let mut holder = Box::new(Holder { reader });
+            assert_eq!(read_rest(&mut holder)?, vec![2, 3]);
+            Ok(())
+        }
+
+        let binary = to_binary_ion("1 2 3")?;
+        check(binary.clone())?;
+        check(binary.clone().into_boxed_slice())?;
+        check(std::sync::Arc::<[u8]>::from(binary.as_slice()))?;
+        check(SharedBuffer(std::rc::Rc::new(binary)))?;
+        check(String::from("1 2 3"))
+    }
+
+    #[test]
+    fn leap_seconds_are_rejected_by_default() -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, "2016-12-31T23:59:60Z")?;
+        assert!(reader.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn clamped_leap_seconds() -> IonResult<()> {
+        let data = "2016-12-31T23:59:60Z 2016-12-31T23:59:60.250Z";
+        let config = v1_0::Text.with_leap_second_handling(LeapSecondHandling::Clamp);
+        let actual = Reader::new(config, data)?.read_all_elements()?;
+        let expected = Element::read_all("2016-12-31T23:59:59Z 2016-12-31T23:59:59.250Z")?;
+        assert!(expected.iter().eq(&actual));
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn preserved_leap_seconds() -> IonResult<()> {
+        use crate::{AnyEncoding, Timestamp};
+
+        let data = r#"
+            2016-12-31T23:59:60Z
+            2016-12-31T23:59:60.250Z
+            2017-01-01T05:29:60.123456789+05:30
+            2016-12-31T23:59:60.1234567890123-00:00
+        "#;
+        let config = v1_0::Text.with_leap_second_handling(LeapSecondHandling::Preserve);
+        let elements = Reader::new(config, data)?.read_all_elements()?;
+        for element in &elements {
+            let timestamp = element.expect_timestamp()?;
+            assert!(timestamp.is_leap_second());
+            assert_eq!(timestamp.second(), 60);
+        }
+        let timestamp = elements.get(1).unwrap().expect_timestamp()?;
+        assert_eq!(timestamp.to_string(), "2016-12-31T23:59:60.250+00:00");
+        assert_eq!(timestamp.milliseconds(), 250);
+        assert_eq!(
+            elements.get(2).unwrap().expect_timestamp()?.to_string(),
+            "2017-01-01T05:29:60.123456789+05:30"
+        );
+
+        // A leap second sorts after every instant of the second it extends.
+        let before = Timestamp::with_ymd(2016, 12, 31)
+            .with_hms(23, 59, 59)
+            .with_milliseconds(999)
+            .with_offset(0)
+            .build()?;
+        let after = Timestamp::with_ymd(2017, 1, 1)
+            .with_hms(0, 0, 0)
+            .with_offset(0)
+            .build()?;
+        assert!(before < timestamp && timestamp < after);
+        assert_ne!(timestamp.to_string(), before.to_string());
+
+        // Leap seconds survive a round trip through each binary encoding.
+        let config = WriteConfig::<BinaryEncoding_1_0>::new();
+        let mut writer = Writer::new(config, Vec::new())?;
+        writer.write_elements(&elements)?;
+        let binary_1_0 = writer.close()?;
+        let mut writer = Writer::new(crate::v1_1::Binary, Vec::new())?;
+        writer.write_elements(&elements)?;
+        let binary_1_1 = writer.close()?;
+        for encoded in [binary_1_0, binary_1_1] {
+            let config = AnyEncoding.with_leap_second_handling(LeapSecondHandling::Preserve);
+            let actual = Reader::new(config, encoded.as_slice())?.read_all_elements()?;
+            assert!(elements.iter().eq(&actual));
+            // Without the option, the binary readers reject the leap seconds.
+            let mut reader = Reader::new(AnyEncoding, encoded.as_slice())?;
+            assert!(reader.expect_next()?.read().is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn strict_utf8_rejects_surrogate_escapes() -> IonResult<()> {
+        let data_and_expected = [
+            (r#""\uD83D\uDE00""#, r#""😀""#),
+            (r"'\uD83D\uDE00'", r"'😀'"),
+            (r"'''\U0000D83D\U0000DE00'''", r#""😀""#),
+            (r#"{"\uD83D\uDE00": 1}"#, r"{'😀': 1}"),
+        ];
+        for (data, expected) in data_and_expected {
+            // By default, the surrogate pair is decoded as the character that it represents.
+            let actual = Reader::new(v1_0::Text, data)?.read_all_elements()?;
+            assert_eq!(actual, Element::read_all(expected)?);
+
+            let config = v1_0::Text.with_strict_utf8(true);
+            let error = Reader::new(config, data)?.read_all_elements().unwrap_err();
+            let IonError::Decoding(error) = error else {
+                panic!("expected a decoding error, found {error:?}");
+            };
+            assert!(error.to_string().contains("U+D83D"), "{error}");
+            assert!(error.position().is_some());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_unicode_escapes_are_rejected() -> IonResult<()> {
+        for data in [r#""\U00110000""#, r#""\uDE00""#] {
+            for strict in [false, true] {
+                let config = v1_0::Text.with_strict_utf8(strict);
+                let result = Reader::new(config, data)?.read_all_elements();
+                assert!(matches!(result, Err(IonError::Decoding(_))), "{result:?}");
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn into_elements_keeps_reader_configuration() -> IonResult<()> {
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(SharedSymbolTable::new("shared_table", 1, ["foo"])?);
+        let limits = ReaderLimits::new().with_max_depth(3);
+        let config = v1_0::Text.with_catalog(catalog).with_limits(limits);
+        let data = r#"
+            $ion_symbol_table::{imports: [{name: "shared_table", version: 1}]}
+            $10 [[[1]]] [[[[2]]]] 3
+        "#;
+        let mut elements = Reader::new(config, data)?.into_elements();
+        assert_eq!(elements.next().unwrap()?, Element::symbol("foo"));
+        assert_eq!(
+            elements.next().unwrap()?,
+            ion_list![ion_list![ion_list![1]]].into()
+        );
+
+        // The reader resumes where the iterator left off.
+        let mut reader = elements.into_inner();
+        assert!(reader.read_next_element().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn limits_reject_deeply_nested_text() -> IonResult<()> {
+        let limits = ReaderLimits::new().with_max_depth(64);
+        let depth = 100_000;
+        let data = format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        let result =
+            Reader::new(v1_0::Text.with_limits(limits), data.as_str())?.read_all_elements();
+        let Err(IonError::Decoding(error)) = result else {
+            panic!("expected a decoding error, found {result:?}");
+        };
+        assert!(error.to_string().contains("64 levels"), "{error}");
+
+        let data = format!("{}{}", "[".repeat(64), "]".repeat(64));
+        let mut reader = Reader::new(v1_0::Text.with_limits(limits), data.as_str())?;
+        assert_eq!(reader.expect_next()?.ion_type(), IonType::List);
+        Ok(())
+    }
+
+    #[test]
+    fn limits_reject_deep_and_long_containers() -> IonResult<()> {
+        let limits = ReaderLimits::new()
+            .with_max_depth(2)
+            .with_max_container_length(3);
+        let accepted = "[1, 2, [3, 4, 5]] {a: (1 2 3)} (a b c) [[], {}]";
+        let rejected = [
+            "[1, 2, 3, 4]",
+            "{a: 1, b: 2, c: 3, d: 4}",
+            "{a: [1, 2, 3, 4]}",
+            "[[[]]]",
+            "{a: {b: {}}}",
+            "(1 (2 (3)))",
+        ];
+        let expected = Element::read_all(accepted)?;
+        let actual = Reader::new(v1_0::Text.with_limits(limits), accepted)?.read_all_elements()?;
+        assert_eq!(actual, expected);
+        let binary = to_binary_ion(accepted)?;
+        let actual = Reader::new(AnyEncoding.with_limits(limits), binary)?.read_all_elements()?;
+        assert_eq!(actual, expected);
+
+        for data in rejected {
+            let result = Reader::new(v1_0::Text.with_limits(limits), data)?.read_all_elements();
+            assert!(
+                matches!(result, Err(IonError::Decoding(_))),
+                "{data}: {result:?}"
+            );
+            let binary = to_binary_ion(data)?;
+            let result = Reader::new(AnyEncoding.with_limits(limits), binary)?.read_all_elements();
+            let Err(IonError::Decoding(error)) = result else {
+                panic!("expected a decoding error for {data}, found {result:?}");
+            };
+            assert!(error.position().is_some());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn limits_reject_large_symbol_tables() -> IonResult<()> {
+        let limits = ReaderLimits::new().with_max_symbols(2);
+        let data = r#"
+            $ion_symbol_table::{symbols: ["a", "b"]}
+            a
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["c"]}
+            c
+        "#;
+        let mut reader = Reader::new(v1_0::Text.with_limits(limits), data)?;
+        assert_eq!(reader.expect_next()?.read()?.expect_symbol()?, "a");
+        assert!(matches!(reader.next(), Err(IonError::Decoding(_))));
+
+        let binary = to_binary_ion("a b c")?;
+        let mut reader = Reader::new(v1_0::Binary.with_limits(limits), binary.as_slice())?;
+        assert!(matches!(reader.next(), Err(IonError::Decoding(_))));
+        let limits = limits.with_max_symbols(3);
+        let mut reader = Reader::new(v1_0::Binary.with_limits(limits), binary.as_slice())?;
+        assert_eq!(reader.read_all_elements()?, Element::read_all("a b c")?);
+        Ok(())
+    }
+
+    #[test]
+    fn limits_skip_large_values() -> IonResult<()> {
+        let data = r#"1 "a string that is too long" 2"#;
+        let limits = ReaderLimits::new().with_max_value_size(10);
+        let mut reader = Reader::new(v1_0::Text.with_limits(limits), data)?;
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 1);
+        assert!(reader.next().is_err());
+        assert_eq!(reader.skipped_value(), Some(2..29));
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 2);
+        assert_eq!(reader.skipped_value(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn limits_stop_buffering_large_values() -> IonResult<()> {
+        use std::io::Read;
+        // A string that never ends; reading all of it would exhaust the available memory.
+        let input = b"1 \"".chain(std::io::repeat(b'a'));
+        let limits = ReaderLimits::new().with_max_value_size(1024);
+        let mut reader = Reader::new(v1_0::Text.with_limits(limits), crate::IonStream::new(input))?;
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 1);
+        let result = reader.next();
+        assert!(matches!(result, Err(IonError::Decoding(_))), "{result:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn extract_field() -> IonResult<()> {
+        let text_data = r#"
+            {user: {name: "Alice", visits: 3}, tags: [a, b]}
+            {user: {name: "Bob"}}
+            {user: "anonymous", user: {visits: 7}}
+            {user: {name: null.string, visits: null}}
+            [user, name]
+            17
+        "#;
+        let binary_data = to_binary_ion(text_data)?;
+
+        let mut reader = Reader::new(v1_0::Text, text_data)?;
+        let names: Vec<Option<String>> = reader.extract_field("user.name")?;
+        let mut reader = Reader::new(v1_0::Binary, binary_data.as_slice())?;
+        let binary_names: Vec<Option<String>> = reader.extract_field("user.name")?;
+        let expected_names = vec![
+            Some("Alice".to_string()),
+            Some("Bob".to_string()),
+            None,
+            None,
+            None,
+            None,
+        ];
+        assert_eq!(names, expected_names);
+        assert_eq!(binary_names, expected_names);
+
+        // When a struct has repeated fields, the first is used.
+        let mut reader = Reader::new(v1_0::Binary, binary_data.as_slice())?;
+        let visits: Vec<Option<u64>> = reader.extract_field("user.visits")?;
+        assert_eq!(visits, vec![Some(3), None, None, None, None, None]);
+
+        let mut reader = Reader::new(v1_0::Text, text_data)?;
+        let tags: Vec<Option<Element>> = reader.extract_field("tags")?;
+        assert_eq!(
+            tags[0],
+            Some(ion_list![Symbol::from("a"), Symbol::from("b")].into())
+        );
+        assert!(tags[1..].iter().all(Option::is_none));
+        Ok(())
+    }
+
+    #[test]
+    fn extract_field_with_wrong_type() -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, "{a: 1} {a: 2.5e0} {a: 3}")?;
+        assert!(reader.extract_field::<i64>("a").is_err());
+        let mut reader = Reader::new(v1_0::Text, "{a: 1}")?;
+        assert!(reader.extract_field::<i64>("a[").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn cloned_readers_are_independent() -> IonResult<()> {
+        fn check<Input: IonInput>(mut reader: Reader<AnyEncoding, Input>) -> IonResult<()>
+        where
+            Input::DataSource: Clone,
+        {
+            assert_eq!(reader.expect_next()?.read()?.expect_symbol()?, "foo");
+            let mut clone = reader.try_clone()?;
+            // Reading ahead on the clone does not affect the original...
+            assert_eq!(clone.expect_next()?.read()?.expect_symbol()?, "bar");
+            assert_eq!(clone.expect_next()?.read()?.expect_symbol()?, "foo");
+            assert!(clone.next()?.is_none());
+            // ...and vice versa.
+            assert_eq!(reader.expect_next()?.read()?.expect_symbol()?, "bar");
+            let mut clone = reader.try_clone()?;
+            assert_eq!(reader.expect_next()?.read()?.expect_symbol()?, "foo");
+            assert!(reader.next()?.is_none());
+            assert_eq!(clone.expect_next()?.read()?.expect_symbol()?, "foo");
+            assert!(clone.next()?.is_none());
+            Ok(())
+        }
+
+        let data = "foo bar foo";
+        check(Reader::new(AnyEncoding, data)?)?;
+        let binary = to_binary_ion(data)?;
+        check(Reader::new(AnyEncoding, binary.as_slice())?)?;
+        check(Reader::new(AnyEncoding, std::rc::Rc::<[u8]>::from(binary))?)
+    }
+
+    #[test]
+    fn cloned_readers_keep_their_own_symbol_tables() -> IonResult<()> {
+        let data = r#"
+            $ion_symbol_table::{symbols: ["foo"]}
+            $10
+            $ion_symbol_table::{symbols: ["bar"]}
+            $10
+        "#;
+        let mut reader = Reader::new(v1_0::Text, data)?;
+        assert_eq!(reader.expect_next()?.read()?.expect_symbol()?, "foo");
+        let mut clone = reader.try_clone()?;
+        assert_eq!(clone.expect_next()?.read()?.expect_symbol()?, "bar");
+        // The clone's new symbol table is not visible to the original reader.
+        assert_eq!(reader.symbol_table().text_for(10), Some("foo"));
+        assert_eq!(clone.symbol_table().text_for(10), Some("bar"));
+        assert_eq!(reader.expect_next()?.read()?.expect_symbol()?, "bar");
+        Ok(())
+    }
 }
 
 #[cfg(all(test, feature = "experimental-ion-1-1"))]
 mod tests_1_1 {
     use crate::lazy::text::raw::v1_1::reader::MacroAddress;
-    use crate::{v1_1, IonResult, MacroTable, Reader};
+    use crate::{
+        v1_1, AnyEncoding, Decoder, Element, ElementReader, IonError, IonResult, MacroTable,
+        Reader, ReaderLimits,
+    };
 
     fn expand_macro_test(
         macro_source: &str,
@@ -425,6 +1698,40 @@ mod tests_1_1 {
         test_fn(reader)
     }
 
+    #[test]
+    fn read_batch_of_macro_output() -> IonResult<()> {
+        let data = r#"
+            $ion_1_1
+            (:add_macros
+                (macro greet (name) (.make_string "Hello, " (%name))))
+            (:greet "Alice")
+            (:values 1 2 3)
+            (:greet "Bob")
+        "#;
+        let mut reader = Reader::new(crate::AnyEncoding, data)?;
+        let batch = reader.read_batch(10)?;
+        assert_eq!(batch.len(), 5);
+        let values = batch
+            .iter()
+            .map(crate::Element::try_from)
+            .collect::<IonResult<Vec<_>>>()?;
+        let expected = crate::Element::read_all(r#""Hello, Alice" 1 2 3 "Hello, Bob""#)?;
+        assert!(expected.iter().eq(&values));
+        Ok(())
+    }
+
+    #[test]
+    fn cannot_clone_reader_during_macro_expansion() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "$ion_1_1 (:values 1 2) 3")?;
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 1);
+        assert!(reader.try_clone().is_err());
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 2);
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 3);
+        let mut clone = reader.try_clone()?;
+        assert!(clone.next()?.is_none());
+        Ok(())
+    }
+
     #[test]
     fn expand_binary_template_macro() -> IonResult<()> {
         let macro_source = "(macro seventeen () 17)";
@@ -496,4 +1803,122 @@ mod tests_1_1 {
             Ok(())
         })
     }
+
+    #[test]
+    fn restore_context_discards_request_changes() -> IonResult<()> {
+        let mut reader = Reader::new(
+            AnyEncoding,
+            r#"
+                $ion_1_1
+                $ion::
+                (module _
+                    (symbol_table ["shared", "common"])
+                    (macro_table $ion (macro setup () 1)))
+                (:setup)
+
+                // A request that defines its own symbols, macros, and modules
+                $ion::
+                (module _
+                    (symbol_table _ ["request", "shared"])
+                    (macro_table _ (macro request () 2)))
+                $ion::(module m (macro_table (macro foo () 3)))
+                (:request)
+
+                // A request that replaces the symbol table
+                $ion::(module _ (symbol_table ["other"]))
+                $1
+            "#,
+        )?;
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 1);
+        let snapshot = reader.context_snapshot();
+        let symbol_table_len = reader.symbol_table().len();
+        let macro_table_len = reader.macro_table().len();
+
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 2);
+        assert_eq!(reader.symbol_table().sid_for("shared"), Some(4));
+        assert!(reader.macro_table().macro_with_name("request").is_some());
+        reader.restore_context(&snapshot)?;
+        assert_eq!(reader.symbol_table().len(), symbol_table_len);
+        assert_eq!(reader.symbol_table().sid_for("shared"), Some(1));
+        assert_eq!(reader.symbol_table().sid_for("request"), None);
+        assert_eq!(reader.macro_table().len(), macro_table_len);
+        assert!(reader.macro_table().macro_with_name("request").is_none());
+        assert!(reader
+            .system_reader
+            .expanding_reader
+            .context()
+            .modules()
+            .get("m")
+            .is_none());
+
+        assert_eq!(reader.expect_next()?.read()?.expect_symbol()?, "other");
+        // The symbol table was replaced, so it can no longer be rolled back.
+        assert!(reader.restore_context(&snapshot).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn limits_reject_long_macro_expansions() -> IonResult<()> {
+        let limits = ReaderLimits::new().with_max_macro_steps(100);
+        let data = "(:repeat 10 1) (:repeat 10 2) (:repeat 1000000 3)";
+        let mut reader = Reader::new(v1_1::Text.with_limits(limits), data)?;
+        // Each top-level e-expression has its own budget.
+        for expected in [1, 2] {
+            for _ in 0..10 {
+                assert_eq!(reader.expect_next()?.read()?.expect_i64()?, expected);
+            }
+        }
+        let result = reader.read_all_elements();
+        assert!(matches!(result, Err(IonError::Decoding(_))), "{result:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn limits_reject_deeply_nested_macro_invocations() -> IonResult<()> {
+        let data = "(:values (:values (:values 1) 2) 3)";
+        // Each invocation and each argument group that it passes to `values` adds a level.
+        let limits = ReaderLimits::new().with_max_macro_depth(5);
+        let actual = Reader::new(v1_1::Text.with_limits(limits), data)?.read_all_elements()?;
+        assert_eq!(actual, Element::read_all("1 2 3")?);
+
+        let limits = ReaderLimits::new().with_max_macro_depth(4);
+        let result = Reader::new(v1_1::Text.with_limits(limits), data)?.read_all_elements();
+        assert!(matches!(result, Err(IonError::Decoding(_))), "{result:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn limits_reject_large_macro_output() -> IonResult<()> {
+        let data = "(:repeat 5 (:values 1 2)) 3";
+        let limits = ReaderLimits::new().with_max_macro_output(10);
+        let actual = Reader::new(v1_1::Text.with_limits(limits), data)?.read_all_elements()?;
+        assert_eq!(actual.len(), 11);
+
+        let limits = ReaderLimits::new().with_max_macro_output(9);
+        let result = Reader::new(v1_1::Text.with_limits(limits), data)?.read_all_elements();
+        assert!(matches!(result, Err(IonError::Decoding(_))), "{result:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn skip_next_evaluates_e_expressions() -> IonResult<()> {
+        // The macro is only defined if skipping the `add_macros` invocation evaluates it.
+        let data = "(:add_macros (macro pair () (.values 1 2))) (:none) (:pair) a::{b: 3} 4";
+        let mut reader = Reader::new(v1_1::Text, data)?;
+        for _ in 0..3 {
+            assert!(reader.skip_next()?);
+        }
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 4);
+        assert!(!reader.skip_next()?);
+        Ok(())
+    }
+
+    #[test]
+    fn macros_cannot_invoke_themselves() -> IonResult<()> {
+        // A macro can only invoke macros that were defined before it, so expansions cannot cycle.
+        let data = "(:add_macros (macro loop () (.loop))) (:loop)";
+        let result = Reader::new(v1_1::Text, data)?.read_all_elements();
+        assert!(result.is_err(), "{result:?}");
+        Ok(())
+    }
 }
@@ -0,0 +1,374 @@
+use std::cell::Cell;
+use std::ops::Range;
+
+use crate::lazy::decoder::{
+    Decoder, LazyRawFieldExpr, LazyRawSequence, LazyRawStruct, LazyRawValue, LazyRawValueExpr,
+    RawValueExpr,
+};
+use crate::lazy::raw_value_ref::RawValueRef;
+use crate::result::{DecodingError, IonFailure};
+use crate::{HasRange, IonError, IonResult};
+
+/// Limits on the resources that a [`Reader`](crate::Reader) will commit to a stream, for use when
+/// reading input from untrusted sources.
+///
+/// By default, each limit is unbounded. A reader that exceeds one of its limits returns a
+/// decoding error instead of continuing to read.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{v1_0, Decoder, Reader, ReaderLimits};
+///
+/// let limits = ReaderLimits::new().with_max_depth(2);
+/// let mut reader = Reader::new(v1_0::Text.with_limits(limits), "[[1]] [[[1]]]")?;
+/// assert!(reader.next().is_ok());
+/// assert!(reader.next().is_err());
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ReaderLimits {
+    max_depth: usize,
+    max_container_length: usize,
+    max_symbols: usize,
+    max_macro_steps: usize,
+    max_macro_depth: usize,
+    max_macro_output: usize,
+    max_value_size: usize,
+}
+
+impl ReaderLimits {
+    /// Constructs a set of limits in which each limit is unbounded.
+    pub const fn new() -> Self {
+        Self {
+            max_depth: usize::MAX,
+            max_container_length: usize::MAX,
+            max_symbols: usize::MAX,
+            max_macro_steps: usize::MAX,
+            max_macro_depth: usize::MAX,
+            max_macro_output: usize::MAX,
+            max_value_size: usize::MAX,
+        }
+    }
+
+    /// Sets the number of containers that a value may be nested within. A top-level container has
+    /// a depth of one, so a `max_depth` of zero only permits top-level scalars.
+    ///
+    /// In text Ion, e-expressions are counted as containers.
+    pub const fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the number of child values (or struct fields) that a container in the input may have.
+    /// Macro invocations that appear in a container are counted as a single child.
+    pub const fn with_max_container_length(mut self, max_container_length: usize) -> Self {
+        self.max_container_length = max_container_length;
+        self
+    }
+
+    /// Sets the number of symbols that the stream may add to the symbol table. System symbols are
+    /// not counted.
+    pub const fn with_max_symbols(mut self, max_symbols: usize) -> Self {
+        self.max_symbols = max_symbols;
+        self
+    }
+
+    /// Sets the number of steps that the macro evaluator may take while expanding the
+    /// e-expressions in a single top-level expression. Each value that a macro produces requires
+    /// at least one step.
+    pub const fn with_max_macro_steps(mut self, max_macro_steps: usize) -> Self {
+        self.max_macro_steps = max_macro_steps;
+        self
+    }
+
+    /// Sets the number of macro expansions that the macro evaluator may have in progress at once.
+    /// A macro invocation that is encountered while another is being expanded adds a level, as
+    /// does each argument expression group that the outer macro expands.
+    pub const fn with_max_macro_depth(mut self, max_macro_depth: usize) -> Self {
+        self.max_macro_depth = max_macro_depth;
+        self
+    }
+
+    /// Sets the number of values that macro expansions may produce while evaluating the
+    /// e-expressions in a single top-level expression. This includes values that are passed as
+    /// arguments to other macros as well as those that appear in the data stream.
+    pub const fn with_max_macro_output(mut self, max_macro_output: usize) -> Self {
+        self.max_macro_output = max_macro_output;
+        self
+    }
+
+    /// Sets the number of bytes of input that a single top-level value or e-expression may
+    /// occupy. When reading from a stream, the reader stops buffering input once an incomplete
+    /// value has reached this size. A complete value that exceeds the limit is skipped, so the
+    /// reader can continue with the next one.
+    pub const fn with_max_value_size(mut self, max_value_size: usize) -> Self {
+        self.max_value_size = max_value_size;
+        self
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    pub fn max_container_length(&self) -> usize {
+        self.max_container_length
+    }
+
+    pub fn max_symbols(&self) -> usize {
+        self.max_symbols
+    }
+
+    pub fn max_macro_steps(&self) -> usize {
+        self.max_macro_steps
+    }
+
+    pub fn max_macro_depth(&self) -> usize {
+        self.max_macro_depth
+    }
+
+    pub fn max_macro_output(&self) -> usize {
+        self.max_macro_output
+    }
+
+    pub fn max_value_size(&self) -> usize {
+        self.max_value_size
+    }
+
+    /// Returns `true` if the depth or length of containers in the input is limited.
+    fn limits_containers(&self) -> bool {
+        self.max_depth != usize::MAX || self.max_container_length != usize::MAX
+    }
+
+    pub(crate) fn check_symbols(&self, num_symbols: usize) -> IonResult<()> {
+        if num_symbols > self.max_symbols {
+            return IonResult::decoding_error(format!(
+                "the stream defined {num_symbols} symbols, exceeding the reader's limit of {}",
+                self.max_symbols
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn check_macro_steps(&self, num_steps: usize) -> IonResult<()> {
+        if num_steps > self.max_macro_steps {
+            return IonResult::decoding_error(format!(
+                "macro evaluation exceeded the reader's limit of {} steps",
+                self.max_macro_steps
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn check_macro_depth(&self, depth: usize) -> IonResult<()> {
+        if depth > self.max_macro_depth {
+            return IonResult::decoding_error(format!(
+                "macro invocations are nested more than the reader's limit of {} levels deep",
+                self.max_macro_depth
+            ));
+        }
+        Ok(())
+    }
+
+    /// Confirms that the top-level item at `range` in the input is not too large.
+    pub(crate) fn check_value_size(&self, range: Range<usize>) -> IonResult<()> {
+        if range.len() > self.max_value_size {
+            return Err(self.limit_exceeded(
+                range.start,
+                format!(
+                    "a top-level value is {} bytes long, exceeding the reader's limit of {} bytes",
+                    range.len(),
+                    self.max_value_size
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Confirms that the reader may buffer more input to finish reading an incomplete top-level
+    /// item that begins at `offset` and already spans `num_bytes`.
+    pub(crate) fn check_incomplete_value_size(
+        &self,
+        offset: usize,
+        num_bytes: usize,
+    ) -> IonResult<()> {
+        if num_bytes >= self.max_value_size {
+            return Err(self.limit_exceeded(
+                offset,
+                format!(
+                    "an incomplete top-level value already spans {num_bytes} bytes; its size \
+                     would exceed the reader's limit of {} bytes",
+                    self.max_value_size
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn check_macro_output(&self, num_values: usize) -> IonResult<()> {
+        if num_values > self.max_macro_output {
+            return IonResult::decoding_error(format!(
+                "macro evaluation produced more than the reader's limit of {} values",
+                self.max_macro_output
+            ));
+        }
+        Ok(())
+    }
+
+    /// Visits each container nested within `value`, confirming that none of them exceed the
+    /// maximum depth or length. The traversal does not recurse, so it is safe to use on input
+    /// of any depth.
+    ///
+    /// Values passed to e-expressions are not visited.
+    pub(crate) fn check_containers<'top, D: Decoder>(
+        &self,
+        value: D::Value<'top>,
+    ) -> IonResult<()> {
+        if !self.limits_containers() {
+            return Ok(());
+        }
+        let mut stack: Vec<ChildIterator<'top, D>> = Vec::new();
+        let mut next_value = Some(value);
+        loop {
+            if let Some(value) = next_value.take() {
+                if let Some(children) = self.enter(&stack, value)? {
+                    stack.push(children);
+                }
+            }
+            let Some(children) = stack.last_mut() else {
+                return Ok(());
+            };
+            match children.next_value() {
+                Some(result) => {
+                    next_value = result?;
+                    children.length += 1;
+                    if children.length > self.max_container_length {
+                        return Err(self.limit_exceeded(
+                            children.start,
+                            format!(
+                                "a container has more than the reader's limit of {} children",
+                                self.max_container_length
+                            ),
+                        ));
+                    }
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    /// If `value` is a container, confirms that it does not exceed the maximum depth and returns
+    /// an iterator over its children.
+    fn enter<'top, D: Decoder>(
+        &self,
+        stack: &[ChildIterator<'top, D>],
+        value: D::Value<'top>,
+    ) -> IonResult<Option<ChildIterator<'top, D>>> {
+        if value.is_null() {
+            return Ok(None);
+        }
+        let kind = match value.read()? {
+            RawValueRef::List(list) => ChildIteratorKind::List(list.iter()),
+            RawValueRef::SExp(sexp) => ChildIteratorKind::SExp(sexp.iter()),
+            RawValueRef::Struct(struct_) => ChildIteratorKind::Struct(struct_.iter()),
+            _ => return Ok(None),
+        };
+        let start = value.range().start;
+        if stack.len() >= self.max_depth {
+            return Err(self.limit_exceeded(start, self.depth_exceeded_message()));
+        }
+        Ok(Some(ChildIterator {
+            kind,
+            start,
+            length: 0,
+        }))
+    }
+
+    /// Records that the text parser has entered a container, returning a guard that records its
+    /// exit when dropped. Returns `None` if entering the container would exceed the maximum depth.
+    ///
+    /// Text containers are matched recursively, so this check must happen as the input is parsed
+    /// rather than afterward.
+    pub(crate) fn enter_text_container<'a>(
+        &self,
+        depth: &'a Cell<usize>,
+    ) -> Option<TextContainerGuard<'a>> {
+        if depth.get() >= self.max_depth {
+            return None;
+        }
+        depth.set(depth.get() + 1);
+        Some(TextContainerGuard { depth })
+    }
+
+    pub(crate) fn depth_exceeded_message(&self) -> String {
+        format!(
+            "containers are nested more than the reader's limit of {} levels deep",
+            self.max_depth
+        )
+    }
+
+    fn limit_exceeded(&self, offset: usize, description: String) -> IonError {
+        DecodingError::new(description).with_position(offset).into()
+    }
+}
+
+impl Default for ReaderLimits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Decrements the text parser's container depth when dropped. See
+/// [`ReaderLimits::enter_text_container`].
+pub(crate) struct TextContainerGuard<'a> {
+    depth: &'a Cell<usize>,
+}
+
+impl Drop for TextContainerGuard<'_> {
+    fn drop(&mut self) {
+        self.depth.set(self.depth.get() - 1);
+    }
+}
+
+/// The children of a container being visited by [`ReaderLimits::check_containers`].
+struct ChildIterator<'top, D: Decoder> {
+    kind: ChildIteratorKind<'top, D>,
+    // The offset at which the container begins.
+    start: usize,
+    // The number of children visited so far.
+    length: usize,
+}
+
+enum ChildIteratorKind<'top, D: Decoder> {
+    List(<D::List<'top> as LazyRawSequence<'top, D>>::Iterator),
+    SExp(<D::SExp<'top> as LazyRawSequence<'top, D>>::Iterator),
+    Struct(<D::Struct<'top> as LazyRawStruct<'top, D>>::Iterator),
+}
+
+impl<'top, D: Decoder> ChildIterator<'top, D> {
+    /// Returns the next child of the container. If the child is a value literal, it is returned
+    /// as `Some(value)`. If it is an e-expression, it is returned as `None`.
+    fn next_value(&mut self) -> Option<IonResult<Option<D::Value<'top>>>> {
+        fn literal<'top, D: Decoder>(expr: LazyRawValueExpr<'top, D>) -> Option<D::Value<'top>> {
+            match expr {
+                RawValueExpr::ValueLiteral(value) => Some(value),
+                RawValueExpr::EExp(_) => None,
+            }
+        }
+        let result = match &mut self.kind {
+            ChildIteratorKind::List(iter) => iter.next()?.map(literal::<D>),
+            ChildIteratorKind::SExp(iter) => iter.next()?.map(literal::<D>),
+            ChildIteratorKind::Struct(iter) => iter.next()?.map(|field| match field {
+                LazyRawFieldExpr::NameValue(_name, value) => Some(value),
+                LazyRawFieldExpr::NameEExp(..) | LazyRawFieldExpr::EExp(_) => None,
+            }),
+        };
+        Some(result)
+    }
+}
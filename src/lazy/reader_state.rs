@@ -0,0 +1,337 @@
+use crate::lazy::encoder::value_writer::{
+    AnnotatableWriter, SequenceWriter, StructWriter, ValueWriter,
+};
+use crate::lazy::encoder::write_as_ion::WriteAsIon;
+use crate::lazy::expanded::EncodingContext;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::result::IonFailure;
+use crate::{
+    v1_0, v1_1, AnyEncoding, IonEncoding, IonError, IonResult, IonType, IonVersion, Reader, Writer,
+};
+
+/// The position and encoding context of a [`Reader`] partway through a stream.
+///
+/// A `ReaderState` is captured with [`Reader::save_state`] and can be written out as Ion, allowing
+/// a long-running job to persist its progress through a large stream. After a restart,
+/// [`Reader::resume_from_state`] constructs a reader that picks up where the original left off,
+/// reading the remaining input with the symbol table, macro table, and modules that were in
+/// effect at that point.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{v1_0, AnyEncoding, Reader, ReaderState, WriteAsIon};
+///
+/// let data = r#"$ion_symbol_table::{symbols: ["name"]} {$10: "a"} {$10: "b"}"#;
+/// let mut reader = Reader::new(AnyEncoding, data)?;
+/// reader.expect_next()?;
+/// // Persist the reader's state as Ion...
+/// let saved = reader.save_state()?.encode_as(v1_0::Text)?;
+///
+/// // ...and later, resume reading from the same point in the stream.
+/// let state = ReaderState::from_ion(saved.as_str())?;
+/// let remaining = &data[state.stream_position()..];
+/// let mut reader = Reader::resume_from_state(AnyEncoding, remaining, &state)?;
+/// let value = reader.expect_next()?.read()?.expect_struct()?;
+/// assert_eq!(value.get_expected("name")?.expect_string()?, "b");
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReaderState {
+    stream_position: usize,
+    encoding: IonEncoding,
+    // An Ion text stream whose system values recreate the reader's encoding context.
+    context: String,
+}
+
+impl ReaderState {
+    pub(crate) fn new(
+        stream_position: usize,
+        encoding: IonEncoding,
+        context: &EncodingContext,
+    ) -> IonResult<Self> {
+        let context = match encoding.version() {
+            IonVersion::v1_0 => {
+                let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+                let mut symbol_table = writer
+                    .value_writer()
+                    .with_annotations("$ion_symbol_table")?
+                    .struct_writer()?;
+                let mut symbols = symbol_table.field_writer("symbols").list_writer()?;
+                for symbol in context.symbol_table().application_symbols() {
+                    match symbol.text() {
+                        Some(text) => symbols.write_string(text)?,
+                        // A `null` in the symbols list reserves a symbol ID with unknown text.
+                        None => symbols.write_null(IonType::String)?,
+                    };
+                }
+                symbols.close()?;
+                symbol_table.close()?;
+                writer.close()?
+            }
+            IonVersion::v1_1 => {
+                let mut writer = Writer::new(v1_1::Text, Vec::new())?;
+                context.write_directives(&mut writer)?;
+                writer.close()?
+            }
+        };
+        Ok(Self {
+            stream_position,
+            encoding,
+            context: String::from_utf8(context).expect("text writers produce UTF-8"),
+        })
+    }
+
+    /// Returns the offset within the overall stream at which the reader stopped. This is the
+    /// position at which the input passed to [`Reader::resume_from_state`] must begin.
+    pub fn stream_position(&self) -> usize {
+        self.stream_position
+    }
+
+    /// Returns the Ion encoding of the stream at the point where the reader stopped.
+    pub fn encoding(&self) -> IonEncoding {
+        self.encoding
+    }
+
+    /// Reconstructs the encoding context that was in effect when the state was saved.
+    pub(crate) fn encoding_context(&self) -> IonResult<EncodingContext> {
+        let context = EncodingContext::from_directives(self.context.as_str())?;
+        if context.symbol_table().ion_version() != self.encoding.version() {
+            return IonResult::decoding_error(format!(
+                "reader state's encoding context does not match its encoding ({})",
+                self.encoding.name()
+            ));
+        }
+        Ok(context)
+    }
+
+    /// Reads a `ReaderState` from the first value in `input`, which must be a struct like the
+    /// one written by its [`WriteAsIon`] implementation.
+    pub fn from_ion<Input: IonInput>(input: Input) -> IonResult<ReaderState> {
+        let mut reader = Reader::new(AnyEncoding, input)?;
+        let state = reader.expect_next()?.read()?.expect_struct()?;
+        let stream_position = state
+            .get_expected("stream_position")?
+            .expect_int()?
+            .expect_usize()?;
+        let encoding_name = state.get_expected("encoding")?.expect_string()?;
+        let encoding = [
+            IonEncoding::Text_1_0,
+            IonEncoding::Binary_1_0,
+            IonEncoding::Text_1_1,
+            IonEncoding::Binary_1_1,
+        ]
+        .into_iter()
+        .find(|encoding| encoding.name() == encoding_name.text())
+        .ok_or_else(|| {
+            IonError::decoding_error(format!(
+                "reader state has unrecognized encoding '{}'",
+                encoding_name.text()
+            ))
+        })?;
+        let context = state
+            .get_expected("context")?
+            .expect_string()?
+            .text()
+            .to_owned();
+        Ok(ReaderState {
+            stream_position,
+            encoding,
+            context,
+        })
+    }
+}
+
+/// Writes the state as a struct with the fields `stream_position`, `encoding`, and `context`.
+/// The `context` field is a string containing an Ion text stream whose system values recreate the
+/// reader's encoding context. The state can be read back with [`ReaderState::from_ion`].
+impl WriteAsIon for ReaderState {
+    fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        let mut struct_writer = writer.struct_writer()?;
+        struct_writer
+            .write("stream_position", self.stream_position)?
+            .write("encoding", self.encoding.name())?
+            .write("context", self.context.as_str())?;
+        struct_writer.close()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        v1_0, AnyEncoding, Element, ElementReader, IonResult, Reader, ReaderState, Sequence,
+        SequenceWriter, WriteAsIon, Writer,
+    };
+
+    /// Reads `data` up to (and including) its `n`th value, saves the reader's state, and then
+    /// reads the rest of the stream with a reader resumed from a round-tripped copy of that state.
+    fn resume_after(data: &[u8], n: usize) -> IonResult<Sequence> {
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        for _ in 0..n {
+            reader.expect_next()?;
+        }
+        let encoded = reader.save_state()?.encode_as(v1_0::Binary)?;
+        let state = ReaderState::from_ion(encoded)?;
+        let remaining = &data[state.stream_position()..];
+        let mut resumed = Reader::resume_from_state(AnyEncoding, remaining, &state)?;
+        resumed.read_all_elements()
+    }
+
+    /// Indexes every value in `data`, then reads the values back in reverse order by seeking to
+    /// each one.
+    fn read_in_reverse(data: &[u8]) -> IonResult<Sequence> {
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        let mut index = Vec::new();
+        while let Some(value) = reader.next()? {
+            let offset = value.raw_range().unwrap().start;
+            index.push((offset, reader.save_state()?));
+        }
+        let mut elements = Vec::new();
+        for (offset, state) in index.iter().rev() {
+            reader.seek_to(*offset, state)?;
+            elements.push(Element::try_from(reader.expect_next()?)?);
+        }
+        Ok(elements.into())
+    }
+
+    #[test]
+    fn resume_ion_1_0_text() -> IonResult<()> {
+        let data = r#"
+            $ion_symbol_table::{symbols: ["foo", "bar"]}
+            $10
+            (baz $11)
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["quux"]}
+            $12
+        "#;
+        let expected = Element::read_all("(baz bar) quux")?;
+        assert_eq!(resume_after(data.as_bytes(), 1)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn resume_ion_1_0_binary() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer.write_symbol("foo")?.write_symbol("bar")?;
+        writer.flush()?;
+        writer.write_symbol("baz")?.write_symbol("foo")?;
+        let data = writer.close()?;
+        let expected = Element::read_all("bar baz foo")?;
+        assert_eq!(resume_after(&data, 1)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "experimental-ion-1-1")]
+    fn resume_ion_1_1_text() -> IonResult<()> {
+        let data = r#"
+            $ion_1_1
+            $ion::(module _
+                (symbol_table ["foo"])
+                (macro_table $ion (macro greet (name) (.make_string "Hello, " (%name)))))
+            (:greet "Waldo")
+            $1
+            (:greet "Ruth")
+        "#;
+        let expected = Element::read_all(r#"foo "Hello, Ruth""#)?;
+        assert_eq!(resume_after(data.as_bytes(), 1)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "experimental-ion-1-1")]
+    fn resume_ion_1_1_binary() -> IonResult<()> {
+        let mut writer = Writer::new(crate::v1_1::Binary, Vec::new())?;
+        writer.write_symbol("foo")?.write_symbol("bar")?;
+        writer.flush()?;
+        writer.write_symbol("baz")?.write_symbol("bar")?;
+        let data = writer.close()?;
+        let expected = Element::read_all("bar baz bar")?;
+        assert_eq!(resume_after(&data, 1)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn seek_ion_1_0_binary() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer.write_symbol("foo")?.write_symbol("bar")?;
+        writer.flush()?;
+        writer.write_symbol("baz")?.write_symbol("foo")?;
+        let data = writer.close()?;
+        let expected = Element::read_all("foo baz bar foo")?;
+        assert_eq!(read_in_reverse(&data)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "experimental-ion-1-1")]
+    fn seek_ion_1_1_binary() -> IonResult<()> {
+        let mut writer = Writer::new(crate::v1_1::Binary, Vec::new())?;
+        writer.write_symbol("foo")?.write_symbol("bar")?;
+        writer.flush()?;
+        writer.write_symbol("baz")?.write_symbol("bar")?;
+        let data = writer.close()?;
+        let expected = Element::read_all("bar baz bar foo")?;
+        assert_eq!(read_in_reverse(&data)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn seek_outside_of_input() -> IonResult<()> {
+        let data = r#"$ion_symbol_table::{symbols: ["foo"]} 1 2 $10"#;
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        reader.expect_next()?;
+        let state = reader.save_state()?;
+        assert!(reader.seek_to(data.len() + 1, &state).is_err());
+        // A failed seek leaves the reader where it was.
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 2);
+
+        // A resumed reader cannot seek to a part of the stream before its input begins.
+        let mut resumed =
+            Reader::resume_from_state(AnyEncoding, &data[state.stream_position()..], &state)?;
+        assert!(resumed.seek_to(0, &state).is_err());
+        resumed.seek_to(data.len() - 3, &state)?;
+        assert_eq!(resumed.read_all_elements()?, Element::read_all("foo")?);
+        Ok(())
+    }
+
+    #[test]
+    fn resumed_reader_can_save_its_state() -> IonResult<()> {
+        let data = r#"$ion_symbol_table::{symbols: ["foo"]} 1 2 $10"#;
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        reader.expect_next()?;
+        let state = reader.save_state()?;
+        let mut resumed =
+            Reader::resume_from_state(AnyEncoding, &data[state.stream_position()..], &state)?;
+        resumed.expect_next()?;
+        // The state of the resumed reader refers to a position in the original stream.
+        let state = resumed.save_state()?;
+        let mut resumed =
+            Reader::resume_from_state(AnyEncoding, &data[state.stream_position()..], &state)?;
+        assert_eq!(resumed.read_all_elements()?, Element::read_all("foo")?);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "experimental-ion-1-1")]
+    fn state_round_trips_through_ion() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "$ion_1_1 1 2")?;
+        reader.expect_next()?;
+        let state = reader.save_state()?;
+        let text = state.encode_as(crate::v1_1::Text)?;
+        assert_eq!(ReaderState::from_ion(text.as_str())?, state);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_malformed_state() {
+        assert!(ReaderState::from_ion("{stream_position: 1}").is_err());
+        assert!(ReaderState::from_ion(
+            r#"{stream_position: 1, encoding: "punch cards", context: ""}"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,253 @@
+use crate::lazy::decoder::Decoder;
+use crate::lazy::expanded::lazy_element::LazyElement;
+use crate::lazy::reader::Reader;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::value::LazyValue;
+use crate::result::IonFailure;
+use crate::IonResult;
+
+/// A reader that only yields a sample of the top-level values in its input.
+///
+/// Values that are not part of the sample are skipped without being read. In binary Ion, this
+/// means that their bodies are stepped over using their length prefixes, so sampling even a very
+/// large stream only pays to decode the values that are returned.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{Reader, SampledReader};
+/// use ion_rs::v1_0::Text;
+///
+/// let reader = Reader::new(Text, "0 1 2 3 4 5 6 7")?;
+/// let mut sampled = SampledReader::every_nth(reader, 3)?;
+/// let mut sample = Vec::new();
+/// while let Some(value) = sampled.next()? {
+///     sample.push(value.read()?.expect_i64()?);
+/// }
+/// assert_eq!(sample, vec![0, 3, 6]);
+/// assert_eq!(sampled.num_skipped(), 5);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub struct SampledReader<D: Decoder, Input: IonInput> {
+    reader: Reader<D, Input>,
+    sampling: Sampling,
+    num_skipped: usize,
+}
+
+enum Sampling {
+    EveryNth { n: usize, values_until_next: usize },
+    Rate { rate: f64, rng: SplitMix64 },
+}
+
+impl Sampling {
+    /// Decides whether the next value should be part of the sample.
+    fn should_keep(&mut self) -> bool {
+        match self {
+            Sampling::EveryNth {
+                n,
+                values_until_next,
+            } => {
+                if *values_until_next == 0 {
+                    *values_until_next = *n - 1;
+                    true
+                } else {
+                    *values_until_next -= 1;
+                    false
+                }
+            }
+            Sampling::Rate { rate, rng } => rng.next_f64() < *rate,
+        }
+    }
+}
+
+impl<D: Decoder, Input: IonInput> SampledReader<D, Input> {
+    /// Constructs a reader that yields the first top-level value from `reader` and every `n`th
+    /// value after it. Returns an `Err` if `n` is zero.
+    pub fn every_nth(reader: Reader<D, Input>, n: usize) -> IonResult<Self> {
+        if n == 0 {
+            return IonResult::illegal_operation("cannot sample every 0th value");
+        }
+        Ok(Self::new(
+            reader,
+            Sampling::EveryNth {
+                n,
+                values_until_next: 0,
+            },
+        ))
+    }
+
+    /// Constructs a reader that yields each top-level value from `reader` with probability `rate`,
+    /// which must be between `0.0` and `1.0` (inclusive).
+    ///
+    /// Values are chosen using a pseudorandom number generator initialized with `seed`, so sampling
+    /// the same input with the same seed always produces the same sample.
+    pub fn with_rate(reader: Reader<D, Input>, rate: f64, seed: u64) -> IonResult<Self> {
+        if !(0.0..=1.0).contains(&rate) {
+            return IonResult::illegal_operation(format!(
+                "sample rate must be between 0.0 and 1.0, found {rate}"
+            ));
+        }
+        let rng = SplitMix64 { state: seed };
+        Ok(Self::new(reader, Sampling::Rate { rate, rng }))
+    }
+
+    fn new(reader: Reader<D, Input>, sampling: Sampling) -> Self {
+        Self {
+            reader,
+            sampling,
+            num_skipped: 0,
+        }
+    }
+
+    /// Returns the next top-level value in the sample, or `Ok(None)` at the end of the stream.
+    #[allow(clippy::should_implement_trait)]
+    // ^-- As in `Reader::next`, this returns a value that borrows the reader.
+    pub fn next(&mut self) -> IonResult<Option<LazyValue<'_, D>>> {
+        while !self.sampling.should_keep() {
+            if self.reader.next()?.is_none() {
+                return Ok(None);
+            }
+            self.num_skipped += 1;
+        }
+        self.reader.next()
+    }
+
+    /// Returns the number of top-level values that have been skipped so far.
+    pub fn num_skipped(&self) -> usize {
+        self.num_skipped
+    }
+
+    /// Returns the underlying reader, which will resume reading after the last value that was
+    /// sampled or skipped.
+    pub fn into_inner(self) -> Reader<D, Input> {
+        self.reader
+    }
+}
+
+impl<D: Decoder, Input: IonInput> Iterator for SampledReader<D, Input> {
+    type Item = IonResult<LazyElement<D>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next() {
+            Ok(None) => None,
+            Ok(Some(lazy_value)) => Some(Ok(lazy_value.to_owned())),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// The SplitMix64 pseudorandom number generator, which is small and fast enough to be consulted
+/// once per value. Its output is not suitable for cryptographic use.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in the range `[0.0, 1.0)`.
+    fn next_f64(&mut self) -> f64 {
+        // Use the top 53 bits, which is the precision of an `f64`'s significand.
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::encoding::Encoding;
+    use crate::{v1_0, AnyEncoding, Element, IonError, Sequence};
+
+    fn ints(count: usize) -> String {
+        (0..count)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn sample_ints(mut sampled: SampledReader<AnyEncoding, &str>) -> IonResult<Vec<i64>> {
+        let mut sample = Vec::new();
+        while let Some(value) = sampled.next()? {
+            sample.push(value.read()?.expect_i64()?);
+        }
+        Ok(sample)
+    }
+
+    #[test]
+    fn every_nth_value() -> IonResult<()> {
+        let data = ints(10);
+        let reader = Reader::new(AnyEncoding, data.as_str())?;
+        assert_eq!(
+            sample_ints(SampledReader::every_nth(reader, 3)?)?,
+            vec![0, 3, 6, 9]
+        );
+        let reader = Reader::new(AnyEncoding, data.as_str())?;
+        assert_eq!(
+            sample_ints(SampledReader::every_nth(reader, 1)?)?,
+            (0..10).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sample_binary_containers() -> IonResult<()> {
+        let elements = Element::read_all("{a: 1} [2, {b: 3}] (4) {a: 5} \"six\"")?;
+        let data = v1_0::Binary::encode_all(&elements)?;
+        let reader = Reader::new(AnyEncoding, data.as_slice())?;
+        let sample = SampledReader::every_nth(reader, 2)?
+            .map(|value| Element::try_from(value?))
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(
+            Sequence::from(sample),
+            Element::read_all("{a: 1} (4) \"six\"")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sample_at_rate() -> IonResult<()> {
+        let data = ints(1000);
+        let sample_with = |rate: f64, seed: u64| -> IonResult<Vec<i64>> {
+            let reader = Reader::new(AnyEncoding, data.as_str())?;
+            sample_ints(SampledReader::with_rate(reader, rate, seed)?)
+        };
+        assert!(sample_with(0.0, 1)?.is_empty());
+        assert_eq!(sample_with(1.0, 1)?.len(), 1000);
+
+        let sample = sample_with(0.25, 42)?;
+        assert!(
+            (150..350).contains(&sample.len()),
+            "sampled {} values",
+            sample.len()
+        );
+        // The sample is in stream order and is reproducible using the same seed.
+        assert!(sample.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(sample, sample_with(0.25, 42)?);
+        assert_ne!(sample, sample_with(0.25, 43)?);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_sampling_parameters() -> IonResult<()> {
+        let reader = || Reader::new(AnyEncoding, "1 2 3");
+        for result in [
+            SampledReader::every_nth(reader()?, 0),
+            SampledReader::with_rate(reader()?, 1.5, 0),
+            SampledReader::with_rate(reader()?, -0.1, 0),
+            SampledReader::with_rate(reader()?, f64::NAN, 0),
+        ] {
+            assert!(matches!(result, Err(IonError::IllegalOperation(_))));
+        }
+        Ok(())
+    }
+}
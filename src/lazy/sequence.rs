@@ -1,6 +1,7 @@
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 
+use crate::ion_data::IonEq;
 use crate::lazy::decoder::Decoder;
 use crate::lazy::expanded::sequence::{
     ExpandedListIterator, ExpandedSExpIterator, LazyExpandedList, LazyExpandedSExp,
@@ -150,6 +151,36 @@ impl<'top, D: Decoder> TryFrom<LazyList<'top, D>> for Element {
     }
 }
 
+/// Compares the child values of two lazy sequences pairwise using `value_eq`. Sequences whose
+/// contents cannot be fully read are never considered equal.
+fn sequences_eq<'top, D: Decoder>(
+    mut these: impl Iterator<Item = IonResult<LazyValue<'top, D>>>,
+    mut those: impl Iterator<Item = IonResult<LazyValue<'top, D>>>,
+    value_eq: impl Fn(&LazyValue<'top, D>, &LazyValue<'top, D>) -> bool,
+) -> bool {
+    loop {
+        match (these.next(), those.next()) {
+            (None, None) => return true,
+            (Some(Ok(this)), Some(Ok(that))) if value_eq(&this, &that) => continue,
+            _ => return false,
+        }
+    }
+}
+
+/// Compares the child values of two lists. Like [`Value::List`], the comparison does not consider
+/// the lists' own annotations; compare the lists' [`LazyValue`] representations to include them.
+impl<D: Decoder> PartialEq for LazyList<'_, D> {
+    fn eq(&self, other: &Self) -> bool {
+        sequences_eq(self.iter(), other.iter(), |this, that| this == that)
+    }
+}
+
+impl<D: Decoder> IonEq for LazyList<'_, D> {
+    fn ion_eq(&self, other: &Self) -> bool {
+        sequences_eq(self.iter(), other.iter(), |this, that| this.ion_eq(that))
+    }
+}
+
 impl<'top, D: Decoder> IntoIterator for &LazyList<'top, D> {
     type Item = IonResult<LazyValue<'top, D>>;
     type IntoIter = ListIterator<'top, D>;
@@ -311,6 +342,20 @@ impl<'top, D: Decoder> TryFrom<LazySExp<'top, D>> for Element {
     }
 }
 
+/// Compares the child values of two s-expressions. Like [`Value::SExp`], the comparison does not
+/// consider the s-expressions' own annotations.
+impl<D: Decoder> PartialEq for LazySExp<'_, D> {
+    fn eq(&self, other: &Self) -> bool {
+        sequences_eq(self.iter(), other.iter(), |this, that| this == that)
+    }
+}
+
+impl<D: Decoder> IonEq for LazySExp<'_, D> {
+    fn ion_eq(&self, other: &Self) -> bool {
+        sequences_eq(self.iter(), other.iter(), |this, that| this.ion_eq(that))
+    }
+}
+
 impl<'top, D: Decoder> IntoIterator for &LazySExp<'top, D> {
     type Item = IonResult<LazyValue<'top, D>>;
     type IntoIter = SExpIterator<'top, D>;
@@ -351,8 +396,9 @@ impl<'top, D: Decoder> Iterator for SExpIterator<'top, D> {
 #[cfg(test)]
 mod tests {
     use crate::element::Element;
+    use crate::ion_data::IonEq;
     use crate::lazy::binary::test_utilities::to_binary_ion;
-    use crate::{v1_0, IonResult, Reader};
+    use crate::{v1_0, AnyEncoding, IonResult, Reader};
 
     #[test]
     fn annotations() -> IonResult<()> {
@@ -375,4 +421,38 @@ mod tests {
         assert_eq!(result?, Element::read_one(ion_text)?);
         Ok(())
     }
+
+    #[test]
+    fn sequence_equality() -> IonResult<()> {
+        let cases = [
+            ("[1, 2, foo::3]", "[1, 2, foo::3]", true),
+            ("[1, 2, foo::3]", "[1, 2, bar::3]", false),
+            ("[1, 2, 3]", "[1, 2]", false),
+            ("[1, 2]", "[2, 1]", false),
+            // The lists' own annotations are not part of the comparison.
+            ("a::[1, {b: 2}]", "[1, {b: 2}]", true),
+            ("(1 2 (3))", "(1 2 (3))", true),
+            ("(1 2 (3))", "(1 2 [3])", false),
+        ];
+        for (text1, text2, expected) in cases {
+            let mut reader1 = Reader::new(AnyEncoding, to_binary_ion(text1)?)?;
+            let mut reader2 = Reader::new(AnyEncoding, text2)?;
+            let value1 = reader1.expect_next()?.read()?;
+            let value2 = reader2.expect_next()?.read()?;
+            assert_eq!(value1 == value2, expected, "{text1} == {text2}");
+            assert_eq!(value1.ion_eq(&value2), expected, "{text1} ion_eq {text2}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn nan_is_ion_equivalent_but_not_equal() -> IonResult<()> {
+        let mut reader1 = Reader::new(AnyEncoding, "[nan]")?;
+        let mut reader2 = Reader::new(AnyEncoding, "[nan]")?;
+        let list1 = reader1.expect_next()?.read()?.expect_list()?;
+        let list2 = reader2.expect_next()?.read()?.expect_list()?;
+        assert_ne!(list1, list2);
+        assert!(list1.ion_eq(&list2));
+        Ok(())
+    }
 }
@@ -0,0 +1,322 @@
+use std::cmp::Ordering;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+use crate::ion_path::{IonPath, ToIonPath};
+use crate::lazy::decoder::Decoder;
+use crate::lazy::encoder::writer::Writer;
+use crate::lazy::encoding::Encoding;
+use crate::lazy::merge::merge_by;
+use crate::lazy::reader::Reader;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::{v1_0, AnyEncoding, Element, IonData, IonResult};
+
+/// Configuration options for [`sort`] and [`sort_by`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExternalSortOptions {
+    max_run_length: usize,
+    temp_dir: Option<PathBuf>,
+}
+
+impl ExternalSortOptions {
+    /// The number of values held in memory at once if no other limit is configured.
+    pub const DEFAULT_MAX_RUN_LENGTH: usize = 100_000;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the maximum number of values that will be held in memory at once.
+    pub fn max_run_length(&self) -> usize {
+        self.max_run_length
+    }
+
+    /// Sets the maximum number of values that will be held in memory at once. When the input has
+    /// more values than this, they are sorted in batches ("runs") of at most `max_values`, each
+    /// of which is written to a temporary file. The runs are then merged to produce the output.
+    ///
+    /// If `max_values` is zero, it is treated as one.
+    pub fn with_max_run_length(mut self, max_values: usize) -> Self {
+        self.max_run_length = max_values.max(1);
+        self
+    }
+
+    /// Returns the directory in which temporary files will be created, if one has been set.
+    pub fn temp_dir(&self) -> Option<&Path> {
+        self.temp_dir.as_deref()
+    }
+
+    /// Sets the directory in which temporary files will be created. If no directory is set,
+    /// [`std::env::temp_dir`] is used.
+    pub fn with_temp_dir(mut self, temp_dir: impl Into<PathBuf>) -> Self {
+        self.temp_dir = Some(temp_dir.into());
+        self
+    }
+}
+
+impl Default for ExternalSortOptions {
+    fn default() -> Self {
+        Self {
+            max_run_length: Self::DEFAULT_MAX_RUN_LENGTH,
+            temp_dir: None,
+        }
+    }
+}
+
+/// The number of values that [`sort`] or [`sort_by`] wrote to its output and how they were sorted.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SortSummary {
+    num_values: usize,
+    num_runs: usize,
+}
+
+impl SortSummary {
+    /// Returns the number of values written to the output.
+    pub fn num_values(&self) -> usize {
+        self.num_values
+    }
+
+    /// Returns the number of sorted runs that were written to temporary files. If the input fit
+    /// in memory, this is zero.
+    pub fn num_runs(&self) -> usize {
+        self.num_runs
+    }
+}
+
+/// Writes the top-level values of `reader` to `writer`, sorted by the value found at `key_path`.
+///
+/// Keys are compared using the total ordering provided by [`IonData`]; values that do not have a
+/// value at `key_path` sort before all others. The sort is stable: values with equal keys are
+/// written in the order in which they were read. To compare keys in a different order, use
+/// [`sort_by`].
+///
+/// At most [`max_run_length`](ExternalSortOptions::with_max_run_length) values are held in memory
+/// at once. Larger inputs are sorted in runs that are written to temporary files and then
+/// [merged](crate::merge). The temporary files are deleted before this function returns.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{sort, v1_0, Element, ExternalSortOptions, Reader, Writer};
+///
+/// let mut reader = Reader::new(v1_0::Text, "{id: 3} {id: 1} {id: 4} {id: 2}")?;
+/// let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+/// let options = ExternalSortOptions::new().with_max_run_length(2);
+/// let summary = sort(&mut reader, &mut writer, "id", options)?;
+/// assert_eq!(summary.num_runs(), 2);
+///
+/// let sorted = Element::read_all(writer.close()?)?;
+/// assert_eq!(sorted, Element::read_all("{id: 1} {id: 2} {id: 3} {id: 4}")?);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub fn sort<D, Input, E, Output>(
+    reader: &mut Reader<D, Input>,
+    writer: &mut Writer<E, Output>,
+    key_path: impl ToIonPath,
+    options: ExternalSortOptions,
+) -> IonResult<SortSummary>
+where
+    D: Decoder,
+    Input: IonInput,
+    E: Encoding,
+    Output: Write,
+{
+    sort_by(reader, writer, key_path, options, |key1, key2| {
+        IonData::from(key1).cmp(&IonData::from(key2))
+    })
+}
+
+/// Like [`sort`], but compares keys using the provided function.
+pub fn sort_by<D, Input, E, Output, F>(
+    reader: &mut Reader<D, Input>,
+    writer: &mut Writer<E, Output>,
+    key_path: impl ToIonPath,
+    options: ExternalSortOptions,
+    mut compare: F,
+) -> IonResult<SortSummary>
+where
+    D: Decoder,
+    Input: IonInput,
+    E: Encoding,
+    Output: Write,
+    F: FnMut(&Element, &Element) -> Ordering,
+{
+    let key_path = key_path.to_ion_path()?;
+    let mut summary = SortSummary::default();
+    let mut runs: Vec<TempFile> = Vec::new();
+    let mut run = Vec::new();
+    loop {
+        let is_done = !read_run(reader, &key_path, options.max_run_length, &mut run)?;
+        summary.num_values += run.len();
+        sort_run(&mut run, &mut compare);
+        if is_done && runs.is_empty() {
+            // Everything fit in memory; there is no need to spill to disk.
+            for (_key, element) in run.drain(..) {
+                writer.write(&element)?;
+            }
+            return Ok(summary);
+        }
+        if !run.is_empty() {
+            runs.push(TempFile::write_run(&options, run.drain(..))?);
+        }
+        if is_done {
+            break;
+        }
+    }
+    summary.num_runs = runs.len();
+
+    let mut run_readers = runs
+        .iter()
+        .map(|run| Reader::new(AnyEncoding, BufReader::new(File::open(&run.path)?)))
+        .collect::<IonResult<Vec<_>>>()?;
+    merge_by(&mut run_readers, writer, &*key_path, compare)?;
+    Ok(summary)
+}
+
+type KeyedElement = (Option<Element>, Element);
+
+/// Reads up to `max_values` values from `reader` into `run`. Returns `false` if the reader was
+/// exhausted.
+fn read_run<D: Decoder, Input: IonInput>(
+    reader: &mut Reader<D, Input>,
+    key_path: &IonPath,
+    max_values: usize,
+    run: &mut Vec<KeyedElement>,
+) -> IonResult<bool> {
+    while run.len() < max_values {
+        let Some(value) = reader.next()? else {
+            return Ok(false);
+        };
+        let key = value
+            .get_path(key_path)?
+            .map(Element::try_from)
+            .transpose()?;
+        run.push((key, Element::try_from(value)?));
+    }
+    Ok(true)
+}
+
+fn sort_run<F: FnMut(&Element, &Element) -> Ordering>(run: &mut [KeyedElement], compare: &mut F) {
+    // `sort_by` is stable, so values with equal keys keep their input order.
+    run.sort_by(|(key1, _), (key2, _)| match (key1, key2) {
+        (Some(key1), Some(key2)) => compare(key1, key2),
+        // Values without a key sort first, as they do in `merge_by`.
+        _ => key1.is_some().cmp(&key2.is_some()),
+    });
+}
+
+/// A temporary file holding one sorted run. The file is deleted when this is dropped.
+struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    fn write_run(
+        options: &ExternalSortOptions,
+        values: impl Iterator<Item = KeyedElement>,
+    ) -> IonResult<Self> {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        let dir = options.temp_dir.clone().unwrap_or_else(std::env::temp_dir);
+        let id = NEXT_ID.fetch_add(1, AtomicOrdering::Relaxed);
+        let path = dir.join(format!("ion-sort-{}-{id}.10n", std::process::id()));
+        let file = File::options().write(true).create_new(true).open(&path)?;
+        // Construct `temp_file` before writing so that the file is removed if writing fails.
+        let temp_file = TempFile { path };
+        let mut writer = Writer::new(v1_0::Binary, BufWriter::new(file))?;
+        for (_key, element) in values {
+            writer.write(&element)?;
+        }
+        writer.close()?.flush()?;
+        Ok(temp_file)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Sequence;
+
+    fn sort_text(
+        data: &str,
+        key_path: &str,
+        options: ExternalSortOptions,
+    ) -> IonResult<(Sequence, SortSummary)> {
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        let summary = sort(&mut reader, &mut writer, key_path, options)?;
+        Ok((Element::read_all(writer.close()?)?, summary))
+    }
+
+    #[test]
+    fn sort_in_memory() -> IonResult<()> {
+        let (sorted, summary) = sort_text(
+            "{k: 2, n: a} {k: 1} {n: no_key} {k: 2, n: b} {k: 0}",
+            "k",
+            ExternalSortOptions::new(),
+        )?;
+        assert_eq!(summary.num_values(), 5);
+        assert_eq!(summary.num_runs(), 0);
+        let expected = Element::read_all("{n: no_key} {k: 0} {k: 1} {k: 2, n: a} {k: 2, n: b}")?;
+        assert_eq!(sorted, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn sort_with_spilled_runs() -> IonResult<()> {
+        let values: Vec<String> = (0..50)
+            .map(|i| format!("{{k: {}, seq: {i}}}", (i * 37) % 10))
+            .collect();
+        let data = values.join(" ");
+        let temp_dir = tempfile::tempdir()?;
+        let options = ExternalSortOptions::new()
+            .with_max_run_length(7)
+            .with_temp_dir(temp_dir.path());
+        let (sorted, summary) = sort_text(&data, "k", options)?;
+        assert_eq!(summary.num_values(), 50);
+        assert_eq!(summary.num_runs(), 8);
+        // The output is sorted by `k`, and values with equal keys keep their input order.
+        let pairs: Vec<(i64, i64)> = sorted
+            .iter()
+            .map(|element| {
+                let fields = element.expect_struct().unwrap();
+                (
+                    fields.get("k").unwrap().expect_i64().unwrap(),
+                    fields.get("seq").unwrap().expect_i64().unwrap(),
+                )
+            })
+            .collect();
+        let mut expected = pairs.clone();
+        expected.sort();
+        assert_eq!(pairs, expected);
+        // All of the temporary files have been removed.
+        assert_eq!(std::fs::read_dir(temp_dir.path())?.count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn sort_by_descending_key() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "1 5 3 2 4")?;
+        let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+        let options = ExternalSortOptions::new().with_max_run_length(2);
+        sort_by(&mut reader, &mut writer, "", options, |a, b| {
+            b.expect_i64().unwrap().cmp(&a.expect_i64().unwrap())
+        })?;
+        assert_eq!(
+            Element::read_all(writer.close()?)?,
+            Element::read_all("5 4 3 2 1")?
+        );
+        Ok(())
+    }
+}
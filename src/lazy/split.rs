@@ -0,0 +1,301 @@
+use std::io::Write;
+
+use crate::constants::v1_0::SYSTEM_SYMBOLS;
+use crate::lazy::decoder::{Decoder, LazyRawFieldName, LazyRawSequence, LazyRawStruct};
+use crate::lazy::decoder::{LazyRawFieldExpr, LazyRawValue, RawValueExpr};
+use crate::lazy::encoder::writer::Writer;
+use crate::lazy::encoding::Encoding;
+use crate::lazy::raw_value_ref::RawValueRef;
+use crate::lazy::reader::Reader;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::value::LazyValue;
+use crate::result::IonFailure;
+use crate::{HasSpan, IonResult, IonVersion, RawSymbolRef};
+
+/// The number of values that [`split`] read from its input and what it did with each of them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SplitSummary {
+    values_per_writer: Vec<usize>,
+    num_copied: usize,
+    num_discarded: usize,
+}
+
+impl SplitSummary {
+    /// Returns the number of values that were sent to each writer, in the order the writers
+    /// were provided.
+    pub fn values_per_writer(&self) -> &[usize] {
+        &self.values_per_writer
+    }
+
+    /// Returns the number of values that were written by copying their encoded bytes from the
+    /// input rather than re-encoding them.
+    pub fn num_copied(&self) -> usize {
+        self.num_copied
+    }
+
+    /// Returns the number of values for which the routing function returned `None`.
+    pub fn num_discarded(&self) -> usize {
+        self.num_discarded
+    }
+
+    /// Returns the total number of values read from the input.
+    pub fn num_values(&self) -> usize {
+        self.values_per_writer.iter().sum::<usize>() + self.num_discarded
+    }
+}
+
+/// Reads each top-level value from `reader` and writes it to one of `writers`.
+///
+/// `route` is called with each value and returns the index of the writer that should receive
+/// it, or `None` to discard the value. Values are read lazily; `route` only pays to read the
+/// parts of each value it inspects.
+///
+/// When the input and the writers both use the same Ion 1.0 encoding and a value does not refer
+/// to any symbols in the input's symbol table, the value's encoded bytes are copied to the
+/// writer as-is rather than being re-encoded. All other values are re-encoded.
+///
+/// The writers are not flushed; callers should flush or close each of them afterward.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{split, v1_0, Element, Reader, Writer};
+///
+/// let mut reader = Reader::new(v1_0::Text, r#"
+///     {level: "INFO", message: "started"}
+///     {level: "ERROR", message: "disk full"}
+///     {level: "DEBUG", message: "tick"}
+/// "#)?;
+/// let mut writers = vec![
+///     Writer::new(v1_0::Text, Vec::new())?, // Errors
+///     Writer::new(v1_0::Text, Vec::new())?, // Everything else
+/// ];
+/// let summary = split(&mut reader, &mut writers, |value| {
+///     let level = value.read()?.expect_struct()?.get_expected("level")?;
+///     Ok(match level.expect_string()?.text() {
+///         "ERROR" => Some(0),
+///         "DEBUG" => None,
+///         _ => Some(1),
+///     })
+/// })?;
+/// assert_eq!(summary.values_per_writer(), &[1, 1]);
+/// assert_eq!(summary.num_discarded(), 1);
+///
+/// let mut writers = writers.into_iter();
+/// let errors = writers.next().unwrap().close()?;
+/// assert_eq!(
+///     Element::read_all(errors)?,
+///     Element::read_all(r#"{level: "ERROR", message: "disk full"}"#)?
+/// );
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub fn split<D, Input, E, Output, F>(
+    reader: &mut Reader<D, Input>,
+    writers: &mut [Writer<E, Output>],
+    mut route: F,
+) -> IonResult<SplitSummary>
+where
+    D: Decoder,
+    Input: IonInput,
+    E: Encoding,
+    Output: Write,
+    F: FnMut(&LazyValue<'_, D>) -> IonResult<Option<usize>>,
+{
+    let mut summary = SplitSummary {
+        values_per_writer: vec![0; writers.len()],
+        ..SplitSummary::default()
+    };
+    while let Some(value) = reader.next()? {
+        let Some(index) = route(&value)? else {
+            summary.num_discarded += 1;
+            continue;
+        };
+        let Some(writer) = writers.get_mut(index) else {
+            return IonResult::illegal_operation(format!(
+                "routing function selected writer {index}, but only {} writers were provided",
+                summary.values_per_writer.len()
+            ));
+        };
+        match copyable_raw_value::<D, E>(&value)? {
+            Some(raw_value) => {
+                writer.write_encoded_value(raw_value.span().bytes())?;
+                summary.num_copied += 1;
+            }
+            None => {
+                writer.write(value)?;
+            }
+        }
+        summary.values_per_writer[index] += 1;
+    }
+    Ok(summary)
+}
+
+/// If `value`'s encoded bytes can be written as-is by a writer using encoding `E`, returns the raw
+/// value. Otherwise, returns `None`.
+pub(crate) fn copyable_raw_value<'top, D: Decoder, E: Encoding>(
+    value: &LazyValue<'top, D>,
+) -> IonResult<Option<D::Value<'top>>> {
+    // Values produced by a macro have no encoding of their own.
+    let Some(raw_value) = value.raw() else {
+        return Ok(None);
+    };
+    // In Ion 1.1, the bytes of a value may depend on the active macro table as well as the
+    // symbol table, so only Ion 1.0 values are copied.
+    let encoding = raw_value.encoding();
+    if encoding != E::instance().encoding() || encoding.version() != IonVersion::v1_0 {
+        return Ok(None);
+    }
+    if is_symbol_table_independent::<D>(raw_value)? {
+        Ok(Some(raw_value))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Returns `true` if the raw value's encoding does not refer to any symbol IDs outside the Ion 1.0
+/// system symbol table, which is a prefix of every Ion 1.0 symbol table.
+fn is_symbol_table_independent<'top, D: Decoder>(raw_value: D::Value<'top>) -> IonResult<bool> {
+    for annotation in raw_value.annotations() {
+        if !is_system_or_text(annotation?) {
+            return Ok(false);
+        }
+    }
+    let is_independent = match raw_value.read()? {
+        RawValueRef::Symbol(symbol) => is_system_or_text(symbol),
+        RawValueRef::List(list) => all_values_independent::<D>(list.iter())?,
+        RawValueRef::SExp(sexp) => all_values_independent::<D>(sexp.iter())?,
+        RawValueRef::Struct(struct_) => {
+            for field in struct_.iter() {
+                let LazyRawFieldExpr::NameValue(name, value) = field? else {
+                    return Ok(false);
+                };
+                if !is_system_or_text(name.read()?) || !is_symbol_table_independent::<D>(value)? {
+                    return Ok(false);
+                }
+            }
+            true
+        }
+        _ => true,
+    };
+    Ok(is_independent)
+}
+
+fn all_values_independent<'top, D: Decoder>(
+    values: impl Iterator<Item = IonResult<RawValueExpr<D::Value<'top>, D::EExp<'top>>>>,
+) -> IonResult<bool> {
+    for value in values {
+        let RawValueExpr::ValueLiteral(value) = value? else {
+            return Ok(false);
+        };
+        if !is_symbol_table_independent::<D>(value)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+fn is_system_or_text(symbol: RawSymbolRef<'_>) -> bool {
+    match symbol {
+        RawSymbolRef::SymbolId(sid) => sid <= SYSTEM_SYMBOLS.len(),
+        RawSymbolRef::Text(_) => true,
+        RawSymbolRef::SystemSymbol_1_1(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{v1_0, AnyEncoding, Element, IonError, TextFormat};
+
+    fn route_by_type(value: &LazyValue<'_, AnyEncoding>) -> IonResult<Option<usize>> {
+        Ok(match value.ion_type() {
+            crate::IonType::Int => Some(0),
+            crate::IonType::Null => None,
+            _ => Some(1),
+        })
+    }
+
+    #[test]
+    fn split_text_to_text() -> IonResult<()> {
+        let data = r#"1 foo::"bar" null 2 {a: (b c), $0: 3} $10"#;
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        let mut writers = vec![
+            Writer::new(v1_0::Text.with_format(TextFormat::Compact), Vec::new())?,
+            Writer::new(v1_0::Text.with_format(TextFormat::Compact), Vec::new())?,
+        ];
+        // `$10` is not defined in the input's symbol table, so it cannot be copied through.
+        assert!(split(&mut reader, &mut writers, route_by_type).is_err());
+
+        let data = r#"1 foo::"bar" null 2 {a: (b c), $0: 3} $9"#;
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        let mut writers = vec![
+            Writer::new(v1_0::Text.with_format(TextFormat::Compact), Vec::new())?,
+            Writer::new(v1_0::Text.with_format(TextFormat::Compact), Vec::new())?,
+        ];
+        let summary = split(&mut reader, &mut writers, route_by_type)?;
+        assert_eq!(summary.values_per_writer(), &[2, 3]);
+        assert_eq!(summary.num_discarded(), 1);
+        assert_eq!(summary.num_copied(), 5);
+        assert_eq!(summary.num_values(), 6);
+        let outputs = writers
+            .into_iter()
+            .map(|writer| {
+                String::from_utf8(writer.close()?)
+                    .map_err(|e| IonError::encoding_error(e.to_string()))
+            })
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(outputs[0], "1 2 ");
+        assert_eq!(outputs[1], r#"foo::"bar" {a: (b c), $0: 3} $9 "#);
+        Ok(())
+    }
+
+    #[test]
+    fn split_binary_to_binary() -> IonResult<()> {
+        let elements =
+            Element::read_all(r#"1 name::"bar" null 2 {version: [3, 4.5e0]} foo {foo: 5}"#)?;
+        let data = v1_0::Binary::encode_all(&elements)?;
+        let mut reader = Reader::new(AnyEncoding, data.as_slice())?;
+        let mut writers = vec![
+            Writer::new(v1_0::Binary, Vec::new())?,
+            Writer::new(v1_0::Binary, Vec::new())?,
+        ];
+        let summary = split(&mut reader, &mut writers, route_by_type)?;
+        assert_eq!(summary.values_per_writer(), &[2, 4]);
+        // `foo` and `{foo: 5}` refer to a symbol in the input's symbol table and must be
+        // re-encoded. The other values only use system symbols.
+        assert_eq!(summary.num_copied(), 4);
+        let mut writers = writers.into_iter();
+        let ints = Element::read_all(writers.next().unwrap().close()?)?;
+        let others = Element::read_all(writers.next().unwrap().close()?)?;
+        assert_eq!(ints, Element::read_all("1 2")?);
+        assert_eq!(
+            others,
+            Element::read_all(r#"name::"bar" {version: [3, 4.5e0]} foo {foo: 5}"#)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn split_across_encodings_reencodes() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "1 two [3]")?;
+        let mut writers = vec![Writer::new(v1_0::Binary, Vec::new())?];
+        let summary = split(&mut reader, &mut writers, |_| Ok(Some(0)))?;
+        assert_eq!(summary.num_copied(), 0);
+        let output = writers.pop().unwrap().close()?;
+        assert_eq!(Element::read_all(output)?, Element::read_all("1 two [3]")?);
+        Ok(())
+    }
+
+    #[test]
+    fn split_rejects_invalid_writer_index() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "1 2 3")?;
+        let mut writers = vec![Writer::new(v1_0::Binary, Vec::new())?];
+        let result = split(&mut reader, &mut writers, |_| Ok(Some(1)));
+        assert!(matches!(result, Err(IonError::IllegalOperation(_))));
+        Ok(())
+    }
+}
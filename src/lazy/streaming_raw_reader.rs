@@ -1,17 +1,20 @@
 use crate::lazy::any_encoding::IonEncoding;
 use crate::lazy::decoder::{Decoder, LazyRawReader};
 use crate::lazy::expanded::EncodingContextRef;
-use crate::lazy::raw_stream_item::LazyRawStreamItem;
+use crate::lazy::raw_stream_item::{LazyRawStreamItem, RawStreamItem};
 use crate::location::SourceLocationState;
-use crate::{IonError, IonResult, LazyRawValue, Span};
+use crate::result::IonFailure;
+use crate::{HasRange, IonError, IonResult, LazyRawValue, Span};
 use std::cell::{OnceCell, UnsafeCell};
+use std::collections::VecDeque;
 use std::fs::File;
 use std::io;
 use std::io::{BufReader, Read, StdinLock};
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
-use std::ops::DerefMut;
+use std::ops::{DerefMut, Range};
 use std::rc::Rc;
+use std::sync::Arc;
 
 /// Wraps an implementation of [`IonDataSource`] and reads one top level value at a time from the input.
 pub struct StreamingRawReader<Encoding: Decoder, Input: IonInput> {
@@ -27,6 +30,13 @@ pub struct StreamingRawReader<Encoding: Decoder, Input: IonInput> {
     // The absolute position of the reader within the overall stream. This is the index of the first
     // byte that has not yet been read.
     stream_position: usize,
+    // The position within the overall stream at which the input begins. This is only nonzero for
+    // readers that were resumed partway through a stream. The positions of the items that the reader
+    // produces are relative to the beginning of the input.
+    input_offset: usize,
+    // The range of the most recently read item if it was consumed without being returned because
+    // it exceeded the reader's maximum value size.
+    skipped_value: Option<Range<usize>>,
     // XXX: The `UnsafeCell` wrappers around the field below is a workaround for a limitation in
     //      rustc's borrow checker that prevents mutable references from being conditionally
     //      returned in a loop.
@@ -92,9 +102,59 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
             detected_encoding: Encoding::INITIAL_ENCODING_EXPECTED,
             input: input.into_data_source().into(),
             stream_position: 0,
+            input_offset: 0,
+            skipped_value: None,
         }
     }
 
+    /// Configures a newly constructed reader whose input begins at `input_offset` within a stream
+    /// that was already found to be using `encoding`.
+    pub(crate) fn resume_at(&mut self, input_offset: usize, encoding: IonEncoding) {
+        self.input_offset = input_offset;
+        self.detected_encoding = encoding;
+    }
+
+    /// Returns a copy of this reader that will resume reading from the same position in its own
+    /// copy of the input.
+    pub(crate) fn clone_with_input(&self) -> Self
+    where
+        Input::DataSource: Clone,
+    {
+        StreamingRawReader {
+            decoder: PhantomData,
+            detected_encoding: self.detected_encoding,
+            stream_position: self.stream_position,
+            input_offset: self.input_offset,
+            skipped_value: self.skipped_value.clone(),
+            // SAFETY: The input is only modified via `&mut self`, so no mutation can be in
+            //         progress while we hold `&self`.
+            input: unsafe { &*self.input.get() }.clone().into(),
+        }
+    }
+
+    /// Repositions a reader over a fixed slice of input at `offset` within the overall stream.
+    /// The caller is responsible for confirming that `offset` is the start of a top-level item
+    /// encoded using `encoding`.
+    pub(crate) fn seek_to<Buffer: AsRef<[u8]>>(
+        &mut self,
+        offset: usize,
+        encoding: IonEncoding,
+    ) -> IonResult<()>
+    where
+        Input: IonInput<DataSource = IonSlice<Buffer>>,
+    {
+        let Some(position) = offset.checked_sub(self.input_offset) else {
+            return IonResult::illegal_operation(format!(
+                "cannot seek to offset {offset}; the reader's input begins at offset {}",
+                self.input_offset
+            ));
+        };
+        self.input.get_mut().seek(position)?;
+        self.stream_position = position;
+        self.detected_encoding = encoding;
+        Ok(())
+    }
+
     /// Gets a reference to the data source and tries to fill its buffer.
     #[inline]
     fn pull_more_data_from_source(&mut self) -> IonResult<usize> {
@@ -134,6 +194,7 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
         // If the input is a stream, we assume there may be more data available.
         // If it's a fixed slice, we know it's already complete.
         let mut input_source_exhausted = !Input::DataSource::IS_STREAMING;
+        self.skipped_value = None;
         loop {
             // If the input buffer is empty, try to pull more data from the source before proceeding.
             // It's important that we do this _before_ reading from the buffer; any item returned
@@ -181,6 +242,13 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
                 if input_source_exhausted {
                     // There's no more data, so the result is final.
                 } else {
+                    if matches!(result, Err(IonError::Incomplete(_))) {
+                        // Don't let a single item grow the buffer beyond the reader's limit.
+                        context.limits.check_incomplete_value_size(
+                            self.stream_position,
+                            available_bytes.len(),
+                        )?;
+                    }
                     // ...more data may be available, so try to pull from the data source.
                     if self.pull_more_data_from_source()? == 0 {
                         input_source_exhausted = true;
@@ -235,6 +303,13 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
                     }
                 }
 
+                let size_check = match item {
+                    RawStreamItem::Value(_) | RawStreamItem::EExp(_) => {
+                        context.limits.check_value_size(item.range())
+                    }
+                    _ => Ok(()),
+                };
+
                 // If this isn't just a peek, update our state to remember what we've already read.
                 if !is_peek {
                     // Mark those input bytes as having been consumed so they are not read again.
@@ -245,6 +320,15 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
                     // If the item read was an IVM, this will be a new value.
                     self.detected_encoding = new_encoding;
                 }
+
+                // An item that is too large has been consumed, so the next read will pick up
+                // after it.
+                if let Err(error) = size_check {
+                    if !is_peek {
+                        self.skipped_value = Some(item.range());
+                    }
+                    return Err(error);
+                }
             }
 
             // At this point, `self.input` is no longer being modified.
@@ -259,6 +343,22 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
     pub fn encoding(&self) -> IonEncoding {
         self.detected_encoding
     }
+
+    /// Returns the number of bytes of input that have been consumed.
+    pub(crate) fn stream_position(&self) -> usize {
+        self.stream_position
+    }
+
+    /// If the most recent read failed because the item it found was larger than the reader's
+    /// maximum value size, returns the range of that item, which has been skipped.
+    pub(crate) fn skipped_value(&self) -> Option<Range<usize>> {
+        self.skipped_value.clone()
+    }
+
+    /// Returns the position within the overall stream at which the input begins.
+    pub(crate) fn input_offset(&self) -> usize {
+        self.input_offset
+    }
 }
 
 // This is a separate trait so it can be `dyn`-compatible.
@@ -335,6 +435,31 @@ impl<SliceType: AsRef<[u8]>> IonSlice<SliceType> {
     pub fn stream_span(&self) -> Span<'_> {
         Span::with_offset(0, self.source.as_ref())
     }
+
+    /// Makes the byte at `position` the next one to be read.
+    #[cfg_attr(not(feature = "experimental-reader-writer"), allow(dead_code))]
+    pub(crate) fn seek(&mut self, position: usize) -> IonResult<()> {
+        let length = self.stream_bytes().len();
+        if position > length {
+            return IonResult::illegal_operation(format!(
+                "cannot seek to offset {position}; the input is only {length} bytes long"
+            ));
+        }
+        self.position = position;
+        Ok(())
+    }
+}
+
+// Cloning an `IonSlice` clones its `SliceType`; for types like `&[u8]` or `Rc<[u8]>`, this is cheap.
+impl<SliceType: AsRef<[u8]> + Clone> Clone for IonSlice<SliceType> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            position: self.position,
+            source_location_state: self.source_location_state.clone(),
+            shared_stream_data: self.shared_stream_data.clone(),
+        }
+    }
 }
 
 impl<SliceType: AsRef<[u8]>> IoBufferHandle for IonSlice<SliceType> {
@@ -578,6 +703,14 @@ impl<R: Read> IonStream<R> {
     }
 }
 
+impl<R: Read> IonStream<ChainedSources<R>> {
+    /// Constructs an `IonStream` that reads from each of the provided sources in turn, treating
+    /// them as a single logical stream. A value may begin in one source and end in the next.
+    pub fn from_sources(sources: impl IntoIterator<Item = R>) -> Self {
+        IonStream::new(ChainedSources::new(sources))
+    }
+}
+
 impl<R: Read> IoBufferHandle for IonStream<R> {
     fn save_io_buffer(&self) -> IoBuffer {
         // `self.buffer` is reference counted, so we can cheaply clone it.
@@ -609,6 +742,50 @@ impl<R: Read> IonDataSource for IonStream<R> {
     }
 }
 
+/// An [`io::Read`] implementation that reads each of an ordered series of sources to completion
+/// before moving on to the next one. Unlike [`io::Chain`], any number of sources can be chained.
+///
+/// To read from sources of different types, box them as `Box<dyn Read>`.
+pub struct ChainedSources<R: Read> {
+    // The sources that have not yet been exhausted. The first source is the one being read.
+    sources: VecDeque<R>,
+}
+
+impl<R: Read> ChainedSources<R> {
+    pub fn new(sources: impl IntoIterator<Item = R>) -> Self {
+        ChainedSources {
+            sources: sources.into_iter().collect(),
+        }
+    }
+
+    /// Adds a source to the end of the chain.
+    pub fn push(&mut self, source: R) {
+        self.sources.push_back(source);
+    }
+
+    /// Returns the number of sources that have not yet been exhausted.
+    pub fn remaining_sources(&self) -> usize {
+        self.sources.len()
+    }
+}
+
+impl<R: Read> Read for ChainedSources<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        while let Some(source) = self.sources.front_mut() {
+            let bytes_read = source.read(buf)?;
+            if bytes_read > 0 {
+                return Ok(bytes_read);
+            }
+            // This source is exhausted; discard it and try the next one.
+            self.sources.pop_front();
+        }
+        Ok(0)
+    }
+}
+
 /// Types that can be used as a source of Ion data.
 ///
 /// In general, this trait is implemented by mapping `Self` to either:
@@ -652,7 +829,17 @@ macro_rules! impl_ion_input_for_slice_types {
     };
 }
 
-impl_ion_input_for_slice_types!(&'a [u8], &'a str, String, &'a String, Vec<u8>, &'a Vec<u8>);
+impl_ion_input_for_slice_types!(
+    &'a [u8],
+    &'a str,
+    String,
+    &'a String,
+    Vec<u8>,
+    &'a Vec<u8>,
+    Box<[u8]>,
+    Rc<[u8]>,
+    Arc<[u8]>,
+);
 
 impl IonInput for File {
     type DataSource = IonStream<BufReader<Self>>;
@@ -686,6 +873,14 @@ impl<T: Read, U: Read> IonInput for io::Chain<T, U> {
     }
 }
 
+impl<R: Read> IonInput for ChainedSources<R> {
+    type DataSource = IonStream<Self>;
+
+    fn into_data_source(self) -> Self::DataSource {
+        IonStream::new(self)
+    }
+}
+
 impl IonInput for Box<dyn Read> {
     type DataSource = IonStream<Self>;
 
@@ -704,9 +899,12 @@ mod tests {
     use crate::lazy::expanded::EncodingContext;
     use crate::lazy::raw_stream_item::LazyRawStreamItem;
     use crate::lazy::raw_value_ref::RawValueRef;
-    use crate::lazy::streaming_raw_reader::{IonInput, StreamingRawReader};
+    use crate::lazy::streaming_raw_reader::{ChainedSources, IonInput, StreamingRawReader};
     use crate::raw_symbol_ref::AsRawSymbolRef;
-    use crate::{v1_0, Decimal, IonError, IonResult, IonStream, RawSymbolRef, RawVersionMarker};
+    use crate::{
+        v1_0, Decimal, Element, ElementReader, IonError, IonResult, IonStream, RawSymbolRef,
+        RawVersionMarker, Reader,
+    };
 
     fn expect_value<'a, D: Decoder>(
         actual: LazyRawStreamItem<'a, D>,
@@ -788,6 +986,35 @@ mod tests {
         read_example_stream(input)
     }
 
+    #[test]
+    fn read_chained_sources() -> IonResult<()> {
+        // Split the stream at several points, including in the middle of values and comments.
+        let split_points = [0, 0, 10, 11, 27, 60, 61, 104];
+        let sources = split_points
+            .windows(2)
+            .map(|bounds| Cursor::new(&EXAMPLE_STREAM[bounds[0]..bounds[1]]));
+        read_example_stream(ChainedSources::new(sources))?;
+        let sources = split_points
+            .windows(2)
+            .map(|bounds| tiny_buf_reader(&EXAMPLE_STREAM[bounds[0]..bounds[1]]));
+        read_example_stream(IonStream::from_sources(sources))
+    }
+
+    #[test]
+    fn read_binary_values_spanning_chained_sources() -> IonResult<()> {
+        let elements = Element::read_all(r#"foo "a string value" [1, 2, 3] {bar: baz}"#)?;
+        let binary = elements.encode_as(v1_0::Binary)?;
+        // Every split point produces a value that spans two sources.
+        for split_point in 1..binary.len() {
+            let (head, tail) = binary.split_at(split_point);
+            let sources: Vec<Box<dyn Read>> = vec![Box::new(head), Box::new(tail)];
+            let actual =
+                Reader::new(AnyEncoding, IonStream::from_sources(sources))?.read_all_elements()?;
+            assert_eq!(elements, actual, "split at {split_point}");
+        }
+        Ok(())
+    }
+
     const INVALID_EXAMPLE_STREAM: &str = "2024-03-12T16:33.000-05:"; // Missing offset minutes
 
     fn read_invalid_example_stream(input: impl IonInput) -> IonResult<()> {
@@ -1,9 +1,11 @@
 #![allow(non_camel_case_types)]
 
 use crate::element::builders::StructBuilder;
-use crate::lazy::decoder::{Decoder, LazyRawContainer};
+use crate::ion_data::IonEq;
+use crate::lazy::decoder::{Decoder, HasRange, LazyRawContainer};
 use crate::lazy::expanded::r#struct::{
-    ExpandedStructIterator, ExpandedStructSource, LazyExpandedField, LazyExpandedStruct,
+    ExpandedStructIterator, ExpandedStructSource, LazyExpandedField, LazyExpandedFieldName,
+    LazyExpandedStruct,
 };
 use crate::lazy::expanded::LazyExpandedValue;
 use crate::lazy::value::{AnnotationsIterator, LazyValue};
@@ -12,6 +14,7 @@ use crate::result::IonFailure;
 use crate::{Annotations, Element, IntoAnnotatedElement, IonError, IonResult, Struct, SymbolRef};
 use std::fmt;
 use std::fmt::{Debug, Formatter};
+use std::ops::Range;
 
 /// An as-of-yet unread binary Ion struct. `LazyStruct` is immutable; its fields and annotations
 /// can be read any number of times.
@@ -79,6 +82,39 @@ impl<'top, D: Decoder> LazyStruct<'top, D> {
         }
     }
 
+    /// Returns an iterator over the name of each field in this struct and the range of stream
+    /// offsets occupied by that field's encoding. See [`LazyField::range`].
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Reader;
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let ion_data = r#"{foo: 1, bar: [2, 3]}"#;
+    /// let mut reader = Reader::new(Text, ion_data)?;
+    /// let lazy_struct = reader.expect_next()?.read()?.expect_struct()?;
+    /// let mut fields = Vec::new();
+    /// for field in lazy_struct.field_ranges() {
+    ///     let (name, range) = field?;
+    ///     fields.push((name.text().unwrap(), &ion_data[range.unwrap()]));
+    /// }
+    /// assert_eq!(fields, vec![("foo", "foo: 1"), ("bar", "bar: [2, 3]")]);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn field_ranges(
+        &self,
+    ) -> impl Iterator<Item = IonResult<(SymbolRef<'top>, Option<Range<usize>>)>> + 'top {
+        self.iter().map(|field| {
+            let field = field?;
+            Ok((field.name()?, field.range()))
+        })
+    }
+
     #[cfg(feature = "experimental-tooling-apis")]
     pub fn expanded(&self) -> LazyExpandedStruct<'top, D> {
         self.expanded_struct
@@ -226,6 +262,60 @@ impl<'top, D: Decoder> LazyStruct<'top, D> {
         })
     }
 
+    /// Finds the first field with each of the specified names in a single pass over the struct,
+    /// returning their values in the same order as `names`. If the struct has no field with a
+    /// given name, its position in the output holds `None`.
+    ///
+    /// Calling [`LazyStruct::find`] once per name would scan the struct's fields each time.
+    /// Instead, this method visits each field at most once and stops as soon as every name has
+    /// been found. The values of fields that are not selected are never read; in binary Ion,
+    /// they are stepped over using their length prefixes.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, Reader};
+    /// use ion_rs::v1_0::Binary;
+    ///
+    /// let ion_data = r#"{id: 7, tags: [a, b, c], name: "foo", id: 8}"#;
+    /// let ion_bytes = Element::read_one(ion_data)?.encode_as(Binary)?;
+    /// let mut reader = Reader::new(Binary, ion_bytes)?;
+    ///
+    /// let lazy_struct = reader.expect_next()?.read()?.expect_struct()?;
+    /// let [name, id, zip] = lazy_struct.project(&["name", "id", "zip"])?[..] else {
+    ///     unreachable!("there is one value for each name");
+    /// };
+    /// assert_eq!(name.unwrap().read()?.expect_string()?, "foo");
+    /// // As with `find`, the first field with a repeated name is returned.
+    /// assert_eq!(id.unwrap().read()?.expect_i64()?, 7);
+    /// assert!(zip.is_none());
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn project(&self, names: &[&str]) -> IonResult<Vec<Option<LazyValue<'top, D>>>> {
+        let mut values = vec![None; names.len()];
+        let mut num_missing = names.len();
+        let mut fields = self.iter();
+        while num_missing > 0 {
+            let Some(field) = fields.next() else {
+                break;
+            };
+            let field = field?;
+            let Some(field_name) = field.name()?.text() else {
+                continue;
+            };
+            for (value, name) in values.iter_mut().zip(names) {
+                if value.is_none() && *name == field_name {
+                    *value = Some(field.value());
+                    num_missing -= 1;
+                }
+            }
+        }
+        Ok(values)
+    }
+
     /// Returns an iterator over the annotations on this value. If this value has no annotations,
     /// the resulting iterator will be empty.
     ///
@@ -322,15 +412,15 @@ impl<'top, D: Decoder> LazyField<'top, D> {
         }
     }
 
-    #[cfg(feature = "experimental-tooling-apis")]
-    pub fn range(&self) -> Option<std::ops::Range<usize>> {
-        use crate::HasRange;
-        match (self.raw_name(), self.raw_value()) {
-            (Some(raw_name), Some(raw_value)) => {
-                Some(raw_name.range().start..raw_value.range().end)
-            }
-            _ => None,
-        }
+    /// Returns the range of stream offsets occupied by this field's encoding, beginning with its
+    /// name and ending with its value. If the field's name or value was produced by a macro,
+    /// returns `None`.
+    pub fn range(&self) -> Option<Range<usize>> {
+        let LazyExpandedFieldName::RawName(_context, raw_name) = self.expanded_field.name() else {
+            return None;
+        };
+        let value_range = self.value().raw_range()?;
+        Some(raw_name.range().start..value_range.end)
     }
 }
 
@@ -385,6 +475,75 @@ impl<'top, D: Decoder> TryFrom<LazyStruct<'top, D>> for Element {
     }
 }
 
+impl<'top, D: Decoder> LazyStruct<'top, D> {
+    /// Reads all of the struct's fields, returning `None` if any of them cannot be read.
+    fn read_fields(&self) -> Option<Vec<(SymbolRef<'top>, LazyValue<'top, D>)>> {
+        self.iter()
+            .map(|field| {
+                let field = field?;
+                Ok((field.name()?, field.value()))
+            })
+            .collect::<IonResult<Vec<_>>>()
+            .ok()
+    }
+}
+
+/// Returns `true` if each field name appears the same number of times in `these` and `those`, and
+/// the values with that name are Ion-equivalent in some order.
+fn fields_eq<'top, D: Decoder>(
+    mut these: Vec<(SymbolRef<'top>, LazyValue<'top, D>)>,
+    mut those: Vec<(SymbolRef<'top>, LazyValue<'top, D>)>,
+) -> bool {
+    if these.len() != those.len() {
+        return false;
+    }
+    // Sorting by name puts each name's fields next to each other, so each group only needs to be
+    // compared with the other struct's group at the same position.
+    these.sort_by_key(|(name, _)| *name);
+    those.sort_by_key(|(name, _)| *name);
+    let by_name = |(a, _): &(SymbolRef<'top>, _), (b, _): &(SymbolRef<'top>, _)| a == b;
+    these
+        .chunk_by(by_name)
+        .zip(those.chunk_by(by_name))
+        .all(|(these, those)| {
+            these.len() == those.len() && these[0].0 == those[0].0 && values_eq(these, those)
+        })
+}
+
+/// Returns `true` if each value in `these` can be paired with a distinct Ion-equivalent value in
+/// `those`, which must be the same length.
+fn values_eq<'top, D: Decoder>(
+    these: &[(SymbolRef<'top>, LazyValue<'top, D>)],
+    those: &[(SymbolRef<'top>, LazyValue<'top, D>)],
+) -> bool {
+    let mut unmatched: Vec<&LazyValue<'top, D>> = those.iter().map(|(_, v)| v).collect();
+    for (_, value) in these {
+        let Some(index) = unmatched.iter().position(|other| value.ion_eq(other)) else {
+            return false;
+        };
+        unmatched.swap_remove(index);
+    }
+    true
+}
+
+/// Like [`Struct`], two lazy structs are equal if they contain the same fields in any order. Field
+/// values are compared using Ion equivalence, and the structs' own annotations are not considered.
+/// Structs whose fields cannot all be read are never considered equal.
+impl<D: Decoder> PartialEq for LazyStruct<'_, D> {
+    fn eq(&self, other: &Self) -> bool {
+        let (Some(these), Some(those)) = (self.read_fields(), other.read_fields()) else {
+            return false;
+        };
+        fields_eq(these, those)
+    }
+}
+
+impl<D: Decoder> IonEq for LazyStruct<'_, D> {
+    fn ion_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
 impl<'top, D: Decoder> IntoIterator for &LazyStruct<'top, D> {
     type Item = IonResult<LazyField<'top, D>>;
     type IntoIter = StructIterator<'top, D>;
@@ -406,10 +565,86 @@ impl<'top, D: Decoder> IntoIterator for LazyStruct<'top, D> {
 #[cfg(test)]
 mod tests {
     use crate::lazy::binary::test_utilities::to_binary_ion;
-    use crate::{v1_0, Reader};
+    use crate::{v1_0, AnyEncoding, Reader};
 
     use super::*;
 
+    #[test]
+    fn struct_equality_ignores_field_order() -> IonResult<()> {
+        let cases = [
+            ("{a: 1, b: 2, a: 3}", "{b: 2, a: 3, a: 1}", true),
+            ("{a: 1, b: 2}", "{a: 1, b: 2, b: 2}", false),
+            ("{a: 1, a: 1}", "{a: 1, a: x::1}", false),
+            ("{a: [1, {b: 2, c: 3}]}", "{a: [1, {c: 3, b: 2}]}", true),
+            ("{a: [1, {b: 2, c: 3}]}", "{a: [{c: 3, b: 2}, 1]}", false),
+            ("{a: nan}", "{a: nan}", true),
+            ("{c: 1, a: 2, b: 3, a: 1}", "{a: 1, b: 3, a: 2, c: 1}", true),
+            ("{a: 1, a: 2, b: 3}", "{a: 2, b: 3, a: 2}", false),
+            ("{a: 1, b: 2}", "{a: 1, c: 2}", false),
+        ];
+        for (text1, text2, expected) in cases {
+            // Compare binary and text encodings of the same data.
+            let mut reader1 = Reader::new(AnyEncoding, to_binary_ion(text1)?)?;
+            let mut reader2 = Reader::new(AnyEncoding, text2)?;
+            let struct1 = reader1.expect_next()?.read()?.expect_struct()?;
+            let struct2 = reader2.expect_next()?.read()?.expect_struct()?;
+            assert_eq!(struct1 == struct2, expected, "{text1} == {text2}");
+            assert_eq!(struct2 == struct1, expected, "{text2} == {text1}");
+            assert_eq!(struct1.ion_eq(&struct2), expected, "{text1} ion_eq {text2}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn wide_struct_equality() -> IonResult<()> {
+        // Each name appears twice, and the second struct lists the fields in reverse order.
+        let fields: Vec<String> = (0..2_000)
+            .map(|i| format!("field_{}: {i}", i % 1_000))
+            .collect();
+        let text1 = format!("{{{}}}", fields.join(", "));
+        let mut reversed = fields.clone();
+        reversed.reverse();
+        let text2 = format!("{{{}}}", reversed.join(", "));
+        reversed[0] = String::from("field_999: -1");
+        let text3 = format!("{{{}}}", reversed.join(", "));
+
+        let mut reader1 = Reader::new(AnyEncoding, text1)?;
+        let mut reader2 = Reader::new(AnyEncoding, text2)?;
+        let mut reader3 = Reader::new(AnyEncoding, text3)?;
+        let struct1 = reader1.expect_next()?.read()?.expect_struct()?;
+        let struct2 = reader2.expect_next()?.read()?.expect_struct()?;
+        let struct3 = reader3.expect_next()?.read()?.expect_struct()?;
+        assert_eq!(struct1, struct2);
+        assert_ne!(struct1, struct3);
+        Ok(())
+    }
+
+    #[test]
+    fn find_in_large_struct() -> IonResult<()> {
+        // Build a struct that is large enough to be given a field index.
+        let mut ion_text = String::from("{");
+        for i in 0..64 {
+            ion_text.push_str(&format!("field_{i}: {i}, "));
+        }
+        ion_text.push_str("field_7: duplicate, $0: unknown_text}");
+        for ion_data in [to_binary_ion(&ion_text)?, ion_text.clone().into_bytes()] {
+            let mut reader = Reader::new(AnyEncoding, ion_data)?;
+            let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+            // Look up every field more than once, in an order unrelated to the encoding.
+            for _ in 0..2 {
+                for i in (0..64).rev() {
+                    let value = struct_.get_expected(&format!("field_{i}"))?;
+                    assert_eq!(value, ValueRef::Int(i.into()));
+                }
+            }
+            // When a name is repeated, the first field is returned.
+            assert_eq!(struct_.get("field_7")?, Some(ValueRef::Int(7.into())));
+            assert_eq!(struct_.get("field_64")?, None);
+            assert_eq!(struct_.get("")?, None);
+        }
+        Ok(())
+    }
+
     #[test]
     fn find() -> IonResult<()> {
         let ion_data = to_binary_ion("{foo: 1, bar: 2, baz: 3}")?;
@@ -460,6 +695,104 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn project() -> IonResult<()> {
+        let ion_text = "{foo: 1, bar: 2, $0: 3, baz: 4, bar: 5}";
+        for ion_data in [to_binary_ion(ion_text)?, ion_text.as_bytes().to_vec()] {
+            let mut reader = Reader::new(AnyEncoding, ion_data)?;
+            let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+            let values = struct_
+                .project(&["baz", "quux", "bar", "baz"])?
+                .into_iter()
+                .map(|value| value.map(|v| v.read()).transpose())
+                .collect::<IonResult<Vec<_>>>()?;
+            assert_eq!(
+                values,
+                vec![
+                    Some(ValueRef::Int(4.into())),
+                    None,
+                    Some(ValueRef::Int(2.into())),
+                    Some(ValueRef::Int(4.into())),
+                ]
+            );
+            assert!(struct_.project(&[])?.is_empty());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn project_stops_after_finding_every_name() -> IonResult<()> {
+        #[rustfmt::skip]
+        let ion_data = [
+            0xE0, 0x01, 0x00, 0xEA, // IVM
+            0xD8,                   // A struct with an 8-byte body
+            0x84, 0x21, 0x01,       // name: 1
+            0x85, 0x21, 0x02,       // version: 2
+            0x86, 0xF0,             // imports: <an invalid type descriptor>
+        ];
+        let mut reader = Reader::new(v1_0::Binary, ion_data.as_slice())?;
+        let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+        let values = struct_.project(&["version", "name"])?;
+        assert_eq!(values[0].unwrap().read()?, ValueRef::Int(2.into()));
+        assert_eq!(values[1].unwrap().read()?, ValueRef::Int(1.into()));
+        // Looking for a name that is not among the valid fields reaches the invalid one.
+        assert!(struct_.project(&["name", "symbols"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn field_ranges() -> IonResult<()> {
+        let ion_text = r#"{foo: 1, bar: x::"two", baz: [3]}"#;
+        let mut reader = Reader::new(AnyEncoding, ion_text)?;
+        let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+        let fields = struct_
+            .field_ranges()
+            .map(|field| {
+                let (name, range) = field?;
+                Ok((name.text().unwrap(), &ion_text[range.unwrap()]))
+            })
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(
+            fields,
+            vec![
+                ("foo", "foo: 1"),
+                ("bar", r#"bar: x::"two""#),
+                ("baz", "baz: [3]")
+            ]
+        );
+
+        // In binary Ion, each field's range begins with its field ID and ends with its value.
+        let ion_data = to_binary_ion(ion_text)?;
+        let mut reader = Reader::new(v1_0::Binary, ion_data.as_slice())?;
+        let value = reader.expect_next()?;
+        let struct_range = value.raw_range().unwrap();
+        let struct_ = value.read()?.expect_struct()?;
+        let ranges = struct_
+            .field_ranges()
+            .map(|field| Ok(field?.1.unwrap()))
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(ranges.len(), 3);
+        assert_eq!(ranges[2].end, struct_range.end);
+        for (range, field) in ranges.iter().zip(&struct_) {
+            let value_range = field?.value().raw_range().unwrap();
+            assert_eq!(range.start + 1, value_range.start);
+            assert_eq!(range.end, value_range.end);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn field_ranges_of_macro_output() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "$ion_1_1 {foo: 1, (:make_field bar 2)}")?;
+        let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+        let fields = struct_.field_ranges().collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].1, Some(10..16));
+        assert_eq!(fields[1].1, None);
+        Ok(())
+    }
+
     #[test]
     fn annotations() -> IonResult<()> {
         let ion_data = to_binary_ion("a::b::c::{foo: 1, bar: 2, baz: quux::quuz::3}")?;
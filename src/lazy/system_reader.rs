@@ -4,22 +4,26 @@ use crate::constants::v1_1;
 use crate::lazy::any_encoding::{IonEncoding, IonVersion};
 use crate::lazy::decoder::Decoder;
 use crate::lazy::expanded::compiler::TemplateCompiler;
-use crate::lazy::expanded::encoding_module::EncodingModule;
+use crate::lazy::expanded::encoding_module::{EncodingModule, ModuleBindings};
 use crate::lazy::expanded::macro_table::{MacroTable, ION_1_1_SYSTEM_MACROS};
 use crate::lazy::expanded::template::TemplateMacro;
-use crate::lazy::expanded::{ExpandedStreamItem, ExpandingReader, LazyExpandedValue};
+use crate::lazy::expanded::{
+    EncodingContextSnapshot, ExpandedStreamItem, ExpandingReader, LazyExpandedValue,
+};
+use crate::lazy::reader_state::ReaderState;
 use crate::lazy::sequence::SExpIterator;
-use crate::lazy::streaming_raw_reader::{IonInput, StreamingRawReader};
-use crate::lazy::system_stream_item::SystemStreamItem;
+use crate::lazy::streaming_raw_reader::{IonInput, IonSlice, StreamingRawReader};
+use crate::lazy::system_stream_item::{SystemStreamEvent, SystemStreamItem};
+use crate::lazy::table_growth::TableGrowth;
 use crate::lazy::text::raw::v1_1::reader::MacroAddress;
 use crate::lazy::value::LazyValue;
 use crate::read_config::ReadConfig;
 use crate::result::IonFailure;
 use crate::{
-    AnyEncoding, Catalog, Int, IonError, IonResult, IonType, LazyField, LazySExp, LazyStruct,
-    Symbol, SymbolTable, ValueRef,
+    Catalog, Int, IonError, IonResult, IonType, LazyField, LazySExp, LazyStruct, Symbol,
+    SymbolTable, ValueRef,
 };
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 use std::sync::Arc;
 
 /// A binary reader that only reads each value that it visits upon request (that is: lazily).
@@ -83,7 +87,7 @@ pub(crate) struct SystemReader<Encoding: Decoder, Input: IonInput> {
 
 // If the reader encounters a symbol table in the stream, it will store all of the symbols that
 // the table defines in this structure so that they may be applied when the reader next advances.
-#[derive(Default)]
+#[derive(Clone, Default)]
 #[cfg_attr(feature = "experimental-tooling-apis", visibility::make(pub))]
 pub(crate) struct PendingContextChanges {
     pub(crate) switch_to_version: Option<IonVersion>,
@@ -92,8 +96,10 @@ pub(crate) struct PendingContextChanges {
     pub(crate) imported_symbols: Vec<Symbol>,
     pub(crate) symbols: Vec<Symbol>,
     // A new encoding modules defined in the current encoding directive.
-    // TODO: Support for defining several modules
     pub(crate) new_active_module: Option<EncodingModule>,
+    // If the current encoding directive bound any named modules, the complete set of bindings
+    // that should replace the encoding context's.
+    pub(crate) new_module_bindings: Option<ModuleBindings>,
 }
 
 #[cfg_attr(not(feature = "experimental-tooling-apis"), allow(dead_code))]
@@ -106,6 +112,7 @@ impl PendingContextChanges {
             symbols: Vec::new(),
             imported_symbols: Vec::new(),
             new_active_module: None,
+            new_module_bindings: None,
         }
     }
     pub fn local_symbols(&self) -> &[Symbol] {
@@ -125,6 +132,12 @@ impl PendingContextChanges {
     pub(crate) fn take_new_active_module(&mut self) -> Option<EncodingModule> {
         self.new_active_module.take()
     }
+    pub fn new_module_bindings(&self) -> Option<&ModuleBindings> {
+        self.new_module_bindings.as_ref()
+    }
+    pub(crate) fn take_new_module_bindings(&mut self) -> Option<ModuleBindings> {
+        self.new_module_bindings.take()
+    }
 }
 
 #[cfg_attr(not(feature = "experimental-tooling-apis"), allow(dead_code))]
@@ -135,10 +148,52 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
     ) -> SystemReader<Encoding, Input> {
         let config = config.into();
         let raw_reader = StreamingRawReader::new(config.encoding(), input);
-        let expanding_reader = ExpandingReader::new(raw_reader, config.catalog);
+        let mut expanding_reader = ExpandingReader::new(raw_reader, config.catalog);
+        expanding_reader.context_mut().defer_timestamp_parsing = config.defer_timestamp_parsing;
+        expanding_reader.context_mut().leap_second_handling = config.leap_second_handling;
+        expanding_reader.context_mut().strict_utf8 = config.strict_utf8;
+        expanding_reader.context_mut().limits = config.limits;
         SystemReader { expanding_reader }
     }
 
+    /// See [`Reader::try_clone`](crate::Reader::try_clone).
+    pub(crate) fn try_clone(&self) -> IonResult<Self>
+    where
+        Input::DataSource: Clone,
+    {
+        Ok(SystemReader {
+            expanding_reader: self.expanding_reader.try_clone()?,
+        })
+    }
+
+    /// See [`Reader::save_state`](crate::Reader::save_state).
+    pub(crate) fn save_state(&self) -> IonResult<ReaderState> {
+        self.expanding_reader.save_state()
+    }
+
+    /// See [`Reader::resume_from_state`](crate::Reader::resume_from_state).
+    pub(crate) fn resume_from_state(
+        config: impl Into<ReadConfig<Encoding>>,
+        input: Input,
+        state: &ReaderState,
+    ) -> IonResult<SystemReader<Encoding, Input>> {
+        let mut reader = SystemReader::new(config, input);
+        reader.expanding_reader.resume_from_state(state)?;
+        Ok(reader)
+    }
+
+    /// See [`Reader::seek_to`](crate::Reader::seek_to).
+    pub(crate) fn seek_to<Buffer: AsRef<[u8]>>(
+        &mut self,
+        offset: usize,
+        state: &ReaderState,
+    ) -> IonResult<()>
+    where
+        Input: IonInput<DataSource = IonSlice<Buffer>>,
+    {
+        self.expanding_reader.seek_to(offset, state)
+    }
+
     pub fn register_template_src(&mut self, template_definition: &str) -> IonResult<MacroAddress> {
         self.expanding_reader
             .register_template_src(template_definition)
@@ -197,6 +252,18 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
         self.expanding_reader.pending_context_changes()
     }
 
+    /// Returns a snapshot of the reader's encoding context. See
+    /// [`EncodingContext::snapshot`](crate::lazy::expanded::EncodingContext::snapshot).
+    pub fn context_snapshot(&self) -> EncodingContextSnapshot {
+        self.expanding_reader.context().context.snapshot()
+    }
+
+    /// Rolls the reader's encoding context back to `snapshot`. Any encoding directives that have
+    /// been read but not yet applied are discarded.
+    pub fn restore_context(&mut self, snapshot: &EncodingContextSnapshot) -> IonResult<()> {
+        self.expanding_reader.restore_context(snapshot)
+    }
+
     /// Returns the next top-level stream item (IVM, symbol table, encoding directive, Value, or nothing)
     /// as an [`ExpandedStreamItem`].
     ///
@@ -206,6 +273,17 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
         self.expanding_reader.next_item()
     }
 
+    /// Returns the next top-level stream item (IVM, e-expression, symbol table, encoding directive,
+    /// value, or nothing) as a [`SystemStreamEvent`] that also provides access to the encoding
+    /// context that was used to read it.
+    ///
+    /// E-expressions are surfaced before the (ephemeral) items they expand to; see
+    /// [`SystemStreamEvent::is_ephemeral`].
+    pub fn next_event(&mut self) -> IonResult<SystemStreamEvent<'_, Encoding>> {
+        let (item, context, is_expansion) = self.expanding_reader.next_item_with_context()?;
+        Ok(SystemStreamEvent::new(item, context, is_expansion))
+    }
+
     /// Returns the next top-level stream item (IVM, symbol table, encoding directive, Value, or nothing)
     /// as a [`SystemStreamItem`].
     pub fn next_item(&mut self) -> IonResult<SystemStreamItem<'_, Encoding>> {
@@ -218,6 +296,50 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
         self.expanding_reader.next_value()
     }
 
+    /// Like [`next_value`](Self::next_value), but also returns the number of bytes of input that
+    /// have been consumed and the reader's [`TableGrowth`] once the value (or the end of the
+    /// stream) has been found.
+    pub(crate) fn next_value_with_stats(
+        &mut self,
+    ) -> IonResult<(Option<LazyValue<'_, Encoding>>, usize, TableGrowth)> {
+        use SystemStreamItem::*;
+        // `next_system_item` only needs an immutable reference, which allows the reader's state to
+        // be read while the value is still borrowed.
+        let reader = &self.expanding_reader;
+        loop {
+            let value = match reader.next_system_item()? {
+                Value(value) => Some(value),
+                EndOfStream(_) => None,
+                _ => continue,
+            };
+            return Ok((value, reader.stream_position(), reader.table_growth()));
+        }
+    }
+
+    /// Advances past the next application value without constructing it. Returns `false` if the
+    /// end of the stream was reached instead. See [`ExpandingReader::skip_next_value`].
+    pub(crate) fn skip_next_value(&mut self) -> IonResult<bool> {
+        self.expanding_reader.skip_next_value()
+    }
+
+    /// Like [`skip_next_value`](Self::skip_next_value), but also returns the number of bytes of
+    /// input that have been consumed and the reader's [`TableGrowth`].
+    pub(crate) fn skip_next_value_with_stats(&mut self) -> IonResult<(bool, usize, TableGrowth)> {
+        let found = self.expanding_reader.skip_next_value()?;
+        let reader = &self.expanding_reader;
+        Ok((found, reader.stream_position(), reader.table_growth()))
+    }
+
+    /// See [`StreamingRawReader::skipped_value`](crate::lazy::streaming_raw_reader::StreamingRawReader::skipped_value).
+    pub(crate) fn skipped_value(&self) -> Option<Range<usize>> {
+        self.expanding_reader.skipped_value()
+    }
+
+    /// Returns counters describing how the symbol and macro tables have grown.
+    pub(crate) fn table_growth(&self) -> TableGrowth {
+        self.expanding_reader.table_growth()
+    }
+
     /// Like [`next_value`](Self::next_value) but returns an error if there is not another
     /// application value in the stream.
     pub fn expect_next_value(&mut self) -> IonResult<LazyValue<'_, Encoding>> {
@@ -228,20 +350,51 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
 
     pub(crate) fn process_encoding_directive(
         pending_changes: &mut PendingContextChanges,
+        catalog: &dyn Catalog,
         directive: LazyExpandedValue<'_, Encoding>,
     ) -> IonResult<()> {
         // We've already confirmed this is an annotated sexp
         let directive = LazyValue::new(directive).read()?.expect_sexp()?;
+        let context = directive.expanded().context;
         let mut exprs = directive.iter();
         let operation = Self::expect_next_sexp_value("operation name", &mut exprs)?;
         let operation_name = Self::expect_symbol_text("operation name", operation)?;
-        // For now, the only supported directive is `$ion::(module _ /*...*/)`.
+        // The named modules that are bound when this directive begins. If an earlier directive in
+        // the same top-level expression bound any modules, those bindings are still pending.
+        let mut modules = match pending_changes.new_module_bindings() {
+            Some(pending_modules) => pending_modules.clone(),
+            None => context.modules().clone(),
+        };
         match operation_name {
-            "module" => {}
-            todo_operation @ ("encoding" | "import") => {
-                return IonResult::decoding_error(format!(
-                    "directive operation `{todo_operation}` is not yet supported"
-                ));
+            // `$ion::(module name /*...*/)` defines a module. If the name is `_`, the new module
+            // replaces the default module. Otherwise, it is bound to the name, shadowing any
+            // module that was previously bound to it.
+            "module" => {
+                let module_name = Self::expect_next_sexp_value("module name", &mut exprs)?;
+                let module_name = Self::expect_symbol_text("module name", module_name)?;
+                if module_name == v1_1::constants::DEFAULT_MODULE_NAME {
+                    let mut module = pending_changes
+                        .take_new_active_module()
+                        .unwrap_or_else(Self::new_default_module);
+                    Self::process_module_definition(catalog, &modules, &mut module, exprs)?;
+                    pending_changes.new_active_module = Some(module);
+                } else {
+                    let mut module = Self::new_named_module(module_name);
+                    Self::process_module_definition(catalog, &modules, &mut module, exprs)?;
+                    modules.bind(module)?;
+                    pending_changes.new_module_bindings = Some(modules);
+                }
+            }
+            // `$ion::(import name catalog_key version)` binds a shared module from the catalog to
+            // the name.
+            "import" => {
+                modules.bind(Self::process_module_import(catalog, directive)?)?;
+                pending_changes.new_module_bindings = Some(modules);
+            }
+            "encoding" => {
+                return IonResult::decoding_error(
+                    "directive operation `encoding` is not yet supported",
+                );
             }
             invalid_operation => {
                 return IonResult::decoding_error(format!(
@@ -249,79 +402,166 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
                 ));
             }
         }
+        Ok(())
+    }
 
-        let module_name = Self::expect_next_sexp_value("module name", &mut exprs)?;
-        let module_name = Self::expect_symbol_text("module name", module_name)?;
+    /// Returns the module that a `(module _ ...)` definition modifies if it does not specify a
+    /// symbol table or macro table: one with the system macros and no symbols.
+    fn new_default_module() -> EncodingModule {
+        EncodingModule::new(
+            v1_1::constants::DEFAULT_MODULE_NAME.to_owned(),
+            MacroTable::with_system_macros(IonVersion::v1_1),
+            SymbolTable::empty(IonVersion::v1_1),
+        )
+    }
 
-        if module_name != v1_1::constants::DEFAULT_MODULE_NAME {
-            return IonResult::decoding_error("only the default module `_` is currently supported");
-        }
+    fn new_named_module(name: &str) -> EncodingModule {
+        EncodingModule::new(
+            name.to_owned(),
+            MacroTable::empty(),
+            SymbolTable::empty(IonVersion::v1_1),
+        )
+    }
 
-        for step in exprs {
-            Self::process_encoding_directive_operation(pending_changes, step?)?;
+    /// Applies each step in a module definition to `module`. Steps can refer to the modules in
+    /// `modules` by name, as well as to any modules that earlier steps imported or defined.
+    /// Those nested modules are only visible within the definition.
+    pub(crate) fn process_module_definition(
+        catalog: &dyn Catalog,
+        modules: &ModuleBindings,
+        module: &mut EncodingModule,
+        steps: SExpIterator<'_, Encoding>,
+    ) -> IonResult<()> {
+        let mut visible_modules = modules.clone();
+        for step in steps {
+            let step = step?;
+            let operation_sexp = step.read()?.expect_sexp().map_err(|_| {
+                IonError::decoding_error(format!(
+                    "found an encoding directive step that was not an s-expression: {step:?}"
+                ))
+            })?;
+
+            let mut values = operation_sexp.iter();
+            let first_value =
+                Self::expect_next_sexp_value("encoding directive operation name", &mut values)?;
+            let step_name_text =
+                Self::expect_symbol_text("encoding directive operation name", first_value)?;
+
+            match step_name_text {
+                "module" => {
+                    let module_name = Self::expect_next_sexp_value("module name", &mut values)?;
+                    let module_name = Self::expect_symbol_text("module name", module_name)?;
+                    let mut nested_module = Self::new_named_module(module_name);
+                    Self::process_module_definition(
+                        catalog,
+                        &visible_modules,
+                        &mut nested_module,
+                        values,
+                    )?;
+                    visible_modules.bind(nested_module)?;
+                }
+                "import" => {
+                    visible_modules.bind(Self::process_module_import(catalog, operation_sexp)?)?;
+                }
+                "symbol_table" => {
+                    let symbol_table =
+                        Self::process_symbol_table_definition(operation_sexp, &visible_modules)?;
+                    module.set_symbol_table(symbol_table);
+                }
+                "macro_table" => {
+                    let macro_table =
+                        Self::process_macro_table_definition(operation_sexp, &visible_modules)?;
+                    module.set_macro_table(macro_table);
+                }
+                _ => {
+                    return IonResult::decoding_error(format!(
+                        "unsupported encoding directive step '{step_name_text}'"
+                    ))
+                }
+            }
         }
         Ok(())
     }
 
-    pub(crate) fn process_encoding_directive_operation(
-        pending_changes: &mut PendingContextChanges,
-        value: LazyValue<'_, Encoding>,
-    ) -> IonResult<()> {
-        let operation_sexp = value.read()?.expect_sexp().map_err(|_| {
-            IonError::decoding_error(format!(
-                "found an encoding directive step that was not an s-expression: {value:?}"
-            ))
-        })?;
-
-        let mut values = operation_sexp.iter();
-        let first_value =
-            Self::expect_next_sexp_value("encoding directive operation name", &mut values)?;
-        let step_name_text =
-            Self::expect_symbol_text("encoding directive operation name", first_value)?;
-
-        match step_name_text {
-            "module" => todo!("defining a new named module"),
-            "symbol_table" => {
-                let symbol_table = Self::process_symbol_table_definition(operation_sexp)?;
-                let new_encoding_module = match pending_changes.take_new_active_module() {
-                    None => EncodingModule::new(
-                        v1_1::constants::DEFAULT_MODULE_NAME.to_owned(),
-                        MacroTable::with_system_macros(IonVersion::v1_1),
-                        symbol_table,
-                    ),
-                    Some(mut module) => {
-                        module.set_symbol_table(symbol_table);
-                        module
-                    }
-                };
-                pending_changes.new_active_module = Some(new_encoding_module);
-            }
-            "macro_table" => {
-                let macro_table = Self::process_macro_table_definition(operation_sexp)?;
-                let new_encoding_module = match pending_changes.take_new_active_module() {
-                    None => EncodingModule::new(
-                        v1_1::constants::DEFAULT_MODULE_NAME.to_owned(),
-                        macro_table,
-                        SymbolTable::empty(IonVersion::v1_1),
-                    ),
-                    Some(mut module) => {
-                        module.set_macro_table(macro_table);
-                        module
-                    }
-                };
-                pending_changes.new_active_module = Some(new_encoding_module);
-            }
-            _ => {
+    /// Looks up the shared module that an `(import name catalog_key version)` step refers to in the
+    /// catalog and returns it as an `EncodingModule` called `name`.
+    fn process_module_import(
+        catalog: &dyn Catalog,
+        operation: LazySExp<'_, Encoding>,
+    ) -> IonResult<EncodingModule> {
+        let mut args = operation.iter();
+        // The caller has already confirmed that the operation name is `import`.
+        let _operation_name =
+            Self::expect_next_sexp_value("an `import` operation name", &mut args)?;
+        let module_name = Self::expect_next_sexp_value("an imported module name", &mut args)?;
+        let module_name = Self::expect_symbol_text("an imported module name", module_name)?;
+        let catalog_key = Self::expect_next_sexp_value("a catalog key", &mut args)?;
+        let catalog_key = match catalog_key.read()? {
+            ValueRef::String(s) => s.text(),
+            ValueRef::Symbol(s) => s.text().ok_or_else(|| {
+                IonError::decoding_error("found a catalog key that had undefined text ($0)")
+            })?,
+            other => {
                 return IonResult::decoding_error(format!(
-                    "unsupported encoding directive step '{step_name_text}'"
+                    "found a catalog key that was not text: {other:?}"
                 ))
             }
+        };
+        let version = match args.next().transpose()? {
+            // If there's no version, we treat it as version 1.
+            None => 1,
+            Some(value) => match value.read()? {
+                ValueRef::Int(i) if i > Int::ZERO => usize::try_from(i).map_err(|_| {
+                    IonError::decoding_error(format!(
+                        "found a module import (catalog key='{catalog_key}') with a version number too high to support: {i}"
+                    ))
+                })?,
+                other => {
+                    return IonResult::decoding_error(format!(
+                        "found a module import (catalog key='{catalog_key}') with an invalid version: {other:?}"
+                    ))
+                }
+            },
+        };
+        if let Some(unexpected) = args.next().transpose()? {
+            return IonResult::decoding_error(format!(
+                "found an unexpected value in the (import ...): {unexpected:?}"
+            ));
         }
-        Ok(())
+
+        let shared_symbols = catalog.get_table_with_version(catalog_key, version);
+        let shared_macros = catalog.get_macro_table_with_version(catalog_key, version);
+        if shared_symbols.is_none() && shared_macros.is_none() {
+            return IonResult::decoding_error(format!(
+                "module import failed, could not find module with name='{catalog_key}' and version={version}"
+            ));
+        }
+        let mut symbol_table = SymbolTable::empty(IonVersion::v1_1);
+        for symbol in shared_symbols.iter().flat_map(|table| table.symbols()) {
+            symbol_table.add_symbol(symbol.clone());
+        }
+        let macro_table = shared_macros.cloned().unwrap_or_else(MacroTable::empty);
+        Ok(EncodingModule::new(
+            module_name.to_owned(),
+            macro_table,
+            symbol_table,
+        ))
+    }
+
+    fn expect_module<'a>(
+        modules: &'a ModuleBindings,
+        module_name: &str,
+    ) -> IonResult<&'a EncodingModule> {
+        modules.get(module_name).ok_or_else(|| {
+            IonError::decoding_error(format!(
+                "found a reference to module '{module_name}', which is not bound"
+            ))
+        })
     }
 
     fn process_symbol_table_definition(
         operation: LazySExp<'_, Encoding>,
+        modules: &ModuleBindings,
     ) -> IonResult<SymbolTable> {
         let mut args = operation.iter();
         let operation_name_value =
@@ -344,7 +584,11 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
                     }
                 }
                 ValueRef::Symbol(symbol) => {
-                    todo!("modules other than _ (found symbol '{symbol:?}')")
+                    let module_name = symbol.text().unwrap_or_default();
+                    let module = Self::expect_module(modules, module_name)?;
+                    for symbol in module.symbol_table().application_symbols() {
+                        symbol_table.add_symbol(symbol.clone());
+                    }
                 }
                 ValueRef::List(symbol_list) => {
                     for value in symbol_list {
@@ -369,7 +613,10 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
         Ok(symbol_table)
     }
 
-    fn process_macro_table_definition(operation: LazySExp<'_, Encoding>) -> IonResult<MacroTable> {
+    pub(crate) fn process_macro_table_definition(
+        operation: LazySExp<'_, Encoding>,
+        modules: &ModuleBindings,
+    ) -> IonResult<MacroTable> {
         let mut args = operation.iter();
         let operation_name_value =
             Self::expect_next_sexp_value("a `macro_table` operation name", &mut args)?;
@@ -389,6 +636,7 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
                     let new_macro = TemplateCompiler::compile_from_sexp(
                         context.macro_table(),
                         &new_macro_table,
+                        modules,
                         macro_def_sexp,
                     )?;
                     new_macro_table.add_template_macro(new_macro)?;
@@ -404,8 +652,10 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
                 {
                     new_macro_table.append_all_macros_from(&ION_1_1_SYSTEM_MACROS)?;
                 }
-                ValueRef::Symbol(_module_name) => {
-                    todo!("re-exporting macros from a module other than _")
+                ValueRef::Symbol(module_name) => {
+                    let module_name = module_name.text().unwrap_or_default();
+                    let module = Self::expect_module(modules, module_name)?;
+                    new_macro_table.append_all_macros_from(module.macro_table())?;
                 }
                 _other => {
                     return IonResult::decoding_error(format!(
@@ -641,7 +891,7 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
 }
 
 #[cfg_attr(not(feature = "experimental-tooling-apis"), allow(dead_code))]
-impl<Input: IonInput> SystemReader<AnyEncoding, Input> {
+impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
     pub fn detected_encoding(&self) -> IonEncoding {
         self.expanding_reader.detected_encoding()
     }
@@ -1149,4 +1399,286 @@ mod tests {
         assert_eq!(reader.expect_next_value()?.read()?.expect_i64()?, 12);
         Ok(())
     }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn events_surface_eexps_with_context() -> IonResult<()> {
+        let ion = r#"
+            $ion_1_1
+            $ion::
+            (module _
+                (symbol_table ["foo"])
+                (macro_table _ (macro twice (x) (.values (%x) (%x)))))
+            (:twice foo)
+            bar
+        "#;
+        let mut reader = SystemReader::new(AnyEncoding, ion);
+        let event = reader.next_event()?;
+        assert!(matches!(event.item(), ExpandedStreamItem::VersionMarker(_)));
+        assert!(!event.is_ephemeral());
+
+        let event = reader.next_event()?;
+        assert!(matches!(
+            event.item(),
+            ExpandedStreamItem::EncodingDirective(_)
+        ));
+        // The directive's changes have not been applied yet.
+        assert!(event.macro_table().macro_with_name("twice").is_none());
+
+        // The e-expression is surfaced as-is, along with the context that defines its macro.
+        let event = reader.next_event()?;
+        let ExpandedStreamItem::EExp(eexp) = event.item() else {
+            panic!("expected an e-expression, found {:?}", event.item());
+        };
+        assert_eq!(eexp.invoked_macro.name(), Some("twice"));
+        assert!(!event.is_ephemeral());
+        assert!(event.raw_item().is_some());
+        assert!(event.symbol_table().sid_for("foo").is_some());
+        let after_directive = event.context_snapshot();
+
+        // Its expansion follows, marked as ephemeral.
+        for _ in 0..2 {
+            let event = reader.next_event()?;
+            assert!(event.is_ephemeral());
+            assert!(event.raw_item().is_none());
+            let ExpandedStreamItem::Value(value) = event.item() else {
+                panic!("expected a value, found {:?}", event.item());
+            };
+            assert_eq!(value.read()?.expect_symbol()?, "foo");
+        }
+
+        let event = reader.next_event()?;
+        assert!(!event.is_ephemeral());
+        let ExpandedStreamItem::Value(value) = event.item() else {
+            panic!("expected a value, found {:?}", event.item());
+        };
+        assert_eq!(value.read()?.expect_symbol()?, "bar");
+        assert!(matches!(
+            reader.next_event()?.item(),
+            ExpandedStreamItem::EndOfStream(_)
+        ));
+
+        // The event's snapshot can be used to roll the reader back to the context it was read with.
+        reader.restore_context(&after_directive)?;
+        assert!(reader.macro_table().macro_with_name("twice").is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn events_for_ion_1_0() -> IonResult<()> {
+        let ion = r#"
+            $ion_symbol_table::{symbols: ["foo"]}
+            foo
+        "#;
+        let mut reader = SystemReader::new(AnyEncoding, ion);
+        let event = reader.next_event()?;
+        assert!(matches!(event.item(), ExpandedStreamItem::SymbolTable(_)));
+        assert!(event.symbol_table().sid_for("foo").is_none());
+
+        let event = reader.next_event()?;
+        assert!(event.symbol_table().sid_for("foo").is_some());
+        let ExpandedStreamItem::Value(value) = event.item() else {
+            panic!("expected a value, found {:?}", event.item());
+        };
+        assert_eq!(value.read()?.expect_symbol()?, "foo");
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    fn catalog_with_shared_module() -> IonResult<MapCatalog> {
+        let mut map_catalog = MapCatalog::new();
+        map_catalog.insert_table(SharedSymbolTable::new("shared_module", 1, ["foo", "bar"])?);
+        let mut macro_table = MacroTable::empty();
+        macro_table.add_template_macro(TemplateCompiler::compile_from_source(
+            &ION_1_1_SYSTEM_MACROS,
+            r#"(macro greet (x) (.make_string "Hello, " (%x)))"#,
+        )?)?;
+        map_catalog.insert_macro_table("shared_module", 1, macro_table);
+        Ok(map_catalog)
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn use_appends_shared_module_to_default_module() -> IonResult<()> {
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+                $ion_1_1
+                $ion::
+                (module _
+                    (symbol_table ["local_symbol"])
+                    (macro_table (macro seventeen () 17)))
+                // The system macros are no longer in the default module.
+                (:$ion::use "shared_module" 1)
+                $1 // local_symbol
+                $2 // foo
+                $3 // bar
+                (:seventeen)
+                (:greet "Waldo")
+            "#,
+            catalog_with_shared_module()?,
+        );
+        assert_eq!(
+            reader.expect_next_value()?.read()?.expect_symbol()?,
+            "local_symbol"
+        );
+        assert_eq!(reader.expect_next_value()?.read()?.expect_symbol()?, "foo");
+        assert_eq!(reader.expect_next_value()?.read()?.expect_symbol()?, "bar");
+        assert_eq!(reader.expect_next_value()?.read()?.expect_i64()?, 17);
+        assert_eq!(
+            reader.expect_next_value()?.read()?.expect_string()?,
+            "Hello, Waldo"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn use_defaults_to_version_one() -> IonResult<()> {
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+                $ion_1_1
+                (:use shared_module)
+                (:greet "Waldo")
+            "#,
+            catalog_with_shared_module()?,
+        );
+        assert_eq!(
+            reader.expect_next_value()?.read()?.expect_string()?,
+            "Hello, Waldo"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn use_requires_module_in_catalog() -> IonResult<()> {
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+                $ion_1_1
+                (:use "shared_module" 2)
+            "#,
+            catalog_with_shared_module()?,
+        );
+        assert!(reader.expect_next_value().is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn imported_modules_are_referenced_by_name() -> IonResult<()> {
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+                $ion_1_1
+                $ion::(import shared "shared_module" 1)
+                (:shared::greet "Waldo")
+                (:shared::0 "Quux")
+                // Importing a module does not change the default module.
+                (:values 1)
+            "#,
+            catalog_with_shared_module()?,
+        );
+        assert_eq!(
+            reader.expect_next_value()?.read()?.expect_string()?,
+            "Hello, Waldo"
+        );
+        assert_eq!(
+            reader.expect_next_value()?.read()?.expect_string()?,
+            "Hello, Quux"
+        );
+        assert_eq!(reader.expect_next_value()?.read()?.expect_i64()?, 1);
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn named_modules_can_be_defined_and_referenced() -> IonResult<()> {
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+                $ion_1_1
+                $ion::(module m (macro_table (macro seventeen () 17)))
+                (:m::seventeen)
+                $ion::
+                (module _
+                    (macro_table _ (macro thirty_four () (.$ion::sum (.m::seventeen) (.m::0)))))
+                (:thirty_four)
+                (:m::seventeen)
+            "#,
+            MapCatalog::new(),
+        );
+        assert_eq!(reader.expect_next_value()?.read()?.expect_i64()?, 17);
+        assert_eq!(reader.expect_next_value()?.read()?.expect_i64()?, 34);
+        assert_eq!(reader.expect_next_value()?.read()?.expect_i64()?, 17);
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn binding_a_module_name_again_shadows_the_earlier_module() -> IonResult<()> {
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+                $ion_1_1
+                $ion::(module m (macro_table (macro foo () 1)))
+                (:m::foo)
+                $ion::(module m (macro_table (macro foo () 2)))
+                (:m::foo)
+            "#,
+            MapCatalog::new(),
+        );
+        assert_eq!(reader.expect_next_value()?.read()?.expect_i64()?, 1);
+        assert_eq!(reader.expect_next_value()?.read()?.expect_i64()?, 2);
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn default_module_can_include_named_module_tables() -> IonResult<()> {
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+                $ion_1_1
+                $ion::(module m (symbol_table ["foo", "bar"]) (macro_table (macro baz () 3)))
+                $ion::(module _ (symbol_table m) (macro_table m))
+                $1
+                $2
+                (:baz)
+            "#,
+            MapCatalog::new(),
+        );
+        assert_eq!(reader.expect_next_value()?.read()?.expect_symbol()?, "foo");
+        assert_eq!(reader.expect_next_value()?.read()?.expect_symbol()?, "bar");
+        assert_eq!(reader.expect_next_value()?.read()?.expect_i64()?, 3);
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn references_to_unbound_modules_are_errors() -> IonResult<()> {
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+                $ion_1_1
+                (:m::foo)
+            "#,
+            MapCatalog::new(),
+        );
+        assert!(reader.expect_next_value().is_err());
+
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+                $ion_1_1
+                $ion::(module _ (macro_table m))
+            "#,
+            MapCatalog::new(),
+        );
+        assert!(reader.expect_next_value().is_err());
+
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+                $ion_1_1
+                $ion::(module m (macro_table (macro foo () 1)))
+                (:m::bar)
+            "#,
+            MapCatalog::new(),
+        );
+        assert!(reader.expect_next_value().is_err());
+        Ok(())
+    }
 }
@@ -1,11 +1,12 @@
 use std::fmt::{Debug, Formatter};
 
 use crate::lazy::decoder::{Decoder, RawVersionMarker};
+use crate::lazy::expanded::{EncodingContextRef, EncodingContextSnapshot};
 use crate::lazy::r#struct::LazyStruct;
 use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem, RawStreamItem};
 use crate::lazy::value::LazyValue;
 use crate::result::IonFailure;
-use crate::{ExpandedStreamItem, IonError, IonResult, LazySExp};
+use crate::{ExpandedStreamItem, IonError, IonResult, LazySExp, MacroTable, SymbolTable};
 
 /// System stream elements that a SystemReader may encounter.
 #[non_exhaustive]
@@ -145,3 +146,69 @@ impl<D: Decoder> Debug for SystemStreamItem<'_, D> {
         }
     }
 }
+
+/// A top-level item read by a [`SystemReader`](crate::lazy::system_reader::SystemReader) that
+/// leaves nothing out of the stream, paired with the encoding context used to read it.
+///
+/// Unlike a [`SystemStreamItem`], an event can be an e-expression. Each e-expression is surfaced
+/// before the items that it expands to, which are marked as [ephemeral](Self::is_ephemeral).
+/// Tools that only want to see the stream as it was encoded can skip ephemeral events; the reader
+/// still evaluates the e-expressions so that any changes they make to the encoding context are
+/// applied.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "experimental-tooling-apis", visibility::make(pub))]
+pub(crate) struct SystemStreamEvent<'top, D: Decoder> {
+    item: ExpandedStreamItem<'top, D>,
+    context: EncodingContextRef<'top>,
+    is_ephemeral: bool,
+}
+
+#[cfg_attr(not(feature = "experimental-tooling-apis"), allow(dead_code))]
+impl<'top, D: Decoder> SystemStreamEvent<'top, D> {
+    pub(crate) fn new(
+        item: ExpandedStreamItem<'top, D>,
+        context: EncodingContextRef<'top>,
+        is_ephemeral: bool,
+    ) -> Self {
+        Self {
+            item,
+            context,
+            is_ephemeral,
+        }
+    }
+
+    pub fn item(&self) -> ExpandedStreamItem<'top, D> {
+        self.item
+    }
+
+    /// Returns `true` if this event's item was produced by evaluating an e-expression rather than
+    /// being read from the input. This includes values that were passed to the e-expression as
+    /// arguments.
+    pub fn is_ephemeral(&self) -> bool {
+        self.is_ephemeral
+    }
+
+    /// If this event's item was read from the input, returns the raw stream item backing it.
+    pub fn raw_item(&self) -> Option<LazyRawStreamItem<'top, D>> {
+        if self.is_ephemeral {
+            return None;
+        }
+        self.item.raw_item()
+    }
+
+    /// The symbol table that was in effect when this item was read.
+    pub fn symbol_table(&self) -> &'top SymbolTable {
+        self.context.symbol_table()
+    }
+
+    /// The macro table that was in effect when this item was read.
+    pub fn macro_table(&self) -> &'top MacroTable {
+        self.context.macro_table()
+    }
+
+    /// Returns a snapshot of the encoding context that was in effect when this item was read.
+    /// See [`EncodingContext::snapshot`](crate::lazy::expanded::EncodingContext::snapshot).
+    pub fn context_snapshot(&self) -> EncodingContextSnapshot {
+        self.context.context.snapshot()
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{MacroTable, SymbolTable};
+
+/// Counters describing how a [`Reader`](crate::Reader)'s symbol and macro tables have grown over
+/// the course of a stream.
+///
+/// A producer that defines a new symbol for every value it writes (for example, by using IDs or
+/// timestamps as field names) forces its readers to hold an ever-larger symbol table in memory.
+/// These counters, together with
+/// [`Reader::set_table_growth_callback`](crate::Reader::set_table_growth_callback), allow such
+/// streams to be detected.
+///
+/// The counts only include symbols and macros defined by the stream, not the system symbols and
+/// macros that each table begins with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TableGrowth {
+    // The sizes of the tables after the most recent IVM, which are not counted.
+    num_system_symbols: usize,
+    num_system_macros: usize,
+    num_symbols: usize,
+    peak_num_symbols: usize,
+    num_symbols_defined: u64,
+    num_macros: usize,
+    peak_num_macros: usize,
+    num_macros_defined: u64,
+}
+
+impl TableGrowth {
+    /// Returns the number of symbols in the current symbol table.
+    pub fn num_symbols(&self) -> usize {
+        self.num_symbols
+    }
+
+    /// Returns the largest number of symbols that the symbol table has contained.
+    pub fn peak_num_symbols(&self) -> usize {
+        self.peak_num_symbols
+    }
+
+    /// Returns the total number of symbols that the stream has defined, including those in
+    /// symbol tables that have since been replaced.
+    pub fn num_symbols_defined(&self) -> u64 {
+        self.num_symbols_defined
+    }
+
+    /// Returns the number of macros in the current macro table.
+    pub fn num_macros(&self) -> usize {
+        self.num_macros
+    }
+
+    /// Returns the largest number of macros that the macro table has contained.
+    pub fn peak_num_macros(&self) -> usize {
+        self.peak_num_macros
+    }
+
+    /// Returns the total number of macros that the stream has defined, including those in macro
+    /// tables that have since been replaced.
+    pub fn num_macros_defined(&self) -> u64 {
+        self.num_macros_defined
+    }
+
+    /// Constructs counters for a reader whose tables initially contain only system symbols and
+    /// macros.
+    pub(crate) fn new(symbol_table: &SymbolTable, macro_table: &MacroTable) -> Self {
+        Self {
+            num_system_symbols: symbol_table.len(),
+            num_system_macros: macro_table.len(),
+            num_symbols: 0,
+            peak_num_symbols: 0,
+            num_symbols_defined: 0,
+            num_macros: 0,
+            peak_num_macros: 0,
+            num_macros_defined: 0,
+        }
+    }
+
+    /// Updates the counters after an IVM has reset the tables to their system contents.
+    pub(crate) fn record_reset(&mut self, symbol_table: &SymbolTable, macro_table: &MacroTable) {
+        self.num_system_symbols = symbol_table.len();
+        self.num_system_macros = macro_table.len();
+        self.record_change(symbol_table, macro_table, 0, 0);
+    }
+
+    /// Updates the counters after a change to the encoding context that defined `num_symbols` new
+    /// symbols and `num_macros` new macros.
+    pub(crate) fn record_change(
+        &mut self,
+        symbol_table: &SymbolTable,
+        macro_table: &MacroTable,
+        num_symbols: usize,
+        num_macros: usize,
+    ) {
+        *self = self.with_current_sizes(symbol_table, macro_table);
+        self.peak_num_symbols = self.peak_num_symbols.max(self.num_symbols);
+        self.peak_num_macros = self.peak_num_macros.max(self.num_macros);
+        self.num_symbols_defined += num_symbols as u64;
+        self.num_macros_defined += num_macros as u64;
+    }
+
+    /// Returns a copy of these counters with the current table sizes taken from the provided
+    /// tables.
+    pub(crate) fn with_current_sizes(
+        mut self,
+        symbol_table: &SymbolTable,
+        macro_table: &MacroTable,
+    ) -> Self {
+        self.num_symbols = symbol_table.len().saturating_sub(self.num_system_symbols);
+        self.num_macros = macro_table.len().saturating_sub(self.num_system_macros);
+        self
+    }
+}
+
+/// Passed to the callback registered with
+/// [`Reader::set_table_growth_callback`](crate::Reader::set_table_growth_callback) when one of
+/// the reader's tables grows past its threshold.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TableGrowthWarning {
+    /// The symbol table contains more than `threshold` symbols.
+    Symbols {
+        num_symbols: usize,
+        threshold: usize,
+    },
+    /// The macro table contains more than `threshold` macros.
+    Macros { num_macros: usize, threshold: usize },
+}
+
+/// Compares a reader's [`TableGrowth`] to a pair of thresholds, calling a user-provided callback
+/// each time a table grows past its threshold.
+pub(crate) struct TableGrowthMonitor {
+    callback: Box<dyn FnMut(TableGrowthWarning)>,
+    max_symbols: usize,
+    max_macros: usize,
+    // Whether each table was over its threshold when it was last checked. A table only causes
+    // another warning after it has been replaced by one that is within its threshold.
+    symbols_exceeded: bool,
+    macros_exceeded: bool,
+}
+
+impl TableGrowthMonitor {
+    pub(crate) fn new(
+        max_symbols: usize,
+        max_macros: usize,
+        callback: impl FnMut(TableGrowthWarning) + 'static,
+    ) -> Self {
+        Self {
+            callback: Box::new(callback),
+            max_symbols,
+            max_macros,
+            symbols_exceeded: false,
+            macros_exceeded: false,
+        }
+    }
+
+    pub(crate) fn check(&mut self, growth: &TableGrowth) {
+        let symbols_exceeded = growth.num_symbols() > self.max_symbols;
+        if symbols_exceeded && !self.symbols_exceeded {
+            (self.callback)(TableGrowthWarning::Symbols {
+                num_symbols: growth.num_symbols(),
+                threshold: self.max_symbols,
+            });
+        }
+        self.symbols_exceeded = symbols_exceeded;
+
+        let macros_exceeded = growth.num_macros() > self.max_macros;
+        if macros_exceeded && !self.macros_exceeded {
+            (self.callback)(TableGrowthWarning::Macros {
+                num_macros: growth.num_macros(),
+                threshold: self.max_macros,
+            });
+        }
+        self.macros_exceeded = macros_exceeded;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AnyEncoding, IonResult, Reader};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn reader_counts_symbols() -> IonResult<()> {
+        let data = r#"
+            $ion_symbol_table::{symbols: ["a", "b", "c"]}
+            1
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["d"]}
+            2
+            $ion_1_0
+            $ion_symbol_table::{symbols: ["e", "f"]}
+            3
+        "#;
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        let mut sizes = Vec::new();
+        while reader.next()?.is_some() {
+            sizes.push(reader.table_growth().num_symbols());
+        }
+        assert_eq!(sizes, vec![3, 4, 2]);
+        let growth = reader.table_growth();
+        assert_eq!(growth.peak_num_symbols(), 4);
+        assert_eq!(growth.num_symbols_defined(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn reader_warns_when_tables_grow() -> IonResult<()> {
+        let mut data = String::new();
+        for segment in 0..2 {
+            data.push_str("$ion_1_0 ");
+            for i in 0..5 {
+                data.push_str(&format!(
+                    "$ion_symbol_table::{{imports: $ion_symbol_table, symbols: [\"s{segment}_{i}\"]}} {i} "
+                ));
+            }
+        }
+        let warnings = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&warnings);
+        let mut reader = Reader::new(AnyEncoding, data.as_str())?;
+        reader.set_table_growth_callback(3, usize::MAX, move |warning| {
+            sink.borrow_mut().push(warning)
+        });
+        while reader.next()?.is_some() {}
+        // The warning is repeated once the table has been reset and grows past the threshold again.
+        let expected = TableGrowthWarning::Symbols {
+            num_symbols: 4,
+            threshold: 3,
+        };
+        assert_eq!(*warnings.borrow(), vec![expected, expected]);
+        assert_eq!(reader.table_growth().num_symbols_defined(), 10);
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn reader_counts_macros() -> IonResult<()> {
+        use crate::{v1_1, SequenceWriter, Writer};
+
+        let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+        let one = writer.compile_macro("(macro one () 1)")?;
+        writer.eexp_writer(&one)?.close()?;
+        writer.write_symbol("hello")?;
+        let data = writer.close()?;
+
+        let mut reader = Reader::new(AnyEncoding, data.as_slice())?;
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 1);
+        assert_eq!(reader.expect_next()?.read()?.expect_symbol()?, "hello");
+        let growth = reader.table_growth();
+        assert_eq!(growth.num_macros(), 1);
+        assert_eq!(growth.num_macros_defined(), 1);
+        assert_eq!(growth.num_symbols(), 1);
+        assert_eq!(growth.num_symbols_defined(), 1);
+        Ok(())
+    }
+}
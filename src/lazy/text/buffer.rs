@@ -27,15 +27,14 @@ use crate::lazy::text::matched::{
 use crate::lazy::text::parse_result::IonParseError;
 use crate::lazy::text::parse_result::{IonMatchResult, IonParseResult};
 use crate::lazy::text::raw::v1_1::arg_group::{EExpArg, EExpArgExpr, TextEExpArgGroup};
-use crate::lazy::text::raw::v1_1::reader::{
-    MacroIdLike, MacroIdRef, SystemMacroAddress, TextEExpression_1_1,
-};
+use crate::lazy::text::raw::v1_1::reader::{MacroIdRef, SystemMacroAddress, TextEExpression_1_1};
 use crate::lazy::text::value::{
     LazyRawTextValue, LazyRawTextValue_1_0, LazyRawTextValue_1_1, LazyRawTextVersionMarker,
 };
 use crate::result::DecodingError;
 use crate::{
-    Encoding, HasRange, IonError, IonResult, IonType, RawSymbolRef, Span, TimestampPrecision,
+    Encoding, HasRange, IonError, IonResult, IonType, LeapSecondHandling, RawSymbolRef, Span,
+    TimestampPrecision,
 };
 
 use crate::lazy::expanded::macro_table::ION_1_1_SYSTEM_MACROS;
@@ -645,16 +644,41 @@ impl<'top> TextBuffer<'top> {
                 };
                 MacroIdRef::SystemAddress(system_address)
             }
-            MacroIdRef::SystemAddress(_) => {
-                unreachable!("`match_e_expression_address` always returns a LocalAddress")
-            }
+            _ => unreachable!("`match_e_expression_address` always returns a LocalAddress"),
         };
         Ok(system_id)
     }
 
+    /// Matches a macro ID that is qualified with the name of a module other than `$ion`, like
+    /// `my_module::my_macro` or `my_module::3`.
+    pub fn match_qualified_eexp_id(&mut self) -> IonParseResult<'top, MacroIdRef<'top>> {
+        let (module_id, _delimiter) = (
+            Self::match_e_expression_name,
+            (whitespace_and_then("::"), Self::match_whitespace0),
+        )
+            .parse_next(self)?;
+        let MacroIdRef::LocalName(module_name) = module_id else {
+            unreachable!("`match_e_expression_name` always returns a LocalName")
+        };
+
+        let id = alt((
+            Self::match_e_expression_address,
+            Self::match_e_expression_name,
+        ))
+        .parse_next(self)?;
+
+        let qualified_id = match id {
+            MacroIdRef::LocalName(name) => MacroIdRef::QualifiedName(module_name, name),
+            MacroIdRef::LocalAddress(address) => MacroIdRef::QualifiedAddress(module_name, address),
+            _ => unreachable!("matched an unqualified name or address"),
+        };
+        Ok(qualified_id)
+    }
+
     pub fn match_e_expression_id(&mut self) -> IonParseResult<'top, MacroIdRef<'top>> {
         let id = alt((
             Self::match_system_eexp_id,
+            Self::match_qualified_eexp_id,
             Self::match_e_expression_name,
             Self::match_e_expression_address,
         ))
@@ -670,10 +694,21 @@ impl<'top> TextBuffer<'top> {
         let original_input = *self;
         let parser = |input: &mut TextBuffer<'top>| {
             let _opening_tag = "(:".parse_next(input)?;
+            let context = input.context;
+            let Some(_depth_guard) = context
+                .limits
+                .enter_text_container(&context.text_container_depth)
+            else {
+                let message = context.limits.depth_exceeded_message();
+                return (*input)
+                    .invalid(message)
+                    .context("reading an e-expression")
+                    .cut();
+            };
             let id = Self::match_e_expression_id(input)?;
             let mut arg_expr_cache = BumpVec::new_in(input.context.allocator());
 
-            let macro_ref = id.resolve(input.context().macro_table()).map_err(|_| {
+            let macro_ref = input.context().resolve_macro_id(id).map_err(|_| {
                 (*input)
                     .invalid(format!("could not find macro with id {id:?}"))
                     .context("reading an e-expression")
@@ -1103,7 +1138,7 @@ impl<'top> TextBuffer<'top> {
     }
 
     /// Matches an Ion float of any syntax
-    fn match_float(&mut self) -> IonParseResult<'top, MatchedFloat> {
+    pub fn match_float(&mut self) -> IonParseResult<'top, MatchedFloat> {
         terminated(
             alt((Self::match_float_special, Self::match_float_numeric_value)),
             Self::peek_stop_character,
@@ -1629,31 +1664,52 @@ impl<'top> TextBuffer<'top> {
     /// Matches a timestamp of any precision.
     #[inline]
     pub fn match_timestamp(&mut self) -> IonParseResult<'top, MatchedTimestamp> {
-        #[inline(never)]
-        pub fn full_match_timestamp<'t>(
-            input: &mut TextBuffer<'t>,
-        ) -> IonParseResult<'t, MatchedTimestamp> {
-            // TODO: As-is, matching common timestamps (those with greater than second precision)
-            //       is slow because the parser tries each shorter arrangement in turn. We should
-            //       rewrite this to use a single path that can accept any precision.
-            alt((
-                TextBuffer::match_timestamp_y,
-                TextBuffer::match_timestamp_ym,
-                TextBuffer::match_timestamp_ymd,
-                TextBuffer::match_timestamp_ymd_hm,
-                TextBuffer::match_timestamp_ymd_hms,
-                TextBuffer::match_timestamp_ymd_hms_fractional,
-            ))
-            .parse_next(input)
-        }
-
         match self.bytes().first() {
-            Some(byte) if byte.is_ascii_digit() => full_match_timestamp(self),
+            Some(byte) if byte.is_ascii_digit() => {
+                if self.context.defer_timestamp_parsing {
+                    self.match_timestamp_boundary()
+                } else {
+                    self.match_timestamp_syntax()
+                }
+            }
             Some(_) => self.unrecognized().backtrack(),
             None => self.incomplete("a timestamp"),
         }
     }
 
+    /// Matches a timestamp, validating each of its components.
+    #[inline(never)]
+    pub(crate) fn match_timestamp_syntax(&mut self) -> IonParseResult<'top, MatchedTimestamp> {
+        // TODO: As-is, matching common timestamps (those with greater than second precision)
+        //       is slow because the parser tries each shorter arrangement in turn. We should
+        //       rewrite this to use a single path that can accept any precision.
+        alt((
+            TextBuffer::match_timestamp_y,
+            TextBuffer::match_timestamp_ym,
+            TextBuffer::match_timestamp_ymd,
+            TextBuffer::match_timestamp_ymd_hm,
+            TextBuffer::match_timestamp_ymd_hms,
+            TextBuffer::match_timestamp_ymd_hms_fractional,
+        ))
+        .parse_next(self)
+    }
+
+    /// Matches the extent of a timestamp without validating its components. The value is
+    /// identified by its four-digit year followed by a `-` or `T`, which no other Ion type can
+    /// begin with. Validation is performed by [`MatchedTimestamp::read`].
+    fn match_timestamp_boundary(&mut self) -> IonParseResult<'top, MatchedTimestamp> {
+        terminated(
+            (
+                Self::match_timestamp_year,
+                one_of(b"-T"),
+                take_while(0.., |b: u8| b.is_ascii_digit() || b"-:.TZ+".contains(&b)),
+            ),
+            Self::peek_stop_character,
+        )
+        .map(|_| MatchedTimestamp::deferred())
+        .parse_next(self)
+    }
+
     /// Matches a timestamp with year precision.
     fn match_timestamp_y(&mut self) -> IonParseResult<'top, MatchedTimestamp> {
         terminated(Self::match_timestamp_year, ("T", Self::peek_stop_character))
@@ -1796,7 +1852,16 @@ impl<'top> TextBuffer<'top> {
 
     /// Matches a leading `:`, and any two-digit second component from `00` to `59` inclusive.
     fn match_timestamp_seconds(&mut self) -> IonMatchResult<'top> {
-        preceded(":", (one_of(b"012345"), Self::match_any_digit).take()).parse_next(self)
+        let allow_leap_second = self.context.leap_second_handling != LeapSecondHandling::Reject;
+        preceded(
+            ":",
+            alt((
+                (one_of(b"012345"), Self::match_any_digit).take(),
+                "60".take()
+                    .verify(move |_: &TextBuffer<'top>| allow_leap_second),
+            )),
+        )
+        .parse_next(self)
     }
 
     /// Matches the fractional seconds component of a timestamp, including a leading `.`.
@@ -19,7 +19,6 @@
 //! use the previously recorded information to minimize the amount of information that needs to be
 //! re-discovered.
 
-use std::num::IntErrorKind;
 use std::ops::{Neg, Range};
 use std::str::FromStr;
 
@@ -31,6 +30,7 @@ use crate::lazy::str_ref::StrRef;
 use crate::lazy::text::as_utf8::AsUtf8;
 use crate::lazy::text::buffer::TextBuffer;
 use crate::result::{DecodingError, IonFailure};
+use crate::text::base64;
 use crate::{
     Decimal, Int, IonError, IonResult, IonType, RawSymbolRef, Timestamp, TimestampPrecision,
 };
@@ -150,9 +150,6 @@ pub struct MatchedInt {
 }
 
 impl MatchedInt {
-    // Integers that take more than 32 bytes to represent will heap allocate a larger buffer.
-    const STACK_ALLOC_BUFFER_CAPACITY: usize = 32;
-
     /// Constructs a new `MatchedInt`.
     pub fn new(radix: u8, is_negative: bool, digits_offset: usize) -> Self {
         debug_assert!(
@@ -180,35 +177,17 @@ impl MatchedInt {
     /// Attempts to finish reading the partially parsed integer.
     pub fn read(&self, matched_input: TextBuffer<'_>) -> IonResult<Int> {
         let digits = matched_input.slice_to_end(self.digits_offset as usize);
-        let mut sanitized: SmallVec<[u8; Self::STACK_ALLOC_BUFFER_CAPACITY]> =
-            SmallVec::with_capacity(Self::STACK_ALLOC_BUFFER_CAPACITY);
-        // Copy the input text over to the sanitization buffer, discarding any underscores. These
-        // are legal input, but Rust's integer `from_str_radix` method does not support them.
-        sanitized.extend(digits.bytes().iter().copied().filter(|b| *b != b'_'));
-        // Note: This UTF-8 validation step should be unnecessary as the parser only recognizes
-        //       ASCII integer characters. If this shows up in profiling, we could consider skipping it.
-        let text = sanitized.as_utf8(matched_input.offset())?;
-        let int: Int = match i128::from_str_radix(text, self.radix()) {
-            Ok(i) => i.into(),
-            Err(parse_int_error) => {
-                debug_assert!(
-                    // `from_str_radix` can fail for a variety of reasons, but our rules for matching an
-                    // int rule out most of them (empty str, invalid digit, etc). The only ones that should
-                    // happen are overflow and underflow. In those cases, we fall back to using `BigInt`.
-                    parse_int_error.kind() == &IntErrorKind::NegOverflow
-                        || parse_int_error.kind() == &IntErrorKind::PosOverflow
-                );
-                return cold_path!(IonResult::decoding_error(format!(
-                    "encountered an int whose value was exceeded the supported range: '{}'",
-                    std::str::from_utf8(matched_input.bytes()).unwrap_or("invalid UTF-8")
-                )));
-            }
-        };
-
-        if self.is_negative {
-            Ok(-int)
-        } else {
-            Ok(int)
+        // The matcher has already confirmed that the input is made up of digits in `self.radix`
+        // and underscores, so the magnitude can be accumulated directly from the input bytes
+        // without first copying them into a sanitized buffer.
+        let int = parse_magnitude(digits.bytes(), self.radix())
+            .and_then(|magnitude| apply_sign(magnitude, self.is_negative));
+        match int {
+            Some(int) => Ok(int.into()),
+            None => cold_path!(IonResult::decoding_error(format!(
+                "encountered an int whose value was exceeded the supported range: '{}'",
+                std::str::from_utf8(matched_input.bytes()).unwrap_or("invalid UTF-8")
+            ))),
         }
     }
 }
@@ -238,11 +217,21 @@ impl MatchedFloat {
             MatchedFloat::Numeric => {} // fall through
         };
 
-        let mut sanitized: SmallVec<[u8; Self::STACK_ALLOC_BUFFER_CAPACITY]> =
-            SmallVec::with_capacity(Self::STACK_ALLOC_BUFFER_CAPACITY);
-        sanitized.extend(matched_input.bytes().iter().copied().filter(|b| *b != b'_'));
+        if let Some(float) = read_float_fast_path(matched_input.bytes()) {
+            return Ok(float);
+        }
 
-        let text = sanitized.as_utf8(matched_input.offset())?;
+        // Rust's `f64::from_str` does not accept underscores, so we only pay for a sanitizing copy
+        // of the input when one is actually present.
+        let mut sanitized: SmallVec<[u8; Self::STACK_ALLOC_BUFFER_CAPACITY]> = SmallVec::new();
+        let bytes = if memchr::memchr(b'_', matched_input.bytes()).is_some() {
+            sanitized.extend(matched_input.bytes().iter().copied().filter(|b| *b != b'_'));
+            sanitized.as_slice()
+        } else {
+            matched_input.bytes()
+        };
+
+        let text = bytes.as_utf8(matched_input.offset())?;
         let float = f64::from_str(text).map_err(|e| {
             matched_input
                 .invalid(format!("encountered an unexpected error ({e:?})"))
@@ -252,6 +241,127 @@ impl MatchedFloat {
     }
 }
 
+/// Powers of ten that can be represented exactly as an `f64`.
+const EXACT_F64_POWERS_OF_TEN: [f64; 23] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+];
+
+/// Attempts to convert the text of a numeric Ion float (e.g. `-1_024.5e-3`) to an `f64` without
+/// going through the general-purpose float parser.
+///
+/// When the significand fits in the 53 bits of an `f64`'s mantissa and the decimal exponent has
+/// an exact `f64` representation, a single multiplication or division yields the correctly rounded
+/// result (Clinger's fast path). Most floats found in real data meet these criteria. Any input
+/// that does not causes this function to return `None`; the caller is expected to fall back to
+/// `f64::from_str`.
+fn read_float_fast_path(bytes: &[u8]) -> Option<f64> {
+    const MAX_EXACT_SIGNIFICAND: u64 = 1 << f64::MANTISSA_DIGITS;
+    let (is_negative, mut remaining) = match bytes.split_first() {
+        Some((b'-', rest)) => (true, rest),
+        _ => (false, bytes),
+    };
+
+    let mut significand: u64 = 0;
+    let mut num_fractional_digits: i64 = 0;
+    let mut seen_decimal_point = false;
+    loop {
+        let (&byte, rest) = remaining.split_first()?;
+        match byte {
+            b'0'..=b'9' => {
+                significand = significand
+                    .checked_mul(10)?
+                    .checked_add((byte - b'0') as u64)?;
+                num_fractional_digits += seen_decimal_point as i64;
+            }
+            b'.' => seen_decimal_point = true,
+            b'_' => {}
+            b'e' | b'E' => {
+                remaining = rest;
+                break;
+            }
+            _ => return None,
+        }
+        remaining = rest;
+    }
+    if significand > MAX_EXACT_SIGNIFICAND {
+        return None;
+    }
+
+    let (exponent_is_negative, exponent_digits) = match remaining.split_first() {
+        Some((b'-', rest)) => (true, rest),
+        Some((b'+', rest)) => (false, rest),
+        _ => (false, remaining),
+    };
+    // Exponents outside of the fast path's range will be rejected below; bounding the number of
+    // digits here guarantees that accumulating the exponent cannot overflow.
+    if exponent_digits.is_empty() || exponent_digits.len() > 4 {
+        return None;
+    }
+    let mut exponent: i64 = 0;
+    for &byte in exponent_digits {
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+        exponent = exponent * 10 + (byte - b'0') as i64;
+    }
+    if exponent_is_negative {
+        exponent = -exponent;
+    }
+    exponent -= num_fractional_digits;
+
+    let magnitude = significand as f64;
+    let value = match exponent {
+        _ if significand == 0 => 0f64,
+        0..=22 => magnitude * EXACT_F64_POWERS_OF_TEN[exponent as usize],
+        -22..=-1 => magnitude / EXACT_F64_POWERS_OF_TEN[-exponent as usize],
+        _ => return None,
+    };
+    Some(if is_negative { -value } else { value })
+}
+
+/// Accumulates the magnitude of the digits in `bytes`, interpreting them in the specified `radix`.
+/// Bytes that are not digits in that radix (underscores, a decimal point) are skipped; callers
+/// are expected to have already validated the input's syntax.
+///
+/// Digits are accumulated in a `u64` for as long as the magnitude fits, which covers the vast
+/// majority of numbers. If it overflows, accumulation continues in a `u128`. Returns `None` if
+/// the magnitude is too large to be represented as a `u128`.
+fn parse_magnitude(bytes: &[u8], radix: u32) -> Option<u128> {
+    let digit_values = bytes
+        .iter()
+        .filter_map(|byte| (*byte as char).to_digit(radix));
+    let mut digit_values = digit_values.peekable();
+    let mut magnitude: u64 = 0;
+    while let Some(&digit) = digit_values.peek() {
+        match magnitude
+            .checked_mul(radix as u64)
+            .and_then(|m| m.checked_add(digit as u64))
+        {
+            Some(m) => magnitude = m,
+            None => break,
+        }
+        digit_values.next();
+    }
+    let mut magnitude = magnitude as u128;
+    for digit in digit_values {
+        magnitude = magnitude
+            .checked_mul(radix as u128)?
+            .checked_add(digit as u128)?;
+    }
+    Some(magnitude)
+}
+
+/// Converts an unsigned magnitude to an `i128` with the requested sign, returning `None` if the
+/// result would be out of range.
+fn apply_sign(magnitude: u128, is_negative: bool) -> Option<i128> {
+    if is_negative {
+        0i128.checked_sub_unsigned(magnitude)
+    } else {
+        i128::try_from(magnitude).ok()
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct MatchedDecimal {
     is_negative: bool,
@@ -264,9 +374,6 @@ pub struct MatchedDecimal {
 }
 
 impl MatchedDecimal {
-    // Decimals that take more than 32 bytes of text to represent will heap allocate a larger buffer.
-    const STACK_ALLOC_BUFFER_CAPACITY: usize = 32;
-
     pub fn new(
         is_negative: bool,
         digits_offset: u16,
@@ -288,25 +395,15 @@ impl MatchedDecimal {
     }
 
     pub fn read(&self, matched_input: TextBuffer<'_>) -> IonResult<Decimal> {
-        let mut sanitized: SmallVec<[u8; Self::STACK_ALLOC_BUFFER_CAPACITY]> =
-            SmallVec::with_capacity(Self::STACK_ALLOC_BUFFER_CAPACITY);
-
         let digits = matched_input.slice(self.digits_offset as usize, self.digits_length as usize);
 
-        // Copy all of the digits (but not the decimal point or underscores) over to the buffer.
-        sanitized.extend(
-            digits
-                .bytes()
-                .iter()
-                .copied()
-                .filter(|b| b.is_ascii_digit()),
-        );
-
-        let digits_text = sanitized.as_utf8(digits.offset())?;
-        let magnitude: Int = i128::from_str(digits_text)
-            .map_err(|e| {
+        // Accumulate the digits of the coefficient, skipping the decimal point and underscores.
+        let magnitude: Int = parse_magnitude(digits.bytes(), 10)
+            .and_then(|magnitude| apply_sign(magnitude, false))
+            .ok_or_else(|| {
                 IonError::decoding_error(format!(
-                    "decimal magnitude '{digits_text}' was larger than supported size ({e:?}"
+                    "decimal magnitude '{}' was larger than supported size",
+                    std::str::from_utf8(digits.bytes()).unwrap_or("invalid UTF-8")
                 ))
             })?
             .into();
@@ -324,31 +421,25 @@ impl MatchedDecimal {
         let mut exponent: i64 = match self.exponent_digits_length {
             0 => 0,
             _ => {
-                sanitized.clear();
                 let exponent_digits = matched_input.slice(
                     self.exponent_digits_offset as usize,
                     self.exponent_digits_length as usize,
                 );
-                // Copy all of the digits over to the buffer.
-                sanitized.extend(
-                    exponent_digits
-                        .bytes()
-                        .iter()
-                        .copied()
-                        .filter(|b| b.is_ascii_digit()),
-                );
-                let exponent_text = sanitized
-                    .as_utf8(matched_input.offset() + self.exponent_digits_offset as usize)?;
-                let exponent_magnitude = i64::from_str(exponent_text).map_err(|e| {
-                    IonError::decoding_error(format!(
-                        "failed to parse decimal exponent '{exponent_text}': {e:?}"
-                    ))
-                })?;
-                if self.exponent_is_negative {
-                    -exponent_magnitude
-                } else {
-                    exponent_magnitude
-                }
+                parse_magnitude(exponent_digits.bytes(), 10)
+                    .and_then(|magnitude| i64::try_from(magnitude).ok())
+                    .map(|magnitude| {
+                        if self.exponent_is_negative {
+                            -magnitude
+                        } else {
+                            magnitude
+                        }
+                    })
+                    .ok_or_else(|| {
+                        IonError::decoding_error(format!(
+                            "failed to parse decimal exponent '{}': out of range",
+                            std::str::from_utf8(exponent_digits.bytes()).unwrap_or("invalid UTF-8")
+                        ))
+                    })?
             }
         };
 
@@ -429,7 +520,9 @@ impl MatchedString {
             // Support unicode escapes
             true,
         )?;
-        let text = std::str::from_utf8(sanitized.into_bump_slice()).unwrap();
+        let text = sanitized
+            .into_bump_slice()
+            .as_utf8(matched_input.offset())?;
         Ok(StrRef::from(text))
     }
 
@@ -463,7 +556,9 @@ impl MatchedString {
                 true,
             )?;
         }
-        let text = std::str::from_utf8(sanitized.into_bump_slice()).unwrap();
+        let text = sanitized
+            .into_bump_slice()
+            .as_utf8(matched_input.offset())?;
         Ok(StrRef::from(text))
     }
 
@@ -497,7 +592,9 @@ impl MatchedString {
             // Support Unicode escapes
             true,
         )?;
-        let text = std::str::from_utf8(sanitized.into_bump_slice()).unwrap();
+        let text = sanitized
+            .into_bump_slice()
+            .as_utf8(matched_input.offset())?;
         Ok(StrRef::from(text))
     }
 }
@@ -714,6 +811,14 @@ fn decode_hex_digits_escape<'data>(
     // (Note: this will only ever be true for 4- and 8-digit escape sequences. `\x` escapes don't
     // have enough digits to represent a high surrogate.)
     if code_point_is_a_high_surrogate(code_point) {
+        if input.context.strict_utf8 {
+            return Err(IonError::Decoding(
+                DecodingError::new(format!(
+                    "found an escape sequence for surrogate code point U+{code_point:04X}, which is not allowed when strict UTF-8 decoding is enabled"
+                ))
+                .with_position(input.offset()),
+            ));
+        }
         // The spec has MAY-style language around supporting high surrogates. Supporting them is
         // allowed but discouraged. The ion-tests spec conformance tests include cases with UTF-16
         // surrogates, so ion-rust supports them.
@@ -722,8 +827,15 @@ fn decode_hex_digits_escape<'data>(
 
     // A Rust `char` can represent any Unicode scalar value--a code point that is not part of a
     // surrogate pair. If the value we found isn't a high surrogate, then it's a complete scalar
-    // value. We can safely convert it to a `char`.
-    let character = char::from_u32(code_point).unwrap();
+    // value unless it is beyond the end of the Unicode code space (e.g. `\U00110000`).
+    let Some(character) = char::from_u32(code_point) else {
+        return Err(IonError::Decoding(
+            DecodingError::new(format!(
+                "found an escape sequence for U+{code_point:X}, which is not a Unicode code point"
+            ))
+            .with_position(input.offset()),
+        ));
+    };
     let utf8_buffer: &mut [u8; 4] = &mut [0; 4];
     let utf8_encoded = character.encode_utf8(utf8_buffer);
     sanitized.extend_from_slice(utf8_encoded.as_bytes());
@@ -845,9 +957,7 @@ impl MatchedSymbol {
         // Take a slice of the input that ignores the first and last bytes, which are quotes.
         let body = matched_input.slice(1, matched_input.len() - 2);
         // There are no escaped characters, so we can just validate the string in-place.
-        let text = body
-            .as_text()
-            .expect("successfully lexed symbol later found to be invalid UTF-8");
+        let text = body.as_text()?;
         let str_ref = RawSymbolRef::Text(text);
         Ok(str_ref)
     }
@@ -864,7 +974,9 @@ impl MatchedSymbol {
         // that replaces the escaped characters with their corresponding bytes.
         let mut sanitized = BumpVec::with_capacity_in(matched_input.len(), allocator);
         replace_escapes_with_byte_values(body, &mut sanitized, false, true)?;
-        let text = std::str::from_utf8(sanitized.into_bump_slice()).unwrap();
+        let text = sanitized
+            .into_bump_slice()
+            .as_utf8(matched_input.offset())?;
         Ok(RawSymbolRef::Text(text))
     }
 
@@ -894,6 +1006,9 @@ impl MatchedSymbol {
 pub struct MatchedTimestamp {
     precision: TimestampPrecision,
     offset: MatchedTimestampOffset,
+    // If `true`, only the extent of the timestamp was matched; its components will be
+    // validated when it is read.
+    is_deferred: bool,
 }
 
 impl MatchedTimestamp {
@@ -901,6 +1016,15 @@ impl MatchedTimestamp {
         Self {
             precision,
             offset: MatchedTimestampOffset::Unknown,
+            is_deferred: false,
+        }
+    }
+
+    /// Constructs a `MatchedTimestamp` whose precision and offset have not yet been determined.
+    pub(crate) fn deferred() -> Self {
+        Self {
+            is_deferred: true,
+            ..Self::new(TimestampPrecision::Year)
         }
     }
 }
@@ -912,12 +1036,16 @@ impl MatchedTimestamp {
     }
 
     pub(crate) fn read(&self, matched_input: TextBuffer<'_>) -> IonResult<Timestamp> {
+        if self.is_deferred {
+            return Self::read_deferred(matched_input);
+        }
         // The parser has already confirmed that each subfield is made of ASCII digits,
         // so UTF-8 validation and parsing cannot fail. `unwrap()` is used in such cases
         // throughout.
         let year_text = matched_input.slice(0, 4).as_text().unwrap();
         let year = u32::from_str(year_text).unwrap();
-        let timestamp = Timestamp::with_year(year);
+        let timestamp = Timestamp::with_year(year)
+            .with_leap_second_handling(matched_input.context.leap_second_handling);
 
         if self.precision == TimestampPrecision::Year {
             return timestamp.build();
@@ -1040,6 +1168,21 @@ impl MatchedTimestamp {
             timestamp.build()
         }
     }
+
+    /// Validates the components of a timestamp whose matching was deferred and then reads it.
+    #[inline(never)]
+    fn read_deferred(matched_input: TextBuffer<'_>) -> IonResult<Timestamp> {
+        let mut remaining = matched_input;
+        match remaining.match_timestamp_syntax() {
+            Ok(matched) if remaining.is_empty() => matched.read(matched_input),
+            _ => {
+                let error = matched_input
+                    .invalid("found an invalid timestamp")
+                    .context("reading a timestamp");
+                Err(error.into())
+            }
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -1078,7 +1221,7 @@ impl MatchedBlob {
         // has inner whitespace, we need to strip it out.
         let contains_whitespace = matched_bytes.iter().any(|b| b.is_ascii_whitespace());
 
-        let max_decoded_size = matched_bytes.len().div_ceil(4) * 3;
+        let max_decoded_size = base64::max_decoded_size(matched_bytes.len());
         let mut decoding_buffer = BumpVec::with_capacity_in(max_decoded_size, allocator);
 
         decoding_buffer.resize(max_decoded_size, 0u8);
@@ -1092,24 +1235,19 @@ impl MatchedBlob {
                 .copied()
                 .filter(|b| !b.is_ascii_whitespace());
             sanitized_base64_text.extend(non_whitespaces_bytes);
-            base64::decode_config_slice(
+            base64::decode_to_slice(
                 sanitized_base64_text.as_slice(),
-                base64::STANDARD,
                 decoding_buffer.as_mut_slice(),
             )
         } else {
-            base64::decode_config_slice(
-                matched_bytes,
-                base64::STANDARD,
-                decoding_buffer.as_mut_slice(),
-            )
+            base64::decode_to_slice(matched_bytes, decoding_buffer.as_mut_slice())
         };
 
         let decoded_size = match decode_result {
             Ok(size) => size,
             Err(e) => {
                 return IonResult::decoding_error(format!(
-                    "failed to parse blob with invalid base64 data:\n'{:?}'\n{e:?}:",
+                    "failed to parse blob with invalid base64 data:\n'{:?}'\n{e}:",
                     matched_input.bytes()
                 ))
             }
@@ -1248,6 +1386,19 @@ mod tests {
                 "-1234567890_1234567890_1234567890",
                 Int::from(-1234567890_1234567890_1234567890i128),
             ),
+            ("0xFF_ff", Int::from(0xFFFF)),
+            ("-0b1010", Int::from(-10)),
+            ("18446744073709551615", Int::from(u64::MAX as i128)),
+            ("18446744073709551616", Int::from(u64::MAX as i128 + 1)),
+            ("0x1_0000_0000_0000_0000", Int::from(1i128 << 64)),
+            (
+                "170141183460469231731687303715884105727",
+                Int::from(i128::MAX),
+            ),
+            (
+                "-170141183460469231731687303715884105728",
+                Int::from(i128::MIN),
+            ),
         ];
 
         for (input, expected) in tests {
@@ -1256,6 +1407,68 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn read_out_of_range_ints() {
+        for input in [
+            "170141183460469231731687303715884105728",
+            "-170141183460469231731687303715884105729",
+            "0x1_0000_0000_0000_0000_0000_0000_0000_0000",
+        ] {
+            let encoding_context = EncodingContext::empty();
+            let context = encoding_context.get_ref();
+            let mut buffer = TextBuffer::new(context, input.as_bytes());
+            let matched = peek(TextBuffer::match_int).parse_next(&mut buffer).unwrap();
+            assert!(matched.read(buffer).is_err(), "input: '{input}'");
+        }
+    }
+
+    #[test]
+    fn read_floats() -> IonResult<()> {
+        fn expect_float(data: &str, expected: f64) {
+            let encoding_context = EncodingContext::empty();
+            let context = encoding_context.get_ref();
+            let mut buffer = TextBuffer::new(context, data.as_bytes());
+            let matched = peek(TextBuffer::match_float)
+                .parse_next(&mut buffer)
+                .unwrap();
+            let actual = matched.read(buffer).unwrap();
+            assert_eq!(
+                actual.to_bits(),
+                expected.to_bits(),
+                "Actual didn't match expected for input '{data}'.\n{actual:?}\n!=\n{expected:?}",
+            );
+        }
+
+        let tests = [
+            ("0e0", 0f64),
+            ("-0e0", -0f64),
+            ("0e400", 0f64),
+            ("1e0", 1f64),
+            ("-1.5e0", -1.5f64),
+            ("1_024.25e-2", 10.2425f64),
+            ("0.1e0", 0.1f64),
+            ("3.141592653589793e0", std::f64::consts::PI),
+            ("1E22", 1e22f64),
+            // Inputs beyond the reach of the fast path fall back to the general parser
+            ("1e23", 1e23f64),
+            ("1e-300", 1e-300f64),
+            ("1.7976931348623157e308", f64::MAX),
+            ("9007199254740993e0", 9007199254740993f64),
+            ("12345678901234567890123e0", 12345678901234567890123f64),
+            (
+                "1_2345_6789_0123_4567_8901_23e-5",
+                123456789012345678.90123f64,
+            ),
+            ("+inf", f64::INFINITY),
+            ("-inf", f64::NEG_INFINITY),
+        ];
+
+        for (input, expected) in tests {
+            expect_float(input, expected);
+        }
+        Ok(())
+    }
+
     #[test]
     fn read_timestamps() -> IonResult<()> {
         fn expect_timestamp(data: &str, expected: Timestamp) {
@@ -1425,6 +1638,16 @@ mod tests {
             ("-0.005d+2", Decimal::new(-5, -1)),
             ("0.005D-2", Decimal::new(5, -5)),
             ("-0.005D-2", Decimal::new(-5, -5)),
+            ("1_000.5", Decimal::new(10005, -1)),
+            // Coefficients that overflow a u64 are promoted to a wider accumulator
+            (
+                "12345678901234567890.123",
+                Decimal::new(12345678901234567890123i128, -3),
+            ),
+            (
+                "-12345678901234567890.123d10",
+                Decimal::new(-12345678901234567890123i128, 7),
+            ),
         ];
 
         for (input, expected) in tests {
@@ -102,6 +102,10 @@ impl<'data, E: TextEncoding> RawTextListIterator<'data, E> {
             spooky: PhantomData,
         }
     }
+    /// Returns the input that follows the last child value the iterator has returned.
+    pub(crate) fn remaining_input(&self) -> TextBuffer<'data> {
+        self.input
+    }
 }
 
 impl<'data, E: TextEncoding> Iterator for RawTextListIterator<'data, E> {
@@ -174,6 +178,10 @@ impl<'top, E: TextEncoding> RawTextSExpIterator<'top, E> {
             spooky: PhantomData,
         }
     }
+    /// Returns the input that follows the last child value the iterator has returned.
+    pub(crate) fn remaining_input(&self) -> TextBuffer<'top> {
+        self.input
+    }
 }
 
 impl<'data, E: TextEncoding> Iterator for RawTextSExpIterator<'data, E> {
@@ -1,5 +1,6 @@
 #![allow(non_camel_case_types)]
 
+use crate::constants::v1_1::constants::DEFAULT_MODULE_NAME;
 use crate::lazy::any_encoding::IonEncoding;
 use crate::lazy::decoder::private::LazyContainerPrivate;
 use crate::lazy::decoder::{
@@ -170,9 +171,9 @@ pub(crate) mod system_macros {
 pub enum ModuleKind {
     Default,
     System,
-    // TODO: For the moment, this can only be `Default` or `System`.
-    //       We need to add support for user-defined modules,
-    //       possibly with a `UserDefined(CompactString)` variant.
+    /// A module that was bound to a name in the reader's encoding context. Only macros that the
+    /// reader resolves (see `EncodingContext::resolve_macro_id`) can belong to a named module.
+    Named,
 }
 
 /// A `(module, address)` pair referring to a location in the encoding context where a macro resides.
@@ -215,11 +216,10 @@ pub trait MacroIdLike<'a>: Sized + Copy {
     }
 
     fn resolve<'b: 'a>(&self, macro_table: &'b MacroTable) -> IonResult<MacroRef<'b>> {
-        use MacroIdRef::*;
         let id = self.as_macro_id_ref();
 
         let qualified_address = match id {
-            LocalName(name) => {
+            MacroIdRef::LocalName(name) => {
                 let address = macro_table.address_for_id(id).ok_or_else(|| {
                     IonError::illegal_operation(format!(
                         "macro table does not contain a macro named '{name}'"
@@ -227,13 +227,33 @@ pub trait MacroIdLike<'a>: Sized + Copy {
                 })?;
                 QualifiedAddress::new(ModuleKind::Default, address)
             }
-            LocalAddress(address) => QualifiedAddress::new(ModuleKind::Default, address),
-            SystemAddress(address) => QualifiedAddress::new(ModuleKind::System, address.as_usize()),
+            MacroIdRef::LocalAddress(address) => {
+                QualifiedAddress::new(ModuleKind::Default, address)
+            }
+            MacroIdRef::SystemAddress(address) => {
+                QualifiedAddress::new(ModuleKind::System, address.as_usize())
+            }
+            // A macro table can only resolve qualified IDs that refer to the system module or the
+            // default module. See `EncodingContext::resolve_macro_id` for IDs in named modules.
+            MacroIdRef::QualifiedName(module_name, name) => {
+                let (module, macro_table) = qualified_module(module_name, macro_table)?;
+                let address = macro_table.address_for_name(name).ok_or_else(|| {
+                    IonError::illegal_operation(format!(
+                        "module '{module_name}' does not contain a macro named '{name}'"
+                    ))
+                })?;
+                QualifiedAddress::new(module, address)
+            }
+            MacroIdRef::QualifiedAddress(module_name, address) => {
+                let (module, _) = qualified_module(module_name, macro_table)?;
+                QualifiedAddress::new(module, address)
+            }
         };
 
         let macro_table: &MacroTable = match qualified_address.module() {
             ModuleKind::Default => macro_table,
             ModuleKind::System => &ION_1_1_SYSTEM_MACROS,
+            ModuleKind::Named => unreachable!("named modules are rejected above"),
         };
 
         let macro_def = macro_table
@@ -246,6 +266,22 @@ pub trait MacroIdLike<'a>: Sized + Copy {
     }
 }
 
+/// Maps the module name in a qualified macro ID to the corresponding module and macro table.
+/// Only the system module (`$ion`) and the default module (`_`) can be found without an
+/// encoding context.
+fn qualified_module<'b>(
+    module_name: &str,
+    macro_table: &'b MacroTable,
+) -> IonResult<(ModuleKind, &'b MacroTable)> {
+    match module_name {
+        "$ion" => Ok((ModuleKind::System, &ION_1_1_SYSTEM_MACROS)),
+        DEFAULT_MODULE_NAME => Ok((ModuleKind::Default, macro_table)),
+        _ => IonResult::illegal_operation(format!(
+            "cannot resolve a macro in module '{module_name}'; it is not bound"
+        )),
+    }
+}
+
 impl<'a> MacroIdLike<'a> for &'a Macro {
     fn as_macro_id_ref(&self) -> MacroIdRef<'a> {
         self.prefer_name()
@@ -292,6 +328,9 @@ impl<'a> MacroIdLike<'a> for QualifiedAddress {
             ModuleKind::System => {
                 MacroIdRef::SystemAddress(SystemMacroAddress::new_unchecked(self.address()))
             }
+            ModuleKind::Named => {
+                unreachable!("writers only invoke macros in the system and default modules")
+            }
         }
     }
 
@@ -299,6 +338,7 @@ impl<'a> MacroIdLike<'a> for QualifiedAddress {
         let macro_def = match self.module() {
             ModuleKind::Default => macro_table.macro_at_address(self.address()),
             ModuleKind::System => ION_1_1_SYSTEM_MACROS.macro_at_address(self.address()),
+            ModuleKind::Named => None,
         }
         .ok_or_else(|| {
             IonError::encoding_error(format!("could not find macro with ID {self:?}"))
@@ -312,15 +352,39 @@ pub enum MacroIdRef<'data> {
     LocalName(&'data str),
     LocalAddress(usize),
     SystemAddress(SystemMacroAddress),
-    // TODO: Addresses and qualified names
+    /// A `(module name, macro name)` pair, like `my_module::my_macro`.
+    QualifiedName(&'data str, &'data str),
+    /// A `(module name, macro address)` pair, like `my_module::3`.
+    QualifiedAddress(&'data str, usize),
 }
 
-impl MacroIdRef<'_> {
+impl<'data> MacroIdRef<'data> {
+    /// If this ID is qualified, returns the module name and the unqualified ID of the macro within
+    /// that module. Otherwise, returns `None`.
+    pub(crate) fn split_module(self) -> Option<(&'data str, MacroIdRef<'data>)> {
+        match self {
+            MacroIdRef::QualifiedName(module_name, name) => {
+                Some((module_name, MacroIdRef::LocalName(name)))
+            }
+            MacroIdRef::QualifiedAddress(module_name, address) => {
+                Some((module_name, MacroIdRef::LocalAddress(address)))
+            }
+            _ => None,
+        }
+    }
+
     pub fn to_owned(self) -> MacroId {
         match &self {
             MacroIdRef::LocalName(name) => MacroId::LocalName(CompactString::from(*name)),
             MacroIdRef::LocalAddress(address) => MacroId::LocalAddress(*address),
             MacroIdRef::SystemAddress(address) => MacroId::SystemAddress(*address),
+            MacroIdRef::QualifiedName(module_name, name) => MacroId::QualifiedName(
+                CompactString::from(*module_name),
+                CompactString::from(*name),
+            ),
+            MacroIdRef::QualifiedAddress(module_name, address) => {
+                MacroId::QualifiedAddress(CompactString::from(*module_name), *address)
+            }
         }
     }
 }
@@ -333,6 +397,10 @@ impl Display for MacroIdRef<'_> {
             MacroIdRef::SystemAddress(address) => {
                 write!(f, "$ion::{}", address.as_usize())
             }
+            MacroIdRef::QualifiedName(module_name, name) => write!(f, "{module_name}::{name}"),
+            MacroIdRef::QualifiedAddress(module_name, address) => {
+                write!(f, "{module_name}::{address}")
+            }
         }
     }
 }
@@ -360,7 +428,8 @@ pub enum MacroId {
     LocalName(CompactString),
     LocalAddress(usize),
     SystemAddress(SystemMacroAddress),
-    // TODO: Qualified names and addresses
+    QualifiedName(CompactString, CompactString),
+    QualifiedAddress(CompactString, usize),
 }
 
 impl MacroId {
@@ -369,6 +438,12 @@ impl MacroId {
             MacroId::LocalName(name) => MacroIdRef::LocalName(name.as_str()),
             MacroId::LocalAddress(address) => MacroIdRef::LocalAddress(*address),
             MacroId::SystemAddress(address) => MacroIdRef::SystemAddress(*address),
+            MacroId::QualifiedName(module_name, name) => {
+                MacroIdRef::QualifiedName(module_name.as_str(), name.as_str())
+            }
+            MacroId::QualifiedAddress(module_name, address) => {
+                MacroIdRef::QualifiedAddress(module_name.as_str(), *address)
+            }
         }
     }
 }
@@ -580,7 +655,6 @@ impl<'top, E: TextEncoding> LazyRawStruct<'top, E> for LazyRawTextStruct<'top, E
 #[cfg(test)]
 mod tests {
     use crate::lazy::any_encoding::IonVersion;
-    use crate::lazy::expanded::compiler::TemplateCompiler;
     use crate::lazy::expanded::EncodingContext;
     use crate::lazy::raw_value_ref::RawValueRef;
     use crate::{IonType, RawVersionMarker};
@@ -616,10 +690,9 @@ mod tests {
             false
        "#;
 
-        let mut context = EncodingContext::for_ion_version(IonVersion::v1_1);
-        let macro_quux =
-            TemplateCompiler::compile_from_source(context.macro_table(), "(macro quux (x) null)")?;
-        context.macro_table_mut().add_template_macro(macro_quux)?;
+        let context = EncodingContext::builder(IonVersion::v1_1)
+            .with_macro_source("(macro quux (x) null)")
+            .build()?;
         let reader = &mut LazyRawTextReader_1_1::new(context.get_ref(), data.as_bytes(), true);
 
         // $ion_1_1
@@ -0,0 +1,277 @@
+use rustc_hash::FxHashMap;
+
+use crate::element::builders::StructBuilder;
+use crate::lazy::decoder::Decoder;
+use crate::lazy::reader::Reader;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::{Annotations, Element, IntoAnnotatedElement, IonResult, Sequence, Symbol, Value};
+
+/// A set of changes to be made to each value read from a stream: dropping fields, renaming
+/// fields, and renaming or dropping annotations.
+///
+/// Each change applies at every depth. Field and annotation names are matched against their text;
+/// names without known text are left as they are. The values of dropped fields are skipped
+/// without being read; in binary Ion, this means that their bodies are stepped over using their
+/// length prefixes.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{Element, Reader, Transform};
+/// use ion_rs::v1_0::Text;
+///
+/// let transform = Transform::new()
+///     .drop_field("internal_id")
+///     .rename_field("usr", "user")
+///     .rename_annotation("evt_v1", "event");
+/// let mut reader = Reader::new(Text, r#"evt_v1::{internal_id: 17, usr: "alice"}"#)?;
+/// assert_eq!(
+///     reader.next_transformed(&transform)?,
+///     Some(Element::read_one(r#"event::{user: "alice"}"#)?)
+/// );
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Transform {
+    // Maps each affected field name to its new name, or to `None` if the field is dropped.
+    fields: FxHashMap<String, Option<String>>,
+    // Maps each affected annotation to its new text, or to `None` if the annotation is dropped.
+    annotations: FxHashMap<String, Option<String>>,
+}
+
+impl Transform {
+    /// Constructs a `Transform` that leaves values unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes struct fields named `name`.
+    pub fn drop_field(mut self, name: impl Into<String>) -> Self {
+        self.fields.insert(name.into(), None);
+        self
+    }
+
+    /// Changes the name of struct fields named `from` to `to`.
+    pub fn rename_field(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.fields.insert(from.into(), Some(to.into()));
+        self
+    }
+
+    /// Removes the annotation `name` wherever it appears.
+    pub fn drop_annotation(mut self, name: impl Into<String>) -> Self {
+        self.annotations.insert(name.into(), None);
+        self
+    }
+
+    /// Replaces the annotation `from` with `to` wherever it appears.
+    pub fn rename_annotation(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.annotations.insert(from.into(), Some(to.into()));
+        self
+    }
+
+    /// Produces an [`Element`] containing `value` with this transform's changes applied.
+    pub fn apply<D: Decoder>(&self, value: LazyValue<'_, D>) -> IonResult<Element> {
+        let annotations = self.transform_annotations(&value)?;
+        let transformed = match value.read()? {
+            ValueRef::List(list) => {
+                let children = list
+                    .iter()
+                    .map(|child| self.apply(child?))
+                    .collect::<IonResult<Vec<_>>>()?;
+                Value::List(Sequence::from(children))
+            }
+            ValueRef::SExp(sexp) => {
+                let children = sexp
+                    .iter()
+                    .map(|child| self.apply(child?))
+                    .collect::<IonResult<Vec<_>>>()?;
+                Value::SExp(Sequence::from(children))
+            }
+            ValueRef::Struct(strukt) => {
+                let mut builder = StructBuilder::new();
+                for field in &strukt {
+                    let field = field?;
+                    let name = field.name()?;
+                    let name = match name.text().and_then(|text| self.fields.get(text)) {
+                        // This field is dropped; its value will not be read.
+                        Some(None) => continue,
+                        Some(Some(new_name)) => Symbol::from(new_name.as_str()),
+                        None => name.to_owned(),
+                    };
+                    builder = builder.with_field(name, self.apply(field.value())?);
+                }
+                Value::Struct(builder.build())
+            }
+            _ => Element::try_from(value)?.into_value(),
+        };
+        Ok(transformed.with_annotations(annotations))
+    }
+
+    fn transform_annotations<D: Decoder>(
+        &self,
+        value: &LazyValue<'_, D>,
+    ) -> IonResult<Annotations> {
+        let mut annotations = Vec::new();
+        for annotation in value.annotations() {
+            let annotation = annotation?;
+            match annotation
+                .text()
+                .and_then(|text| self.annotations.get(text))
+            {
+                Some(None) => {}
+                Some(Some(new_text)) => annotations.push(Symbol::from(new_text.as_str())),
+                None => annotations.push(annotation.to_owned()),
+            }
+        }
+        Ok(annotations.into())
+    }
+}
+
+/// A reader that applies a [`Transform`] to each top-level value as it is read, allowing
+/// downstream code to work with the transformed data without knowing how the stream is written.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{Element, Reader, Sequence, Transform, TransformingReader};
+/// use ion_rs::v1_0::Text;
+///
+/// let reader = Reader::new(Text, "{a: 1, b: 2} {a: 3, c: tmp::4}")?;
+/// let transform = Transform::new().drop_field("b").drop_annotation("tmp");
+/// let transformed: Sequence = TransformingReader::new(reader, transform)
+///     .collect::<Result<Vec<_>, _>>()?
+///     .into();
+/// assert_eq!(transformed, Element::read_all("{a: 1} {a: 3, c: 4}")?);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub struct TransformingReader<D: Decoder, Input: IonInput> {
+    reader: Reader<D, Input>,
+    transform: Transform,
+}
+
+impl<D: Decoder, Input: IonInput> TransformingReader<D, Input> {
+    pub fn new(reader: Reader<D, Input>, transform: Transform) -> Self {
+        Self { reader, transform }
+    }
+
+    /// Returns the next top-level value with the transform applied, or `Ok(None)` at the end of
+    /// the stream.
+    #[allow(clippy::should_implement_trait)]
+    // ^-- This returns an `IonResult<Option<_>>` like `Reader::next`; the `Iterator` impl wraps it.
+    pub fn next(&mut self) -> IonResult<Option<Element>> {
+        self.reader.next_transformed(&self.transform)
+    }
+
+    pub fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    /// Returns the underlying reader, which will resume reading after the last value that was
+    /// transformed.
+    pub fn into_inner(self) -> Reader<D, Input> {
+        self.reader
+    }
+}
+
+impl<D: Decoder, Input: IonInput> Iterator for TransformingReader<D, Input> {
+    type Item = IonResult<Element>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1_0;
+
+    const DATA: &str = r#"
+        old::{id: 1, secret: {nested: [1, 2]}, usr: "a", tags: [old::x, y]}
+        {items: [{secret: 2, usr: "b"}], details: (keep::1 old::2 tmp::3)}
+        tmp::old::"scalar"
+        [{usr: null}, null.struct]
+    "#;
+
+    const EXPECTED: &str = r#"
+        new::{id: 1, user: "a", tags: [new::x, y]}
+        {items: [{user: "b"}], details: (keep::1 new::2 3)}
+        new::"scalar"
+        [{user: null}, null.struct]
+    "#;
+
+    fn transform() -> Transform {
+        Transform::new()
+            .drop_field("secret")
+            .rename_field("usr", "user")
+            .rename_annotation("old", "new")
+            .drop_annotation("tmp")
+    }
+
+    fn read_transformed<D: Decoder>(reader: Reader<D, &[u8]>) -> IonResult<Sequence> {
+        TransformingReader::new(reader, transform())
+            .collect::<IonResult<Vec<_>>>()
+            .map(Sequence::from)
+    }
+
+    #[test]
+    fn transform_text() -> IonResult<()> {
+        let reader = Reader::new(v1_0::Text, DATA.as_bytes())?;
+        assert_eq!(read_transformed(reader)?, Element::read_all(EXPECTED)?);
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn transform_binary() -> IonResult<()> {
+        use crate::v1_1;
+
+        for binary_data in [
+            Element::read_all(DATA)?.encode_as(v1_0::Binary)?,
+            Element::read_all(DATA)?.encode_as(v1_1::Binary)?,
+        ] {
+            let reader = Reader::new(crate::AnyEncoding, binary_data.as_slice())?;
+            assert_eq!(read_transformed(reader)?, Element::read_all(EXPECTED)?);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn empty_transform_preserves_values() -> IonResult<()> {
+        use crate::lazy::encoder::writer::Writer;
+        use crate::{v1_1, ElementWriter};
+
+        let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+        writer.write_elements(&Element::read_all(DATA)?)?;
+        let data = writer.close()?;
+        let reader = Reader::new(v1_1::Binary, data.as_slice())?;
+        let transformed =
+            TransformingReader::new(reader, Transform::new()).collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(Sequence::from(transformed), Element::read_all(DATA)?);
+        Ok(())
+    }
+
+    #[test]
+    fn reader_resumes_after_transformed_values() -> IonResult<()> {
+        let reader = Reader::new(v1_0::Text, "{a: 1} {b: 2}")?;
+        let mut transforming = TransformingReader::new(reader, Transform::new().drop_field("a"));
+        assert_eq!(transforming.next()?, Some(Element::read_one("{}")?));
+        let mut reader = transforming.into_inner();
+        assert_eq!(
+            Element::try_from(reader.expect_next()?)?,
+            Element::read_one("{b: 2}")?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,622 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+
+use crate::ion_path::IonPath;
+use crate::lazy::decoder::Decoder;
+use crate::lazy::predicate::ComparisonOp;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+#[cfg(feature = "regex")]
+use crate::result::IonFailure;
+use crate::{Element, IonResult, IonType};
+
+/// A set of requirements that a value must satisfy, loosely modeled on the core constraints of
+/// [Ion Schema](https://amazon-ion.github.io/ion-schema/). A constraint can be checked against a
+/// [`LazyValue`] or an [`Element`]; every requirement that the value does not meet is reported as
+/// a [`Violation`].
+///
+/// A `TypeConstraint` can require that a value:
+/// * has one of a set of [`IonType`]s. Typed nulls like `null.int` have the corresponding type.
+/// * is not null, unless the constraint is [`nullable`](Self::nullable).
+/// * compares with one or more bounds in a particular way. Values are compared as they are by a
+///   [`Predicate`](crate::Predicate).
+/// * has text that matches a regular expression (requires the `regex` feature).
+/// * is a list or s-expression whose elements all satisfy a nested constraint.
+/// * is a struct with fields that satisfy nested constraints.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{ComparisonOp, IonType, Reader, TypeConstraint, ViolationKind};
+/// use ion_rs::v1_0::Text;
+///
+/// let order = TypeConstraint::of_type(IonType::Struct)
+///     .field("id", TypeConstraint::of_type(IonType::Int).compare(ComparisonOp::Gt, 0))
+///     .field(
+///         "items",
+///         TypeConstraint::of_type(IonType::List).elements(TypeConstraint::of_type(IonType::String)),
+///     )
+///     .optional_field("note", TypeConstraint::of_type(IonType::String).nullable(true));
+///
+/// let mut reader = Reader::new(Text, r#"{id: 1, items: ["a", "b"]} {id: 0, items: ["a", 7]}"#)?;
+/// assert!(order.validate(&reader.expect_next()?)?.is_empty());
+///
+/// let violations = order.validate(&reader.expect_next()?)?;
+/// assert_eq!(violations.len(), 2);
+/// assert_eq!(violations[0].path().to_string(), "id");
+/// assert_eq!(violations[1].path().to_string(), "items[1]");
+/// assert!(matches!(violations[1].kind(), ViolationKind::UnexpectedType { actual: IonType::Int, .. }));
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TypeConstraint {
+    // If empty, values of any type are accepted.
+    ion_types: Vec<IonType>,
+    nullable: bool,
+    bounds: Vec<(ComparisonOp, Element)>,
+    #[cfg(feature = "regex")]
+    pattern: Option<Regex>,
+    elements: Option<Box<TypeConstraint>>,
+    fields: Vec<FieldConstraint>,
+}
+
+#[derive(Clone, Debug)]
+struct FieldConstraint {
+    name: String,
+    constraint: TypeConstraint,
+    is_required: bool,
+}
+
+impl TypeConstraint {
+    /// Constructs a constraint that accepts any value, including nulls.
+    pub fn any() -> Self {
+        Self::one_of([]).nullable(true)
+    }
+
+    /// Constructs a constraint that accepts non-null values of type `ion_type`.
+    pub fn of_type(ion_type: IonType) -> Self {
+        Self::one_of([ion_type])
+    }
+
+    /// Constructs a constraint that accepts non-null values of any of the specified types. If
+    /// `ion_types` is empty, values of any type are accepted.
+    pub fn one_of(ion_types: impl IntoIterator<Item = IonType>) -> Self {
+        TypeConstraint {
+            ion_types: ion_types.into_iter().collect(),
+            nullable: false,
+            bounds: Vec::new(),
+            #[cfg(feature = "regex")]
+            pattern: None,
+            elements: None,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Sets whether null values of an accepted type satisfy this constraint. A null value is not
+    /// checked against any of the constraint's other requirements.
+    pub fn nullable(mut self, is_nullable: bool) -> Self {
+        self.nullable = is_nullable;
+        self
+    }
+
+    /// Requires that the value compare with `bound` using `op`. This can be called more than once
+    /// to require (for example) both a lower and an upper bound.
+    pub fn compare(mut self, op: ComparisonOp, bound: impl Into<Element>) -> Self {
+        self.bounds.push((op, bound.into()));
+        self
+    }
+
+    /// Requires that the value be greater than or equal to `min` and less than or equal to `max`.
+    pub fn in_range(self, min: impl Into<Element>, max: impl Into<Element>) -> Self {
+        self.compare(ComparisonOp::Ge, min)
+            .compare(ComparisonOp::Le, max)
+    }
+
+    /// Requires that the value be a string or symbol whose text contains a match for the regular
+    /// expression `pattern`. Use `^` and `$` to require that the entire text match. Returns an
+    /// error if `pattern` is not a valid regular expression.
+    #[cfg(feature = "regex")]
+    pub fn matching(mut self, pattern: &str) -> IonResult<Self> {
+        let regex = Regex::new(pattern).or_else(|e| {
+            IonResult::illegal_operation(format!("invalid regular expression '{pattern}': {e}"))
+        })?;
+        self.pattern = Some(regex);
+        Ok(self)
+    }
+
+    /// Requires that each element of a list or s-expression satisfy `constraint`.
+    pub fn elements(mut self, constraint: TypeConstraint) -> Self {
+        self.elements = Some(Box::new(constraint));
+        self
+    }
+
+    /// Requires that a struct have a field called `name` whose value satisfies `constraint`.
+    pub fn field(self, name: impl Into<String>, constraint: TypeConstraint) -> Self {
+        self.with_field(name.into(), constraint, true)
+    }
+
+    /// Requires that if a struct has a field called `name`, its value satisfies `constraint`.
+    pub fn optional_field(self, name: impl Into<String>, constraint: TypeConstraint) -> Self {
+        self.with_field(name.into(), constraint, false)
+    }
+
+    fn with_field(mut self, name: String, constraint: TypeConstraint, is_required: bool) -> Self {
+        self.fields.push(FieldConstraint {
+            name,
+            constraint,
+            is_required,
+        });
+        self
+    }
+
+    /// Checks `value` against this constraint, returning a [`Violation`] for each requirement that
+    /// it does not meet. Containers are navigated lazily and scalars are only read if the
+    /// constraint has a bound or pattern to check them against. Returns an `Err` if the data
+    /// cannot be read.
+    pub fn validate<D: Decoder>(&self, value: &LazyValue<'_, D>) -> IonResult<Vec<Violation>> {
+        let mut violations = Vec::new();
+        self.check(*value, &IonPath::new(), &mut violations)?;
+        Ok(violations)
+    }
+
+    /// Checks `element` against this constraint, returning a [`Violation`] for each requirement
+    /// that it does not meet. Because an `Element` has no encoding, none of the violations will
+    /// have an offset.
+    pub fn validate_element(&self, element: &Element) -> IonResult<Vec<Violation>> {
+        let mut violations = Vec::new();
+        self.check(element, &IonPath::new(), &mut violations)?;
+        Ok(violations)
+    }
+
+    fn check<'a, C: Candidate<'a>>(
+        &self,
+        value: C,
+        path: &IonPath,
+        violations: &mut Vec<Violation>,
+    ) -> IonResult<()> {
+        let mut report = |kind| {
+            violations.push(Violation {
+                path: path.clone(),
+                offset: value.offset(),
+                kind,
+            })
+        };
+        let ion_type = value.ion_type();
+        if !self.ion_types.is_empty() && !self.ion_types.contains(&ion_type) {
+            // The remaining requirements describe values of a different type; checking them would
+            // only produce more noise.
+            report(ViolationKind::UnexpectedType {
+                expected: self.ion_types.clone(),
+                actual: ion_type,
+            });
+            return Ok(());
+        }
+        if value.is_null() {
+            if !self.nullable {
+                report(ViolationKind::UnexpectedNull(ion_type));
+            }
+            return Ok(());
+        }
+
+        if self.has_scalar_requirements() {
+            let element = value.to_element()?;
+            for (op, bound) in &self.bounds {
+                if !op.evaluate(element.value(), bound.value()) {
+                    report(ViolationKind::OutOfRange {
+                        op: *op,
+                        bound: bound.clone(),
+                    });
+                }
+            }
+            #[cfg(feature = "regex")]
+            if let Some(pattern) = &self.pattern {
+                if !element.as_text().is_some_and(|text| pattern.is_match(text)) {
+                    report(ViolationKind::PatternMismatch(pattern.as_str().to_owned()));
+                }
+            }
+        }
+
+        if let Some(constraint) = &self.elements {
+            for (index, child) in value.child_values()?.into_iter().enumerate() {
+                constraint.check(child, &path.clone().index(index), violations)?;
+            }
+        }
+
+        if ion_type == IonType::Struct {
+            for field in &self.fields {
+                match value.field(&field.name)? {
+                    Some(child) => {
+                        let child_path = path.clone().field(field.name.as_str());
+                        field.constraint.check(child, &child_path, violations)?
+                    }
+                    None if field.is_required => violations.push(Violation {
+                        path: path.clone(),
+                        offset: value.offset(),
+                        kind: ViolationKind::MissingField(field.name.clone()),
+                    }),
+                    None => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn has_scalar_requirements(&self) -> bool {
+        #[cfg(feature = "regex")]
+        if self.pattern.is_some() {
+            return true;
+        }
+        !self.bounds.is_empty()
+    }
+}
+
+/// A value that a [`TypeConstraint`] can be checked against.
+trait Candidate<'a>: Copy {
+    fn ion_type(self) -> IonType;
+    fn is_null(self) -> bool;
+    /// The offset of the value's encoding in its stream, if it has one.
+    fn offset(self) -> Option<usize>;
+    fn to_element(self) -> IonResult<Cow<'a, Element>>;
+    /// The child values of a list or s-expression. Other values have no children.
+    fn child_values(self) -> IonResult<Vec<Self>>;
+    /// The value of the named field if this is a struct.
+    fn field(self, name: &str) -> IonResult<Option<Self>>;
+}
+
+impl<'top, D: Decoder> Candidate<'top> for LazyValue<'top, D> {
+    fn ion_type(self) -> IonType {
+        LazyValue::ion_type(&self)
+    }
+
+    fn is_null(self) -> bool {
+        LazyValue::is_null(&self)
+    }
+
+    fn offset(self) -> Option<usize> {
+        // Values produced by a macro have no encoding of their own.
+        self.raw_range().map(|range| range.start)
+    }
+
+    fn to_element(self) -> IonResult<Cow<'top, Element>> {
+        Element::try_from(self).map(Cow::Owned)
+    }
+
+    fn child_values(self) -> IonResult<Vec<Self>> {
+        match self.read()? {
+            ValueRef::List(list) => list.iter().collect(),
+            ValueRef::SExp(sexp) => sexp.iter().collect(),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    fn field(self, name: &str) -> IonResult<Option<Self>> {
+        match self.read()? {
+            ValueRef::Struct(strukt) => strukt.find(name),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl<'a> Candidate<'a> for &'a Element {
+    fn ion_type(self) -> IonType {
+        Element::ion_type(self)
+    }
+
+    fn is_null(self) -> bool {
+        Element::is_null(self)
+    }
+
+    fn offset(self) -> Option<usize> {
+        None
+    }
+
+    fn to_element(self) -> IonResult<Cow<'a, Element>> {
+        Ok(Cow::Borrowed(self))
+    }
+
+    fn child_values(self) -> IonResult<Vec<Self>> {
+        Ok(self
+            .as_sequence()
+            .map(|sequence| sequence.iter().collect())
+            .unwrap_or_default())
+    }
+
+    fn field(self, name: &str) -> IonResult<Option<Self>> {
+        Ok(self.as_struct().and_then(|strukt| strukt.get(name)))
+    }
+}
+
+/// A requirement of a [`TypeConstraint`] that a value did not meet.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+    path: IonPath,
+    offset: Option<usize>,
+    kind: ViolationKind,
+}
+
+impl Violation {
+    /// The path from the validated value to the value that violated the constraint. For a
+    /// [`ViolationKind::MissingField`], this is the path to the struct.
+    pub fn path(&self) -> &IonPath {
+        &self.path
+    }
+
+    /// The offset of the offending value's encoding in its stream, or `None` if the value was an
+    /// [`Element`] or was produced by a macro.
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    pub fn kind(&self) -> &ViolationKind {
+        &self.kind
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "value")?;
+        } else {
+            write!(f, "value at '{}'", self.path)?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " (offset {offset})")?;
+        }
+        write!(f, ": {}", self.kind)
+    }
+}
+
+/// The ways in which a value can fail to satisfy a [`TypeConstraint`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ViolationKind {
+    /// The value's type was not one of the expected types.
+    UnexpectedType {
+        expected: Vec<IonType>,
+        actual: IonType,
+    },
+    /// The value was a null of the given type, but the constraint is not nullable.
+    UnexpectedNull(IonType),
+    /// The value did not compare with `bound` using `op`.
+    OutOfRange { op: ComparisonOp, bound: Element },
+    /// The value was not text that matched the given regular expression.
+    PatternMismatch(String),
+    /// A struct did not have a required field with the given name.
+    MissingField(String),
+}
+
+impl Display for ViolationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ViolationKind::UnexpectedType { expected, actual } => {
+                write!(f, "expected ")?;
+                for (index, ion_type) in expected.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, " or ")?;
+                    }
+                    write!(f, "{ion_type}")?;
+                }
+                write!(f, " but found {actual}")
+            }
+            ViolationKind::UnexpectedNull(ion_type) => write!(f, "found null.{ion_type}"),
+            ViolationKind::OutOfRange { op, bound } => write!(f, "expected a value {op} {bound}"),
+            ViolationKind::PatternMismatch(pattern) => {
+                write!(f, "expected text matching /{pattern}/")
+            }
+            ViolationKind::MissingField(name) => write!(f, "missing required field '{name}'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::reader::Reader;
+    use crate::{ion_list, AnyEncoding, Element};
+
+    const DATA: &str = r#"
+        {
+            id: 7,
+            name: "widget",
+            price: 12.50,
+            tags: [red, "blue", null.symbol],
+            dims: {w: 3, h: 4},
+            note: null.string,
+        }
+    "#;
+
+    fn validate(constraint: &TypeConstraint) -> IonResult<Vec<Violation>> {
+        let mut reader = Reader::new(AnyEncoding, DATA)?;
+        let lazy_violations = constraint.validate(&reader.expect_next()?)?;
+        let element_violations = constraint.validate_element(&Element::read_one(DATA)?)?;
+        // The two kinds of value should produce the same violations, but only lazy values
+        // have offsets.
+        assert_eq!(lazy_violations.len(), element_violations.len());
+        for (lazy, element) in lazy_violations.iter().zip(&element_violations) {
+            assert_eq!(lazy.path(), element.path());
+            assert_eq!(lazy.kind(), element.kind());
+            assert!(lazy.offset().is_some());
+            assert_eq!(element.offset(), None);
+        }
+        Ok(lazy_violations)
+    }
+
+    fn kinds(violations: &[Violation]) -> Vec<(String, ViolationKind)> {
+        violations
+            .iter()
+            .map(|v| (v.path().to_string(), v.kind().clone()))
+            .collect()
+    }
+
+    #[test]
+    fn valid_value_has_no_violations() -> IonResult<()> {
+        let constraint = TypeConstraint::of_type(IonType::Struct)
+            .field("id", TypeConstraint::of_type(IonType::Int).in_range(1, 100))
+            .field(
+                "price",
+                TypeConstraint::one_of([IonType::Decimal, IonType::Float])
+                    .compare(ComparisonOp::Gt, 0),
+            )
+            .field(
+                "tags",
+                TypeConstraint::of_type(IonType::List).elements(
+                    TypeConstraint::one_of([IonType::Symbol, IonType::String]).nullable(true),
+                ),
+            )
+            .field("dims", TypeConstraint::any())
+            .optional_field(
+                "note",
+                TypeConstraint::of_type(IonType::String).nullable(true),
+            )
+            .optional_field("missing", TypeConstraint::of_type(IonType::Int));
+        assert_eq!(validate(&constraint)?, vec![]);
+        Ok(())
+    }
+
+    #[test]
+    fn reports_each_violation() -> IonResult<()> {
+        let constraint = TypeConstraint::of_type(IonType::Struct)
+            .field(
+                "id",
+                TypeConstraint::of_type(IonType::Int)
+                    .compare(ComparisonOp::Gt, 10)
+                    .compare(ComparisonOp::Ne, 7),
+            )
+            .field("name", TypeConstraint::of_type(IonType::Symbol))
+            .field(
+                "tags",
+                TypeConstraint::of_type(IonType::List)
+                    .elements(TypeConstraint::of_type(IonType::Symbol)),
+            )
+            .field(
+                "dims",
+                TypeConstraint::of_type(IonType::Struct)
+                    .field("d", TypeConstraint::any())
+                    .field("h", TypeConstraint::of_type(IonType::Int).in_range(0, 3)),
+            )
+            .field("note", TypeConstraint::of_type(IonType::String))
+            .field("missing", TypeConstraint::any());
+        let violations = validate(&constraint)?;
+        let expected = vec![
+            (
+                "id".to_string(),
+                ViolationKind::OutOfRange {
+                    op: ComparisonOp::Gt,
+                    bound: 10.into(),
+                },
+            ),
+            (
+                "id".to_string(),
+                ViolationKind::OutOfRange {
+                    op: ComparisonOp::Ne,
+                    bound: 7.into(),
+                },
+            ),
+            (
+                "name".to_string(),
+                ViolationKind::UnexpectedType {
+                    expected: vec![IonType::Symbol],
+                    actual: IonType::String,
+                },
+            ),
+            (
+                "tags[1]".to_string(),
+                ViolationKind::UnexpectedType {
+                    expected: vec![IonType::Symbol],
+                    actual: IonType::String,
+                },
+            ),
+            (
+                "tags[2]".to_string(),
+                ViolationKind::UnexpectedNull(IonType::Symbol),
+            ),
+            (
+                "dims".to_string(),
+                ViolationKind::MissingField("d".to_string()),
+            ),
+            (
+                "dims.h".to_string(),
+                ViolationKind::OutOfRange {
+                    op: ComparisonOp::Le,
+                    bound: 3.into(),
+                },
+            ),
+            (
+                "note".to_string(),
+                ViolationKind::UnexpectedNull(IonType::String),
+            ),
+            (
+                "".to_string(),
+                ViolationKind::MissingField("missing".to_string()),
+            ),
+        ];
+        assert_eq!(kinds(&violations), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn offsets_locate_the_offending_value() -> IonResult<()> {
+        let constraint = TypeConstraint::of_type(IonType::Struct)
+            .field("name", TypeConstraint::of_type(IonType::Int));
+        let violations = validate(&constraint)?;
+        let offset = violations[0].offset().unwrap();
+        assert!(DATA[offset..].starts_with(r#""widget""#));
+        assert_eq!(
+            violations[0].to_string(),
+            format!("value at 'name' (offset {offset}): expected int but found string")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn validate_element_sequence() -> IonResult<()> {
+        let list: Element = ion_list![1, 2, "three"].into();
+        let constraint = TypeConstraint::of_type(IonType::List)
+            .elements(TypeConstraint::of_type(IonType::Int).compare(ComparisonOp::Lt, 2));
+        let violations = constraint.validate_element(&list)?;
+        assert_eq!(
+            violations
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "value at '[1]': expected a value < 2",
+                "value at '[2]': expected int but found string",
+            ]
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn pattern() -> IonResult<()> {
+        let constraint = TypeConstraint::of_type(IonType::Struct)
+            .field("name", TypeConstraint::any().matching("^wid")?)
+            .field(
+                "tags",
+                TypeConstraint::any().elements(TypeConstraint::any().matching("^[a-z]{4}$")?),
+            )
+            .field("id", TypeConstraint::any().matching("7")?);
+        assert_eq!(
+            kinds(&validate(&constraint)?),
+            vec![
+                (
+                    "tags[0]".to_string(),
+                    ViolationKind::PatternMismatch("^[a-z]{4}$".to_string())
+                ),
+                (
+                    "id".to_string(),
+                    ViolationKind::PatternMismatch("7".to_string())
+                ),
+            ]
+        );
+        assert!(TypeConstraint::any().matching("(").is_err());
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+use crate::ion_data::IonEq;
+use crate::ion_path::{PathComponent, ToIonPath};
 use crate::lazy::decoder::Decoder;
 use crate::lazy::expanded::lazy_element::LazyElement;
 use crate::lazy::expanded::{
@@ -11,6 +13,7 @@ use crate::{
     try_or_some_err, Annotations, Element, ExpandedValueSource, HasSpan, IntoAnnotatedElement,
     IonError, IonResult, IonType, LazyRawValue, Span, SymbolRef, SymbolTable, Value,
 };
+use std::ops::Range;
 
 /// A value in a binary Ion stream whose header has been parsed but whose body (i.e. its data) has
 /// not. A `LazyValue` is immutable; its data can be read any number of times.
@@ -128,6 +131,38 @@ impl<'top, D: Decoder> LazyValue<'top, D> {
         }
     }
 
+    /// If this value came from a raw value literal encoded in the data stream, returns the range
+    /// of stream offsets occupied by its encoding, including any annotations. Values that were
+    /// produced by a macro have no encoding of their own; for those, returns `None`.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Reader;
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let ion_data = r#"true foo::"hello""#;
+    /// let mut reader = Reader::new(Text, ion_data)?;
+    /// let _ = reader.expect_next()?;
+    /// let lazy_value = reader.expect_next()?;
+    /// let range = lazy_value.raw_range().unwrap();
+    /// assert_eq!(&ion_data[range], r#"foo::"hello""#);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn raw_range(&self) -> Option<Range<usize>> {
+        self.expanded_value.range()
+    }
+
+    /// Like [`raw_range`](Self::raw_range), but returns a [`Span`] that also provides access to
+    /// the encoded bytes.
+    pub fn raw_span(&self) -> Option<Span<'top>> {
+        self.expanded_value.span()
+    }
+
     /// Returns `true` if this value is any form of `null`, including
     /// `null`, `null.string`, `null.int`, etc. Otherwise, returns `false`.
     ///
@@ -241,6 +276,51 @@ impl<'top, D: Decoder> LazyValue<'top, D> {
         self.expanded_value.context()
     }
 
+    /// Returns the value nested within this one at the specified [`IonPath`], or `None` if there
+    /// is no such value. `path` can be an `IonPath` or text to be parsed as one.
+    ///
+    /// Only the containers along the path are read; sibling values are skipped. If a struct has
+    /// more than one field with a name in the path, the first such field is selected. (See
+    /// [`LazyStruct::find`](crate::LazyStruct::find).)
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Reader, ValueRef};
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let mut reader = Reader::new(Text, r#"{a: {b: [1, 2, {c: "hello"}]}}"#)?;
+    /// let lazy_value = reader.expect_next()?;
+    /// let hello = lazy_value.get_path("a.b[2].c")?.unwrap();
+    /// assert_eq!(hello.read()?, ValueRef::String("hello".into()));
+    /// assert!(lazy_value.get_path("a.b[3]")?.is_none());
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn get_path(&self, path: impl ToIonPath) -> IonResult<Option<LazyValue<'top, D>>> {
+        let path = path.to_ion_path()?;
+        let mut current = *self;
+        for component in path.components() {
+            let next = match (component, current.read()?) {
+                (PathComponent::Field(name), ValueRef::Struct(strukt)) => strukt.find(name)?,
+                (PathComponent::Index(index), ValueRef::List(list)) => {
+                    list.iter().nth(*index).transpose()?
+                }
+                (PathComponent::Index(index), ValueRef::SExp(sexp)) => {
+                    sexp.iter().nth(*index).transpose()?
+                }
+                _ => None,
+            };
+            let Some(next) = next else {
+                return Ok(None);
+            };
+            current = next;
+        }
+        Ok(Some(current))
+    }
+
     pub fn location(&self) -> SourceLocation {
         if let Some(raw) = self.raw() {
             if raw.encoding().is_text() {
@@ -300,6 +380,21 @@ impl<'top, D: Decoder> LazyValue<'top, D> {
     }
 }
 
+#[cfg(feature = "experimental-tooling-apis")]
+impl<'top> LazyValue<'top, crate::v1_0::Binary> {
+    /// If this value has annotations, returns a description of how its annotations wrapper was
+    /// encoded. Returns `None` if the value has no annotations.
+    pub fn encoded_annotations(&self) -> Option<crate::v1_0::EncodedBinaryAnnotations<'top, 'top>> {
+        self.raw()?.encoded_annotations()
+    }
+
+    /// Returns a description of how this value's opcode, length, and body were encoded.
+    /// Returns `None` if the value did not come from the data stream.
+    pub fn encoded_data(&self) -> Option<crate::v1_0::EncodedBinaryValueData<'top, 'top>> {
+        Some(self.raw()?.encoded_data())
+    }
+}
+
 impl<'top, D: Decoder> TryFrom<LazyValue<'top, D>> for Element {
     type Error = IonError;
 
@@ -316,6 +411,44 @@ impl<'top, D: Decoder> TryFrom<LazyValue<'top, D>> for Element {
     }
 }
 
+impl<'top, D: Decoder> LazyValue<'top, D> {
+    /// Returns `true` if both values have the same annotations and `value_eq` considers their
+    /// data equal. Values that cannot be read are never considered equal.
+    fn eq_by(
+        &self,
+        other: &Self,
+        value_eq: impl FnOnce(&ValueRef<'top, D>, &ValueRef<'top, D>) -> bool,
+    ) -> bool {
+        let mut these_annotations = self.annotations();
+        let mut those_annotations = other.annotations();
+        loop {
+            match (these_annotations.next(), those_annotations.next()) {
+                (None, None) => break,
+                (Some(Ok(this)), Some(Ok(that))) if this == that => continue,
+                _ => return false,
+            }
+        }
+        match (self.read(), other.read()) {
+            (Ok(this), Ok(that)) => value_eq(&this, &that),
+            _ => false,
+        }
+    }
+}
+
+/// Compares the annotations and data of two lazy values. If either value cannot be read (for
+/// example, because its encoding is invalid), the values are considered unequal.
+impl<D: Decoder> PartialEq for LazyValue<'_, D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_by(other, |this, that| this == that)
+    }
+}
+
+impl<D: Decoder> IonEq for LazyValue<'_, D> {
+    fn ion_eq(&self, other: &Self) -> bool {
+        self.eq_by(other, |this, that| this.ion_eq(that))
+    }
+}
+
 /// Iterates over a slice of bytes, lazily reading them as a sequence of symbol tokens encoded
 /// using the format described by generic type parameter `D`.
 pub struct AnnotationsIterator<'top, D: Decoder> {
@@ -493,6 +626,39 @@ mod tests {
     };
     use crate::{Element, IntoAnnotatedElement};
 
+    #[test]
+    fn raw_range() -> IonResult<()> {
+        let ion_text = "foo::[1, 2] bar";
+        let mut reader = Reader::new(AnyEncoding, ion_text)?;
+        let list = reader.expect_next()?;
+        assert_eq!(list.raw_range(), Some(0..11));
+        assert_eq!(list.raw_span().unwrap().bytes(), b"foo::[1, 2]");
+        let second = list.read()?.expect_list()?.iter().nth(1).unwrap()?;
+        assert_eq!(&ion_text[second.raw_range().unwrap()], "2");
+        assert_eq!(reader.expect_next()?.raw_range(), Some(12..15));
+
+        let ion_data = to_binary_ion("foo::[1, 2] bar")?;
+        let mut reader = Reader::new(v1_0::Binary, ion_data.as_slice())?;
+        let list = reader.expect_next()?;
+        let span = list.raw_span().unwrap();
+        assert_eq!(span.range(), list.raw_range().unwrap());
+        assert_eq!(span.bytes(), &ion_data[span.range()]);
+        // The span begins with the annotations wrapper's type descriptor.
+        assert_eq!(span.bytes()[0] & 0xF0, 0xE0);
+        Ok(())
+    }
+
+    #[cfg(feature = "experimental-ion-1-1")]
+    #[test]
+    fn raw_range_of_macro_output() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, r#"$ion_1_1 (:make_string "a" "b")"#)?;
+        let value = reader.expect_next()?;
+        assert_eq!(value.read()?.expect_string()?, "ab");
+        assert_eq!(value.raw_range(), None);
+        assert!(value.raw_span().is_none());
+        Ok(())
+    }
+
     #[test]
     fn annotations_are() -> IonResult<()> {
         let ion_data = to_binary_ion("foo::bar::baz::5")?;
@@ -513,6 +679,35 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::empty("", Some("{a: {b: [1, 2, (3 {c: 4})]}, a: 5}"))]
+    #[case::field("a.b[0]", Some("1"))]
+    #[case::sexp_index("a.b[2][1].c", Some("4"))]
+    #[case::first_of_repeated_field("a", Some("{b: [1, 2, (3 {c: 4})]}"))]
+    #[case::missing_field("a.c", None)]
+    #[case::index_out_of_bounds("a.b[3]", None)]
+    #[case::field_of_list("a.b.c", None)]
+    #[case::index_of_struct("a[0]", None)]
+    #[case::child_of_scalar("a.b[0].c", None)]
+    fn get_path(#[case] path: &str, #[case] expected: Option<&str>) -> IonResult<()> {
+        let ion_text = "{a: {b: [1, 2, (3 {c: 4})]}, a: 5}";
+        for ion_data in [to_binary_ion(ion_text)?, ion_text.as_bytes().to_vec()] {
+            let mut reader = Reader::new(AnyEncoding, ion_data)?;
+            let value = reader.expect_next()?;
+            let actual = value.get_path(path)?.map(Element::try_from).transpose()?;
+            let expected = expected.map(Element::read_one).transpose()?;
+            assert_eq!(actual, expected, "path: {path}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn get_path_with_invalid_path_text() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "{a: 1}")?;
+        assert!(reader.expect_next()?.get_path("a..b").is_err());
+        Ok(())
+    }
+
     fn lazy_value_equals(ion_text: &str, expected: impl Into<Element>) -> IonResult<()> {
         let binary_ion = to_binary_ion(ion_text)?;
         let mut reader = Reader::new(v1_0::Binary, binary_ion)?;
@@ -826,4 +1021,47 @@ mod tests {
         }
         Ok(locations)
     }
+    #[cfg(feature = "experimental-tooling-apis")]
+    #[test]
+    fn binary_1_0_encoded_layout() -> IonResult<()> {
+        use crate::lazy::expanded::r#struct::FieldExpr;
+        use crate::HasRange;
+        let ion_data = to_binary_ion(r#"{greeting: foo::bar::"hi"}"#)?;
+        let mut reader = Reader::new(v1_0::Binary, ion_data.as_slice())?;
+        let strukt = reader.expect_next()?.read()?.expect_struct()?;
+
+        let field_exprs = strukt
+            .expanded()
+            .field_exprs()
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(field_exprs.len(), 1);
+        assert!(matches!(field_exprs[0], FieldExpr::NameValue(..)));
+
+        let field = strukt.iter().next().unwrap()?;
+        let value = field.value();
+        let annotations = value.encoded_annotations().unwrap();
+        let data = value.encoded_data().unwrap();
+        // The field's range spans its name, annotations wrapper, and value.
+        assert_eq!(
+            field.range().unwrap(),
+            field.raw_name().unwrap().range().start..data.range().end
+        );
+        assert_eq!(annotations.opcode_span().bytes()[0] & 0xF0, 0xE0);
+        // The wrapper's length fits in the opcode, so there is no trailing wrapper length.
+        assert!(annotations.wrapper_length_span().is_empty());
+        assert_eq!(annotations.sequence_length_span().bytes(), &[0x82]);
+        assert_eq!(annotations.header_span().len(), 2);
+        assert_eq!(annotations.sequence_span().len(), 2);
+        assert_eq!(
+            annotations.sequence_length_span().range().end,
+            annotations.sequence_span().range().start
+        );
+
+        assert_eq!(data.range().start, annotations.range().end);
+        assert_eq!(data.opcode_span().bytes(), &[0x82]);
+        assert_eq!(data.header_span().bytes(), &[0x82]);
+        assert!(data.trailing_length_span().is_empty());
+        assert_eq!(data.body_span().bytes(), b"hi");
+        Ok(())
+    }
 }
@@ -1,4 +1,6 @@
 use crate::element::Value;
+use crate::ion_data;
+use crate::ion_data::IonEq;
 use crate::lazy::bytes_ref::BytesRef;
 use crate::lazy::decoder::Decoder;
 use crate::lazy::expanded::template::TemplateElement;
@@ -51,10 +53,31 @@ impl<D: Decoder> PartialEq for ValueRef<'_, D> {
             (Symbol(s1), Symbol(s2)) => s1 == s2,
             (Blob(b1), Blob(b2)) => b1 == b2,
             (Clob(c1), Clob(c2)) => c1 == c2,
-            // TODO: The following is no longer true; should we finish implementing PartialEq for
-            //       container types? https://github.com/amazon-ion/ion-rust/issues/761
-            // We cannot compare lazy containers as we cannot guarantee that their complete contents
-            // are available in the buffer. Is `{foo: bar}` equal to `{foo: b`?
+            (SExp(s1), SExp(s2)) => s1 == s2,
+            (List(l1), List(l2)) => l1 == l2,
+            (Struct(s1), Struct(s2)) => s1 == s2,
+            _ => false,
+        }
+    }
+}
+
+impl<D: Decoder> IonEq for ValueRef<'_, D> {
+    fn ion_eq(&self, other: &Self) -> bool {
+        use ValueRef::*;
+        match (self, other) {
+            (Null(this), Null(that)) => this == that,
+            (Bool(this), Bool(that)) => ion_data::ion_eq_bool(this, that),
+            (Int(this), Int(that)) => this.ion_eq(that),
+            (Float(this), Float(that)) => ion_data::ion_eq_f64(this, that),
+            (Decimal(this), Decimal(that)) => this.ion_eq(that),
+            (Timestamp(this), Timestamp(that)) => this.ion_eq(that),
+            (String(this), String(that)) => this == that,
+            (Symbol(this), Symbol(that)) => this == that,
+            (Blob(this), Blob(that)) => this == that,
+            (Clob(this), Clob(that)) => this == that,
+            (SExp(this), SExp(that)) => this.ion_eq(that),
+            (List(this), List(that)) => this.ion_eq(that),
+            (Struct(this), Struct(that)) => this.ion_eq(that),
             _ => false,
         }
     }
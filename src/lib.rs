@@ -139,13 +139,17 @@
 use rstest_reuse;
 
 // Exposed to allow benchmark comparisons between the 1.0 primitives and 1.1 primitives
-pub use catalog::{Catalog, EmptyCatalog, MapCatalog};
+pub use catalog::{Catalog, CompositeCatalog, EmptyCatalog, MapCatalog};
 pub use element::builders::{SequenceBuilder, StructBuilder};
 pub use element::{
-    element_writer::ElementWriter, reader::ElementReader, Annotations, Element,
-    IntoAnnotatedElement, IntoAnnotations, OwnedSequenceIterator, Sequence, Value,
+    element_writer::ElementWriter,
+    reader::{ElementReader, OwnedElementIterator},
+    Annotations, Element, ElementStream, ElementStreamError, ElementStreamOptions,
+    IntoAnnotatedElement, IntoAnnotations, OwnedSequenceIterator, Sequence, StreamedElement, Value,
 };
+pub use ion_data::diff::{diff, DiffEntry, DiffKind};
 pub use ion_data::IonData;
+pub use ion_path::{IonPath, PathComponent, ToIonPath};
 
 #[doc(inline)]
 pub use result::{ConversionOperationError, ConversionOperationResult, IonError, IonResult};
@@ -153,8 +157,9 @@ pub use shared_symbol_table::SharedSymbolTable;
 pub use symbol_ref::SymbolRef;
 #[doc(inline)]
 pub use types::{
-    decimal::Decimal, Blob, Bytes, Clob, Int, IonType, List, Null, SExp, Str, Struct, Symbol,
-    SymbolId, Timestamp, TimestampPrecision, UInt,
+    decimal::Decimal, Blob, Bytes, Clob, FractionalSecondsRounding, Int, IonType,
+    LeapSecondHandling, List, Null, SExp, Str, Struct, Symbol, SymbolId, Timestamp,
+    TimestampPrecision, TimestampPrecisionPolicy, UInt,
 };
 // Allow access to less commonly used types like decimal::coefficient::{Coefficient, Sign}
 pub use types::decimal;
@@ -166,7 +171,15 @@ pub use crate::text::text_formatter::{FmtValueFormatter, IoValueFormatter};
 pub(crate) mod binary;
 pub(crate) mod catalog;
 pub(crate) mod constants;
+#[cfg(feature = "experimental-reader-writer")]
+pub mod convert;
+// `conversion::json` is the path the JSON down-conversion API was first requested under.
+#[cfg(feature = "experimental-reader-writer")]
+pub use convert as conversion;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 mod ion_data;
+mod ion_path;
 mod raw_symbol_ref;
 mod shared_symbol_table;
 mod symbol_ref;
@@ -192,23 +205,53 @@ mod write_config;
 
 pub use crate::lazy::any_encoding::AnyEncoding;
 pub use crate::lazy::decoder::{HasRange, HasSpan};
+pub use crate::lazy::reader_limits::ReaderLimits;
 pub use crate::lazy::span::Span;
+#[cfg(feature = "ion-rs-derive")]
+pub use ion_rs_derive::WriteAsIon;
 macro_rules! v1_x_reader_writer {
     ($visibility:vis) => {
        #[allow(unused_imports)]
         $visibility use crate::{
-            lazy::streaming_raw_reader::{IonInput, IonSlice, IonStream},
+            lazy::streaming_raw_reader::{ChainedSources, IonInput, IonSlice, IonStream},
             lazy::decoder::Decoder,
             lazy::encoder::Encoder,
             lazy::encoding::Encoding,
             lazy::encoder::annotate::Annotatable,
+            lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsBuf, AnnotationsVec},
             lazy::encoder::write_as_ion::WriteAsIon,
-            lazy::encoder::writer::Writer,
-            lazy::reader::Reader,
+            lazy::encoder::writer::{StreamingSequenceWriter, Writer},
+            lazy::encoder::middleware::{
+                CountValues, FieldAction, MiddlewareSequenceWriter, MiddlewareStructWriter,
+                MiddlewareValueWriter, MiddlewareWriter, RedactFields, StampAnnotations,
+                ValueContext, WriterMiddleware,
+            },
+            lazy::extract::FromLazyValue,
+            lazy::pipeline::{MappedPipeline, Pipeline},
+            lazy::cancellation::CancellationToken,
+            lazy::predicate::{ComparisonOp, Predicate},
+            lazy::progress::Progress,
+            lazy::table_growth::{TableGrowth, TableGrowthWarning},
+            lazy::projection::Projection,
+            lazy::path_extractor::{PathExtractor, SearchPaths},
+            lazy::transform::{Transform, TransformingReader},
+            lazy::validation::{TypeConstraint, Violation, ViolationKind},
+            ion_data::diff::diff_readers,
+            lazy::reader::{ElementIterator, OwnedReader, Reader, ValueBatch},
+            lazy::reader_state::ReaderState,
+            lazy::sample::SampledReader,
+            lazy::incremental::IncrementalSequenceReader,
+            lazy::aggregate::{AggregateKind, Aggregator},
+            lazy::merge::{merge, merge_by, MergeSummary},
+            lazy::sort::{sort, sort_by, ExternalSortOptions, SortSummary},
+            lazy::split::{split, SplitSummary},
+            lazy::expanded::lazy_element::LazyElement,
             raw_symbol_ref::RawSymbolRef,
             symbol_table::SymbolTable,
             lazy::value::LazyValue,
             lazy::value_ref::ValueRef,
+            lazy::owned_value_ref::OwnedValueRef,
+            lazy::element_ref::{ElementRef, ElementValueRef},
             lazy::r#struct::{LazyStruct, LazyField},
             lazy::sequence::{LazyList, LazySExp},
             lazy::encoder::value_writer::{AnnotatableWriter, ValueWriter, ContextWriter, StructWriter, SequenceWriter, EExpWriter},
@@ -224,6 +267,8 @@ macro_rules! v1_x_reader_writer {
             lazy::expanded::macro_table::MacroKind,
             lazy::expanded::macro_table::MacroTable,
             lazy::expanded::EncodingContext,
+            lazy::expanded::EncodingContextBuilder,
+            lazy::expanded::EncodingContextSnapshot,
             lazy::any_encoding::IonVersion,
             lazy::binary::raw::reader::LazyRawBinaryReader_1_0,
             lazy::binary::raw::v1_1::reader::LazyRawBinaryReader_1_1,
@@ -236,7 +281,12 @@ macro_rules! v1_x_reader_writer {
     };
 }
 
-pub use crate::write_config::WriteConfig;
+pub use crate::read_config::ReadConfig;
+pub use crate::text::float_format::FloatFormat;
+pub use crate::text::macro_format::MacroFormat;
+pub use crate::text::pretty_config::PrettyConfig;
+pub use crate::text::symbol_id_format::SymbolIdFormat;
+pub use crate::write_config::{SymbolTableRotation, WriteConfig};
 
 macro_rules! v1_0_reader_writer {
     ($visibility:vis) => {
@@ -262,7 +312,7 @@ macro_rules! v1_x_tooling_apis {
     ($visibility:vis) => {
         #[allow(unused_imports)]
         $visibility use crate::{
-            lazy::raw_stream_item::RawStreamItem,
+            lazy::raw_stream_item::{RawStreamItem, RawStreamItemOrNop, NopPad},
             lazy::any_encoding::{
                 LazyRawAnyVersionMarker, LazyRawAnyVersionMarkerKind,
                 LazyRawAnyValue, LazyRawValueKind,
@@ -286,12 +336,16 @@ macro_rules! v1_x_tooling_apis {
             lazy::encoder::{
                 LazyRawWriter,
             },
+            lazy::encoder::system_writer::SystemWriter,
             lazy::encoder::value_writer_config::{
                 ValueWriterConfig,
                 ContainerEncoding,
+                ScalarEncoding,
+                EExpEncoding,
                 SymbolValueEncoding,
                 AnnotationsEncoding,
                 FieldNameEncoding,
+                SymbolInterningPolicy,
             },
             lazy::expanded::r#struct::{
                 LazyExpandedStruct, ExpandedStructSource,
@@ -302,7 +356,7 @@ macro_rules! v1_x_tooling_apis {
             lazy::expanded::e_expression::{EExpression, EExpressionArgsIterator, EExpArgGroup, EExpArgGroupIterator},
             lazy::expanded::sequence::{Environment, ExpandedListSource, ExpandedSExpSource, LazyExpandedList, LazyExpandedSExp},
             lazy::expanded::{ExpandedStreamItem, LazyExpandedValue, ExpandingReader, ExpandedValueSource, ExpandedAnnotationsSource, ExpandedValueRef},
-            lazy::system_stream_item::SystemStreamItem,
+            lazy::system_stream_item::{SystemStreamEvent, SystemStreamItem},
             lazy::system_reader::{SystemReader},
         };
     };
@@ -340,8 +394,6 @@ macro_rules! v1_1_tooling_apis {
     ($visibility:vis) => {
         #[allow(unused_imports)]
         $visibility use crate::{
-            lazy::encoder::binary::v1_1::flex_int::FlexInt,
-            lazy::encoder::binary::v1_1::flex_uint::FlexUInt,
             lazy::encoder::binary::v1_1::writer::LazyRawBinaryWriter_1_1 as RawBinaryWriter,
             lazy::encoder::text::v1_1::writer::LazyRawTextWriter_1_1 as RawTextWriter,
             lazy::binary::raw::v1_1::sequence::{
@@ -354,6 +406,12 @@ macro_rules! v1_1_tooling_apis {
                 LazyRawBinaryVersionMarker_1_1 as LazyRawBinaryVersionMarker,
             },
         };
+        #[cfg(feature = "experimental-tooling-apis")]
+        #[allow(unused_imports)]
+        $visibility use crate::lazy::binary::raw::v1_1::{
+            binary_buffer::ArgGrouping,
+            e_expression::BinaryEExpArgEncoding,
+        };
     };
 }
 
@@ -385,11 +443,39 @@ pub mod v1_0 {
     pub use crate::lazy::encoding::{BinaryEncoding_1_0 as Binary, TextEncoding_1_0 as Text};
 }
 
+/// The Ion 1.1 binary encoding primitives.
+///
+/// Unlike the rest of the Ion 1.1 APIs, these codecs are stable and available without opting into
+/// any experimental features. Tools that define their own framing or sidecar formats can use them
+/// directly.
+///
+/// ```
+/// use ion_rs::encoding_primitives::FlexUInt;
+/// # use ion_rs::IonResult;
+/// # fn main() -> IonResult<()> {
+/// let mut buffer = Vec::new();
+/// FlexUInt::write(&mut buffer, 1_000u64)?;
+/// let flex_uint = FlexUInt::read(&buffer, 0)?;
+/// assert_eq!(flex_uint.value(), 1_000);
+/// assert_eq!(flex_uint.size_in_bytes(), buffer.len());
+/// # Ok(())
+/// # }
+/// ```
+pub mod encoding_primitives {
+    pub use crate::lazy::encoder::binary::v1_1::{
+        flex_int::FlexInt,
+        flex_sym::{FlexSym, FlexSymValue},
+        flex_uint::FlexUInt,
+    };
+}
+
 #[cfg(feature = "experimental-ion-1-1")]
 pub mod v1_1 {
     pub use crate::constants::v1_1::constants;
     pub use crate::constants::v1_1::system_symbols;
 
+    pub use crate::encoding_primitives::*;
+
     #[cfg(feature = "experimental-tooling-apis")]
     v1_1_tooling_apis!(pub);
 
@@ -405,6 +491,8 @@ pub mod v1_1 {
 
 #[cfg(not(feature = "experimental-ion-1-1"))]
 pub(crate) mod v1_1 {
+    pub use crate::encoding_primitives::*;
+
     #[cfg(feature = "experimental-tooling-apis")]
     v1_1_tooling_apis!(pub);
 
@@ -145,6 +145,12 @@ impl<'a> From<&'a str> for RawSymbolRef<'a> {
     }
 }
 
+impl<'a> From<&'a String> for RawSymbolRef<'a> {
+    fn from(value: &'a String) -> Self {
+        RawSymbolRef::Text(value.as_str())
+    }
+}
+
 impl<'a> From<&'a &str> for RawSymbolRef<'a> {
     fn from(value: &'a &str) -> Self {
         RawSymbolRef::Text(value)
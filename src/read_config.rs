@@ -3,12 +3,16 @@ use crate::lazy::any_encoding::AnyEncoding;
 use crate::lazy::encoding::{
     BinaryEncoding_1_0, BinaryEncoding_1_1, TextEncoding_1_0, TextEncoding_1_1,
 };
-use crate::{Catalog, Decoder};
+use crate::{Catalog, Decoder, LeapSecondHandling, ReaderLimits};
 
 /// Provides configuration details for reader construction.
 pub struct ReadConfig<D: Decoder> {
     pub(crate) catalog: Box<dyn Catalog>,
     encoding: D,
+    pub(crate) defer_timestamp_parsing: bool,
+    pub(crate) leap_second_handling: LeapSecondHandling,
+    pub(crate) strict_utf8: bool,
+    pub(crate) limits: ReaderLimits,
 }
 
 impl<D: Decoder> ReadConfig<D> {
@@ -20,12 +24,56 @@ impl<D: Decoder> ReadConfig<D> {
         ReadConfig {
             catalog: Box::new(catalog),
             encoding,
+            defer_timestamp_parsing: false,
+            leap_second_handling: LeapSecondHandling::Reject,
+            strict_utf8: false,
+            limits: ReaderLimits::new(),
         }
     }
 
     pub fn encoding(&self) -> D {
         self.encoding
     }
+
+    /// When `defer` is `true`, text readers will only locate the end of each timestamp in the
+    /// input, postponing the parsing and validation of its components until the timestamp is
+    /// read. This makes skipping over timestamps cheaper, but means that a malformed timestamp
+    /// will not be reported until an attempt is made to read it. Defaults to `false`.
+    ///
+    /// This setting has no effect on binary readers.
+    pub fn with_deferred_timestamp_parsing(mut self, defer: bool) -> Self {
+        self.defer_timestamp_parsing = defer;
+        self
+    }
+
+    /// Configures how readers handle timestamps with a seconds field of `60` (a leap second).
+    /// By default, such timestamps are rejected with an error; see [`LeapSecondHandling`] for the
+    /// alternatives.
+    pub fn with_leap_second_handling(mut self, handling: LeapSecondHandling) -> Self {
+        self.leap_second_handling = handling;
+        self
+    }
+
+    /// When `strict` is `true`, text readers will reject escape sequences in strings and symbols
+    /// that encode a UTF-16 surrogate code point (`U+D800` through `U+DFFF`), including escapes
+    /// that form a surrogate pair like `"\uD83D\uDE00"`. By default, surrogate pairs are combined
+    /// into the character that they represent. Defaults to `false`.
+    ///
+    /// Text and binary readers always validate that the encoded bytes of strings and symbols are
+    /// valid UTF-8, and always reject escape sequences that do not encode a Unicode code point.
+    /// Strict decoding is intended for ingesting untrusted data that should only contain
+    /// well-formed Unicode text.
+    pub fn with_strict_utf8(mut self, strict: bool) -> Self {
+        self.strict_utf8 = strict;
+        self
+    }
+
+    /// Configures the resources that readers will commit to the stream before raising an error.
+    /// By default, readers are unlimited; see [`ReaderLimits`] for the limits that can be set.
+    pub fn with_limits(mut self, limits: ReaderLimits) -> Self {
+        self.limits = limits;
+        self
+    }
 }
 
 impl From<TextEncoding_1_0> for ReadConfig<TextEncoding_1_0> {
@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+/// Indicates that a read, write, or conversion stopped early because the cancellation token it
+/// was given had been cancelled.
+#[derive(Clone, Debug, Error, PartialEq)]
+#[error("the operation was cancelled")]
+pub struct CancelledError {
+    // Keeps this error from being constructed outside the crate.
+    _private: (),
+}
+
+impl CancelledError {
+    pub(crate) fn new() -> Self {
+        CancelledError { _private: () }
+    }
+}
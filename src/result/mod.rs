@@ -10,6 +10,7 @@ use thiserror::Error;
 #[cfg(feature = "experimental-serde")]
 use serde::{de, ser};
 
+mod cancelled;
 mod conversion;
 mod decoding_error;
 mod encoding_error;
@@ -17,6 +18,7 @@ mod illegal_operation;
 mod incomplete;
 mod io_error;
 
+pub use cancelled::CancelledError;
 pub use conversion::ConversionOperationError;
 pub use conversion::ConversionOperationResult;
 pub use conversion::IonTypeExpectation;
@@ -64,6 +66,11 @@ pub enum IonError {
     /// not trivially convertable.
     #[error("{0}")]
     Conversion(#[from] ConversionError),
+
+    /// Returned when an operation was stopped early because its cancellation token was
+    /// cancelled.
+    #[error("{0}")]
+    Cancelled(#[from] CancelledError),
 }
 
 impl From<io::Error> for IonError {
@@ -138,6 +145,7 @@ pub(crate) trait IonFailure {
     fn decoding_error<S: Into<Cow<'static, str>>>(description: S) -> Self;
     fn encoding_error<S: Into<Cow<'static, str>>>(description: S) -> Self;
     fn illegal_operation<S: Into<Cow<'static, str>>>(operation: S) -> Self;
+    fn cancelled() -> Self;
 }
 
 impl IonFailure for IonError {
@@ -156,6 +164,10 @@ impl IonFailure for IonError {
     fn illegal_operation<S: Into<Cow<'static, str>>>(operation: S) -> Self {
         IllegalOperation::new(operation).into()
     }
+
+    fn cancelled() -> Self {
+        CancelledError::new().into()
+    }
 }
 
 impl<T> IonFailure for IonResult<T> {
@@ -174,4 +186,8 @@ impl<T> IonFailure for IonResult<T> {
     fn illegal_operation<S: Into<Cow<'static, str>>>(operation: S) -> Self {
         Err(IonError::illegal_operation(operation))
     }
+
+    fn cancelled() -> Self {
+        Err(IonError::cancelled())
+    }
 }
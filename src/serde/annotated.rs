@@ -0,0 +1,135 @@
+use serde::de::{SeqAccess, Visitor};
+use serde::ser::SerializeTupleStruct;
+use serde::{self, de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+pub(crate) const TUNNELED_ANNOTATED_TYPE_NAME: &str = "$__ion_rs_annotated__";
+
+/// A value paired with the Ion annotations that precede it, like `foo::bar::{...}`.
+///
+/// Serde's data model has no notion of annotations, so they are normally dropped on read.
+/// Wrapping a field's type in `Annotated` captures its annotations as data when deserializing,
+/// and writes them back out when serializing.
+///
+/// When reading, `Annotated` captures all of the value's annotations apart from those consumed
+/// by an enclosing enum variant. Because of this, `T` should not be an enum whose variants are
+/// themselves represented as annotations.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::serde::{from_ion, to_string, Annotated};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Order {
+///     quantity: Annotated<u32>,
+/// }
+///
+/// let order: Order = from_ion("{quantity: units::dozen::3}")?;
+/// assert_eq!(order.quantity.annotations, vec!["units", "dozen"]);
+/// assert_eq!(order.quantity.value, 3);
+/// assert_eq!(to_string(&order)?.trim(), "{quantity: units::dozen::3, }");
+///# Ok(())
+///# }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Annotated<T> {
+    pub annotations: Vec<String>,
+    pub value: T,
+}
+
+impl<T> Annotated<T> {
+    pub fn new<A: Into<String>, I: IntoIterator<Item = A>>(annotations: I, value: T) -> Self {
+        Self {
+            annotations: annotations.into_iter().map(Into::into).collect(),
+            value,
+        }
+    }
+
+    /// Returns `true` if the value has an annotation with the specified text.
+    pub fn has_annotation(&self, annotation: &str) -> bool {
+        self.annotations.iter().any(|a| a == annotation)
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> From<T> for Annotated<T> {
+    fn from(value: T) -> Self {
+        Self {
+            annotations: Vec::new(),
+            value,
+        }
+    }
+}
+
+/// Serialization for `Annotated`
+/// This serialization uses `serialize_tuple_struct` to tunnel the annotations through serde.
+/// The tuple struct is named with `$__ion_rs_annotated__` to distinguish it from an actual `tuple_struct`;
+/// each annotation is written as a field, followed by the value itself.
+impl<T: Serialize> Serialize for Annotated<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut fields = serializer
+            .serialize_tuple_struct(TUNNELED_ANNOTATED_TYPE_NAME, self.annotations.len() + 1)?;
+        for annotation in &self.annotations {
+            fields.serialize_field(annotation)?;
+        }
+        fields.serialize_field(&self.value)?;
+        fields.end()
+    }
+}
+
+/// Deserialization for `Annotated`
+/// This deserialization uses `deserialize_tuple_struct` to tunnel the annotations through serde.
+/// The deserializer reports the number of remaining fields as the sequence's size hint; all but the
+/// last are the value's annotations.
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Annotated<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct AnnotatedVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for AnnotatedVisitor<T> {
+            type Value = Annotated<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("an annotated Ion value")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let num_fields = seq.size_hint().ok_or_else(|| {
+                    de::Error::custom("annotated values can only be read from Ion")
+                })?;
+                let mut annotations = Vec::with_capacity(num_fields.saturating_sub(1));
+                for _ in 1..num_fields {
+                    let annotation = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::custom("missing annotation"))?;
+                    annotations.push(annotation);
+                }
+                let value = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::custom("missing annotated value"))?;
+                Ok(Annotated { annotations, value })
+            }
+        }
+
+        deserializer.deserialize_tuple_struct(
+            TUNNELED_ANNOTATED_TYPE_NAME,
+            // The number of annotations isn't known until the value has been read.
+            0,
+            AnnotatedVisitor(PhantomData),
+        )
+    }
+}
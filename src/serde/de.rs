@@ -2,15 +2,19 @@ use serde::de;
 use serde::de::{DeserializeOwned, DeserializeSeed, EnumAccess, MapAccess, SeqAccess, Visitor};
 
 use crate::lazy::any_encoding::AnyEncoding;
+use crate::lazy::decoder::{Decoder, LazyRawValue};
+use crate::lazy::encoding::{BinaryEncoding_1_1, TextEncoding_1_1};
 use crate::lazy::r#struct::{LazyField, StructIterator};
 use crate::lazy::streaming_raw_reader::IonInput;
 use crate::lazy::value::LazyValue;
 use crate::lazy::value_ref::ValueRef;
 use crate::result::IonFailure;
+use crate::serde::annotated::TUNNELED_ANNOTATED_TYPE_NAME;
 use crate::serde::decimal::TUNNELED_DECIMAL_TYPE_NAME;
+use crate::serde::symbol::TUNNELED_SYMBOL_TYPE_NAME;
 use crate::serde::timestamp::TUNNELED_TIMESTAMP_TYPE_NAME;
 use crate::{
-    Decimal, IonEncoding, IonError, IonResult, IonType, RawVersionMarker, SystemReader,
+    Decimal, IonError, IonResult, IonType, RawVersionMarker, Reader, SystemReader,
     SystemStreamItem, Timestamp,
 };
 
@@ -21,8 +25,67 @@ where
     T: DeserializeOwned,
     I: IonInput,
 {
-    let mut ion_encoding = IonEncoding::default();
-    let mut reader = SystemReader::new(AnyEncoding, input);
+    from_system_reader(SystemReader::new(AnyEncoding, input))
+}
+
+/// Deserializes an object from a stream of Ion 1.1 text.
+///
+/// The stream does not need to begin with an `$ion_1_1` version marker. Values produced by
+/// e-expressions in the stream are expanded before they are deserialized.
+pub fn from_ion_1_1_text<T, I>(input: I) -> IonResult<T>
+where
+    T: DeserializeOwned,
+    I: IonInput,
+{
+    from_system_reader(SystemReader::new(TextEncoding_1_1, input))
+}
+
+/// Deserializes an object from a stream of Ion 1.1 binary.
+///
+/// Values produced by e-expressions in the stream are expanded before they are deserialized.
+pub fn from_ion_1_1_binary<T, I>(input: I) -> IonResult<T>
+where
+    T: DeserializeOwned,
+    I: IonInput,
+{
+    from_system_reader(SystemReader::new(BinaryEncoding_1_1, input))
+}
+
+/// Deserializes an object from the next value in the provided [`Reader`].
+///
+/// Unlike [`from_ion`], this does not consume the reader; calling it repeatedly will deserialize
+/// each of the stream's top-level values in turn. Returns a decoding error if the reader has
+/// no more values.
+pub fn from_reader<T, D, I>(reader: &mut Reader<D, I>) -> IonResult<T>
+where
+    T: DeserializeOwned,
+    D: Decoder,
+    I: IonInput,
+{
+    // The stream's format (text or binary) cannot change partway through, so the encoding
+    // detected before reading the value is only consulted when the value has no encoding of its
+    // own (for example, if it was produced by a macro).
+    let detected_encoding = reader.detected_encoding();
+    let Some(value) = reader.next()? else {
+        return IonResult::decoding_error("stream did not contain any more values");
+    };
+    let ion_encoding = value
+        .raw()
+        .map(|raw_value| raw_value.encoding())
+        .unwrap_or(detected_encoding);
+    T::deserialize(ValueDeserializer::new(
+        &value,
+        /*is_human_readable=*/ ion_encoding.is_text(),
+    ))
+}
+
+fn from_system_reader<T, D, I>(mut reader: SystemReader<D, I>) -> IonResult<T>
+where
+    T: DeserializeOwned,
+    D: Decoder,
+    I: IonInput,
+{
+    let mut ion_encoding = D::INITIAL_ENCODING_EXPECTED;
     loop {
         match reader.next_item()? {
             SystemStreamItem::VersionMarker(marker) => {
@@ -47,16 +110,16 @@ where
 }
 
 #[derive(Clone, Copy)]
-pub struct ValueDeserializer<'a, 'de> {
-    pub(crate) value: &'a LazyValue<'de, AnyEncoding>,
+pub struct ValueDeserializer<'a, 'de, D: Decoder = AnyEncoding> {
+    pub(crate) value: &'a LazyValue<'de, D>,
     is_human_readable: bool,
     variant_nesting_depth: usize,  // Holds the number of nested variants we are for tracking
                                    // variant names in annotations. 0 indicates we're not in a
                                    // variant.
 }
 
-impl<'a, 'de> ValueDeserializer<'a, 'de> {
-    pub(crate) fn new(value: &'a LazyValue<'de, AnyEncoding>, is_human_readable: bool) -> Self {
+impl<'a, 'de, D: Decoder> ValueDeserializer<'a, 'de, D> {
+    pub(crate) fn new(value: &'a LazyValue<'de, D>, is_human_readable: bool) -> Self {
         Self {
             value,
             is_human_readable,
@@ -86,7 +149,7 @@ impl<'a, 'de> ValueDeserializer<'a, 'de> {
     }
 }
 
-impl<'de> de::Deserializer<'de> for ValueDeserializer<'_, 'de> {
+impl<'de, D: Decoder> de::Deserializer<'de> for ValueDeserializer<'_, 'de, D> {
     type Error = IonError;
 
     /// Determine whether Deserialize implementations should expect to deserialize their human-readable form.
@@ -365,6 +428,15 @@ impl<'de> de::Deserializer<'de> for ValueDeserializer<'_, 'de> {
             // The assert statement above that compares the sizes of the Decimal and V::Value types
             let visitor_value = unsafe { std::mem::transmute_copy::<Decimal, V::Value>(&decimal) };
             return Ok(visitor_value);
+        } else if name == TUNNELED_SYMBOL_TYPE_NAME {
+            return match value {
+                ValueRef::Symbol(symbol) => match symbol.text() {
+                    Some(text) => visitor.visit_str(text),
+                    None => visitor.visit_unit(),
+                },
+                ValueRef::String(text) => visitor.visit_str(text.text()),
+                _ => IonResult::decoding_error("expected a symbol or string"),
+            };
         }
 
         visitor.visit_newtype_struct(self)
@@ -386,13 +458,16 @@ impl<'de> de::Deserializer<'de> for ValueDeserializer<'_, 'de> {
 
     fn deserialize_tuple_struct<V>(
         self,
-        _name: &'static str,
+        name: &'static str,
         _len: usize,
         visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
+        if name == TUNNELED_ANNOTATED_TYPE_NAME {
+            return visitor.visit_seq(AnnotatedAccess::new(self)?);
+        }
         self.deserialize_as_sequence(visitor)
     }
 
@@ -468,9 +543,10 @@ impl<'de> de::Deserializer<'de> for ValueDeserializer<'_, 'de> {
 
 pub(crate) struct SequenceIterator<S>(pub(crate) S, bool);
 
-impl<'de, S> SeqAccess<'de> for SequenceIterator<S>
+impl<'de, D, S> SeqAccess<'de> for SequenceIterator<S>
 where
-    S: Iterator<Item = IonResult<LazyValue<'de, AnyEncoding>>>,
+    D: Decoder,
+    S: Iterator<Item = IonResult<LazyValue<'de, D>>>,
 {
     type Error = IonError;
 
@@ -486,14 +562,14 @@ where
     }
 }
 
-struct StructAsMap<'de> {
-    iter: StructIterator<'de, AnyEncoding>,
-    current_field: Option<LazyField<'de, AnyEncoding>>,
+struct StructAsMap<'de, D: Decoder> {
+    iter: StructIterator<'de, D>,
+    current_field: Option<LazyField<'de, D>>,
     is_human_readable: bool,
 }
 
-impl<'de> StructAsMap<'de> {
-    pub fn new(iter: StructIterator<'de, AnyEncoding>, is_human_readable: bool) -> Self {
+impl<'de, D: Decoder> StructAsMap<'de, D> {
+    pub fn new(iter: StructIterator<'de, D>, is_human_readable: bool) -> Self {
         Self {
             iter,
             current_field: None,
@@ -502,7 +578,7 @@ impl<'de> StructAsMap<'de> {
     }
 }
 
-impl<'de> MapAccess<'de> for StructAsMap<'de> {
+impl<'de, D: Decoder> MapAccess<'de> for StructAsMap<'de, D> {
     type Error = IonError;
 
     fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
@@ -537,20 +613,69 @@ impl<'de> MapAccess<'de> for StructAsMap<'de> {
     }
 }
 
+/// Presents an annotated value as a sequence of its annotations' text followed by the value itself.
+/// Annotations that were consumed by an enclosing enum variant are skipped.
+struct AnnotatedAccess<'a, 'de, D: Decoder> {
+    annotations: std::vec::IntoIter<String>,
+    value: Option<ValueDeserializer<'a, 'de, D>>,
+}
+
+impl<'a, 'de, D: Decoder> AnnotatedAccess<'a, 'de, D> {
+    fn new(de: ValueDeserializer<'a, 'de, D>) -> IonResult<Self> {
+        let annotations = de
+            .value
+            .annotations()
+            .skip(de.variant_nesting_depth)
+            .map(|annotation| {
+                annotation?.text().map(str::to_owned).ok_or_else(|| {
+                    IonError::decoding_error("found an annotation with unknown text")
+                })
+            })
+            .collect::<IonResult<Vec<String>>>()?;
+        Ok(Self {
+            annotations: annotations.into_iter(),
+            value: Some(de),
+        })
+    }
+}
+
+impl<'de, D: Decoder> SeqAccess<'de> for AnnotatedAccess<'_, 'de, D> {
+    type Error = IonError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if let Some(annotation) = self.annotations.next() {
+            return seed
+                .deserialize(MapKeyDeserializer { key: annotation })
+                .map(Some);
+        }
+        match self.value.take() {
+            Some(de) => seed.deserialize(de).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.annotations.len() + usize::from(self.value.is_some()))
+    }
+}
+
 #[derive(Clone, Copy)]
-struct VariantAccess<'a, 'de> {
-    de: ValueDeserializer<'a, 'de>,
+struct VariantAccess<'a, 'de, D: Decoder> {
+    de: ValueDeserializer<'a, 'de, D>,
 }
 
-impl<'a, 'de> VariantAccess<'a, 'de> {
-    fn new(de: ValueDeserializer<'a, 'de>) -> Self {
+impl<'a, 'de, D: Decoder> VariantAccess<'a, 'de, D> {
+    fn new(de: ValueDeserializer<'a, 'de, D>) -> Self {
         let de = ValueDeserializer { variant_nesting_depth: de.variant_nesting_depth + 1, ..de };
 
         VariantAccess { de }
     }
 }
 
-impl<'de> EnumAccess<'de> for VariantAccess<'_, 'de> {
+impl<'de, D: Decoder> EnumAccess<'de> for VariantAccess<'_, 'de, D> {
     type Error = IonError;
     type Variant = Self;
 
@@ -562,7 +687,7 @@ impl<'de> EnumAccess<'de> for VariantAccess<'_, 'de> {
     }
 }
 
-impl<'de> de::VariantAccess<'de> for VariantAccess<'_, 'de> {
+impl<'de, D: Decoder> de::VariantAccess<'de> for VariantAccess<'_, 'de, D> {
     type Error = IonError;
 
     fn unit_variant(self) -> Result<(), Self::Error> {
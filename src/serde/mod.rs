@@ -3,8 +3,8 @@
 //! This module offers APIs for serialization of Rust data structures into Ion data and deserialization
 //! of Ion data into Rust data structures. The APIs use the `serde` framework for serialization and
 //! deserialization. See [the Serde website](https://serde.rs/) for additional documentation and
-//! usage examples. This feature doesn't yet support [Ion annotations] and [Ion SExpressions] for
-//! serialization and deserialization.
+//! usage examples. This feature doesn't yet support [Ion SExpressions] for serialization and
+//! deserialization. [Ion annotations] can be read and written by wrapping a value in [`Annotated`].
 //!
 //! There are three different APIs for serializing Ion data:
 //!
@@ -12,7 +12,9 @@
 //! * `to_pretty`: Serialize an object into pretty formatted Ion text.
 //! * `to_binary`:  Serialize an object into Ion binary format.
 //!
-//! For deserialization `from_ion` API is provided through this module.
+//! For deserialization `from_ion` API is provided through this module. It detects the encoding of
+//! its input. `from_ion_1_1_text` and `from_ion_1_1_binary` read streams that are known to be
+//! encoded as Ion 1.1, and `from_reader` deserializes the next value from an existing `Reader`.
 //!
 //! ## Mapping of Ion data types to Rust and serde data types
 //!
@@ -26,6 +28,7 @@
 //!| clob          | byte array                           | byte array                                            |
 //!| bool          | bool                                 | bool                                                  |
 //!| symbol        | string                               | string                                                |
+//!| symbol        | Symbol(Ion Element API)              | newtype_struct (with name as `$__ion_rs_symbol__`)    |
 //!| string        | string                               | string                                                |
 //!| struct        | struct                               | struct                                                |
 //!| list          | vector                               | seq                                                   |
@@ -36,11 +39,11 @@
 //!| Serde data type                                              | Ion representation                          |
 //!|--------------------------------------------------------------|---------------------------------------------|
 //!| u64, i64, u32, i32, u16, i16, u8, i8                         | int                                         |
-//!| char, string, unit_variant                                   | string                                      |
+//!| char, string                                                 | string                                      |
 //!| byte-array                                                   | blob                                        |
 //!| option                                                       | None - null, Some - based on other mappings |
 //!| unit                                                         | null                                        |
-//!| unit_struct                                                  | symbol                                      |
+//!| unit_struct, unit_variant                                    | symbol                                      |
 //!| seq, tuple, tuple_struct                                     | list                                        |
 //!| newtype_struct, map, struct                                  | struct                                      |
 //!| newtype_variant                                              | variant value with annotation               |
@@ -53,6 +56,11 @@
 //! implementation of decimal and timestamp serialization and deserialization. If one wants to use [chrono::DateTime],
 //! it needs to be tagged with `#[serde_as(as = crate::Timestamp)]`._
 //!
+//! _Note: Strings are serialized as Ion strings. To write a value as an Ion symbol instead (for example, so that
+//! repeated text is only written once in a binary stream's symbol table), use [`Symbol`](crate::Symbol), which is
+//! tunneled through serde as a `newtype_struct` named `$__ion_rs_symbol__`. A `String` field can be written as a
+//! symbol by tagging it with `#[serde_as(as = crate::Symbol)]`._
+//!
 //! ## Example of serialization of Rust struct into Ion data
 //! ```
 //! use ion_rs::IonResult;
@@ -193,21 +201,28 @@
 //! [Ion timestamp]: https://amazon-ion.github.io/ion-docs/docs/spec.html#timestamp
 //! [serde data model]: https://serde.rs/data-model.html#types
 
+mod annotated;
 pub mod de;
 mod decimal;
 pub mod ser;
+mod symbol;
 mod timestamp;
 
-pub use de::from_ion;
+pub use annotated::Annotated;
+pub use de::{from_ion, from_ion_1_1_binary, from_ion_1_1_text, from_reader};
 pub use ser::{to_binary, to_pretty, to_string};
 
 #[cfg(test)]
 #[cfg(feature = "experimental-serde")]
 mod tests {
-    use crate::serde::{from_ion, to_binary, to_pretty, to_string};
+    use crate::serde::{
+        from_ion, from_ion_1_1_binary, Annotated, from_ion_1_1_text, from_reader, to_binary, to_pretty,
+        to_string,
+    };
     use std::net::IpAddr;
 
-    use crate::{Decimal, Element, Timestamp};
+    use crate::lazy::encoding::{BinaryEncoding_1_1, TextEncoding_1_1};
+    use crate::{AnyEncoding, Decimal, Element, IonResult, IonType, Reader, Symbol, Timestamp};
     use chrono::{DateTime, FixedOffset, Utc};
     use serde::{Deserialize, Serialize};
     use serde_with::serde_as;
@@ -470,4 +485,187 @@ mod tests {
         assert_eq!(&from_ion::<IpAddr, _>(s).unwrap(), &ip);
         assert_eq!(&from_ion::<IpAddr, _>(binary).unwrap(), &ip);
     }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Reading {
+        sensor: String,
+        values: Vec<i64>,
+    }
+
+    #[test]
+    fn ion_1_1_text_with_macros() -> IonResult<()> {
+        let ion_data = r#"
+            {
+                sensor: (:make_string "temp" "-" (:values "01")),
+                values: [1, (:values 2 3), (:none), 4],
+            }
+        "#;
+        let expected = Reading {
+            sensor: String::from("temp-01"),
+            values: vec![1, 2, 3, 4],
+        };
+        assert_eq!(from_ion_1_1_text::<Reading, _>(ion_data)?, expected);
+        // The generic entry point can read the same data once the stream declares its version.
+        assert_eq!(
+            from_ion::<Reading, _>(format!("$ion_1_1 {ion_data}"))?,
+            expected
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ion_1_1_binary() -> IonResult<()> {
+        let ip: IpAddr = "127.0.0.1".parse().unwrap();
+        // Binary Ion is not human readable, so the address is serialized as a byte sequence.
+        let element = Element::read_one(to_binary(&ip)?)?;
+        let binary_1_1 = element.encode_as(BinaryEncoding_1_1)?;
+        assert_eq!(from_ion_1_1_binary::<IpAddr, _>(binary_1_1.as_slice())?, ip);
+        assert_eq!(from_ion::<IpAddr, _>(binary_1_1)?, ip);
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_from_reader() -> IonResult<()> {
+        let mut reader = Reader::new(
+            TextEncoding_1_1,
+            r#"
+                {sensor: "a", values: []}
+                (:values {sensor: "b", values: [1]} {sensor: "c", values: [2, 3]})
+            "#,
+        )?;
+        let sensors: Vec<String> = (0..3)
+            .map(|_| from_reader::<Reading, _, _>(&mut reader).map(|r| r.sensor))
+            .collect::<IonResult<_>>()?;
+        assert_eq!(sensors, vec!["a", "b", "c"]);
+        assert!(from_reader::<Reading, _, _>(&mut reader).is_err());
+
+        let ip: IpAddr = "10.0.0.1".parse().unwrap();
+        let mut reader = Reader::new(AnyEncoding, to_binary(&ip)?)?;
+        assert_eq!(from_reader::<IpAddr, _, _>(&mut reader)?, ip);
+        Ok(())
+    }
+
+    #[test]
+    fn annotated_values() -> IonResult<()> {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Shipment {
+            weight: Annotated<f64>,
+            items: Annotated<Vec<Annotated<String>>>,
+            destination: Annotated<Address>,
+            note: Option<Annotated<String>>,
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Address {
+            city: String,
+        }
+
+        let ion_data = r#"
+            {
+                weight: kg::2.5e0,
+                items: manifest::[fragile::"vase", "rug"],
+                destination: domestic::priority::{city: "Seattle"},
+            }
+        "#;
+        let expected = Shipment {
+            weight: Annotated::new(["kg"], 2.5),
+            items: Annotated::new(
+                ["manifest"],
+                vec![Annotated::new(["fragile"], "vase".to_owned()), "rug".to_owned().into()],
+            ),
+            destination: Annotated::new(
+                ["domestic", "priority"],
+                Address {
+                    city: "Seattle".to_owned(),
+                },
+            ),
+            note: None,
+        };
+        let shipment: Shipment = from_ion(ion_data)?;
+        assert_eq!(shipment, expected);
+
+        // Annotations survive a round trip through both text and binary Ion.
+        let text = to_string(&shipment)?;
+        let expected_text = r#"
+            {
+                weight: kg::2.5e0,
+                items: manifest::[fragile::"vase", "rug"],
+                destination: domestic::priority::{city: "Seattle"},
+                note: null,
+            }
+        "#;
+        assert_eq!(Element::read_one(&text)?, Element::read_one(expected_text)?);
+        assert_eq!(from_ion::<Shipment, _>(text)?, expected);
+        assert_eq!(from_ion::<Shipment, _>(to_binary(&shipment)?)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn annotated_enum_variant_payload() -> IonResult<()> {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        enum Measurement {
+            Length(Annotated<i64>),
+        }
+
+        let measurement = Measurement::Length(Annotated::new(["cm"], 12));
+        let text = to_string(&measurement)?;
+        assert_eq!(text.trim(), "Length::cm::12");
+        assert_eq!(from_ion::<Measurement, _>(text)?, measurement);
+        Ok(())
+    }
+
+    #[test]
+    fn symbols() -> IonResult<()> {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        enum Color {
+            Red,
+            Blue,
+        }
+
+        #[serde_as]
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Palette {
+            name: Symbol,
+            #[serde_as(as = "Vec<crate::Symbol>")]
+            tags: Vec<String>,
+            colors: Vec<Color>,
+            unknown: Symbol,
+        }
+
+        let palette = Palette {
+            name: Symbol::from("warm"),
+            tags: vec!["warm".to_owned(), "bright".to_owned(), "warm".to_owned()],
+            colors: vec![Color::Red, Color::Blue, Color::Red],
+            unknown: Symbol::unknown_text(),
+        };
+
+        let text = to_string(&palette)?;
+        let expected = Element::read_one(
+            "{name: warm, tags: [warm, bright, warm], colors: [Red, Blue, Red], unknown: $0}",
+        )?;
+        assert_eq!(Element::read_one(&text)?, expected);
+        assert_eq!(from_ion::<Palette, _>(text)?, palette);
+
+        // In binary Ion 1.0, each distinct symbol's text is written once in the symbol table and
+        // every occurrence is written as a symbol ID.
+        let binary = to_binary(&palette)?;
+        for text in ["warm", "bright", "Red", "Blue"] {
+            let occurrences = binary
+                .windows(text.len())
+                .filter(|window| *window == text.as_bytes())
+                .count();
+            assert_eq!(occurrences, 1, "symbol text '{text}' was written {occurrences} times");
+        }
+        assert_eq!(Element::read_one(&binary)?, expected);
+        assert_eq!(from_ion::<Palette, _>(binary)?, palette);
+
+        // Symbols can also be read from Ion strings.
+        let symbol: Symbol = from_ion("\"warm\"")?;
+        assert_eq!(symbol, Symbol::from("warm"));
+        assert_eq!(
+            Element::read_one(to_string(&symbol)?)?.ion_type(),
+            IonType::Symbol
+        );
+        Ok(())
+    }
 }
@@ -9,7 +9,9 @@ use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter, ValueWrit
 use crate::lazy::encoder::writer::Writer;
 use crate::lazy::encoding::{BinaryEncoding_1_0, Encoding, TextEncoding_1_0};
 use crate::result::IonFailure;
+use crate::serde::annotated::TUNNELED_ANNOTATED_TYPE_NAME;
 use crate::serde::decimal::TUNNELED_DECIMAL_TYPE_NAME;
+use crate::serde::symbol::{TunneledSymbol, TUNNELED_SYMBOL_TYPE_NAME};
 use crate::serde::timestamp::TUNNELED_TIMESTAMP_TYPE_NAME;
 use crate::symbol_ref::AsSymbolRef;
 use crate::write_config::{WriteConfig, WriteConfigKind};
@@ -68,7 +70,7 @@ where
 pub struct ValueSerializer<'a, V: ValueWriter> {
     pub(crate) value_writer: V,
     pub(crate) is_human_readable: bool,
-    pub(crate) annotations: Vec<&'static str>,
+    pub(crate) annotations: Vec<&'a str>,
     lifetime: PhantomData<&'a ()>,
 }
 
@@ -87,12 +89,12 @@ impl<'a, V: ValueWriter + 'a> ser::Serializer for ValueSerializer<'a, V> {
     type Ok = ();
     type Error = IonError;
 
-    type SerializeSeq = SeqWriter<V>;
+    type SerializeSeq = SeqWriter<V::AnnotatedValueWriter<'a>>;
     type SerializeTuple = SeqWriter<V::AnnotatedValueWriter<'a>>;
-    type SerializeTupleStruct = SeqWriter<V::AnnotatedValueWriter<'a>>;
+    type SerializeTupleStruct = TupleStructWriter<'a, V>;
     type SerializeTupleVariant = SeqWriter<V::AnnotatedValueWriter<'a>>;
-    type SerializeMap = MapWriter<V>;
-    type SerializeStruct = MapWriter<V>;
+    type SerializeMap = MapWriter<V::AnnotatedValueWriter<'a>>;
+    type SerializeStruct = MapWriter<V::AnnotatedValueWriter<'a>>;
     type SerializeStructVariant = MapWriter<V::AnnotatedValueWriter<'a>>;
 
     /// Determine whether Serialize implementations should serialize in human-readable form.
@@ -103,7 +105,7 @@ impl<'a, V: ValueWriter + 'a> ser::Serializer for ValueSerializer<'a, V> {
 
     /// Serialize a boolean to a bool value
     fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
-        self.value_writer.write(v)
+        self.value_writer.with_annotations(self.annotations)?.write(v)
     }
 
     /// Serialize all integer types using the `Integer` intermediary type.
@@ -147,7 +149,7 @@ impl<'a, V: ValueWriter + 'a> ser::Serializer for ValueSerializer<'a, V> {
     }
 
     fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
-        self.value_writer.write(v)
+        self.value_writer.with_annotations(self.annotations)?.write(v)
     }
 
     fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
@@ -221,7 +223,15 @@ impl<'a, V: ValueWriter + 'a> ser::Serializer for ValueSerializer<'a, V> {
             // The assert statement above that compares the sizes of the Decimal and value types
             assert_eq!(std::mem::size_of_val(value), std::mem::size_of::<Decimal>());
             let decimal = unsafe { std::mem::transmute_copy::<&T, &Decimal>(&value) };
-            self.value_writer.write_decimal(decimal)
+            self.value_writer.with_annotations(self.annotations)?.write_decimal(decimal)
+        } else if name == TUNNELED_SYMBOL_TYPE_NAME {
+            // # Safety
+            // compiler doesn't understand that the generic T here is actually TunneledSymbol here since
+            // we are using TUNNELED_SYMBOL_TYPE_NAME flag here which indicates a symbol value
+            // The assert statement above that compares the sizes of the TunneledSymbol and value types
+            assert_eq!(std::mem::size_of_val(value), std::mem::size_of::<TunneledSymbol<'_>>());
+            let symbol = unsafe { std::mem::transmute_copy::<&T, &TunneledSymbol<'_>>(&value) }.0;
+            self.value_writer.with_annotations(self.annotations)?.write(symbol.as_symbol_ref())
         } else {
             value.serialize(self)
         }
@@ -243,7 +253,7 @@ impl<'a, V: ValueWriter + 'a> ser::Serializer for ValueSerializer<'a, V> {
 
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
         Ok(SeqWriter {
-            seq_writer: self.value_writer.list_writer()?,
+            seq_writer: self.value_writer.with_annotations(self.annotations)?.list_writer()?,
             is_human_readable: self.is_human_readable,
         })
     }
@@ -259,16 +269,25 @@ impl<'a, V: ValueWriter + 'a> ser::Serializer for ValueSerializer<'a, V> {
     fn serialize_tuple_struct(
         self,
         name: &'static str,
-        _len: usize,
+        len: usize,
     ) -> Result<Self::SerializeTupleStruct, Self::Error> {
         let ValueSerializer { value_writer, is_human_readable, mut annotations, .. } = self;
+        if name == TUNNELED_ANNOTATED_TYPE_NAME {
+            return Ok(TupleStructWriter::Annotated(AnnotatedWriter {
+                value_writer: Some(value_writer),
+                is_human_readable,
+                annotations,
+                captured_annotations: Vec::with_capacity(len.saturating_sub(1)),
+                remaining_fields: len,
+            }));
+        }
         annotations.push(name);
-        Ok(SeqWriter {
+        Ok(TupleStructWriter::Sequence(SeqWriter {
             seq_writer: value_writer
                 .with_annotations(annotations)?
                 .list_writer()?,
             is_human_readable,
-        })
+        }))
     }
 
     fn serialize_tuple_variant(
@@ -290,7 +309,7 @@ impl<'a, V: ValueWriter + 'a> ser::Serializer for ValueSerializer<'a, V> {
 
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
         Ok(MapWriter {
-            map_writer: self.value_writer.struct_writer()?,
+            map_writer: self.value_writer.with_annotations(self.annotations)?.struct_writer()?,
             is_human_readable: self.is_human_readable,
         })
     }
@@ -301,7 +320,7 @@ impl<'a, V: ValueWriter + 'a> ser::Serializer for ValueSerializer<'a, V> {
         _len: usize,
     ) -> Result<Self::SerializeStruct, Self::Error> {
         Ok(MapWriter {
-            map_writer: self.value_writer.struct_writer()?,
+            map_writer: self.value_writer.with_annotations(self.annotations)?.struct_writer()?,
             is_human_readable: self.is_human_readable,
         })
     }
@@ -394,6 +413,81 @@ impl<V: ValueWriter> ser::SerializeTupleStruct for SeqWriter<V> {
     }
 }
 
+/// The serializer used for tuple structs. Most tuple structs are written as annotated lists, but
+/// [`Annotated`](crate::serde::Annotated) values tunnel their annotations through a tuple struct
+/// and are written as the annotated value instead.
+pub enum TupleStructWriter<'a, V: ValueWriter + 'a> {
+    Sequence(SeqWriter<V::AnnotatedValueWriter<'a>>),
+    Annotated(AnnotatedWriter<'a, V>),
+}
+
+impl<'a, V: ValueWriter + 'a> ser::SerializeTupleStruct for TupleStructWriter<'a, V> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        match self {
+            TupleStructWriter::Sequence(seq_writer) => seq_writer.serialize_field(value),
+            TupleStructWriter::Annotated(annotated_writer) => {
+                annotated_writer.serialize_field(value)
+            }
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        match self {
+            TupleStructWriter::Sequence(seq_writer) => ser::SerializeTupleStruct::end(seq_writer),
+            TupleStructWriter::Annotated(annotated_writer) => annotated_writer.end(),
+        }
+    }
+}
+
+/// Collects the annotations of an [`Annotated`](crate::serde::Annotated) value, then writes the
+/// value itself with those annotations when its final field is serialized.
+pub struct AnnotatedWriter<'a, V: ValueWriter> {
+    value_writer: Option<V>,
+    is_human_readable: bool,
+    // Annotations added by enclosing enum variants
+    annotations: Vec<&'a str>,
+    captured_annotations: Vec<String>,
+    remaining_fields: usize,
+}
+
+impl<V: ValueWriter> AnnotatedWriter<'_, V> {
+    fn serialize_field<T>(&mut self, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.remaining_fields = self.remaining_fields.saturating_sub(1);
+        if self.remaining_fields > 0 {
+            let annotation = value.serialize(MapKeySerializer {})?;
+            self.captured_annotations.push(annotation);
+            return Ok(());
+        }
+        let value_writer = self.value_writer.take().ok_or_else(|| {
+            IonError::encoding_error("annotated value has already been written")
+        })?;
+        let mut annotations = self.annotations.clone();
+        annotations.extend(self.captured_annotations.iter().map(String::as_str));
+        value.serialize(ValueSerializer {
+            value_writer,
+            is_human_readable: self.is_human_readable,
+            annotations,
+            lifetime: PhantomData,
+        })
+    }
+
+    fn end(self) -> IonResult<()> {
+        if self.value_writer.is_some() {
+            return IonResult::encoding_error("annotated value did not include a value");
+        }
+        Ok(())
+    }
+}
+
 impl<V: ValueWriter> ser::SerializeTupleVariant for SeqWriter<V> {
     type Ok = ();
     type Error = IonError;
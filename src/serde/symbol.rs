@@ -0,0 +1,110 @@
+use crate::Symbol;
+use serde::de::Visitor;
+use serde::{self, de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+use std::fmt;
+
+pub(crate) const TUNNELED_SYMBOL_TYPE_NAME: &str = "$__ion_rs_symbol__";
+
+/// The payload of a tunneled symbol. Serializers other than Ion's will see a string (or a unit,
+/// for a symbol with unknown text) inside of a `newtype_struct`.
+pub(crate) struct TunneledSymbol<'a>(pub(crate) &'a Symbol);
+
+impl Serialize for TunneledSymbol<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0.text() {
+            Some(text) => serializer.serialize_str(text),
+            None => serializer.serialize_unit(),
+        }
+    }
+}
+
+/// Serialization for Ion `Symbol`
+/// This serialization internally uses `serialize_newtype_struct` to trick serde to serialize a string value into a symbol.
+/// This `newtype_struct` is named with `$__ion_rs_symbol__` to distinguish it from an actual `newtype_struct`.
+/// More information on `newtype_struct` can be found in the serde data model: `<https://serde.rs/data-model.html#types>`
+impl Serialize for Symbol {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(TUNNELED_SYMBOL_TYPE_NAME, &TunneledSymbol(self))
+    }
+}
+
+/// Deserialization for Ion `Symbol`
+/// This deserialization internally uses `deserialize_newtype_struct` to ask the Ion deserializer for a symbol.
+/// Both Ion symbols and Ion strings can be read as a `Symbol`; a symbol with unknown text (`$0`) is
+/// visited as a unit.
+impl<'de> Deserialize<'de> for Symbol {
+    fn deserialize<D>(deserializer: D) -> Result<Symbol, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SymbolVisitor;
+
+        impl<'de> Visitor<'de> for SymbolVisitor {
+            type Value = Symbol;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("an Ion Symbol")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(Symbol::owned(v))
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(Symbol::owned(v))
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(Symbol::unknown_text())
+            }
+
+            fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_any(self)
+            }
+        }
+
+        deserializer.deserialize_newtype_struct(TUNNELED_SYMBOL_TYPE_NAME, SymbolVisitor)
+    }
+}
+
+impl SerializeAs<String> for Symbol {
+    fn serialize_as<S>(source: &String, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Symbol::owned(source.as_str()).serialize(serializer)
+    }
+}
+
+impl SerializeAs<str> for Symbol {
+    fn serialize_as<S>(source: &str, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Symbol::owned(source).serialize(serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, String> for Symbol {
+    fn deserialize_as<D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let symbol = Symbol::deserialize(deserializer)?;
+        match symbol.text() {
+            Some(text) => Ok(text.to_owned()),
+            None => Err(de::Error::custom("found a symbol with unknown text")),
+        }
+    }
+}
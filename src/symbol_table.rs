@@ -175,6 +175,30 @@ impl SymbolTable {
         id
     }
 
+    /// Removes the symbols at or above `new_len`. Symbol text that was assigned more than one ID
+    /// maps to its highest remaining ID.
+    pub(crate) fn truncate(&mut self, new_len: usize) {
+        if new_len >= self.symbols_by_id.len() {
+            return;
+        }
+        let removed_symbols = self.symbols_by_id.split_off(new_len);
+        let mut needs_remapping = false;
+        for symbol in removed_symbols {
+            if matches!(self.ids_by_text.get(&symbol), Some(id) if *id >= new_len) {
+                self.ids_by_text.remove(&symbol);
+                needs_remapping = true;
+            }
+        }
+        if needs_remapping {
+            // Some of the removed text may also have been assigned a lower ID. Replaying the
+            // remaining symbols in order restores those mappings; as in `add_symbol`, later IDs
+            // take precedence.
+            for (id, symbol) in self.symbols_by_id.iter().enumerate() {
+                self.ids_by_text.insert(symbol.clone(), id);
+            }
+        }
+    }
+
     /// Assigns unknown text to the next available symbol ID. This is used when an Ion reader
     /// encounters null or non-string values in a stream's symbol table.
     pub(crate) fn add_placeholder(&mut self) -> SymbolId {
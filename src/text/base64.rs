@@ -0,0 +1,82 @@
+//! Base64 encoding and decoding for text Ion blobs.
+//!
+//! When the `simd-base64` feature is enabled, these functions use a SIMD-accelerated
+//! implementation. Otherwise, they use the scalar `base64` crate. Both implementations use the
+//! standard alphabet and require padding.
+
+use std::fmt::Display;
+
+/// An error encountered while decoding base64 text.
+#[derive(Debug)]
+pub(crate) struct Base64DecodeError(String);
+
+impl Display for Base64DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Returns the maximum number of bytes that decoding `base64_len` bytes of base64 text can produce.
+pub(crate) fn max_decoded_size(base64_len: usize) -> usize {
+    base64_len.div_ceil(4) * 3
+}
+
+/// Decodes `input` into `output`, returning the number of bytes that were written.
+///
+/// `output` must be at least [`max_decoded_size(input.len())`](max_decoded_size) bytes long.
+#[cfg(feature = "simd-base64")]
+pub(crate) fn decode_to_slice(input: &[u8], output: &mut [u8]) -> Result<usize, Base64DecodeError> {
+    use base64_simd::{Out, STANDARD};
+    STANDARD
+        .decode(input, Out::from_slice(output))
+        .map(|decoded| decoded.len())
+        .map_err(|e| Base64DecodeError(e.to_string()))
+}
+
+/// Decodes `input` into `output`, returning the number of bytes that were written.
+///
+/// `output` must be at least [`max_decoded_size(input.len())`](max_decoded_size) bytes long.
+#[cfg(not(feature = "simd-base64"))]
+pub(crate) fn decode_to_slice(input: &[u8], output: &mut [u8]) -> Result<usize, Base64DecodeError> {
+    base64::decode_config_slice(input, base64::STANDARD, output)
+        .map_err(|e| Base64DecodeError(format!("{e:?}")))
+}
+
+/// Encodes `input` as base64 text.
+#[cfg(feature = "simd-base64")]
+pub(crate) fn encode(input: impl AsRef<[u8]>) -> String {
+    base64_simd::STANDARD.encode_to_string(input.as_ref())
+}
+
+/// Encodes `input` as base64 text.
+#[cfg(not(feature = "simd-base64"))]
+pub(crate) fn encode(input: impl AsRef<[u8]>) -> String {
+    base64::encode(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::empty(b"", "")]
+    #[case::one_byte(b"a", "YQ==")]
+    #[case::two_bytes(b"ab", "YWI=")]
+    #[case::three_bytes(b"abc", "YWJj")]
+    #[case::longer(b"hello, world! 0123456789", "aGVsbG8sIHdvcmxkISAwMTIzNDU2Nzg5")]
+    fn round_trip(#[case] bytes: &[u8], #[case] base64_text: &str) {
+        assert_eq!(encode(bytes), base64_text);
+        let mut output = vec![0u8; max_decoded_size(base64_text.len())];
+        let decoded_size = decode_to_slice(base64_text.as_bytes(), &mut output).unwrap();
+        assert_eq!(&output[..decoded_size], bytes);
+    }
+
+    #[rstest]
+    #[case::invalid_character("YW*j")]
+    #[case::truncated("YWJ")]
+    fn invalid_base64(#[case] base64_text: &str) {
+        let mut output = vec![0u8; max_decoded_size(base64_text.len())];
+        assert!(decode_to_slice(base64_text.as_bytes(), &mut output).is_err());
+    }
+}
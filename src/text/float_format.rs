@@ -0,0 +1,183 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use arrayvec::ArrayString;
+use num_traits::Float;
+
+/// How the text writer represents numeric `float` values. (`nan`, `+inf`, and `-inf` are always
+/// written as keywords.)
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum FloatFormat {
+    /// Scientific notation using the fewest significant digits that will read back as the same
+    /// 64-bit value (e.g. `1.25e2`). This is the default.
+    #[default]
+    Scientific,
+    /// The shorter of scientific notation (`1e-5`) and positional notation (`125.5e0`), using the
+    /// fewest significant digits that will read back as the same 64-bit value. 32-bit floats are
+    /// widened first, so only those that are exactly representable in a few digits (like `3.25`)
+    /// are written briefly; the rest keep enough digits to match the binary encoding.
+    Shortest,
+    /// Scientific notation with exactly the specified number of digits after the decimal point
+    /// (e.g. `1.250e2` for a precision of 3). Values that require more digits will be rounded, so
+    /// this format does not guarantee that values will round-trip; it is intended for documents
+    /// meant to be read by people.
+    FixedPrecision(u8),
+}
+
+/// Adapts a float value and a [`FloatFormat`] into a `Display` implementation that writes the
+/// value as Ion text.
+pub(crate) struct FormattedFloat<F> {
+    value: F,
+    format: FloatFormat,
+}
+
+impl<F> FormattedFloat<F> {
+    pub(crate) fn new(value: F, format: FloatFormat) -> Self {
+        Self { value, format }
+    }
+}
+
+impl<F: Float> Display for FormattedFloat<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = self.value;
+        if value.is_nan() {
+            return f.write_str("nan");
+        }
+
+        if value.is_infinite() {
+            return match value.is_sign_positive() {
+                true => f.write_str("+inf"),
+                false => f.write_str("-inf"),
+            };
+        }
+
+        // The {:e} formatter provided by the Display trait writes floats using scientific
+        // notation. It works for all floating point values except -0.0 (it drops the sign).
+        // See: https://github.com/rust-lang/rust/issues/20596
+        if value.is_zero() && value.is_sign_negative() {
+            return f.write_str("-0e0");
+        }
+
+        // Floats are formatted at 64-bit precision regardless of their width. Readers (and the
+        // binary encoding) treat every Ion float as 64 bits, so the text must describe the same
+        // 64-bit value.
+        let value_f64 = value.to_f64().ok_or(fmt::Error)?;
+        match self.format {
+            FloatFormat::Scientific => write!(f, "{value_f64:e}"),
+            FloatFormat::FixedPrecision(precision) => {
+                write!(
+                    f,
+                    "{value_f64:.precision$e}",
+                    precision = precision as usize
+                )
+            }
+            FloatFormat::Shortest => {
+                // Rust's float formatting produces the fewest digits that will round-trip,
+                // in both its positional and scientific forms.
+                let mut scientific = ArrayString::<48>::new();
+                fmt::write(&mut scientific, format_args!("{value_f64:e}"))?;
+                // When the lengths are the same, positional notation is easier to read.
+                if positional_length(&scientific) <= scientific.len() {
+                    write!(f, "{value_f64}e0")
+                } else {
+                    f.write_str(&scientific)
+                }
+            }
+        }
+    }
+}
+
+/// Given the scientific notation text of a finite float (e.g. `-1.2345e3`), returns the length of
+/// the same value written in positional notation with an `e0` suffix (`-1234.5e0`).
+fn positional_length(scientific: &str) -> usize {
+    let (significand, exponent) = scientific
+        .split_once('e')
+        .expect("scientific notation has an exponent");
+    let exponent: i64 = exponent.parse().expect("exponent is a valid integer");
+    let is_negative = significand.starts_with('-') as usize;
+    let num_digits = significand.bytes().filter(|b| b.is_ascii_digit()).count() as i64;
+    let body_length = if exponent < 0 {
+        // A leading `0.`, followed by any zeros between the decimal point and the first digit.
+        2 + (-exponent - 1) + num_digits
+    } else if num_digits - 1 <= exponent {
+        // An integer, possibly padded with trailing zeros
+        exponent + 1
+    } else {
+        // Digits on either side of the decimal point
+        num_digits + 1
+    };
+    is_negative + body_length as usize + "e0".len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::text::float_format::{FloatFormat, FormattedFloat};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::zero(0f64, "0e0")]
+    #[case::negative_zero(-0f64, "-0e0")]
+    #[case::nan(f64::NAN, "nan")]
+    #[case::infinity(f64::INFINITY, "+inf")]
+    #[case::negative_infinity(f64::NEG_INFINITY, "-inf")]
+    #[case::integer(400f64, "4e2")]
+    #[case::fraction(0.1f64, "1e-1")]
+    #[case::many_digits(1234.5f64, "1.2345e3")]
+    fn scientific(#[case] value: f64, #[case] expected: &str) {
+        let actual = FormattedFloat::new(value, FloatFormat::Scientific).to_string();
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case::zero(0f64, "0e0")]
+    #[case::negative_zero(-0f64, "-0e0")]
+    #[case::one(1f64, "1e0")]
+    #[case::large_integer(400f64, "4e2")]
+    #[case::fraction(0.5f64, "5e-1")]
+    #[case::tie_prefers_positional(1234.5f64, "1234.5e0")]
+    #[case::positional_is_shorter(12345678901.5f64, "12345678901.5e0")]
+    #[case::negative_positional(-12.5f64, "-12.5e0")]
+    #[case::small(0.000123f64, "1.23e-4")]
+    #[case::huge(f64::MAX, "1.7976931348623157e308")]
+    #[case::tiny(f64::MIN_POSITIVE, "2.2250738585072014e-308")]
+    fn shortest_f64(#[case] value: f64, #[case] expected: &str) {
+        let actual = FormattedFloat::new(value, FloatFormat::Shortest).to_string();
+        assert_eq!(actual, expected);
+        assert_eq!(
+            actual.parse::<f64>().unwrap().to_bits(),
+            value.to_bits(),
+            "'{actual}' did not round-trip"
+        );
+    }
+
+    #[rstest]
+    #[case::fraction(0.1f32, "0.10000000149011612e0")]
+    #[case::positional(3.25f32, "3.25e0")]
+    #[case::max(f32::MAX, "3.4028234663852886e38")]
+    fn shortest_f32(#[case] value: f32, #[case] expected: &str) {
+        let actual = FormattedFloat::new(value, FloatFormat::Shortest).to_string();
+        assert_eq!(actual, expected);
+        // The text must read back as the same 64-bit value that the binary encoding would hold.
+        assert_eq!(
+            actual.parse::<f64>().unwrap().to_bits(),
+            f64::from(value).to_bits()
+        );
+    }
+
+    #[test]
+    fn scientific_f32_uses_64_bit_precision() {
+        let actual = FormattedFloat::new(0.1f32, FloatFormat::Scientific).to_string();
+        assert_eq!(actual, "1.0000000149011612e-1");
+    }
+
+    #[rstest]
+    #[case::pads(125f64, 3, "1.250e2")]
+    #[case::rounds(std::f64::consts::PI, 4, "3.1416e0")]
+    #[case::no_fraction(12345f64, 0, "1e4")]
+    #[case::nan(f64::NAN, 2, "nan")]
+    fn fixed_precision(#[case] value: f64, #[case] precision: u8, #[case] expected: &str) {
+        let actual = FormattedFloat::new(value, FloatFormat::FixedPrecision(precision)).to_string();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,93 @@
+/// How the Ion 1.1 text writer lays out e-expressions and macro definitions (TDL).
+///
+/// These options only affect formats that put nested values on their own lines, like
+/// [`TextFormat::Pretty`](crate::TextFormat::Pretty). The default settings lay out e-expressions
+/// and macro definitions the same way as any other s-expression.
+///
+/// ```
+/// # use ion_rs::*;
+/// # #[cfg(feature = "experimental-ion-1-1")]
+/// # fn main() -> IonResult<()> {
+/// let macro_format = MacroFormat::new()
+///     .with_max_inline_args(2)
+///     .with_system_macros_by_name(true);
+/// let config = WriteConfig::<v1_1::Text>::new(TextFormat::Pretty).with_macro_format(macro_format);
+/// let mut writer = Writer::new(config, Vec::new())?;
+/// let greet = writer.compile_macro("(macro greet (name) (.make_string \"Hello, \" (%name)))")?;
+/// let mut eexp = writer.eexp_writer(&greet)?;
+/// eexp.write("World")?;
+/// eexp.close()?;
+/// let text = String::from_utf8(writer.close()?).unwrap();
+/// assert!(text.contains("(:greet \"World\" )"));
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "experimental-ion-1-1"))]
+/// # fn main() {}
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct MacroFormat {
+    max_inline_args: usize,
+    indent_values: bool,
+    system_macros_by_name: bool,
+}
+
+impl MacroFormat {
+    /// Returns the default format, which lays out e-expressions and macro definitions like other
+    /// s-expressions and refers to system macros by address.
+    pub const fn new() -> Self {
+        Self {
+            max_inline_args: 0,
+            indent_values: true,
+            system_macros_by_name: false,
+        }
+    }
+
+    /// Writes argument lists on a single line if they have no more than `max_inline_args`
+    /// arguments. Longer argument lists have one argument per line.
+    ///
+    /// An e-expression is written on a single line if the macro it invokes has at most this many
+    /// parameters. In a macro definition, the limit applies to the signature and to each macro
+    /// invocation, variable reference, and expression group in the body.
+    pub const fn with_max_inline_args(mut self, max_inline_args: usize) -> Self {
+        self.max_inline_args = max_inline_args;
+        self
+    }
+
+    /// Sets whether each expression in a macro definition's `(.values ...)` body is written on its
+    /// own indented line (the default). If `false`, `(.values ...)` invocations are written on a
+    /// single line regardless of how many arguments they have.
+    pub const fn with_indented_values(mut self, indent_values: bool) -> Self {
+        self.indent_values = indent_values;
+        self
+    }
+
+    /// Sets whether e-expressions invoking a system macro by address (like `(:$ion::3 ...)`)
+    /// refer to the macro by name instead (`(:$ion::make_string ...)`).
+    pub const fn with_system_macros_by_name(mut self, system_macros_by_name: bool) -> Self {
+        self.system_macros_by_name = system_macros_by_name;
+        self
+    }
+
+    pub const fn max_inline_args(&self) -> usize {
+        self.max_inline_args
+    }
+
+    pub const fn indents_values(&self) -> bool {
+        self.indent_values
+    }
+
+    pub const fn writes_system_macros_by_name(&self) -> bool {
+        self.system_macros_by_name
+    }
+
+    /// Returns `true` if a list of `num_args` arguments should be written on a single line.
+    pub(crate) fn is_inline(&self, num_args: usize) -> bool {
+        num_args <= self.max_inline_args
+    }
+}
+
+impl Default for MacroFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
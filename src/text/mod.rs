@@ -1,2 +1,7 @@
+pub(crate) mod base64;
+pub(crate) mod float_format;
+pub(crate) mod macro_format;
+pub(crate) mod pretty_config;
+pub(crate) mod symbol_id_format;
 pub(crate) mod text_formatter;
 pub(crate) mod whitespace_config;
@@ -0,0 +1,115 @@
+use crate::text::whitespace_config::{WhitespaceConfig, PRETTY_WHITESPACE_CONFIG};
+
+/// How the text writer lays out values when it is configured to use
+/// [`TextFormat::Pretty`](crate::TextFormat::Pretty). Other formats ignore these settings.
+///
+/// The default settings indent nested values by two spaces, put each value in a container on its
+/// own line, follow each value in a list or struct with a comma, and follow each field name with a
+/// space.
+///
+/// ```
+/// # use ion_rs::*;
+/// # fn main() -> IonResult<()> {
+/// let pretty_config = PrettyConfig::new()
+///     .with_indentation("    ")
+///     .with_max_inline_width(20)
+///     .with_trailing_commas(false);
+/// let config = WriteConfig::<v1_0::Text>::new(TextFormat::Pretty).with_pretty_config(pretty_config);
+/// let element = Element::read_one("{name: \"Ion\", versions: [1.0, 1.1], first_release: 2016}")?;
+/// let text = element.encode_as(config)?;
+/// assert_eq!(
+///     text,
+///     "{\n    name: \"Ion\",\n    versions: [1.0, 1.1],\n    first_release: 2016\n}\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PrettyConfig {
+    indentation: &'static str,
+    max_inline_width: usize,
+    trailing_commas: bool,
+    space_after_colon: bool,
+}
+
+impl PrettyConfig {
+    /// Returns the default layout.
+    pub const fn new() -> Self {
+        Self {
+            indentation: "  ",
+            max_inline_width: 0,
+            trailing_commas: true,
+            space_after_colon: true,
+        }
+    }
+
+    /// Sets the text that is written once per level of nesting before each nested value, like
+    /// `"    "` or `"\t"`.
+    pub const fn with_indentation(mut self, indentation: &'static str) -> Self {
+        self.indentation = indentation;
+        self
+    }
+
+    /// Writes a container on a single line if it (including any nested values) can be written in
+    /// at most `max_inline_width` characters. Other containers have one value per line. By
+    /// default, the limit is zero and every container is written across several lines.
+    ///
+    /// The writer buffers each top-level container until it is complete so that it can measure
+    /// the containers nested inside it.
+    pub const fn with_max_inline_width(mut self, max_inline_width: usize) -> Self {
+        self.max_inline_width = max_inline_width;
+        self
+    }
+
+    /// Sets whether the last value in a list or struct that spans several lines is followed by a
+    /// comma. Containers written on a single line never have a trailing comma.
+    ///
+    /// Like [`with_max_inline_width`](Self::with_max_inline_width), disabling trailing commas
+    /// causes the writer to buffer each top-level container until it is complete.
+    pub const fn with_trailing_commas(mut self, trailing_commas: bool) -> Self {
+        self.trailing_commas = trailing_commas;
+        self
+    }
+
+    /// Sets whether a space is written between a field name's `:` and the field's value.
+    pub const fn with_space_after_colon(mut self, space_after_colon: bool) -> Self {
+        self.space_after_colon = space_after_colon;
+        self
+    }
+
+    pub const fn indentation(&self) -> &'static str {
+        self.indentation
+    }
+
+    pub const fn max_inline_width(&self) -> usize {
+        self.max_inline_width
+    }
+
+    pub const fn writes_trailing_commas(&self) -> bool {
+        self.trailing_commas
+    }
+
+    pub const fn writes_space_after_colon(&self) -> bool {
+        self.space_after_colon
+    }
+
+    /// Returns `true` if the writer needs to see a container's contents before it can write the
+    /// container.
+    pub(crate) fn requires_layout(&self) -> bool {
+        self.max_inline_width > 0 || !self.trailing_commas
+    }
+
+    pub(crate) fn whitespace_config(&self) -> WhitespaceConfig {
+        WhitespaceConfig {
+            indentation: self.indentation,
+            space_after_field_name: if self.space_after_colon { " " } else { "" },
+            ..PRETTY_WHITESPACE_CONFIG
+        }
+    }
+}
+
+impl Default for PrettyConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
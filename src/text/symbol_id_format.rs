@@ -0,0 +1,42 @@
+/// How a text writer represents the symbol IDs of symbols that it transcribes from another stream,
+/// as when a [`SystemWriter`](crate::SystemWriter) converts binary Ion to text for debugging.
+///
+/// Other writers are given symbol text rather than symbol IDs, so this setting does not affect
+/// them.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-tooling-apis")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::*;
+///
+/// let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+/// writer.write_symbol("foo")?;
+/// let binary = writer.close()?;
+///
+/// let config = WriteConfig::<v1_0::Text>::new(TextFormat::Compact)
+///     .with_symbol_id_format(SymbolIdFormat::Comment);
+/// let mut writer = SystemWriter::new(config, Vec::new())?;
+/// writer.transcribe(&mut SystemReader::new(AnyEncoding, binary.as_slice()))?;
+/// let text = String::from_utf8(writer.close()?).unwrap();
+/// assert!(text.contains("foo /*$10*/"));
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-tooling-apis"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum SymbolIdFormat {
+    /// Symbols are written using their text. Symbols whose text is unknown are written as `$0`.
+    /// This is the default.
+    #[default]
+    Omit,
+    /// Symbols are written using their text. Symbols whose text is unknown are written using the
+    /// symbol ID that the input stream used for them (e.g. `$123`), which is only meaningful in
+    /// the context of that stream.
+    PreserveUnknown,
+    /// Like [`PreserveUnknown`](Self::PreserveUnknown), but each symbol with known text that was
+    /// encoded as a symbol ID is followed by a comment showing that ID (e.g. `name /*$10*/`).
+    Comment,
+}
@@ -1,6 +1,7 @@
 use crate::constants::v1_1;
 use crate::raw_symbol_ref::{AsRawSymbolRef, RawSymbolRef};
 use crate::result::IonFailure;
+use crate::text::float_format::{FloatFormat, FormattedFloat};
 use crate::{Annotations, Sequence};
 use crate::{Decimal, Int, Struct, Timestamp};
 use crate::{IonResult, IonType};
@@ -181,6 +182,66 @@ pub(crate) const fn string_escape_code_init() -> [&'static str; 256] {
     string_escape_codes
 }
 
+/// Escape sequences for each byte that must be escaped in the body of a string or symbol.
+/// Bytes that can be written as-is map to the empty string.
+pub(crate) static TEXT_ESCAPE_SEQUENCES: [&str; 256] = text_escape_sequences_init();
+
+const fn text_escape_sequences_init() -> [&'static str; 256] {
+    let mut escape_sequences = [""; 256];
+    escape_sequences[b'\n' as usize] = r"\n";
+    escape_sequences[b'\r' as usize] = r"\r";
+    escape_sequences[b'\t' as usize] = r"\t";
+    escape_sequences[b'\\' as usize] = r"\\";
+    escape_sequences[b'"' as usize] = r#"\""#;
+    escape_sequences[b'\'' as usize] = r"\'";
+    escape_sequences[0x00] = r"\0"; // NUL
+    escape_sequences[0x07] = r"\a"; // alert BEL
+    escape_sequences[0x08] = r"\b"; // backspace
+    escape_sequences[0x0B] = r"\v"; // vertical tab
+    escape_sequences[0x0C] = r"\f"; // form feed
+    escape_sequences
+}
+
+/// Returns the index of the first byte in `bytes` that must be escaped when writing the body of a
+/// string or symbol, or `None` if the text can be written as-is.
+///
+/// Most text has long runs that require no escaping. Rather than examining each character, this
+/// tests eight bytes at a time for any byte that _might_ need escaping and only inspects
+/// individual bytes once a candidate is found.
+#[inline]
+pub(crate) fn find_next_text_escape(bytes: &[u8]) -> Option<usize> {
+    const ONES: u64 = u64::from_ne_bytes([0x01; 8]);
+    const HIGH_BITS: u64 = u64::from_ne_bytes([0x80; 8]);
+    // Sets the high bit of each byte in `word` that is less than `n`. (Valid for `n` <= 128.)
+    // Bytes with their high bit already set (i.e. non-ASCII) are never flagged.
+    const fn bytes_less_than(word: u64, n: u8) -> u64 {
+        word.wrapping_sub(ONES * n as u64) & !word & HIGH_BITS
+    }
+    // Sets the high bit of each byte in `word` that is equal to `byte`.
+    const fn bytes_equal_to(word: u64, byte: u8) -> u64 {
+        bytes_less_than(word ^ (ONES * byte as u64), 1)
+    }
+
+    let mut index = 0;
+    while let Some(chunk) = bytes[index..].first_chunk::<{ size_of::<u64>() }>() {
+        let word = u64::from_ne_bytes(*chunk);
+        // Every control character that requires escaping is less than 0x0E; other bytes in that
+        // range are false positives that the byte-by-byte check below will skip.
+        let candidates = bytes_less_than(word, 0x0E)
+            | bytes_equal_to(word, b'\\')
+            | bytes_equal_to(word, b'"')
+            | bytes_equal_to(word, b'\'');
+        if candidates != 0 {
+            break;
+        }
+        index += size_of::<u64>();
+    }
+    bytes[index..]
+        .iter()
+        .position(|byte| !TEXT_ESCAPE_SEQUENCES[*byte as usize].is_empty())
+        .map(|position| index + position)
+}
+
 /// Provides a text formatter for Ion values
 /// This is used with the Display implementation of `OwnedElement`
 pub struct FmtValueFormatter<'a, W: fmt::Write> {
@@ -307,34 +368,17 @@ impl<W: fmt::Write> FmtValueFormatter<'_, W> {
     /// Writes the body (i.e. no start or end delimiters) of a string or symbol with any illegal
     /// characters escaped.
     pub(crate) fn format_escaped_text_body<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
-        let mut start = 0usize;
-        let text = value.as_ref();
-        for (byte_index, character) in text.char_indices() {
-            let escaped = match character {
-                '\n' => r"\n",
-                '\r' => r"\r",
-                '\t' => r"\t",
-                '\\' => r"\\",
-                '"' => r#"\""#,
-                '\'' => r"\'",
-                '\x00' => r"\0", // NUL
-                '\x07' => r"\a", // alert BEL
-                '\x08' => r"\b", // backspace
-                '\x0B' => r"\v", // vertical tab
-                '\x0C' => r"\f", // form feed
-                _ => {
-                    // Other characters can be left as-is
-                    continue;
-                }
-            };
-            // If we reach this point, the current character needed to be escaped.
-            // Write all of the text leading up to this character to output, then the escaped
-            // version of this character.
-            write!(self.output, "{}{}", &text[start..byte_index], escaped)?;
-            // Update `start` to point to the first byte after the end of this character.
-            start = byte_index + character.len_utf8();
+        let mut remaining = value.as_ref();
+        while let Some(index) = find_next_text_escape(remaining.as_bytes()) {
+            // Write all of the text leading up to the byte that needs escaping, then its escape
+            // sequence. Every character that needs escaping is a single ASCII byte, so `index + 1`
+            // is always a character boundary.
+            let escape_sequence = TEXT_ESCAPE_SEQUENCES[remaining.as_bytes()[index] as usize];
+            self.output.write_str(&remaining[..index])?;
+            self.output.write_str(escape_sequence)?;
+            remaining = &remaining[index + 1..];
         }
-        write!(self.output, "{}", &text[start..])?;
+        self.output.write_str(remaining)?;
         Ok(())
     }
 
@@ -382,29 +426,11 @@ impl<W: fmt::Write> FmtValueFormatter<'_, W> {
     }
 
     pub fn format_float(&mut self, value: f64) -> IonResult<()> {
-        if value.is_nan() {
-            write!(self.output, "nan")?;
-            return Ok(());
-        }
-
-        if value.is_infinite() {
-            if value.is_sign_positive() {
-                write!(self.output, "+inf")?;
-            } else {
-                write!(self.output, "-inf")?;
-            }
-            return Ok(());
-        }
-
-        // The {:e} formatter provided by the Display trait writes floats using scientific
-        // notation. It works for all floating point values except -0.0 (it drops the sign).
-        // See: https://github.com/rust-lang/rust/issues/20596
-        if value == 0.0f64 && value.is_sign_negative() {
-            write!(self.output, "-0e0")?;
-            return Ok(());
-        }
-
-        write!(self.output, "{value:e}")?;
+        write!(
+            self.output,
+            "{}",
+            FormattedFloat::new(value, FloatFormat::default())
+        )?;
         Ok(())
     }
 
@@ -454,7 +480,7 @@ impl<W: fmt::Write> FmtValueFormatter<'_, W> {
     }
 
     pub fn format_blob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
-        write!(self.output, "{{{{{}}}}}", base64::encode(value))?;
+        write!(self.output, "{{{{{}}}}}", crate::text::base64::encode(value))?;
         Ok(())
     }
 
@@ -505,7 +531,7 @@ impl<W: fmt::Write> FmtValueFormatter<'_, W> {
 
 #[cfg(test)]
 mod formatter_test {
-    use crate::text::text_formatter::FmtValueFormatter;
+    use crate::text::text_formatter::{find_next_text_escape, FmtValueFormatter};
     use crate::{ion_list, ion_sexp, ion_struct, IonResult, IonType, Timestamp};
 
     fn formatter<F>(mut f: F, expected: &str)
@@ -585,9 +611,37 @@ mod formatter_test {
         formatter(|ivf| ivf.format_string("\t"), "\"\\t\""); // tab is escaped
         formatter(|ivf| ivf.format_string("\0"), "\"\\0\""); // NUL is escaped
 
+        // Escapes found at various positions relative to the 8-byte chunks being scanned
+        formatter(
+            |ivf| ivf.format_string("0123456\"89abcdef\nhij"),
+            "\"0123456\\\"89abcdef\\nhij\"",
+        );
+        formatter(
+            |ivf| ivf.format_string("\x01\x0E unescaped control characters"),
+            "\"\x01\x0E unescaped control characters\"",
+        );
+        formatter(
+            |ivf| ivf.format_string("naïve café résumé, 'quoted'"),
+            "\"naïve café résumé, \\'quoted\\'\"",
+        );
+
         Ok(())
     }
 
+    #[test]
+    fn find_next_text_escape_at_each_position() {
+        for length in 0..40 {
+            for position in 0..length {
+                for escaped_byte in [b'\n', b'\'', b'"', b'\\', 0x00, 0x0C] {
+                    let mut text = vec![b'a'; length];
+                    text[position] = escaped_byte;
+                    assert_eq!(find_next_text_escape(&text), Some(position));
+                }
+            }
+            assert_eq!(find_next_text_escape(&vec![b'a'; length]), None);
+        }
+    }
+
     #[test]
     fn test_format_blob() -> IonResult<()> {
         formatter(|ivf| ivf.format_blob("hello".as_bytes()), "{{aGVsbG8=}}");
@@ -564,55 +564,103 @@ impl Display for Decimal {
 
 #[cfg(feature = "bigdecimal")]
 mod bigdecimal {
-    use crate::decimal::coefficient::Sign;
     use crate::result::IonFailure;
     use crate::{Decimal, IonError, IonResult};
     use bigdecimal::num_bigint::BigInt;
     use bigdecimal::BigDecimal;
     use num_traits::ToPrimitive;
 
-    impl TryInto<BigDecimal> for Decimal {
+    impl Decimal {
+        /// Converts this `Decimal` to a [`BigDecimal`] with the same coefficient and exponent,
+        /// treating negative zero as positive zero.
+        ///
+        /// `BigDecimal` cannot represent `-0`, so the [`TryFrom`] conversion rejects it. Callers that
+        /// need to restore negative zero later can check
+        /// [`Coefficient::is_negative_zero`](crate::decimal::coefficient::Coefficient::is_negative_zero)
+        /// before converting and use [`Decimal::negative_zero_with_exponent`] on the way back.
+        ///
+        /// ```
+        /// # use ion_rs::IonResult;
+        /// # #[cfg(feature = "bigdecimal")]
+        /// # fn main() -> IonResult<()> {
+        /// use bigdecimal::BigDecimal;
+        /// use ion_rs::Decimal;
+        ///
+        /// let negative_zero = Decimal::negative_zero_with_exponent(-2);
+        /// assert!(BigDecimal::try_from(negative_zero).is_err());
+        /// let big_decimal = negative_zero.to_big_decimal_lossy()?;
+        /// assert_eq!(Decimal::try_from(big_decimal)?, Decimal::new(0, -2));
+        /// # Ok(())
+        /// # }
+        /// # #[cfg(not(feature = "bigdecimal"))]
+        /// # fn main() {}
+        /// ```
+        pub fn to_big_decimal_lossy(&self) -> IonResult<BigDecimal> {
+            let Some(scale) = self.exponent.checked_neg() else {
+                return IonResult::illegal_operation(
+                    "Cannot convert a Decimal with an exponent of i64::MIN to BigDecimal.",
+                );
+            };
+            // The coefficient's value is zero when its sign is negative zero.
+            Ok(BigDecimal::new(BigInt::from(self.coefficient_value.data), scale))
+        }
+    }
+
+    impl TryFrom<&Decimal> for BigDecimal {
         type Error = IonError;
 
-        /// Attempts to create a BigDecimal from a Decimal. Returns an Error if the Decimal being
-        /// converted is a special value (negative zero) or has a magnitude no representable as u128.
-        fn try_into(self) -> Result<BigDecimal, Self::Error> {
-            if self.coefficient().is_negative_zero() {
+        /// Attempts to create a BigDecimal with the same coefficient and exponent as a Decimal.
+        /// Returns an Error if the Decimal being converted is a special value (negative zero) or has
+        /// an exponent whose negation does not fit in an i64.
+        fn try_from(value: &Decimal) -> Result<Self, Self::Error> {
+            if value.coefficient().is_negative_zero() {
                 return IonResult::illegal_operation("Cannot convert negative zero to BigDecimal.");
             }
-            let magnitude = self
-                .coefficient()
-                .magnitude()
-                .as_u128()
-                .expect("All Decimal magnitudes are within u128, this is impossible");
+            value.to_big_decimal_lossy()
+        }
+    }
 
-            let bigint = match self.coefficient().sign() {
-                Sign::Negative => -BigInt::from(magnitude),
-                Sign::Positive => BigInt::from(magnitude),
-            };
-            Ok(BigDecimal::new(bigint, self.scale()))
+    impl TryFrom<Decimal> for BigDecimal {
+        type Error = IonError;
+
+        fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+            BigDecimal::try_from(&value)
         }
     }
 
-    impl TryFrom<BigDecimal> for Decimal {
+    impl TryFrom<&BigDecimal> for Decimal {
         type Error = IonError;
 
-        /// Attempts to create a Decimal from a BigDecimal. Returns an Error if the BigDecimal cannot be
-        /// represented as a Decimal in this library.
-        fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
-            let (coeff, exponent) = value.into_bigint_and_exponent();
-            let Some(data) = coeff.to_i128() else {
+        /// Attempts to create a Decimal with the same coefficient and exponent as a BigDecimal.
+        /// Returns an Error if the BigDecimal cannot be represented as a Decimal in this library.
+        fn try_from(value: &BigDecimal) -> Result<Self, Self::Error> {
+            let (coefficient, scale) = value.as_bigint_and_scale();
+            let Some(coefficient) = coefficient.to_i128() else {
                 return IonResult::illegal_operation("Cannot represent coefficient as i128.");
             };
+            let Some(exponent) = scale.checked_neg() else {
+                return IonResult::illegal_operation(
+                    "Cannot represent a BigDecimal with a scale of i64::MIN as a Decimal.",
+                );
+            };
+            Ok(Decimal::new(coefficient, exponent))
+        }
+    }
 
-            Ok(Decimal::new(data, -exponent))
+    impl TryFrom<BigDecimal> for Decimal {
+        type Error = IonError;
+
+        fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+            Decimal::try_from(&value)
         }
     }
 
     #[cfg(test)]
     mod tests {
-        use crate::Decimal;
+        use crate::ion_data::IonEq;
+        use crate::{Decimal, Int};
         use bigdecimal::BigDecimal;
+        use num_traits::ToPrimitive;
         use rstest::*;
 
         #[fixture]
@@ -672,6 +720,41 @@ mod bigdecimal {
             let actual: BigDecimal = Decimal::try_into(input).unwrap();
             assert_eq!(actual, expected);
         }
+
+        #[rstest]
+        #[case(Decimal::new(100, -2))]
+        #[case(Decimal::new(0, 5))]
+        #[case(Decimal::new(0, -5))]
+        #[case(Decimal::new(-7, i64::MAX))]
+        #[case(Decimal::new(i128::MAX, -40))]
+        #[case(Decimal::new(i128::MIN, 40))]
+        /// BigDecimal's `PartialEq` compares values numerically, so this checks that the coefficient
+        /// and exponent are both preserved.
+        fn bigdecimal_round_trip_is_exact(#[case] input: Decimal) {
+            let big_decimal = BigDecimal::try_from(&input).unwrap();
+            let (coefficient, scale) = big_decimal.as_bigint_and_scale();
+            let expected_coefficient = Int::try_from(input.coefficient()).unwrap();
+            assert_eq!(coefficient.to_i128(), expected_coefficient.as_i128());
+            assert_eq!(scale, -input.exponent());
+            let round_tripped = Decimal::try_from(big_decimal).unwrap();
+            assert!(round_tripped.ion_eq(&input));
+        }
+
+        #[test]
+        fn negative_zero_to_bigdecimal() {
+            let negative_zero = Decimal::negative_zero_with_exponent(-3);
+            assert!(BigDecimal::try_from(&negative_zero).is_err());
+            let big_decimal = negative_zero.to_big_decimal_lossy().unwrap();
+            assert_eq!(big_decimal.as_bigint_and_scale().1, 3);
+            let positive_zero = Decimal::try_from(big_decimal).unwrap();
+            assert!(positive_zero.ion_eq(&Decimal::new(0, -3)));
+        }
+
+        #[test]
+        fn unrepresentable_scale_is_an_error() {
+            assert!(BigDecimal::try_from(Decimal::new(1, i64::MIN)).is_err());
+            assert!(Decimal::try_from(BigDecimal::new(1.into(), i64::MIN)).is_err());
+        }
     }
 }
 
@@ -1,6 +1,8 @@
+use half::f16;
+
 pub(crate) enum FloatRepr {
     Zero,
-    // TODO: Half(f16)
+    Half(f16),
     Single(f32),
     Double(f64),
 }
@@ -19,8 +21,12 @@ impl SmallestFloatRepr for f32 {
             return FloatRepr::Zero;
         }
 
-        // TODO: See if we can scale down to Half(f16)
-
+        // `f32::is_finite` returns false for `NaN`, `+inf`, and `-inf`
+        let is_special_value = !self.is_finite();
+        let value_f16 = f16::from_f32(self);
+        if is_special_value || value_f16.to_f32() == self {
+            return FloatRepr::Half(value_f16);
+        }
         FloatRepr::Single(self)
     }
 }
@@ -32,11 +38,14 @@ impl SmallestFloatRepr for f64 {
             return FloatRepr::Zero;
         }
 
-        // TODO: See if we can scale down to Half(f16)
         // `f64::is_finite` returns false for `NaN`, `+inf`, and `-inf`
         let is_special_value = !self.is_finite();
+        let value_f16 = f16::from_f64(self);
+        if is_special_value || value_f16.to_f64() == self {
+            return FloatRepr::Half(value_f16);
+        }
         let value_f32 = self as f32;
-        if is_special_value || value_f32 as f64 == self {
+        if value_f32 as f64 == self {
             return FloatRepr::Single(value_f32);
         }
         FloatRepr::Double(self)
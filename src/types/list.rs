@@ -34,8 +34,13 @@ impl List {
             pub fn clone_builder(&self) -> SequenceBuilder;
             pub fn elements(&self) -> SequenceIterator<'_>;
             pub fn get(&self, index: usize) -> Option<&Element>;
+            pub fn get_mut(&mut self, index: usize) -> Option<&mut Element>;
             pub fn len(&self) -> usize;
             pub fn is_empty(&self) -> bool;
+            pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Element>;
+            pub fn push<E: Into<Element>>(&mut self, element: E);
+            pub fn insert<E: Into<Element>>(&mut self, index: usize, element: E);
+            pub fn remove(&mut self, index: usize) -> Option<Element>;
         }
     }
 }
@@ -29,7 +29,11 @@ pub use r#struct::Struct;
 pub use sexp::SExp;
 pub use string::Str;
 pub use symbol::Symbol;
-pub use timestamp::{HasDay, HasFractionalSeconds, HasHour, HasMinute, HasMonth, HasOffset, HasSeconds, HasYear, Mantissa, Timestamp, TimestampBuilder, TimestampPrecision};
+pub use timestamp::{
+    FractionalSecondsRounding, HasDay, HasFractionalSeconds, HasHour, HasMinute, HasMonth,
+    HasOffset, HasSeconds, HasYear, LeapSecondHandling, Mantissa, Timestamp, TimestampBuilder,
+    TimestampPrecision, TimestampPrecisionPolicy,
+};
 
 use crate::ion_data::{IonDataHash, IonDataOrd};
 use std::cmp::Ordering;
@@ -79,6 +79,51 @@ impl Fields {
     fn iter(&self) -> impl Iterator<Item = &(Symbol, Element)> {
         self.by_index.iter()
     }
+
+    /// Removes and returns all of the indexes associated with the given field name.
+    fn take_indexes<A: AsSymbolRef>(&mut self, field_name: A) -> Option<IndexVec> {
+        match field_name.as_symbol_ref().text() {
+            Some(text) => self.by_name.remove(text),
+            None => self.by_name.remove(&Symbol::unknown_text()),
+        }
+    }
+
+    /// Gets a mutable reference to the last value in the Struct that is associated with the
+    /// specified field name.
+    fn get_last_mut<A: AsSymbolRef>(&mut self, field_name: A) -> Option<&mut Element> {
+        let index = *self.get_indexes(field_name)?.last()?;
+        self.by_index.get_mut(index).map(|(_name, value)| value)
+    }
+
+    /// Appends a (name, value) pair to the end of the struct.
+    fn push(&mut self, field_name: Symbol, field_value: Element) {
+        self.by_name
+            .entry(field_name.clone())
+            .or_default()
+            .push(self.by_index.len());
+        self.by_index.push((field_name, field_value));
+    }
+
+    /// Removes the field at the specified index, updating the name-to-index mapping of any
+    /// fields that followed it.
+    fn remove_at(&mut self, index: usize) -> (Symbol, Element) {
+        let (name, value) = self.by_index.remove(index);
+        let mut name_is_unused = false;
+        if let Some(indexes) = self.by_name.get_mut(&name) {
+            indexes.retain(|i| *i != index);
+            name_is_unused = indexes.is_empty();
+        }
+        if name_is_unused {
+            self.by_name.remove(&name);
+        }
+        // Every field after the one that was removed has moved one position to the left.
+        for indexes in self.by_name.values_mut() {
+            for i in indexes.iter_mut().filter(|i| **i > index) {
+                *i -= 1;
+            }
+        }
+        (name, value)
+    }
 }
 
 /// Iterates over the (field name, field value) pairs in a Struct.
@@ -246,6 +291,15 @@ impl Struct {
         FieldIterator::new(&self.fields.by_index)
     }
 
+    /// Returns the field at the specified position in this Struct, if it exists.
+    #[cfg_attr(not(feature = "ffi"), allow(dead_code))]
+    pub(crate) fn field_at(&self, index: usize) -> Option<(&Symbol, &Element)> {
+        self.fields
+            .by_index
+            .get(index)
+            .map(|(name, element)| (name, element))
+    }
+
     /// Returns the value associated with the specified field name.
     ///
     /// If more than one field in this struct has that name, this method will return the value of
@@ -259,6 +313,84 @@ impl Struct {
     pub fn get_all<A: AsSymbolRef>(&self, field_name: A) -> impl Iterator<Item = &Element> {
         self.fields.get_all(field_name)
     }
+
+    /// Returns a mutable reference to the value associated with the specified field name.
+    ///
+    /// As with [`get`](Self::get), if more than one field in this struct has that name, the value
+    /// of the _last_ field with that name is returned.
+    pub fn get_mut<A: AsSymbolRef>(&mut self, field_name: A) -> Option<&mut Element> {
+        self.fields.get_last_mut(field_name)
+    }
+
+    /// Sets the value of the specified field, returning the value it replaced (if any).
+    ///
+    /// If the struct already contains a field with that name, the value of the _last_ such field
+    /// is replaced in place and the field keeps its position. Otherwise, a new field is appended
+    /// to the end of the struct.
+    ///
+    /// ```
+    /// use ion_rs::{ion_struct, Element};
+    ///
+    /// let mut struct_ = ion_struct! { "foo": 1, "bar": 2 };
+    /// assert_eq!(struct_.insert("foo", 10), Some(Element::int(1)));
+    /// assert_eq!(struct_.insert("baz", 3), None);
+    /// assert_eq!(struct_.remove("bar"), Some(Element::int(2)));
+    /// assert!(struct_.rename_field("baz", "quux"));
+    /// assert_eq!(struct_, ion_struct! { "foo": 10, "quux": 3 });
+    /// ```
+    pub fn insert<S: Into<Symbol>, E: Into<Element>>(
+        &mut self,
+        field_name: S,
+        field_value: E,
+    ) -> Option<Element> {
+        let field_name = field_name.into();
+        let field_value = field_value.into();
+        match self.fields.get_last_mut(&field_name) {
+            Some(value) => Some(std::mem::replace(value, field_value)),
+            None => {
+                self.fields.push(field_name, field_value);
+                None
+            }
+        }
+    }
+
+    /// Appends a field to the end of the struct, even if the struct already contains a field with
+    /// the same name.
+    pub fn push_field<S: Into<Symbol>, E: Into<Element>>(&mut self, field_name: S, field_value: E) {
+        self.fields.push(field_name.into(), field_value.into());
+    }
+
+    /// Removes the field with the specified name from the struct, returning its value.
+    ///
+    /// As with [`get`](Self::get), if more than one field in this struct has that name, only the
+    /// _last_ field with that name is removed.
+    pub fn remove<A: AsSymbolRef>(&mut self, field_name: A) -> Option<Element> {
+        let index = *self.fields.get_indexes(field_name)?.last()?;
+        let (_name, value) = self.fields.remove_at(index);
+        Some(value)
+    }
+
+    /// Changes the name of every field called `old_name` to `new_name`. The renamed fields keep
+    /// their positions and values. Returns `true` if any fields were renamed.
+    pub fn rename_field<A: AsSymbolRef, S: Into<Symbol>>(
+        &mut self,
+        old_name: A,
+        new_name: S,
+    ) -> bool {
+        let indexes = match self.fields.take_indexes(old_name) {
+            Some(indexes) => indexes,
+            None => return false,
+        };
+        let new_name = new_name.into();
+        for index in &indexes {
+            self.fields.by_index[*index].0 = new_name.clone();
+        }
+        let new_name_indexes = self.fields.by_name.entry(new_name).or_default();
+        new_name_indexes.extend(indexes);
+        // Keep the indexes in field order so the last index always refers to the last field.
+        new_name_indexes.sort_unstable();
+        true
+    }
 }
 
 // Allows `for (name, value) in &my_struct {...}` syntax
@@ -402,4 +534,59 @@ mod tests {
         }
         assert_eq!(baz_value, Some(Element::int(3)));
     }
+
+    #[test]
+    fn insert_replaces_last_field_with_name() {
+        let mut s = ion_struct! { "foo": 1, "bar": 2, "foo": 3 };
+        assert_eq!(s.insert("foo", 4), Some(Element::int(3)));
+        assert_eq!(s.insert("baz", 5), None);
+        assert_eq!(
+            s.get_all("foo").collect::<Vec<_>>(),
+            vec![&1.into(), &4.into()]
+        );
+        let fields: Vec<_> = s.iter().map(|(name, _)| name.text().unwrap()).collect();
+        assert_eq!(fields, vec!["foo", "bar", "foo", "baz"]);
+    }
+
+    #[test]
+    fn remove_updates_remaining_fields() {
+        let mut s = ion_struct! { "foo": 1, "bar": 2, "foo": 3, "baz": 4 };
+        assert_eq!(s.remove("foo"), Some(Element::int(3)));
+        assert_eq!(s.get("baz"), Some(&Element::int(4)));
+        assert_eq!(s.remove("bar"), Some(Element::int(2)));
+        assert_eq!(s.get("foo"), Some(&Element::int(1)));
+        assert_eq!(s.get("baz"), Some(&Element::int(4)));
+        assert_eq!(s.remove("foo"), Some(Element::int(1)));
+        assert_eq!(s.remove("foo"), None);
+        assert_eq!(s, ion_struct! { "baz": 4 });
+        s.push_field("baz", 5);
+        assert_eq!(s.remove("baz"), Some(Element::int(5)));
+        assert_eq!(s.get("baz"), Some(&Element::int(4)));
+    }
+
+    #[test]
+    fn rename_field_merges_with_existing_names() {
+        let mut s = ion_struct! { "a": 1, "b": 2, "a": 3, "c": 4 };
+        assert!(s.rename_field("c", "a"));
+        assert!(!s.rename_field("missing", "a"));
+        assert_eq!(s.get("a"), Some(&Element::int(4)));
+        assert_eq!(s.get("c"), None);
+        assert!(s.rename_field("a", "d"));
+        assert_eq!(s.get_all("d").count(), 3);
+        assert_eq!(s, ion_struct! { "d": 1, "b": 2, "d": 3, "d": 4 });
+    }
+
+    #[test]
+    fn get_mut_modifies_field_in_place() {
+        let mut s = ion_struct! { "foo": 1, "bar": ion_struct! { "baz": 2 } };
+        *s.get_mut("foo").unwrap() = Element::string("one");
+        s.get_mut("bar")
+            .and_then(Element::as_struct_mut)
+            .unwrap()
+            .insert("quux", 3);
+        assert_eq!(
+            s,
+            ion_struct! { "foo": "one", "bar": ion_struct! { "baz": 2, "quux": 3 } }
+        );
+    }
 }
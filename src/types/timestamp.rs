@@ -29,6 +29,94 @@ pub enum TimestampPrecision {
     Second,
 }
 
+/// Controls how a writer adjusts the fractional seconds of each [`Timestamp`] before encoding it.
+/// Only timestamps with [`TimestampPrecision::Second`] are affected; timestamps with a coarser
+/// precision are always written as-is.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default, Hash)]
+#[non_exhaustive]
+pub enum TimestampPrecisionPolicy {
+    /// Timestamps are written with the precision they already have. This is the default.
+    #[default]
+    Preserve,
+    /// Timestamps are written with exactly the specified number of fractional second digits,
+    /// truncating any digits beyond it or padding with zeros as needed. A value of `0` drops
+    /// fractional seconds entirely. Values greater than `9` are treated as `9`.
+    FractionalDigits(u8),
+    /// Timestamps are written with at most the specified number of fractional second digits;
+    /// any digits beyond it are truncated. Timestamps with fewer digits are not padded. Values
+    /// greater than `9` are treated as `9`.
+    MaxFractionalDigits(u8),
+}
+
+impl TimestampPrecisionPolicy {
+    /// Returns a copy of `timestamp` with its fractional seconds adjusted according to this policy.
+    pub(crate) fn apply(&self, timestamp: &Timestamp) -> Timestamp {
+        const MAX_DIGITS: u8 = 9;
+        if timestamp.precision != TimestampPrecision::Second {
+            return *timestamp;
+        }
+        let current_digits = timestamp.fractional_seconds_scale().unwrap_or(0).max(0);
+        let target_digits = match *self {
+            TimestampPrecisionPolicy::Preserve => return *timestamp,
+            TimestampPrecisionPolicy::FractionalDigits(digits) => digits.min(MAX_DIGITS),
+            TimestampPrecisionPolicy::MaxFractionalDigits(digits) => {
+                if current_digits <= i64::from(digits) {
+                    return *timestamp;
+                }
+                digits.min(MAX_DIGITS)
+            }
+        };
+        if current_digits == i64::from(target_digits) {
+            return *timestamp;
+        }
+        let fraction = timestamp.fractional_seconds_with_precision(
+            target_digits as u32,
+            FractionalSecondsRounding::Truncate,
+        );
+        timestamp
+            .with_fraction(fraction)
+            .expect("a truncated fraction of a second-precision timestamp is always valid")
+    }
+}
+
+/// How [`Timestamp::fractional_seconds_with_precision`] handles digits beyond the requested
+/// precision.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default, Hash)]
+#[non_exhaustive]
+pub enum FractionalSecondsRounding {
+    /// Digits beyond the requested precision are discarded. This is the default.
+    #[default]
+    Truncate,
+    /// The value is rounded up if any discarded digit is non-zero.
+    Ceiling,
+    /// The value is rounded to the nearest unit; ties are rounded up.
+    HalfUp,
+    /// The value is rounded to the nearest unit; ties are rounded to the nearest even unit.
+    HalfEven,
+}
+
+/// Controls how readers handle timestamps whose seconds field is `60`, which denotes a leap
+/// second. Ion's grammar only permits seconds from `00` to `59`, but data captured from some
+/// real-world sources records leap seconds as they occurred.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default, Hash)]
+#[non_exhaustive]
+pub enum LeapSecondHandling {
+    /// Timestamps with a seconds field of `60` are rejected with an error. This is the default.
+    #[default]
+    Reject,
+    /// A seconds field of `60` is read as `59`. Any fractional seconds are kept, so
+    /// `23:59:60.5Z` is read as `23:59:59.5Z`.
+    Clamp,
+    /// The leap second is kept; [`Timestamp::second`] will return `60` and the timestamp will be
+    /// written back out with a seconds field of `60`. A leap second sorts after every instant
+    /// in the second that precedes it.
+    Preserve,
+}
+
+// The number of nanoseconds in a second. chrono represents a leap second as the 59th second of a
+// minute with this many nanoseconds added.
+const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+
 // [Default] cannot be derived for enum types. Providing a manual implementation of this type
 // allows us to derive Default for [Timestamp].
 
@@ -153,6 +241,171 @@ impl Timestamp {
         timestamp
     }
 
+    /// Returns a copy of this Timestamp with its fractional seconds set to the specified number of
+    /// milliseconds. If the Timestamp does not have [`TimestampPrecision::Second`] or
+    /// `milliseconds` is not less than `1_000`, returns an `Err`.
+    pub fn with_milliseconds(&self, milliseconds: u32) -> IonResult<Timestamp> {
+        self.with_fractional_seconds_in_units(milliseconds, 3)
+    }
+
+    /// Returns a copy of this Timestamp with its fractional seconds set to the specified number of
+    /// microseconds. If the Timestamp does not have [`TimestampPrecision::Second`] or
+    /// `microseconds` is not less than `1_000_000`, returns an `Err`.
+    pub fn with_microseconds(&self, microseconds: u32) -> IonResult<Timestamp> {
+        self.with_fractional_seconds_in_units(microseconds, 6)
+    }
+
+    /// Returns a copy of this Timestamp with its fractional seconds set to the specified number of
+    /// nanoseconds. If the Timestamp does not have [`TimestampPrecision::Second`] or
+    /// `nanoseconds` is not less than `1_000_000_000`, returns an `Err`.
+    pub fn with_nanoseconds(&self, nanoseconds: u32) -> IonResult<Timestamp> {
+        self.with_fractional_seconds_in_units(nanoseconds, 9)
+    }
+
+    /// Returns a copy of this Timestamp with its fractional seconds set to `fraction`, which may
+    /// have any precision. The fraction's exponent determines the precision of the resulting
+    /// Timestamp; for example, `Decimal::new(50, -3)` produces a fractional seconds value of
+    /// `.050`. If the Timestamp does not have [`TimestampPrecision::Second`] or `fraction` is not
+    /// in the range `>= 0` and `< 1`, returns an `Err`.
+    pub fn with_fraction(&self, fraction: Decimal) -> IonResult<Timestamp> {
+        self.expect_second_precision()?;
+        if fraction.is_less_than_zero() || fraction.is_greater_than_or_equal_to_one() {
+            return IonResult::illegal_operation(format!(
+                "fractional seconds must be >= 0 and < 1; found {fraction}"
+            ));
+        }
+        let mut timestamp = *self;
+        let scale = fraction.scale();
+        if scale <= 0 {
+            // The fraction is zero with no digits after the decimal point.
+            timestamp.date_time = self
+                .date_time
+                .with_nanosecond(self.leap_nanoseconds())
+                .unwrap();
+            timestamp.fractional_seconds = None;
+        } else if scale <= 9 {
+            // The fraction can be stored in the `date_time` as a number of nanoseconds.
+            let units = fraction.coefficient().magnitude().data as u32;
+            return self.with_fractional_seconds_in_units(units, scale as u32);
+        } else {
+            timestamp.date_time = self
+                .date_time
+                .with_nanosecond(self.leap_nanoseconds())
+                .unwrap();
+            timestamp.fractional_seconds = Some(Mantissa::Arbitrary(fraction));
+        }
+        Ok(timestamp)
+    }
+
+    /// Returns a copy of this Timestamp with `digits` (at most 9) digits of fractional seconds
+    /// whose value is `units` (e.g. milliseconds when `digits` is `3`).
+    fn with_fractional_seconds_in_units(&self, units: u32, digits: u32) -> IonResult<Timestamp> {
+        self.expect_second_precision()?;
+        let units_per_second = 10u32.pow(digits);
+        if units >= units_per_second {
+            return IonResult::illegal_operation(format!(
+                "fractional seconds with {digits} digits must be less than {units_per_second}; found {units}"
+            ));
+        }
+        let mut timestamp = *self;
+        timestamp.date_time = self
+            .date_time
+            .with_nanosecond(units * 10u32.pow(9 - digits) + self.leap_nanoseconds())
+            .unwrap();
+        timestamp.fractional_seconds = match digits {
+            0 => None,
+            digits => Some(Mantissa::Digits(digits)),
+        };
+        Ok(timestamp)
+    }
+
+    /// Returns the nanoseconds stored in `date_time`, excluding the extra second that chrono adds
+    /// to represent a leap second.
+    pub(crate) fn subsecond_nanoseconds(&self) -> u32 {
+        self.date_time.nanosecond() % NANOSECONDS_PER_SECOND
+    }
+
+    /// Returns the extra second (in nanoseconds) that chrono adds to `date_time` to represent a
+    /// leap second, or `0` if this Timestamp is not a leap second.
+    fn leap_nanoseconds(&self) -> u32 {
+        self.date_time.nanosecond() - self.subsecond_nanoseconds()
+    }
+
+    fn expect_second_precision(&self) -> IonResult<()> {
+        if self.precision != TimestampPrecision::Second {
+            return IonResult::illegal_operation(format!(
+                "cannot set the fractional seconds of a timestamp with {:?} precision",
+                self.precision
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns this Timestamp's fractional seconds as a Decimal with exactly `digits` digits after
+    /// the decimal point, using `rounding` to handle any digits beyond the requested precision.
+    /// Timestamps without fractional seconds return zero.
+    ///
+    /// Rounding up can carry into the seconds; for example, `.9996` rounded to 3 digits with
+    /// [`FractionalSecondsRounding::HalfUp`] returns `1.000`. Values of `digits` greater than 38
+    /// are treated as 38.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::{Decimal, FractionalSecondsRounding, Timestamp};
+    /// let timestamp = Timestamp::with_ymd(2024, 6, 15)
+    ///     .with_hms(12, 30, 45)
+    ///     .with_microseconds(123_567)
+    ///     .build()?;
+    /// let truncated = timestamp.fractional_seconds_with_precision(3, FractionalSecondsRounding::Truncate);
+    /// assert_eq!(truncated, Decimal::new(123, -3));
+    /// let rounded = timestamp.fractional_seconds_with_precision(3, FractionalSecondsRounding::HalfUp);
+    /// assert_eq!(rounded, Decimal::new(124, -3));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fractional_seconds_with_precision(
+        &self,
+        digits: u32,
+        rounding: FractionalSecondsRounding,
+    ) -> Decimal {
+        // The largest power of ten that fits in the i128 used to store a Decimal's coefficient.
+        const MAX_DIGITS: u32 = 38;
+        let digits = digits.min(MAX_DIGITS);
+        let exponent = -i64::from(digits);
+        let Some(fraction) = self.fractional_seconds_as_decimal() else {
+            return Decimal::new(0, exponent);
+        };
+        let magnitude = fraction.coefficient().magnitude().data;
+        let scale = fraction.scale().max(0);
+        if scale <= i64::from(digits) {
+            // The fraction is less than 1, so its magnitude is less than `10^scale` and this
+            // cannot overflow.
+            let padded = magnitude * 10u128.pow(digits - scale as u32);
+            return Decimal::new(padded as i128, exponent);
+        }
+        let (quotient, remainder, divisor) =
+            match 10u128.checked_pow((scale - i64::from(digits)) as u32) {
+                Some(divisor) => (magnitude / divisor, magnitude % divisor, Some(divisor)),
+                // The divisor is larger than any magnitude; every digit is discarded.
+                None => (0, magnitude, None),
+            };
+        use FractionalSecondsRounding::*;
+        let round_up = match (rounding, divisor) {
+            (Truncate, _) => false,
+            (Ceiling, _) => remainder > 0,
+            // The remainder is always less than half of an unrepresentable divisor.
+            (HalfUp | HalfEven, None) => false,
+            (HalfUp, Some(divisor)) => remainder * 2 >= divisor,
+            (HalfEven, Some(divisor)) => match (remainder * 2).cmp(&divisor) {
+                Ordering::Greater => true,
+                Ordering::Equal => quotient % 2 == 1,
+                Ordering::Less => false,
+            },
+        };
+        Decimal::new((quotient + u128::from(round_up)) as i128, exponent)
+    }
+
     /// If the precision is [TimestampPrecision::Second], returns the Decimal scale of this Timestamp's
     /// fractional seconds; otherwise, returns None.
     ///
@@ -185,7 +438,7 @@ impl Timestamp {
             // We'll need to convert the date_time's nanoseconds to a Decimal and return it.
             Some(Digits(number_of_digits)) => {
                 const MAX_NANOSECOND_DIGITS: u32 = 9; // If it were 10, it'd be > a second
-                let nanoseconds = self.date_time.nanosecond();
+                let nanoseconds = self.subsecond_nanoseconds();
                 let leading_zeros = MAX_NANOSECOND_DIGITS - nanoseconds.count_decimal_digits();
                 let coefficient = if leading_zeros >= *number_of_digits {
                     0
@@ -216,7 +469,7 @@ impl Timestamp {
             // This timestamp already stores its fractional seconds in its `date_time` field.
             // We can ignore the `number_of_digits` (which tracks its precision) and simply return
             // `self.date_time`'s nanoseconds.
-            Some(Digits(_number_of_digits)) => Some(self.date_time.nanosecond()),
+            Some(Digits(_number_of_digits)) => Some(self.subsecond_nanoseconds()),
             // This timestamp stores its fractional seconds as a Decimal. Down-convert it to a u32
             // representing the number of nanoseconds.
             Some(Arbitrary(decimal)) => {
@@ -226,10 +479,12 @@ impl Timestamp {
                 let magnitude = match &decimal.coefficient().magnitude().data {
                     m if *m >= NANOSECONDS_PER_SECOND => {
                         // The coefficient is more precise than nanoseconds. We need to truncate a
-                        // copy of it.
-                        m.div(10f64.powi(exponent_delta.abs() as i32) as u128)
+                        // copy of it. Once truncated, it is already a number of nanoseconds.
+                        let nanoseconds = m
+                            .div(10f64.powi(exponent_delta.abs() as i32) as u128)
                             .to_u32()
-                            .expect("failed to convert coefficient magnitude to u32 nanos")
+                            .expect("failed to convert coefficient magnitude to u32 nanos");
+                        return Some(nanoseconds);
                     }
                     m => *m as u32,
                 };
@@ -262,8 +517,8 @@ impl Timestamp {
                 d1.cmp(&d2)
             }
             (Some(Digits(_d1)), Some(Digits(_d2))) => {
-                let d1 = self.date_time.nanosecond();
-                let d2 = other.date_time.nanosecond();
+                let d1 = self.subsecond_nanoseconds();
+                let d2 = other.subsecond_nanoseconds();
                 d1.cmp(&d2)
             }
             (Some(Arbitrary(d1)), Some(Arbitrary(d2))) => Mantissa::decimals_compare(d1, d2),
@@ -302,8 +557,8 @@ impl Timestamp {
                     // Different precisions
                     return false;
                 }
-                let d1 = first_n_digits_of(*d1, self.date_time.nanosecond());
-                let d2 = first_n_digits_of(*d2, other.date_time.nanosecond());
+                let d1 = first_n_digits_of(*d1, self.subsecond_nanoseconds());
+                let d2 = first_n_digits_of(*d2, other.subsecond_nanoseconds());
                 d1 == d2
             }
             (Arbitrary(d1), Arbitrary(d2)) => Mantissa::decimals_equal(d1, d2),
@@ -341,7 +596,7 @@ impl Timestamp {
                 // Example: if `num_digits` is 3 (that is: millisecond precision), we need to
                 // divide the nanoseconds by 10^(9-3) to get the correct precision:
                 //      123,000,000 nanoseconds / 10^(9-3) = 123 milliseconds
-                let scaled = self.date_time.nanosecond() / 10u32.pow(9 - *num_digits);
+                let scaled = self.subsecond_nanoseconds() / 10u32.pow(9 - *num_digits);
                 // If our scaled number has fewer digits than the precision states, add leading
                 // zeros to the output to make up the difference.
                 // Example: `num_digits` is 6 (microsecond precision) but our number of microseconds
@@ -438,7 +693,9 @@ impl Timestamp {
             return Ok(());
         }
 
-        write!(output, ":{:0>2}", datetime.second())?;
+        // chrono reports a leap second as second 59; `Timestamp::second` reports it as 60.
+        let second = datetime.second() + u32::from(self.is_leap_second());
+        write!(output, ":{second:0>2}")?;
         //                   ^-- delimiting colon, formatted second
         self.format_fractional_seconds(output)?;
         self.format_offset(offset_minutes, output)?;
@@ -563,9 +820,15 @@ impl Timestamp {
     }
 
     /// Returns the second(s) that has been specified in the [Timestamp].
-    /// Returns the second number from 0 to 59.
+    /// Returns the second number from 0 to 59, or 60 if the Timestamp is a leap second.
     pub fn second(&self) -> u32 {
-        self.date_time.second()
+        self.date_time.second() + u32::from(self.is_leap_second())
+    }
+
+    /// Returns `true` if this Timestamp represents a leap second (that is: its seconds field is
+    /// `60`). Leap seconds can only be read when [`LeapSecondHandling::Preserve`] is configured.
+    pub fn is_leap_second(&self) -> bool {
+        self.date_time.nanosecond() >= NANOSECONDS_PER_SECOND
     }
 
     /// Return a UTC timestamp for this [Timestamp]
@@ -636,9 +899,12 @@ impl Ord for Timestamp {
         let date_time_comparison = self_datetime.cmp(&other_datetime);
 
         match date_time_comparison {
-            // if the datetime comparison is Ordering::Equal,
-            // then return fractional seconds comparison result
-            Ordering::Equal => self.fractional_seconds_compare(other),
+            // if the datetime comparison is Ordering::Equal, a leap second sorts after the
+            // second it extends. Otherwise, return fractional seconds comparison result
+            Ordering::Equal => self
+                .is_leap_second()
+                .cmp(&other.is_leap_second())
+                .then_with(|| self.fractional_seconds_compare(other)),
             // if datetime comparison is not equal,
             // then no need to check for fractional seconds comparison
             _ => date_time_comparison,
@@ -658,7 +924,8 @@ impl PartialEq for Timestamp {
         // Timestamps with different Mantissa representations are a bit tricky to compare. Once
         // we've established that the fractional seconds match, we can compare all of the other
         // fields in the timestamp by comparing their respective `DateTime`s.
-        if !self.fractional_seconds_equal(other) {
+        if !self.fractional_seconds_equal(other) || self.is_leap_second() != other.is_leap_second()
+        {
             return false;
         }
 
@@ -719,7 +986,7 @@ impl IonEq for Timestamp {
             return true;
         }
 
-        if self_dt.second() != other_dt.second() || !self.fractional_seconds_equal(other) {
+        if self.second() != other.second() || !self.fractional_seconds_equal(other) {
             return false;
         }
 
@@ -781,7 +1048,7 @@ impl IonDataHash for Timestamp {
             self_dt.minute().hash(state);
         }
         if self.precision == TimestampPrecision::Second {
-            self_dt.second().hash(state);
+            self.second().hash(state);
 
             let fractional_seconds_scale = self.fractional_seconds_scale();
             match fractional_seconds_scale {
@@ -823,6 +1090,8 @@ pub struct TimestampBuilder<T> {
     second: u32,
     fractional_seconds: Option<Mantissa>,
     nanoseconds: Option<u32>,
+    // How a `second` value of 60 is handled by `build()`.
+    leap_second_handling: LeapSecondHandling,
 }
 
 impl<T> TimestampBuilder<T> {
@@ -842,9 +1111,17 @@ impl<T> TimestampBuilder<T> {
             second: self.second,
             fractional_seconds: self.fractional_seconds,
             nanoseconds: self.nanoseconds,
+            leap_second_handling: self.leap_second_handling,
         }
     }
 
+    /// Configures how [`Self::build`] handles a `second` value of `60`. Readers use this to apply
+    /// their configured [`LeapSecondHandling`].
+    pub(crate) fn with_leap_second_handling(mut self, handling: LeapSecondHandling) -> Self {
+        self.leap_second_handling = handling;
+        self
+    }
+
     /// Sets all of the fields on the given [`NaiveDateTime`] or [`DateTime<FixedOffset>`] using the
     /// values from the TimestampBuilder. Only those fields required by the TimestampBuilder's
     /// configured [`TimestampPrecision`] will be set.
@@ -897,7 +1174,20 @@ impl<T> TimestampBuilder<T> {
         }
 
         // If precision >= Second, the second must be set...
-        let second = self.second;
+        let mut second = self.second;
+        // chrono represents a leap second as second 59 with an extra second's worth of nanoseconds.
+        let mut leap_nanoseconds = 0;
+        if second == 60 {
+            match self.leap_second_handling {
+                // Leave the second as-is; chrono will reject it below.
+                LeapSecondHandling::Reject => {}
+                LeapSecondHandling::Clamp => second = 59,
+                LeapSecondHandling::Preserve => {
+                    second = 59;
+                    leap_nanoseconds = NANOSECONDS_PER_SECOND;
+                }
+            }
+        }
         datetime = datetime.with_second(second).ok_or_else(|| {
             IonError::illegal_operation(format!("provided second ('{second}') is invalid."))
         })?;
@@ -908,7 +1198,7 @@ impl<T> TimestampBuilder<T> {
         // field to 0. The real value will be stored in the Timestamp alongside the DateTime
         // as a Decimal.
         datetime = datetime
-            .with_nanosecond(self.nanoseconds.unwrap_or(0))
+            .with_nanosecond(self.nanoseconds.unwrap_or(0) + leap_nanoseconds)
             .ok_or_else(|| {
                 IonError::illegal_operation(format!("provided nanosecond ('{second}') is invalid"))
             })?;
@@ -1038,6 +1328,7 @@ impl TimestampBuilder<HasYear> {
             second: 0,
             fractional_seconds: None,
             nanoseconds: None,
+            leap_second_handling: LeapSecondHandling::Reject,
         }
     }
 
@@ -1204,9 +1495,13 @@ fn downconvert_to_naive_datetime_with_nanoseconds(timestamp: &Timestamp) -> Naiv
         // This operation may add or lose precision, but is necessary to conform with
         // chrono's expectations.
         let nanoseconds = timestamp.fractional_seconds_as_nanoseconds().unwrap_or(0);
-        // Copy `self.date_time` and set the copy's nanoseconds to this new value.
+        // Copy `self.date_time` and set the copy's nanoseconds to this new value, keeping the
+        // extra second that marks a leap second (if any).
         // Modifying the nanoseconds should never be invalid.
-        timestamp.date_time.with_nanosecond(nanoseconds).unwrap()
+        timestamp
+            .date_time
+            .with_nanosecond(nanoseconds + timestamp.leap_nanoseconds())
+            .unwrap()
     } else {
         // NaiveDateTime implements `Copy`
         timestamp.date_time
@@ -2066,4 +2361,139 @@ mod timestamp_tests {
         write!(&mut buf, "{ts}").unwrap();
         assert_eq!(expect, String::from_utf8(buf).unwrap());
     }
+
+    #[rstest]
+    #[case::preserve(
+        TimestampPrecisionPolicy::Preserve,
+        "2024-06-15T12:30:45.123456Z",
+        "2024-06-15T12:30:45.123456+00:00"
+    )]
+    #[case::truncate_to_millis(
+        TimestampPrecisionPolicy::FractionalDigits(3),
+        "2024-06-15T12:30:45.123456Z",
+        "2024-06-15T12:30:45.123+00:00"
+    )]
+    #[case::pad_to_millis(
+        TimestampPrecisionPolicy::FractionalDigits(3),
+        "2024-06-15T12:30:45.1Z",
+        "2024-06-15T12:30:45.100+00:00"
+    )]
+    #[case::pad_seconds_to_millis(
+        TimestampPrecisionPolicy::FractionalDigits(3),
+        "2024-06-15T12:30:45Z",
+        "2024-06-15T12:30:45.000+00:00"
+    )]
+    #[case::drop_fraction(
+        TimestampPrecisionPolicy::FractionalDigits(0),
+        "2024-06-15T12:30:45.999-05:00",
+        "2024-06-15T12:30:45-05:00"
+    )]
+    #[case::truncate_arbitrary(
+        TimestampPrecisionPolicy::FractionalDigits(9),
+        "2024-06-15T12:30:45.123456789123Z",
+        "2024-06-15T12:30:45.123456789+00:00"
+    )]
+    #[case::coarse_precision(
+        TimestampPrecisionPolicy::FractionalDigits(3),
+        "2024-06-15T12:30Z",
+        "2024-06-15T12:30+00:00"
+    )]
+    #[case::max_truncates(
+        TimestampPrecisionPolicy::MaxFractionalDigits(3),
+        "2024-06-15T12:30:45.123456Z",
+        "2024-06-15T12:30:45.123+00:00"
+    )]
+    #[case::max_does_not_pad(
+        TimestampPrecisionPolicy::MaxFractionalDigits(3),
+        "2024-06-15T12:30:45.1Z",
+        "2024-06-15T12:30:45.1+00:00"
+    )]
+    fn test_timestamp_precision_policy(
+        #[case] policy: TimestampPrecisionPolicy,
+        #[case] input: &str,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let timestamp = crate::Element::read_one(input)?.expect_timestamp()?;
+        let adjusted = policy.apply(&timestamp);
+        assert_eq!(adjusted.to_string(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_fractional_seconds() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2024, 6, 15)
+            .with_hms(12, 30, 45)
+            .with_offset(0)
+            .build()?;
+        let expect = |ts: Timestamp, text: &str| assert_eq!(ts.to_string(), text);
+        expect(
+            timestamp.with_milliseconds(7)?,
+            "2024-06-15T12:30:45.007+00:00",
+        );
+        expect(
+            timestamp.with_microseconds(7)?,
+            "2024-06-15T12:30:45.000007+00:00",
+        );
+        expect(
+            timestamp.with_nanoseconds(7)?,
+            "2024-06-15T12:30:45.000000007+00:00",
+        );
+        expect(
+            timestamp.with_fraction(Decimal::new(50, -3))?,
+            "2024-06-15T12:30:45.050+00:00",
+        );
+        expect(
+            timestamp.with_fraction(Decimal::new(27, -12))?,
+            "2024-06-15T12:30:45.000000000027+00:00",
+        );
+        expect(
+            timestamp
+                .with_nanoseconds(7)?
+                .with_fraction(Decimal::new(0, 0))?,
+            "2024-06-15T12:30:45+00:00",
+        );
+        assert_eq!(
+            timestamp.with_microseconds(123_456)?.microseconds(),
+            123_456
+        );
+
+        assert!(timestamp.with_milliseconds(1_000).is_err());
+        assert!(timestamp.with_nanoseconds(1_000_000_000).is_err());
+        assert!(timestamp.with_fraction(Decimal::new(1, 0)).is_err());
+        assert!(timestamp.with_fraction(Decimal::new(-5, -1)).is_err());
+        let minutes = Timestamp::with_ymd(2024, 6, 15)
+            .with_hour_and_minute(12, 30)
+            .build()?;
+        assert!(minutes.with_milliseconds(7).is_err());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::truncate(".123567", 3, FractionalSecondsRounding::Truncate, Decimal::new(123, -3))]
+    #[case::ceiling(".123001", 3, FractionalSecondsRounding::Ceiling, Decimal::new(124, -3))]
+    #[case::ceiling_exact(".123000", 3, FractionalSecondsRounding::Ceiling, Decimal::new(123, -3))]
+    #[case::half_up(".1235", 3, FractionalSecondsRounding::HalfUp, Decimal::new(124, -3))]
+    #[case::half_up_below(".12349", 3, FractionalSecondsRounding::HalfUp, Decimal::new(123, -3))]
+    #[case::half_even_down(".1225", 3, FractionalSecondsRounding::HalfEven, Decimal::new(122, -3))]
+    #[case::half_even_up(".1235", 3, FractionalSecondsRounding::HalfEven, Decimal::new(124, -3))]
+    #[case::carry(".9996", 3, FractionalSecondsRounding::HalfUp, Decimal::new(1000, -3))]
+    #[case::pad(".5", 6, FractionalSecondsRounding::Truncate, Decimal::new(500_000, -6))]
+    #[case::no_fraction("", 3, FractionalSecondsRounding::HalfUp, Decimal::new(0, -3))]
+    #[case::arbitrary(".123456789987", 9, FractionalSecondsRounding::HalfUp, Decimal::new(123_456_790, -9))]
+    #[case::tiny(".00000000000000000000000000000000000000000001", 3, FractionalSecondsRounding::Ceiling, Decimal::new(1, -3))]
+    fn test_fractional_seconds_with_precision(
+        #[case] fraction: &str,
+        #[case] digits: u32,
+        #[case] rounding: FractionalSecondsRounding,
+        #[case] expected: Decimal,
+    ) -> IonResult<()> {
+        let text = format!("2024-06-15T12:30:45{fraction}Z");
+        let timestamp = crate::Element::read_one(text)?.expect_timestamp()?;
+        let actual = timestamp.fractional_seconds_with_precision(digits, rounding);
+        assert!(
+            actual.ion_eq(&expected),
+            "expected {expected}, found {actual}"
+        );
+        Ok(())
+    }
 }
@@ -1,24 +1,203 @@
 use std::io;
 use std::marker::PhantomData;
 
+use crate::lazy::cancellation::CancellationToken;
+use crate::lazy::encoder::text::v1_0::layout::LayoutBuffer;
 use crate::lazy::encoder::value_writer::SequenceWriter;
+use crate::lazy::encoder::value_writer_config::{
+    AnnotationsEncoding, EExpEncoding, ScalarEncoding, SymbolInterningPolicy, ValueWriterConfig,
+};
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoder::writer::Writer;
 use crate::lazy::encoding::{
     BinaryEncoding_1_0, BinaryEncoding_1_1, Encoding, OutputFromBytes, TextEncoding_1_0,
     TextEncoding_1_1,
 };
-use crate::{IonResult, TextFormat};
+use crate::text::whitespace_config::{
+    WhitespaceConfig, COMPACT_WHITESPACE_CONFIG, LINES_WHITESPACE_CONFIG,
+};
+use crate::{
+    FloatFormat, IonResult, MacroFormat, PrettyConfig, SharedSymbolTable, SymbolIdFormat,
+    TextFormat, TimestampPrecisionPolicy,
+};
 
 /// Writer configuration to provide format and Ion version details to writer through encoding
 /// This will be used to create a writer without specifying which writer methods to use
 #[derive(Clone, Debug)]
 pub struct WriteConfig<E: Encoding> {
     pub(crate) kind: WriteConfigKind,
+    timestamp_precision: TimestampPrecisionPolicy,
+    cancellation_token: Option<CancellationToken>,
+    max_segment_size: Option<usize>,
+    symbol_table_limit: Option<SymbolTableLimit>,
+    shared_symbol_tables: Vec<SharedSymbolTable>,
     phantom_data: PhantomData<E>,
 }
 
+/// What a writer keeps when it replaces a symbol table that has reached its maximum size. See
+/// [`WriteConfig::with_max_symbol_table_size`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SymbolTableRotation {
+    /// Start the new symbol table with only the system symbols.
+    #[default]
+    Reset,
+    /// Keep the first `n` symbols that were added to the old table. The symbols a stream uses
+    /// most often, like the field names of a fixed schema, are usually among the first it adds,
+    /// so this avoids re-declaring them in every new table. Keeping the same number of symbols
+    /// as the maximum size (or more) means that every new symbol causes a rotation.
+    KeepFirst(usize),
+}
+
+/// The maximum number of symbols in a writer's symbol table and how to replace it when it is
+/// full.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct SymbolTableLimit {
+    pub(crate) max_symbols: usize,
+    pub(crate) rotation: SymbolTableRotation,
+}
+
+impl SymbolTableLimit {
+    /// Returns the number of symbols the writer keeps when it rotates its symbol table.
+    pub(crate) fn num_retained(&self) -> usize {
+        match self.rotation {
+            SymbolTableRotation::Reset => 0,
+            SymbolTableRotation::KeepFirst(n) => n.min(self.max_symbols),
+        }
+    }
+}
+
 impl<E: Encoding> WriteConfig<E> {
+    /// Returns the layout the writer will use for e-expressions and macro definitions. Binary
+    /// writers always use the default.
+    pub(crate) fn macro_format(&self) -> MacroFormat {
+        match &self.kind {
+            WriteConfigKind::Text(text_config) => text_config.macro_format,
+            WriteConfigKind::Binary(_) => MacroFormat::default(),
+        }
+    }
+
+    /// Returns how the writer represents transcribed symbol IDs. Binary writers always write
+    /// symbol IDs as-is.
+    pub(crate) fn symbol_id_format(&self) -> SymbolIdFormat {
+        match &self.kind {
+            WriteConfigKind::Text(text_config) => text_config.symbol_id_format,
+            WriteConfigKind::Binary(_) => SymbolIdFormat::default(),
+        }
+    }
+
+    /// Sets how the writer adjusts the fractional seconds of timestamps before encoding them,
+    /// for example to always write millisecond precision. See [`TimestampPrecisionPolicy`] for
+    /// details.
+    pub fn with_timestamp_precision(
+        mut self,
+        timestamp_precision: TimestampPrecisionPolicy,
+    ) -> Self {
+        self.timestamp_precision = timestamp_precision;
+        self
+    }
+
+    /// Sets the token that writers constructed from this configuration will check before writing
+    /// each top-level value and before each flush. This allows conversions that construct their
+    /// own writer, like [`ndjson_to_ion`](crate::convert::ndjson::ndjson_to_ion), to be cancelled.
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    pub(crate) fn cancellation_token(&self) -> Option<&CancellationToken> {
+        self.cancellation_token.as_ref()
+    }
+
+    /// Divides the writer's output into segments of at most `max_bytes` bytes that can each be
+    /// read on their own, as when each segment will be sent as a separate Kinesis or Kafka
+    /// record. Every segment after the first begins with an IVM and defines all of the writer's
+    /// symbols and macros again.
+    ///
+    /// When a value would not fit in the current segment, the writer writes the segment to the
+    /// output, flushes the output, and begins a new segment containing the value. Calling
+    /// [`Writer::flush`] also finishes the current segment. Writing a value that would not fit
+    /// even in an empty segment returns an `Err`; the value is not written, but the segment
+    /// before it will have been finished.
+    ///
+    /// Because a value may have to be written again in a new segment, top-level values must be
+    /// written using [`write`](Writer::write), the methods built on it (like `write_all` and
+    /// `write_string`), or an [`ElementWriter`](crate::ElementWriter) method. The
+    /// [`ValueWriter`](crate::ValueWriter) returned by `value_writer()` and the container writers
+    /// built on it, like `struct_writer()` and `list_writer()`, return an `Err` instead of
+    /// writing a value.
+    pub fn with_max_segment_size(mut self, max_bytes: usize) -> Self {
+        self.max_segment_size = Some(max_bytes);
+        self
+    }
+
+    pub(crate) fn max_segment_size(&self) -> Option<usize> {
+        self.max_segment_size
+    }
+
+    /// Limits the writer's symbol table to `max_symbols` symbols, not counting the system
+    /// symbols. This keeps long-lived writers whose data contains many distinct field names or
+    /// symbol values from growing their symbol table without bound.
+    ///
+    /// When writing a value would add more symbols than the limit allows, the writer first writes
+    /// its buffered data to the output. It then writes an IVM, which starts a new symbol table
+    /// containing the symbols that `rotation` keeps, and writes the value after it. Symbols that
+    /// are kept retain their symbol IDs. Writing a value that needs more symbols than fit in a
+    /// newly rotated table returns an `Err`; the value is not written.
+    ///
+    /// Text writers only add symbols to their symbol table when configured to write them as
+    /// symbol IDs. As with [`with_max_segment_size`](Self::with_max_segment_size), top-level
+    /// values must be written using [`write`](Writer::write) or the methods built on it; writers
+    /// from `value_writer()`, `struct_writer()`, and the like return an `Err`.
+    pub fn with_max_symbol_table_size(
+        mut self,
+        max_symbols: usize,
+        rotation: SymbolTableRotation,
+    ) -> Self {
+        self.symbol_table_limit = Some(SymbolTableLimit {
+            max_symbols,
+            rotation,
+        });
+        self
+    }
+
+    pub(crate) fn symbol_table_limit(&self) -> Option<SymbolTableLimit> {
+        self.symbol_table_limit
+    }
+
+    /// Imports the symbols of each shared symbol table in `tables`, in order, into the writer's
+    /// symbol table. Symbols in the imported tables are encoded as symbol IDs without being
+    /// defined in the stream. Instead, the first symbol table after each IVM names the imported
+    /// tables and their versions: an Ion 1.0 stream imports them in its local symbol table, and
+    /// an Ion 1.1 stream imports them in a `(module _ ...)` directive. Readers of the stream must
+    /// have the same tables in their [`Catalog`](crate::Catalog).
+    pub fn with_shared_symbol_table_imports(
+        mut self,
+        tables: impl IntoIterator<Item = SharedSymbolTable>,
+    ) -> Self {
+        self.shared_symbol_tables.extend(tables);
+        self
+    }
+
+    pub(crate) fn shared_symbol_tables(&self) -> &[SharedSymbolTable] {
+        &self.shared_symbol_tables
+    }
+
+    /// Returns the value writer configuration the writer will use for top-level values. Binary
+    /// 1.1 writers honor the configured annotations, scalar, and e-expression encodings and the
+    /// symbol interning policy; all other settings use the encoding's defaults.
+    pub(crate) fn value_writer_config(&self) -> ValueWriterConfig {
+        let config =
+            E::default_value_writer_config().with_timestamp_precision(self.timestamp_precision);
+        match &self.kind {
+            WriteConfigKind::Text(_) => config,
+            WriteConfigKind::Binary(binary_config) => config
+                .with_annotations_encoding(binary_config.annotations_encoding)
+                .with_scalar_encoding(binary_config.scalar_encoding)
+                .with_eexp_encoding(binary_config.eexp_encoding)
+                .with_symbol_interning_policy(binary_config.symbol_interning_policy),
+        }
+    }
+
     pub(crate) fn encode<V: WriteAsIon>(&self, value: V) -> IonResult<E::Output> {
         let bytes = self.encode_to(value, Vec::new())?;
         Ok(E::Output::from_bytes(bytes))
@@ -56,25 +235,113 @@ impl<E: Encoding> WriteConfig<E> {
 impl WriteConfig<TextEncoding_1_0> {
     pub fn new(text_kind: TextFormat) -> Self {
         Self {
-            kind: WriteConfigKind::Text(TextWriteConfig { text_kind }),
+            kind: WriteConfigKind::Text(TextWriteConfig {
+                text_kind,
+                float_format: FloatFormat::default(),
+                macro_format: MacroFormat::default(),
+                symbol_id_format: SymbolIdFormat::default(),
+                pretty_config: PrettyConfig::default(),
+            }),
+            timestamp_precision: TimestampPrecisionPolicy::default(),
+            cancellation_token: None,
+            max_segment_size: None,
+            symbol_table_limit: None,
+            shared_symbol_tables: Vec::new(),
             phantom_data: Default::default(),
         }
     }
+
+    /// Sets the notation the writer will use for `float` values. See [`FloatFormat`] for details.
+    pub fn with_float_format(mut self, float_format: FloatFormat) -> Self {
+        if let WriteConfigKind::Text(text_config) = &mut self.kind {
+            text_config.float_format = float_format;
+        }
+        self
+    }
+
+    /// Sets how the writer represents the symbol IDs of symbols that it transcribes from another
+    /// stream. See [`SymbolIdFormat`] for details.
+    pub fn with_symbol_id_format(mut self, symbol_id_format: SymbolIdFormat) -> Self {
+        if let WriteConfigKind::Text(text_config) = &mut self.kind {
+            text_config.symbol_id_format = symbol_id_format;
+        }
+        self
+    }
+
+    /// Sets how the writer lays out values when using [`TextFormat::Pretty`]. See
+    /// [`PrettyConfig`] for details.
+    pub fn with_pretty_config(mut self, pretty_config: PrettyConfig) -> Self {
+        if let WriteConfigKind::Text(text_config) = &mut self.kind {
+            text_config.pretty_config = pretty_config;
+        }
+        self
+    }
 }
 
 impl WriteConfig<TextEncoding_1_1> {
     pub fn new(text_kind: TextFormat) -> Self {
         Self {
-            kind: WriteConfigKind::Text(TextWriteConfig { text_kind }),
+            kind: WriteConfigKind::Text(TextWriteConfig {
+                text_kind,
+                float_format: FloatFormat::default(),
+                macro_format: MacroFormat::default(),
+                symbol_id_format: SymbolIdFormat::default(),
+                pretty_config: PrettyConfig::default(),
+            }),
+            timestamp_precision: TimestampPrecisionPolicy::default(),
+            cancellation_token: None,
+            max_segment_size: None,
+            symbol_table_limit: None,
+            shared_symbol_tables: Vec::new(),
             phantom_data: Default::default(),
         }
     }
+
+    /// Sets the notation the writer will use for `float` values. See [`FloatFormat`] for details.
+    pub fn with_float_format(mut self, float_format: FloatFormat) -> Self {
+        if let WriteConfigKind::Text(text_config) = &mut self.kind {
+            text_config.float_format = float_format;
+        }
+        self
+    }
+
+    /// Sets how the writer represents the symbol IDs of symbols that it transcribes from another
+    /// stream. See [`SymbolIdFormat`] for details.
+    pub fn with_symbol_id_format(mut self, symbol_id_format: SymbolIdFormat) -> Self {
+        if let WriteConfigKind::Text(text_config) = &mut self.kind {
+            text_config.symbol_id_format = symbol_id_format;
+        }
+        self
+    }
+
+    /// Sets how the writer lays out values when using [`TextFormat::Pretty`]. See
+    /// [`PrettyConfig`] for details.
+    pub fn with_pretty_config(mut self, pretty_config: PrettyConfig) -> Self {
+        if let WriteConfigKind::Text(text_config) = &mut self.kind {
+            text_config.pretty_config = pretty_config;
+        }
+        self
+    }
+
+    /// Sets how the writer lays out e-expressions and macro definitions. See [`MacroFormat`] for
+    /// details.
+    pub fn with_macro_format(mut self, macro_format: MacroFormat) -> Self {
+        if let WriteConfigKind::Text(text_config) = &mut self.kind {
+            text_config.macro_format = macro_format;
+        }
+        self
+    }
 }
 
 impl WriteConfig<BinaryEncoding_1_0> {
     pub fn new() -> Self {
         Self {
-            kind: WriteConfigKind::Binary(BinaryWriteConfig),
+            kind: WriteConfigKind::Binary(BinaryWriteConfig::default()),
+            timestamp_precision: TimestampPrecisionPolicy::default(),
+            cancellation_token: None,
+            max_segment_size: None,
+            symbol_table_limit: None,
+            shared_symbol_tables: Vec::new(),
             phantom_data: Default::default(),
         }
     }
@@ -83,10 +350,56 @@ impl WriteConfig<BinaryEncoding_1_0> {
 impl WriteConfig<BinaryEncoding_1_1> {
     pub fn new() -> Self {
         Self {
-            kind: WriteConfigKind::Binary(BinaryWriteConfig),
+            kind: WriteConfigKind::Binary(BinaryWriteConfig::default()),
+            timestamp_precision: TimestampPrecisionPolicy::default(),
+            cancellation_token: None,
+            max_segment_size: None,
+            symbol_table_limit: None,
+            shared_symbol_tables: Vec::new(),
             phantom_data: Default::default(),
         }
     }
+
+    /// Sets how the writer encodes annotations. By default, annotation text is added to the
+    /// symbol table and written as a symbol ID; [`AnnotationsEncoding::InlineText`] writes it
+    /// as an inline FlexSym instead. Field names and symbol values are not affected.
+    pub fn with_annotations_encoding(mut self, annotations_encoding: AnnotationsEncoding) -> Self {
+        if let WriteConfigKind::Binary(binary_config) = &mut self.kind {
+            binary_config.annotations_encoding = annotations_encoding;
+        }
+        self
+    }
+
+    /// Sets whether the writer encodes decimals, timestamps, and strings using their short forms
+    /// when possible or always uses their long forms. See [`ScalarEncoding`] for details.
+    pub fn with_scalar_encoding(mut self, scalar_encoding: ScalarEncoding) -> Self {
+        if let WriteConfigKind::Binary(binary_config) = &mut self.kind {
+            binary_config.scalar_encoding = scalar_encoding;
+        }
+        self
+    }
+
+    /// Sets whether the writer encodes e-expressions with the length of their arguments so that
+    /// readers can skip over them. See [`EExpEncoding`] for details.
+    pub fn with_eexp_encoding(mut self, eexp_encoding: EExpEncoding) -> Self {
+        if let WriteConfigKind::Binary(binary_config) = &mut self.kind {
+            binary_config.eexp_encoding = eexp_encoding;
+        }
+        self
+    }
+
+    /// Sets which new symbol text the writer adds to its symbol table. By default, the text of
+    /// every symbol value, field name, and annotation is added; text that the policy declines to
+    /// intern is written inline instead. See [`SymbolInterningPolicy`] for details.
+    pub fn with_symbol_interning_policy(
+        mut self,
+        symbol_interning_policy: SymbolInterningPolicy,
+    ) -> Self {
+        if let WriteConfigKind::Binary(binary_config) = &mut self.kind {
+            binary_config.symbol_interning_policy = symbol_interning_policy;
+        }
+        self
+    }
 }
 
 impl Default for WriteConfig<TextEncoding_1_0> {
@@ -124,12 +437,42 @@ pub(crate) enum WriteConfigKind {
 #[derive(Clone, Debug)]
 pub(crate) struct TextWriteConfig {
     pub(crate) text_kind: TextFormat,
+    pub(crate) float_format: FloatFormat,
+    pub(crate) macro_format: MacroFormat,
+    pub(crate) symbol_id_format: SymbolIdFormat,
+    pub(crate) pretty_config: PrettyConfig,
+}
+
+impl TextWriteConfig {
+    /// Returns the whitespace that the writer puts between and within values.
+    pub(crate) fn whitespace_config(&self) -> WhitespaceConfig {
+        match self.text_kind {
+            TextFormat::Compact => COMPACT_WHITESPACE_CONFIG,
+            TextFormat::Lines => LINES_WHITESPACE_CONFIG,
+            TextFormat::Pretty => self.pretty_config.whitespace_config(),
+        }
+    }
+
+    /// Returns a buffer for deciding how to lay out containers, if the configured format
+    /// requires one.
+    pub(crate) fn layout_buffer(&self) -> Option<Box<LayoutBuffer>> {
+        match self.text_kind {
+            TextFormat::Pretty if self.pretty_config.requires_layout() => {
+                Some(Box::new(LayoutBuffer::new(self.pretty_config)))
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Binary writer configuration to be used to create a writer
-// TODO: Add appropriate binary configuration if required for 1.1
-#[derive(Clone, Debug)]
-pub(crate) struct BinaryWriteConfig;
+#[derive(Clone, Debug, Default)]
+pub(crate) struct BinaryWriteConfig {
+    pub(crate) annotations_encoding: AnnotationsEncoding,
+    pub(crate) scalar_encoding: ScalarEncoding,
+    pub(crate) eexp_encoding: EExpEncoding,
+    pub(crate) symbol_interning_policy: SymbolInterningPolicy,
+}
 
 impl From<TextEncoding_1_0> for WriteConfig<TextEncoding_1_0> {
     fn from(_encoding: TextEncoding_1_0) -> Self {
@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::str::FromStr;
 
 use super::context::Context;
@@ -11,13 +12,26 @@ use ion_rs::{Element, Sequence};
 #[derive(Debug, Default)]
 pub(crate) struct Document {
     pub name: Option<String>,
+    /// Path to the file that the document was loaded from, if any.
+    pub file: PathBuf,
     pub fragments: Vec<Fragment>,
     pub continuation: Continuation,
 }
 
 impl Document {
-    /// Execute the test by evaluating the document's continuation.
+    /// Execute the test by evaluating the document's continuation. Any error that is returned
+    /// identifies the document's file and name.
     pub fn run(&self) -> Result<()> {
+        self.evaluate().map_err(|e| {
+            let e = e.in_file(&self.file);
+            match &self.name {
+                Some(name) => e.in_test(name),
+                None => e,
+            }
+        })
+    }
+
+    fn evaluate(&self) -> Result<()> {
         let ctx = Context::new(IonVersion::Unspecified, self.encoding(), &self.fragments)?;
         self.continuation.evaluate(&ctx)?;
         Ok(())
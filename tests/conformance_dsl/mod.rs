@@ -88,6 +88,24 @@ impl From<ConformanceErrorImpl> for ConformanceError {
     }
 }
 
+impl ConformanceError {
+    /// Sets the path of the file containing the failed test, unless the error already names one.
+    pub(crate) fn in_file<P: AsRef<Path>>(mut self, file: P) -> Self {
+        if self.0.file.as_os_str().is_empty() {
+            self.0.file = file.as_ref().to_owned();
+        }
+        self
+    }
+
+    /// Sets the name of the failed test, unless the error already names one.
+    pub(crate) fn in_test(mut self, test_name: &str) -> Self {
+        if self.0.test_name.is_empty() {
+            self.0.test_name = test_name.to_owned();
+        }
+        self
+    }
+}
+
 impl From<std::io::Error> for ConformanceError {
     fn from(other: std::io::Error) -> Self {
         ConformanceErrorImpl {
@@ -237,15 +255,14 @@ pub(crate) struct TestCollection {
 impl TestCollection {
     /// Loads a TestCollection from a file at the provided path.
     pub fn load<P: AsRef<Path>>(path: P) -> Result<TestCollection> {
-        let test_file = std::fs::File::open(&path)?;
-        match Self::load_from(test_file) {
-            Err(e) => Err(ConformanceErrorImpl {
-                file: path.as_ref().to_owned(),
-                ..*e.0
-            }
-            .into()),
-            Ok(t) => Ok(t),
+        let path = path.as_ref();
+        let test_file =
+            std::fs::File::open(path).map_err(|e| ConformanceError::from(e).in_file(path))?;
+        let mut collection = Self::load_from(test_file).map_err(|e| e.in_file(path))?;
+        for doc in collection.documents.iter_mut() {
+            doc.file = path.to_owned();
         }
+        Ok(collection)
     }
 
     pub fn load_from<R: Read>(reader: R) -> Result<TestCollection> {
@@ -259,17 +276,20 @@ impl TestCollection {
                     let seq = element.as_sexp().unwrap();
                     let doc = match Document::try_from(seq.clone()) {
                         Err(kind) => {
-                            return Err(ConformanceErrorImpl {
-                                kind,
-                                ..Default::default()
-                            }
-                            .into())
+                            let error = ConformanceError::from(kind);
+                            // The document's optional name follows its clause type.
+                            return Err(match seq.get(1).and_then(Element::as_string) {
+                                Some(name) => error.in_test(name),
+                                None => error,
+                            });
                         }
                         Ok(doc) => doc,
                     };
                     docs.push(doc);
                 }
-                _ => todo!(),
+                // Test files may contain top-level values other than test documents, like strings
+                // that describe the tests that follow. These are not evaluated.
+                _ => continue,
             }
         }
 
@@ -477,6 +497,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_load_collection() {
+        let source = r#"
+            "Values other than test documents are skipped."
+            (ion_1_0 "passes" (text "a") (produces a))
+            (ion_1_0 "fails" (text "a") (produces b))
+        "#;
+        let collection = TestCollection::load_from(source.as_bytes())
+            .unwrap_or_else(|e| panic!("Failed to load collection:\n{e:?}"));
+        assert_eq!(collection.len(), 2);
+        let error = collection
+            .run()
+            .expect_err("expected the second test to fail");
+        assert_eq!(error.0.test_name, "fails");
+        assert!(matches!(
+            error.0.kind,
+            ConformanceErrorKind::MismatchedProduce
+        ));
+
+        // Errors in a document's clauses identify the document.
+        let error = TestCollection::load_from(r#"(ion_1_0 "broken" (text "a"))"#.as_bytes())
+            .err()
+            .expect("expected the document to be rejected");
+        assert_eq!(error.0.test_name, "broken");
+    }
+
     #[test]
     fn test_encoding() {
         let test: &str = r#"
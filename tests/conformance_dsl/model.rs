@@ -2,7 +2,9 @@ use super::{
     parse_bytes_exp, parse_text_exp, Clause, ClauseType, ConformanceErrorKind, Context, InnerResult,
 };
 use ion_rs::decimal::coefficient::Coefficient;
-use ion_rs::{v1_0::RawValueRef, Int, LazyRawValue, List, SExp, SymbolId, SymbolRef, Value};
+use ion_rs::{
+    v1_0::RawValueRef, Int, LazyRawValue, List, SExp, Symbol, SymbolId, SymbolRef, Value,
+};
 use ion_rs::{Decimal, Element, IonType, Sequence, Timestamp, ValueRef};
 
 /// Represents a symbol in the Data Model representation of ion data.
@@ -29,6 +31,18 @@ impl SymbolToken {
             Address(..) | Absent(..) => todo!("deal with SymbolToken with ambiguous meaning"),
         }
     }
+
+    /// Converts the token to an owned `Symbol`. Tokens that refer to a symbol by its address (other
+    /// than `$0`) or by its position in a shared symbol table cannot be resolved without a context,
+    /// and are reported as an error.
+    fn to_symbol(&self) -> InnerResult<Symbol> {
+        use SymbolToken::*;
+        match self {
+            Text(text) => Ok(Symbol::owned(text.as_str())),
+            Address(0) => Ok(Symbol::unknown_text()),
+            Address(..) | Absent(..) => Err(ConformanceErrorKind::ExpectedSymbolType),
+        }
+    }
 }
 
 impl std::fmt::Display for SymbolToken {
@@ -156,10 +170,22 @@ impl TryFrom<&ModelValue> for Element {
                     .collect::<Result<Vec<_>, ConformanceErrorKind>>()?;
                 SExp::from(elements).into()
             }
-            ModelValue::Struct(_) => todo!(),
-            ModelValue::Blob(_) => todo!(),
-            ModelValue::Clob(_) => todo!(),
-            ModelValue::Annot(_, _) => todo!(), // Not used currently.
+            ModelValue::Struct(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|(name, value)| Ok((name.to_symbol()?, Element::try_from(value)?)))
+                    .collect::<InnerResult<Vec<_>>>()?;
+                Element::struct_builder().with_fields(fields).build().into()
+            }
+            ModelValue::Blob(bytes) => Element::blob(bytes),
+            ModelValue::Clob(bytes) => Element::clob(bytes),
+            ModelValue::Annot(value, annots) => {
+                let annots = annots
+                    .iter()
+                    .map(SymbolToken::to_symbol)
+                    .collect::<InnerResult<Vec<_>>>()?;
+                Element::try_from(value.as_ref())?.with_annotations(annots)
+            }
         };
         Ok(element)
     }
@@ -402,15 +428,8 @@ pub(crate) fn compare_values<T: ion_rs::Decoder>(
             if actual_struct.len() != expected_fields.len() {
                 return Ok(false);
             }
-            let expected_struct = Element::struct_builder()
-                .with_fields(expected_fields.iter().map(|(token, model_value)| {
-                    (
-                        token.as_symbol_ref().to_owned(),
-                        Element::try_from(model_value).unwrap(),
-                    )
-                }))
-                .build();
-            Ok(actual_struct.eq(&expected_struct))
+            let expected_elem = Element::try_from(model)?;
+            Ok(actual_struct.eq(expected_elem.as_struct().unwrap()))
         }
         ModelValue::List(expected) => {
             let ValueRef::List(list) = other.read().expect("error reading list") else {
@@ -762,4 +781,39 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    /// Tests the conversion of nested structs, lobs, and annotations from the data model to Element.
+    fn test_model_value_to_element() {
+        let model_value = ModelValue::Annot(
+            Box::new(ModelValue::Struct(vec![
+                (
+                    SymbolToken::Text("a".to_string()),
+                    ModelValue::Blob(vec![0x01, 0x02]),
+                ),
+                (
+                    SymbolToken::Address(0),
+                    ModelValue::Annot(
+                        Box::new(ModelValue::Struct(vec![(
+                            SymbolToken::Text("b".to_string()),
+                            ModelValue::Clob(b"hi".to_vec()),
+                        )])),
+                        vec![SymbolToken::Text("inner".to_string())],
+                    ),
+                ),
+            ])),
+            vec![SymbolToken::Text("outer".to_string())],
+        );
+        let expected = Element::read_one(r#"outer::{a: {{AQI=}}, $0: inner::{b: {{"hi"}}}}"#)
+            .expect("unable to read expected value");
+        assert_eq!(
+            Element::try_from(&model_value).expect("unable to convert model value"),
+            expected
+        );
+
+        // Symbol IDs other than $0 can't be resolved without a context.
+        let unresolvable =
+            ModelValue::Struct(vec![(SymbolToken::Address(10), ModelValue::Bool(true))]);
+        assert!(Element::try_from(&unresolvable).is_err());
+    }
 }